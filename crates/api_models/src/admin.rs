@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use common_utils::{
     consts,
@@ -962,6 +962,10 @@ pub struct BusinessProfileCreate {
     /// Default payout link config
     #[schema(value_type = Option<BusinessPayoutLinkConfig>)]
     pub payout_link_config: Option<BusinessPayoutLinkConfig>,
+    /// The number of days after which payments authorized under this business profile, which
+    /// have not been captured, are voided automatically. Automatic voiding is disabled if not set.
+    #[schema(minimum = 1, maximum = 30, example = 7)]
+    pub auto_void_after_days: Option<u16>,
 }
 
 #[derive(Clone, Debug, ToSchema, Serialize)]
@@ -1054,6 +1058,10 @@ pub struct BusinessProfileResponse {
     /// Default payout link config
     #[schema(value_type = Option<BusinessPayoutLinkConfig>)]
     pub payout_link_config: Option<BusinessPayoutLinkConfig>,
+    /// The number of days after which payments authorized under this business profile, which
+    /// have not been captured, are voided automatically. Automatic voiding is disabled if not set.
+    #[schema(minimum = 1, maximum = 30, example = 7)]
+    pub auto_void_after_days: Option<u16>,
 }
 
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
@@ -1138,6 +1146,86 @@ pub struct BusinessProfileUpdate {
     /// Default payout link config
     #[schema(value_type = Option<BusinessPayoutLinkConfig>)]
     pub payout_link_config: Option<BusinessPayoutLinkConfig>,
+    /// The number of days after which payments authorized under this business profile, which
+    /// have not been captured, are voided automatically. Automatic voiding is disabled if not set.
+    #[schema(minimum = 1, maximum = 30, example = 7)]
+    pub auto_void_after_days: Option<u16>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookSigningSecretRotateRequest {
+    /// The duration (in seconds) for which the currently active signing secrets continue to be
+    /// used for signing webhooks, alongside the newly generated secret.
+    #[schema(example = 86400)]
+    pub overlap_seconds: Option<u32>,
+}
+
+#[derive(Clone, Debug, ToSchema, Serialize)]
+pub struct WebhookSigningSecretRotateResponse {
+    /// The identifier for the business profile
+    #[schema(max_length = 64, example = "pro_abcdefghijklmnopqrstuvwxyz")]
+    pub profile_id: String,
+
+    /// The identifier for the newly generated signing secret
+    #[schema(max_length = 64, example = "whsec_abcdefghijklmnopqrstuvwxyz")]
+    pub secret_id: String,
+
+    /// The newly generated signing secret. The secret is only displayed once on rotation, please
+    /// ensure you store it securely.
+    #[schema(value_type = String)]
+    pub secret: masking::StrongSecret<String>,
+
+    /// The time at which the signing secret was created
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+
+    /// The time at which the previously active signing secrets stop being used for signing
+    /// webhooks
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub previous_secrets_expire_at: Option<time::PrimitiveDateTime>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ToSchema, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookSigningSecretStatus {
+    /// The secret is being used for signing webhooks
+    Active,
+    /// The overlap window of the secret has elapsed
+    Expired,
+    /// The secret has been revoked
+    Revoked,
+}
+
+#[derive(Clone, Debug, ToSchema, Serialize)]
+pub struct WebhookSigningSecretResponse {
+    /// The identifier for the business profile
+    #[schema(max_length = 64, example = "pro_abcdefghijklmnopqrstuvwxyz")]
+    pub profile_id: String,
+
+    /// The identifier for the signing secret
+    #[schema(max_length = 64, example = "whsec_abcdefghijklmnopqrstuvwxyz")]
+    pub secret_id: String,
+
+    /// The status of the signing secret
+    pub status: WebhookSigningSecretStatus,
+
+    /// The time at which the signing secret was created
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+
+    /// The time after which the signing secret is no longer used for signing webhooks
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<time::PrimitiveDateTime>,
+
+    /// The time at which the signing secret was revoked
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub revoked_at: Option<time::PrimitiveDateTime>,
 }
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct BusinessCollectLinkConfig {
@@ -1160,6 +1248,16 @@ pub struct BusinessGenericLinkConfig {
     /// Custom domain name to be used for hosting the link
     pub domain_name: Option<String>,
 
+    /// List of domains allowed to embed the link in an iframe, entries prefixed with `*.` allow
+    /// all subdomains of the domain
+    #[schema(value_type = Vec<String>, example = r#"["checkout.example.com", "*.example.org"]"#)]
+    #[serde(default)]
+    pub allowed_domains: HashSet<String>,
+
+    /// Custom HTML and CSS used for rendering the link
+    #[schema(value_type = Option<LinkTemplateConfig>)]
+    pub custom_template: Option<link_utils::LinkTemplateConfig>,
+
     #[serde(flatten)]
     #[schema(value_type = GenericLinkUiConfig)]
     pub ui_config: link_utils::GenericLinkUiConfig,
@@ -1175,6 +1273,13 @@ pub struct BusinessPaymentLinkConfig {
     pub default_config: Option<PaymentLinkConfigRequest>,
     /// list of configs for multi theme setup
     pub business_specific_configs: Option<HashMap<String, PaymentLinkConfigRequest>>,
+    /// List of domains allowed to embed the payment link in an iframe, entries prefixed with `*.`
+    /// allow all subdomains of the domain
+    #[schema(value_type = Option<Vec<String>>, example = r#"["checkout.example.com", "*.example.org"]"#)]
+    pub allowed_domains: Option<HashSet<String>>,
+    /// Custom HTML and CSS used for rendering the payment link
+    #[schema(value_type = Option<LinkTemplateConfig>)]
+    pub custom_template: Option<link_utils::LinkTemplateConfig>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, PartialEq, ToSchema)]
@@ -0,0 +1,95 @@
+use common_enums::{AuditActorType, AuditEntityType};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+/// The constraints to apply when filtering audit log entries.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AuditLogListConstraints {
+    /// Filter entries for the specified kind of resource.
+    pub entity_type: Option<AuditEntityType>,
+
+    /// Filter entries for the specified resource identifier.
+    pub entity_id: Option<String>,
+
+    /// Filter entries performed by the specified user or API key.
+    pub actor_id: Option<String>,
+
+    /// Filter entries for the specified action (for example, `MerchantConnectorsUpdate`).
+    pub action: Option<String>,
+
+    /// Filter entries created after the specified time.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_after: Option<PrimitiveDateTime>,
+
+    /// Filter entries created before the specified time.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_before: Option<PrimitiveDateTime>,
+
+    /// Include at most the specified number of entries.
+    pub limit: Option<u16>,
+
+    /// Include entries after the specified offset.
+    pub offset: Option<u16>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AuditLogListRequestInternal {
+    pub merchant_id: String,
+    pub constraints: AuditLogListConstraints,
+}
+
+/// The response body for each item when listing audit log entries.
+#[derive(Debug, Serialize)]
+pub struct AuditLogListItemResponse {
+    /// The identifier for the audit log entry.
+    pub id: String,
+
+    /// The identifier for the Organization the change was made in.
+    pub org_id: Option<String>,
+
+    /// The identifier for the Merchant Account the change was made in.
+    pub merchant_id: Option<String>,
+
+    /// The kind of principal that performed the action.
+    pub actor_type: AuditActorType,
+
+    /// The user ID or API key ID that performed the action.
+    pub actor_id: Option<String>,
+
+    /// The action that was performed.
+    pub action: String,
+
+    /// The kind of resource that was modified.
+    pub entity_type: AuditEntityType,
+
+    /// The identifier for the resource that was modified.
+    pub entity_id: Option<String>,
+
+    /// The top-level fields of the resource that differ between `previous` and `after`.
+    pub changed_fields: Vec<String>,
+
+    /// The masked state of the resource before the action, absent for resources which were
+    /// created by the action.
+    pub previous: Option<serde_json::Value>,
+
+    /// The masked state of the resource after the action.
+    pub after: Option<serde_json::Value>,
+
+    /// The IP address the request originated from.
+    pub ip_address: Option<String>,
+
+    /// The request ID of the API call that performed the action.
+    pub request_id: Option<String>,
+
+    /// Time at which the action was performed.
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
+
+impl common_utils::events::ApiEventMetric for AuditLogListRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::AuditLog {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
@@ -9,6 +9,20 @@ pub enum BlocklistRequest {
     CardBin(String),
     Fingerprint(String),
     ExtendedCardBin(String),
+    /// An email address, blocked irrespective of its case
+    Email(String),
+    /// The domain of email addresses, such as `example.com`
+    EmailDomain(String),
+    /// An IPv4 or IPv6 address
+    IpAddress(String),
+    /// A range of IP addresses in CIDR notation, such as `203.0.113.0/24`
+    IpRange(String),
+    /// A phone number in the E.164 format, such as `+14155552671`
+    PhoneNumber(String),
+    /// The identifier of a customer of the merchant
+    CustomerId(String),
+    /// The device fingerprint sent in the browser information of the payment
+    DeviceFingerprint(String),
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
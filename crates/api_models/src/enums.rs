@@ -136,6 +136,7 @@ pub enum Connector {
     Signifyd,
     Plaid,
     Riskified,
+    RulesEngine,
     Zen,
     Zsl,
 }
@@ -253,6 +254,7 @@ impl Connector {
             | Self::Signifyd
             | Self::Plaid
             | Self::Riskified
+            | Self::RulesEngine
             | Self::Threedsecureio
             // | Self::Datatrans
             | Self::Netcetera
@@ -393,6 +395,8 @@ pub enum FrmConnectors {
     /// Signifyd Risk Manager. Official docs: https://docs.signifyd.com/
     Signifyd,
     Riskified,
+    /// In-house rules engine evaluating merchant defined rules over payment attributes and velocity counts
+    RulesEngine,
 }
 
 #[derive(
@@ -671,6 +675,56 @@ pub enum StripeChargeType {
     Destination,
 }
 
+/// The type of installment plan
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Hash,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum InstallmentPlanType {
+    /// The amount is split into installments of equal value
+    #[default]
+    Regular,
+    /// The amount is charged to the revolving credit line of the card
+    Revolving,
+}
+
+/// The party bearing the cost of financing the installments
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Hash,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum InstallmentFinancingType {
+    /// The merchant finances the installments, and the customer is not charged interest
+    #[default]
+    MerchantFinanced,
+    /// The issuer finances the installments, and may charge the customer interest
+    IssuerFinanced,
+}
+
 #[cfg(feature = "frm")]
 pub fn convert_frm_connector(connector_name: &str) -> Option<FrmConnectors> {
     FrmConnectors::from_str(connector_name).ok()
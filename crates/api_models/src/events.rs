@@ -34,6 +34,7 @@ use crate::{
     payment_methods::*,
     payments::*,
     verifications::*,
+    webhook_endpoints::*,
 };
 
 impl ApiEventMetric for TimeRange {}
@@ -75,6 +76,14 @@ impl_misc_api_event_type!(
     BusinessProfileResponse,
     BusinessProfileUpdate,
     BusinessProfileCreate,
+    WebhookSigningSecretRotateRequest,
+    WebhookSigningSecretRotateResponse,
+    WebhookSigningSecretResponse,
+    WebhookEndpointCreateRequest,
+    WebhookEndpointUpdateRequest,
+    WebhookEndpointSecretRotateRequest,
+    WebhookEndpointResponse,
+    WebhookEndpointDeleteResponse,
     RevokeApiKeyResponse,
     ToggleKVResponse,
     ToggleKVRequest,
@@ -0,0 +1,69 @@
+use euclid::frontend::{
+    ast::Program,
+    dir::{DirKeyKind, EuclidDirFilter},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Decision taken by the in-house fraud rules engine for a payment
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FrmRuleDecision {
+    /// Let the payment proceed
+    #[default]
+    Approve,
+    /// Hold the payment for a manual review by the merchant
+    Review,
+    /// Cancel the payment as fraudulent
+    Decline,
+}
+
+/// Output of a rule in the fraud rules program
+#[derive(Clone, Debug, Default, Serialize, Deserialize, ToSchema)]
+pub struct FrmRuleOutput {
+    pub decision: FrmRuleDecision,
+    /// Risk score recorded against the fraud check when the rule matches
+    pub score: Option<i32>,
+}
+
+impl EuclidDirFilter for FrmRuleOutput {
+    const ALLOWED: &'static [DirKeyKind] = &[
+        DirKeyKind::PaymentMethod,
+        DirKeyKind::CardType,
+        DirKeyKind::CardNetwork,
+        DirKeyKind::CardBin,
+        DirKeyKind::MetaData,
+        DirKeyKind::PaymentAmount,
+        DirKeyKind::PaymentCurrency,
+        DirKeyKind::AuthenticationType,
+        DirKeyKind::CaptureMethod,
+        DirKeyKind::BillingCountry,
+        DirKeyKind::BusinessCountry,
+        DirKeyKind::SetupFutureUsage,
+        DirKeyKind::CardAttempts,
+        DirKeyKind::CustomerAttempts,
+        DirKeyKind::IpAttempts,
+        DirKeyKind::EmailAttempts,
+        DirKeyKind::CustomerDistinctCards,
+    ];
+}
+
+/// Metadata of a `rules_engine` merchant connector account, holding the fraud rules program
+/// evaluated for the payments of the business profile
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FrmRulesEngineMetadata {
+    pub rules: Program<FrmRuleOutput>,
+}
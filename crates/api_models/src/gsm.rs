@@ -1,3 +1,4 @@
+use common_utils::types::RetrySchedule;
 use utoipa::ToSchema;
 
 use crate::enums::Connector;
@@ -26,6 +27,8 @@ pub struct GsmCreateRequest {
     pub unified_code: Option<String>,
     /// error message unified across the connectors
     pub unified_message: Option<String>,
+    /// schedule for retrying the payment later, required when the decision is `retry_later`
+    pub retry_schedule: Option<RetrySchedule>,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
@@ -60,6 +63,8 @@ pub struct GsmRetrieveRequest {
 pub enum GsmDecision {
     Retry,
     Requeue,
+    /// Retry the off-session payment later, on the schedule configured in `retry_schedule`
+    RetryLater,
     #[default]
     DoDefault,
 }
@@ -88,6 +93,8 @@ pub struct GsmUpdateRequest {
     pub unified_code: Option<String>,
     /// error message unified across the connectors
     pub unified_message: Option<String>,
+    /// schedule for retrying the payment later, required when the decision is `retry_later`
+    pub retry_schedule: Option<RetrySchedule>,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
@@ -141,4 +148,206 @@ pub struct GsmResponse {
     pub unified_code: Option<String>,
     /// error message unified across the connectors
     pub unified_message: Option<String>,
+    /// schedule for retrying the payment later, when the decision is `retry_later`
+    pub retry_schedule: Option<RetrySchedule>,
+}
+
+#[derive(Debug, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct GsmListRequest {
+    /// Only list the rules of this connector
+    pub connector: Option<Connector>,
+    /// Only list the rules of this flow
+    pub flow: Option<String>,
+    /// Only list the rules with this decision
+    pub decision: Option<GsmDecision>,
+    /// The maximum number of rules to return
+    pub limit: Option<u32>,
+    /// The number of rules to skip
+    pub offset: Option<u32>,
+}
+
+#[derive(
+    Default,
+    Clone,
+    Copy,
+    Debug,
+    strum::Display,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum GsmFileFormat {
+    /// A CSV file with a header row. The `retry_schedule` column holds the schedule as JSON.
+    #[default]
+    Csv,
+    /// A JSON array of GSM rules, in the format of the create request
+    Json,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct GsmImportRequest {
+    /// The format of the rules in `data`
+    #[serde(default)]
+    pub format: GsmFileFormat,
+    /// The rules to import, in the same format as the export of the rules
+    pub data: String,
+    /// Validate the rules and return the changes they would make, without applying them
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    strum::Display,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum GsmImportAction {
+    /// The rule does not exist, and is created
+    Create,
+    /// The rule exists with different values, and is updated
+    Update,
+    /// The rule exists with the same values
+    Unchanged,
+    /// The row is invalid, and no rules are imported
+    Invalid,
+}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct GsmImportRowResult {
+    /// The number of the row in the imported rules, starting from 1
+    pub row_number: usize,
+    /// The connector of the rule, unless the row could not be parsed
+    pub connector: Option<String>,
+    /// The flow of the rule, unless the row could not be parsed
+    pub flow: Option<String>,
+    /// The sub_flow of the rule, unless the row could not be parsed
+    pub sub_flow: Option<String>,
+    /// The code of the rule, unless the row could not be parsed
+    pub code: Option<String>,
+    /// The message of the rule, unless the row could not be parsed
+    pub message: Option<String>,
+    /// The change made by the row
+    pub action: GsmImportAction,
+    /// The fields of the existing rule changed by the row, when the action is `update`
+    pub changed_fields: Vec<String>,
+    /// The reason the row is invalid, when the action is `invalid`
+    pub error: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct GsmImportResponse {
+    /// Whether the changes were only validated, without being applied
+    pub dry_run: bool,
+    /// The number of rules created
+    pub created_count: usize,
+    /// The number of rules updated
+    pub updated_count: usize,
+    /// The number of rules which were left unchanged
+    pub unchanged_count: usize,
+    /// The number of invalid rows
+    pub invalid_count: usize,
+    /// The outcome of every row of the import
+    pub rows: Vec<GsmImportRowResult>,
+}
+
+#[derive(Debug, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct GsmExportRequest {
+    /// Only export the rules of this connector
+    pub connector: Option<Connector>,
+    /// Only export the rules of this flow
+    pub flow: Option<String>,
+    /// Only export the rules with this decision
+    pub decision: Option<GsmDecision>,
+    /// The format of the exported file
+    #[serde(default)]
+    pub format: GsmFileFormat,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct UnifiedTranslationsCreateRequest {
+    /// error code unified across the connectors
+    pub unified_code: String,
+    /// error message unified across the connectors, which is translated
+    pub unified_message: String,
+    /// The locale of the translation, as a language tag such as `fr` or `fr-CA`
+    pub locale: String,
+    /// The translation of the unified error message in the locale
+    pub translation: String,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct UnifiedTranslationsUpdateRequest {
+    /// error code unified across the connectors
+    pub unified_code: String,
+    /// error message unified across the connectors, which is translated
+    pub unified_message: String,
+    /// The locale of the translation, as a language tag such as `fr` or `fr-CA`
+    pub locale: String,
+    /// The translation of the unified error message in the locale
+    pub translation: String,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct UnifiedTranslationsDeleteRequest {
+    /// error code unified across the connectors
+    pub unified_code: String,
+    /// error message unified across the connectors, which is translated
+    pub unified_message: String,
+    /// The locale of the translation, as a language tag such as `fr` or `fr-CA`
+    pub locale: String,
+}
+
+#[derive(Debug, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct UnifiedTranslationsListRequest {
+    /// Only list the translations of this unified error code
+    pub unified_code: Option<String>,
+    /// Only list the translations in this locale
+    pub locale: Option<String>,
+    /// The maximum number of translations to return
+    pub limit: Option<u32>,
+    /// The number of translations to skip
+    pub offset: Option<u32>,
+}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct UnifiedTranslationsResponse {
+    /// error code unified across the connectors
+    pub unified_code: String,
+    /// error message unified across the connectors, which is translated
+    pub unified_message: String,
+    /// The locale of the translation
+    pub locale: String,
+    /// The translation of the unified error message in the locale
+    pub translation: String,
+    /// The time at which the translation was created
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    /// The time at which the translation was last modified
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub last_modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct UnifiedTranslationsDeleteResponse {
+    pub translation_delete: bool,
+    /// error code unified across the connectors
+    pub unified_code: String,
+    /// error message unified across the connectors, which is translated
+    pub unified_message: String,
+    /// The locale of the translation
+    pub locale: String,
 }
@@ -3,6 +3,7 @@ pub mod admin;
 pub mod analytics;
 pub mod api_keys;
 pub mod apple_pay_certificates_migration;
+pub mod audit_log;
 pub mod blocklist;
 pub mod cards_info;
 pub mod conditional_configs;
@@ -16,9 +17,11 @@ pub mod ephemeral_key;
 pub mod errors;
 pub mod events;
 pub mod files;
+pub mod frm_rules;
 pub mod gsm;
 pub mod health_check;
 pub mod locker_migration;
+pub mod log_filter;
 pub mod mandates;
 pub mod organization;
 pub mod payment_methods;
@@ -31,10 +34,13 @@ pub mod poll;
 pub mod recon;
 pub mod refunds;
 pub mod routing;
+pub mod subscriptions;
 pub mod surcharge_decision_configs;
+pub mod tenant;
 pub mod user;
 pub mod user_role;
 pub mod verifications;
 pub mod verify_connector;
+pub mod webhook_endpoints;
 pub mod webhook_events;
 pub mod webhooks;
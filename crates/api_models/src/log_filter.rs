@@ -0,0 +1,81 @@
+use common_utils::{
+    events::{ApiEventMetric, ApiEventsType},
+    impl_misc_api_event_type,
+};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// Attribute of the requests which are logged at a more verbose level
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum LogFilterField {
+    MerchantId,
+    ProfileId,
+    Connector,
+    /// Matched as a prefix of the path of the request
+    RequestPath,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum LogFilterLevel {
+    Debug,
+    Trace,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct LogFilterCreateRequest {
+    /// The attribute of the requests which are logged at a more verbose level
+    pub field: LogFilterField,
+
+    /// The value of the attribute
+    #[schema(example = "merchant_1668273825")]
+    pub value: String,
+
+    /// The level at which the matching requests are logged
+    pub level: LogFilterLevel,
+
+    /// The duration for which the matching requests are logged at the level, in seconds.
+    /// Defaults to 15 minutes, and cannot exceed a day.
+    #[schema(example = 900)]
+    pub duration_in_secs: Option<u64>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct LogFilterResponse {
+    /// The identifier of the log filter
+    #[schema(example = "log_filter_01J2AW3PQ3SXG5JS1Q5M8CWV2P")]
+    pub filter_id: String,
+
+    /// The attribute of the requests which are logged at a more verbose level
+    pub field: LogFilterField,
+
+    /// The value of the attribute
+    #[schema(example = "merchant_1668273825")]
+    pub value: String,
+
+    /// The level at which the matching requests are logged
+    pub level: LogFilterLevel,
+
+    /// Time after which the log filter is no longer applied
+    #[schema(example = "2024-07-08T10:15:30.000Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct LogFilterDeleteResponse {
+    /// The identifier of the log filter
+    pub filter_id: String,
+
+    /// Whether the log filter was deleted
+    pub deleted: bool,
+}
+
+impl_misc_api_event_type!(
+    LogFilterCreateRequest,
+    LogFilterResponse,
+    LogFilterDeleteResponse
+);
@@ -441,6 +441,12 @@ pub struct BankDebitTypes {
     pub eligible_connectors: Vec<String>,
 }
 
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema, PartialEq, Eq)]
+pub struct InstallmentTypes {
+    /// The connectors which can process this payment method type in installments
+    pub eligible_connectors: Vec<String>,
+}
+
 #[derive(Debug, Clone, serde::Serialize, ToSchema, PartialEq)]
 pub struct ResponsePaymentMethodTypes {
     /// The payment method type enabled
@@ -461,6 +467,9 @@ pub struct ResponsePaymentMethodTypes {
     /// The Bank transfer payment method information, if applicable for a payment method type.
     pub bank_transfers: Option<BankTransferTypes>,
 
+    /// The installment payment information, if any enabled connector supports installments for this payment method type
+    pub installments: Option<InstallmentTypes>,
+
     /// Required fields for the payment_method_type.
     pub required_fields: Option<HashMap<String, RequiredFieldInfo>>,
 
@@ -559,6 +568,7 @@ pub struct ResponsePaymentMethodIntermediate {
     pub card_networks: Option<Vec<api_enums::CardNetwork>>,
     pub payment_method: api_enums::PaymentMethod,
     pub connector: String,
+    pub installment_payment_enabled: bool,
 }
 
 impl ResponsePaymentMethodIntermediate {
@@ -573,6 +583,7 @@ impl ResponsePaymentMethodIntermediate {
             card_networks: pm_type.card_networks,
             payment_method: pm,
             connector,
+            installment_payment_enabled: pm_type.installment_payment_enabled,
         }
     }
 }
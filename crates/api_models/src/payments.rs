@@ -11,7 +11,7 @@ use common_utils::{
     ext_traits::{ConfigExt, Encode},
     id_type,
     pii::{self, Email},
-    types::{MinorUnit, StringMajorUnit},
+    types::{MinorUnit, SplitPayments, StringMajorUnit},
 };
 use masking::{PeekInterface, Secret};
 use router_derive::Setter;
@@ -293,6 +293,8 @@ pub struct PaymentsRequest {
 
     /// A timestamp (ISO 8601 code) that determines when the payment should be captured.
     /// Providing this field will automatically set `capture` to true
+    /// For payments with `capture_method` set to `manual`, the payment is captured at this time
+    /// once it has been authorized, unless it is captured or voided before then.
     #[schema(example = "2022-09-10T10:11:12Z")]
     #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
     #[remove_in(PaymentsUpdateRequest, PaymentsCreateRequest, PaymentsConfirmRequest)]
@@ -506,6 +508,32 @@ pub struct PaymentsRequest {
 
     /// Fee information to be charged on the payment being collected
     pub charges: Option<PaymentChargeRequest>,
+
+    /// Split of the payment across recipient accounts of a marketplace. Only applicable during payment creation, and cannot be combined with `charges`
+    #[schema(value_type = Option<Vec<PaymentSplit>>)]
+    pub split_payments: Option<SplitPayments>,
+
+    /// Installment plan to split the payment into. Installments are applied when the payment is authorized, so this has to be passed in the request which confirms the payment
+    pub installments: Option<Installments>,
+}
+
+/// Installment plan of a payment
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub struct Installments {
+    /// Number of installments the payment is split into
+    #[schema(example = 6, minimum = 2)]
+    pub count: u8,
+
+    /// Type of the installment plan
+    #[serde(default)]
+    #[schema(value_type = InstallmentPlanType, example = "regular")]
+    pub plan_type: api_enums::InstallmentPlanType,
+
+    /// Whether the installments are financed by the merchant or by the issuer
+    #[serde(default)]
+    #[schema(value_type = InstallmentFinancingType, example = "merchant_financed")]
+    pub financing_type: api_enums::InstallmentFinancingType,
 }
 
 /// Fee information to be charged on the payment being collected
@@ -581,6 +609,10 @@ pub struct BrowserInformation {
 
     /// User-agent of the browser
     pub user_agent: Option<String>,
+
+    /// Fingerprint of the device of the client, as computed by the device fingerprinting solution
+    /// of the merchant
+    pub device_fingerprint: Option<String>,
 }
 
 impl RequestSurchargeDetails {
@@ -601,6 +633,8 @@ pub struct HeaderPayload {
     pub x_hs_latency: Option<bool>,
     pub browser_name: Option<api_enums::BrowserName>,
     pub x_client_platform: Option<api_enums::ClientPlatform>,
+    /// The language most preferred by the client, in which the unified error message is returned
+    pub locale: Option<String>,
 }
 
 impl HeaderPayload {
@@ -3415,6 +3449,8 @@ pub struct PaymentsResponse {
 
     /// A timestamp (ISO 8601 code) that determines when the payment should be captured.
     /// Providing this field will automatically set `capture` to true
+    /// For payments with `capture_method` set to `manual`, the payment is captured at this time
+    /// once it has been authorized, unless it is captured or voided before then.
     #[schema(example = "2022-09-10T10:11:12Z")]
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
     pub capture_on: Option<PrimitiveDateTime>,
@@ -3620,6 +3656,13 @@ pub struct PaymentsResponse {
     /// Fee information to be charged on the payment being collected
     pub charges: Option<PaymentChargeResponse>,
 
+    /// Split of the payment across recipient accounts of a marketplace
+    #[schema(value_type = Option<Vec<PaymentSplit>>)]
+    pub split_payments: Option<SplitPayments>,
+
+    /// Installment plan the payment was authorized with
+    pub installments: Option<Installments>,
+
     /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. FRM Metadata is useful for storing additional, structured information on an object related to FRM.
     #[schema(value_type = Option<Object>, example = r#"{ "fulfillment_method" : "deliver", "coverage_request" : "fraud" }"#)]
     pub frm_metadata: Option<pii::SecretSerdeValue>,
@@ -4797,11 +4840,28 @@ pub struct PaymentsStartRequest {
 }
 
 /// additional data that might be required by hyperswitch
-#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct FeatureMetadata {
     /// Redirection response coming in request as metadata field only for redirection scenarios
     #[schema(value_type = Option<RedirectResponse>)]
     pub redirect_response: Option<RedirectResponse>,
+    /// Whether an off-session payment which is soft declined may be retried later, on the
+    /// schedule configured for the decline. Smart retries are enabled unless set to false.
+    pub smart_retry_enabled: Option<bool>,
+    /// Details of the declined payment retried by this payment, present only on payments
+    /// created by smart retries
+    pub smart_retry_details: Option<SmartRetryDetails>,
+}
+
+/// Details of a payment created to retry a soft declined off-session payment
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct SmartRetryDetails {
+    /// The payment which was declined in the first place
+    pub original_payment_id: String,
+    /// The declined payment retried by this payment
+    pub retried_payment_id: String,
+    /// The number of this retry, starting from 1 for the first retry of the original payment
+    pub retry_count: u16,
 }
 
 ///frm message is an object sent inside the payments response...when frm is invoked, its value is Some(...), else its None
@@ -742,3 +742,133 @@ pub struct PayoutLinkStatusDetails {
     #[serde(flatten)]
     pub ui_config: link_utils::GenericLinkUiConfigFormData,
 }
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutBatchCreateRequest {
+    /// The identifier of the file containing the payouts of the batch, uploaded through the
+    /// `/files` API with the purpose `payout_batch`.
+    ///
+    /// The file can either be a JSON array of payout create requests, or a CSV file with the
+    /// columns `payout_id`, `amount`, `currency`, `customer_id`, `payout_type`, `payout_token`,
+    /// `connector`, `description`, `priority`, `auto_fulfill`, `entity_type`, `profile_id`,
+    /// `business_country` and `business_label`, of which only `amount` and `currency` are
+    /// required.
+    #[schema(example = "file_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub file_id: String,
+
+    /// The maximum number of payouts of the batch to be created concurrently
+    #[schema(default = 10, maximum = 50, example = 20)]
+    pub concurrency: Option<u16>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct PayoutBatchResponse {
+    /// The identifier for the payout batch
+    #[schema(example = "pbatch_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub batch_id: String,
+
+    /// The identifier for the merchant account
+    #[schema(example = "merchant_1668273825")]
+    pub merchant_id: String,
+
+    /// The identifier of the file the batch was created from
+    pub file_id: String,
+
+    /// The status of the batch, aggregated from the status of its rows
+    #[schema(value_type = PayoutBatchStatus, example = "processing")]
+    pub status: api_enums::PayoutBatchStatus,
+
+    /// The maximum number of payouts of the batch created concurrently
+    pub concurrency: u16,
+
+    /// The number of rows in the batch
+    pub total_count: u32,
+
+    /// The number of rows for which a payout was created
+    pub succeeded_count: u32,
+
+    /// The number of rows which failed validation, or for which the payout could not be created
+    pub failed_count: u32,
+
+    /// The number of rows cancelled before a payout was created for them
+    pub cancelled_count: u32,
+
+    /// The number of rows yet to be processed
+    pub pending_count: u32,
+
+    /// The identifier of the CSV file with the outcome of every row of the batch, available once
+    /// the batch has been processed. The file can be downloaded through the `/files` API.
+    pub result_file_id: Option<String>,
+
+    /// The time at which the batch was created
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// The time at which the batch was last updated
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct PayoutBatchRetrieveRequest {
+    /// The identifier for the payout batch
+    pub batch_id: String,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutBatchItemListConstraints {
+    /// Only list the rows with this status
+    #[schema(value_type = Option<PayoutBatchItemStatus>)]
+    pub status: Option<api_enums::PayoutBatchItemStatus>,
+
+    /// The maximum number of rows to return
+    #[schema(default = 10, maximum = 100)]
+    #[serde(default = "default_payouts_list_limit")]
+    pub limit: u32,
+
+    /// The number of rows to skip
+    pub offset: Option<u32>,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct PayoutBatchItemListRequest {
+    pub batch_id: String,
+    pub constraints: PayoutBatchItemListConstraints,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct PayoutBatchItemResponse {
+    /// The position of the row in the batch file, starting from 1
+    pub row_number: u32,
+
+    /// The identifier of the payout created for the row
+    #[schema(example = "payout_mbabizu24mvu3mela5njyhpit4")]
+    pub payout_id: String,
+
+    /// The status of the row
+    #[schema(value_type = PayoutBatchItemStatus, example = "succeeded")]
+    pub status: api_enums::PayoutBatchItemStatus,
+
+    /// The status of the payout created for the row
+    #[schema(value_type = Option<PayoutStatus>, example = "success")]
+    pub payout_status: Option<api_enums::PayoutStatus>,
+
+    /// The error code, if the row failed
+    pub error_code: Option<String>,
+
+    /// The error message, if the row failed
+    pub error_message: Option<String>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct PayoutBatchItemListResponse {
+    /// The number of rows included in the list
+    pub size: usize,
+
+    /// The rows of the batch
+    pub data: Vec<PayoutBatchItemResponse>,
+}
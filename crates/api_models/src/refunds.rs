@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use common_utils::pii;
-pub use common_utils::types::{ChargeRefunds, MinorUnit};
+pub use common_utils::types::{ChargeRefunds, MinorUnit, SplitRefunds};
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 use utoipa::ToSchema;
@@ -58,6 +58,10 @@ pub struct RefundRequest {
     /// Charge specific fields for controlling the revert of funds from either platform or connected account
     #[schema(value_type = Option<ChargeRefunds>)]
     pub charges: Option<ChargeRefunds>,
+
+    /// Amounts to be reversed from the recipient accounts of a split payment. If not passed, the refund is reversed from the recipients in proportion to their share of the payment
+    #[schema(value_type = Option<Vec<RefundSplit>>)]
+    pub split_refunds: Option<SplitRefunds>,
 }
 
 #[derive(Default, Debug, Clone, Deserialize)]
@@ -161,6 +165,9 @@ pub struct RefundResponse {
     /// Charge specific fields for controlling the revert of funds from either platform or connected account
     #[schema(value_type = Option<ChargeRefunds>)]
     pub charges: Option<ChargeRefunds>,
+    /// Amounts reversed from the recipient accounts of a split payment
+    #[schema(value_type = Option<Vec<RefundSplit>>)]
+    pub split_refunds: Option<SplitRefunds>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
@@ -101,6 +101,7 @@ impl EuclidDirFilter for ConnectorSelection {
         DirKeyKind::CryptoType,
         DirKeyKind::MetaData,
         DirKeyKind::PaymentAmount,
+        DirKeyKind::InstallmentCount,
         DirKeyKind::PaymentCurrency,
         DirKeyKind::AuthenticationType,
         DirKeyKind::MandateAcceptanceType,
@@ -0,0 +1,407 @@
+use common_utils::{
+    events::{ApiEventMetric, ApiEventsType},
+    id_type, pii,
+    types::MinorUnit,
+};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionPlanCreateRequest {
+    /// The name of the plan
+    #[schema(max_length = 255, example = "Premium monthly")]
+    pub name: String,
+
+    /// A description of the plan
+    #[schema(example = "Access to all the premium features")]
+    pub description: Option<String>,
+
+    /// The amount charged for every billing interval, in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 1999)]
+    pub amount: MinorUnit,
+
+    /// The currency in which the plan is billed
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// The unit of the billing interval
+    #[schema(value_type = SubscriptionInterval, example = "month")]
+    pub interval: api_enums::SubscriptionInterval,
+
+    /// The number of units in a billing interval, for example 3 with the `month` interval bills
+    /// every quarter
+    #[schema(default = 1, minimum = 1, example = 1)]
+    pub interval_count: Option<u16>,
+
+    /// The number of days subscriptions to the plan are not charged for after they are created
+    #[schema(example = 14)]
+    pub trial_period_days: Option<u16>,
+
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type = Option<Object>, example = r#"{ "udf1": "some-value", "udf2": "some-value" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionPlanUpdateRequest {
+    /// The name of the plan
+    #[schema(max_length = 255, example = "Premium monthly")]
+    pub name: Option<String>,
+
+    /// A description of the plan
+    pub description: Option<String>,
+
+    /// Whether new subscriptions can be created for the plan. Existing subscriptions to an
+    /// inactive plan continue to be billed.
+    pub active: Option<bool>,
+
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type = Option<Object>, example = r#"{ "udf1": "some-value", "udf2": "some-value" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct SubscriptionPlanResponse {
+    /// The identifier for the plan
+    #[schema(example = "plan_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub plan_id: String,
+
+    /// The identifier for the merchant account
+    #[schema(example = "merchant_1668273825")]
+    pub merchant_id: String,
+
+    /// The name of the plan
+    pub name: String,
+
+    /// A description of the plan
+    pub description: Option<String>,
+
+    /// The amount charged for every billing interval, in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 1999)]
+    pub amount: MinorUnit,
+
+    /// The currency in which the plan is billed
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// The unit of the billing interval
+    #[schema(value_type = SubscriptionInterval, example = "month")]
+    pub interval: api_enums::SubscriptionInterval,
+
+    /// The number of units in a billing interval
+    pub interval_count: u16,
+
+    /// The number of days subscriptions to the plan are not charged for after they are created
+    pub trial_period_days: Option<u16>,
+
+    /// Whether new subscriptions can be created for the plan
+    pub active: bool,
+
+    /// Metadata of the plan
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+
+    /// The time at which the plan was created
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionPlanListConstraints {
+    /// Only list the plans which are active, or the ones which are not
+    pub active: Option<bool>,
+
+    /// The maximum number of plans to return
+    #[schema(default = 10, maximum = 100)]
+    pub limit: Option<u32>,
+
+    /// The number of plans to skip
+    pub offset: Option<u32>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionCreateRequest {
+    /// The identifier for the customer subscribing to the plan
+    #[schema(value_type = String, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+
+    /// The identifier for the plan
+    #[schema(example = "plan_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub plan_id: String,
+
+    /// The identifier for the active mandate of the customer, with which the invoices of the
+    /// subscription are charged off-session
+    #[schema(example = "man_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub mandate_id: String,
+
+    /// The business profile which the payments of the subscription are created for, and whose
+    /// webhook settings are used for the subscription webhooks. Defaults to the default business
+    /// profile of the merchant.
+    pub profile_id: Option<String>,
+
+    /// The number of days the subscription is not charged for after it is created, overriding the
+    /// trial period of the plan. Pass 0 to charge the first invoice right away.
+    #[schema(example = 14)]
+    pub trial_period_days: Option<u16>,
+
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type = Option<Object>, example = r#"{ "udf1": "some-value", "udf2": "some-value" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct SubscriptionResponse {
+    /// The identifier for the subscription
+    #[schema(example = "sub_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub subscription_id: String,
+
+    /// The identifier for the merchant account
+    #[schema(example = "merchant_1668273825")]
+    pub merchant_id: String,
+
+    /// The identifier for the customer
+    #[schema(value_type = String, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+
+    /// The identifier for the plan
+    #[schema(example = "plan_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub plan_id: String,
+
+    /// The identifier for the mandate the invoices of the subscription are charged with
+    #[schema(example = "man_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub mandate_id: String,
+
+    /// The identifier for the business profile of the subscription
+    pub profile_id: String,
+
+    /// The status of the subscription
+    #[schema(value_type = SubscriptionStatus, example = "active")]
+    pub status: api_enums::SubscriptionStatus,
+
+    /// The start of the current billing period, or of the trial period
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub current_period_start: Option<PrimitiveDateTime>,
+
+    /// The end of the current billing period, or of the trial period
+    #[schema(example = "2022-10-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub current_period_end: Option<PrimitiveDateTime>,
+
+    /// The end of the trial period, if the subscription had one
+    #[schema(example = "2022-09-24T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub trial_end: Option<PrimitiveDateTime>,
+
+    /// The time at which the next invoice of the subscription is charged
+    #[schema(example = "2022-10-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub next_billing_at: Option<PrimitiveDateTime>,
+
+    /// Whether the subscription is cancelled at the end of the current period, instead of being
+    /// renewed
+    pub cancel_at_period_end: bool,
+
+    /// The time at which the subscription was cancelled
+    #[schema(example = "2022-10-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub cancelled_at: Option<PrimitiveDateTime>,
+
+    /// The reason for which the subscription was cancelled
+    pub cancellation_reason: Option<String>,
+
+    /// Metadata of the subscription
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+
+    /// The time at which the subscription was created
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct SubscriptionId {
+    /// The identifier for the subscription
+    pub subscription_id: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionListConstraints {
+    /// Only list the subscriptions of this customer
+    #[schema(value_type = Option<String>)]
+    pub customer_id: Option<id_type::CustomerId>,
+
+    /// Only list the subscriptions to this plan
+    pub plan_id: Option<String>,
+
+    /// Only list the subscriptions with this status
+    #[schema(value_type = Option<SubscriptionStatus>)]
+    pub status: Option<api_enums::SubscriptionStatus>,
+
+    /// The maximum number of subscriptions to return
+    #[schema(default = 10, maximum = 100)]
+    pub limit: Option<u32>,
+
+    /// The number of subscriptions to skip
+    pub offset: Option<u32>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionCancelRequest {
+    /// Cancel the subscription at the end of the current billing or trial period instead of right
+    /// away. Subscriptions whose payment is due are always cancelled right away.
+    #[serde(default)]
+    pub cancel_at_period_end: bool,
+
+    /// The reason for which the subscription is cancelled
+    #[schema(max_length = 255, example = "requested_by_customer")]
+    pub cancellation_reason: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct SubscriptionCancelRequestWithId {
+    pub subscription_id: String,
+    pub request: SubscriptionCancelRequest,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionInvoiceListConstraints {
+    /// The maximum number of invoices to return
+    #[schema(default = 10, maximum = 100)]
+    pub limit: Option<u32>,
+
+    /// The number of invoices to skip
+    pub offset: Option<u32>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct SubscriptionInvoiceListRequest {
+    pub subscription_id: String,
+    pub constraints: SubscriptionInvoiceListConstraints,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct SubscriptionInvoiceResponse {
+    /// The identifier for the invoice
+    #[schema(example = "inv_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub invoice_id: String,
+
+    /// The identifier for the subscription
+    #[schema(example = "sub_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub subscription_id: String,
+
+    /// The amount of the invoice, in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 1999)]
+    pub amount: MinorUnit,
+
+    /// The currency of the invoice
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// The status of the invoice
+    #[schema(value_type = SubscriptionInvoiceStatus, example = "paid")]
+    pub status: api_enums::SubscriptionInvoiceStatus,
+
+    /// The start of the billing period the invoice is for
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub period_start: PrimitiveDateTime,
+
+    /// The end of the billing period the invoice is for
+    #[schema(example = "2022-10-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub period_end: PrimitiveDateTime,
+
+    /// The identifier of the latest payment created for the invoice
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: Option<String>,
+
+    /// The number of payments created for the invoice
+    pub attempt_count: u32,
+
+    /// The time at which the payment for the invoice is attempted again, after a failed attempt
+    #[schema(example = "2022-10-11T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub next_attempt_at: Option<PrimitiveDateTime>,
+
+    /// The error code of the latest failed payment attempt
+    pub error_code: Option<String>,
+
+    /// The error message of the latest failed payment attempt
+    pub error_message: Option<String>,
+
+    /// The time at which the invoice was created
+    #[schema(example = "2022-10-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+impl ApiEventMetric for SubscriptionCreateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.customer_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for SubscriptionResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Subscription {
+            subscription_id: self.subscription_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for SubscriptionId {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Subscription {
+            subscription_id: self.subscription_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for SubscriptionCancelRequestWithId {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Subscription {
+            subscription_id: self.subscription_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for SubscriptionInvoiceListRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Subscription {
+            subscription_id: self.subscription_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for SubscriptionListConstraints {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+impl ApiEventMetric for SubscriptionPlanListConstraints {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+common_utils::impl_misc_api_event_type!(
+    SubscriptionPlanCreateRequest,
+    SubscriptionPlanUpdateRequest,
+    SubscriptionPlanResponse
+);
@@ -0,0 +1,109 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums::TenantStatus;
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TenantCreateRequest {
+    /// The identifier of the tenant, sent in the `x-tenant-id` header of its requests
+    #[schema(max_length = 64, example = "acme")]
+    pub tenant_id: String,
+
+    /// The name of the tenant
+    #[schema(max_length = 255, example = "Acme")]
+    pub name: String,
+
+    /// The base URL of the APIs of the tenant
+    #[schema(max_length = 255, example = "https://acme.example.com")]
+    pub base_url: String,
+
+    /// The Postgres schema holding the data of the tenant. Defaults to the tenant ID.
+    /// The schema is created and migrated when the tenant is provisioned.
+    #[schema(max_length = 63, example = "acme")]
+    pub schema_name: Option<String>,
+
+    /// The prefix of the Redis keys of the tenant. Defaults to the tenant ID.
+    #[schema(max_length = 64, example = "acme")]
+    pub redis_key_prefix: Option<String>,
+
+    /// The ClickHouse database holding the analytics of the tenant
+    #[schema(max_length = 64, example = "acme")]
+    pub clickhouse_database: String,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct TenantResponse {
+    /// The identifier of the tenant
+    #[schema(example = "acme")]
+    pub tenant_id: String,
+
+    /// The name of the tenant
+    #[schema(example = "Acme")]
+    pub name: String,
+
+    /// The base URL of the APIs of the tenant
+    #[schema(example = "https://acme.example.com")]
+    pub base_url: String,
+
+    /// The Postgres schema holding the data of the tenant
+    #[schema(example = "acme")]
+    pub schema_name: String,
+
+    /// The prefix of the Redis keys of the tenant
+    #[schema(example = "acme")]
+    pub redis_key_prefix: String,
+
+    /// The ClickHouse database holding the analytics of the tenant
+    #[schema(example = "acme")]
+    pub clickhouse_database: String,
+
+    /// Whether requests for the tenant are served
+    pub status: TenantStatus,
+
+    /// Time at which the tenant was provisioned
+    #[schema(example = "2024-07-04T10:15:30.000Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// Time at which the tenant was last updated
+    #[schema(example = "2024-07-04T10:15:30.000Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct TenantDeleteResponse {
+    /// The identifier of the tenant
+    #[schema(example = "acme")]
+    pub tenant_id: String,
+
+    /// Whether the tenant was deleted. The schema of the tenant and its data are retained.
+    pub deleted: bool,
+}
+
+impl ApiEventMetric for TenantCreateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Tenant {
+            tenant_id: self.tenant_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for TenantResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Tenant {
+            tenant_id: self.tenant_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for TenantDeleteResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Tenant {
+            tenant_id: self.tenant_id.clone(),
+        })
+    }
+}
@@ -289,6 +289,10 @@ pub enum AuthConfig {
         private_config: OpenIdConnectPrivateConfig,
         public_config: OpenIdConnectPublicConfig,
     },
+    Saml {
+        private_config: SamlPrivateConfig,
+        public_config: SamlPublicConfig,
+    },
     MagicLink,
     Password,
 }
@@ -315,6 +319,49 @@ pub enum OpenIdProvider {
     Okta,
 }
 
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct SamlPrivateConfig {
+    /// Metadata XML of the identity provider, containing its entity ID, SSO URL and signing certificates
+    pub idp_metadata: Secret<String>,
+    /// Assertion attribute holding the email of the user, the `NameID` is used if not provided
+    pub email_attribute: Option<String>,
+    /// Assertion attribute holding the name of the user
+    pub name_attribute: Option<String>,
+    /// Assertion attribute whose values are matched against `role_mappings`
+    pub role_attribute: Option<String>,
+    /// Roles to be assigned to just-in-time provisioned users, based on `role_attribute`
+    #[serde(default)]
+    pub role_mappings: Vec<SamlRoleMapping>,
+    /// Role to be assigned to just-in-time provisioned users when none of the mappings match
+    pub default_role: Option<SamlRoleAssignment>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct SamlRoleMapping {
+    pub attribute_value: String,
+    #[serde(flatten)]
+    pub assignment: SamlRoleAssignment,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone, Eq, PartialEq)]
+pub struct SamlRoleAssignment {
+    pub merchant_id: String,
+    pub role_id: String,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct SamlPublicConfig {
+    pub name: String,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct SamlAcsRequest {
+    #[serde(rename = "SAMLResponse")]
+    pub saml_response: Secret<String>,
+    #[serde(rename = "RelayState")]
+    pub relay_state: Option<Secret<String>>,
+}
+
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 pub struct OpenIdConnect {
     pub name: OpenIdProvider,
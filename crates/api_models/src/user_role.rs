@@ -35,6 +35,7 @@ pub enum Permission {
     PayoutWrite,
     PayoutRead,
     WebhookEventWrite,
+    AuditLogRead,
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -0,0 +1,152 @@
+use common_enums::EventType;
+use masking::{Secret, StrongSecret};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// The authentication to be included in webhooks sent to a webhook endpoint.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum WebhookEndpointAuth {
+    /// HTTP basic authentication
+    Basic {
+        #[schema(value_type = String)]
+        username: Secret<String>,
+        #[schema(value_type = String)]
+        password: Secret<String>,
+    },
+    /// A bearer token sent in the `Authorization` header
+    Bearer {
+        #[schema(value_type = String)]
+        token: Secret<String>,
+    },
+    /// A custom header sent along with the webhook
+    CustomHeader {
+        name: String,
+        #[schema(value_type = String)]
+        value: Secret<String>,
+    },
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookEndpointCreateRequest {
+    /// The URL to which webhooks are to be delivered
+    #[schema(value_type = String, example = "https://www.example.com/webhooks")]
+    pub url: url::Url,
+
+    /// A description of the webhook endpoint
+    #[schema(max_length = 255, example = "Payment status notifications")]
+    pub description: Option<String>,
+
+    /// The authentication to be included in webhooks sent to the endpoint
+    pub auth: Option<WebhookEndpointAuth>,
+
+    /// The event types the endpoint is subscribed to
+    #[schema(value_type = Vec<EventType>, example = json!(["payment_succeeded", "refund_succeeded"]))]
+    pub enabled_events: Vec<EventType>,
+
+    /// Whether webhooks are to be delivered to the endpoint
+    #[schema(default = true, example = true)]
+    pub is_enabled: Option<bool>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookEndpointUpdateRequest {
+    /// The URL to which webhooks are to be delivered
+    #[schema(value_type = Option<String>, example = "https://www.example.com/webhooks")]
+    pub url: Option<url::Url>,
+
+    /// A description of the webhook endpoint
+    #[schema(max_length = 255, example = "Payment status notifications")]
+    pub description: Option<String>,
+
+    /// The authentication to be included in webhooks sent to the endpoint
+    pub auth: Option<WebhookEndpointAuth>,
+
+    /// The event types the endpoint is subscribed to
+    #[schema(value_type = Option<Vec<EventType>>, example = json!(["payment_succeeded", "refund_succeeded"]))]
+    pub enabled_events: Option<Vec<EventType>>,
+
+    /// Whether webhooks are to be delivered to the endpoint
+    #[schema(example = true)]
+    pub is_enabled: Option<bool>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookEndpointSecretRotateRequest {
+    /// The duration (in seconds) for which the current signing secret of the endpoint continues to
+    /// be used for signing webhooks, alongside the newly generated secret.
+    #[schema(example = 86400)]
+    pub overlap_seconds: Option<u32>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct WebhookEndpointResponse {
+    /// The identifier for the webhook endpoint
+    #[schema(max_length = 64, example = "whep_SqB0zwDGR5wHppWf0bx7")]
+    pub endpoint_id: String,
+
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+
+    /// The identifier for the Business Profile
+    #[schema(max_length = 64, example = "pro_abcdefghijklmnopqrstuvwxyz")]
+    pub profile_id: String,
+
+    /// The URL to which webhooks are delivered
+    #[schema(example = "https://www.example.com/webhooks")]
+    pub url: String,
+
+    /// A description of the webhook endpoint
+    pub description: Option<String>,
+
+    /// The event types the endpoint is subscribed to
+    #[schema(value_type = Vec<EventType>)]
+    pub enabled_events: Vec<EventType>,
+
+    /// Whether webhooks are delivered to the endpoint
+    pub is_enabled: bool,
+
+    /// Whether authentication is configured for the endpoint
+    pub has_auth: bool,
+
+    /// The secret used for signing webhooks sent to the endpoint. The secret is only displayed
+    /// once on creation or rotation, please ensure you store it securely.
+    #[schema(value_type = Option<String>)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signing_secret: Option<StrongSecret<String>>,
+
+    /// Time until which webhooks sent to the endpoint are also signed using the signing secret
+    /// replaced by the last rotation
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub previous_signing_secret_expires_at: Option<PrimitiveDateTime>,
+
+    /// Time at which the webhook endpoint was created
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// Time at which the webhook endpoint was last modified
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct WebhookEndpointDeleteResponse {
+    /// The identifier for the webhook endpoint
+    #[schema(max_length = 64, example = "whep_SqB0zwDGR5wHppWf0bx7")]
+    pub endpoint_id: String,
+
+    /// The identifier for the Business Profile
+    #[schema(max_length = 64, example = "pro_abcdefghijklmnopqrstuvwxyz")]
+    pub profile_id: String,
+
+    /// Whether the webhook endpoint was deleted
+    pub deleted: bool,
+}
@@ -76,6 +76,11 @@ pub struct EventListItemResponse {
     #[schema(example = "2022-09-10T10:11:12Z")]
     #[serde(with = "common_utils::custom_serde::iso8601")]
     pub created: PrimitiveDateTime,
+
+    /// The identifier for the webhook endpoint the event was delivered to. This will be absent
+    /// for events delivered to the webhook URL configured in the Business Profile.
+    #[schema(max_length = 64, example = "whep_SqB0zwDGR5wHppWf0bx7")]
+    pub webhook_endpoint_id: Option<String>,
 }
 
 /// The response body for retrieving an event.
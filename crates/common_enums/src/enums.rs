@@ -310,6 +310,13 @@ pub enum BlocklistDataKind {
     PaymentMethod,
     CardBin,
     ExtendedCardBin,
+    Email,
+    EmailDomain,
+    IpAddress,
+    IpRange,
+    PhoneNumber,
+    CustomerId,
+    DeviceFingerprint,
 }
 
 /// Default value if not passed is set to 'automatic' which results in Auth and Capture in one single API request. Pass 'manual' or 'manual_multiple' in case you want do a separate Auth and Capture by first authorizing and placing a hold on your customer's funds so that you can use the Payments/Capture endpoint later to capture the authorized amount. Pass 'manual' if you want to only capture the amount later once or 'manual_multiple' if you want to capture the funds multiple times later. Both 'manual' and 'manual_multiple' are only supported by a specific list of processors
@@ -1078,6 +1085,8 @@ pub enum EventClass {
     Mandates,
     #[cfg(feature = "payouts")]
     Payouts,
+    PaymentMethods,
+    Subscriptions,
 }
 
 #[derive(
@@ -1103,6 +1112,8 @@ pub enum EventType {
     PaymentProcessing,
     PaymentCancelled,
     PaymentAuthorized,
+    /// The payment was not confirmed before its session expired
+    PaymentExpired,
     PaymentCaptured,
     ActionRequired,
     RefundSucceeded,
@@ -1114,6 +1125,8 @@ pub enum EventType {
     DisputeChallenged,
     DisputeWon,
     DisputeLost,
+    /// The deadline for submitting evidence for an open dispute is approaching
+    DisputeEvidenceDue,
     MandateActive,
     MandateRevoked,
     PayoutSuccess,
@@ -1123,6 +1136,13 @@ pub enum EventType {
     PayoutCancelled,
     PayoutExpired,
     PayoutReversed,
+    /// The card details of a saved payment method were updated by the card account updater
+    PaymentMethodUpdated,
+    /// An invoice of a subscription was paid, starting a new billing period
+    SubscriptionRenewed,
+    /// The payment for an invoice of a subscription failed, and is being retried
+    SubscriptionPastDue,
+    SubscriptionCancelled,
 }
 
 #[derive(
@@ -1201,6 +1221,8 @@ pub enum IntentStatus {
     RequiresCapture,
     PartiallyCaptured,
     PartiallyCapturedAndCapturable,
+    /// The payment was not confirmed before its session expired
+    Expired,
 }
 
 /// Indicates that you intend to make future payments with this Payment’s payment method. Providing this parameter will attach the payment method to the Customer, if present, after the Payment is confirmed and any required actions from the user are complete.
@@ -2580,6 +2602,7 @@ pub enum PermissionGroup {
     MerchantDetailsView,
     MerchantDetailsManage,
     OrganizationManage,
+    AuditLogView,
 }
 
 /// Name of banks supported by Hyperswitch
@@ -2845,6 +2868,7 @@ pub enum TokenPurpose {
 #[serde(rename_all = "snake_case")]
 pub enum UserAuthType {
     OpenIdConnect,
+    Saml,
     MagicLink,
     #[default]
     Password,
@@ -2869,3 +2893,277 @@ pub enum Owner {
     Tenant,
     Internal,
 }
+
+/// The kind of principal that performed an audited action
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum AuditActorType {
+    User,
+    ApiKey,
+    AdminApiKey,
+}
+
+/// The kind of resource modified by an audited action
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum AuditEntityType {
+    MerchantAccount,
+    BusinessProfile,
+    MerchantConnectorAccount,
+    ApiKey,
+    Routing,
+    Config,
+    Gsm,
+    Blocklist,
+    User,
+    UserRole,
+    Role,
+    UserAuthenticationMethod,
+    Tenant,
+    LogFilter,
+    SubscriptionPlan,
+    Subscription,
+    UnifiedTranslation,
+}
+
+/// The status of a payout batch
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum PayoutBatchStatus {
+    /// The batch has been created and is yet to be picked up for processing
+    #[default]
+    Pending,
+    /// The payouts of the batch are being created
+    Processing,
+    /// Payouts were created for all the rows of the batch
+    Completed,
+    /// Payouts were created for some of the rows of the batch, while the others failed
+    PartiallyCompleted,
+    /// None of the rows of the batch could be processed
+    Failed,
+    /// The batch was cancelled before all of its rows were processed
+    Cancelled,
+}
+
+impl PayoutBatchStatus {
+    pub fn is_terminal(self) -> bool {
+        match self {
+            Self::Completed | Self::PartiallyCompleted | Self::Failed | Self::Cancelled => true,
+            Self::Pending | Self::Processing => false,
+        }
+    }
+}
+
+/// The status of a single row of a payout batch
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum PayoutBatchItemStatus {
+    /// The row is yet to be processed
+    #[default]
+    Pending,
+    /// A payout has been created for the row
+    Succeeded,
+    /// The row failed validation, or the payout could not be created
+    Failed,
+    /// The row was cancelled along with its batch, before a payout was created for it
+    Cancelled,
+}
+
+/// The status of the network token provisioned for a saved card
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum NetworkTokenStatus {
+    /// The network token can be used for payments
+    Active,
+    /// The network token has been suspended by the card network or the issuer
+    Suspended,
+    /// The network token has been permanently deactivated
+    Deactivated,
+}
+
+/// The status of a tenant provisioned at runtime
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum TenantStatus {
+    /// The tenant is served by all the services
+    #[default]
+    Active,
+    /// The tenant is retained, but requests for it are rejected until it is resumed
+    Suspended,
+    /// The schema of the tenant is being created and migrated, the tenant is served once done
+    Provisioning,
+    /// The schema of the tenant could not be migrated, provisioning is retried when the tenant is
+    /// resumed
+    ProvisioningFailed,
+}
+
+/// The unit of the billing interval of a subscription plan
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum SubscriptionInterval {
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+/// The status of a subscription
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum SubscriptionStatus {
+    /// The first invoice of the subscription is yet to be paid
+    #[default]
+    Incomplete,
+    /// The subscription is in its trial period, the first invoice is charged when the trial ends
+    Trialing,
+    /// The latest invoice of the subscription has been paid
+    Active,
+    /// The payment for the latest invoice of the subscription failed, and is being retried
+    PastDue,
+    /// The subscription was cancelled, or the payment for an invoice could not be collected
+    Cancelled,
+}
+
+/// The status of an invoice of a subscription
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum SubscriptionInvoiceStatus {
+    /// The invoice is awaiting a payment attempt
+    #[default]
+    Open,
+    /// A payment for the invoice has been created, and its outcome is yet to be known
+    Processing,
+    /// The invoice has been paid
+    Paid,
+    /// All the payment attempts for the invoice failed
+    Uncollectible,
+    /// The subscription was cancelled before the invoice was paid
+    Void,
+}
@@ -93,6 +93,9 @@ pub const DEFAULT_TENANT: &str = "public";
 /// Default tenant to be used when multitenancy is disabled
 pub const TENANT_HEADER: &str = "x-tenant-id";
 
+/// Redis channel on which the tenants provisioned, suspended or deleted at runtime are announced
+pub const TENANT_REGISTRY_CHANNEL: &str = "hyperswitch_tenant_registry";
+
 /// Max Length for MerchantReferenceId
 pub const MAX_ALLOWED_MERCHANT_REFERENCE_ID_LENGTH: u8 = 64;
 
@@ -15,6 +15,12 @@ pub enum ApiEventsType {
     Payout {
         payout_id: String,
     },
+    PayoutBatch {
+        batch_id: String,
+    },
+    Subscription {
+        subscription_id: String,
+    },
     Payment {
         payment_id: String,
     },
@@ -66,6 +72,12 @@ pub enum ApiEventsType {
         poll_id: String,
     },
     Analytics,
+    AuditLog {
+        merchant_id: String,
+    },
+    Tenant {
+        tenant_id: String,
+    },
 }
 
 impl ApiEventMetric for serde_json::Value {}
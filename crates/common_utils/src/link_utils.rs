@@ -182,6 +182,19 @@ pub struct GenericLinkUiConfig {
     pub theme: Option<String>,
 }
 
+/// Object for LinkTemplateConfig
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, Serialize, ToSchema)]
+pub struct LinkTemplateConfig {
+    /// HTML template rendered in place of the default template of the link's form. It is rendered
+    /// with the same variables as the default template
+    #[schema(value_type = Option<String>, example = "<!DOCTYPE html><html lang=\"{{ locale }}\">...</html>")]
+    pub html: Option<String>,
+
+    /// Stylesheet applied on top of the default styles of all pages of the link
+    #[schema(value_type = Option<String>, example = "body { font-family: serif; }")]
+    pub css: Option<String>,
+}
+
 /// Object for GenericLinkUiConfigFormData
 #[derive(Clone, Debug, serde::Deserialize, Serialize, ToSchema)]
 pub struct GenericLinkUiConfigFormData {
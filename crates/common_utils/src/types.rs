@@ -703,3 +703,115 @@ pub struct ChargeRefunds {
 }
 
 crate::impl_to_sql_from_sql_json!(ChargeRefunds);
+
+#[derive(Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+/// Share of a payment to be transferred to a recipient account
+pub struct PaymentSplit {
+    /// Identifier of the recipient's account at the connector
+    pub account_id: String,
+
+    /// Amount to be transferred to the recipient's account, including the platform fees
+    #[schema(value_type = i64, example = 6000)]
+    pub amount: MinorUnit,
+
+    /// Platform fees to be retained from the amount transferred to the recipient
+    #[serde(default)]
+    #[schema(value_type = i64, example = 120)]
+    pub fees: MinorUnit,
+
+    /// Reference for the split, passed on to the connector if supported
+    pub reference: Option<String>,
+}
+
+#[derive(
+    Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq, FromSqlRow, AsExpression, ToSchema,
+)]
+#[diesel(sql_type = Jsonb)]
+#[serde(transparent)]
+/// Split of a payment across recipient accounts.
+/// The amount not assigned to any recipient is retained by the platform.
+pub struct SplitPayments(pub Vec<PaymentSplit>);
+
+crate::impl_to_sql_from_sql_json!(SplitPayments);
+
+impl SplitPayments {
+    /// Sum of the amounts transferred to all the recipients
+    pub fn total_amount(&self) -> MinorUnit {
+        self.0
+            .iter()
+            .fold(MinorUnit::new(0), |total, split| total + split.amount)
+    }
+
+    /// Find the split made to the given account
+    pub fn find_by_account_id(&self, account_id: &str) -> Option<&PaymentSplit> {
+        self.0.iter().find(|split| split.account_id == account_id)
+    }
+}
+
+#[derive(Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+/// Amount of a refund to be reversed from a recipient account
+pub struct RefundSplit {
+    /// Identifier of the recipient's account at the connector
+    pub account_id: String,
+
+    /// Amount to be reversed from the recipient's account
+    #[schema(value_type = i64, example = 3000)]
+    pub amount: MinorUnit,
+}
+
+#[derive(
+    Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq, FromSqlRow, AsExpression, ToSchema,
+)]
+#[diesel(sql_type = Jsonb)]
+#[serde(transparent)]
+/// Split of a refund across the recipient accounts of the payment
+pub struct SplitRefunds(pub Vec<RefundSplit>);
+
+crate::impl_to_sql_from_sql_json!(SplitRefunds);
+
+impl SplitRefunds {
+    /// Sum of the amounts reversed from all the recipients
+    pub fn total_amount(&self) -> MinorUnit {
+        self.0
+            .iter()
+            .fold(MinorUnit::new(0), |total, split| total + split.amount)
+    }
+}
+
+#[derive(
+    Serialize,
+    serde::Deserialize,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    FromSqlRow,
+    AsExpression,
+    ToSchema,
+)]
+#[diesel(sql_type = Jsonb)]
+#[serde(tag = "type", rename_all = "snake_case")]
+/// Schedule on which declined off-session payments are retried later, configured on GSM records
+/// with the `retry_later` decision
+pub enum RetrySchedule {
+    /// Retry after each of the intervals, measured from the previous declined attempt
+    Intervals {
+        /// Intervals between consecutive attempts, in hours
+        #[schema(example = json!([24, 72, 168]))]
+        intervals_in_hours: Vec<u16>,
+    },
+    /// Retry on the given days of the month, such as the days on which salaries are usually paid
+    DaysOfMonth {
+        /// Days of the month on which the payment is retried. The last day of the month is used
+        /// for days which do not exist in a month.
+        #[schema(example = json!([1, 15]))]
+        days: Vec<u8>,
+        /// Maximum number of retries made for a declined payment
+        #[schema(example = 4)]
+        max_retries: u8,
+    },
+}
+
+crate::impl_to_sql_from_sql_json!(RetrySchedule);
@@ -314,6 +314,9 @@ impl ConnectorConfig {
             Connector::Powertranz => Ok(connector_data.powertranz),
             Connector::Rapyd => Ok(connector_data.rapyd),
             Connector::Riskified => Ok(connector_data.riskified),
+            Connector::RulesEngine => {
+                Err("rules_engine is configured through the connector metadata".to_string())
+            }
             Connector::Shift4 => Ok(connector_data.shift4),
             Connector::Signifyd => Ok(connector_data.signifyd),
             Connector::Square => Ok(connector_data.square),
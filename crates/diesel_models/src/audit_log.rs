@@ -0,0 +1,57 @@
+use diesel::{Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{enums, schema::audit_log};
+
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = audit_log)]
+pub struct AuditLog {
+    pub id: String,
+    pub tenant_id: String,
+    pub org_id: Option<String>,
+    pub merchant_id: Option<String>,
+    pub actor_type: enums::AuditActorType,
+    pub actor_id: Option<String>,
+    pub action: String,
+    pub entity_type: enums::AuditEntityType,
+    pub entity_id: Option<String>,
+    /// The state of the entity before the change
+    pub previous_state: Option<serde_json::Value>,
+    pub after_state: Option<serde_json::Value>,
+    pub ip_address: Option<String>,
+    pub request_id: Option<String>,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(router_derive::Setter, Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = audit_log)]
+pub struct AuditLogNew {
+    pub id: String,
+    pub tenant_id: String,
+    pub org_id: Option<String>,
+    pub merchant_id: Option<String>,
+    pub actor_type: enums::AuditActorType,
+    pub actor_id: Option<String>,
+    pub action: String,
+    pub entity_type: enums::AuditEntityType,
+    pub entity_id: Option<String>,
+    /// The state of the entity before the change
+    pub previous_state: Option<serde_json::Value>,
+    pub after_state: Option<serde_json::Value>,
+    pub ip_address: Option<String>,
+    pub request_id: Option<String>,
+    pub created_at: PrimitiveDateTime,
+}
+
+/// The constraints to apply when filtering audit log entries of a merchant.
+#[derive(Clone, Debug, Default)]
+pub struct AuditLogListConstraints {
+    pub entity_type: Option<enums::AuditEntityType>,
+    pub entity_id: Option<String>,
+    pub actor_id: Option<String>,
+    pub action: Option<String>,
+    pub created_after: Option<PrimitiveDateTime>,
+    pub created_before: Option<PrimitiveDateTime>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
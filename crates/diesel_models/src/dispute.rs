@@ -1,7 +1,7 @@
 use common_utils::custom_serde;
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use masking::Secret;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
 use crate::{enums as storage_enums, schema::dispute};
@@ -135,3 +135,12 @@ impl From<DisputeUpdate> for DisputeUpdateInternal {
         }
     }
 }
+
+// Tracking data by process_tracker
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct DisputeDeadlineReminderTrackingData {
+    pub dispute_id: String,
+    pub merchant_id: String,
+    // Hours prior to `challenge_required_by` at which reminders have to be sent, in descending order.
+    pub reminder_hours: Vec<u16>,
+}
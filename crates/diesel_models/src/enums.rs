@@ -69,6 +69,8 @@ pub enum EventObjectType {
     DisputeDetails,
     MandateDetails,
     PayoutDetails,
+    PaymentMethodDetails,
+    SubscriptionDetails,
 }
 
 #[derive(
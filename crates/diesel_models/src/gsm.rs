@@ -3,6 +3,7 @@
 use common_utils::{
     custom_serde,
     events::{ApiEventMetric, ApiEventsType},
+    types::RetrySchedule,
 };
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use time::PrimitiveDateTime;
@@ -38,6 +39,7 @@ pub struct GatewayStatusMap {
     pub step_up_possible: bool,
     pub unified_code: Option<String>,
     pub unified_message: Option<String>,
+    pub retry_schedule: Option<RetrySchedule>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Insertable)]
@@ -54,6 +56,7 @@ pub struct GatewayStatusMappingNew {
     pub step_up_possible: bool,
     pub unified_code: Option<String>,
     pub unified_message: Option<String>,
+    pub retry_schedule: Option<RetrySchedule>,
 }
 
 #[derive(
@@ -79,6 +82,7 @@ pub struct GatewayStatusMapperUpdateInternal {
     pub step_up_possible: Option<bool>,
     pub unified_code: Option<String>,
     pub unified_message: Option<String>,
+    pub retry_schedule: Option<RetrySchedule>,
 }
 
 #[derive(Debug)]
@@ -89,6 +93,7 @@ pub struct GatewayStatusMappingUpdate {
     pub step_up_possible: Option<bool>,
     pub unified_code: Option<String>,
     pub unified_message: Option<String>,
+    pub retry_schedule: Option<RetrySchedule>,
 }
 
 impl From<GatewayStatusMappingUpdate> for GatewayStatusMapperUpdateInternal {
@@ -100,6 +105,7 @@ impl From<GatewayStatusMappingUpdate> for GatewayStatusMapperUpdateInternal {
             step_up_possible,
             unified_code,
             unified_message,
+            retry_schedule,
         } = value;
         Self {
             status,
@@ -108,11 +114,22 @@ impl From<GatewayStatusMappingUpdate> for GatewayStatusMapperUpdateInternal {
             step_up_possible,
             unified_code,
             unified_message,
+            retry_schedule,
             ..Default::default()
         }
     }
 }
 
+/// The constraints to apply when listing gateway status mappings.
+#[derive(Clone, Debug, Default)]
+pub struct GatewayStatusMapListConstraints {
+    pub connector: Option<String>,
+    pub flow: Option<String>,
+    pub decision: Option<String>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
 impl ApiEventMetric for GatewayStatusMap {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::Gsm)
@@ -72,6 +72,7 @@ pub struct PaymentAttempt {
     pub charge_id: Option<String>,
     pub client_source: Option<String>,
     pub client_version: Option<String>,
+    pub installments: Option<serde_json::Value>,
 }
 
 impl PaymentAttempt {
@@ -155,6 +156,7 @@ pub struct PaymentAttemptNew {
     pub charge_id: Option<String>,
     pub client_source: Option<String>,
     pub client_version: Option<String>,
+    pub installments: Option<serde_json::Value>,
 }
 
 impl PaymentAttemptNew {
@@ -195,6 +197,7 @@ pub enum PaymentAttemptUpdate {
         fingerprint_id: Option<String>,
         payment_method_billing_address_id: Option<String>,
         updated_by: String,
+        installments: Option<serde_json::Value>,
     },
     UpdateTrackers {
         payment_token: Option<String>,
@@ -240,6 +243,7 @@ pub enum PaymentAttemptUpdate {
         payment_method_billing_address_id: Option<String>,
         client_source: Option<String>,
         client_version: Option<String>,
+        installments: Option<serde_json::Value>,
     },
     VoidUpdate {
         status: storage_enums::AttemptStatus,
@@ -410,6 +414,7 @@ pub struct PaymentAttemptUpdateInternal {
     charge_id: Option<String>,
     client_source: Option<String>,
     client_version: Option<String>,
+    installments: Option<serde_json::Value>,
 }
 
 impl PaymentAttemptUpdateInternal {
@@ -478,6 +483,7 @@ impl PaymentAttemptUpdate {
             charge_id,
             client_source,
             client_version,
+            installments,
         } = PaymentAttemptUpdateInternal::from(self).populate_derived_fields(&source);
         PaymentAttempt {
             amount: amount.unwrap_or(source.amount),
@@ -529,6 +535,7 @@ impl PaymentAttemptUpdate {
             charge_id: charge_id.or(source.charge_id),
             client_source: client_source.or(source.client_source),
             client_version: client_version.or(source.client_version),
+            installments: installments.or(source.installments),
             ..source
         }
     }
@@ -556,6 +563,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 fingerprint_id,
                 updated_by,
                 payment_method_billing_address_id,
+                installments,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -576,6 +584,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 fingerprint_id,
                 payment_method_billing_address_id,
                 updated_by,
+                installments,
                 ..Default::default()
             },
             PaymentAttemptUpdate::AuthenticationTypeUpdate {
@@ -617,6 +626,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 payment_method_id,
                 client_source,
                 client_version,
+                installments,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -648,6 +658,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 capture_method,
                 client_source,
                 client_version,
+                installments,
                 ..Default::default()
             },
             PaymentAttemptUpdate::VoidUpdate {
@@ -915,6 +926,34 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
     }
 }
 
+// Tracking data by process_tracker
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ScheduledCaptureTrackingData {
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub merchant_id: String,
+}
+
+// Tracking data by process_tracker
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct SmartRetryTrackingData {
+    /// The declined payment which is to be retried
+    pub payment_id: String,
+    pub merchant_id: String,
+    /// The payment which was declined in the first place
+    pub original_payment_id: String,
+    /// The number of the retry to be made, starting from 1
+    pub retry_count: u16,
+}
+
+// Tracking data by process_tracker
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct AutoVoidTrackingData {
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub merchant_id: String,
+}
+
 mod tests {
 
     #[test]
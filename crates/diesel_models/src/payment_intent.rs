@@ -1,5 +1,8 @@
 use common_enums::RequestIncrementalAuthorization;
-use common_utils::{id_type, pii, types::MinorUnit};
+use common_utils::{
+    id_type, pii,
+    types::{MinorUnit, SplitPayments},
+};
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
@@ -60,6 +63,7 @@ pub struct PaymentIntent {
     pub charges: Option<pii::SecretSerdeValue>,
     pub frm_metadata: Option<pii::SecretSerdeValue>,
     pub customer_details: Option<Encryption>,
+    pub split_payments: Option<SplitPayments>,
 }
 
 #[derive(
@@ -116,6 +120,7 @@ pub struct PaymentIntentNew {
     pub charges: Option<pii::SecretSerdeValue>,
     pub frm_metadata: Option<pii::SecretSerdeValue>,
     pub customer_details: Option<Encryption>,
+    pub split_payments: Option<SplitPayments>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -174,6 +179,7 @@ pub enum PaymentIntentUpdate {
         fingerprint_id: Option<String>,
         request_external_three_ds_authentication: Option<bool>,
         frm_metadata: Option<pii::SecretSerdeValue>,
+        split_payments: Option<SplitPayments>,
         customer_details: Option<Encryption>,
     },
     PaymentAttemptAndAttemptCountUpdate {
@@ -250,6 +256,7 @@ pub struct PaymentIntentUpdateInternal {
     pub fingerprint_id: Option<String>,
     pub request_external_three_ds_authentication: Option<bool>,
     pub frm_metadata: Option<pii::SecretSerdeValue>,
+    pub split_payments: Option<SplitPayments>,
     pub customer_details: Option<Encryption>,
 }
 
@@ -286,6 +293,7 @@ impl PaymentIntentUpdate {
             fingerprint_id,
             request_external_three_ds_authentication,
             frm_metadata,
+            split_payments,
             customer_details,
         } = self.into();
         PaymentIntent {
@@ -324,6 +332,7 @@ impl PaymentIntentUpdate {
             request_external_three_ds_authentication: request_external_three_ds_authentication
                 .or(source.request_external_three_ds_authentication),
             frm_metadata: frm_metadata.or(source.frm_metadata),
+            split_payments: split_payments.or(source.split_payments),
             customer_details: customer_details.or(source.customer_details),
             ..source
         }
@@ -355,6 +364,7 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 fingerprint_id,
                 request_external_three_ds_authentication,
                 frm_metadata,
+                split_payments,
                 customer_details,
             } => Self {
                 amount: Some(amount),
@@ -379,6 +389,7 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 fingerprint_id,
                 request_external_three_ds_authentication,
                 frm_metadata,
+                split_payments,
                 customer_details,
                 ..Default::default()
             },
@@ -532,6 +543,13 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
     }
 }
 
+// Tracking data by process_tracker
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PaymentIntentExpiryTrackingData {
+    pub payment_id: String,
+    pub merchant_id: String,
+}
+
 mod tests {
     #[test]
     fn test_backwards_compatibility() {
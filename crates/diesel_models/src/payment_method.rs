@@ -43,6 +43,11 @@ pub struct PaymentMethod {
     pub client_secret: Option<String>,
     pub payment_method_billing_address: Option<Encryption>,
     pub updated_by: Option<String>,
+    pub network_token_reference_id: Option<String>,
+    pub network_token_status: Option<storage_enums::NetworkTokenStatus>,
+    pub network_token_data: Option<Encryption>,
+    pub account_update_requested_at: Option<PrimitiveDateTime>,
+    pub card_expiry_month_start: Option<PrimitiveDateTime>,
 }
 
 #[derive(
@@ -80,6 +85,11 @@ pub struct PaymentMethodNew {
     pub client_secret: Option<String>,
     pub payment_method_billing_address: Option<Encryption>,
     pub updated_by: Option<String>,
+    pub network_token_reference_id: Option<String>,
+    pub network_token_status: Option<storage_enums::NetworkTokenStatus>,
+    pub network_token_data: Option<Encryption>,
+    pub account_update_requested_at: Option<PrimitiveDateTime>,
+    pub card_expiry_month_start: Option<PrimitiveDateTime>,
 }
 
 impl PaymentMethodNew {
@@ -94,6 +104,19 @@ pub struct TokenizeCoreWorkflow {
     pub pm: storage_enums::PaymentMethod,
 }
 
+// Tracking data by process_tracker
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct CardAccountUpdaterTrackingData {
+    pub merchant_id: String,
+}
+
+// Tracking data by process_tracker
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct NetworkTokenizationTrackingData {
+    pub merchant_id: String,
+    pub payment_method_id: String,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub enum PaymentMethodUpdate {
     MetadataUpdateAndLastUsed {
@@ -128,6 +151,26 @@ pub enum PaymentMethodUpdate {
     ConnectorMandateDetailsUpdate {
         connector_mandate_details: Option<serde_json::Value>,
     },
+    NetworkTokenDataUpdate {
+        network_token_reference_id: Option<String>,
+        network_token_status: Option<storage_enums::NetworkTokenStatus>,
+        network_token_data: Option<Encryption>,
+    },
+    NetworkTokenStatusUpdate {
+        network_token_status: Option<storage_enums::NetworkTokenStatus>,
+    },
+    AccountUpdateRequestedUpdate {
+        account_update_requested_at: PrimitiveDateTime,
+    },
+    AccountUpdaterUpdate {
+        payment_method_data: Option<Encryption>,
+        status: Option<storage_enums::PaymentMethodStatus>,
+        locker_id: Option<String>,
+        card_expiry_month_start: Option<PrimitiveDateTime>,
+    },
+    CardExpiryUpdate {
+        card_expiry_month_start: PrimitiveDateTime,
+    },
 }
 
 impl PaymentMethodUpdate {
@@ -157,6 +200,11 @@ pub struct PaymentMethodUpdateInternal {
     updated_by: Option<String>,
     payment_method_type: Option<storage_enums::PaymentMethodType>,
     payment_method_issuer: Option<String>,
+    network_token_reference_id: Option<String>,
+    network_token_status: Option<storage_enums::NetworkTokenStatus>,
+    network_token_data: Option<Encryption>,
+    account_update_requested_at: Option<Option<PrimitiveDateTime>>,
+    card_expiry_month_start: Option<PrimitiveDateTime>,
 }
 
 impl PaymentMethodUpdateInternal {
@@ -173,8 +221,14 @@ impl PaymentMethodUpdateInternal {
             last_used_at,
             network_transaction_id,
             status,
+            locker_id,
             connector_mandate_details,
             updated_by,
+            network_token_reference_id,
+            network_token_status,
+            network_token_data,
+            account_update_requested_at,
+            card_expiry_month_start,
             ..
         } = self;
 
@@ -185,9 +239,18 @@ impl PaymentMethodUpdateInternal {
             network_transaction_id: network_transaction_id
                 .map_or(source.network_transaction_id, Some),
             status: status.unwrap_or(source.status),
+            locker_id: locker_id.map_or(source.locker_id, Some),
             connector_mandate_details: connector_mandate_details
                 .map_or(source.connector_mandate_details, Some),
             updated_by: updated_by.map_or(source.updated_by, Some),
+            network_token_reference_id: network_token_reference_id
+                .map_or(source.network_token_reference_id, Some),
+            network_token_status: network_token_status.map_or(source.network_token_status, Some),
+            network_token_data: network_token_data.map_or(source.network_token_data, Some),
+            account_update_requested_at: account_update_requested_at
+                .unwrap_or(source.account_update_requested_at),
+            card_expiry_month_start: card_expiry_month_start
+                .map_or(source.card_expiry_month_start, Some),
             ..source
         }
     }
@@ -211,6 +274,11 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 updated_by: None,
                 payment_method_issuer: None,
                 payment_method_type: None,
+                network_token_reference_id: None,
+                network_token_status: None,
+                network_token_data: None,
+                account_update_requested_at: None,
+                card_expiry_month_start: None,
             },
             PaymentMethodUpdate::PaymentMethodDataUpdate {
                 payment_method_data,
@@ -226,6 +294,11 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 updated_by: None,
                 payment_method_issuer: None,
                 payment_method_type: None,
+                network_token_reference_id: None,
+                network_token_status: None,
+                network_token_data: None,
+                account_update_requested_at: None,
+                card_expiry_month_start: None,
             },
             PaymentMethodUpdate::LastUsedUpdate { last_used_at } => Self {
                 metadata: None,
@@ -239,6 +312,11 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 updated_by: None,
                 payment_method_issuer: None,
                 payment_method_type: None,
+                network_token_reference_id: None,
+                network_token_status: None,
+                network_token_data: None,
+                account_update_requested_at: None,
+                card_expiry_month_start: None,
             },
             PaymentMethodUpdate::UpdatePaymentMethodDataAndLastUsed {
                 payment_method_data,
@@ -255,6 +333,11 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 updated_by: None,
                 payment_method_issuer: None,
                 payment_method_type: None,
+                network_token_reference_id: None,
+                network_token_status: None,
+                network_token_data: None,
+                account_update_requested_at: None,
+                card_expiry_month_start: None,
             },
             PaymentMethodUpdate::NetworkTransactionIdAndStatusUpdate {
                 network_transaction_id,
@@ -271,6 +354,11 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 updated_by: None,
                 payment_method_issuer: None,
                 payment_method_type: None,
+                network_token_reference_id: None,
+                network_token_status: None,
+                network_token_data: None,
+                account_update_requested_at: None,
+                card_expiry_month_start: None,
             },
             PaymentMethodUpdate::StatusUpdate { status } => Self {
                 metadata: None,
@@ -284,6 +372,11 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 updated_by: None,
                 payment_method_issuer: None,
                 payment_method_type: None,
+                network_token_reference_id: None,
+                network_token_status: None,
+                network_token_data: None,
+                account_update_requested_at: None,
+                card_expiry_month_start: None,
             },
             PaymentMethodUpdate::AdditionalDataUpdate {
                 payment_method_data,
@@ -304,6 +397,11 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 updated_by: None,
                 payment_method_issuer,
                 payment_method_type,
+                network_token_reference_id: None,
+                network_token_status: None,
+                network_token_data: None,
+                account_update_requested_at: None,
+                card_expiry_month_start: None,
             },
             PaymentMethodUpdate::ConnectorMandateDetailsUpdate {
                 connector_mandate_details,
@@ -319,6 +417,116 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 updated_by: None,
                 payment_method_issuer: None,
                 payment_method_type: None,
+                network_token_reference_id: None,
+                network_token_status: None,
+                network_token_data: None,
+                account_update_requested_at: None,
+                card_expiry_month_start: None,
+            },
+            PaymentMethodUpdate::NetworkTokenDataUpdate {
+                network_token_reference_id,
+                network_token_status,
+                network_token_data,
+            } => Self {
+                metadata: None,
+                payment_method_data: None,
+                last_used_at: None,
+                network_transaction_id: None,
+                status: None,
+                locker_id: None,
+                payment_method: None,
+                connector_mandate_details: None,
+                updated_by: None,
+                payment_method_issuer: None,
+                payment_method_type: None,
+                network_token_reference_id,
+                network_token_status,
+                network_token_data,
+                account_update_requested_at: None,
+                card_expiry_month_start: None,
+            },
+            PaymentMethodUpdate::NetworkTokenStatusUpdate {
+                network_token_status,
+            } => Self {
+                metadata: None,
+                payment_method_data: None,
+                last_used_at: None,
+                network_transaction_id: None,
+                status: None,
+                locker_id: None,
+                payment_method: None,
+                connector_mandate_details: None,
+                updated_by: None,
+                payment_method_issuer: None,
+                payment_method_type: None,
+                network_token_reference_id: None,
+                network_token_status,
+                network_token_data: None,
+                account_update_requested_at: None,
+                card_expiry_month_start: None,
+            },
+            PaymentMethodUpdate::AccountUpdateRequestedUpdate {
+                account_update_requested_at,
+            } => Self {
+                metadata: None,
+                payment_method_data: None,
+                last_used_at: None,
+                network_transaction_id: None,
+                status: None,
+                locker_id: None,
+                payment_method: None,
+                connector_mandate_details: None,
+                updated_by: None,
+                payment_method_issuer: None,
+                payment_method_type: None,
+                network_token_reference_id: None,
+                network_token_status: None,
+                network_token_data: None,
+                account_update_requested_at: Some(Some(account_update_requested_at)),
+                card_expiry_month_start: None,
+            },
+            PaymentMethodUpdate::AccountUpdaterUpdate {
+                payment_method_data,
+                status,
+                locker_id,
+                card_expiry_month_start,
+            } => Self {
+                metadata: None,
+                payment_method_data,
+                last_used_at: None,
+                network_transaction_id: None,
+                status,
+                locker_id,
+                payment_method: None,
+                connector_mandate_details: None,
+                updated_by: None,
+                payment_method_issuer: None,
+                payment_method_type: None,
+                network_token_reference_id: None,
+                network_token_status: None,
+                network_token_data: None,
+                account_update_requested_at: Some(None),
+                card_expiry_month_start,
+            },
+            PaymentMethodUpdate::CardExpiryUpdate {
+                card_expiry_month_start,
+            } => Self {
+                metadata: None,
+                payment_method_data: None,
+                last_used_at: None,
+                network_transaction_id: None,
+                status: None,
+                locker_id: None,
+                payment_method: None,
+                connector_mandate_details: None,
+                updated_by: None,
+                payment_method_issuer: None,
+                payment_method_type: None,
+                network_token_reference_id: None,
+                network_token_status: None,
+                network_token_data: None,
+                account_update_requested_at: None,
+                card_expiry_month_start: Some(card_expiry_month_start),
             },
         }
     }
@@ -360,6 +568,11 @@ impl From<&PaymentMethodNew> for PaymentMethod {
             payment_method_billing_address: payment_method_new
                 .payment_method_billing_address
                 .clone(),
+            network_token_reference_id: payment_method_new.network_token_reference_id.clone(),
+            network_token_status: payment_method_new.network_token_status,
+            network_token_data: payment_method_new.network_token_data.clone(),
+            account_update_requested_at: payment_method_new.account_update_requested_at,
+            card_expiry_month_start: payment_method_new.card_expiry_month_start,
         }
     }
 }
@@ -0,0 +1,257 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums,
+    schema::{payout_batch, payout_batch_item},
+};
+
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = payout_batch, primary_key(batch_id))]
+pub struct PayoutBatch {
+    pub batch_id: String,
+    pub merchant_id: String,
+    pub file_id: String,
+    pub status: storage_enums::PayoutBatchStatus,
+    pub concurrency: i32,
+    pub total_count: i32,
+    pub succeeded_count: i32,
+    pub failed_count: i32,
+    pub cancelled_count: i32,
+    pub result_file_id: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_batch)]
+pub struct PayoutBatchNew {
+    pub batch_id: String,
+    pub merchant_id: String,
+    pub file_id: String,
+    pub status: storage_enums::PayoutBatchStatus,
+    pub concurrency: i32,
+    pub total_count: i32,
+    pub succeeded_count: i32,
+    pub failed_count: i32,
+    pub cancelled_count: i32,
+    pub result_file_id: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum PayoutBatchUpdate {
+    StatusUpdate {
+        status: storage_enums::PayoutBatchStatus,
+    },
+    CountsUpdate {
+        status: storage_enums::PayoutBatchStatus,
+        succeeded_count: i32,
+        failed_count: i32,
+        cancelled_count: i32,
+    },
+    ResultFileUpdate {
+        result_file_id: String,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_batch)]
+pub struct PayoutBatchUpdateInternal {
+    status: Option<storage_enums::PayoutBatchStatus>,
+    succeeded_count: Option<i32>,
+    failed_count: Option<i32>,
+    cancelled_count: Option<i32>,
+    result_file_id: Option<String>,
+    modified_at: PrimitiveDateTime,
+}
+
+impl From<PayoutBatchUpdate> for PayoutBatchUpdateInternal {
+    fn from(payout_batch_update: PayoutBatchUpdate) -> Self {
+        let modified_at = common_utils::date_time::now();
+        match payout_batch_update {
+            PayoutBatchUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                succeeded_count: None,
+                failed_count: None,
+                cancelled_count: None,
+                result_file_id: None,
+                modified_at,
+            },
+            PayoutBatchUpdate::CountsUpdate {
+                status,
+                succeeded_count,
+                failed_count,
+                cancelled_count,
+            } => Self {
+                status: Some(status),
+                succeeded_count: Some(succeeded_count),
+                failed_count: Some(failed_count),
+                cancelled_count: Some(cancelled_count),
+                result_file_id: None,
+                modified_at,
+            },
+            PayoutBatchUpdate::ResultFileUpdate { result_file_id } => Self {
+                status: None,
+                succeeded_count: None,
+                failed_count: None,
+                cancelled_count: None,
+                result_file_id: Some(result_file_id),
+                modified_at,
+            },
+        }
+    }
+}
+
+impl PayoutBatchUpdateInternal {
+    pub fn apply_changeset(self, source: PayoutBatch) -> PayoutBatch {
+        let Self {
+            status,
+            succeeded_count,
+            failed_count,
+            cancelled_count,
+            result_file_id,
+            modified_at,
+        } = self;
+        PayoutBatch {
+            status: status.unwrap_or(source.status),
+            succeeded_count: succeeded_count.unwrap_or(source.succeeded_count),
+            failed_count: failed_count.unwrap_or(source.failed_count),
+            cancelled_count: cancelled_count.unwrap_or(source.cancelled_count),
+            result_file_id: result_file_id.or(source.result_file_id),
+            modified_at,
+            ..source
+        }
+    }
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = payout_batch_item, primary_key(batch_id, row_number))]
+pub struct PayoutBatchItem {
+    pub batch_id: String,
+    pub row_number: i32,
+    pub merchant_id: String,
+    pub payout_id: String,
+    pub status: storage_enums::PayoutBatchItemStatus,
+    pub payout_status: Option<storage_enums::PayoutStatus>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_batch_item)]
+pub struct PayoutBatchItemNew {
+    pub batch_id: String,
+    pub row_number: i32,
+    pub merchant_id: String,
+    pub payout_id: String,
+    pub status: storage_enums::PayoutBatchItemStatus,
+    pub payout_status: Option<storage_enums::PayoutStatus>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum PayoutBatchItemUpdate {
+    PayoutCreated {
+        payout_status: storage_enums::PayoutStatus,
+    },
+    PayoutStatusUpdate {
+        payout_status: storage_enums::PayoutStatus,
+    },
+    Failed {
+        error_code: String,
+        error_message: String,
+    },
+    Cancelled,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_batch_item)]
+pub struct PayoutBatchItemUpdateInternal {
+    status: Option<storage_enums::PayoutBatchItemStatus>,
+    payout_status: Option<storage_enums::PayoutStatus>,
+    error_code: Option<String>,
+    error_message: Option<String>,
+    modified_at: PrimitiveDateTime,
+}
+
+impl From<PayoutBatchItemUpdate> for PayoutBatchItemUpdateInternal {
+    fn from(payout_batch_item_update: PayoutBatchItemUpdate) -> Self {
+        let modified_at = common_utils::date_time::now();
+        match payout_batch_item_update {
+            PayoutBatchItemUpdate::PayoutCreated { payout_status } => Self {
+                status: Some(storage_enums::PayoutBatchItemStatus::Succeeded),
+                payout_status: Some(payout_status),
+                error_code: None,
+                error_message: None,
+                modified_at,
+            },
+            PayoutBatchItemUpdate::PayoutStatusUpdate { payout_status } => Self {
+                status: None,
+                payout_status: Some(payout_status),
+                error_code: None,
+                error_message: None,
+                modified_at,
+            },
+            PayoutBatchItemUpdate::Failed {
+                error_code,
+                error_message,
+            } => Self {
+                status: Some(storage_enums::PayoutBatchItemStatus::Failed),
+                payout_status: None,
+                error_code: Some(error_code),
+                error_message: Some(error_message),
+                modified_at,
+            },
+            PayoutBatchItemUpdate::Cancelled => Self {
+                status: Some(storage_enums::PayoutBatchItemStatus::Cancelled),
+                payout_status: None,
+                error_code: None,
+                error_message: None,
+                modified_at,
+            },
+        }
+    }
+}
+
+impl PayoutBatchItemUpdateInternal {
+    pub fn apply_changeset(self, source: PayoutBatchItem) -> PayoutBatchItem {
+        let Self {
+            status,
+            payout_status,
+            error_code,
+            error_message,
+            modified_at,
+        } = self;
+        PayoutBatchItem {
+            status: status.unwrap_or(source.status),
+            payout_status: payout_status.or(source.payout_status),
+            error_code: error_code.or(source.error_code),
+            error_message: error_message.or(source.error_message),
+            modified_at,
+            ..source
+        }
+    }
+}
+
+/// The constraints to apply when listing the rows of a payout batch.
+#[derive(Clone, Debug, Default)]
+pub struct PayoutBatchItemListConstraints {
+    pub status: Option<storage_enums::PayoutBatchItemStatus>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+// Tracking data by process_tracker
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PayoutBatchTrackingData {
+    pub batch_id: String,
+    pub merchant_id: String,
+}
@@ -146,6 +146,21 @@ impl Default for ProcessTrackerUpdateInternal {
     }
 }
 
+impl ProcessTrackerUpdateInternal {
+    pub fn apply_changeset(self, source: ProcessTracker) -> ProcessTracker {
+        ProcessTracker {
+            name: self.name.or(source.name),
+            retry_count: self.retry_count.unwrap_or(source.retry_count),
+            schedule_time: self.schedule_time.or(source.schedule_time),
+            tracking_data: self.tracking_data.unwrap_or(source.tracking_data),
+            business_status: self.business_status.unwrap_or(source.business_status),
+            status: self.status.unwrap_or(source.status),
+            updated_at: self.updated_at.unwrap_or(source.updated_at),
+            ..source
+        }
+    }
+}
+
 impl From<ProcessTrackerUpdate> for ProcessTrackerUpdateInternal {
     fn from(process_tracker_update: ProcessTrackerUpdate) -> Self {
         match process_tracker_update {
@@ -209,6 +224,15 @@ pub enum ProcessTrackerRunner {
     OutgoingWebhookRetryWorkflow,
     AttachPayoutAccountWorkflow,
     PaymentMethodStatusUpdateWorkflow,
+    DisputeDeadlineReminderWorkflow,
+    ScheduledCaptureWorkflow,
+    PaymentIntentExpiryWorkflow,
+    AutoVoidWorkflow,
+    PayoutBatchWorkflow,
+    CardAccountUpdaterWorkflow,
+    SubscriptionBillingWorkflow,
+    SmartRetryWorkflow,
+    NetworkTokenizationWorkflow,
 }
 
 #[cfg(test)]
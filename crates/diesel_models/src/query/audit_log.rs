@@ -0,0 +1,80 @@
+use diesel::{associations::HasTable, ExpressionMethods};
+
+use crate::{audit_log::*, query::generics, schema::audit_log::dsl, PgPooledConn, StorageResult};
+
+impl AuditLogNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<AuditLog> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl AuditLog {
+    pub async fn list_by_merchant_id_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        constraints: AuditLogListConstraints,
+    ) -> StorageResult<Vec<Self>> {
+        use async_bb8_diesel::AsyncRunQueryDsl;
+        use diesel::{debug_query, pg::Pg, QueryDsl};
+        use error_stack::ResultExt;
+        use router_env::logger;
+
+        use super::generics::db_metrics::{track_database_call, DatabaseOperation};
+        use crate::errors::DatabaseError;
+
+        let AuditLogListConstraints {
+            entity_type,
+            entity_id,
+            actor_id,
+            action,
+            created_after,
+            created_before,
+            limit,
+            offset,
+        } = constraints;
+
+        let mut query = Self::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .order(dsl::created_at.desc())
+            .into_boxed();
+
+        if let Some(entity_type) = entity_type {
+            query = query.filter(dsl::entity_type.eq(entity_type));
+        }
+
+        if let Some(entity_id) = entity_id {
+            query = query.filter(dsl::entity_id.eq(entity_id));
+        }
+
+        if let Some(actor_id) = actor_id {
+            query = query.filter(dsl::actor_id.eq(actor_id));
+        }
+
+        if let Some(action) = action {
+            query = query.filter(dsl::action.eq(action));
+        }
+
+        if let Some(created_after) = created_after {
+            query = query.filter(dsl::created_at.ge(created_after));
+        }
+
+        if let Some(created_before) = created_before {
+            query = query.filter(dsl::created_at.le(created_before));
+        }
+
+        if let Some(limit) = limit {
+            query = query.limit(limit);
+        }
+
+        if let Some(offset) = offset {
+            query = query.offset(offset);
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(DatabaseError::Others) // Query returns empty Vec when no records are found
+            .attach_printable("Error filtering audit logs by constraints")
+    }
+}
@@ -1,4 +1,4 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table};
 use error_stack::report;
 
 use crate::{
@@ -32,6 +32,19 @@ impl GatewayStatusMap {
         .await
     }
 
+    pub async fn find_by_connectors(
+        conn: &PgPooledConn,
+        connectors: Vec<String>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(conn, dsl::connector.eq_any(connectors), None, None, None)
+        .await
+    }
+
     pub async fn retrieve_decision(
         conn: &PgPooledConn,
         connector: String,
@@ -78,6 +91,63 @@ impl GatewayStatusMap {
         })
     }
 
+    pub async fn list_by_constraints(
+        conn: &PgPooledConn,
+        constraints: GatewayStatusMapListConstraints,
+    ) -> StorageResult<Vec<Self>> {
+        use async_bb8_diesel::AsyncRunQueryDsl;
+        use diesel::{debug_query, pg::Pg, QueryDsl};
+        use error_stack::ResultExt;
+        use router_env::logger;
+
+        use super::generics::db_metrics::{track_database_call, DatabaseOperation};
+
+        let GatewayStatusMapListConstraints {
+            connector,
+            flow,
+            decision,
+            limit,
+            offset,
+        } = constraints;
+
+        let mut query = Self::table()
+            .order((
+                dsl::connector.asc(),
+                dsl::flow.asc(),
+                dsl::sub_flow.asc(),
+                dsl::code.asc(),
+                dsl::message.asc(),
+            ))
+            .into_boxed();
+
+        if let Some(connector) = connector {
+            query = query.filter(dsl::connector.eq(connector));
+        }
+
+        if let Some(flow) = flow {
+            query = query.filter(dsl::flow.eq(flow));
+        }
+
+        if let Some(decision) = decision {
+            query = query.filter(dsl::decision.eq(decision));
+        }
+
+        if let Some(limit) = limit {
+            query = query.limit(limit);
+        }
+
+        if let Some(offset) = offset {
+            query = query.offset(offset);
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(errors::DatabaseError::Others) // Query returns empty Vec when no records are found
+            .attach_printable("Error filtering gsm entries by constraints")
+    }
+
     pub async fn delete(
         conn: &PgPooledConn,
         connector: String,
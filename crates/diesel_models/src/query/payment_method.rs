@@ -5,6 +5,7 @@ use diesel::{
     QueryDsl, Table,
 };
 use error_stack::ResultExt;
+use time::PrimitiveDateTime;
 
 use super::generics;
 use crate::{
@@ -54,6 +55,17 @@ impl PaymentMethod {
         .await
     }
 
+    pub async fn find_by_network_token_reference_id(
+        conn: &PgPooledConn,
+        network_token_reference_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::network_token_reference_id.eq(network_token_reference_id.to_owned()),
+        )
+        .await
+    }
+
     pub async fn find_by_payment_method_id(
         conn: &PgPooledConn,
         payment_method_id: &str,
@@ -149,6 +161,37 @@ impl PaymentMethod {
         .await
     }
 
+    /// Saved cards of a merchant which have to be sent to the card account updater, which are
+    /// the cards flagged after an expired card decline, the cards expiring by the cutoff and the
+    /// cards whose expiry has not been recorded yet
+    pub async fn find_account_update_candidates_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        expiry_cutoff: PrimitiveDateTime,
+        after_id: Option<i32>,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_method.eq(storage_enums::PaymentMethod::Card))
+                .and(dsl::status.eq(storage_enums::PaymentMethodStatus::Active))
+                .and(dsl::locker_id.is_not_null())
+                .and(dsl::id.gt(after_id.unwrap_or(i32::MIN)))
+                .and(
+                    dsl::account_update_requested_at
+                        .is_not_null()
+                        .or(dsl::card_expiry_month_start.is_null())
+                        .or(dsl::card_expiry_month_start.le(expiry_cutoff)),
+                ),
+            Some(limit),
+            None,
+            Some(dsl::id.asc()),
+        )
+        .await
+    }
+
     pub async fn update_with_payment_method_id(
         self,
         conn: &PgPooledConn,
@@ -0,0 +1,189 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    enums, errors,
+    payout_batch::{
+        PayoutBatch, PayoutBatchItem, PayoutBatchItemListConstraints, PayoutBatchItemNew,
+        PayoutBatchItemUpdate, PayoutBatchItemUpdateInternal, PayoutBatchNew, PayoutBatchUpdate,
+        PayoutBatchUpdateInternal,
+    },
+    schema::{payout_batch::dsl, payout_batch_item::dsl as item_dsl},
+    PgPooledConn, StorageResult,
+};
+
+impl PayoutBatchNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PayoutBatch> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl PayoutBatch {
+    pub async fn find_by_merchant_id_batch_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        batch_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::batch_id.eq(batch_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        payout_batch_update: PayoutBatchUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::batch_id.eq(self.batch_id.to_owned()),
+            PayoutBatchUpdateInternal::from(payout_batch_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
+
+impl PayoutBatchItem {
+    pub async fn insert_batch(
+        conn: &PgPooledConn,
+        batch: Vec<PayoutBatchItemNew>,
+    ) -> StorageResult<Vec<Self>> {
+        use async_bb8_diesel::AsyncRunQueryDsl;
+        use diesel::debug_query;
+        use error_stack::ResultExt;
+        use router_env::logger;
+
+        let query = diesel::insert_into(<Self>::table()).values(batch);
+
+        logger::debug!(query = %debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+        query
+            .get_results_async(conn)
+            .await
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error while inserting payout batch items")
+    }
+
+    pub async fn list_by_batch_id_constraints(
+        conn: &PgPooledConn,
+        batch_id: &str,
+        constraints: PayoutBatchItemListConstraints,
+    ) -> StorageResult<Vec<Self>> {
+        let PayoutBatchItemListConstraints {
+            status,
+            limit,
+            offset,
+        } = constraints;
+
+        match status {
+            Some(status) => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    item_dsl::batch_id
+                        .eq(batch_id.to_owned())
+                        .and(item_dsl::status.eq(status)),
+                    limit,
+                    offset,
+                    Some(item_dsl::row_number.asc()),
+                )
+                .await
+            }
+            None => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    item_dsl::batch_id.eq(batch_id.to_owned()),
+                    limit,
+                    offset,
+                    Some(item_dsl::row_number.asc()),
+                )
+                .await
+            }
+        }
+    }
+
+    pub async fn get_status_counts_by_batch_id(
+        conn: &PgPooledConn,
+        batch_id: &str,
+    ) -> StorageResult<Vec<(enums::PayoutBatchItemStatus, i64)>> {
+        use async_bb8_diesel::AsyncRunQueryDsl;
+        use diesel::{debug_query, dsl::count_star, pg::Pg, QueryDsl};
+        use error_stack::ResultExt;
+        use router_env::logger;
+
+        use super::generics::db_metrics::{track_database_call, DatabaseOperation};
+
+        let query = Self::table()
+            .filter(item_dsl::batch_id.eq(batch_id.to_owned()))
+            .group_by(item_dsl::status)
+            .select((item_dsl::status, count_star()));
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Count)
+            .await
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error counting payout batch items by status")
+    }
+
+    /// Update the row only if its status has not changed since it was read, so that a row which
+    /// has been cancelled concurrently is not overwritten
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        payout_batch_item_update: PayoutBatchItemUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            item_dsl::batch_id
+                .eq(self.batch_id.to_owned())
+                .and(item_dsl::row_number.eq(self.row_number))
+                .and(item_dsl::status.eq(self.status)),
+            PayoutBatchItemUpdateInternal::from(payout_batch_item_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+
+    pub async fn update_by_batch_id_status(
+        conn: &PgPooledConn,
+        batch_id: &str,
+        status: enums::PayoutBatchItemStatus,
+        payout_batch_item_update: PayoutBatchItemUpdate,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            item_dsl::batch_id
+                .eq(batch_id.to_owned())
+                .and(item_dsl::status.eq(status)),
+            PayoutBatchItemUpdateInternal::from(payout_batch_item_update),
+        )
+        .await
+    }
+}
@@ -0,0 +1,273 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods, QueryDsl,
+};
+use error_stack::ResultExt;
+use router_env::logger;
+
+use super::generics::{
+    self,
+    db_metrics::{track_database_call, DatabaseOperation},
+};
+use crate::{
+    enums, errors,
+    schema::{
+        subscription::dsl, subscription_invoice::dsl as invoice_dsl,
+        subscription_plan::dsl as plan_dsl,
+    },
+    subscription::{
+        Subscription, SubscriptionInvoice, SubscriptionInvoiceNew, SubscriptionInvoiceUpdate,
+        SubscriptionInvoiceUpdateInternal, SubscriptionListConstraints, SubscriptionNew,
+        SubscriptionPlan, SubscriptionPlanListConstraints, SubscriptionPlanNew,
+        SubscriptionPlanUpdate, SubscriptionPlanUpdateInternal, SubscriptionUpdate,
+        SubscriptionUpdateInternal,
+    },
+    PgPooledConn, StorageResult,
+};
+
+impl SubscriptionPlanNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<SubscriptionPlan> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl SubscriptionPlan {
+    pub async fn find_by_merchant_id_plan_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        plan_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            plan_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(plan_dsl::plan_id.eq(plan_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        constraints: SubscriptionPlanListConstraints,
+    ) -> StorageResult<Vec<Self>> {
+        let SubscriptionPlanListConstraints {
+            active,
+            limit,
+            offset,
+        } = constraints;
+
+        let mut query = Self::table()
+            .filter(plan_dsl::merchant_id.eq(merchant_id.to_owned()))
+            .order(plan_dsl::created_at.desc())
+            .into_boxed();
+
+        if let Some(active) = active {
+            query = query.filter(plan_dsl::active.eq(active));
+        }
+
+        if let Some(limit) = limit {
+            query = query.limit(limit);
+        }
+
+        if let Some(offset) = offset {
+            query = query.offset(offset);
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(errors::DatabaseError::Others) // Query returns empty Vec when no records are found
+            .attach_printable("Error filtering subscription plans by constraints")
+    }
+
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        subscription_plan_update: SubscriptionPlanUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            plan_dsl::plan_id.eq(self.plan_id.to_owned()),
+            SubscriptionPlanUpdateInternal::from(subscription_plan_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
+
+impl SubscriptionNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<Subscription> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl Subscription {
+    pub async fn find_by_merchant_id_subscription_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        subscription_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::subscription_id.eq(subscription_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        constraints: SubscriptionListConstraints,
+    ) -> StorageResult<Vec<Self>> {
+        let SubscriptionListConstraints {
+            customer_id,
+            plan_id,
+            status,
+            limit,
+            offset,
+        } = constraints;
+
+        let mut query = Self::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .order(dsl::created_at.desc())
+            .into_boxed();
+
+        if let Some(customer_id) = customer_id {
+            query = query.filter(dsl::customer_id.eq(customer_id));
+        }
+
+        if let Some(plan_id) = plan_id {
+            query = query.filter(dsl::plan_id.eq(plan_id));
+        }
+
+        if let Some(status) = status {
+            query = query.filter(dsl::status.eq(status));
+        }
+
+        if let Some(limit) = limit {
+            query = query.limit(limit);
+        }
+
+        if let Some(offset) = offset {
+            query = query.offset(offset);
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(errors::DatabaseError::Others) // Query returns empty Vec when no records are found
+            .attach_printable("Error filtering subscriptions by constraints")
+    }
+
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        subscription_update: SubscriptionUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::subscription_id.eq(self.subscription_id.to_owned()),
+            SubscriptionUpdateInternal::from(subscription_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
+
+impl SubscriptionInvoiceNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<SubscriptionInvoice> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl SubscriptionInvoice {
+    pub async fn list_by_subscription_id(
+        conn: &PgPooledConn,
+        subscription_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            invoice_dsl::subscription_id.eq(subscription_id.to_owned()),
+            limit,
+            offset,
+            Some(invoice_dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    /// Find the invoice of a subscription which is either awaiting a payment attempt, or whose
+    /// payment is being processed. A subscription has at most one such invoice at a time.
+    pub async fn find_unpaid_by_subscription_id(
+        conn: &PgPooledConn,
+        subscription_id: &str,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            invoice_dsl::subscription_id
+                .eq(subscription_id.to_owned())
+                .and(invoice_dsl::status.eq_any([
+                    enums::SubscriptionInvoiceStatus::Open,
+                    enums::SubscriptionInvoiceStatus::Processing,
+                ])),
+            Some(1),
+            None,
+            Some(invoice_dsl::created_at.desc()),
+        )
+        .await
+        .map(|mut invoices| invoices.pop())
+    }
+
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        subscription_invoice_update: SubscriptionInvoiceUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            invoice_dsl::invoice_id.eq(self.invoice_id.to_owned()),
+            SubscriptionInvoiceUpdateInternal::from(subscription_invoice_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use diesel::{associations::HasTable, ExpressionMethods};
+
+use crate::{query::generics, schema::tenant::dsl, tenant::*, PgPooledConn, StorageResult};
+
+impl TenantNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<Tenant> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl Tenant {
+    pub async fn find_by_tenant_id(conn: &PgPooledConn, tenant_id: &str) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::tenant_id.eq(tenant_id.to_owned()),
+        )
+        .await
+    }
+
+    pub async fn list(conn: &PgPooledConn) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::tenant_id.ne_all(vec![""]),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_tenant_id(
+        conn: &PgPooledConn,
+        tenant_id: &str,
+        tenant_update: TenantUpdateInternal,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(conn, dsl::tenant_id.eq(tenant_id.to_owned()), tenant_update)
+        .await
+    }
+
+    pub async fn delete_by_tenant_id(conn: &PgPooledConn, tenant_id: &str) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            dsl::tenant_id.eq(tenant_id.to_owned()),
+        )
+        .await
+    }
+}
@@ -0,0 +1,120 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use crate::{
+    query::generics, schema::unified_translations::dsl, unified_translations::*, PgPooledConn,
+    StorageResult,
+};
+
+impl UnifiedTranslationsNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<UnifiedTranslations> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl UnifiedTranslations {
+    pub async fn find_by_unified_code_unified_message_locale(
+        conn: &PgPooledConn,
+        unified_code: String,
+        unified_message: String,
+        locale: String,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::unified_code
+                .eq(unified_code)
+                .and(dsl::unified_message.eq(unified_message))
+                .and(dsl::locale.eq(locale)),
+        )
+        .await
+    }
+
+    pub async fn update_by_unified_code_unified_message_locale(
+        conn: &PgPooledConn,
+        unified_code: String,
+        unified_message: String,
+        locale: String,
+        data: UnifiedTranslationsUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            UnifiedTranslationsUpdateInternal,
+            _,
+            _,
+        >(
+            conn,
+            dsl::unified_code
+                .eq(unified_code)
+                .and(dsl::unified_message.eq(unified_message))
+                .and(dsl::locale.eq(locale)),
+            data.into(),
+        )
+        .await
+    }
+
+    pub async fn delete_by_unified_code_unified_message_locale(
+        conn: &PgPooledConn,
+        unified_code: String,
+        unified_message: String,
+        locale: String,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            dsl::unified_code
+                .eq(unified_code)
+                .and(dsl::unified_message.eq(unified_message))
+                .and(dsl::locale.eq(locale)),
+        )
+        .await
+    }
+
+    pub async fn list_by_constraints(
+        conn: &PgPooledConn,
+        constraints: UnifiedTranslationsListConstraints,
+    ) -> StorageResult<Vec<Self>> {
+        use async_bb8_diesel::AsyncRunQueryDsl;
+        use diesel::{debug_query, pg::Pg, QueryDsl};
+        use error_stack::ResultExt;
+        use router_env::logger;
+
+        use super::generics::db_metrics::{track_database_call, DatabaseOperation};
+        use crate::errors::DatabaseError;
+
+        let UnifiedTranslationsListConstraints {
+            unified_code,
+            locale,
+            limit,
+            offset,
+        } = constraints;
+
+        let mut query = Self::table()
+            .order((
+                dsl::unified_code.asc(),
+                dsl::unified_message.asc(),
+                dsl::locale.asc(),
+            ))
+            .into_boxed();
+
+        if let Some(unified_code) = unified_code {
+            query = query.filter(dsl::unified_code.eq(unified_code));
+        }
+
+        if let Some(locale) = locale {
+            query = query.filter(dsl::locale.eq(locale));
+        }
+
+        if let Some(limit) = limit {
+            query = query.limit(limit);
+        }
+
+        if let Some(offset) = offset {
+            query = query.offset(offset);
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(DatabaseError::Others) // Query returns empty Vec when no records are found
+            .attach_printable("Error filtering unified translations by constraints")
+    }
+}
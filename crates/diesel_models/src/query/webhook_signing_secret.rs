@@ -0,0 +1,49 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use crate::{
+    query::generics, schema::webhook_signing_secret::dsl, webhook_signing_secret::*, PgPooledConn,
+    StorageResult,
+};
+
+impl WebhookSigningSecretNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<WebhookSigningSecret> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl WebhookSigningSecret {
+    pub async fn list_by_profile_id(
+        conn: &PgPooledConn,
+        profile_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::profile_id.eq(profile_id.to_owned()),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_profile_id_secret_id(
+        conn: &PgPooledConn,
+        profile_id: &str,
+        secret_id: &str,
+        webhook_signing_secret_update: WebhookSigningSecretUpdateInternal,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::profile_id
+                .eq(profile_id.to_owned())
+                .and(dsl::secret_id.eq(secret_id.to_owned())),
+            webhook_signing_secret_update,
+        )
+        .await
+    }
+}
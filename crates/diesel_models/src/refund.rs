@@ -1,6 +1,6 @@
 use common_utils::{
     pii,
-    types::{ChargeRefunds, MinorUnit},
+    types::{ChargeRefunds, MinorUnit, SplitRefunds},
 };
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use serde::{Deserialize, Serialize};
@@ -43,6 +43,7 @@ pub struct Refund {
     pub updated_by: String,
     pub merchant_connector_id: Option<String>,
     pub charges: Option<ChargeRefunds>,
+    pub split_refunds: Option<SplitRefunds>,
 }
 
 #[derive(
@@ -86,6 +87,7 @@ pub struct RefundNew {
     pub updated_by: String,
     pub merchant_connector_id: Option<String>,
     pub charges: Option<ChargeRefunds>,
+    pub split_refunds: Option<SplitRefunds>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -299,7 +301,8 @@ mod tests {
     "profile_id": null,
     "updated_by": "admin",
     "merchant_connector_id": null,
-    "charges": null
+    "charges": null,
+    "split_refunds": null
 }"#;
         let deserialized = serde_json::from_str::<super::Refund>(serialized_refund);
 
@@ -0,0 +1,437 @@
+use common_utils::{id_type, pii, types::MinorUnit};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums,
+    schema::{subscription, subscription_invoice, subscription_plan},
+};
+
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = subscription_plan, primary_key(plan_id))]
+pub struct SubscriptionPlan {
+    pub plan_id: String,
+    pub merchant_id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub billing_interval: storage_enums::SubscriptionInterval,
+    pub billing_interval_count: i32,
+    pub trial_period_days: Option<i32>,
+    pub active: bool,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = subscription_plan)]
+pub struct SubscriptionPlanNew {
+    pub plan_id: String,
+    pub merchant_id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub billing_interval: storage_enums::SubscriptionInterval,
+    pub billing_interval_count: i32,
+    pub trial_period_days: Option<i32>,
+    pub active: bool,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum SubscriptionPlanUpdate {
+    Update {
+        name: Option<String>,
+        description: Option<String>,
+        active: Option<bool>,
+        metadata: Option<pii::SecretSerdeValue>,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = subscription_plan)]
+pub struct SubscriptionPlanUpdateInternal {
+    name: Option<String>,
+    description: Option<String>,
+    active: Option<bool>,
+    metadata: Option<pii::SecretSerdeValue>,
+    modified_at: PrimitiveDateTime,
+}
+
+impl From<SubscriptionPlanUpdate> for SubscriptionPlanUpdateInternal {
+    fn from(subscription_plan_update: SubscriptionPlanUpdate) -> Self {
+        match subscription_plan_update {
+            SubscriptionPlanUpdate::Update {
+                name,
+                description,
+                active,
+                metadata,
+            } => Self {
+                name,
+                description,
+                active,
+                metadata,
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
+
+impl SubscriptionPlanUpdateInternal {
+    pub fn apply_changeset(self, source: SubscriptionPlan) -> SubscriptionPlan {
+        let Self {
+            name,
+            description,
+            active,
+            metadata,
+            modified_at,
+        } = self;
+        SubscriptionPlan {
+            name: name.unwrap_or(source.name),
+            description: description.or(source.description),
+            active: active.unwrap_or(source.active),
+            metadata: metadata.or(source.metadata),
+            modified_at,
+            ..source
+        }
+    }
+}
+
+/// The constraints to apply when listing the subscription plans of a merchant.
+#[derive(Clone, Debug, Default)]
+pub struct SubscriptionPlanListConstraints {
+    pub active: Option<bool>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = subscription, primary_key(subscription_id))]
+pub struct Subscription {
+    pub subscription_id: String,
+    pub merchant_id: String,
+    pub customer_id: id_type::CustomerId,
+    pub plan_id: String,
+    pub mandate_id: String,
+    pub profile_id: String,
+    pub status: storage_enums::SubscriptionStatus,
+    pub billing_cycle_anchor: PrimitiveDateTime,
+    pub current_period_start: Option<PrimitiveDateTime>,
+    pub current_period_end: Option<PrimitiveDateTime>,
+    pub trial_end: Option<PrimitiveDateTime>,
+    pub next_billing_at: Option<PrimitiveDateTime>,
+    pub cancel_at_period_end: bool,
+    pub cancelled_at: Option<PrimitiveDateTime>,
+    pub cancellation_reason: Option<String>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = subscription)]
+pub struct SubscriptionNew {
+    pub subscription_id: String,
+    pub merchant_id: String,
+    pub customer_id: id_type::CustomerId,
+    pub plan_id: String,
+    pub mandate_id: String,
+    pub profile_id: String,
+    pub status: storage_enums::SubscriptionStatus,
+    pub billing_cycle_anchor: PrimitiveDateTime,
+    pub current_period_start: Option<PrimitiveDateTime>,
+    pub current_period_end: Option<PrimitiveDateTime>,
+    pub trial_end: Option<PrimitiveDateTime>,
+    pub next_billing_at: Option<PrimitiveDateTime>,
+    pub cancel_at_period_end: bool,
+    pub cancelled_at: Option<PrimitiveDateTime>,
+    pub cancellation_reason: Option<String>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum SubscriptionUpdate {
+    /// An invoice of the subscription was paid, starting a new billing period
+    BillingPeriodUpdate {
+        current_period_start: PrimitiveDateTime,
+        current_period_end: PrimitiveDateTime,
+    },
+    StatusUpdate {
+        status: storage_enums::SubscriptionStatus,
+    },
+    CancelAtPeriodEndUpdate {
+        cancellation_reason: Option<String>,
+    },
+    Cancelled {
+        cancellation_reason: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = subscription)]
+pub struct SubscriptionUpdateInternal {
+    status: Option<storage_enums::SubscriptionStatus>,
+    current_period_start: Option<PrimitiveDateTime>,
+    current_period_end: Option<PrimitiveDateTime>,
+    next_billing_at: Option<Option<PrimitiveDateTime>>,
+    cancel_at_period_end: Option<bool>,
+    cancelled_at: Option<PrimitiveDateTime>,
+    cancellation_reason: Option<String>,
+    modified_at: PrimitiveDateTime,
+}
+
+impl From<SubscriptionUpdate> for SubscriptionUpdateInternal {
+    fn from(subscription_update: SubscriptionUpdate) -> Self {
+        let modified_at = common_utils::date_time::now();
+        match subscription_update {
+            SubscriptionUpdate::BillingPeriodUpdate {
+                current_period_start,
+                current_period_end,
+            } => Self {
+                status: Some(storage_enums::SubscriptionStatus::Active),
+                current_period_start: Some(current_period_start),
+                current_period_end: Some(current_period_end),
+                // The next invoice is charged when the billing period ends
+                next_billing_at: Some(Some(current_period_end)),
+                cancel_at_period_end: None,
+                cancelled_at: None,
+                cancellation_reason: None,
+                modified_at,
+            },
+            SubscriptionUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                current_period_start: None,
+                current_period_end: None,
+                next_billing_at: None,
+                cancel_at_period_end: None,
+                cancelled_at: None,
+                cancellation_reason: None,
+                modified_at,
+            },
+            SubscriptionUpdate::CancelAtPeriodEndUpdate {
+                cancellation_reason,
+            } => Self {
+                status: None,
+                current_period_start: None,
+                current_period_end: None,
+                next_billing_at: None,
+                cancel_at_period_end: Some(true),
+                cancelled_at: None,
+                cancellation_reason,
+                modified_at,
+            },
+            SubscriptionUpdate::Cancelled {
+                cancellation_reason,
+            } => Self {
+                status: Some(storage_enums::SubscriptionStatus::Cancelled),
+                current_period_start: None,
+                current_period_end: None,
+                next_billing_at: Some(None),
+                cancel_at_period_end: None,
+                cancelled_at: Some(modified_at),
+                cancellation_reason,
+                modified_at,
+            },
+        }
+    }
+}
+
+impl SubscriptionUpdateInternal {
+    pub fn apply_changeset(self, source: Subscription) -> Subscription {
+        let Self {
+            status,
+            current_period_start,
+            current_period_end,
+            next_billing_at,
+            cancel_at_period_end,
+            cancelled_at,
+            cancellation_reason,
+            modified_at,
+        } = self;
+        Subscription {
+            status: status.unwrap_or(source.status),
+            current_period_start: current_period_start.or(source.current_period_start),
+            current_period_end: current_period_end.or(source.current_period_end),
+            next_billing_at: next_billing_at.unwrap_or(source.next_billing_at),
+            cancel_at_period_end: cancel_at_period_end.unwrap_or(source.cancel_at_period_end),
+            cancelled_at: cancelled_at.or(source.cancelled_at),
+            cancellation_reason: cancellation_reason.or(source.cancellation_reason),
+            modified_at,
+            ..source
+        }
+    }
+}
+
+/// The constraints to apply when listing the subscriptions of a merchant.
+#[derive(Clone, Debug, Default)]
+pub struct SubscriptionListConstraints {
+    pub customer_id: Option<id_type::CustomerId>,
+    pub plan_id: Option<String>,
+    pub status: Option<storage_enums::SubscriptionStatus>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = subscription_invoice, primary_key(invoice_id))]
+pub struct SubscriptionInvoice {
+    pub invoice_id: String,
+    pub subscription_id: String,
+    pub merchant_id: String,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub status: storage_enums::SubscriptionInvoiceStatus,
+    pub period_start: PrimitiveDateTime,
+    pub period_end: PrimitiveDateTime,
+    pub payment_id: Option<String>,
+    pub attempt_count: i32,
+    pub next_attempt_at: Option<PrimitiveDateTime>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = subscription_invoice)]
+pub struct SubscriptionInvoiceNew {
+    pub invoice_id: String,
+    pub subscription_id: String,
+    pub merchant_id: String,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub status: storage_enums::SubscriptionInvoiceStatus,
+    pub period_start: PrimitiveDateTime,
+    pub period_end: PrimitiveDateTime,
+    pub payment_id: Option<String>,
+    pub attempt_count: i32,
+    pub next_attempt_at: Option<PrimitiveDateTime>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum SubscriptionInvoiceUpdate {
+    /// A payment was created for the invoice
+    PaymentAttempted {
+        payment_id: String,
+        attempt_count: i32,
+    },
+    Paid,
+    /// The payment for the invoice failed, the payment is attempted again at `next_attempt_at`,
+    /// or never if it is `None`
+    PaymentFailed {
+        error_code: Option<String>,
+        error_message: Option<String>,
+        next_attempt_at: Option<PrimitiveDateTime>,
+    },
+    Voided,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = subscription_invoice)]
+pub struct SubscriptionInvoiceUpdateInternal {
+    status: Option<storage_enums::SubscriptionInvoiceStatus>,
+    payment_id: Option<String>,
+    attempt_count: Option<i32>,
+    next_attempt_at: Option<Option<PrimitiveDateTime>>,
+    error_code: Option<Option<String>>,
+    error_message: Option<Option<String>>,
+    modified_at: PrimitiveDateTime,
+}
+
+impl From<SubscriptionInvoiceUpdate> for SubscriptionInvoiceUpdateInternal {
+    fn from(subscription_invoice_update: SubscriptionInvoiceUpdate) -> Self {
+        let modified_at = common_utils::date_time::now();
+        match subscription_invoice_update {
+            SubscriptionInvoiceUpdate::PaymentAttempted {
+                payment_id,
+                attempt_count,
+            } => Self {
+                status: Some(storage_enums::SubscriptionInvoiceStatus::Processing),
+                payment_id: Some(payment_id),
+                attempt_count: Some(attempt_count),
+                next_attempt_at: Some(None),
+                error_code: None,
+                error_message: None,
+                modified_at,
+            },
+            SubscriptionInvoiceUpdate::Paid => Self {
+                status: Some(storage_enums::SubscriptionInvoiceStatus::Paid),
+                payment_id: None,
+                attempt_count: None,
+                next_attempt_at: None,
+                error_code: Some(None),
+                error_message: Some(None),
+                modified_at,
+            },
+            SubscriptionInvoiceUpdate::PaymentFailed {
+                error_code,
+                error_message,
+                next_attempt_at,
+            } => Self {
+                status: Some(if next_attempt_at.is_some() {
+                    storage_enums::SubscriptionInvoiceStatus::Open
+                } else {
+                    storage_enums::SubscriptionInvoiceStatus::Uncollectible
+                }),
+                payment_id: None,
+                attempt_count: None,
+                next_attempt_at: Some(next_attempt_at),
+                error_code: Some(error_code),
+                error_message: Some(error_message),
+                modified_at,
+            },
+            SubscriptionInvoiceUpdate::Voided => Self {
+                status: Some(storage_enums::SubscriptionInvoiceStatus::Void),
+                payment_id: None,
+                attempt_count: None,
+                next_attempt_at: Some(None),
+                error_code: None,
+                error_message: None,
+                modified_at,
+            },
+        }
+    }
+}
+
+impl SubscriptionInvoiceUpdateInternal {
+    pub fn apply_changeset(self, source: SubscriptionInvoice) -> SubscriptionInvoice {
+        let Self {
+            status,
+            payment_id,
+            attempt_count,
+            next_attempt_at,
+            error_code,
+            error_message,
+            modified_at,
+        } = self;
+        SubscriptionInvoice {
+            status: status.unwrap_or(source.status),
+            payment_id: payment_id.or(source.payment_id),
+            attempt_count: attempt_count.unwrap_or(source.attempt_count),
+            next_attempt_at: next_attempt_at.unwrap_or(source.next_attempt_at),
+            error_code: error_code.unwrap_or(source.error_code),
+            error_message: error_message.unwrap_or(source.error_message),
+            modified_at,
+            ..source
+        }
+    }
+}
+
+// Tracking data by process_tracker
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct SubscriptionTrackingData {
+    pub subscription_id: String,
+    pub merchant_id: String,
+}
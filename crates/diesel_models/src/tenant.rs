@@ -0,0 +1,65 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::tenant};
+
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = tenant, primary_key(tenant_id))]
+pub struct Tenant {
+    pub tenant_id: String,
+    pub name: String,
+    pub base_url: String,
+    pub schema_name: String,
+    pub redis_key_prefix: String,
+    pub clickhouse_database: String,
+    pub status: storage_enums::TenantStatus,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = tenant)]
+pub struct TenantNew {
+    pub tenant_id: String,
+    pub name: String,
+    pub base_url: String,
+    pub schema_name: String,
+    pub redis_key_prefix: String,
+    pub clickhouse_database: String,
+    pub status: storage_enums::TenantStatus,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum TenantUpdate {
+    StatusUpdate { status: storage_enums::TenantStatus },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = tenant)]
+pub struct TenantUpdateInternal {
+    pub status: Option<storage_enums::TenantStatus>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<TenantUpdate> for TenantUpdateInternal {
+    fn from(value: TenantUpdate) -> Self {
+        match value {
+            TenantUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
+
+impl TenantUpdateInternal {
+    pub fn apply_changeset(self, source: Tenant) -> Tenant {
+        Tenant {
+            status: self.status.unwrap_or(source.status),
+            modified_at: self.modified_at,
+            ..source
+        }
+    }
+}
@@ -0,0 +1,58 @@
+//! Translations of the unified error messages
+
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::schema::unified_translations;
+
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = unified_translations, primary_key(unified_code, unified_message, locale))]
+pub struct UnifiedTranslations {
+    pub unified_code: String,
+    pub unified_message: String,
+    pub locale: String,
+    pub translation: String,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = unified_translations)]
+pub struct UnifiedTranslationsNew {
+    pub unified_code: String,
+    pub unified_message: String,
+    pub locale: String,
+    pub translation: String,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub struct UnifiedTranslationsUpdate {
+    pub translation: String,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = unified_translations)]
+pub struct UnifiedTranslationsUpdateInternal {
+    pub translation: Option<String>,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+impl From<UnifiedTranslationsUpdate> for UnifiedTranslationsUpdateInternal {
+    fn from(value: UnifiedTranslationsUpdate) -> Self {
+        Self {
+            translation: Some(value.translation),
+            last_modified_at: common_utils::date_time::now(),
+        }
+    }
+}
+
+/// The constraints to apply when listing unified translations.
+#[derive(Clone, Debug, Default)]
+pub struct UnifiedTranslationsListConstraints {
+    pub unified_code: Option<String>,
+    pub locale: Option<String>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
@@ -0,0 +1,130 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{encryption::Encryption, enums as storage_enums, schema::webhook_endpoint};
+
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = webhook_endpoint, primary_key(endpoint_id))]
+pub struct WebhookEndpoint {
+    pub endpoint_id: String,
+    pub merchant_id: String,
+    pub profile_id: String,
+    pub url: String,
+    pub description: Option<String>,
+    pub auth: Option<Encryption>,
+    pub signing_secret: Encryption,
+    pub enabled_events: Vec<storage_enums::EventType>,
+    pub is_enabled: bool,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+    pub previous_signing_secret: Option<Encryption>,
+    pub previous_signing_secret_expires_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = webhook_endpoint)]
+pub struct WebhookEndpointNew {
+    pub endpoint_id: String,
+    pub merchant_id: String,
+    pub profile_id: String,
+    pub url: String,
+    pub description: Option<String>,
+    pub auth: Option<Encryption>,
+    pub signing_secret: Encryption,
+    pub enabled_events: Vec<storage_enums::EventType>,
+    pub is_enabled: bool,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+    pub previous_signing_secret: Option<Encryption>,
+    pub previous_signing_secret_expires_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug)]
+pub enum WebhookEndpointUpdate {
+    Update {
+        url: Option<String>,
+        description: Option<String>,
+        auth: Option<Encryption>,
+        enabled_events: Option<Vec<storage_enums::EventType>>,
+        is_enabled: Option<bool>,
+    },
+    SigningSecretUpdate {
+        signing_secret: Encryption,
+        previous_signing_secret: Encryption,
+        previous_signing_secret_expires_at: PrimitiveDateTime,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = webhook_endpoint)]
+pub struct WebhookEndpointUpdateInternal {
+    pub url: Option<String>,
+    pub description: Option<String>,
+    pub auth: Option<Encryption>,
+    pub enabled_events: Option<Vec<storage_enums::EventType>>,
+    pub is_enabled: Option<bool>,
+    pub modified_at: PrimitiveDateTime,
+    pub signing_secret: Option<Encryption>,
+    pub previous_signing_secret: Option<Encryption>,
+    pub previous_signing_secret_expires_at: Option<PrimitiveDateTime>,
+}
+
+impl From<WebhookEndpointUpdate> for WebhookEndpointUpdateInternal {
+    fn from(value: WebhookEndpointUpdate) -> Self {
+        match value {
+            WebhookEndpointUpdate::Update {
+                url,
+                description,
+                auth,
+                enabled_events,
+                is_enabled,
+            } => Self {
+                url,
+                description,
+                auth,
+                enabled_events,
+                is_enabled,
+                modified_at: common_utils::date_time::now(),
+                signing_secret: None,
+                previous_signing_secret: None,
+                previous_signing_secret_expires_at: None,
+            },
+            WebhookEndpointUpdate::SigningSecretUpdate {
+                signing_secret,
+                previous_signing_secret,
+                previous_signing_secret_expires_at,
+            } => Self {
+                url: None,
+                description: None,
+                auth: None,
+                enabled_events: None,
+                is_enabled: None,
+                modified_at: common_utils::date_time::now(),
+                signing_secret: Some(signing_secret),
+                previous_signing_secret: Some(previous_signing_secret),
+                previous_signing_secret_expires_at: Some(previous_signing_secret_expires_at),
+            },
+        }
+    }
+}
+
+impl WebhookEndpointUpdateInternal {
+    pub fn apply_changeset(self, source: WebhookEndpoint) -> WebhookEndpoint {
+        WebhookEndpoint {
+            url: self.url.unwrap_or(source.url),
+            description: self.description.or(source.description),
+            auth: self.auth.or(source.auth),
+            enabled_events: self.enabled_events.unwrap_or(source.enabled_events),
+            is_enabled: self.is_enabled.unwrap_or(source.is_enabled),
+            modified_at: self.modified_at,
+            signing_secret: self.signing_secret.unwrap_or(source.signing_secret),
+            previous_signing_secret: self
+                .previous_signing_secret
+                .or(source.previous_signing_secret),
+            previous_signing_secret_expires_at: self
+                .previous_signing_secret_expires_at
+                .or(source.previous_signing_secret_expires_at),
+            ..source
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{encryption::Encryption, schema::webhook_signing_secret};
+
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = webhook_signing_secret, primary_key(secret_id))]
+pub struct WebhookSigningSecret {
+    pub secret_id: String,
+    pub merchant_id: String,
+    pub profile_id: String,
+    pub secret: Encryption,
+    pub created_at: PrimitiveDateTime,
+    pub expires_at: Option<PrimitiveDateTime>,
+    pub revoked_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = webhook_signing_secret)]
+pub struct WebhookSigningSecretNew {
+    pub secret_id: String,
+    pub merchant_id: String,
+    pub profile_id: String,
+    pub secret: Encryption,
+    pub created_at: PrimitiveDateTime,
+    pub expires_at: Option<PrimitiveDateTime>,
+    pub revoked_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug)]
+pub enum WebhookSigningSecretUpdate {
+    ExpiryUpdate { expires_at: PrimitiveDateTime },
+    Revoke { revoked_at: PrimitiveDateTime },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = webhook_signing_secret)]
+pub struct WebhookSigningSecretUpdateInternal {
+    pub expires_at: Option<PrimitiveDateTime>,
+    pub revoked_at: Option<PrimitiveDateTime>,
+}
+
+impl From<WebhookSigningSecretUpdate> for WebhookSigningSecretUpdateInternal {
+    fn from(value: WebhookSigningSecretUpdate) -> Self {
+        match value {
+            WebhookSigningSecretUpdate::ExpiryUpdate { expires_at } => Self {
+                expires_at: Some(expires_at),
+                ..Default::default()
+            },
+            WebhookSigningSecretUpdate::Revoke { revoked_at } => Self {
+                revoked_at: Some(revoked_at),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl WebhookSigningSecretUpdateInternal {
+    pub fn apply_changeset(self, source: WebhookSigningSecret) -> WebhookSigningSecret {
+        WebhookSigningSecret {
+            expires_at: self.expires_at.or(source.expires_at),
+            revoked_at: self.revoked_at.or(source.revoked_at),
+            ..source
+        }
+    }
+}
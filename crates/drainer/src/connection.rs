@@ -1,14 +1,16 @@
+use std::sync::Arc;
+
 use bb8::PooledConnection;
 use common_utils::DbConnectionParams;
-use diesel::PgConnection;
+use storage_impl::database::credentials::{CredentialsProvider, PgConnectionManager};
 
 use crate::{settings::Database, Settings};
 
-pub type PgPool = bb8::Pool<async_bb8_diesel::ConnectionManager<PgConnection>>;
+pub type PgPool = bb8::Pool<PgConnectionManager>;
 
 #[allow(clippy::expect_used)]
 pub async fn redis_connection(conf: &Settings) -> redis_interface::RedisConnectionPool {
-    redis_interface::RedisConnectionPool::new(&conf.redis)
+    redis_interface::RedisConnectionPool::new(conf.redis.get_inner())
         .await
         .expect("Failed to create Redis connection Pool")
 }
@@ -20,24 +22,36 @@ pub async fn redis_connection(conf: &Settings) -> redis_interface::RedisConnecti
 #[allow(clippy::expect_used)]
 pub async fn diesel_make_pg_pool(
     database: &Database,
+    credentials_provider: Option<Arc<dyn CredentialsProvider>>,
     _test_transaction: bool,
     schema: &str,
 ) -> PgPool {
+    let credentials_refresh = credentials_provider.zip(database.credentials_refresh_interval);
     let database_url = database.get_database_url(schema);
-    let manager = async_bb8_diesel::ConnectionManager::<PgConnection>::new(database_url);
+    let manager = PgConnectionManager::new(database_url, credentials_refresh.is_some());
     let pool = bb8::Pool::builder()
         .max_size(database.pool_size)
         .connection_timeout(std::time::Duration::from_secs(database.connection_timeout));
 
-    pool.build(manager)
+    let pool = pool
+        .build(manager.clone())
         .await
-        .expect("Failed to create PostgreSQL connection pool")
+        .expect("Failed to create PostgreSQL connection pool");
+
+    if let Some((provider, refresh_interval)) = credentials_refresh {
+        manager.spawn_credentials_refresh(
+            database.clone(),
+            schema.to_owned(),
+            provider,
+            std::time::Duration::from_secs(refresh_interval),
+        );
+    }
+
+    pool
 }
 
 #[allow(clippy::expect_used)]
-pub async fn pg_connection(
-    pool: &PgPool,
-) -> PooledConnection<'_, async_bb8_diesel::ConnectionManager<PgConnection>> {
+pub async fn pg_connection(pool: &PgPool) -> PooledConnection<'_, PgConnectionManager> {
     pool.get()
         .await
         .expect("Couldn't retrieve PostgreSQL connection")
@@ -1,7 +1,4 @@
-use std::{
-    collections::HashMap,
-    sync::{atomic, Arc},
-};
+use std::sync::{atomic, Arc, PoisonError};
 
 use router_env::tracing::Instrument;
 use tokio::{
@@ -10,8 +7,8 @@ use tokio::{
 };
 
 use crate::{
-    errors, instrument, logger, metrics, query::ExecuteQuery, tracing, utils, DrainerSettings,
-    Store, StreamData,
+    errors, instrument, logger, metrics, query::ExecuteQuery, services::Stores, tracing, utils,
+    DrainerSettings, Store, StreamData,
 };
 
 /// Handler handles the spawning and closing of drainer
@@ -34,12 +31,12 @@ pub struct HandlerInner {
     loop_interval: Duration,
     active_tasks: Arc<atomic::AtomicU64>,
     conf: DrainerSettings,
-    stores: HashMap<String, Arc<Store>>,
+    stores: Stores,
     running: Arc<atomic::AtomicBool>,
 }
 
 impl Handler {
-    pub fn from_conf(conf: DrainerSettings, stores: HashMap<String, Arc<Store>>) -> Self {
+    pub fn from_conf(conf: DrainerSettings, stores: Stores) -> Self {
         let shutdown_interval = Duration::from_millis(conf.shutdown_interval.into());
         let loop_interval = Duration::from_millis(conf.loop_interval.into());
 
@@ -61,6 +58,15 @@ impl Handler {
         }
     }
 
+    fn get_stores(&self) -> Vec<Arc<Store>> {
+        self.stores
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .values()
+            .cloned()
+            .collect()
+    }
+
     pub fn close(&self) {
         self.running.store(false, atomic::Ordering::SeqCst);
     }
@@ -71,7 +77,7 @@ impl Handler {
 
         while self.running.load(atomic::Ordering::SeqCst) {
             metrics::DRAINER_HEALTH.add(&metrics::CONTEXT, 1, &[]);
-            for store in self.stores.values() {
+            for store in self.get_stores() {
                 if store.is_stream_available(stream_index).await {
                     let _task_handle = tokio::spawn(
                         drainer_handler(
@@ -122,9 +128,8 @@ impl Handler {
     pub fn spawn_error_handlers(&self, tx: mpsc::Sender<()>) -> errors::DrainerResult<()> {
         let (redis_error_tx, redis_error_rx) = oneshot::channel();
         let redis_conn_clone = self
-            .stores
-            .values()
-            .next()
+            .get_stores()
+            .first()
             .map(|store| store.redis_conn.clone());
         match redis_conn_clone {
             None => {
@@ -165,6 +170,55 @@ pub async fn redis_error_receiver(rx: oneshot::Receiver<()>, shutdown_channel: m
     }
 }
 
+/// Drains the streams of a store which is no longer picked up by the handler, so that the writes
+/// queued for a deregistered tenant are applied before its store is dropped.
+///
+/// A partition is left as is if draining it does not make progress, the entries remaining in it
+/// are applied once the tenant is registered again.
+#[router_env::instrument(skip_all)]
+pub(crate) async fn drain_store(store: Arc<Store>, conf: DrainerSettings) {
+    let loop_interval = Duration::from_millis(conf.loop_interval.into());
+    let active_tasks = Arc::new(atomic::AtomicU64::new(0));
+    let jobs_picked = Arc::new(atomic::AtomicU8::new(0));
+
+    for stream_index in 0..conf.num_partitions {
+        let stream_name = store.get_drainer_stream_name(stream_index);
+        let mut previous_length = None;
+        loop {
+            let length = match store.redis_conn.stream_get_length(&stream_name).await {
+                Ok(length) => length,
+                Err(error) => {
+                    logger::error!(?error, %stream_name, "Failed to get the length of the stream");
+                    break;
+                }
+            };
+            if length == 0 {
+                break;
+            }
+            if previous_length == Some(length) {
+                logger::error!(%stream_name, length, "Failed to drain the stream");
+                break;
+            }
+
+            if store.is_stream_available(stream_index).await {
+                previous_length = Some(length);
+                drainer_handler(
+                    store.clone(),
+                    stream_index,
+                    conf.max_read_count,
+                    active_tasks.clone(),
+                    jobs_picked.clone(),
+                )
+                .await
+                .ok();
+            } else {
+                // The stream is being drained by a task spawned before the store was removed
+                time::sleep(loop_interval).await;
+            }
+        }
+    }
+}
+
 #[router_env::instrument(skip_all)]
 async fn drainer_handler(
     store: Arc<Store>,
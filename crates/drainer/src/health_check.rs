@@ -1,4 +1,7 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::HashMap,
+    sync::{Arc, PoisonError},
+};
 
 use actix_web::{web, Scope};
 use async_bb8_diesel::{AsyncConnection, AsyncRunQueryDsl};
@@ -10,7 +13,7 @@ use router_env::{instrument, logger, tracing};
 use crate::{
     connection::pg_connection,
     errors::HealthCheckError,
-    services::{self, log_and_return_error_response, Store},
+    services::{self, log_and_return_error_response, Store, Stores},
     Settings,
 };
 
@@ -20,7 +23,7 @@ pub const TEST_STREAM_DATA: &[(&str, &str)] = &[("data", "sample_data")];
 pub struct Health;
 
 impl Health {
-    pub fn server(conf: Settings, stores: HashMap<String, Arc<Store>>) -> Scope {
+    pub fn server(conf: Settings, stores: Stores) -> Scope {
         web::scope("health")
             .app_data(web::Data::new(conf))
             .app_data(web::Data::new(stores))
@@ -38,8 +41,14 @@ pub async fn health() -> impl actix_web::Responder {
 #[instrument(skip_all)]
 pub async fn deep_health_check(
     conf: web::Data<Settings>,
-    stores: web::Data<HashMap<String, Arc<Store>>>,
+    stores: web::Data<Stores>,
 ) -> impl actix_web::Responder {
+    let stores: Vec<_> = stores
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .iter()
+        .map(|(tenant, store)| (tenant.clone(), store.clone()))
+        .collect();
     let mut deep_health_res = HashMap::new();
     for (tenant, store) in stores.iter() {
         logger::info!("Tenant: {:?}", tenant);
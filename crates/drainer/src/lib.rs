@@ -5,13 +5,13 @@ mod health_check;
 pub mod logger;
 pub(crate) mod metrics;
 mod query;
+mod secrets_transformers;
 pub mod services;
 pub mod settings;
 mod stream;
+pub mod tenant_registry;
 mod types;
 mod utils;
-use std::{collections::HashMap, sync::Arc};
-mod secrets_transformers;
 
 use actix_web::dev::Server;
 use common_utils::signals::get_allowed_signals;
@@ -27,13 +27,13 @@ use tokio::sync::mpsc;
 pub(crate) type Settings = settings::Settings<RawSecret>;
 
 use crate::{
-    connection::pg_connection, services::Store, settings::DrainerSettings, types::StreamData,
+    connection::pg_connection,
+    services::{Store, Stores},
+    settings::DrainerSettings,
+    types::StreamData,
 };
 
-pub async fn start_drainer(
-    stores: HashMap<String, Arc<Store>>,
-    conf: DrainerSettings,
-) -> errors::DrainerResult<()> {
+pub async fn start_drainer(stores: Stores, conf: DrainerSettings) -> errors::DrainerResult<()> {
     let drainer_handler = handler::Handler::from_conf(conf, stores);
 
     let (tx, rx) = mpsc::channel::<()>(1);
@@ -62,7 +62,7 @@ pub async fn start_drainer(
 
 pub async fn start_web_server(
     conf: Settings,
-    stores: HashMap<String, Arc<Store>>,
+    stores: Stores,
 ) -> Result<Server, errors::DrainerError> {
     let server = conf.server.clone();
     let web_server = actix_web::HttpServer::new(move || {
@@ -1,7 +1,11 @@
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
 
 use drainer::{
     errors::DrainerResult, logger::logger, services, settings, start_drainer, start_web_server,
+    tenant_registry,
 };
 use router_env::tracing::Instrument;
 
@@ -21,9 +25,10 @@ async fn main() -> DrainerResult<()> {
 
     let mut stores = HashMap::new();
     for (tenant_name, tenant) in conf.multitenancy.get_tenants() {
-        let store = std::sync::Arc::new(services::Store::new(&state.conf, false, tenant).await);
+        let store = Arc::new(services::Store::new(&state, false, tenant).await);
         stores.insert(tenant_name.clone(), store);
     }
+    let stores: services::Stores = Arc::new(RwLock::new(stores));
 
     #[cfg(feature = "vergen")]
     println!("Starting drainer (Version: {})", router_env::git_tag!());
@@ -50,6 +55,13 @@ async fn main() -> DrainerResult<()> {
         .in_current_span(),
     );
 
+    if conf.multitenancy.enabled {
+        #[allow(clippy::expect_used)]
+        tenant_registry::register_provisioned_tenants(state.clone(), stores.clone())
+            .await
+            .expect("Failed to register the provisioned tenants");
+    }
+
     logger::debug!(startup_config=?conf);
     logger::info!("Drainer started [{:?}] [{:?}]", conf.drainer, conf.log);
 
@@ -37,10 +37,16 @@ pub async fn fetch_raw_secrets(
         .await
         .expect("Failed to decrypt database password");
 
+    #[allow(clippy::expect_used)]
+    let redis =
+        redis_interface::RedisSettings::convert_to_raw_secret(conf.redis, secret_management_client)
+            .await
+            .expect("Failed to decrypt redis configuration");
+
     Settings {
         server: conf.server,
         master_database: database,
-        redis: conf.redis,
+        redis,
         log: conf.log,
         drainer: conf.drainer,
         encryption_management: conf.encryption_management,
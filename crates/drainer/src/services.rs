@@ -1,4 +1,7 @@
-use std::sync::Arc;
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
 
 use actix_web::{body, HttpResponse, ResponseError};
 use error_stack::Report;
@@ -7,9 +10,13 @@ use redis_interface::RedisConnectionPool;
 use crate::{
     connection::{diesel_make_pg_pool, PgPool},
     logger,
-    settings::Tenant,
+    settings::{AppState, Tenant},
 };
 
+/// Stores of the tenants being drained, by tenant ID. Tenants provisioned at runtime are added
+/// and removed while the drainer is running.
+pub type Stores = Arc<RwLock<HashMap<String, Arc<Store>>>>;
+
 #[derive(Clone)]
 pub struct Store {
     pub master_pool: PgPool,
@@ -30,11 +37,13 @@ impl Store {
     /// Panics if there is a failure while obtaining the HashiCorp client using the provided configuration.
     /// This panic indicates a critical failure in setting up external services, and the application cannot proceed without a valid HashiCorp client.
     ///
-    pub async fn new(config: &crate::Settings, test_transaction: bool, tenant: &Tenant) -> Self {
+    pub async fn new(state: &AppState, test_transaction: bool, tenant: &Tenant) -> Self {
+        let config = &state.conf;
         let redis_conn = crate::connection::redis_connection(config).await;
         Self {
             master_pool: diesel_make_pg_pool(
                 config.master_database.get_inner(),
+                state.database_credentials.clone(),
                 test_transaction,
                 &tenant.schema,
             )
@@ -1,14 +1,16 @@
 use std::{collections::HashMap, path::PathBuf, sync::Arc};
 
-use common_utils::{ext_traits::ConfigExt, DbConnectionParams};
+use common_utils::{ext_traits::ConfigExt, DbConnectionParams, PgSslMode};
 use config::{Environment, File};
 use external_services::managers::{
     encryption_management::EncryptionManagementConfig, secrets_management::SecretsManagementConfig,
 };
 use hyperswitch_interfaces::{
     encryption_interface::EncryptionManagementInterface,
-    secrets_interface::secret_state::{
-        RawSecret, SecretState, SecretStateContainer, SecuredSecret,
+    secrets_interface::{
+        database_credentials::SecretManagedCredentials,
+        secret_state::{RawSecret, SecretState, SecretStateContainer, SecuredSecret},
+        SecretManagementInterface,
     },
 };
 use masking::Secret;
@@ -16,6 +18,7 @@ use redis_interface as redis;
 pub use router_env::config::{Log, LogConsole, LogFile, LogTelemetry};
 use router_env::{env, logger};
 use serde::Deserialize;
+use storage_impl::database::credentials::CredentialsProvider;
 
 use crate::{errors, secrets_transformers};
 
@@ -32,6 +35,9 @@ pub struct CmdLineConf {
 pub struct AppState {
     pub conf: Arc<Settings<RawSecret>>,
     pub encryption_client: Arc<dyn EncryptionManagementInterface>,
+    /// Provider of the credentials of the database user, present if the credentials are
+    /// refreshed periodically
+    pub database_credentials: Option<Arc<dyn CredentialsProvider>>,
 }
 
 impl AppState {
@@ -40,11 +46,19 @@ impl AppState {
     /// Panics if secret or encryption management client cannot be initiated
     pub async fn new(conf: Settings<SecuredSecret>) -> Self {
         #[allow(clippy::expect_used)]
-        let secret_management_client = conf
+        let secret_management_client: Arc<dyn SecretManagementInterface> = conf
             .secrets_management
             .get_secret_management_client()
             .await
-            .expect("Failed to create secret management client");
+            .expect("Failed to create secret management client")
+            .into();
+
+        let master_database = conf.master_database.get_inner();
+        let database_credentials = SecretManagedCredentials::for_database(
+            &master_database.password,
+            master_database.credentials_refresh_interval,
+            &secret_management_client,
+        );
 
         let raw_conf =
             secrets_transformers::fetch_raw_secrets(conf, &*secret_management_client).await;
@@ -59,6 +73,7 @@ impl AppState {
         Self {
             conf: Arc::new(raw_conf),
             encryption_client,
+            database_credentials,
         }
     }
 }
@@ -68,7 +83,7 @@ impl AppState {
 pub struct Settings<S: SecretState> {
     pub server: Server,
     pub master_database: SecretStateContainer<Database, S>,
-    pub redis: redis::RedisSettings,
+    pub redis: SecretStateContainer<redis::RedisSettings, S>,
     pub log: Log,
     pub drainer: DrainerSettings,
     pub encryption_management: EncryptionManagementConfig,
@@ -86,6 +101,11 @@ pub struct Database {
     pub dbname: String,
     pub pool_size: u32,
     pub connection_timeout: u64,
+    pub sslmode: Option<PgSslMode>,
+    pub sslrootcert: Option<String>,
+    /// Interval in seconds at which the password is re-fetched through the secrets manager. The
+    /// password is not refreshed if not configured.
+    pub credentials_refresh_interval: Option<u64>,
 }
 
 impl DbConnectionParams for Database {
@@ -104,6 +124,12 @@ impl DbConnectionParams for Database {
     fn get_dbname(&self) -> &str {
         &self.dbname
     }
+    fn get_sslmode(&self) -> Option<PgSslMode> {
+        self.sslmode
+    }
+    fn get_sslrootcert(&self) -> Option<&str> {
+        self.sslrootcert.as_deref()
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -120,6 +146,8 @@ pub struct DrainerSettings {
 pub struct Multitenancy {
     pub enabled: bool,
     pub tenants: TenantConfig,
+    #[serde(default)]
+    pub global_tenant: GlobalTenant,
 }
 impl Multitenancy {
     pub fn get_tenants(&self) -> &HashMap<String, Tenant> {
@@ -146,6 +174,26 @@ pub struct Tenant {
     pub clickhouse_database: String,
 }
 
+impl From<diesel_models::tenant::Tenant> for Tenant {
+    fn from(tenant: diesel_models::tenant::Tenant) -> Self {
+        Self {
+            name: tenant.name,
+            base_url: tenant.base_url,
+            schema: tenant.schema_name,
+            redis_key_prefix: tenant.redis_key_prefix,
+            clickhouse_database: tenant.clickhouse_database,
+        }
+    }
+}
+
+/// The schema holding the tables shared by all the tenants, such as the tenants provisioned at
+/// runtime
+#[derive(Debug, Deserialize, Clone)]
+pub struct GlobalTenant {
+    pub schema: String,
+    pub redis_key_prefix: String,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[serde(default)]
 pub struct Server {
@@ -174,6 +222,9 @@ impl Default for Database {
             dbname: String::new(),
             pool_size: 5,
             connection_timeout: 10,
+            sslmode: None,
+            sslrootcert: None,
+            credentials_refresh_interval: None,
         }
     }
 }
@@ -190,6 +241,15 @@ impl Default for DrainerSettings {
     }
 }
 
+impl Default for GlobalTenant {
+    fn default() -> Self {
+        Self {
+            schema: "public".to_string(),
+            redis_key_prefix: String::new(),
+        }
+    }
+}
+
 impl Default for Server {
     fn default() -> Self {
         Self {
@@ -226,6 +286,12 @@ impl Database {
             Err(errors::DrainerError::ConfigParsingError(
                 "database user password must not be empty".into(),
             ))
+        })?;
+
+        when(self.credentials_refresh_interval == Some(0), || {
+            Err(errors::DrainerError::ConfigParsingError(
+                "database credentials refresh interval must be greater than 0".into(),
+            ))
         })
     }
 }
@@ -269,7 +335,8 @@ impl Settings<SecuredSecret> {
                     .try_parsing(true)
                     .separator("__")
                     .list_separator(",")
-                    .with_list_parse_key("redis.cluster_urls"),
+                    .with_list_parse_key("redis.cluster_urls")
+                    .with_list_parse_key("redis.sentinel_urls"),
             )
             .build()?;
 
@@ -283,7 +350,7 @@ impl Settings<SecuredSecret> {
     pub fn validate(&self) -> Result<(), errors::DrainerError> {
         self.server.validate()?;
         self.master_database.get_inner().validate()?;
-        self.redis.validate().map_err(|error| {
+        self.redis.get_inner().validate().map_err(|error| {
             println!("{error}");
             errors::DrainerError::ConfigParsingError("invalid Redis configuration".into())
         })?;
@@ -0,0 +1,131 @@
+//! Tenants provisioned at runtime through the admin API of the router, which are drained in
+//! addition to the tenants declared in the `multitenancy` configuration.
+
+use std::sync::{Arc, PoisonError};
+
+use common_utils::consts::TENANT_REGISTRY_CHANNEL;
+use diesel_models::{errors::DatabaseError, tenant::Tenant as ProvisionedTenant};
+use error_stack::ResultExt;
+use redis_interface::PubsubInterface;
+use router_env::{logger, tracing::Instrument};
+
+use crate::{
+    connection::{diesel_make_pg_pool, pg_connection, redis_connection},
+    errors, handler,
+    services::{Store, Stores},
+    settings::{AppState, Tenant},
+    Settings,
+};
+
+/// Registers the stores of the tenants provisioned at runtime, and listens on the tenant registry
+/// channel for the tenants provisioned or deleted afterwards.
+///
+/// Suspended tenants are still drained, so that the writes queued before the suspension are not
+/// lost.
+pub async fn register_provisioned_tenants(
+    state: AppState,
+    stores: Stores,
+) -> errors::DrainerResult<()> {
+    let global_pool = diesel_make_pg_pool(
+        state.conf.master_database.get_inner(),
+        state.database_credentials.clone(),
+        false,
+        &state.conf.multitenancy.global_tenant.schema,
+    )
+    .await;
+
+    let conn = pg_connection(&global_pool).await;
+    let tenants = ProvisionedTenant::list(&conn).await.change_context(
+        errors::DrainerError::UnexpectedError("Failed to list the provisioned tenants".into()),
+    )?;
+    drop(conn);
+    for tenant in tenants {
+        register_tenant(&state, &stores, tenant).await;
+    }
+
+    let redis_conn = redis_connection(&state.conf).await;
+    redis_conn.subscriber.manage_subscriptions();
+    redis_conn
+        .subscriber
+        .subscribe(TENANT_REGISTRY_CHANNEL)
+        .await
+        .change_context(errors::DrainerError::UnexpectedError(
+            "Failed to subscribe to the tenant registry channel".into(),
+        ))?;
+
+    let mut rx = redis_conn.subscriber.on_message();
+    let _task_handle = tokio::spawn(
+        async move {
+            while let Ok(message) = rx.recv().await {
+                let channel_name = message.channel.to_string();
+                if channel_name != TENANT_REGISTRY_CHANNEL {
+                    continue;
+                }
+                let Some(tenant_id) = message.value.as_string() else {
+                    logger::error!("Received an invalid message on the tenant registry channel");
+                    continue;
+                };
+
+                let conn = pg_connection(&global_pool).await;
+                match ProvisionedTenant::find_by_tenant_id(&conn, &tenant_id).await {
+                    Ok(tenant) => register_tenant(&state, &stores, tenant).await,
+                    Err(error) if matches!(error.current_context(), DatabaseError::NotFound) => {
+                        deregister_tenant(&state.conf, &stores, &tenant_id)
+                    }
+                    Err(error) => {
+                        logger::error!(?error, %tenant_id, "Failed to reload tenant");
+                    }
+                }
+            }
+            logger::error!("Stopped listening on the tenant registry channel");
+            drop(redis_conn);
+        }
+        .in_current_span(),
+    );
+
+    Ok(())
+}
+
+async fn register_tenant(state: &AppState, stores: &Stores, tenant: ProvisionedTenant) {
+    let tenant_id = tenant.tenant_id.clone();
+    if state.conf.multitenancy.get_tenant(&tenant_id).is_some()
+        || stores
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .contains_key(&tenant_id)
+    {
+        return;
+    }
+
+    let store = Arc::new(Store::new(state, false, &Tenant::from(tenant)).await);
+    stores
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(tenant_id.clone(), store);
+    logger::info!(%tenant_id, "Registered tenant");
+}
+
+/// Stops picking up the streams of the tenant, the writes already queued for it are drained before
+/// its store is dropped
+fn deregister_tenant(conf: &Settings, stores: &Stores, tenant_id: &str) {
+    if conf.multitenancy.get_tenant(tenant_id).is_some() {
+        return;
+    }
+
+    let store = stores
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .remove(tenant_id);
+    if let Some(store) = store {
+        logger::info!(%tenant_id, "Deregistered tenant");
+        let tenant_id = tenant_id.to_owned();
+        let drainer_settings = conf.drainer.clone();
+        let _task_handle = tokio::spawn(
+            async move {
+                handler::drain_store(store, drainer_settings).await;
+                logger::info!(%tenant_id, "Drained the streams of the deregistered tenant");
+            }
+            .in_current_span(),
+        );
+    }
+}
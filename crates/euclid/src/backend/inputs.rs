@@ -28,6 +28,16 @@ pub struct PaymentInput {
     pub billing_country: Option<enums::Country>,
     pub business_label: Option<String>,
     pub setup_future_usage: Option<enums::SetupFutureUsage>,
+    pub installment_count: Option<u8>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct VelocityInput {
+    pub card_attempts: Option<u32>,
+    pub customer_attempts: Option<u32>,
+    pub ip_attempts: Option<u32>,
+    pub email_attempts: Option<u32>,
+    pub customer_distinct_cards: Option<u32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -36,4 +46,6 @@ pub struct BackendInput {
     pub payment: PaymentInput,
     pub payment_method: PaymentMethodInput,
     pub mandate: MandateData,
+    #[serde(default)]
+    pub velocity: VelocityInput,
 }
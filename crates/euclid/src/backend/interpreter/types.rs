@@ -37,7 +37,7 @@ impl Deref for Context {
 
 impl From<inputs::BackendInput> for Context {
     fn from(input: inputs::BackendInput) -> Self {
-        let ctx = HashMap::<String, Option<ValueType>>::from_iter([
+        let mut ctx = HashMap::<String, Option<ValueType>>::from_iter([
             (
                 EuclidKey::PaymentMethod.to_string(),
                 input
@@ -70,12 +70,41 @@ impl From<inputs::BackendInput> for Context {
                 EuclidKey::PaymentAmount.to_string(),
                 Some(ValueType::Number(input.payment.amount)),
             ),
+            (
+                EuclidKey::InstallmentCount.to_string(),
+                input.payment.installment_count.map(|count| {
+                    ValueType::Number(common_utils::types::MinorUnit::new(i64::from(count)))
+                }),
+            ),
             (
                 EuclidKey::PaymentCurrency.to_string(),
                 Some(ValueType::EnumVariant(input.payment.currency.to_string())),
             ),
         ]);
 
+        let velocity = input.velocity;
+        ctx.extend(
+            [
+                (EuclidKey::CardAttempts, velocity.card_attempts),
+                (EuclidKey::CustomerAttempts, velocity.customer_attempts),
+                (EuclidKey::IpAttempts, velocity.ip_attempts),
+                (EuclidKey::EmailAttempts, velocity.email_attempts),
+                (
+                    EuclidKey::CustomerDistinctCards,
+                    velocity.customer_distinct_cards,
+                ),
+            ]
+            .into_iter()
+            .map(|(key, count)| {
+                (
+                    key.to_string(),
+                    count.map(|count| {
+                        ValueType::Number(common_utils::types::MinorUnit::new(i64::from(count)))
+                    }),
+                )
+            }),
+        );
+
         Self(ctx)
     }
 }
@@ -140,6 +140,7 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                installment_count: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -151,6 +152,7 @@ mod test {
                 mandate_type: None,
                 payment_type: None,
             },
+            velocity: inputs::VelocityInput::default(),
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -180,6 +182,7 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                installment_count: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -191,6 +194,7 @@ mod test {
                 mandate_type: None,
                 payment_type: Some(enums::PaymentType::SetupMandate),
             },
+            velocity: inputs::VelocityInput::default(),
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -221,6 +225,7 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                installment_count: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -232,6 +237,7 @@ mod test {
                 mandate_type: Some(enums::MandateType::SingleUse),
                 payment_type: None,
             },
+            velocity: inputs::VelocityInput::default(),
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -262,6 +268,7 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                installment_count: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -273,6 +280,7 @@ mod test {
                 mandate_type: None,
                 payment_type: None,
             },
+            velocity: inputs::VelocityInput::default(),
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -303,6 +311,7 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                installment_count: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -314,6 +323,7 @@ mod test {
                 mandate_type: None,
                 payment_type: None,
             },
+            velocity: inputs::VelocityInput::default(),
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -344,6 +354,7 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                installment_count: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -355,6 +366,7 @@ mod test {
                 mandate_type: None,
                 payment_type: None,
             },
+            velocity: inputs::VelocityInput::default(),
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -385,6 +397,7 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                installment_count: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -396,6 +409,7 @@ mod test {
                 mandate_type: None,
                 payment_type: None,
             },
+            velocity: inputs::VelocityInput::default(),
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -426,6 +440,7 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: Some(enums::SetupFutureUsage::OffSession),
+                installment_count: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -437,6 +452,7 @@ mod test {
                 mandate_type: None,
                 payment_type: None,
             },
+            velocity: inputs::VelocityInput::default(),
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -469,6 +485,7 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                installment_count: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -480,6 +497,7 @@ mod test {
                 mandate_type: None,
                 payment_type: None,
             },
+            velocity: inputs::VelocityInput::default(),
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -510,6 +528,7 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                installment_count: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -521,6 +540,7 @@ mod test {
                 mandate_type: None,
                 payment_type: None,
             },
+            velocity: inputs::VelocityInput::default(),
         };
         let mut inp_equal = inp_greater.clone();
         inp_equal.payment.amount = MinorUnit::new(123);
@@ -560,6 +580,7 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                installment_count: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -571,6 +592,7 @@ mod test {
                 mandate_type: None,
                 payment_type: None,
             },
+            velocity: inputs::VelocityInput::default(),
         };
         let mut inp_equal = inp_lower.clone();
         inp_equal.payment.amount = MinorUnit::new(123);
@@ -586,4 +608,116 @@ mod test {
             "rule_1"
         );
     }
+
+    #[test]
+    fn test_installment_count() {
+        let program_str = r#"
+        default: ["stripe", "adyen"]
+
+        rule_1: ["stripe"]
+        {
+           installment_count >= 6
+        }
+        "#;
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let inp_installments = inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount: MinorUnit::new(120),
+                card_bin: None,
+                currency: enums::Currency::USD,
+                authentication_type: Some(enums::AuthenticationType::NoThreeDs),
+                capture_method: Some(enums::CaptureMethod::Automatic),
+                business_country: Some(enums::Country::UnitedStatesOfAmerica),
+                billing_country: Some(enums::Country::France),
+                business_label: None,
+                setup_future_usage: None,
+                installment_count: Some(12),
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: Some(enums::PaymentMethod::Card),
+                payment_method_type: Some(enums::PaymentMethodType::Credit),
+                card_network: None,
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+            velocity: inputs::VelocityInput::default(),
+        };
+        let mut inp_fewer_installments = inp_installments.clone();
+        inp_fewer_installments.payment.installment_count = Some(3);
+        let mut inp_no_installments = inp_installments.clone();
+        inp_no_installments.payment.installment_count = None;
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+        let result_installments = backend.execute(inp_installments).expect("Execution");
+        let result_fewer_installments = backend.execute(inp_fewer_installments).expect("Execution");
+        let result_no_installments = backend.execute(inp_no_installments).expect("Execution");
+        assert_eq!(
+            result_installments.rule_name.expect("Rule Name").as_str(),
+            "rule_1"
+        );
+        assert!(result_fewer_installments.rule_name.is_none());
+        assert!(result_no_installments.rule_name.is_none());
+    }
+
+    #[test]
+    fn test_velocity_counts() {
+        let program_str = r#"
+        default: ["stripe", "adyen"]
+
+        rule_1: ["stripe"]
+        {
+           card_attempts > 3 & customer_distinct_cards >= 2
+        }
+        "#;
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let inp_high_velocity = inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount: MinorUnit::new(120),
+                card_bin: None,
+                currency: enums::Currency::USD,
+                authentication_type: Some(enums::AuthenticationType::NoThreeDs),
+                capture_method: Some(enums::CaptureMethod::Automatic),
+                business_country: Some(enums::Country::UnitedStatesOfAmerica),
+                billing_country: Some(enums::Country::France),
+                business_label: None,
+                setup_future_usage: None,
+                installment_count: None,
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: Some(enums::PaymentMethod::Card),
+                payment_method_type: Some(enums::PaymentMethodType::Credit),
+                card_network: None,
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+            velocity: inputs::VelocityInput {
+                card_attempts: Some(5),
+                customer_attempts: Some(5),
+                ip_attempts: None,
+                email_attempts: None,
+                customer_distinct_cards: Some(2),
+            },
+        };
+        let mut inp_low_velocity = inp_high_velocity.clone();
+        inp_low_velocity.velocity.card_attempts = Some(1);
+        let mut inp_no_velocity = inp_high_velocity.clone();
+        inp_no_velocity.velocity = inputs::VelocityInput::default();
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+        let result_high_velocity = backend.execute(inp_high_velocity).expect("Execution");
+        let result_low_velocity = backend.execute(inp_low_velocity).expect("Execution");
+        let result_no_velocity = backend.execute(inp_no_velocity).expect("Execution");
+        assert_eq!(
+            result_high_velocity.rule_name.expect("Rule Name").as_str(),
+            "rule_1"
+        );
+        assert!(result_low_velocity.rule_name.is_none());
+        assert!(result_no_velocity.rule_name.is_none());
+    }
 }
@@ -56,6 +56,7 @@ impl Context {
         let payment_method = input.payment_method;
         let meta_data = input.metadata;
         let payment_mandate = input.mandate;
+        let velocity = input.velocity;
 
         let mut enum_values: FxHashSet<EuclidValue> =
             FxHashSet::from_iter([EuclidValue::PaymentCurrency(payment.currency)]);
@@ -114,13 +115,41 @@ impl Context {
             enum_values.insert(EuclidValue::MandateAcceptanceType(mandate_acceptance_type));
         }
 
-        let numeric_values: FxHashMap<EuclidKey, EuclidValue> = FxHashMap::from_iter([(
+        let mut numeric_values: FxHashMap<EuclidKey, EuclidValue> = FxHashMap::from_iter([(
             EuclidKey::PaymentAmount,
             EuclidValue::PaymentAmount(types::NumValue {
                 number: payment.amount,
                 refinement: None,
             }),
         )]);
+        if let Some(installment_count) = payment.installment_count {
+            numeric_values.insert(
+                EuclidKey::InstallmentCount,
+                EuclidValue::InstallmentCount(types::NumValue {
+                    number: common_utils::types::MinorUnit::new(i64::from(installment_count)),
+                    refinement: None,
+                }),
+            );
+        }
+        let velocity_values: [(Option<u32>, fn(types::NumValue) -> EuclidValue); 5] = [
+            (velocity.card_attempts, EuclidValue::CardAttempts),
+            (velocity.customer_attempts, EuclidValue::CustomerAttempts),
+            (velocity.ip_attempts, EuclidValue::IpAttempts),
+            (velocity.email_attempts, EuclidValue::EmailAttempts),
+            (
+                velocity.customer_distinct_cards,
+                EuclidValue::CustomerDistinctCards,
+            ),
+        ];
+        for (count, to_value) in velocity_values {
+            if let Some(count) = count {
+                let value = to_value(types::NumValue {
+                    number: common_utils::types::MinorUnit::new(i64::from(count)),
+                    refinement: None,
+                });
+                numeric_values.insert(value.get_key(), value);
+            }
+        }
 
         Self {
             atomic_values: enum_values,
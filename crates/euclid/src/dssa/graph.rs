@@ -53,6 +53,12 @@ impl cgraph::NodeViz for dir::DirValue {
             Self::CryptoType(ct) => ct.to_string(),
             Self::RewardType(rt) => rt.to_string(),
             Self::PaymentAmount(amt) => amt.number.to_string(),
+            Self::InstallmentCount(count) => count.number.to_string(),
+            Self::CardAttempts(count) => count.number.to_string(),
+            Self::CustomerAttempts(count) => count.number.to_string(),
+            Self::IpAttempts(count) => count.number.to_string(),
+            Self::EmailAttempts(count) => count.number.to_string(),
+            Self::CustomerDistinctCards(count) => count.number.to_string(),
             Self::PaymentCurrency(curr) => curr.to_string(),
             Self::AuthenticationType(at) => at.to_string(),
             Self::CaptureMethod(cm) => cm.to_string(),
@@ -295,6 +295,20 @@ fn lower_comparison_inner<O: EuclidDirFilter>(
 
         dir::DirKeyKind::PaymentAmount => lower_number!(PaymentAmount, value, comparison),
 
+        dir::DirKeyKind::InstallmentCount => lower_number!(InstallmentCount, value, comparison),
+
+        dir::DirKeyKind::CardAttempts => lower_number!(CardAttempts, value, comparison),
+
+        dir::DirKeyKind::CustomerAttempts => lower_number!(CustomerAttempts, value, comparison),
+
+        dir::DirKeyKind::IpAttempts => lower_number!(IpAttempts, value, comparison),
+
+        dir::DirKeyKind::EmailAttempts => lower_number!(EmailAttempts, value, comparison),
+
+        dir::DirKeyKind::CustomerDistinctCards => {
+            lower_number!(CustomerDistinctCards, value, comparison)
+        }
+
         dir::DirKeyKind::Connector => Err(AnalysisErrorType::InvalidKey(
             dir::DirKeyKind::Connector.to_string(),
         )),
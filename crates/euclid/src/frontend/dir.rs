@@ -253,6 +253,48 @@ pub enum DirKeyKind {
     )]
     #[serde(rename = "amount")]
     PaymentAmount,
+    #[strum(
+        serialize = "installment_count",
+        detailed_message = "Number of installments the payment is split into",
+        props(Category = "Payments")
+    )]
+    #[serde(rename = "installment_count")]
+    InstallmentCount,
+    #[strum(
+        serialize = "card_attempts",
+        detailed_message = "Number of payment attempts made with the same card within the velocity window",
+        props(Category = "Velocity")
+    )]
+    #[serde(rename = "card_attempts")]
+    CardAttempts,
+    #[strum(
+        serialize = "customer_attempts",
+        detailed_message = "Number of payment attempts made by the same customer within the velocity window",
+        props(Category = "Velocity")
+    )]
+    #[serde(rename = "customer_attempts")]
+    CustomerAttempts,
+    #[strum(
+        serialize = "ip_attempts",
+        detailed_message = "Number of payment attempts made from the same IP address within the velocity window",
+        props(Category = "Velocity")
+    )]
+    #[serde(rename = "ip_attempts")]
+    IpAttempts,
+    #[strum(
+        serialize = "email_attempts",
+        detailed_message = "Number of payment attempts made with the same email within the velocity window",
+        props(Category = "Velocity")
+    )]
+    #[serde(rename = "email_attempts")]
+    EmailAttempts,
+    #[strum(
+        serialize = "customer_distinct_cards",
+        detailed_message = "Number of distinct cards used by the same customer within the velocity window",
+        props(Category = "Velocity")
+    )]
+    #[serde(rename = "customer_distinct_cards")]
+    CustomerDistinctCards,
     #[strum(
         serialize = "currency",
         detailed_message = "Currency used for the payment",
@@ -356,6 +398,12 @@ impl DirKeyKind {
             Self::CryptoType => types::DataType::EnumVariant,
             Self::RewardType => types::DataType::EnumVariant,
             Self::PaymentAmount => types::DataType::Number,
+            Self::InstallmentCount => types::DataType::Number,
+            Self::CardAttempts => types::DataType::Number,
+            Self::CustomerAttempts => types::DataType::Number,
+            Self::IpAttempts => types::DataType::Number,
+            Self::EmailAttempts => types::DataType::Number,
+            Self::CustomerDistinctCards => types::DataType::Number,
             Self::PaymentCurrency => types::DataType::EnumVariant,
             Self::AuthenticationType => types::DataType::EnumVariant,
             Self::CaptureMethod => types::DataType::EnumVariant,
@@ -441,6 +489,12 @@ impl DirKeyKind {
                     .collect(),
             ),
             Self::PaymentAmount => None,
+            Self::InstallmentCount => None,
+            Self::CardAttempts => None,
+            Self::CustomerAttempts => None,
+            Self::IpAttempts => None,
+            Self::EmailAttempts => None,
+            Self::CustomerDistinctCards => None,
             Self::PaymentCurrency => Some(
                 enums::PaymentCurrency::iter()
                     .map(DirValue::PaymentCurrency)
@@ -545,6 +599,18 @@ pub enum DirValue {
     GiftCardType(enums::GiftCardType),
     #[serde(rename = "amount")]
     PaymentAmount(types::NumValue),
+    #[serde(rename = "installment_count")]
+    InstallmentCount(types::NumValue),
+    #[serde(rename = "card_attempts")]
+    CardAttempts(types::NumValue),
+    #[serde(rename = "customer_attempts")]
+    CustomerAttempts(types::NumValue),
+    #[serde(rename = "ip_attempts")]
+    IpAttempts(types::NumValue),
+    #[serde(rename = "email_attempts")]
+    EmailAttempts(types::NumValue),
+    #[serde(rename = "customer_distinct_cards")]
+    CustomerDistinctCards(types::NumValue),
     #[serde(rename = "currency")]
     PaymentCurrency(enums::PaymentCurrency),
     #[serde(rename = "authentication_type")]
@@ -587,6 +653,12 @@ impl DirValue {
             Self::AuthenticationType(_) => (DirKeyKind::AuthenticationType, None),
             Self::CaptureMethod(_) => (DirKeyKind::CaptureMethod, None),
             Self::PaymentAmount(_) => (DirKeyKind::PaymentAmount, None),
+            Self::InstallmentCount(_) => (DirKeyKind::InstallmentCount, None),
+            Self::CardAttempts(_) => (DirKeyKind::CardAttempts, None),
+            Self::CustomerAttempts(_) => (DirKeyKind::CustomerAttempts, None),
+            Self::IpAttempts(_) => (DirKeyKind::IpAttempts, None),
+            Self::EmailAttempts(_) => (DirKeyKind::EmailAttempts, None),
+            Self::CustomerDistinctCards(_) => (DirKeyKind::CustomerDistinctCards, None),
             Self::PaymentCurrency(_) => (DirKeyKind::PaymentCurrency, None),
             Self::Connector(_) => (DirKeyKind::Connector, None),
             Self::BankDebitType(_) => (DirKeyKind::BankDebitType, None),
@@ -618,6 +690,12 @@ impl DirValue {
             Self::CaptureMethod(_) => None,
             Self::GiftCardType(_) => None,
             Self::PaymentAmount(_) => None,
+            Self::InstallmentCount(_) => None,
+            Self::CardAttempts(_) => None,
+            Self::CustomerAttempts(_) => None,
+            Self::IpAttempts(_) => None,
+            Self::EmailAttempts(_) => None,
+            Self::CustomerDistinctCards(_) => None,
             Self::PaymentCurrency(_) => None,
             Self::BusinessCountry(_) => None,
             Self::BillingCountry(_) => None,
@@ -646,7 +724,13 @@ impl DirValue {
 
     pub fn get_num_value(&self) -> Option<types::NumValue> {
         match self {
-            Self::PaymentAmount(val) => Some(val.clone()),
+            Self::PaymentAmount(val)
+            | Self::InstallmentCount(val)
+            | Self::CardAttempts(val)
+            | Self::CustomerAttempts(val)
+            | Self::IpAttempts(val)
+            | Self::EmailAttempts(val)
+            | Self::CustomerDistinctCards(val) => Some(val.clone()),
             _ => None,
         }
     }
@@ -225,6 +225,12 @@ fn lower_value(dir_value: dir::DirValue) -> Result<EuclidValue, AnalysisErrorTyp
         dir::DirValue::AuthenticationType(at) => EuclidValue::AuthenticationType(at),
         dir::DirValue::CaptureMethod(cm) => EuclidValue::CaptureMethod(cm),
         dir::DirValue::PaymentAmount(pa) => EuclidValue::PaymentAmount(pa),
+        dir::DirValue::InstallmentCount(ic) => EuclidValue::InstallmentCount(ic),
+        dir::DirValue::CardAttempts(count) => EuclidValue::CardAttempts(count),
+        dir::DirValue::CustomerAttempts(count) => EuclidValue::CustomerAttempts(count),
+        dir::DirValue::IpAttempts(count) => EuclidValue::IpAttempts(count),
+        dir::DirValue::EmailAttempts(count) => EuclidValue::EmailAttempts(count),
+        dir::DirValue::CustomerDistinctCards(count) => EuclidValue::CustomerDistinctCards(count),
         dir::DirValue::PaymentCurrency(pc) => EuclidValue::PaymentCurrency(pc),
         dir::DirValue::BusinessCountry(buc) => EuclidValue::BusinessCountry(buc),
         dir::DirValue::BillingCountry(bic) => EuclidValue::BillingCountry(bic),
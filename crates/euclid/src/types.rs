@@ -50,6 +50,18 @@ pub enum EuclidKey {
     CaptureMethod,
     #[strum(serialize = "amount")]
     PaymentAmount,
+    #[strum(serialize = "installment_count")]
+    InstallmentCount,
+    #[strum(serialize = "card_attempts")]
+    CardAttempts,
+    #[strum(serialize = "customer_attempts")]
+    CustomerAttempts,
+    #[strum(serialize = "ip_attempts")]
+    IpAttempts,
+    #[strum(serialize = "email_attempts")]
+    EmailAttempts,
+    #[strum(serialize = "customer_distinct_cards")]
+    CustomerDistinctCards,
     #[strum(serialize = "currency")]
     PaymentCurrency,
     #[strum(serialize = "country", to_string = "business_country")]
@@ -72,6 +84,12 @@ impl EuclidDirFilter for DummyOutput {
         DirKeyKind::CardBin,
         DirKeyKind::PayLaterType,
         DirKeyKind::PaymentAmount,
+        DirKeyKind::InstallmentCount,
+        DirKeyKind::CardAttempts,
+        DirKeyKind::CustomerAttempts,
+        DirKeyKind::IpAttempts,
+        DirKeyKind::EmailAttempts,
+        DirKeyKind::CustomerDistinctCards,
         DirKeyKind::MetaData,
         DirKeyKind::MandateAcceptanceType,
         DirKeyKind::MandateType,
@@ -130,6 +148,12 @@ impl EuclidKey {
             Self::AuthenticationType => DataType::EnumVariant,
             Self::CaptureMethod => DataType::EnumVariant,
             Self::PaymentAmount => DataType::Number,
+            Self::InstallmentCount => DataType::Number,
+            Self::CardAttempts => DataType::Number,
+            Self::CustomerAttempts => DataType::Number,
+            Self::IpAttempts => DataType::Number,
+            Self::EmailAttempts => DataType::Number,
+            Self::CustomerDistinctCards => DataType::Number,
             Self::PaymentCurrency => DataType::EnumVariant,
             Self::BusinessCountry => DataType::EnumVariant,
             Self::BillingCountry => DataType::EnumVariant,
@@ -248,6 +272,12 @@ pub enum EuclidValue {
     MandateAcceptanceType(enums::MandateAcceptanceType),
     MandateType(enums::MandateType),
     PaymentAmount(NumValue),
+    InstallmentCount(NumValue),
+    CardAttempts(NumValue),
+    CustomerAttempts(NumValue),
+    IpAttempts(NumValue),
+    EmailAttempts(NumValue),
+    CustomerDistinctCards(NumValue),
     PaymentCurrency(enums::Currency),
     BusinessCountry(enums::Country),
     BillingCountry(enums::Country),
@@ -258,7 +288,13 @@ pub enum EuclidValue {
 impl EuclidValue {
     pub fn get_num_value(&self) -> Option<NumValue> {
         match self {
-            Self::PaymentAmount(val) => Some(val.clone()),
+            Self::PaymentAmount(val)
+            | Self::InstallmentCount(val)
+            | Self::CardAttempts(val)
+            | Self::CustomerAttempts(val)
+            | Self::IpAttempts(val)
+            | Self::EmailAttempts(val)
+            | Self::CustomerDistinctCards(val) => Some(val.clone()),
             _ => None,
         }
     }
@@ -276,6 +312,12 @@ impl EuclidValue {
             Self::AuthenticationType(_) => EuclidKey::AuthenticationType,
             Self::CaptureMethod(_) => EuclidKey::CaptureMethod,
             Self::PaymentAmount(_) => EuclidKey::PaymentAmount,
+            Self::InstallmentCount(_) => EuclidKey::InstallmentCount,
+            Self::CardAttempts(_) => EuclidKey::CardAttempts,
+            Self::CustomerAttempts(_) => EuclidKey::CustomerAttempts,
+            Self::IpAttempts(_) => EuclidKey::IpAttempts,
+            Self::EmailAttempts(_) => EuclidKey::EmailAttempts,
+            Self::CustomerDistinctCards(_) => EuclidKey::CustomerDistinctCards,
             Self::PaymentCurrency(_) => EuclidKey::PaymentCurrency,
             Self::BusinessCountry(_) => EuclidKey::BusinessCountry,
             Self::BillingCountry(_) => EuclidKey::BillingCountry,
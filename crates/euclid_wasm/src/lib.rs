@@ -265,6 +265,12 @@ pub fn get_variant_values(key: &str) -> Result<JsValue, JsValue> {
         dir::DirKeyKind::RealTimePaymentType => dir_enums::RealTimePaymentType::VARIANTS,
 
         dir::DirKeyKind::PaymentAmount
+        | dir::DirKeyKind::InstallmentCount
+        | dir::DirKeyKind::CardAttempts
+        | dir::DirKeyKind::CustomerAttempts
+        | dir::DirKeyKind::IpAttempts
+        | dir::DirKeyKind::EmailAttempts
+        | dir::DirKeyKind::CustomerDistinctCards
         | dir::DirKeyKind::Connector
         | dir::DirKeyKind::CardBin
         | dir::DirKeyKind::BusinessLabel
@@ -65,6 +65,17 @@ pub struct Card {
     pub nick_name: Option<Secret<String>>,
 }
 
+/// Network token of a stored card along with a cryptogram generated for a single payment
+#[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize)]
+pub struct NetworkTokenData {
+    pub token_number: cards::CardNumber,
+    pub token_exp_month: Secret<String>,
+    pub token_exp_year: Secret<String>,
+    pub token_cryptogram: Secret<String>,
+    pub eci: Option<String>,
+    pub card_network: Option<common_enums::CardNetwork>,
+}
+
 #[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize)]
 pub enum CardRedirectData {
     Knet {},
@@ -1,4 +1,9 @@
-use common_utils::{self, crypto::Encryptable, id_type, pii, types::MinorUnit};
+use common_utils::{
+    self,
+    crypto::Encryptable,
+    id_type, pii,
+    types::{MinorUnit, SplitPayments},
+};
 use masking::Secret;
 use time::PrimitiveDateTime;
 
@@ -63,4 +68,5 @@ pub struct PaymentIntent {
     pub charges: Option<pii::SecretSerdeValue>,
     pub frm_metadata: Option<pii::SecretSerdeValue>,
     pub customer_details: Option<Encryptable<Secret<serde_json::Value>>>,
+    pub split_payments: Option<SplitPayments>,
 }
@@ -175,6 +175,7 @@ pub struct PaymentAttempt {
     pub charge_id: Option<String>,
     pub client_source: Option<String>,
     pub client_version: Option<String>,
+    pub installments: Option<serde_json::Value>,
 }
 
 impl PaymentAttempt {
@@ -264,6 +265,7 @@ pub struct PaymentAttemptNew {
     pub charge_id: Option<String>,
     pub client_source: Option<String>,
     pub client_version: Option<String>,
+    pub installments: Option<serde_json::Value>,
 }
 
 impl PaymentAttemptNew {
@@ -301,6 +303,7 @@ pub enum PaymentAttemptUpdate {
         fingerprint_id: Option<String>,
         payment_method_billing_address_id: Option<String>,
         updated_by: String,
+        installments: Option<serde_json::Value>,
     },
     UpdateTrackers {
         payment_token: Option<String>,
@@ -346,6 +349,7 @@ pub enum PaymentAttemptUpdate {
         payment_method_id: Option<String>,
         client_source: Option<String>,
         client_version: Option<String>,
+        installments: Option<serde_json::Value>,
     },
     RejectUpdate {
         status: storage_enums::AttemptStatus,
@@ -530,6 +534,7 @@ impl behaviour::Conversion for PaymentIntent {
             charges: self.charges,
             frm_metadata: self.frm_metadata,
             customer_details: self.customer_details.map(Encryption::from),
+            split_payments: self.split_payments,
         })
     }
 
@@ -591,6 +596,7 @@ impl behaviour::Conversion for PaymentIntent {
                     .customer_details
                     .async_lift(inner_decrypt)
                     .await?,
+                split_payments: storage_model.split_payments,
             })
         }
         .await
@@ -645,6 +651,7 @@ impl behaviour::Conversion for PaymentIntent {
             charges: self.charges,
             frm_metadata: self.frm_metadata,
             customer_details: self.customer_details.map(Encryption::from),
+            split_payments: self.split_payments,
         })
     }
 }
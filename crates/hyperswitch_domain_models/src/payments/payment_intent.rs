@@ -4,7 +4,7 @@ use common_utils::{
     crypto::Encryptable,
     id_type,
     pii::{self, Email},
-    types::MinorUnit,
+    types::{MinorUnit, SplitPayments},
 };
 use masking::{Deserialize, Secret};
 use serde::Serialize;
@@ -134,6 +134,7 @@ pub struct PaymentIntentNew {
     pub request_external_three_ds_authentication: Option<bool>,
     pub charges: Option<pii::SecretSerdeValue>,
     pub customer_details: Option<Encryptable<Secret<serde_json::Value>>>,
+    pub split_payments: Option<SplitPayments>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -187,6 +188,7 @@ pub enum PaymentIntentUpdate {
         order_details: Option<Vec<pii::SecretSerdeValue>>,
         metadata: Option<pii::SecretSerdeValue>,
         frm_metadata: Option<pii::SecretSerdeValue>,
+        split_payments: Option<SplitPayments>,
         payment_confirm_source: Option<storage_enums::PaymentSource>,
         updated_by: String,
         fingerprint_id: Option<String>,
@@ -269,6 +271,7 @@ pub struct PaymentIntentUpdateInternal {
     pub session_expiry: Option<PrimitiveDateTime>,
     pub request_external_three_ds_authentication: Option<bool>,
     pub frm_metadata: Option<pii::SecretSerdeValue>,
+    pub split_payments: Option<SplitPayments>,
     pub customer_details: Option<Encryptable<Secret<serde_json::Value>>>,
 }
 
@@ -297,6 +300,7 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 session_expiry,
                 request_external_three_ds_authentication,
                 frm_metadata,
+                split_payments,
                 customer_details,
             } => Self {
                 amount: Some(amount),
@@ -321,6 +325,7 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 session_expiry,
                 request_external_three_ds_authentication,
                 frm_metadata,
+                split_payments,
                 customer_details,
                 ..Default::default()
             },
@@ -561,6 +566,7 @@ impl From<PaymentIntentUpdate> for DieselPaymentIntentUpdate {
                 session_expiry,
                 request_external_three_ds_authentication,
                 frm_metadata,
+                split_payments,
                 customer_details,
             } => Self::Update {
                 amount,
@@ -584,6 +590,7 @@ impl From<PaymentIntentUpdate> for DieselPaymentIntentUpdate {
                 session_expiry,
                 request_external_three_ds_authentication,
                 frm_metadata,
+                split_payments,
                 customer_details: customer_details.map(Encryption::from),
             },
             PaymentIntentUpdate::PaymentAttemptAndAttemptCountUpdate {
@@ -10,7 +10,7 @@ use masking::Secret;
 use serde::Serialize;
 use serde_with::serde_as;
 
-use super::payment_method_data::PaymentMethodData;
+use super::payment_method_data::{NetworkTokenData, PaymentMethodData};
 use crate::{
     errors::api_error_response::ApiErrorResponse,
     mandates, payments,
@@ -59,6 +59,10 @@ pub struct PaymentsAuthorizeData {
     pub metadata: Option<pii::SecretSerdeValue>,
     pub authentication_data: Option<AuthenticationData>,
     pub charges: Option<PaymentCharges>,
+    pub installments: Option<api_models::payments::Installments>,
+    pub split_payments: Option<common_types::SplitPayments>,
+    /// Network token and cryptogram to be sent instead of the card number, when available
+    pub network_token_data: Option<NetworkTokenData>,
 
     // New amount for amount frame work
     pub minor_amount: MinorUnit,
@@ -396,6 +400,7 @@ pub struct BrowserInformation {
     pub ip_address: Option<std::net::IpAddr>,
     pub accept_header: Option<String>,
     pub user_agent: Option<String>,
+    pub device_fingerprint: Option<String>,
 }
 
 #[derive(Debug, Clone, Default, Serialize)]
@@ -505,6 +510,8 @@ pub struct RefundsData {
     pub browser_info: Option<BrowserInformation>,
     /// Charges associated with the payment
     pub charges: Option<ChargeRefunds>,
+    /// Amounts to be reversed from the recipient accounts of a split payment
+    pub split_refunds: Option<common_types::SplitRefunds>,
 
     // New amount for amount frame work
     pub minor_payment_amount: MinorUnit,
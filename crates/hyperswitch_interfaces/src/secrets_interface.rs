@@ -1,5 +1,7 @@
 //! Secrets management interface
 
+pub mod database_credentials;
+
 pub mod secret_handler;
 
 pub mod secret_state;
@@ -0,0 +1,52 @@
+//! Module containing the provider of the refreshed credentials of the database users
+
+use std::{fmt, sync::Arc};
+
+use common_utils::errors::CustomResult;
+use masking::Secret;
+use storage_impl::database::credentials::{CredentialsError, CredentialsProvider};
+
+use crate::secrets_interface::SecretManagementInterface;
+
+/// Re-fetches the password of a database user through the secrets manager
+pub struct SecretManagedCredentials {
+    secret_management_client: Arc<dyn SecretManagementInterface>,
+    /// The password as configured, before it is resolved by the secrets manager
+    secured_password: Secret<String>,
+}
+
+impl SecretManagedCredentials {
+    /// Get the provider of the password of a database user, present only when the credentials of
+    /// the database are refreshed periodically
+    pub fn for_database(
+        secured_password: &Secret<String>,
+        credentials_refresh_interval: Option<u64>,
+        secret_management_client: &Arc<dyn SecretManagementInterface>,
+    ) -> Option<Arc<dyn CredentialsProvider>> {
+        credentials_refresh_interval.map(|_| {
+            let provider: Arc<dyn CredentialsProvider> = Arc::new(Self {
+                secret_management_client: Arc::clone(secret_management_client),
+                secured_password: secured_password.clone(),
+            });
+            provider
+        })
+    }
+}
+
+impl fmt::Debug for SecretManagedCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SecretManagedCredentials")
+            .field("secured_password", &self.secured_password)
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait::async_trait]
+impl CredentialsProvider for SecretManagedCredentials {
+    async fn get_password(&self) -> CustomResult<Secret<String>, CredentialsError> {
+        self.secret_management_client
+            .get_secret(self.secured_password.clone())
+            .await
+            .map_err(|error| error.change_context(CredentialsError))
+    }
+}
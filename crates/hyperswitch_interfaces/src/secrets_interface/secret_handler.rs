@@ -1,6 +1,8 @@
 //! Module containing trait for raw secret retrieval
 
 use common_utils::errors::CustomResult;
+use masking::Secret;
+use redis_interface::RedisSettings;
 
 use crate::secrets_interface::{
     secret_state::{RawSecret, SecretStateContainer, SecuredSecret},
@@ -19,3 +21,36 @@ where
         kms_client: &dyn SecretManagementInterface,
     ) -> CustomResult<SecretStateContainer<Self, RawSecret>, SecretsManagementError>;
 }
+
+#[async_trait::async_trait]
+impl SecretsHandler for RedisSettings {
+    async fn convert_to_raw_secret(
+        value: SecretStateContainer<Self, SecuredSecret>,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<SecretStateContainer<Self, RawSecret>, SecretsManagementError> {
+        let redis = value.get_inner();
+        let password =
+            get_optional_secret(redis.password.clone(), secret_management_client).await?;
+        let tls_client_key =
+            get_optional_secret(redis.tls_client_key.clone(), secret_management_client).await?;
+        let sentinel_password =
+            get_optional_secret(redis.sentinel_password.clone(), secret_management_client).await?;
+
+        Ok(value.transition_state(|redis| Self {
+            password,
+            tls_client_key,
+            sentinel_password,
+            ..redis
+        }))
+    }
+}
+
+async fn get_optional_secret(
+    secret: Option<Secret<String>>,
+    secret_management_client: &dyn SecretManagementInterface,
+) -> CustomResult<Option<Secret<String>>, SecretsManagementError> {
+    match secret {
+        Some(secret) => secret_management_client.get_secret(secret).await.map(Some),
+        None => Ok(None),
+    }
+}
@@ -24,6 +24,35 @@ impl IntoContext for BackendInput {
 
         ctx.push(dir::DirValue::PaymentCurrency(self.payment.currency));
 
+        if let Some(installment_count) = self.payment.installment_count {
+            ctx.push(dir::DirValue::InstallmentCount(NumValue {
+                number: common_utils::types::MinorUnit::new(i64::from(installment_count)),
+                refinement: None,
+            }));
+        }
+
+        let velocity_values: [(Option<u32>, fn(NumValue) -> dir::DirValue); 5] = [
+            (self.velocity.card_attempts, dir::DirValue::CardAttempts),
+            (
+                self.velocity.customer_attempts,
+                dir::DirValue::CustomerAttempts,
+            ),
+            (self.velocity.ip_attempts, dir::DirValue::IpAttempts),
+            (self.velocity.email_attempts, dir::DirValue::EmailAttempts),
+            (
+                self.velocity.customer_distinct_cards,
+                dir::DirValue::CustomerDistinctCards,
+            ),
+        ];
+        for (count, to_value) in velocity_values {
+            if let Some(count) = count {
+                ctx.push(to_value(NumValue {
+                    number: common_utils::types::MinorUnit::new(i64::from(count)),
+                    refinement: None,
+                }));
+            }
+        }
+
         if let Some(auth_type) = self.payment.authentication_type {
             ctx.push(dir::DirValue::AuthenticationType(auth_type));
         }
@@ -66,6 +66,7 @@ Never share your secret api keys. Keep them guarded and secure.
         (name = "payment link", description = "Create payment link"),
         (name = "Routing", description = "Create and manage routing configurations"),
         (name = "Event", description = "Manage events"),
+        (name = "Subscriptions", description = "Create and manage subscription plans and subscriptions"),
     ),
     // The paths will be displayed in the same order as they are registered here
     paths(
@@ -108,6 +109,13 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::gsm::get_gsm_rule,
         routes::gsm::update_gsm_rule,
         routes::gsm::delete_gsm_rule,
+        routes::gsm::list_gsm_rules,
+        routes::gsm::import_gsm_rules,
+        routes::gsm::export_gsm_rules,
+        routes::gsm::create_unified_translation,
+        routes::gsm::update_unified_translation,
+        routes::gsm::delete_unified_translation,
+        routes::gsm::list_unified_translations,
 
         // Routes for mandates
         routes::mandates::get_mandate,
@@ -169,6 +177,10 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payouts::payouts_list,
         routes::payouts::payouts_filter,
         routes::payouts::payouts_confirm,
+        routes::payouts::payout_batch_create,
+        routes::payouts::payout_batch_retrieve,
+        routes::payouts::payout_batch_items_list,
+        routes::payouts::payout_batch_cancel,
 
         // Routes for api keys
         routes::api_keys::api_key_create,
@@ -183,11 +195,26 @@ Never share your secret api keys. Keep them guarded and secure.
 
         // Routes for poll apis
         routes::poll::retrieve_poll_status,
+
+        // Routes for subscriptions
+        routes::subscriptions::create_subscription_plan,
+        routes::subscriptions::retrieve_subscription_plan,
+        routes::subscriptions::list_subscription_plans,
+        routes::subscriptions::update_subscription_plan,
+        routes::subscriptions::create_subscription,
+        routes::subscriptions::retrieve_subscription,
+        routes::subscriptions::list_subscriptions,
+        routes::subscriptions::cancel_subscription,
+        routes::subscriptions::list_subscription_invoices,
     ),
     components(schemas(
         common_utils::types::MinorUnit,
         common_utils::link_utils::GenericLinkUiConfig,
         common_utils::link_utils::EnabledPaymentMethod,
+        common_utils::link_utils::LinkTemplateConfig,
+        common_utils::types::PaymentSplit,
+        common_utils::types::RefundSplit,
+        common_utils::types::RetrySchedule,
         api_models::refunds::RefundRequest,
         api_models::refunds::RefundType,
         api_models::refunds::RefundResponse,
@@ -279,6 +306,19 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::gsm::GsmDeleteResponse,
         api_models::gsm::GsmResponse,
         api_models::gsm::GsmDecision,
+        api_models::gsm::GsmListRequest,
+        api_models::gsm::GsmFileFormat,
+        api_models::gsm::GsmImportRequest,
+        api_models::gsm::GsmImportAction,
+        api_models::gsm::GsmImportRowResult,
+        api_models::gsm::GsmImportResponse,
+        api_models::gsm::GsmExportRequest,
+        api_models::gsm::UnifiedTranslationsCreateRequest,
+        api_models::gsm::UnifiedTranslationsUpdateRequest,
+        api_models::gsm::UnifiedTranslationsDeleteRequest,
+        api_models::gsm::UnifiedTranslationsListRequest,
+        api_models::gsm::UnifiedTranslationsResponse,
+        api_models::gsm::UnifiedTranslationsDeleteResponse,
         api_models::payments::AddressDetails,
         api_models::payments::BankDebitData,
         api_models::payments::AliPayQr,
@@ -314,6 +354,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::BankRedirectBilling,
         api_models::payments::ConnectorMetadata,
         api_models::payments::FeatureMetadata,
+        api_models::payments::SmartRetryDetails,
         api_models::payments::ApplepayConnectorMetadataRequest,
         api_models::payments::SessionTokenInfo,
         api_models::payments::PaymentProcessingDetailsAt,
@@ -481,9 +522,25 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payouts::PayoutLinkResponse,
         api_models::payouts::Bank,
         api_models::payouts::PayoutCreatePayoutLinkConfig,
+        api_models::payouts::PayoutBatchCreateRequest,
+        api_models::payouts::PayoutBatchResponse,
+        api_models::payouts::PayoutBatchItemResponse,
+        api_models::payouts::PayoutBatchItemListResponse,
         api_models::enums::PayoutEntityType,
         api_models::enums::PayoutSendPriority,
         api_models::enums::PayoutStatus,
+        api_models::enums::PayoutBatchStatus,
+        api_models::enums::PayoutBatchItemStatus,
+        api_models::subscriptions::SubscriptionPlanCreateRequest,
+        api_models::subscriptions::SubscriptionPlanUpdateRequest,
+        api_models::subscriptions::SubscriptionPlanResponse,
+        api_models::subscriptions::SubscriptionCreateRequest,
+        api_models::subscriptions::SubscriptionResponse,
+        api_models::subscriptions::SubscriptionCancelRequest,
+        api_models::subscriptions::SubscriptionInvoiceResponse,
+        api_models::enums::SubscriptionInterval,
+        api_models::enums::SubscriptionStatus,
+        api_models::enums::SubscriptionInvoiceStatus,
         api_models::enums::PayoutType,
         api_models::enums::TransactionType,
         api_models::payments::FrmMessage,
@@ -550,7 +607,10 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::WebhookDeliveryAttempt,
         api_models::enums::PaymentChargeType,
         api_models::enums::StripeChargeType,
+        api_models::enums::InstallmentPlanType,
+        api_models::enums::InstallmentFinancingType,
         api_models::payments::PaymentChargeRequest,
+        api_models::payments::Installments,
         api_models::payments::PaymentChargeResponse,
         api_models::refunds::ChargeRefunds,
         api_models::payments::CustomerDetailsResponse,
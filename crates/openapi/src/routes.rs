@@ -16,6 +16,7 @@ pub mod payouts;
 pub mod poll;
 pub mod refunds;
 pub mod routing;
+pub mod subscriptions;
 pub mod webhook_events;
 
 pub use self::{
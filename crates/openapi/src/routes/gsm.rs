@@ -73,3 +73,137 @@ pub async fn update_gsm_rule() {}
     security(("admin_api_key" = [])),
 )]
 pub async fn delete_gsm_rule() {}
+
+/// Gsm - List
+///
+/// Lists the Gsm Rules, filtered by connector, flow and decision
+#[utoipa::path(
+    post,
+    path = "/gsm/list",
+    request_body(
+        content = GsmListRequest,
+    ),
+    responses(
+        (status = 200, description = "Gsm rules listed", body = Vec<GsmResponse>),
+        (status = 400, description = "Invalid filters")
+    ),
+    tag = "Gsm",
+    operation_id = "List Gsm Rules",
+    security(("admin_api_key" = [])),
+)]
+pub async fn list_gsm_rules() {}
+
+/// Gsm - Import
+///
+/// Imports Gsm Rules in bulk from a CSV or JSON file. With `dry_run`, the rules are only
+/// validated and the changes they would make are returned.
+#[utoipa::path(
+    post,
+    path = "/gsm/import",
+    request_body(
+        content = GsmImportRequest,
+    ),
+    responses(
+        (status = 200, description = "Gsm rules imported", body = GsmImportResponse),
+        (status = 400, description = "Invalid Gsm rules")
+    ),
+    tag = "Gsm",
+    operation_id = "Import Gsm Rules",
+    security(("admin_api_key" = [])),
+)]
+pub async fn import_gsm_rules() {}
+
+/// Gsm - Export
+///
+/// Exports the Gsm Rules, filtered by connector, flow and decision, as a CSV or JSON file
+#[utoipa::path(
+    post,
+    path = "/gsm/export",
+    request_body(
+        content = GsmExportRequest,
+    ),
+    responses(
+        (status = 200, description = "Gsm rules exported", content_type = "text/csv"),
+        (status = 400, description = "Invalid filters")
+    ),
+    tag = "Gsm",
+    operation_id = "Export Gsm Rules",
+    security(("admin_api_key" = [])),
+)]
+pub async fn export_gsm_rules() {}
+
+/// Unified Translation - Create
+///
+/// Creates a translation of a unified error message
+#[utoipa::path(
+    post,
+    path = "/gsm/translations",
+    request_body(
+        content = UnifiedTranslationsCreateRequest,
+    ),
+    responses(
+        (status = 200, description = "Translation created", body = UnifiedTranslationsResponse),
+        (status = 400, description = "Missing Mandatory fields")
+    ),
+    tag = "Gsm",
+    operation_id = "Create Unified Translation",
+    security(("admin_api_key" = [])),
+)]
+pub async fn create_unified_translation() {}
+
+/// Unified Translation - Update
+///
+/// Updates a translation of a unified error message
+#[utoipa::path(
+    post,
+    path = "/gsm/translations/update",
+    request_body(
+        content = UnifiedTranslationsUpdateRequest,
+    ),
+    responses(
+        (status = 200, description = "Translation updated", body = UnifiedTranslationsResponse),
+        (status = 400, description = "Missing Mandatory fields")
+    ),
+    tag = "Gsm",
+    operation_id = "Update Unified Translation",
+    security(("admin_api_key" = [])),
+)]
+pub async fn update_unified_translation() {}
+
+/// Unified Translation - Delete
+///
+/// Deletes a translation of a unified error message
+#[utoipa::path(
+    post,
+    path = "/gsm/translations/delete",
+    request_body(
+        content = UnifiedTranslationsDeleteRequest,
+    ),
+    responses(
+        (status = 200, description = "Translation deleted", body = UnifiedTranslationsDeleteResponse),
+        (status = 400, description = "Missing Mandatory fields")
+    ),
+    tag = "Gsm",
+    operation_id = "Delete Unified Translation",
+    security(("admin_api_key" = [])),
+)]
+pub async fn delete_unified_translation() {}
+
+/// Unified Translation - List
+///
+/// Lists the translations of the unified error messages, filtered by unified code and locale
+#[utoipa::path(
+    post,
+    path = "/gsm/translations/list",
+    request_body(
+        content = UnifiedTranslationsListRequest,
+    ),
+    responses(
+        (status = 200, description = "Translations listed", body = Vec<UnifiedTranslationsResponse>),
+        (status = 400, description = "Invalid filters")
+    ),
+    tag = "Gsm",
+    operation_id = "List Unified Translations",
+    security(("admin_api_key" = [])),
+)]
+pub async fn list_unified_translations() {}
@@ -129,3 +129,72 @@ pub async fn payouts_filter() {}
     security(("api_key" = []))
 )]
 pub async fn payouts_confirm() {}
+
+/// Payouts - Create batch
+#[utoipa::path(
+    post,
+    path = "/payouts/batches",
+    request_body=PayoutBatchCreateRequest,
+    responses(
+        (status = 200, description = "Payout batch created", body = PayoutBatchResponse),
+        (status = 400, description = "Invalid payout batch file")
+    ),
+    tag = "Payouts",
+    operation_id = "Create a Payout Batch",
+    security(("api_key" = []))
+)]
+pub async fn payout_batch_create() {}
+
+/// Payouts - Retrieve batch
+#[utoipa::path(
+    get,
+    path = "/payouts/batches/{batch_id}",
+    params(
+        ("batch_id" = String, Path, description = "The identifier for the payout batch")
+    ),
+    responses(
+        (status = 200, description = "Payout batch retrieved", body = PayoutBatchResponse),
+        (status = 404, description = "Payout batch does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Retrieve a Payout Batch",
+    security(("api_key" = []))
+)]
+pub async fn payout_batch_retrieve() {}
+
+/// Payouts - List batch items
+#[utoipa::path(
+    get,
+    path = "/payouts/batches/{batch_id}/items",
+    params(
+        ("batch_id" = String, Path, description = "The identifier for the payout batch"),
+        ("status" = Option<PayoutBatchItemStatus>, Query, description = "Only list the rows with this status"),
+        ("limit" = Option<u32>, Query, description = "The maximum number of rows to return"),
+        ("offset" = Option<u32>, Query, description = "The number of rows to skip")
+    ),
+    responses(
+        (status = 200, description = "Payout batch items listed", body = PayoutBatchItemListResponse),
+        (status = 404, description = "Payout batch does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "List Payout Batch Items",
+    security(("api_key" = []))
+)]
+pub async fn payout_batch_items_list() {}
+
+/// Payouts - Cancel batch
+#[utoipa::path(
+    post,
+    path = "/payouts/batches/{batch_id}/cancel",
+    params(
+        ("batch_id" = String, Path, description = "The identifier for the payout batch")
+    ),
+    responses(
+        (status = 200, description = "Payout batch cancelled", body = PayoutBatchResponse),
+        (status = 400, description = "Payout batch cannot be cancelled")
+    ),
+    tag = "Payouts",
+    operation_id = "Cancel a Payout Batch",
+    security(("api_key" = []))
+)]
+pub async fn payout_batch_cancel() {}
@@ -0,0 +1,169 @@
+/// Subscriptions - Create Plan
+///
+/// Creates a plan, with the amount charged to the subscriptions to the plan for every billing
+/// interval
+#[utoipa::path(
+    post,
+    path = "/subscriptions/plans",
+    request_body = SubscriptionPlanCreateRequest,
+    responses(
+        (status = 200, description = "Subscription plan created", body = SubscriptionPlanResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Create a Subscription Plan",
+    security(("api_key" = []))
+)]
+pub async fn create_subscription_plan() {}
+
+/// Subscriptions - Retrieve Plan
+#[utoipa::path(
+    get,
+    path = "/subscriptions/plans/{plan_id}",
+    params(
+        ("plan_id" = String, Path, description = "The identifier for the subscription plan")
+    ),
+    responses(
+        (status = 200, description = "Subscription plan retrieved", body = SubscriptionPlanResponse),
+        (status = 404, description = "Subscription plan does not exist in our records")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Retrieve a Subscription Plan",
+    security(("api_key" = []))
+)]
+pub async fn retrieve_subscription_plan() {}
+
+/// Subscriptions - List Plans
+#[utoipa::path(
+    get,
+    path = "/subscriptions/plans",
+    params(
+        ("active" = Option<bool>, Query, description = "Only list the active plans, or the inactive ones"),
+        ("limit" = Option<u32>, Query, description = "The maximum number of plans to return"),
+        ("offset" = Option<u32>, Query, description = "The number of plans to skip")
+    ),
+    responses(
+        (status = 200, description = "Subscription plans listed", body = Vec<SubscriptionPlanResponse>)
+    ),
+    tag = "Subscriptions",
+    operation_id = "List Subscription Plans",
+    security(("api_key" = []))
+)]
+pub async fn list_subscription_plans() {}
+
+/// Subscriptions - Update Plan
+///
+/// Updates the name, description, metadata of a plan, or whether new subscriptions can be created
+/// for it. The amount and billing interval of a plan cannot be updated.
+#[utoipa::path(
+    post,
+    path = "/subscriptions/plans/{plan_id}",
+    params(
+        ("plan_id" = String, Path, description = "The identifier for the subscription plan")
+    ),
+    request_body = SubscriptionPlanUpdateRequest,
+    responses(
+        (status = 200, description = "Subscription plan updated", body = SubscriptionPlanResponse),
+        (status = 404, description = "Subscription plan does not exist in our records")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Update a Subscription Plan",
+    security(("api_key" = []))
+)]
+pub async fn update_subscription_plan() {}
+
+/// Subscriptions - Create
+///
+/// Subscribes a customer to a plan. The invoices of the subscription are charged off-session with
+/// the given mandate of the customer, at the start of every billing period.
+#[utoipa::path(
+    post,
+    path = "/subscriptions",
+    request_body = SubscriptionCreateRequest,
+    responses(
+        (status = 200, description = "Subscription created", body = SubscriptionResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Subscription plan, customer or mandate does not exist in our records")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Create a Subscription",
+    security(("api_key" = []))
+)]
+pub async fn create_subscription() {}
+
+/// Subscriptions - Retrieve
+#[utoipa::path(
+    get,
+    path = "/subscriptions/{subscription_id}",
+    params(
+        ("subscription_id" = String, Path, description = "The identifier for the subscription")
+    ),
+    responses(
+        (status = 200, description = "Subscription retrieved", body = SubscriptionResponse),
+        (status = 404, description = "Subscription does not exist in our records")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Retrieve a Subscription",
+    security(("api_key" = []))
+)]
+pub async fn retrieve_subscription() {}
+
+/// Subscriptions - List
+#[utoipa::path(
+    get,
+    path = "/subscriptions",
+    params(
+        ("customer_id" = Option<String>, Query, description = "Only list the subscriptions of this customer"),
+        ("plan_id" = Option<String>, Query, description = "Only list the subscriptions to this plan"),
+        ("status" = Option<SubscriptionStatus>, Query, description = "Only list the subscriptions with this status"),
+        ("limit" = Option<u32>, Query, description = "The maximum number of subscriptions to return"),
+        ("offset" = Option<u32>, Query, description = "The number of subscriptions to skip")
+    ),
+    responses(
+        (status = 200, description = "Subscriptions listed", body = Vec<SubscriptionResponse>)
+    ),
+    tag = "Subscriptions",
+    operation_id = "List Subscriptions",
+    security(("api_key" = []))
+)]
+pub async fn list_subscriptions() {}
+
+/// Subscriptions - Cancel
+///
+/// Cancels a subscription, either right away or at the end of its current billing period
+#[utoipa::path(
+    post,
+    path = "/subscriptions/{subscription_id}/cancel",
+    params(
+        ("subscription_id" = String, Path, description = "The identifier for the subscription")
+    ),
+    request_body = SubscriptionCancelRequest,
+    responses(
+        (status = 200, description = "Subscription cancelled", body = SubscriptionResponse),
+        (status = 404, description = "Subscription does not exist in our records"),
+        (status = 412, description = "Subscription is already cancelled, or the payment for its invoice is being processed")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Cancel a Subscription",
+    security(("api_key" = []))
+)]
+pub async fn cancel_subscription() {}
+
+/// Subscriptions - List Invoices
+#[utoipa::path(
+    get,
+    path = "/subscriptions/{subscription_id}/invoices",
+    params(
+        ("subscription_id" = String, Path, description = "The identifier for the subscription"),
+        ("limit" = Option<u32>, Query, description = "The maximum number of invoices to return"),
+        ("offset" = Option<u32>, Query, description = "The number of invoices to skip")
+    ),
+    responses(
+        (status = 200, description = "Subscription invoices listed", body = Vec<SubscriptionInvoiceResponse>),
+        (status = 404, description = "Subscription does not exist in our records")
+    ),
+    tag = "Subscriptions",
+    operation_id = "List Subscription Invoices",
+    security(("api_key" = []))
+)]
+pub async fn list_subscription_invoices() {}
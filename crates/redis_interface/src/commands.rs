@@ -15,7 +15,9 @@ use common_utils::{
 };
 use error_stack::{report, ResultExt};
 use fred::{
-    interfaces::{HashesInterface, KeysInterface, SetsInterface, StreamsInterface},
+    interfaces::{
+        HashesInterface, KeysInterface, SetsInterface, SortedSetsInterface, StreamsInterface,
+    },
     prelude::RedisErrorKind,
     types::{
         Expiration, FromRedis, MultipleIDs, MultipleKeys, MultipleOrderedPairs, MultipleStrings,
@@ -458,6 +460,18 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::JsonDeserializationFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn delete_hash_field(
+        &self,
+        key: &str,
+        field: &str,
+    ) -> CustomResult<DelReply, errors::RedisError> {
+        self.pool
+            .hdel(self.add_prefix(key), field)
+            .await
+            .change_context(errors::RedisError::DeleteFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn sadd<V>(
         &self,
@@ -474,6 +488,51 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::SetAddMembersFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn zadd<V>(
+        &self,
+        key: &str,
+        score: f64,
+        member: V,
+    ) -> CustomResult<(), errors::RedisError>
+    where
+        V: TryInto<RedisValue> + Debug + Send,
+        V::Error: Into<fred::error::RedisError> + Send,
+    {
+        self.pool
+            .zadd(
+                self.add_prefix(key),
+                None,
+                None,
+                false,
+                false,
+                (score, member),
+            )
+            .await
+            .change_context(errors::RedisError::SortedSetAddMembersFailed)
+    }
+
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn zremrangebyscore(
+        &self,
+        key: &str,
+        min: f64,
+        max: f64,
+    ) -> CustomResult<usize, errors::RedisError> {
+        self.pool
+            .zremrangebyscore(self.add_prefix(key), min, max)
+            .await
+            .change_context(errors::RedisError::SortedSetRemoveMembersFailed)
+    }
+
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn zcard(&self, key: &str) -> CustomResult<usize, errors::RedisError> {
+        self.pool
+            .zcard(self.add_prefix(key))
+            .await
+            .change_context(errors::RedisError::SortedSetGetCardinalityFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn stream_append_entry<F>(
         &self,
@@ -54,6 +54,12 @@ pub enum RedisError {
     SetAddMembersFailed,
     #[error("Failed to get hash field in Redis")]
     GetHashFieldFailed,
+    #[error("Failed to add members to sorted set in Redis")]
+    SortedSetAddMembersFailed,
+    #[error("Failed to remove members from sorted set in Redis")]
+    SortedSetRemoveMembersFailed,
+    #[error("Failed to get sorted set cardinality in Redis")]
+    SortedSetGetCardinalityFailed,
     #[error("The requested value was not found in Redis")]
     NotFound,
     #[error("Invalid RedisEntryId provided")]
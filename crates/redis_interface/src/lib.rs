@@ -26,6 +26,7 @@ use common_utils::errors::CustomResult;
 use error_stack::ResultExt;
 pub use fred::interfaces::PubsubInterface;
 use fred::{interfaces::ClientLike, prelude::EventInterface};
+use masking::PeekInterface;
 
 pub use self::types::*;
 
@@ -98,6 +99,69 @@ impl std::ops::Deref for SubscriberClient {
     }
 }
 
+/// Builds the TLS configuration of the connections, trusting the configured CA certificate in
+/// addition to the system roots and presenting the client certificate, if any
+fn get_tls_config(
+    conf: &RedisSettings,
+) -> CustomResult<Option<fred::types::TlsConfig>, errors::RedisError> {
+    if !conf.use_tls {
+        return Ok(None);
+    }
+
+    let mut builder = native_tls::TlsConnector::builder();
+    if let Some(ca_cert) = &conf.tls_ca_cert {
+        let certificate = native_tls::Certificate::from_pem(ca_cert.as_bytes()).change_context(
+            errors::RedisError::InvalidConfiguration("Invalid Redis `tls_ca_cert`".into()),
+        )?;
+        builder.add_root_certificate(certificate);
+    }
+    if let (Some(client_cert), Some(client_key)) = (&conf.tls_client_cert, &conf.tls_client_key) {
+        let identity =
+            native_tls::Identity::from_pkcs8(client_cert.as_bytes(), client_key.peek().as_bytes())
+                .change_context(errors::RedisError::InvalidConfiguration(
+                    "Invalid Redis `tls_client_cert` or `tls_client_key`".into(),
+                ))?;
+        builder.identity(identity);
+    }
+
+    let connector = builder
+        .build()
+        .change_context(errors::RedisError::RedisConnectionError)
+        .attach_printable("Failed to build the TLS connector")?;
+    Ok(Some(fred::types::TlsConfig::from(
+        fred::types::TlsConnector::from(connector),
+    )))
+}
+
+/// Builds the configuration for discovering the master through the Sentinel nodes
+fn get_sentinel_server_config(
+    conf: &RedisSettings,
+) -> CustomResult<fred::types::ServerConfig, errors::RedisError> {
+    let hosts = conf
+        .sentinel_urls
+        .iter()
+        .map(|url| {
+            url.rsplit_once(':')
+                .and_then(|(host, port)| Some(fred::types::Server::new(host, port.parse().ok()?)))
+                .ok_or_else(|| {
+                    errors::RedisError::InvalidConfiguration(format!(
+                        "Invalid Redis sentinel url `{url}`, expected `host:port`"
+                    ))
+                })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(fred::types::ServerConfig::Sentinel {
+        hosts,
+        service_name: conf.sentinel_service_name.clone(),
+        username: conf.sentinel_username.clone(),
+        password: conf
+            .sentinel_password
+            .as_ref()
+            .map(|password| password.peek().clone()),
+    })
+}
+
 impl RedisConnectionPool {
     /// Create a new Redis connection
     pub async fn new(conf: &RedisSettings) -> CustomResult<Self, errors::RedisError> {
@@ -122,6 +186,16 @@ impl RedisConnectionPool {
         let mut config = fred::types::RedisConfig::from_url(&redis_connection_url)
             .change_context(errors::RedisError::RedisConnectionError)?;
 
+        config.username.clone_from(&conf.username);
+        config.password = conf
+            .password
+            .as_ref()
+            .map(|password| password.peek().clone());
+        config.tls = get_tls_config(conf)?;
+        if conf.sentinel_enabled {
+            config.server = get_sentinel_server_config(conf)?;
+        }
+
         let perf = fred::types::PerformanceConfig {
             auto_pipeline: conf.auto_pipeline,
             default_command_timeout: std::time::Duration::from_secs(conf.default_command_timeout),
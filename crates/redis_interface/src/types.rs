@@ -5,6 +5,7 @@
 
 use common_utils::errors::CustomResult;
 use fred::types::RedisValue as FredRedisValue;
+use masking::Secret;
 
 use crate::errors;
 
@@ -57,6 +58,26 @@ pub struct RedisSettings {
     pub default_command_timeout: u64,
     pub max_feed_count: u64,
     pub unresponsive_timeout: u64,
+    /// Username of the ACL user to authenticate as, the `default` user is used if not specified
+    pub username: Option<String>,
+    /// Password of the ACL user, or of the `default` user if no username is specified
+    pub password: Option<Secret<String>>,
+    pub use_tls: bool,
+    /// PEM encoded certificate of a CA to trust in addition to the system roots
+    pub tls_ca_cert: Option<String>,
+    /// PEM encoded client certificate, for servers requiring mutual TLS
+    pub tls_client_cert: Option<String>,
+    /// PEM encoded PKCS #8 private key of the client certificate
+    pub tls_client_key: Option<Secret<String>>,
+    /// Whether the Redis master is discovered through Sentinel, `host` and `port` are ignored
+    /// when enabled
+    pub sentinel_enabled: bool,
+    /// Addresses of the Sentinel nodes, in the `host:port` format
+    pub sentinel_urls: Vec<String>,
+    /// Name of the master monitored by the Sentinel nodes
+    pub sentinel_service_name: String,
+    pub sentinel_username: Option<String>,
+    pub sentinel_password: Option<Secret<String>>,
 }
 
 impl RedisSettings {
@@ -76,6 +97,65 @@ impl RedisSettings {
             ))
         })?;
 
+        when(self.cluster_enabled && self.sentinel_enabled, || {
+            Err(errors::RedisError::InvalidConfiguration(
+                "Redis `cluster_enabled` and `sentinel_enabled` cannot both be `true`".into(),
+            ))
+        })?;
+
+        when(
+            self.sentinel_enabled && self.sentinel_urls.is_empty(),
+            || {
+                Err(errors::RedisError::InvalidConfiguration(
+                    "Redis `sentinel_urls` must be specified if `sentinel_enabled` is `true`"
+                        .into(),
+                ))
+            },
+        )?;
+
+        when(
+            self.sentinel_enabled && self.sentinel_service_name.is_default_or_empty(),
+            || {
+                Err(errors::RedisError::InvalidConfiguration(
+                    "Redis `sentinel_service_name` must be specified if Sentinel is enabled".into(),
+                ))
+            },
+        )?;
+
+        when(self.username.is_some() && self.password.is_none(), || {
+            Err(errors::RedisError::InvalidConfiguration(
+                "Redis `password` must be specified if `username` is specified".into(),
+            ))
+        })?;
+
+        when(
+            self.sentinel_username.is_some() && self.sentinel_password.is_none(),
+            || {
+                Err(errors::RedisError::InvalidConfiguration(
+                    "Redis `sentinel_password` must be specified with `sentinel_username`".into(),
+                ))
+            },
+        )?;
+
+        when(
+            self.tls_client_cert.is_some() != self.tls_client_key.is_some(),
+            || {
+                Err(errors::RedisError::InvalidConfiguration(
+                    "Redis `tls_client_cert` and `tls_client_key` must be specified together"
+                        .into(),
+                ))
+            },
+        )?;
+
+        when(
+            !self.use_tls && (self.tls_ca_cert.is_some() || self.tls_client_cert.is_some()),
+            || {
+                Err(errors::RedisError::InvalidConfiguration(
+                    "Redis `use_tls` must be `true` if TLS certificates are specified".into(),
+                ))
+            },
+        )?;
+
         when(
             self.default_command_timeout < self.unresponsive_timeout,
             || {
@@ -108,6 +188,17 @@ impl Default for RedisSettings {
             default_command_timeout: 30,
             max_feed_count: 200,
             unresponsive_timeout: 10,
+            username: None,
+            password: None,
+            use_tls: false,
+            tls_ca_cert: None,
+            tls_client_cert: None,
+            tls_client_key: None,
+            sentinel_enabled: false,
+            sentinel_urls: vec![],
+            sentinel_service_name: String::default(),
+            sentinel_username: None,
+            sentinel_password: None,
         }
     }
 }
@@ -283,3 +374,122 @@ impl fred::types::FromRedis for SaddReply {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_invalid_configuration(settings: &RedisSettings) -> bool {
+        settings.validate().is_err_and(|error| {
+            matches!(
+                error.current_context(),
+                errors::RedisError::InvalidConfiguration(_)
+            )
+        })
+    }
+
+    #[test]
+    fn test_validate_tls_settings() {
+        let settings = RedisSettings {
+            use_tls: true,
+            tls_ca_cert: Some("ca certificate".to_string()),
+            tls_client_cert: Some("client certificate".to_string()),
+            tls_client_key: Some(Secret::new("client key".to_string())),
+            ..Default::default()
+        };
+        assert!(settings.validate().is_ok());
+
+        let without_client_key = RedisSettings {
+            tls_client_key: None,
+            ..settings.clone()
+        };
+        assert!(is_invalid_configuration(&without_client_key));
+
+        let without_client_cert = RedisSettings {
+            tls_client_cert: None,
+            ..settings.clone()
+        };
+        assert!(is_invalid_configuration(&without_client_cert));
+
+        let without_tls = RedisSettings {
+            use_tls: false,
+            ..settings.clone()
+        };
+        assert!(is_invalid_configuration(&without_tls));
+
+        let ca_cert_without_tls = RedisSettings {
+            use_tls: false,
+            tls_ca_cert: Some("ca certificate".to_string()),
+            ..Default::default()
+        };
+        assert!(is_invalid_configuration(&ca_cert_without_tls));
+    }
+
+    #[test]
+    fn test_validate_acl_settings() {
+        let settings = RedisSettings {
+            username: Some("router".to_string()),
+            password: Some(Secret::new("password".to_string())),
+            ..Default::default()
+        };
+        assert!(settings.validate().is_ok());
+
+        let password_only = RedisSettings {
+            username: None,
+            ..settings.clone()
+        };
+        assert!(password_only.validate().is_ok());
+
+        let without_password = RedisSettings {
+            password: None,
+            ..settings.clone()
+        };
+        assert!(is_invalid_configuration(&without_password));
+    }
+
+    #[test]
+    fn test_validate_sentinel_settings() {
+        let settings = RedisSettings {
+            sentinel_enabled: true,
+            sentinel_urls: vec!["127.0.0.1:26379".to_string()],
+            sentinel_service_name: "mymaster".to_string(),
+            sentinel_username: Some("sentinel".to_string()),
+            sentinel_password: Some(Secret::new("password".to_string())),
+            ..Default::default()
+        };
+        assert!(settings.validate().is_ok());
+
+        let without_urls = RedisSettings {
+            sentinel_urls: vec![],
+            ..settings.clone()
+        };
+        assert!(is_invalid_configuration(&without_urls));
+
+        let without_service_name = RedisSettings {
+            sentinel_service_name: String::new(),
+            ..settings.clone()
+        };
+        assert!(is_invalid_configuration(&without_service_name));
+
+        let without_password = RedisSettings {
+            sentinel_password: None,
+            ..settings.clone()
+        };
+        assert!(is_invalid_configuration(&without_password));
+
+        let with_cluster = RedisSettings {
+            cluster_enabled: true,
+            cluster_urls: vec!["127.0.0.1:6380".to_string()],
+            ..settings.clone()
+        };
+        assert!(is_invalid_configuration(&with_cluster));
+
+        let disabled_without_urls = RedisSettings {
+            sentinel_enabled: false,
+            sentinel_urls: vec![],
+            sentinel_service_name: String::new(),
+            ..settings
+        };
+        assert!(disabled_without_urls.validate().is_ok());
+    }
+}
@@ -147,10 +147,10 @@ pub async fn deep_health_check(
     service: web::Data<String>,
 ) -> impl actix_web::Responder {
     let mut checks = HashMap::new();
-    let stores = state.stores.clone();
+    let tenants = state.get_tenant_names();
     let app_state = Arc::clone(&state.into_inner());
     let service_name = service.into_inner();
-    for (tenant, _) in stores {
+    for tenant in tenants {
         let session_state_res = app_state.clone().get_session_state(&tenant, || {
             errors::ApiErrorResponse::MissingRequiredField {
                 field_name: "tenant_id",
@@ -313,6 +313,43 @@ impl ProcessTrackerWorkflows<routes::SessionState> for WorkflowRunner {
                 storage::ProcessTrackerRunner::PaymentMethodStatusUpdateWorkflow => Ok(Box::new(
                     workflows::payment_method_status_update::PaymentMethodStatusUpdateWorkflow,
                 )),
+                storage::ProcessTrackerRunner::DisputeDeadlineReminderWorkflow => Ok(Box::new(
+                    workflows::dispute_deadline_reminder::DisputeDeadlineReminderWorkflow,
+                )),
+                storage::ProcessTrackerRunner::ScheduledCaptureWorkflow => Ok(Box::new(
+                    workflows::scheduled_capture::ScheduledCaptureWorkflow,
+                )),
+                storage::ProcessTrackerRunner::PaymentIntentExpiryWorkflow => Ok(Box::new(
+                    workflows::payment_intent_expiry::PaymentIntentExpiryWorkflow,
+                )),
+                storage::ProcessTrackerRunner::AutoVoidWorkflow => {
+                    Ok(Box::new(workflows::auto_void::AutoVoidWorkflow))
+                }
+                storage::ProcessTrackerRunner::PayoutBatchWorkflow => {
+                    #[cfg(feature = "payouts")]
+                    {
+                        Ok(Box::new(workflows::payout_batch::PayoutBatchWorkflow))
+                    }
+                    #[cfg(not(feature = "payouts"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run payout batch workflow when payouts feature is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::CardAccountUpdaterWorkflow => Ok(Box::new(
+                    workflows::card_account_updater::CardAccountUpdaterWorkflow,
+                )),
+                storage::ProcessTrackerRunner::SubscriptionBillingWorkflow => Ok(Box::new(
+                    workflows::subscription_billing::SubscriptionBillingWorkflow,
+                )),
+                storage::ProcessTrackerRunner::SmartRetryWorkflow => {
+                    Ok(Box::new(workflows::smart_retry::SmartRetryWorkflow))
+                }
+                storage::ProcessTrackerRunner::NetworkTokenizationWorkflow => Ok(Box::new(
+                    workflows::network_tokenization::NetworkTokenizationWorkflow,
+                )),
             }
         };
 
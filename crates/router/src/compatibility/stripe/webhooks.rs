@@ -4,13 +4,14 @@ use api_models::{
     enums::{DisputeStatus, MandateStatus},
     webhooks::{self as api},
 };
+use common_utils::date_time;
 #[cfg(feature = "payouts")]
 use common_utils::{
     crypto::Encryptable,
     pii::{self, Email},
 };
-use common_utils::{crypto::SignMessage, date_time, ext_traits::Encode};
 use error_stack::ResultExt;
+use masking::Secret;
 use router_env::logger;
 use serde::Serialize;
 
@@ -20,7 +21,9 @@ use super::{
 use crate::{
     core::{
         errors,
-        webhooks::types::{OutgoingWebhookPayloadWithSignature, OutgoingWebhookType},
+        webhooks::types::{
+            get_timestamped_signature, OutgoingWebhookSignatures, OutgoingWebhookType,
+        },
     },
     headers,
     services::request::Maskable,
@@ -38,38 +41,28 @@ pub struct StripeOutgoingWebhook {
 }
 
 impl OutgoingWebhookType for StripeOutgoingWebhook {
-    fn get_outgoing_webhooks_signature(
-        &self,
-        payment_response_hash_key: Option<impl AsRef<[u8]>>,
-    ) -> errors::CustomResult<OutgoingWebhookPayloadWithSignature, errors::WebhooksFlowError> {
-        let timestamp = self.created;
-
-        let payment_response_hash_key = payment_response_hash_key
-            .ok_or(errors::WebhooksFlowError::MerchantConfigNotFound)
-            .attach_printable("For stripe compatibility payment_response_hash_key is mandatory")?;
-
-        let webhook_signature_payload = self
-            .encode_to_string_of_json()
-            .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
-            .attach_printable("failed encoding outgoing webhook payload")?;
-
-        let new_signature_payload = format!("{timestamp}.{webhook_signature_payload}");
-        let v1 = hex::encode(
-            common_utils::crypto::HmacSha256::sign_message(
-                &common_utils::crypto::HmacSha256,
-                payment_response_hash_key.as_ref(),
-                new_signature_payload.as_bytes(),
-            )
-            .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
-            .attach_printable("Failed to sign the message")?,
-        );
+    fn get_payload_signatures(
+        payload: &str,
+        signing_keys: &[Secret<String>],
+        timestamp: i64,
+    ) -> errors::CustomResult<OutgoingWebhookSignatures, errors::WebhooksFlowError> {
+        if signing_keys.is_empty() {
+            return Err(errors::WebhooksFlowError::MerchantConfigNotFound).attach_printable(
+                "For stripe compatibility payment_response_hash_key is mandatory",
+            );
+        }
 
-        let t = timestamp;
-        let signature = Some(format!("t={t},v1={v1}"));
+        // Stripe allows multiple `v1` entries in the signature header, one for each active key
+        let signature = get_timestamped_signature(
+            &common_utils::crypto::HmacSha256,
+            signing_keys,
+            timestamp,
+            payload,
+        )?;
 
-        Ok(OutgoingWebhookPayloadWithSignature {
-            payload: webhook_signature_payload.into(),
+        Ok(OutgoingWebhookSignatures {
             signature,
+            timestamped_signature: None,
         })
     }
 
@@ -90,6 +83,8 @@ pub enum StripeWebhookObject {
     Mandate(StripeMandateResponse),
     #[cfg(feature = "payouts")]
     Payout(StripePayoutResponse),
+    PaymentMethod(StripePaymentMethodResponse),
+    Subscription(StripeSubscriptionResponse),
 }
 
 #[derive(Serialize, Debug)]
@@ -110,6 +105,43 @@ pub struct StripeMandateResponse {
     pub payment_method: String,
 }
 
+#[derive(Serialize, Debug)]
+pub struct StripePaymentMethodResponse {
+    pub id: String,
+    pub customer: Option<common_utils::id_type::CustomerId>,
+    pub card: Option<StripePaymentMethodCard>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct StripePaymentMethodCard {
+    pub brand: Option<String>,
+    pub last4: Option<String>,
+    pub exp_month: Option<Secret<String>>,
+    pub exp_year: Option<Secret<String>>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct StripeSubscriptionResponse {
+    pub id: String,
+    pub customer: common_utils::id_type::CustomerId,
+    pub status: StripeSubscriptionStatus,
+    pub current_period_start: Option<i64>,
+    pub current_period_end: Option<i64>,
+    pub trial_end: Option<i64>,
+    pub cancel_at_period_end: bool,
+    pub canceled_at: Option<i64>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeSubscriptionStatus {
+    Incomplete,
+    Trialing,
+    Active,
+    PastDue,
+    Canceled,
+}
+
 #[cfg(feature = "payouts")]
 #[derive(Clone, Serialize, Debug)]
 pub struct StripePayoutResponse {
@@ -232,6 +264,50 @@ impl From<api_models::mandates::MandateResponse> for StripeMandateResponse {
     }
 }
 
+impl From<api_models::payment_methods::PaymentMethodResponse> for StripePaymentMethodResponse {
+    fn from(res: api_models::payment_methods::PaymentMethodResponse) -> Self {
+        Self {
+            id: res.payment_method_id,
+            customer: res.customer_id,
+            card: res.card.map(|card| StripePaymentMethodCard {
+                brand: card.scheme,
+                last4: card.last4_digits,
+                exp_month: card.expiry_month,
+                exp_year: card.expiry_year,
+            }),
+        }
+    }
+}
+
+impl From<api_models::subscriptions::SubscriptionResponse> for StripeSubscriptionResponse {
+    fn from(res: api_models::subscriptions::SubscriptionResponse) -> Self {
+        let to_unix_timestamp =
+            |date_time: time::PrimitiveDateTime| date_time.assume_utc().unix_timestamp();
+        Self {
+            id: res.subscription_id,
+            customer: res.customer_id,
+            status: res.status.into(),
+            current_period_start: res.current_period_start.map(to_unix_timestamp),
+            current_period_end: res.current_period_end.map(to_unix_timestamp),
+            trial_end: res.trial_end.map(to_unix_timestamp),
+            cancel_at_period_end: res.cancel_at_period_end,
+            canceled_at: res.cancelled_at.map(to_unix_timestamp),
+        }
+    }
+}
+
+impl From<api_models::enums::SubscriptionStatus> for StripeSubscriptionStatus {
+    fn from(status: api_models::enums::SubscriptionStatus) -> Self {
+        match status {
+            api_models::enums::SubscriptionStatus::Incomplete => Self::Incomplete,
+            api_models::enums::SubscriptionStatus::Trialing => Self::Trialing,
+            api_models::enums::SubscriptionStatus::Active => Self::Active,
+            api_models::enums::SubscriptionStatus::PastDue => Self::PastDue,
+            api_models::enums::SubscriptionStatus::Cancelled => Self::Canceled,
+        }
+    }
+}
+
 impl From<MandateStatus> for StripeMandateStatus {
     fn from(status: MandateStatus) -> Self {
         match status {
@@ -262,6 +338,7 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::PaymentFailed => "payment_intent.payment_failed",
         api_models::enums::EventType::PaymentProcessing => "payment_intent.processing",
         api_models::enums::EventType::PaymentCancelled => "payment_intent.canceled",
+        api_models::enums::EventType::PaymentExpired => "payment_intent.canceled",
 
         // the below are not really stripe compatible because stripe doesn't provide this
         api_models::enums::EventType::ActionRequired => "action.required",
@@ -274,6 +351,7 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::DisputeChallenged => "dispute.challenged",
         api_models::enums::EventType::DisputeWon => "dispute.won",
         api_models::enums::EventType::DisputeLost => "dispute.lost",
+        api_models::enums::EventType::DisputeEvidenceDue => "dispute.evidence_due",
         api_models::enums::EventType::MandateActive => "mandate.active",
         api_models::enums::EventType::MandateRevoked => "mandate.revoked",
 
@@ -290,6 +368,12 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::PayoutProcessing => "payout.created",
         api_models::enums::EventType::PayoutExpired => "payout.failed",
         api_models::enums::EventType::PayoutReversed => "payout.reconciliation_completed",
+        api_models::enums::EventType::PaymentMethodUpdated => {
+            "payment_method.automatically_updated"
+        }
+        api_models::enums::EventType::SubscriptionRenewed => "invoice.paid",
+        api_models::enums::EventType::SubscriptionPastDue => "invoice.payment_failed",
+        api_models::enums::EventType::SubscriptionCancelled => "customer.subscription.deleted",
     }
 }
 
@@ -332,6 +416,12 @@ impl From<api::OutgoingWebhookContent> for StripeWebhookObject {
             }
             #[cfg(feature = "payouts")]
             api::OutgoingWebhookContent::PayoutDetails(payout) => Self::Payout(payout.into()),
+            api::OutgoingWebhookContent::PaymentMethodDetails(payment_method) => {
+                Self::PaymentMethod((*payment_method).into())
+            }
+            api::OutgoingWebhookContent::SubscriptionDetails(subscription) => {
+                Self::Subscription((*subscription).into())
+            }
         }
     }
 }
@@ -141,10 +141,11 @@ where
         }
 
         Ok(api::ApplicationResponse::GenericLinkForm(boxed_generic_link_data)) => {
-            let link_type = (boxed_generic_link_data).to_string();
+            let link_type = boxed_generic_link_data.data.to_string();
+            let headers = boxed_generic_link_data.render_config.get_response_headers();
             match services::generic_link_response::build_generic_link_html(*boxed_generic_link_data)
             {
-                Ok(rendered_html) => api::http_response_html_data(rendered_html),
+                Ok(rendered_html) => api::http_response_html_data(rendered_html, headers),
                 Err(_) => {
                     api::http_response_err(format!("Error while rendering {} HTML page", link_type))
                 }
@@ -154,8 +155,9 @@ where
         Ok(api::ApplicationResponse::PaymentLinkForm(boxed_payment_link_data)) => {
             match *boxed_payment_link_data {
                 api::PaymentLinkAction::PaymentLinkFormData(payment_link_data) => {
+                    let headers = payment_link_data.render_config.get_response_headers();
                     match api::build_payment_link_html(payment_link_data) {
-                        Ok(rendered_html) => api::http_response_html_data(rendered_html),
+                        Ok(rendered_html) => api::http_response_html_data(rendered_html, headers),
                         Err(_) => api::http_response_err(
                             r#"{
                                 "error": {
@@ -166,8 +168,9 @@ where
                     }
                 }
                 api::PaymentLinkAction::PaymentLinkStatus(payment_link_data) => {
+                    let headers = payment_link_data.render_config.get_response_headers();
                     match api::get_payment_link_status(payment_link_data) {
-                        Ok(rendered_html) => api::http_response_html_data(rendered_html),
+                        Ok(rendered_html) => api::http_response_html_data(rendered_html, headers),
                         Err(_) => api::http_response_err(
                             r#"{
                                 "error": {
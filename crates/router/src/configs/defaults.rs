@@ -45,6 +45,9 @@ impl Default for super::settings::Database {
             queue_strategy: Default::default(),
             min_idle: None,
             max_lifetime: None,
+            sslmode: None,
+            sslrootcert: None,
+            credentials_refresh_interval: None,
         }
     }
 }
@@ -105,6 +108,67 @@ impl Default for super::settings::Refund {
     }
 }
 
+impl Default for super::settings::DisputeDeadlineReminder {
+    fn default() -> Self {
+        Self {
+            reminder_hours: vec![72, 24, 6],
+        }
+    }
+}
+
+impl Default for super::settings::CardAccountUpdater {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            expiry_window_days: 30,
+            batch_size: 100,
+            schedule_interval_hours: 24,
+            expired_card_unified_codes: HashSet::new(),
+            updater_service: None,
+        }
+    }
+}
+
+impl Default for super::settings::CustomDomain {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            allowed_domains: HashSet::new(),
+            default_locale: "en".to_string(),
+        }
+    }
+}
+
+impl Default for super::settings::FrmRulesEngine {
+    fn default() -> Self {
+        Self {
+            card_attempts_window_in_secs: 86400,            // 1 day
+            customer_attempts_window_in_secs: 86400,        // 1 day
+            ip_attempts_window_in_secs: 3600,               // 1 hour
+            email_attempts_window_in_secs: 86400,           // 1 day
+            customer_distinct_cards_window_in_secs: 604800, // 7 days
+        }
+    }
+}
+
+impl Default for super::settings::SmartRetry {
+    fn default() -> Self {
+        let thirty_day_limit = |max_retries| super::settings::NetworkRetryLimit {
+            max_retries,
+            window_in_days: 30,
+        };
+
+        Self {
+            enabled: false,
+            network_retry_limits: HashMap::from([
+                ("visa".to_string(), thirty_day_limit(15)),
+                ("mastercard".to_string(), thirty_day_limit(35)),
+            ]),
+            default_retry_limit: thirty_day_limit(15),
+        }
+    }
+}
+
 impl Default for super::settings::EphemeralConfig {
     fn default() -> Self {
         Self { validity: 1 }
@@ -197,6 +197,36 @@ impl SecretsHandler for settings::PaymentMethodAuth {
     }
 }
 
+#[async_trait::async_trait]
+impl SecretsHandler for settings::NetworkTokenization {
+    async fn convert_to_raw_secret(
+        value: SecretStateContainer<Self, SecuredSecret>,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<SecretStateContainer<Self, RawSecret>, SecretsManagementError> {
+        let network_tokenization = value.get_inner();
+
+        let webhook_secret = secret_management_client
+            .get_secret(network_tokenization.webhook_secret.clone())
+            .await?;
+
+        let token_service = match network_tokenization.token_service.clone() {
+            Some(settings::TokenServiceProviderConfig::Http { base_url, api_key }) => {
+                Some(settings::TokenServiceProviderConfig::Http {
+                    base_url,
+                    api_key: secret_management_client.get_secret(api_key).await?,
+                })
+            }
+            None => None,
+        };
+
+        Ok(value.transition_state(|network_tokenization| Self {
+            webhook_secret,
+            token_service,
+            ..network_tokenization
+        }))
+    }
+}
+
 #[async_trait::async_trait]
 impl SecretsHandler for settings::Secrets {
     async fn convert_to_raw_secret(
@@ -249,6 +279,12 @@ pub(crate) async fn fetch_raw_secrets(
             .await
             .expect("Failed to decrypt master database configuration");
 
+    #[allow(clippy::expect_used)]
+    let redis =
+        redis_interface::RedisSettings::convert_to_raw_secret(conf.redis, secret_management_client)
+            .await
+            .expect("Failed to decrypt redis configuration");
+
     #[cfg(feature = "olap")]
     #[allow(clippy::expect_used)]
     let analytics =
@@ -318,6 +354,14 @@ pub(crate) async fn fetch_raw_secrets(
     .await
     .expect("Failed to decrypt payment method auth configs");
 
+    #[allow(clippy::expect_used)]
+    let network_tokenization = settings::NetworkTokenization::convert_to_raw_secret(
+        conf.network_tokenization,
+        secret_management_client,
+    )
+    .await
+    .expect("Failed to decrypt network tokenization configs");
+
     #[allow(clippy::expect_used)]
     let user_auth_methods = settings::UserAuthMethodSettings::convert_to_raw_secret(
         conf.user_auth_methods,
@@ -329,7 +373,7 @@ pub(crate) async fn fetch_raw_secrets(
     Settings {
         server: conf.server,
         master_database,
-        redis: conf.redis,
+        redis,
         log: conf.log,
         #[cfg(feature = "kv_store")]
         drainer: conf.drainer,
@@ -344,6 +388,7 @@ pub(crate) async fn fetch_raw_secrets(
         connectors: conf.connectors,
         forex_api,
         refund: conf.refund,
+        dispute_deadline_reminder: conf.dispute_deadline_reminder,
         eph_key: conf.eph_key,
         scheduler: conf.scheduler,
         jwekey,
@@ -366,6 +411,9 @@ pub(crate) async fn fetch_raw_secrets(
         delayed_session_response: conf.delayed_session_response,
         webhook_source_verification_call: conf.webhook_source_verification_call,
         payment_method_auth,
+        network_tokenization,
+        card_account_updater: conf.card_account_updater,
+        smart_retry: conf.smart_retry,
         connector_request_reference_id_config: conf.connector_request_reference_id_config,
         #[cfg(feature = "payouts")]
         payouts: conf.payouts,
@@ -376,6 +424,8 @@ pub(crate) async fn fetch_raw_secrets(
         temp_locker_enable_config: conf.temp_locker_enable_config,
         generic_link: conf.generic_link,
         payment_link: conf.payment_link,
+        custom_domain: conf.custom_domain,
+        frm_rules_engine: conf.frm_rules_engine,
         #[cfg(feature = "olap")]
         analytics,
         #[cfg(feature = "olap")]
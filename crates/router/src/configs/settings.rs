@@ -6,7 +6,7 @@ use std::{
 #[cfg(feature = "olap")]
 use analytics::{opensearch::OpenSearchConfig, ReportConfig};
 use api_models::{enums, payment_methods::RequiredFieldInfo};
-use common_utils::ext_traits::ConfigExt;
+use common_utils::{ext_traits::ConfigExt, PgSslMode};
 use config::{Environment, File};
 #[cfg(feature = "email")]
 use external_services::email::EmailSettings;
@@ -65,13 +65,14 @@ pub struct Settings<S: SecretState> {
     pub master_database: SecretStateContainer<Database, S>,
     #[cfg(feature = "olap")]
     pub replica_database: SecretStateContainer<Database, S>,
-    pub redis: RedisSettings,
+    pub redis: SecretStateContainer<RedisSettings, S>,
     pub log: Log,
     pub secrets: SecretStateContainer<Secrets, S>,
     pub locker: Locker,
     pub connectors: Connectors,
     pub forex_api: SecretStateContainer<ForexApi, S>,
     pub refund: Refund,
+    pub dispute_deadline_reminder: DisputeDeadlineReminder,
     pub eph_key: EphemeralConfig,
     pub scheduler: Option<SchedulerSettings>,
     #[cfg(feature = "kv_store")]
@@ -98,6 +99,9 @@ pub struct Settings<S: SecretState> {
     pub delayed_session_response: DelayedSessionConfig,
     pub webhook_source_verification_call: WebhookSourceVerificationCall,
     pub payment_method_auth: SecretStateContainer<PaymentMethodAuth, S>,
+    pub network_tokenization: SecretStateContainer<NetworkTokenization, S>,
+    pub card_account_updater: CardAccountUpdater,
+    pub smart_retry: SmartRetry,
     pub connector_request_reference_id_config: ConnectorRequestReferenceIdConfig,
     #[cfg(feature = "payouts")]
     pub payouts: Payouts,
@@ -108,6 +112,8 @@ pub struct Settings<S: SecretState> {
     pub temp_locker_enable_config: TempLockerEnableConfig,
     pub generic_link: GenericLink,
     pub payment_link: PaymentLink,
+    pub custom_domain: CustomDomain,
+    pub frm_rules_engine: FrmRulesEngine,
     #[cfg(feature = "olap")]
     pub analytics: SecretStateContainer<AnalyticsConfig, S>,
     #[cfg(feature = "kv_store")]
@@ -150,7 +156,7 @@ impl Multitenancy {
 #[serde(transparent)]
 pub struct TenantConfig(pub HashMap<String, Tenant>);
 
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Deserialize, Clone, Default, PartialEq, Eq)]
 pub struct Tenant {
     pub name: String,
     pub base_url: String,
@@ -174,6 +180,18 @@ impl storage_impl::config::ClickHouseConfig for Tenant {
     }
 }
 
+impl From<diesel_models::tenant::Tenant> for Tenant {
+    fn from(tenant: diesel_models::tenant::Tenant) -> Self {
+        Self {
+            name: tenant.name,
+            base_url: tenant.base_url,
+            schema: tenant.schema_name,
+            redis_key_prefix: tenant.redis_key_prefix,
+            clickhouse_database: tenant.clickhouse_database,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct GlobalTenant {
     pub schema: String,
@@ -260,6 +278,35 @@ pub struct PaymentLink {
     pub sdk_url: String,
 }
 
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct CustomDomain {
+    /// Whether links issued for a custom domain are served only on that domain
+    pub enabled: bool,
+    /// Domains which business profiles may use for hosting links, entries prefixed with `*.`
+    /// allow all subdomains of the domain
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub allowed_domains: HashSet<String>,
+    /// Locale used for rendering links when none of the locales accepted by the client is
+    /// supported
+    pub default_locale: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct FrmRulesEngine {
+    /// Rolling window over which payment attempts made with the same card are counted
+    pub card_attempts_window_in_secs: i64,
+    /// Rolling window over which payment attempts made by the same customer are counted
+    pub customer_attempts_window_in_secs: i64,
+    /// Rolling window over which payment attempts made from the same IP address are counted
+    pub ip_attempts_window_in_secs: i64,
+    /// Rolling window over which payment attempts made with the same email are counted
+    pub email_attempts_window_in_secs: i64,
+    /// Rolling window over which the distinct cards used by the same customer are counted
+    pub customer_distinct_cards_window_in_secs: i64,
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 #[serde(default)]
 pub struct ForexApi {
@@ -357,6 +404,8 @@ pub struct DummyConnector {
     pub default_return_url: String,
     pub slack_invite_url: String,
     pub discord_invite_url: String,
+    pub webhook_delay: u64,
+    pub slow_response_duration: u64,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -376,6 +425,75 @@ pub struct Mandates {
     pub update_mandate_supported: SupportedPaymentMethodsForMandate,
 }
 
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct NetworkTokenization {
+    /// Whether cards saved with a customer are tokenized with the card networks
+    pub enabled: bool,
+    /// Connectors which accept a network token and cryptogram in place of the card number, only
+    /// Checkout consumes the network token currently
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub supported_connectors: HashSet<enums::Connector>,
+    /// Secret used to verify the signature of token lifecycle webhooks
+    pub webhook_secret: Secret<String>,
+    pub token_service: Option<TokenServiceProviderConfig>,
+}
+
+/// Token service provider used for provisioning network tokens and cryptograms
+#[derive(Debug, Deserialize, Clone)]
+#[serde(tag = "provider", rename_all = "snake_case")]
+pub enum TokenServiceProviderConfig {
+    /// Token service exposed over HTTP
+    Http {
+        base_url: String,
+        api_key: Secret<String>,
+    },
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct CardAccountUpdater {
+    /// Whether saved cards are periodically refreshed through the card account updater
+    pub enabled: bool,
+    /// Cards expiring within these many days are sent to the updater service
+    pub expiry_window_days: u16,
+    /// Maximum number of cards sent to the updater service in a single request
+    pub batch_size: u16,
+    /// Interval between two runs of the updater workflow for a merchant
+    pub schedule_interval_hours: u16,
+    /// Unified codes of GSM records which indicate that the card used for a payment has expired
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub expired_card_unified_codes: HashSet<String>,
+    pub updater_service: Option<CardAccountUpdaterServiceConfig>,
+}
+
+/// Service used for fetching the latest details of saved cards from the card networks
+#[derive(Debug, Deserialize, Clone)]
+#[serde(tag = "provider", rename_all = "snake_case")]
+pub enum CardAccountUpdaterServiceConfig {
+    /// Updates read from a local JSON file, meant for testing the updater workflow
+    File { response_file_path: String },
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct SmartRetry {
+    /// Whether soft declined off-session payments are retried later, on the schedule configured
+    /// in the GSM records having the `retry_later` decision
+    pub enabled: bool,
+    /// Retry limits imposed by the card networks, keyed by the lowercase card network name
+    pub network_retry_limits: HashMap<String, NetworkRetryLimit>,
+    /// Retry limit applied to cards whose network is unknown or has no configured limit
+    pub default_retry_limit: NetworkRetryLimit,
+}
+
+/// Maximum number of retries allowed for a card within a rolling window
+#[derive(Debug, Deserialize, Clone, Copy)]
+pub struct NetworkRetryLimit {
+    pub max_retries: u16,
+    pub window_in_days: u16,
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct NetworkTransactionIdSupportedConnectors {
     #[serde(deserialize_with = "deserialize_hashset")]
@@ -543,6 +661,13 @@ pub struct Refund {
     pub max_age: i64,
 }
 
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct DisputeDeadlineReminder {
+    // Specifies the number of hours before the dispute challenge deadline when reminders should be sent
+    pub reminder_hours: Vec<u16>,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[serde(default)]
 pub struct EphemeralConfig {
@@ -591,6 +716,11 @@ pub struct Database {
     pub queue_strategy: QueueStrategy,
     pub min_idle: Option<u32>,
     pub max_lifetime: Option<u64>,
+    pub sslmode: Option<PgSslMode>,
+    pub sslrootcert: Option<String>,
+    /// Interval in seconds at which the password is re-fetched through the secrets manager. The
+    /// password is not refreshed if not configured.
+    pub credentials_refresh_interval: Option<u64>,
 }
 
 impl From<Database> for storage_impl::config::Database {
@@ -606,6 +736,10 @@ impl From<Database> for storage_impl::config::Database {
             queue_strategy: val.queue_strategy,
             min_idle: val.min_idle,
             max_lifetime: val.max_lifetime,
+            sslmode: val.sslmode,
+            sslrootcert: val.sslrootcert,
+            credentials_refresh_interval: val.credentials_refresh_interval,
+            credentials_provider: None,
         }
     }
 }
@@ -714,6 +848,7 @@ impl Settings<SecuredSecret> {
                     .list_separator(",")
                     .with_list_parse_key("log.telemetry.route_to_trace")
                     .with_list_parse_key("redis.cluster_urls")
+                    .with_list_parse_key("redis.sentinel_urls")
                     .with_list_parse_key("events.kafka.brokers")
                     .with_list_parse_key("connectors.supported.wallets")
                     .with_list_parse_key("connector_request_reference_id_config.merchant_ids_send_payment_id_as_connector_request_id"),
@@ -733,7 +868,7 @@ impl Settings<SecuredSecret> {
         self.master_database.get_inner().validate()?;
         #[cfg(feature = "olap")]
         self.replica_database.get_inner().validate()?;
-        self.redis.validate().map_err(|error| {
+        self.redis.get_inner().validate().map_err(|error| {
             println!("{error}");
             ApplicationError::InvalidConfigurationValueError("Redis configuration".into())
         })?;
@@ -763,6 +898,10 @@ impl Settings<SecuredSecret> {
         #[cfg(feature = "kv_store")]
         self.drainer.validate()?;
         self.api_keys.get_inner().validate()?;
+        self.dispute_deadline_reminder.validate()?;
+        self.network_tokenization.get_inner().validate()?;
+        self.card_account_updater.validate()?;
+        self.smart_retry.validate()?;
 
         self.file_storage
             .validate()
@@ -783,6 +922,8 @@ impl Settings<SecuredSecret> {
             .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.into()))?;
         self.generic_link.payment_method_collect.validate()?;
         self.generic_link.payout_link.validate()?;
+        self.custom_domain.validate()?;
+        self.frm_rules_engine.validate()?;
         Ok(())
     }
 }
@@ -91,6 +91,12 @@ impl super::settings::Database {
             Err(ApplicationError::InvalidConfigurationValueError(
                 "database user password must not be empty".into(),
             ))
+        })?;
+
+        when(self.credentials_refresh_interval == Some(0), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "database credentials refresh interval must be greater than 0".into(),
+            ))
         })
     }
 }
@@ -153,6 +159,64 @@ impl super::settings::ApiKeys {
     }
 }
 
+impl super::settings::DisputeDeadlineReminder {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.reminder_hours.is_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "Dispute deadline reminder hours must not be empty".into(),
+            ))
+        })?;
+
+        when(
+            self.reminder_hours
+                .iter()
+                .zip(self.reminder_hours.iter().skip(1))
+                .any(|(current, next)| current <= next),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "Dispute deadline reminder hours must be in strictly descending order".into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::NetworkTokenization {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.enabled && self.token_service.is_none(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "token service must be configured when network tokenization is enabled".into(),
+            ))
+        })?;
+
+        when(
+            self.enabled && self.webhook_secret.is_default_or_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "network token webhook secret must not be empty when network tokenization is enabled".into(),
+                ))
+            },
+        )?;
+
+        // The network token is only read from the authorize request by the Checkout connector,
+        // other connectors would silently be sent the card number
+        when(
+            self.supported_connectors
+                .iter()
+                .any(|connector| *connector != api_models::enums::Connector::Checkout),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "network tokenization is only supported for the checkout connector".into(),
+                ))
+            },
+        )
+    }
+}
+
 impl super::settings::LockSettings {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
@@ -192,3 +256,156 @@ impl super::settings::GenericLinkEnvConfig {
         })
     }
 }
+
+impl super::settings::CardAccountUpdater {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.enabled && self.updater_service.is_none(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "updater service must be configured when card account updater is enabled".into(),
+            ))
+        })?;
+
+        when(self.batch_size == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "card account updater batch size must be greater than zero".into(),
+            ))
+        })?;
+
+        when(self.schedule_interval_hours == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "card account updater schedule interval must be greater than zero".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::SmartRetry {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        self.network_retry_limits
+            .iter()
+            .map(|(network, limit)| (network.as_str(), limit))
+            .chain(std::iter::once(("default", &self.default_retry_limit)))
+            .try_for_each(|(network, limit)| {
+                when(limit.max_retries == 0 || limit.window_in_days == 0, || {
+                    Err(ApplicationError::InvalidConfigurationValueError(format!(
+                        "smart retry limit for `{network}` must have a non-zero number of \
+                         retries and window"
+                    )))
+                })
+            })
+    }
+}
+
+impl super::settings::CustomDomain {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        use crate::core::link_hosting;
+
+        when(self.enabled && self.allowed_domains.is_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "allowed domains must be configured when custom domains are enabled".into(),
+            ))
+        })?;
+
+        when(
+            !self
+                .allowed_domains
+                .iter()
+                .all(|domain| link_hosting::is_valid_domain_pattern(domain)),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "allowed domains for custom domains must be valid domain names".into(),
+                ))
+            },
+        )?;
+
+        when(
+            !link_hosting::is_supported_locale(&self.default_locale),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(format!(
+                    "default locale for links `{}` is not supported",
+                    self.default_locale
+                )))
+            },
+        )
+    }
+}
+
+impl super::settings::FrmRulesEngine {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            [
+                self.card_attempts_window_in_secs,
+                self.customer_attempts_window_in_secs,
+                self.ip_attempts_window_in_secs,
+                self.email_attempts_window_in_secs,
+                self.customer_distinct_cards_window_in_secs,
+            ]
+            .iter()
+            .any(|window| *window <= 0),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "velocity windows of the fraud rules engine must be positive".into(),
+                ))
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::settings::{DisputeDeadlineReminder, NetworkTokenization};
+
+    #[test]
+    fn test_dispute_deadline_reminder_validation() {
+        assert!(DisputeDeadlineReminder::default().validate().is_ok());
+        assert!(DisputeDeadlineReminder {
+            reminder_hours: vec![24]
+        }
+        .validate()
+        .is_ok());
+        assert!(DisputeDeadlineReminder {
+            reminder_hours: vec![]
+        }
+        .validate()
+        .is_err());
+        assert!(DisputeDeadlineReminder {
+            reminder_hours: vec![24, 72]
+        }
+        .validate()
+        .is_err());
+        assert!(DisputeDeadlineReminder {
+            reminder_hours: vec![24, 24, 6]
+        }
+        .validate()
+        .is_err());
+    }
+
+    #[test]
+    fn test_network_tokenization_validation() {
+        use api_models::enums::Connector;
+
+        assert!(NetworkTokenization::default().validate().is_ok());
+        assert!(NetworkTokenization {
+            supported_connectors: [Connector::Checkout].into_iter().collect(),
+            ..Default::default()
+        }
+        .validate()
+        .is_ok());
+        assert!(NetworkTokenization {
+            supported_connectors: [Connector::Checkout, Connector::Stripe]
+                .into_iter()
+                .collect(),
+            ..Default::default()
+        }
+        .validate()
+        .is_err());
+    }
+}
@@ -1,11 +1,11 @@
 use bb8::PooledConnection;
 use diesel::PgConnection;
 use error_stack::ResultExt;
-use storage_impl::errors as storage_errors;
+use storage_impl::{database::credentials::PgConnectionManager, errors as storage_errors};
 
 use crate::errors;
 
-pub type PgPool = bb8::Pool<async_bb8_diesel::ConnectionManager<PgConnection>>;
+pub type PgPool = bb8::Pool<PgConnectionManager>;
 
 pub type PgPooledConn = async_bb8_diesel::Connection<PgConnection>;
 
@@ -17,17 +17,14 @@ pub type PgPooledConn = async_bb8_diesel::Connection<PgConnection>;
 pub async fn redis_connection(
     conf: &crate::configs::Settings,
 ) -> redis_interface::RedisConnectionPool {
-    redis_interface::RedisConnectionPool::new(&conf.redis)
+    redis_interface::RedisConnectionPool::new(conf.redis.get_inner())
         .await
         .expect("Failed to create Redis Connection Pool")
 }
 
 pub async fn pg_connection_read<T: storage_impl::DatabaseStore>(
     store: &T,
-) -> errors::CustomResult<
-    PooledConnection<'_, async_bb8_diesel::ConnectionManager<PgConnection>>,
-    storage_errors::StorageError,
-> {
+) -> errors::CustomResult<PooledConnection<'_, PgConnectionManager>, storage_errors::StorageError> {
     // If only OLAP is enabled get replica pool.
     #[cfg(all(feature = "olap", not(feature = "oltp")))]
     let pool = store.get_replica_pool();
@@ -50,10 +47,7 @@ pub async fn pg_connection_read<T: storage_impl::DatabaseStore>(
 
 pub async fn pg_connection_write<T: storage_impl::DatabaseStore>(
     store: &T,
-) -> errors::CustomResult<
-    PooledConnection<'_, async_bb8_diesel::ConnectionManager<PgConnection>>,
-    storage_errors::StorageError,
-> {
+) -> errors::CustomResult<PooledConnection<'_, PgConnectionManager>, storage_errors::StorageError> {
     // Since all writes should happen to master DB only choose master DB.
     let pool = store.get_master_pool();
 
@@ -2,7 +2,7 @@
 use api_models::payouts::PayoutMethodData;
 use api_models::{enums, payments, webhooks};
 use cards::CardNumber;
-use common_utils::{ext_traits::Encode, id_type, pii};
+use common_utils::{ext_traits::Encode, id_type, pii, types as common_types};
 use error_stack::{report, ResultExt};
 use masking::{ExposeInterface, PeekInterface};
 use reqwest::Url;
@@ -179,6 +179,8 @@ pub struct AdyenPaymentRequest<'a> {
     line_items: Option<Vec<LineItem>>,
     channel: Option<Channel>,
     metadata: Option<pii::SecretSerdeValue>,
+    installments: Option<AdyenInstallments>,
+    splits: Option<Vec<AdyenSplitData>>,
 }
 
 #[derive(Debug, Serialize)]
@@ -223,6 +225,129 @@ pub enum Channel {
     Web,
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct AdyenInstallments {
+    value: u8,
+    plan: AdyenInstallmentPlan,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AdyenInstallmentPlan {
+    Regular,
+    Revolving,
+    WithInterest,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenSplitData {
+    amount: Amount,
+    #[serde(rename = "type")]
+    split_type: AdyenSplitType,
+    account: Option<String>,
+    reference: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum AdyenSplitType {
+    /// Amount booked to the balance account of a sub-merchant
+    BalanceAccount,
+    /// Amount booked to the liable balance account of the platform
+    Commission,
+}
+
+fn get_adyen_splits<'a>(
+    splits: impl Iterator<Item = (&'a String, i64, Option<String>)>,
+    total_amount: i64,
+    currency: storage_enums::Currency,
+) -> Vec<AdyenSplitData> {
+    let mut adyen_splits: Vec<AdyenSplitData> = splits
+        .map(|(account, value, reference)| AdyenSplitData {
+            amount: Amount { currency, value },
+            split_type: AdyenSplitType::BalanceAccount,
+            account: Some(account.clone()),
+            reference,
+        })
+        .collect();
+
+    // Adyen requires the splits to add up to the total amount, the rest is booked to the platform
+    let commission = total_amount
+        - adyen_splits
+            .iter()
+            .map(|split| split.amount.value)
+            .sum::<i64>();
+    if commission > 0 {
+        adyen_splits.push(AdyenSplitData {
+            amount: Amount {
+                currency,
+                value: commission,
+            },
+            split_type: AdyenSplitType::Commission,
+            account: None,
+            reference: None,
+        });
+    }
+    adyen_splits
+}
+
+fn get_payment_splits(
+    item: &AdyenRouterData<&types::PaymentsAuthorizeRouterData>,
+) -> Option<Vec<AdyenSplitData>> {
+    item.router_data
+        .request
+        .split_payments
+        .as_ref()
+        .map(|split_payments| {
+            get_adyen_splits(
+                split_payments.0.iter().map(|split| {
+                    (
+                        &split.account_id,
+                        (split.amount - split.fees).get_amount_as_i64(),
+                        split.reference.clone(),
+                    )
+                }),
+                item.amount,
+                item.router_data.request.currency,
+            )
+        })
+}
+
+fn get_refund_splits(
+    split_refunds: &common_types::SplitRefunds,
+    refund_amount: i64,
+    currency: storage_enums::Currency,
+) -> Vec<AdyenSplitData> {
+    get_adyen_splits(
+        split_refunds
+            .0
+            .iter()
+            .map(|split| (&split.account_id, split.amount.get_amount_as_i64(), None)),
+        refund_amount,
+        currency,
+    )
+}
+
+impl From<payments::Installments> for AdyenInstallments {
+    fn from(installments: payments::Installments) -> Self {
+        let plan = match (installments.plan_type, installments.financing_type) {
+            (
+                api_enums::InstallmentPlanType::Regular,
+                api_enums::InstallmentFinancingType::MerchantFinanced,
+            ) => AdyenInstallmentPlan::Regular,
+            (
+                api_enums::InstallmentPlanType::Regular,
+                api_enums::InstallmentFinancingType::IssuerFinanced,
+            ) => AdyenInstallmentPlan::WithInterest,
+            (api_enums::InstallmentPlanType::Revolving, _) => AdyenInstallmentPlan::Revolving,
+        };
+        Self {
+            value: installments.count,
+            plan,
+        }
+    }
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AdyenBalanceRequest<'a> {
@@ -1208,6 +1333,7 @@ pub struct AdyenRefundRequest {
     amount: Amount,
     merchant_refund_reason: Option<String>,
     reference: String,
+    splits: Option<Vec<AdyenSplitData>>,
 }
 
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
@@ -2610,6 +2736,8 @@ impl<'a>
             shopper_statement: item.router_data.request.statement_descriptor.clone(),
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone(),
+            installments: None,
+            splits: get_payment_splits(item),
         })
     }
 }
@@ -2672,6 +2800,12 @@ impl<'a>
             shopper_statement: item.router_data.request.statement_descriptor.clone(),
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone(),
+            installments: item
+                .router_data
+                .request
+                .installments
+                .map(AdyenInstallments::from),
+            splits: get_payment_splits(item),
         })
     }
 }
@@ -2725,6 +2859,8 @@ impl<'a>
             shopper_statement: item.router_data.request.statement_descriptor.clone(),
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone(),
+            installments: None,
+            splits: get_payment_splits(item),
         };
         Ok(request)
     }
@@ -2779,6 +2915,8 @@ impl<'a>
             shopper_statement: item.router_data.request.statement_descriptor.clone(),
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone(),
+            installments: None,
+            splits: get_payment_splits(item),
         };
         Ok(request)
     }
@@ -2829,6 +2967,8 @@ impl<'a>
             shopper_statement: item.router_data.request.statement_descriptor.clone(),
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone(),
+            installments: None,
+            splits: get_payment_splits(item),
         };
         Ok(request)
     }
@@ -2879,6 +3019,8 @@ impl<'a>
             shopper_statement: item.router_data.request.statement_descriptor.clone(),
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone(),
+            installments: None,
+            splits: get_payment_splits(item),
         };
         Ok(request)
     }
@@ -2939,6 +3081,8 @@ impl<'a>
             shopper_statement: item.router_data.request.statement_descriptor.clone(),
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone(),
+            installments: None,
+            splits: get_payment_splits(item),
         })
     }
 }
@@ -3034,6 +3178,8 @@ impl<'a>
             shopper_statement: item.router_data.request.statement_descriptor.clone(),
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone(),
+            installments: None,
+            splits: get_payment_splits(item),
         })
     }
 }
@@ -3109,6 +3255,8 @@ impl<'a>
             shopper_statement: item.router_data.request.statement_descriptor.clone(),
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone(),
+            installments: None,
+            splits: get_payment_splits(item),
         })
     }
 }
@@ -3167,6 +3315,8 @@ impl<'a>
             shopper_statement: item.router_data.request.statement_descriptor.clone(),
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone(),
+            installments: None,
+            splits: get_payment_splits(item),
         })
     }
 }
@@ -4016,6 +4166,18 @@ impl<F> TryFrom<&AdyenRouterData<&types::RefundsRouterData<F>>> for AdyenRefundR
             },
             merchant_refund_reason: item.router_data.request.reason.clone(),
             reference: item.router_data.request.refund_id.clone(),
+            splits: item
+                .router_data
+                .request
+                .split_refunds
+                .as_ref()
+                .map(|split_refunds| {
+                    get_refund_splits(
+                        split_refunds,
+                        item.router_data.request.refund_amount,
+                        item.router_data.request.currency,
+                    )
+                }),
         })
     }
 }
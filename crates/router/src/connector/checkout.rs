@@ -947,6 +947,9 @@ impl api::FileUpload for Checkout {
                     })?
                 }
             }
+            api::FilePurpose::PayoutBatch => Err(errors::ConnectorError::FileValidationFailed {
+                reason: "payout batch files are not uploaded to the connector".to_owned(),
+            })?,
         }
         Ok(())
     }
@@ -1,6 +1,6 @@
 use common_utils::{errors::CustomResult, ext_traits::ByteSliceExt};
 use error_stack::ResultExt;
-use masking::{ExposeInterface, Secret};
+use masking::{ExposeInterface, PeekInterface, Secret};
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 use url::Url;
@@ -186,6 +186,7 @@ pub enum PaymentSource {
     Card(CardSource),
     Wallets(WalletSource),
     ApplePayPredecrypt(Box<ApplePayPredecrypt>),
+    NetworkToken(Box<NetworkTokenSource>),
 }
 
 #[derive(Debug, Serialize)]
@@ -200,6 +201,53 @@ pub struct ApplePayPredecrypt {
     cryptogram: Secret<String>,
 }
 
+#[derive(Debug, Serialize)]
+pub struct NetworkTokenSource {
+    token: cards::CardNumber,
+    #[serde(rename = "type")]
+    source_type: String,
+    token_type: String,
+    expiry_month: Secret<String>,
+    expiry_year: Secret<String>,
+    eci: Option<String>,
+    cryptogram: Secret<String>,
+}
+
+impl NetworkTokenSource {
+    /// Checkout only accepts network tokens issued by VTS (Visa) and MDES (Mastercard)
+    fn try_from_network_token_data(network_token_data: domain::NetworkTokenData) -> Option<Self> {
+        let token_type = match network_token_data.card_network? {
+            common_enums::CardNetwork::Visa => "vts",
+            common_enums::CardNetwork::Mastercard => "mdes",
+            common_enums::CardNetwork::AmericanExpress
+            | common_enums::CardNetwork::JCB
+            | common_enums::CardNetwork::DinersClub
+            | common_enums::CardNetwork::Discover
+            | common_enums::CardNetwork::CartesBancaires
+            | common_enums::CardNetwork::UnionPay
+            | common_enums::CardNetwork::Interac
+            | common_enums::CardNetwork::RuPay
+            | common_enums::CardNetwork::Maestro => return None,
+        };
+        let expiry_year = network_token_data.token_exp_year.peek();
+        let expiry_year = if expiry_year.len() == 2 {
+            Secret::new(format!("20{expiry_year}"))
+        } else {
+            network_token_data.token_exp_year.clone()
+        };
+
+        Some(Self {
+            token: network_token_data.token_number,
+            source_type: "network_token".to_string(),
+            token_type: token_type.to_string(),
+            expiry_month: network_token_data.token_exp_month,
+            expiry_year,
+            eci: network_token_data.eci,
+            cryptogram: network_token_data.token_cryptogram,
+        })
+    }
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum CheckoutSourceTypes {
@@ -281,13 +329,24 @@ impl TryFrom<&CheckoutRouterData<&types::PaymentsAuthorizeRouterData>> for Payme
     ) -> Result<Self, Self::Error> {
         let source_var = match item.router_data.request.payment_method_data.clone() {
             domain::PaymentMethodData::Card(ccard) => {
-                let a = PaymentSource::Card(CardSource {
-                    source_type: CheckoutSourceTypes::Card,
-                    number: ccard.card_number.clone(),
-                    expiry_month: ccard.card_exp_month.clone(),
-                    expiry_year: ccard.card_exp_year.clone(),
-                    cvv: ccard.card_cvc,
-                });
+                let network_token_source = item
+                    .router_data
+                    .request
+                    .network_token_data
+                    .clone()
+                    .and_then(NetworkTokenSource::try_from_network_token_data);
+                let a = match network_token_source {
+                    Some(network_token_source) => {
+                        PaymentSource::NetworkToken(Box::new(network_token_source))
+                    }
+                    None => PaymentSource::Card(CardSource {
+                        source_type: CheckoutSourceTypes::Card,
+                        number: ccard.card_number.clone(),
+                        expiry_month: ccard.card_exp_month.clone(),
+                        expiry_year: ccard.card_exp_year.clone(),
+                        cvv: ccard.card_cvc,
+                    }),
+                };
                 Ok(a)
             }
             domain::PaymentMethodData::Wallet(wallet_data) => match wallet_data {
@@ -137,9 +137,15 @@ impl TryFrom<&DlocalRouterData<&types::PaymentsAuthorizeRouterData>> for DlocalP
                         installments: item
                             .router_data
                             .request
-                            .mandate_id
-                            .clone()
-                            .map(|_| "1".to_string()),
+                            .installments
+                            .map(|installments| installments.count.to_string())
+                            .or_else(|| {
+                                item.router_data
+                                    .request
+                                    .mandate_id
+                                    .as_ref()
+                                    .map(|_| "1".to_string())
+                            }),
                     }),
                     order_id: item.router_data.connector_request_reference_id.clone(),
                     three_dsecure: match item.router_data.auth_type {
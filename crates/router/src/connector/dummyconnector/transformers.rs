@@ -1,6 +1,7 @@
 use diesel_models::enums::Currency;
-use masking::Secret;
+use masking::{PeekInterface, Secret};
 use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
 use url::Url;
 
 use crate::{
@@ -58,6 +59,9 @@ impl From<u8> for DummyConnectors {
     }
 }
 
+/// The key in the payment metadata which selects the scenario simulated by the dummy connector
+const SCENARIO_METADATA_KEY: &str = "dummy_connector_scenario";
+
 #[derive(Debug, Serialize, Eq, PartialEq)]
 pub struct DummyConnectorPaymentsRequest<const T: u8> {
     amount: i64,
@@ -65,6 +69,9 @@ pub struct DummyConnectorPaymentsRequest<const T: u8> {
     payment_method_data: PaymentMethodData,
     return_url: Option<String>,
     connector: DummyConnectors,
+    capture_method: Option<enums::CaptureMethod>,
+    webhook_url: Option<String>,
+    scenario: Option<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
@@ -169,12 +176,22 @@ impl<const T: u8> TryFrom<&types::PaymentsAuthorizeRouterData>
             ),
             _ => Err(errors::ConnectorError::NotImplemented("Payment methods".to_string()).into()),
         };
+        let scenario = item
+            .request
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.peek().get(SCENARIO_METADATA_KEY))
+            .and_then(|scenario| scenario.as_str())
+            .map(ToOwned::to_owned);
         Ok(Self {
             amount: item.request.amount,
             currency: item.request.currency,
             payment_method_data: payment_method_data?,
             return_url: item.request.router_return_url.clone(),
             connector: Into::<DummyConnectors>::into(T),
+            capture_method: item.request.capture_method,
+            webhook_url: item.request.webhook_url.clone(),
+            scenario,
         })
     }
 }
@@ -196,7 +213,6 @@ impl TryFrom<&types::ConnectorAuthType> for DummyConnectorAuthType {
     }
 }
 // PaymentsResponse
-//TODO: Append the remaining status flags
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum DummyConnectorPaymentStatus {
@@ -204,6 +220,12 @@ pub enum DummyConnectorPaymentStatus {
     Failed,
     #[default]
     Processing,
+    Pending,
+    #[serde(rename = "requires_capture")]
+    RequiresCapture,
+    #[serde(rename = "partially_captured")]
+    PartiallyCaptured,
+    Cancelled,
 }
 
 impl From<DummyConnectorPaymentStatus> for enums::AttemptStatus {
@@ -212,6 +234,10 @@ impl From<DummyConnectorPaymentStatus> for enums::AttemptStatus {
             DummyConnectorPaymentStatus::Succeeded => Self::Charged,
             DummyConnectorPaymentStatus::Failed => Self::Failure,
             DummyConnectorPaymentStatus::Processing => Self::AuthenticationPending,
+            DummyConnectorPaymentStatus::Pending => Self::Pending,
+            DummyConnectorPaymentStatus::RequiresCapture => Self::Authorized,
+            DummyConnectorPaymentStatus::PartiallyCaptured => Self::PartialCharged,
+            DummyConnectorPaymentStatus::Cancelled => Self::Voided,
         }
     }
 }
@@ -219,12 +245,20 @@ impl From<DummyConnectorPaymentStatus> for enums::AttemptStatus {
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct PaymentsResponse {
     status: DummyConnectorPaymentStatus,
-    id: String,
+    pub id: String,
     amount: i64,
     currency: Currency,
     created: String,
     payment_method_type: PaymentMethodType,
     next_action: Option<DummyConnectorNextAction>,
+    amount_captured: Option<i64>,
+    error: Option<DummyConnectorDeclineDetails>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DummyConnectorDeclineDetails {
+    code: String,
+    message: String,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
@@ -249,9 +283,18 @@ impl<F, T> TryFrom<types::ResponseRouterData<F, PaymentsResponse, T, types::Paym
             .map(|redirection_url| {
                 services::RedirectForm::from((redirection_url, services::Method::Get))
             });
-        Ok(Self {
-            status: enums::AttemptStatus::from(item.response.status),
-            response: Ok(types::PaymentsResponseData::TransactionResponse {
+        let response = match item.response.error {
+            // Declined payments carry the decline code, so that they can be mapped through the
+            // gateway status map
+            Some(error) => Err(types::ErrorResponse {
+                code: error.code,
+                message: error.message.clone(),
+                reason: Some(error.message),
+                status_code: item.http_code,
+                attempt_status: None,
+                connector_transaction_id: Some(item.response.id),
+            }),
+            None => Ok(types::PaymentsResponseData::TransactionResponse {
                 resource_id: types::ResponseId::ConnectorTransactionId(item.response.id),
                 redirection_data,
                 mandate_reference: None,
@@ -261,6 +304,10 @@ impl<F, T> TryFrom<types::ResponseRouterData<F, PaymentsResponse, T, types::Paym
                 incremental_authorization_allowed: None,
                 charge_id: None,
             }),
+        };
+        Ok(Self {
+            status: enums::AttemptStatus::from(item.response.status),
+            response,
             ..item.data
         })
     }
@@ -280,6 +327,20 @@ impl DummyConnectorNextAction {
     }
 }
 
+#[derive(Debug, Serialize)]
+pub struct DummyConnectorCaptureRequest {
+    amount: i64,
+}
+
+impl TryFrom<&types::PaymentsCaptureRouterData> for DummyConnectorCaptureRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: item.request.amount_to_capture,
+        })
+    }
+}
+
 // REFUND :
 // Type definition for RefundRequest
 #[derive(Default, Debug, Serialize)]
@@ -374,3 +435,53 @@ pub struct ErrorData {
     pub message: String,
     pub reason: Option<String>,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DummyConnectorWebhookEventType {
+    PaymentSucceeded,
+    PaymentAuthorized,
+    PaymentFailed,
+    DisputeOpened,
+}
+
+impl From<DummyConnectorWebhookEventType> for api::IncomingWebhookEvent {
+    fn from(value: DummyConnectorWebhookEventType) -> Self {
+        match value {
+            DummyConnectorWebhookEventType::PaymentSucceeded => Self::PaymentIntentSuccess,
+            DummyConnectorWebhookEventType::PaymentAuthorized => {
+                Self::PaymentIntentAuthorizationSuccess
+            }
+            DummyConnectorWebhookEventType::PaymentFailed => Self::PaymentIntentFailure,
+            DummyConnectorWebhookEventType::DisputeOpened => Self::DisputeOpened,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DummyConnectorDispute {
+    pub id: String,
+    pub payment_id: String,
+    pub status: String,
+    pub amount: i64,
+    pub currency: Currency,
+    pub reason: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub evidence_due_by: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "object", rename_all = "snake_case")]
+pub enum DummyConnectorWebhookData {
+    Payment(PaymentsResponse),
+    Dispute(DummyConnectorDispute),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DummyConnectorWebhook {
+    pub event_type: DummyConnectorWebhookEventType,
+    pub data: DummyConnectorWebhookData,
+}
@@ -1661,6 +1661,9 @@ impl api::FileUpload for Stripe {
                     })?
                 }
             }
+            api::FilePurpose::PayoutBatch => Err(errors::ConnectorError::FileValidationFailed {
+                reason: "payout batch files are not uploaded to the connector".to_owned(),
+            })?,
         }
         Ok(())
     }
@@ -159,6 +159,61 @@ pub struct PaymentIntentRequest {
     pub browser_info: Option<StripeBrowserInformation>,
     #[serde(flatten)]
     pub charges: Option<IntentCharges>,
+    #[serde(flatten)]
+    pub installments: Option<StripeInstallmentPlan>,
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+pub struct StripeInstallmentPlan {
+    #[serde(rename = "payment_method_options[card][installments][enabled]")]
+    pub enabled: bool,
+    #[serde(rename = "payment_method_options[card][installments][plan][count]")]
+    pub count: u8,
+    #[serde(rename = "payment_method_options[card][installments][plan][interval]")]
+    pub interval: StripeInstallmentInterval,
+    #[serde(rename = "payment_method_options[card][installments][plan][type]")]
+    pub plan_type: StripeInstallmentPlanType,
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeInstallmentInterval {
+    Month,
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeInstallmentPlanType {
+    FixedCount,
+}
+
+impl TryFrom<api_models::payments::Installments> for StripeInstallmentPlan {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(installments: api_models::payments::Installments) -> Result<Self, Self::Error> {
+        // Stripe only offers fixed count monthly plans financed by the merchant
+        match (installments.plan_type, installments.financing_type) {
+            (
+                api_enums::InstallmentPlanType::Regular,
+                api_enums::InstallmentFinancingType::MerchantFinanced,
+            ) => Ok(Self {
+                enabled: true,
+                count: installments.count,
+                interval: StripeInstallmentInterval::Month,
+                plan_type: StripeInstallmentPlanType::FixedCount,
+            }),
+            (api_enums::InstallmentPlanType::Revolving, _)
+            | (_, api_enums::InstallmentFinancingType::IssuerFinanced) => {
+                Err(errors::ConnectorError::NotSupported {
+                    message: format!(
+                        "{} {} installments",
+                        installments.plan_type, installments.financing_type
+                    ),
+                    connector: "Stripe",
+                }
+                .into())
+            }
+        }
+    }
 }
 
 #[derive(Debug, Eq, PartialEq, Serialize)]
@@ -1869,9 +1924,33 @@ impl TryFrom<(&types::PaymentsAuthorizeRouterData, MinorUnit)> for PaymentIntent
                 };
                 (charges, None)
             }
-            None => (None, item.connector_customer.to_owned().map(Secret::new)),
+            None => match item
+                .request
+                .split_payments
+                .as_ref()
+                .map(|splits| splits.0.as_slice())
+            {
+                Some([split]) => (
+                    Some(IntentCharges {
+                        application_fee_amount: amount - (split.amount - split.fees),
+                        destination_account_id: Some(split.account_id.clone()),
+                    }),
+                    item.connector_customer.to_owned().map(Secret::new),
+                ),
+                Some(_) => Err(errors::ConnectorError::NotSupported {
+                    message: "Split payments to more than one recipient".to_string(),
+                    connector: "Stripe",
+                })?,
+                None => (None, item.connector_customer.to_owned().map(Secret::new)),
+            },
         };
 
+        let installments = item
+            .request
+            .installments
+            .map(StripeInstallmentPlan::try_from)
+            .transpose()?;
+
         Ok(Self {
             amount,                                      //hopefully we don't loose some cents here
             currency: item.request.currency.to_string(), //we need to copy the value and not transfer ownership
@@ -1899,6 +1978,7 @@ impl TryFrom<(&types::PaymentsAuthorizeRouterData, MinorUnit)> for PaymentIntent
             expand: Some(ExpandableObjects::LatestCharge),
             browser_info,
             charges,
+            installments,
         })
     }
 }
@@ -2871,6 +2951,7 @@ pub struct BacsFinancialDetails {
 pub struct RefundRequest {
     pub amount: Option<MinorUnit>, //amount in cents, hence passed as integer
     pub payment_intent: String,
+    pub reverse_transfer: Option<bool>,
     #[serde(flatten)]
     pub meta_data: StripeMetadata,
 }
@@ -2883,6 +2964,7 @@ impl<F> TryFrom<&types::RefundsRouterData<F>> for RefundRequest {
         Ok(Self {
             amount: Some(amount),
             payment_intent,
+            reverse_transfer: item.request.split_refunds.as_ref().map(|_| true),
             meta_data: StripeMetadata {
                 order_id: Some(item.request.refund_id.clone()),
                 is_refund_id_as_reference: Some("true".to_string()),
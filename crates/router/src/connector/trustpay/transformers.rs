@@ -398,6 +398,7 @@ impl TryFrom<&TrustpayRouterData<&types::PaymentsAuthorizeRouterData>> for Trust
             accept_header: Some(browser_info.accept_header.unwrap_or("*".to_string())),
             user_agent: browser_info.user_agent,
             ip_address: browser_info.ip_address,
+            device_fingerprint: browser_info.device_fingerprint,
         };
         let params = get_mandatory_fields(item.router_data)?;
         let amount = item.amount.to_owned();
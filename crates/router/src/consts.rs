@@ -108,6 +108,73 @@ pub const MAX_INTENT_FULFILLMENT_EXPIRY: u32 = 1800;
 /// Min payment intent fulfillment expiry
 pub const MIN_INTENT_FULFILLMENT_EXPIRY: u32 = 60;
 
+/// Max number of days after which uncaptured authorizations can be voided automatically
+pub const MAX_AUTO_VOID_AFTER_DAYS: u16 = 30;
+
+/// Min number of days after which uncaptured authorizations can be voided automatically
+pub const MIN_AUTO_VOID_AFTER_DAYS: u16 = 1;
+
+/// Max size in bytes of the custom HTML and CSS templates configured for links
+pub const MAX_LINK_TEMPLATE_SIZE: usize = 64 * 1024;
+
+/// Max number of installments a payment can be split into
+pub const MAX_INSTALLMENT_COUNT: u8 = 48;
+
+/// Min number of installments a payment can be split into
+pub const MIN_INSTALLMENT_COUNT: u8 = 2;
+
+/// Max size of a payout batch file, in bytes (10 MB)
+pub const MAX_PAYOUT_BATCH_FILE_SIZE: i32 = 10_000_000;
+
+/// Max number of payouts in a payout batch
+pub const MAX_PAYOUT_BATCH_SIZE: usize = 100_000;
+
+/// Max number of rules in a single import of GSM rules, which are applied in one transaction
+pub const MAX_GSM_IMPORT_SIZE: usize = 1_000;
+
+/// Default number of payouts of a batch created concurrently
+pub const DEFAULT_PAYOUT_BATCH_CONCURRENCY: u16 = 10;
+
+/// Max number of payouts of a batch created concurrently
+pub const MAX_PAYOUT_BATCH_CONCURRENCY: u16 = 50;
+
+/// Number of payouts of a batch created in a single run of the payout batch workflow
+pub const PAYOUT_BATCH_CHUNK_SIZE: i64 = 500;
+
+/// Number of rows of a payout batch inserted in a single query
+pub const PAYOUT_BATCH_INSERT_CHUNK_SIZE: usize = 1000;
+
+/// Default duration for which a log filter is applied, in seconds (15 minutes)
+pub const DEFAULT_LOG_FILTER_DURATION_IN_SECS: u64 = 15 * 60;
+
+/// Max duration for which a log filter is applied, in seconds (1 day)
+pub const MAX_LOG_FILTER_DURATION_IN_SECS: u64 = 24 * 60 * 60;
+
+/// Max number of log filters applied at the same time
+pub const MAX_LOG_FILTER_COUNT: usize = 20;
+
+/// Redis hash holding the active log filters by filter ID, applied by the instances started after
+/// they were created
+pub const LOG_FILTERS_REDIS_KEY: &str = "log_filters_by_id";
+
+/// Default number of subscriptions, plans or invoices returned by the subscription list APIs
+pub const DEFAULT_SUBSCRIPTION_LIST_LIMIT: u32 = 10;
+
+/// Max number of subscriptions, plans or invoices returned by the subscription list APIs
+pub const MAX_SUBSCRIPTION_LIST_LIMIT: u32 = 100;
+
+/// Delays after which the payment for a subscription invoice is attempted again, after each
+/// failed attempt. The subscription is cancelled once all of them have been used up.
+pub const SUBSCRIPTION_DUNNING_RETRY_INTERVALS_IN_HOURS: [i64; 3] = [24, 72, 168];
+
+/// Delay after which the status of a subscription invoice payment which is being processed is
+/// checked again, in seconds (1 hour)
+pub const SUBSCRIPTION_PAYMENT_STATUS_CHECK_INTERVAL_IN_SECS: i64 = 60 * 60;
+
+/// Delay after which a subscription is billed again when it could not be locked because it was
+/// being cancelled, in seconds
+pub const SUBSCRIPTION_LOCKED_BILLING_RETRY_INTERVAL_IN_SECS: i64 = 60;
+
 pub const LOCKER_HEALTH_CALL_PATH: &str = "/health";
 
 pub const AUTHENTICATION_ID_PREFIX: &str = "authn";
@@ -22,3 +22,7 @@ pub const REDIS_TOTP_SECRET_TTL_IN_SECS: i64 = 15 * 60; // 15 minutes
 
 pub const REDIS_SSO_PREFIX: &str = "SSO_";
 pub const REDIS_SSO_TTL: i64 = 5 * 60; // 5 minutes
+
+pub const REDIS_SAML_CODE_PREFIX: &str = "SAML_CODE_";
+pub const REDIS_SAML_ASSERTION_PREFIX: &str = "SAML_ASSERTION_";
+pub const REDIS_SAML_ASSERTION_TTL: i64 = 60 * 60; // 1 hour
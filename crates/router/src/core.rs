@@ -2,6 +2,8 @@ pub mod admin;
 pub mod api_keys;
 pub mod api_locking;
 pub mod apple_pay_certificates_migration;
+#[cfg(feature = "olap")]
+pub mod audit_log;
 pub mod authentication;
 pub mod blocklist;
 pub mod cache;
@@ -20,7 +22,9 @@ pub mod files;
 pub mod fraud_check;
 pub mod gsm;
 pub mod health_check;
+pub mod link_hosting;
 pub mod locker_migration;
+pub mod log_filter;
 pub mod mandate;
 pub mod metrics;
 pub mod payment_link;
@@ -34,7 +38,9 @@ pub mod pm_auth;
 pub mod poll;
 pub mod refunds;
 pub mod routing;
+pub mod subscriptions;
 pub mod surcharge_decision_config;
+pub mod tenant;
 #[cfg(feature = "olap")]
 pub mod user;
 #[cfg(feature = "olap")]
@@ -22,6 +22,7 @@ use crate::{
     consts,
     core::{
         errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        link_hosting,
         payments::helpers,
         routing::helpers as routing_helpers,
         utils as core_utils,
@@ -107,6 +108,14 @@ pub async fn create_merchant_account(
             .attach_printable("Invalid routing algorithm given")?;
     }
 
+    if let Some(pm_collect_link_config) = &req.pm_collect_link_config {
+        link_hosting::validate_generic_link_config(
+            &state.conf.custom_domain,
+            "pm_collect_link_config",
+            &pm_collect_link_config.config,
+        )?;
+    }
+
     let pm_collect_link_config = req
         .pm_collect_link_config
         .as_ref()
@@ -458,6 +467,7 @@ pub async fn update_business_profile_cascade(
             collect_shipping_details_from_wallet_connector: None,
             collect_billing_details_from_wallet_connector: None,
             is_connector_agnostic_mit_enabled: None,
+            auto_void_after_days: None,
         };
 
         let update_futures = business_profiles.iter().map(|business_profile| async {
@@ -526,6 +536,14 @@ pub async fn merchant_account_update(
         })
         .transpose()?;
 
+    if let Some(pm_collect_link_config) = &req.pm_collect_link_config {
+        link_hosting::validate_generic_link_config(
+            &state.conf.custom_domain,
+            "pm_collect_link_config",
+            &pm_collect_link_config.config,
+        )?;
+    }
+
     let pm_collect_link_config = req
         .pm_collect_link_config
         .as_ref()
@@ -1525,6 +1543,22 @@ pub async fn create_business_profile(
         helpers::validate_intent_fulfillment_expiry(intent_fulfillment_expiry.to_owned())?;
     }
 
+    if let Some(auto_void_after_days) = request.auto_void_after_days {
+        helpers::validate_auto_void_after_days(auto_void_after_days)?;
+    }
+
+    if let Some(payment_link_config) = &request.payment_link_config {
+        link_hosting::validate_payment_link_config(&state.conf.custom_domain, payment_link_config)?;
+    }
+
+    if let Some(payout_link_config) = &request.payout_link_config {
+        link_hosting::validate_generic_link_config(
+            &state.conf.custom_domain,
+            "payout_link_config",
+            &payout_link_config.config,
+        )?;
+    }
+
     let db = state.store.as_ref();
     let key_store = db
         .get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
@@ -1646,6 +1680,22 @@ pub async fn update_business_profile(
         helpers::validate_intent_fulfillment_expiry(intent_fulfillment_expiry.to_owned())?;
     }
 
+    if let Some(auto_void_after_days) = request.auto_void_after_days {
+        helpers::validate_auto_void_after_days(auto_void_after_days)?;
+    }
+
+    if let Some(payment_link_config) = &request.payment_link_config {
+        link_hosting::validate_payment_link_config(&state.conf.custom_domain, payment_link_config)?;
+    }
+
+    if let Some(payout_link_config) = &request.payout_link_config {
+        link_hosting::validate_generic_link_config(
+            &state.conf.custom_domain,
+            "payout_link_config",
+            &payout_link_config.config,
+        )?;
+    }
+
     let webhook_details = request
         .webhook_details
         .as_ref()
@@ -1736,6 +1786,7 @@ pub async fn update_business_profile(
         collect_billing_details_from_wallet_connector: request
             .collect_billing_details_from_wallet_connector,
         is_connector_agnostic_mit_enabled: request.is_connector_agnostic_mit_enabled,
+        auto_void_after_days: request.auto_void_after_days.map(i32::from),
     };
 
     let updated_business_profile = db
@@ -2136,6 +2187,25 @@ pub(crate) fn validate_auth_and_metadata_type_with_connector(
             threedsecureio::transformers::ThreedsecureioAuthType::try_from(val)?;
             Ok(())
         }
+        api_enums::Connector::RulesEngine => {
+            use euclid::backend::EuclidBackend;
+
+            if !matches!(val, types::ConnectorAuthType::NoKey) {
+                Err(errors::ConnectorError::FailedToObtainAuthType)?
+            }
+            let metadata = utils::to_connector_meta_from_secret::<
+                api_models::frm_rules::FrmRulesEngineMetadata,
+            >(connector_meta_data.clone())
+            .change_context(errors::ConnectorError::InvalidConnectorConfig {
+                config: "metadata",
+            })?;
+            euclid::backend::VirInterpreterBackend::with_program(metadata.rules).change_context(
+                errors::ConnectorError::InvalidConnectorConfig {
+                    config: "metadata.rules",
+                },
+            )?;
+            Ok(())
+        }
     }
 }
 
@@ -0,0 +1,597 @@
+use std::collections::{BTreeSet, HashMap};
+
+use actix_web::HttpRequest;
+use api_models::audit_log as audit_log_api;
+use common_utils::date_time;
+use diesel_models::{audit_log as storage, enums};
+use error_stack::ResultExt;
+use once_cell::sync::Lazy;
+use router_env::{instrument, logger, tracing, types::FlowMetric, Flow};
+
+use crate::{
+    core::{
+        admin, api_keys, configs,
+        errors::{self, RouterResponse},
+        gsm, subscriptions,
+    },
+    routes::SessionState,
+    services::{authentication::AuthenticationType, ApplicationResponse},
+    types::domain,
+};
+
+const AUDIT_LOG_LIST_MAX_LIMIT: i64 = 100;
+
+/// The flows which modify dashboard or merchant configuration, along with the kind of resource
+/// they modify. Only successful requests for these flows are recorded in the audit log.
+///
+/// Fraud rules are configured on the `rules_engine` connector account and custom domains on the
+/// link configs of the merchant account and business profile, so they are covered by those flows.
+static AUDITED_FLOWS: Lazy<HashMap<String, enums::AuditEntityType>> = Lazy::new(|| {
+    use enums::AuditEntityType as Entity;
+
+    [
+        (Flow::MerchantsAccountCreate, Entity::MerchantAccount),
+        (Flow::MerchantsAccountUpdate, Entity::MerchantAccount),
+        (Flow::MerchantsAccountDelete, Entity::MerchantAccount),
+        (Flow::UserMerchantAccountCreate, Entity::MerchantAccount),
+        (Flow::ReconMerchantUpdate, Entity::MerchantAccount),
+        (Flow::BusinessProfileCreate, Entity::BusinessProfile),
+        (Flow::BusinessProfileUpdate, Entity::BusinessProfile),
+        (Flow::BusinessProfileDelete, Entity::BusinessProfile),
+        (Flow::ToggleExtendedCardInfo, Entity::BusinessProfile),
+        (Flow::ToggleConnectorAgnosticMit, Entity::BusinessProfile),
+        (Flow::WebhookSigningSecretRotate, Entity::BusinessProfile),
+        (Flow::WebhookSigningSecretRevoke, Entity::BusinessProfile),
+        (Flow::WebhookEndpointCreate, Entity::BusinessProfile),
+        (Flow::WebhookEndpointUpdate, Entity::BusinessProfile),
+        (Flow::WebhookEndpointDelete, Entity::BusinessProfile),
+        (Flow::WebhookEndpointSecretRotate, Entity::BusinessProfile),
+        (
+            Flow::MerchantConnectorsCreate,
+            Entity::MerchantConnectorAccount,
+        ),
+        (
+            Flow::MerchantConnectorsUpdate,
+            Entity::MerchantConnectorAccount,
+        ),
+        (
+            Flow::MerchantConnectorsDelete,
+            Entity::MerchantConnectorAccount,
+        ),
+        (Flow::ApiKeyCreate, Entity::ApiKey),
+        (Flow::ApiKeyUpdate, Entity::ApiKey),
+        (Flow::ApiKeyRevoke, Entity::ApiKey),
+        (Flow::RoutingCreateConfig, Entity::Routing),
+        (Flow::RoutingLinkConfig, Entity::Routing),
+        (Flow::RoutingUnlinkConfig, Entity::Routing),
+        (Flow::RoutingUpdateConfig, Entity::Routing),
+        (Flow::RoutingUpdateDefaultConfig, Entity::Routing),
+        (Flow::RoutingDeleteConfig, Entity::Routing),
+        (Flow::DecisionManagerUpsertConfig, Entity::Routing),
+        (Flow::DecisionManagerDeleteConfig, Entity::Routing),
+        (Flow::ConfigKeyCreate, Entity::Config),
+        (Flow::CreateConfigKey, Entity::Config),
+        (Flow::ConfigKeyUpdate, Entity::Config),
+        (Flow::ConfigKeyDelete, Entity::Config),
+        (Flow::GsmRuleCreate, Entity::Gsm),
+        (Flow::GsmRuleUpdate, Entity::Gsm),
+        (Flow::GsmRuleDelete, Entity::Gsm),
+        (Flow::GsmRuleImport, Entity::Gsm),
+        (Flow::UnifiedTranslationCreate, Entity::UnifiedTranslation),
+        (Flow::UnifiedTranslationUpdate, Entity::UnifiedTranslation),
+        (Flow::UnifiedTranslationDelete, Entity::UnifiedTranslation),
+        (Flow::AddToBlocklist, Entity::Blocklist),
+        (Flow::DeleteFromBlocklist, Entity::Blocklist),
+        (Flow::ToggleBlocklistGuard, Entity::Blocklist),
+        (Flow::ChangePassword, Entity::User),
+        (Flow::UpdateUserAccountDetails, Entity::User),
+        (Flow::TotpReset, Entity::User),
+        (Flow::UpdateUserRole, Entity::UserRole),
+        (Flow::TransferOrgOwnership, Entity::UserRole),
+        (Flow::InviteMultipleUser, Entity::UserRole),
+        (Flow::ReInviteUser, Entity::UserRole),
+        (Flow::DeleteUserRole, Entity::UserRole),
+        (Flow::CreateRole, Entity::Role),
+        (Flow::UpdateRole, Entity::Role),
+        (
+            Flow::CreateUserAuthenticationMethod,
+            Entity::UserAuthenticationMethod,
+        ),
+        (
+            Flow::UpdateUserAuthenticationMethod,
+            Entity::UserAuthenticationMethod,
+        ),
+        (Flow::TenantCreate, Entity::Tenant),
+        (Flow::TenantSuspend, Entity::Tenant),
+        (Flow::TenantResume, Entity::Tenant),
+        (Flow::TenantDelete, Entity::Tenant),
+        (Flow::LogFilterCreate, Entity::LogFilter),
+        (Flow::LogFilterDelete, Entity::LogFilter),
+        (Flow::SubscriptionPlanCreate, Entity::SubscriptionPlan),
+        (Flow::SubscriptionPlanUpdate, Entity::SubscriptionPlan),
+        (Flow::SubscriptionCreate, Entity::Subscription),
+        (Flow::SubscriptionCancel, Entity::Subscription),
+    ]
+    .into_iter()
+    .map(|(flow, entity_type)| (flow.to_string(), entity_type))
+    .collect()
+});
+
+/// The kind of resource modified by the request, if the flow is audited
+fn get_audited_entity_type(
+    flow: &impl FlowMetric,
+    request: &HttpRequest,
+) -> Option<enums::AuditEntityType> {
+    if request.method() == actix_web::http::Method::GET {
+        return None;
+    }
+
+    AUDITED_FLOWS.get(&flow.to_string()).copied()
+}
+
+/// Loads the state of the resource modified by the request, before the request is handled. The
+/// state is retrieved the same way as the resource is returned by the API, so that it can be
+/// compared with the state recorded after the request.
+///
+/// Resources which are being created, and the kinds of resources which cannot be retrieved, have
+/// no previous state.
+#[instrument(skip_all)]
+pub async fn get_previous_state(
+    state: &SessionState,
+    flow: &impl FlowMetric,
+    auth_type: &AuthenticationType,
+    request: &HttpRequest,
+    serialized_request: &serde_json::Value,
+) -> Option<serde_json::Value> {
+    use enums::AuditEntityType as Entity;
+
+    let entity_type = get_audited_entity_type(flow, request)?;
+    let entity_id = get_entity_id(entity_type, serialized_request, serialized_request, request)?;
+    let merchant_id = auth_type
+        .get_merchant_id()
+        .map(ToOwned::to_owned)
+        .or_else(|| get_string_field(serialized_request, "merchant_id"))
+        .or_else(|| {
+            request
+                .match_info()
+                .get("merchant_id")
+                .map(ToOwned::to_owned)
+        });
+
+    match entity_type {
+        Entity::MerchantAccount => get_response_state(
+            admin::get_merchant_account(
+                state.clone(),
+                api_models::admin::MerchantId {
+                    merchant_id: entity_id,
+                },
+            )
+            .await,
+        ),
+        Entity::BusinessProfile => {
+            get_response_state(admin::retrieve_business_profile(state.clone(), entity_id).await)
+        }
+        Entity::MerchantConnectorAccount => get_response_state(
+            admin::retrieve_payment_connector(state.clone(), merchant_id?, entity_id).await,
+        ),
+        Entity::ApiKey => get_response_state(
+            api_keys::retrieve_api_key(state.clone(), &merchant_id?, &entity_id).await,
+        ),
+        Entity::Config => get_response_state(configs::read_config(state.clone(), &entity_id).await),
+        Entity::Gsm => {
+            let gsm_request = serde_json::from_value(serialized_request.clone()).ok()?;
+            get_response_state(gsm::retrieve_gsm_rule(state.clone(), gsm_request).await)
+        }
+        Entity::SubscriptionPlan => {
+            let merchant_account = get_merchant_account(state, &merchant_id?).await?;
+            get_response_state(
+                subscriptions::retrieve_subscription_plan(
+                    state.clone(),
+                    merchant_account,
+                    entity_id,
+                )
+                .await,
+            )
+        }
+        Entity::Subscription => {
+            let merchant_account = get_merchant_account(state, &merchant_id?).await?;
+            get_response_state(
+                subscriptions::retrieve_subscription(
+                    state.clone(),
+                    merchant_account,
+                    api_models::subscriptions::SubscriptionId {
+                        subscription_id: entity_id,
+                    },
+                )
+                .await,
+            )
+        }
+        Entity::Routing
+        | Entity::Blocklist
+        | Entity::User
+        | Entity::UserRole
+        | Entity::Role
+        | Entity::UserAuthenticationMethod
+        | Entity::Tenant
+        | Entity::LogFilter
+        | Entity::UnifiedTranslation => None,
+    }
+}
+
+/// Serializes the resource returned by a retrieve API, the same way as the response is serialized
+fn get_response_state<T: serde::Serialize>(
+    response: RouterResponse<T>,
+) -> Option<serde_json::Value> {
+    match response {
+        Ok(ApplicationResponse::Json(data) | ApplicationResponse::JsonWithHeaders((data, _))) => {
+            masking::masked_serialize(&data)
+                .map_err(|error| {
+                    logger::error!(?error, "Failed to serialize previous state for audit log");
+                })
+                .ok()
+        }
+        Ok(_) => None,
+        // The resource is being created, or the request is going to fail
+        Err(error) => {
+            logger::debug!(?error, "No previous state for audit log entry");
+            None
+        }
+    }
+}
+
+/// Records an audit log entry for a successful request, if the flow modifies configuration.
+///
+/// Failures are only logged, since the change has already been applied by the time this is called.
+#[instrument(skip_all)]
+pub async fn record_audit_log(
+    state: &SessionState,
+    flow: &impl FlowMetric,
+    auth_type: &AuthenticationType,
+    request: &HttpRequest,
+    serialized_request: &serde_json::Value,
+    serialized_response: Option<&serde_json::Value>,
+    previous_state: Option<serde_json::Value>,
+) {
+    let Some(entity_type) = get_audited_entity_type(flow, request) else {
+        return;
+    };
+
+    let Some((actor_type, actor_id)) = get_actor(auth_type) else {
+        logger::debug!("Skipping audit log for unauthenticated request");
+        return;
+    };
+
+    let after_state = serialized_response
+        .filter(|response| response.is_object())
+        .unwrap_or(serialized_request)
+        .clone();
+
+    // Users modifying their own account details are identified by the actor
+    let entity_id =
+        get_entity_id(entity_type, &after_state, serialized_request, request).or_else(|| {
+            match entity_type {
+                enums::AuditEntityType::User => actor_id.clone(),
+                _ => None,
+            }
+        });
+
+    let merchant_id = auth_type
+        .get_merchant_id()
+        .map(ToOwned::to_owned)
+        .or_else(|| get_string_field(&after_state, "merchant_id"));
+
+    let org_id = match merchant_id.as_deref() {
+        Some(merchant_id) => get_org_id(state, merchant_id).await,
+        None => None,
+    };
+
+    let audit_log = storage::AuditLogNew {
+        id: common_utils::generate_time_ordered_id("audit"),
+        tenant_id: state.tenant.name.clone(),
+        org_id,
+        merchant_id,
+        actor_type,
+        actor_id,
+        action: flow.to_string(),
+        entity_type,
+        entity_id,
+        previous_state,
+        after_state: Some(after_state),
+        ip_address: request
+            .connection_info()
+            .realip_remote_addr()
+            .map(ToOwned::to_owned),
+        request_id: state.request_id.map(|request_id| request_id.to_string()),
+        created_at: date_time::now(),
+    };
+
+    if let Err(error) = state.store.insert_audit_log(audit_log).await {
+        logger::error!(?error, "Failed to insert audit log entry");
+    }
+}
+
+fn get_actor(auth_type: &AuthenticationType) -> Option<(enums::AuditActorType, Option<String>)> {
+    match auth_type {
+        AuthenticationType::ApiKey { key_id, .. } => {
+            Some((enums::AuditActorType::ApiKey, Some(key_id.clone())))
+        }
+        AuthenticationType::AdminApiKey => Some((enums::AuditActorType::AdminApiKey, None)),
+        AuthenticationType::MerchantJwt { user_id, .. } => {
+            Some((enums::AuditActorType::User, user_id.clone()))
+        }
+        AuthenticationType::UserJwt { user_id }
+        | AuthenticationType::SinglePurposeJwt { user_id, .. }
+        | AuthenticationType::SinglePurposeOrLoginJwt { user_id, .. } => {
+            Some((enums::AuditActorType::User, Some(user_id.clone())))
+        }
+        AuthenticationType::MerchantId { .. }
+        | AuthenticationType::PublishableKey { .. }
+        | AuthenticationType::WebhookAuth { .. }
+        | AuthenticationType::NoAuth => None,
+    }
+}
+
+/// Determines the identifier of the modified resource, looking at the response body first, then
+/// the request body and finally the path parameters of the request.
+fn get_entity_id(
+    entity_type: enums::AuditEntityType,
+    after_state: &serde_json::Value,
+    serialized_request: &serde_json::Value,
+    request: &HttpRequest,
+) -> Option<String> {
+    use enums::AuditEntityType as Entity;
+
+    let fields: &[&str] = match entity_type {
+        Entity::MerchantAccount => &["merchant_id", "id"],
+        Entity::BusinessProfile => &["profile_id"],
+        Entity::MerchantConnectorAccount => &["merchant_connector_id"],
+        Entity::ApiKey => &["key_id"],
+        Entity::Routing => &["algorithm_id", "id"],
+        Entity::Config => &["key", "id"],
+        Entity::Blocklist => &["fingerprint_id"],
+        Entity::User | Entity::UserRole => &["user_id"],
+        Entity::Role => &["role_id"],
+        Entity::UserAuthenticationMethod => &["id", "auth_id"],
+        Entity::Tenant => &["tenant_id"],
+        Entity::LogFilter => &["filter_id"],
+        Entity::SubscriptionPlan => &["plan_id"],
+        Entity::Subscription => &["subscription_id"],
+        // Translations are uniquely identified by the unified error and the locale
+        Entity::UnifiedTranslation => {
+            return ["unified_code", "unified_message", "locale"]
+                .iter()
+                .map(|field| {
+                    get_string_field(after_state, field)
+                        .or_else(|| get_string_field(serialized_request, field))
+                })
+                .collect::<Option<Vec<_>>>()
+                .map(|values| values.join("/"));
+        }
+        // GSM rules do not have an identifier, they are uniquely identified by these fields
+        Entity::Gsm => {
+            return ["connector", "flow", "sub_flow", "code", "message"]
+                .iter()
+                .map(|field| {
+                    get_string_field(after_state, field)
+                        .or_else(|| get_string_field(serialized_request, field))
+                })
+                .collect::<Option<Vec<_>>>()
+                .map(|values| values.join("/"));
+        }
+    };
+
+    fields.iter().find_map(|field| {
+        get_string_field(after_state, field)
+            .or_else(|| get_string_field(serialized_request, field))
+            .or_else(|| request.match_info().get(field).map(ToOwned::to_owned))
+    })
+}
+
+fn get_string_field(value: &serde_json::Value, field: &str) -> Option<String> {
+    value
+        .get(field)
+        .and_then(serde_json::Value::as_str)
+        .map(ToOwned::to_owned)
+}
+
+async fn get_org_id(state: &SessionState, merchant_id: &str) -> Option<String> {
+    get_merchant_account(state, merchant_id)
+        .await
+        .map(|merchant_account| merchant_account.organization_id)
+}
+
+async fn get_merchant_account(
+    state: &SessionState,
+    merchant_id: &str,
+) -> Option<domain::MerchantAccount> {
+    let db = state.store.as_ref();
+    let result = async {
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        db.find_merchant_account_by_merchant_id(merchant_id, &key_store)
+            .await
+    }
+    .await;
+
+    result
+        .map_err(|error| {
+            logger::warn!(
+                ?error,
+                "Failed to fetch merchant account for audit log entry"
+            );
+        })
+        .ok()
+}
+
+#[instrument(skip(state))]
+pub async fn list_audit_logs(
+    state: SessionState,
+    merchant_id: String,
+    constraints: audit_log_api::AuditLogListConstraints,
+) -> RouterResponse<Vec<audit_log_api::AuditLogListItemResponse>> {
+    let limit = match constraints.limit.map(i64::from) {
+        Some(limit) if limit <= AUDIT_LOG_LIST_MAX_LIMIT => Ok(limit),
+        Some(_) => Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("`limit` must be a number less than {AUDIT_LOG_LIST_MAX_LIMIT}"),
+        }),
+        None => Ok(AUDIT_LOG_LIST_MAX_LIMIT),
+    }?;
+
+    let audit_logs = state
+        .store
+        .list_audit_logs_by_merchant_id_constraints(
+            &merchant_id,
+            storage::AuditLogListConstraints {
+                entity_type: constraints.entity_type,
+                entity_id: constraints.entity_id,
+                actor_id: constraints.actor_id,
+                action: constraints.action,
+                created_after: constraints.created_after,
+                created_before: constraints.created_before,
+                limit: Some(limit),
+                offset: constraints.offset.map(i64::from),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list audit logs with specified constraints")?;
+
+    Ok(ApplicationResponse::Json(
+        audit_logs
+            .into_iter()
+            .map(|audit_log| audit_log_api::AuditLogListItemResponse {
+                changed_fields: get_changed_fields(
+                    audit_log.previous_state.as_ref(),
+                    audit_log.after_state.as_ref(),
+                ),
+                id: audit_log.id,
+                org_id: audit_log.org_id,
+                merchant_id: audit_log.merchant_id,
+                actor_type: audit_log.actor_type,
+                actor_id: audit_log.actor_id,
+                action: audit_log.action,
+                entity_type: audit_log.entity_type,
+                entity_id: audit_log.entity_id,
+                previous: audit_log.previous_state,
+                after: audit_log.after_state,
+                ip_address: audit_log.ip_address,
+                request_id: audit_log.request_id,
+                created: audit_log.created_at,
+            })
+            .collect(),
+    ))
+}
+
+/// Lists the top-level fields which were added, removed or modified between the two states.
+fn get_changed_fields(
+    before: Option<&serde_json::Value>,
+    after: Option<&serde_json::Value>,
+) -> Vec<String> {
+    let empty = serde_json::Map::new();
+    let before = before
+        .and_then(serde_json::Value::as_object)
+        .unwrap_or(&empty);
+    let after = after
+        .and_then(serde_json::Value::as_object)
+        .unwrap_or(&empty);
+
+    before
+        .keys()
+        .chain(after.keys())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .filter(|field| before.get(*field) != after.get(*field))
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_audited_flows() {
+        use enums::AuditEntityType as Entity;
+
+        for (flow, entity_type) in [
+            (Flow::TenantCreate, Entity::Tenant),
+            (Flow::TenantDelete, Entity::Tenant),
+            (Flow::LogFilterCreate, Entity::LogFilter),
+            (Flow::SubscriptionPlanUpdate, Entity::SubscriptionPlan),
+            (Flow::SubscriptionCancel, Entity::Subscription),
+            (Flow::GsmRuleImport, Entity::Gsm),
+            (Flow::UnifiedTranslationUpdate, Entity::UnifiedTranslation),
+            (
+                Flow::MerchantConnectorsUpdate,
+                Entity::MerchantConnectorAccount,
+            ),
+            (Flow::BusinessProfileUpdate, Entity::BusinessProfile),
+        ] {
+            assert_eq!(AUDITED_FLOWS.get(&flow.to_string()), Some(&entity_type));
+        }
+
+        assert!(!AUDITED_FLOWS.contains_key(&Flow::TenantList.to_string()));
+        assert!(!AUDITED_FLOWS.contains_key(&Flow::SubscriptionRetrieve.to_string()));
+    }
+
+    #[test]
+    fn test_get_entity_id() {
+        use enums::AuditEntityType as Entity;
+
+        let request = actix_web::test::TestRequest::default().to_http_request();
+
+        assert_eq!(
+            get_entity_id(
+                Entity::UnifiedTranslation,
+                &json!({ "status": "ok" }),
+                &json!({ "unified_code": "UE_1", "unified_message": "Declined", "locale": "fr" }),
+                &request,
+            ),
+            Some("UE_1/Declined/fr".to_string())
+        );
+        assert_eq!(
+            get_entity_id(
+                Entity::Subscription,
+                &json!({ "subscription_id": "sub_1" }),
+                &json!({}),
+                &request,
+            ),
+            Some("sub_1".to_string())
+        );
+
+        let request = actix_web::test::TestRequest::default()
+            .param("tenant_id", "tenant_1")
+            .to_http_request();
+        assert_eq!(
+            get_entity_id(Entity::Tenant, &json!({}), &json!({}), &request),
+            Some("tenant_1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_get_changed_fields() {
+        let before = json!({
+            "merchant_name": "old",
+            "return_url": "https://example.com",
+            "metadata": { "key": "value" },
+        });
+        let after = json!({
+            "merchant_name": "new",
+            "return_url": "https://example.com",
+            "webhook_details": { "webhook_url": "https://example.com/webhooks" },
+        });
+
+        assert_eq!(
+            get_changed_fields(Some(&before), Some(&after)),
+            vec!["merchant_name", "metadata", "webhook_details"]
+        );
+        assert_eq!(get_changed_fields(None, Some(&after)).len(), 3);
+        assert!(get_changed_fields(Some(&before), Some(&before)).is_empty());
+    }
+}
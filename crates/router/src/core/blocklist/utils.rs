@@ -1,22 +1,44 @@
+use std::{
+    collections::BTreeSet,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    str::FromStr,
+};
+
 use api_models::blocklist as api_blocklist;
-use common_enums::MerchantDecision;
-use common_utils::errors::CustomResult;
+use common_enums::{BlocklistDataKind, MerchantDecision};
+use common_utils::{
+    crypto::{HmacSha256, SignMessage},
+    errors::CustomResult,
+    ext_traits::{Encode, StringExt, ValueExt},
+    pii,
+};
 use diesel_models::configs;
 use error_stack::ResultExt;
-use masking::StrongSecret;
+use masking::{PeekInterface, StrongSecret};
+use router_env::metrics::add_attributes;
 
 use super::{errors, transformers::generate_fingerprint, SessionState};
 use crate::{
     consts,
     core::{
+        api_locking,
         errors::{RouterResult, StorageErrorExt},
         payments::PaymentData,
     },
     logger,
-    types::{domain, storage, transformers::ForeignInto},
+    routes::{lock_utils, metrics},
+    types::{domain, storage, transformers::ForeignInto, BrowserInformation},
     utils,
 };
 
+/// Prefix lengths of the IP ranges blocked by a merchant, used to look up the ranges an IP
+/// address of a payment could belong to
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+struct BlockedIpRangePrefixLengths {
+    v4: BTreeSet<u8>,
+    v6: BTreeSet<u8>,
+}
+
 pub async fn delete_entry_from_blocklist(
     state: &SessionState,
     merchant_id: String,
@@ -38,6 +60,11 @@ pub async fn delete_entry_from_blocklist(
             .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
                 message: "no blocklist record for the given fingerprint id was found".to_string(),
             })?,
+
+        identifier_request => {
+            let (data_kind, value) = get_blocklist_identifier(&identifier_request)?;
+            delete_identifier_blocklist_entry(state, &merchant_id, data_kind, &value).await?
+        }
     };
 
     Ok(blocklist_entry.foreign_into())
@@ -145,7 +172,7 @@ pub async fn insert_entry_into_blocklist(
     let blocklist_entry = match &to_block {
         api_blocklist::AddToBlocklistRequest::CardBin(bin) => {
             validate_card_bin(bin)?;
-            duplicate_check_insert_bin(
+            duplicate_check_insert_entry(
                 bin,
                 state,
                 &merchant_id,
@@ -156,7 +183,7 @@ pub async fn insert_entry_into_blocklist(
 
         api_blocklist::AddToBlocklistRequest::ExtendedCardBin(bin) => {
             validate_extended_card_bin(bin)?;
-            duplicate_check_insert_bin(
+            duplicate_check_insert_entry(
                 bin,
                 state,
                 &merchant_id,
@@ -202,6 +229,22 @@ pub async fn insert_entry_into_blocklist(
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("failed to add fingerprint to blocklist")?
         }
+
+        identifier_request => {
+            let (data_kind, value) = get_blocklist_identifier(identifier_request)?;
+            if let api_blocklist::AddToBlocklistRequest::IpRange(ip_range) = identifier_request {
+                let (network, prefix_length) = parse_ip_range(ip_range)?;
+                add_blocked_ip_range_prefix_length(state, &merchant_id, network, prefix_length)
+                    .await?;
+            }
+            duplicate_check_insert_entry(
+                &get_identifier_fingerprint(state, &merchant_id, data_kind, &value).await?,
+                state,
+                &merchant_id,
+                data_kind,
+            )
+            .await?
+        }
     };
     Ok(blocklist_entry.foreign_into())
 }
@@ -244,23 +287,26 @@ fn get_merchant_fingerprint_secret_key(merchant_id: &str) -> String {
     format!("fingerprint_secret_{merchant_id}")
 }
 
-async fn duplicate_check_insert_bin(
-    bin: &str,
+async fn duplicate_check_insert_entry(
+    fingerprint_id: &str,
     state: &SessionState,
     merchant_id: &str,
-    data_kind: common_enums::BlocklistDataKind,
+    data_kind: BlocklistDataKind,
 ) -> RouterResult<storage::Blocklist> {
     let blocklist_entry_result = state
         .store
-        .find_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, bin)
+        .find_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, fingerprint_id)
         .await;
 
     match blocklist_entry_result {
         Ok(_) => {
-            return Err(errors::ApiErrorResponse::PreconditionFailed {
-                message: "provided bin is already blocked".to_string(),
-            }
-            .into());
+            let message = match data_kind {
+                BlocklistDataKind::CardBin | BlocklistDataKind::ExtendedCardBin => {
+                    "provided bin is already blocked".to_string()
+                }
+                data_kind => format!("provided {data_kind} is already blocked"),
+            };
+            return Err(errors::ApiErrorResponse::PreconditionFailed { message }.into());
         }
 
         Err(e) if e.current_context().is_db_not_found() => {}
@@ -276,7 +322,7 @@ async fn duplicate_check_insert_bin(
         .store
         .insert_blocklist_entry(storage::BlocklistNew {
             merchant_id: merchant_id.to_string(),
-            fingerprint_id: bin.to_string(),
+            fingerprint_id: fingerprint_id.to_string(),
             data_kind,
             metadata: None,
             created_at: common_utils::date_time::now(),
@@ -300,6 +346,407 @@ async fn delete_card_bin_blocklist_entry(
         })
 }
 
+async fn delete_identifier_blocklist_entry(
+    state: &SessionState,
+    merchant_id: &str,
+    data_kind: BlocklistDataKind,
+    value: &str,
+) -> RouterResult<storage::Blocklist> {
+    let fingerprint_id = get_identifier_fingerprint(state, merchant_id, data_kind, value).await?;
+    state
+        .store
+        .delete_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, &fingerprint_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("could not find a blocklist entry for the given {data_kind}"),
+        })
+}
+
+/// Validates and normalizes the customer identifier to be blocked, for the requests which block
+/// one
+fn get_blocklist_identifier(
+    request: &api_blocklist::BlocklistRequest,
+) -> RouterResult<(BlocklistDataKind, String)> {
+    match request {
+        api_blocklist::BlocklistRequest::Email(email) => {
+            Ok((BlocklistDataKind::Email, normalize_email(email)?))
+        }
+        api_blocklist::BlocklistRequest::EmailDomain(domain) => Ok((
+            BlocklistDataKind::EmailDomain,
+            normalize_email_domain(domain)?,
+        )),
+        api_blocklist::BlocklistRequest::IpAddress(ip_address) => {
+            let ip_address = IpAddr::from_str(ip_address.trim()).map_err(|_| {
+                errors::ApiErrorResponse::InvalidDataFormat {
+                    field_name: "data".to_string(),
+                    expected_format: "an IPv4 or IPv6 address".to_string(),
+                }
+            })?;
+            Ok((BlocklistDataKind::IpAddress, ip_address.to_string()))
+        }
+        api_blocklist::BlocklistRequest::IpRange(ip_range) => {
+            let (network, prefix_length) = parse_ip_range(ip_range)?;
+            Ok((
+                BlocklistDataKind::IpRange,
+                format!("{network}/{prefix_length}"),
+            ))
+        }
+        api_blocklist::BlocklistRequest::PhoneNumber(phone_number) => {
+            let phone_number = normalize_phone_number(phone_number).ok_or(
+                errors::ApiErrorResponse::InvalidDataFormat {
+                    field_name: "data".to_string(),
+                    expected_format: "a phone number in the E.164 format".to_string(),
+                },
+            )?;
+            Ok((BlocklistDataKind::PhoneNumber, phone_number))
+        }
+        api_blocklist::BlocklistRequest::CustomerId(customer_id) => Ok((
+            BlocklistDataKind::CustomerId,
+            validate_non_empty(customer_id)?,
+        )),
+        api_blocklist::BlocklistRequest::DeviceFingerprint(device_fingerprint) => Ok((
+            BlocklistDataKind::DeviceFingerprint,
+            validate_non_empty(device_fingerprint)?,
+        )),
+        api_blocklist::BlocklistRequest::CardBin(_)
+        | api_blocklist::BlocklistRequest::ExtendedCardBin(_)
+        | api_blocklist::BlocklistRequest::Fingerprint(_) => {
+            Err(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("card data is not a customer identifier")
+        }
+    }
+}
+
+fn normalize_email(email: &str) -> RouterResult<String> {
+    let email = email.trim().to_lowercase();
+    pii::Email::from_str(&email).change_context(errors::ApiErrorResponse::InvalidDataFormat {
+        field_name: "data".to_string(),
+        expected_format: "a valid email address".to_string(),
+    })?;
+    Ok(email)
+}
+
+fn normalize_email_domain(domain: &str) -> RouterResult<String> {
+    let domain = domain.trim().trim_start_matches('@').to_lowercase();
+    let is_valid = domain.contains('.')
+        && !domain.starts_with('.')
+        && !domain.ends_with('.')
+        && !domain.contains(|c: char| c == '@' || c.is_whitespace());
+    if is_valid {
+        Ok(domain)
+    } else {
+        Err(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "data".to_string(),
+            expected_format: "an email domain, such as example.com".to_string(),
+        }
+        .into())
+    }
+}
+
+/// Normalizes a phone number to the E.164 format, ignoring the separators commonly used in
+/// phone numbers
+fn normalize_phone_number(phone_number: &str) -> Option<String> {
+    let digits = phone_number
+        .trim()
+        .strip_prefix('+')?
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '-' | '.' | '(' | ')'))
+        .collect::<String>();
+    let is_valid = (6..=15).contains(&digits.len()) && digits.chars().all(|c| c.is_ascii_digit());
+    is_valid.then(|| format!("+{digits}"))
+}
+
+fn validate_non_empty(value: &str) -> RouterResult<String> {
+    let value = value.trim();
+    if value.is_empty() {
+        Err(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "data".to_string(),
+            expected_format: "a non-empty string".to_string(),
+        }
+        .into())
+    } else {
+        Ok(value.to_string())
+    }
+}
+
+/// Parses an IP range in the CIDR notation into its network address and prefix length
+fn parse_ip_range(ip_range: &str) -> RouterResult<(IpAddr, u8)> {
+    ip_range
+        .trim()
+        .split_once('/')
+        .and_then(|(address, prefix_length)| {
+            let address = IpAddr::from_str(address).ok()?;
+            let prefix_length = u8::from_str(prefix_length).ok()?;
+            Some((mask_ip_address(address, prefix_length)?, prefix_length))
+        })
+        .ok_or(
+            errors::ApiErrorResponse::InvalidDataFormat {
+                field_name: "data".to_string(),
+                expected_format: "an IP range in the CIDR notation, such as 203.0.113.0/24"
+                    .to_string(),
+            }
+            .into(),
+        )
+}
+
+/// Clears the host bits of the IP address, returning the address of the network of the given
+/// prefix length it belongs to
+fn mask_ip_address(ip_address: IpAddr, prefix_length: u8) -> Option<IpAddr> {
+    match ip_address {
+        IpAddr::V4(address) => {
+            let host_bits = 32_u32.checked_sub(u32::from(prefix_length))?;
+            let mask = u32::MAX.checked_shl(host_bits).unwrap_or(0);
+            Some(IpAddr::V4(Ipv4Addr::from(u32::from(address) & mask)))
+        }
+        IpAddr::V6(address) => {
+            let host_bits = 128_u32.checked_sub(u32::from(prefix_length))?;
+            let mask = u128::MAX.checked_shl(host_bits).unwrap_or(0);
+            Some(IpAddr::V6(Ipv6Addr::from(u128::from(address) & mask)))
+        }
+    }
+}
+
+/// Provides the identifier for the config holding the prefix lengths of the IP ranges blocked by
+/// the merchant
+fn get_blocked_ip_range_prefix_lengths_key(merchant_id: &str) -> String {
+    format!("blocked_ip_range_prefix_lengths_{merchant_id}")
+}
+
+/// Records the prefix length of a blocked IP range, the config is updated while holding a lock on
+/// it so that prefix lengths added by concurrent requests are not lost
+async fn add_blocked_ip_range_prefix_length(
+    state: &SessionState,
+    merchant_id: &str,
+    network: IpAddr,
+    prefix_length: u8,
+) -> RouterResult<()> {
+    let key = get_blocked_ip_range_prefix_lengths_key(merchant_id);
+    let lock_action = api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: key.clone(),
+            api_identifier: lock_utils::ApiIdentifier::Blocklist,
+            override_lock_retries: None,
+        },
+    };
+    lock_action
+        .clone()
+        .perform_locking_action(state, merchant_id.to_string())
+        .await?;
+
+    let result = update_blocked_ip_range_prefix_lengths(state, key, network, prefix_length).await;
+
+    lock_action
+        .free_lock_action(state, merchant_id.to_string())
+        .await?;
+    result
+}
+
+async fn update_blocked_ip_range_prefix_lengths(
+    state: &SessionState,
+    key: String,
+    network: IpAddr,
+    prefix_length: u8,
+) -> RouterResult<()> {
+    let existing_config = match state.store.find_config_by_key(&key).await {
+        Ok(config) => Some(config),
+        Err(e) if e.current_context().is_db_not_found() => None,
+        Err(e) => Err(e)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("error fetching the blocked IP range prefix lengths")?,
+    };
+    let mut prefix_lengths: BlockedIpRangePrefixLengths = existing_config
+        .as_ref()
+        .map(|config| config.config.parse_struct("BlockedIpRangePrefixLengths"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("error parsing the blocked IP range prefix lengths")?
+        .unwrap_or_default();
+
+    let is_new_prefix_length = match network {
+        IpAddr::V4(_) => prefix_lengths.v4.insert(prefix_length),
+        IpAddr::V6(_) => prefix_lengths.v6.insert(prefix_length),
+    };
+    if !is_new_prefix_length {
+        return Ok(());
+    }
+
+    let config = prefix_lengths
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("error serializing the blocked IP range prefix lengths")?;
+    if existing_config.is_some() {
+        state
+            .store
+            .update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(config),
+                },
+            )
+            .await
+            .map(|_| ())
+    } else {
+        state
+            .store
+            .insert_config(configs::ConfigNew { key, config })
+            .await
+            .map(|_| ())
+    }
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("error updating the blocked IP range prefix lengths")
+}
+
+async fn get_blocked_ip_range_prefix_lengths(
+    state: &SessionState,
+    merchant_id: &str,
+) -> BlockedIpRangePrefixLengths {
+    let key = get_blocked_ip_range_prefix_lengths_key(merchant_id);
+    match state.store.find_config_by_key(&key).await {
+        Ok(config) => StringExt::<BlockedIpRangePrefixLengths>::parse_struct(
+            &config.config,
+            "BlockedIpRangePrefixLengths",
+        )
+        .map_err(|error| {
+            logger::error!(?error, "error parsing the blocked IP range prefix lengths")
+        })
+        .unwrap_or_default(),
+        Err(e) if e.current_context().is_db_not_found() => BlockedIpRangePrefixLengths::default(),
+        Err(error) => {
+            logger::error!(?error, "error fetching the blocked IP range prefix lengths");
+            BlockedIpRangePrefixLengths::default()
+        }
+    }
+}
+
+/// Customer identifiers are blocked by an HMAC of their normalized value, keyed with the
+/// fingerprint secret of the merchant, so that the raw values are neither stored in nor returned
+/// from the blocklist.
+async fn get_identifier_fingerprint(
+    state: &SessionState,
+    merchant_id: &str,
+    data_kind: BlocklistDataKind,
+    value: &str,
+) -> RouterResult<String> {
+    let merchant_fingerprint_secret = get_merchant_fingerprint_secret(state, merchant_id).await?;
+    hash_identifier(&merchant_fingerprint_secret, data_kind, value)
+}
+
+fn hash_identifier(
+    merchant_fingerprint_secret: &str,
+    data_kind: BlocklistDataKind,
+    value: &str,
+) -> RouterResult<String> {
+    HmacSha256
+        .sign_message(
+            merchant_fingerprint_secret.as_bytes(),
+            format!("{data_kind}:{value}").as_bytes(),
+        )
+        .map(hex::encode)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("error in blocklist identifier fingerprint creation")
+}
+
+/// Fingerprints of the customer identifiers of the payment which could be blocked
+async fn get_identifier_fingerprints<F>(
+    state: &SessionState,
+    merchant_fingerprint_secret: &str,
+    payment_data: &PaymentData<F>,
+) -> RouterResult<Vec<String>>
+where
+    F: Send + Clone,
+{
+    let mut identifiers = Vec::new();
+
+    let billing = payment_data.address.get_payment_billing();
+    let shipping = payment_data.address.get_shipping();
+
+    let email = payment_data
+        .email
+        .as_ref()
+        .or(billing.and_then(|billing| billing.email.as_ref()))
+        .map(|email| email.peek().trim().to_lowercase());
+    if let Some(email) = email {
+        if let Some((_, domain)) = email.rsplit_once('@') {
+            identifiers.push((BlocklistDataKind::EmailDomain, domain.to_string()));
+        }
+        identifiers.push((BlocklistDataKind::Email, email));
+    }
+
+    let phone_numbers = [billing, shipping]
+        .into_iter()
+        .flatten()
+        .filter_map(|address| address.phone.as_ref())
+        .filter_map(|phone| {
+            let number = phone.number.as_ref()?;
+            let country_code = phone.country_code.as_deref().unwrap_or_default();
+            normalize_phone_number(&format!(
+                "+{}{}",
+                country_code.trim_start_matches('+'),
+                number.peek()
+            ))
+        })
+        .collect::<BTreeSet<_>>();
+    identifiers.extend(
+        phone_numbers
+            .into_iter()
+            .map(|phone_number| (BlocklistDataKind::PhoneNumber, phone_number)),
+    );
+
+    if let Some(customer_id) = payment_data.payment_intent.customer_id.as_ref() {
+        identifiers.push((
+            BlocklistDataKind::CustomerId,
+            customer_id.get_string_repr().to_string(),
+        ));
+    }
+
+    let browser_info = payment_data
+        .payment_attempt
+        .browser_info
+        .clone()
+        .map(|browser_info| browser_info.parse_value::<BrowserInformation>("BrowserInformation"))
+        .transpose()
+        .map_err(|error| logger::error!(?error, "error parsing browser info for blocklist"))
+        .ok()
+        .flatten();
+
+    if let Some(ip_address) = browser_info
+        .as_ref()
+        .and_then(|browser_info| browser_info.ip_address)
+    {
+        identifiers.push((BlocklistDataKind::IpAddress, ip_address.to_string()));
+
+        let merchant_id = payment_data.payment_attempt.merchant_id.as_str();
+        let prefix_lengths = get_blocked_ip_range_prefix_lengths(state, merchant_id).await;
+        let prefix_lengths = match ip_address {
+            IpAddr::V4(_) => prefix_lengths.v4,
+            IpAddr::V6(_) => prefix_lengths.v6,
+        };
+        identifiers.extend(prefix_lengths.into_iter().filter_map(|prefix_length| {
+            mask_ip_address(ip_address, prefix_length).map(|network| {
+                (
+                    BlocklistDataKind::IpRange,
+                    format!("{network}/{prefix_length}"),
+                )
+            })
+        }));
+    }
+
+    if let Some(device_fingerprint) = browser_info
+        .and_then(|browser_info| browser_info.device_fingerprint)
+        .filter(|device_fingerprint| !device_fingerprint.trim().is_empty())
+    {
+        identifiers.push((
+            BlocklistDataKind::DeviceFingerprint,
+            device_fingerprint.trim().to_string(),
+        ));
+    }
+
+    identifiers
+        .into_iter()
+        .map(|(data_kind, value)| hash_identifier(merchant_fingerprint_secret, data_kind, &value))
+        .collect()
+}
+
 pub async fn validate_data_for_blocklist<F>(
     state: &SessionState,
     merchant_account: &domain::MerchantAccount,
@@ -386,20 +833,37 @@ where
         ));
     }
 
+    // Hashed customer identifiers to check whether or not this payment should be blocked.
+    let identifier_fingerprints =
+        get_identifier_fingerprints(state, &merchant_fingerprint_secret, payment_data).await?;
+    for identifier_fingerprint in identifier_fingerprints.iter() {
+        blocklist_futures.push(db.find_blocklist_entry_by_merchant_id_fingerprint_id(
+            merchant_id,
+            identifier_fingerprint,
+        ));
+    }
+
     let blocklist_lookups = futures::future::join_all(blocklist_futures).await;
 
-    let mut should_payment_be_blocked = false;
+    // Kinds of the blocked data of the payment, in the order the lookups were made
+    let mut blocked_data_kinds = Vec::new();
     for lookup in blocklist_lookups {
         match lookup {
-            Ok(_) => {
-                should_payment_be_blocked = true;
-            }
+            Ok(blocklist_entry) => blocked_data_kinds.push(blocklist_entry.data_kind),
+            Err(e) if e.current_context().is_db_not_found() => {}
             Err(e) => {
                 logger::error!(blocklist_db_error=?e, "failed db operations for blocklist");
             }
         }
     }
-    if should_payment_be_blocked {
+    if let Some(blocked_data_kind) = blocked_data_kinds.first().cloned() {
+        logger::info!(?blocked_data_kinds, "payment blocked by the blocklist");
+        metrics::BLOCKED_PAYMENT_COUNT.add(
+            &metrics::CONTEXT,
+            1,
+            &add_attributes([("data_kind", blocked_data_kind.to_string())]),
+        );
+
         // Update db for attempt and intent status.
         db.update_payment_intent(
             payment_data.payment_intent.clone(),
@@ -417,7 +881,7 @@ where
             "Failed to update status in Payment Intent to failed due to it being blocklisted",
         )?;
 
-        // If payment is blocked not showing connector details
+        // If payment is blocked not showing connector details, nor the kind of the blocked data
         let attempt_update = storage::PaymentAttemptUpdate::BlocklistUpdate {
             status: common_enums::AttemptStatus::Failure,
             error_code: Some(Some("HE-03".to_string())),
@@ -486,3 +950,81 @@ pub async fn generate_payment_fingerprint(
         },
     )
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_normalize_phone_number() {
+        assert_eq!(
+            normalize_phone_number(" +1 (415) 555-0100 "),
+            Some("+14155550100".to_string())
+        );
+        assert_eq!(
+            normalize_phone_number("+44.20.7946.0958"),
+            Some("+442079460958".to_string())
+        );
+        assert_eq!(normalize_phone_number("14155550100"), None);
+        assert_eq!(normalize_phone_number("+1 415 CALL NOW"), None);
+        assert_eq!(normalize_phone_number("+12345"), None);
+        assert_eq!(normalize_phone_number("+1234567890123456"), None);
+    }
+
+    #[test]
+    fn test_normalize_email_domain() {
+        assert_eq!(
+            normalize_email_domain(" @Example.COM ").unwrap(),
+            "example.com"
+        );
+        assert_eq!(
+            normalize_email_domain("mail.example.co.uk").unwrap(),
+            "mail.example.co.uk"
+        );
+        assert!(normalize_email_domain("localhost").is_err());
+        assert!(normalize_email_domain(".example.com").is_err());
+        assert!(normalize_email_domain("example.com.").is_err());
+        assert!(normalize_email_domain("user@example.com").is_err());
+        assert!(normalize_email_domain("exam ple.com").is_err());
+    }
+
+    #[test]
+    fn test_mask_ip_address() {
+        let ipv4_address = IpAddr::from_str("203.0.113.77").unwrap();
+        assert_eq!(
+            mask_ip_address(ipv4_address, 24),
+            Some(IpAddr::from_str("203.0.113.0").unwrap())
+        );
+        assert_eq!(mask_ip_address(ipv4_address, 32), Some(ipv4_address));
+        assert_eq!(
+            mask_ip_address(ipv4_address, 0),
+            Some(IpAddr::from_str("0.0.0.0").unwrap())
+        );
+        assert_eq!(mask_ip_address(ipv4_address, 33), None);
+
+        let ipv6_address = IpAddr::from_str("2001:db8:abcd:12::1").unwrap();
+        assert_eq!(
+            mask_ip_address(ipv6_address, 48),
+            Some(IpAddr::from_str("2001:db8:abcd::").unwrap())
+        );
+        assert_eq!(mask_ip_address(ipv6_address, 128), Some(ipv6_address));
+        assert_eq!(mask_ip_address(ipv6_address, 129), None);
+    }
+
+    #[test]
+    fn test_parse_ip_range() {
+        assert_eq!(
+            parse_ip_range(" 203.0.113.77/24 ").unwrap(),
+            (IpAddr::from_str("203.0.113.0").unwrap(), 24)
+        );
+        assert_eq!(
+            parse_ip_range("2001:db8::1/32").unwrap(),
+            (IpAddr::from_str("2001:db8::").unwrap(), 32)
+        );
+        assert!(parse_ip_range("203.0.113.0").is_err());
+        assert!(parse_ip_range("203.0.113.0/33").is_err());
+        assert!(parse_ip_range("203.0.113.0/-1").is_err());
+        assert!(parse_ip_range("example.com/24").is_err());
+    }
+}
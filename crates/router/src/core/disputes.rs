@@ -1,7 +1,10 @@
 use api_models::{disputes as dispute_models, files as files_api_models};
-use common_utils::ext_traits::{Encode, ValueExt};
+use common_utils::{
+    date_time,
+    ext_traits::{Encode, ValueExt},
+};
 use error_stack::ResultExt;
-use router_env::{instrument, tracing};
+use router_env::{instrument, metrics::add_attributes, tracing};
 pub mod transformers;
 
 use super::{
@@ -10,18 +13,24 @@ use super::{
 };
 use crate::{
     core::{files, payments, utils as core_utils},
-    routes::SessionState,
+    db::StorageInterface,
+    routes::{metrics as route_metrics, SessionState},
     services,
     types::{
         api::{self, disputes},
         domain,
-        storage::enums as storage_enums,
+        storage::{self, enums as storage_enums},
         transformers::ForeignFrom,
         AcceptDisputeRequestData, AcceptDisputeResponse, DefendDisputeRequestData,
         DefendDisputeResponse, SubmitEvidenceRequestData, SubmitEvidenceResponse,
     },
 };
 
+const DISPUTE_DEADLINE_REMINDER_TAG: &str = "DISPUTE";
+const DISPUTE_DEADLINE_REMINDER_NAME: &str = "DISPUTE_DEADLINE_REMINDER";
+const DISPUTE_DEADLINE_REMINDER_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::DisputeDeadlineReminderWorkflow;
+
 #[instrument(skip(state))]
 pub async fn retrieve_dispute(
     state: SessionState,
@@ -464,3 +473,175 @@ pub async fn delete_evidence(
         })?;
     Ok(services::ApplicationResponse::StatusOk)
 }
+
+// Add dispute_deadline_reminder task to the process_tracker table, for disputes which are open and
+// have a challenge deadline.
+// Reminders whose time is already in the past are dropped. If all of them are in the past, a single
+// reminder is scheduled right away, so that the merchant is notified at least once before the deadline.
+// The task ID is derived from the dispute ID, so repeated webhooks for the same dispute are no-ops.
+#[instrument(skip_all)]
+pub async fn add_dispute_deadline_reminder_task(
+    db: &dyn StorageInterface,
+    dispute: &diesel_models::dispute::Dispute,
+    reminder_hours: &[u16],
+) -> errors::RouterResult<()> {
+    if !(dispute.dispute_stage == storage_enums::DisputeStage::Dispute
+        && dispute.dispute_status == storage_enums::DisputeStatus::DisputeOpened)
+    {
+        return Ok(());
+    }
+
+    let Some(challenge_required_by) = dispute.challenge_required_by else {
+        return Ok(());
+    };
+
+    let current_time = date_time::now();
+    if challenge_required_by <= current_time {
+        return Ok(());
+    }
+
+    let pending_reminder_hours =
+        get_pending_reminder_hours(challenge_required_by, current_time, reminder_hours);
+
+    let schedule_time = pending_reminder_hours
+        .first()
+        .map(|hours| get_dispute_deadline_reminder_time(challenge_required_by, *hours))
+        .unwrap_or(current_time);
+
+    let tracking_data = storage::DisputeDeadlineReminderTrackingData {
+        dispute_id: dispute.dispute_id.clone(),
+        merchant_id: dispute.merchant_id.clone(),
+        reminder_hours: pending_reminder_hours,
+    };
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        generate_task_id_for_dispute_deadline_reminder_workflow(&dispute.dispute_id),
+        DISPUTE_DEADLINE_REMINDER_NAME,
+        DISPUTE_DEADLINE_REMINDER_RUNNER,
+        [DISPUTE_DEADLINE_REMINDER_TAG],
+        tracking_data,
+        schedule_time,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct dispute deadline reminder process tracker task")?;
+
+    match db.insert_process(process_tracker_entry).await {
+        Ok(_) => {
+            route_metrics::TASKS_ADDED_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &add_attributes([("flow", "DisputeDeadlineReminder")]),
+            );
+            Ok(())
+        }
+        Err(error) if error.current_context().is_db_unique_violation() => {
+            router_env::logger::debug!(
+                dispute_id = %dispute.dispute_id,
+                "Dispute deadline reminder task already exists, skipping insertion"
+            );
+            Ok(())
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!(
+                    "Failed while inserting dispute deadline reminder to process_tracker: dispute_id: {}",
+                    dispute.dispute_id
+                )
+            }),
+    }
+}
+
+/// Reminders which are still due before the challenge deadline, or a single reminder for the
+/// remaining hours if all of the configured reminders are already in the past
+fn get_pending_reminder_hours(
+    challenge_required_by: time::PrimitiveDateTime,
+    current_time: time::PrimitiveDateTime,
+    reminder_hours: &[u16],
+) -> Vec<u16> {
+    let mut pending_reminder_hours: Vec<u16> = reminder_hours
+        .iter()
+        .copied()
+        .filter(|hours| {
+            get_dispute_deadline_reminder_time(challenge_required_by, *hours) > current_time
+        })
+        .collect();
+
+    if pending_reminder_hours.is_empty() {
+        let hours_remaining = (challenge_required_by - current_time).whole_hours();
+        pending_reminder_hours.push(u16::try_from(hours_remaining).unwrap_or(u16::MAX));
+    }
+
+    pending_reminder_hours
+}
+
+pub fn get_dispute_deadline_reminder_time(
+    challenge_required_by: time::PrimitiveDateTime,
+    reminder_hours: u16,
+) -> time::PrimitiveDateTime {
+    challenge_required_by.saturating_sub(time::Duration::hours(i64::from(reminder_hours)))
+}
+
+fn generate_task_id_for_dispute_deadline_reminder_workflow(dispute_id: &str) -> String {
+    format!("{DISPUTE_DEADLINE_REMINDER_RUNNER}_{DISPUTE_DEADLINE_REMINDER_NAME}_{dispute_id}")
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_pending_reminder_hours_drops_past_reminders() {
+        let challenge_required_by = datetime!(2024-01-10 12:00);
+        let current_time = datetime!(2024-01-08 00:00);
+
+        assert_eq!(
+            get_pending_reminder_hours(challenge_required_by, current_time, &[72, 48, 24, 2]),
+            vec![48, 24, 2]
+        );
+    }
+
+    #[test]
+    fn test_pending_reminder_hours_keeps_all_future_reminders() {
+        let challenge_required_by = datetime!(2024-01-10 12:00);
+        let current_time = datetime!(2024-01-01 00:00);
+
+        assert_eq!(
+            get_pending_reminder_hours(challenge_required_by, current_time, &[72, 24]),
+            vec![72, 24]
+        );
+    }
+
+    #[test]
+    fn test_pending_reminder_hours_falls_back_to_remaining_hours() {
+        let challenge_required_by = datetime!(2024-01-10 12:00);
+        let current_time = datetime!(2024-01-10 10:30);
+
+        assert_eq!(
+            get_pending_reminder_hours(challenge_required_by, current_time, &[72, 24, 2]),
+            vec![1]
+        );
+    }
+
+    #[test]
+    fn test_dispute_deadline_reminder_time() {
+        assert_eq!(
+            get_dispute_deadline_reminder_time(datetime!(2024-01-10 12:00), 36),
+            datetime!(2024-01-09 00:00)
+        );
+        assert_eq!(
+            get_dispute_deadline_reminder_time(datetime!(2024-01-10 12:00), 0),
+            datetime!(2024-01-10 12:00)
+        );
+    }
+
+    #[test]
+    fn test_dispute_deadline_reminder_task_id() {
+        assert_eq!(
+            generate_task_id_for_dispute_deadline_reminder_workflow("dp_123"),
+            "DISPUTE_DEADLINE_REMINDER_WORKFLOW_DISPUTE_DEADLINE_REMINDER_dp_123"
+        );
+    }
+}
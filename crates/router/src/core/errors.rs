@@ -148,6 +148,36 @@ pub enum VaultError {
     GenerateFingerprintFailed,
 }
 
+#[derive(Debug, thiserror::Error)]
+pub enum NetworkTokenizationError {
+    #[error("Network tokenization is not configured")]
+    NotConfigured,
+    #[error("Failed to encode token service request")]
+    RequestEncodingFailed,
+    #[error("Failed to deserialize token service response")]
+    ResponseDeserializationFailed,
+    #[error("Failed to provision network token")]
+    ProvisionTokenFailed,
+    #[error("Failed to fetch cryptogram for network token")]
+    FetchCryptogramFailed,
+    #[error("Failed to delete network token")]
+    DeleteTokenFailed,
+    #[error("Failed to save network token details")]
+    SaveNetworkTokenDataFailed,
+    #[error("Failed to fetch network token details")]
+    FetchNetworkTokenDataFailed,
+    #[error("Failed to fetch saved card from locker")]
+    FetchCardFailed,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CardAccountUpdaterError {
+    #[error("Failed to read account updates from the updater service")]
+    ReadAccountUpdatesFailed,
+    #[error("Failed to deserialize updater service response")]
+    ResponseDeserializationFailed,
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum AwsKmsError {
     #[error("Failed to base64 decode input data")]
@@ -186,6 +216,12 @@ pub enum WebhooksFlowError {
     OutgoingWebhookRetrySchedulingFailed,
     #[error("Outgoing webhook response encoding failed")]
     OutgoingWebhookResponseEncodingFailed,
+    #[error("Failed to fetch webhook signing secrets")]
+    WebhookSigningSecretsFetchFailed,
+    #[error("Webhook endpoint does not exist or has been disabled")]
+    WebhookEndpointUnavailable,
+    #[error("Failed to fetch webhook endpoint")]
+    WebhookEndpointFetchFailed,
 }
 
 impl WebhooksFlowError {
@@ -194,7 +230,8 @@ impl WebhooksFlowError {
             Self::MerchantConfigNotFound
             | Self::MerchantWebhookDetailsNotFound
             | Self::MerchantWebhookUrlNotConfigured
-            | Self::OutgoingWebhookResponseEncodingFailed => false,
+            | Self::OutgoingWebhookResponseEncodingFailed
+            | Self::WebhookEndpointUnavailable => false,
 
             Self::WebhookEventUpdationFailed
             | Self::OutgoingWebhookSigningFailed
@@ -203,7 +240,9 @@ impl WebhooksFlowError {
             | Self::DisputeWebhookValidationFailed
             | Self::OutgoingWebhookEncodingFailed
             | Self::OutgoingWebhookProcessTrackerTaskUpdateFailed
-            | Self::OutgoingWebhookRetrySchedulingFailed => true,
+            | Self::OutgoingWebhookRetrySchedulingFailed
+            | Self::WebhookSigningSecretsFetchFailed
+            | Self::WebhookEndpointFetchFailed => true,
         }
     }
 }
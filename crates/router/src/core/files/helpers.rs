@@ -3,7 +3,10 @@ use common_utils::errors::CustomResult;
 use error_stack::ResultExt;
 use futures::TryStreamExt;
 
+#[cfg(feature = "payouts")]
+use crate::core::payouts;
 use crate::{
+    consts,
     core::{
         errors::{self, StorageErrorExt},
         payments, utils,
@@ -26,6 +29,7 @@ pub async fn get_file_purpose(field: &mut Field) -> Option<api::FilePurpose> {
     let purpose = read_string(field).await;
     match purpose.as_deref() {
         Some("dispute_evidence") => Some(api::FilePurpose::DisputeEvidence),
+        Some("payout_batch") => Some(api::FilePurpose::PayoutBatch),
         _ => None,
     }
 }
@@ -77,6 +81,30 @@ pub async fn validate_file_upload(
                 },
             }
         }
+        api::FilePurpose::PayoutBatch => {
+            let supported_file_types = [mime::TEXT_CSV, mime::APPLICATION_JSON];
+            if !supported_file_types.contains(&create_file_request.file_type) {
+                Err(errors::ApiErrorResponse::FileValidationFailed {
+                    reason: "file_type does not match CSV or JSON format".to_string(),
+                })?
+            }
+            if create_file_request.file_size > consts::MAX_PAYOUT_BATCH_FILE_SIZE {
+                Err(errors::ApiErrorResponse::FileValidationFailed {
+                    reason: "file_size exceeded the max file size of 10MB".to_string(),
+                })?
+            }
+            // The rows are validated upfront, so that an invalid file is rejected before a batch
+            // is created from it
+            #[cfg(feature = "payouts")]
+            {
+                let requests = payouts::batch::parse_payout_batch_file(
+                    create_file_request.file_type.as_ref(),
+                    &create_file_request.file,
+                )?;
+                payouts::batch::validate_payout_batch_requests(&requests)?;
+            }
+            Ok(())
+        }
     }
 }
 
@@ -338,5 +366,18 @@ pub async fn upload_and_get_provider_provider_file_id_profile_id(
                 ))
             }
         }
+        api::FilePurpose::PayoutBatch => {
+            state
+                .file_storage_client
+                .upload_file(&file_key, create_file_request.file.clone())
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+            Ok((
+                file_key,
+                api_models::enums::FileUploadProvider::Router,
+                None,
+                None,
+            ))
+        }
     }
 }
@@ -46,6 +46,7 @@ use crate::{
 };
 pub mod flows;
 pub mod operation;
+pub mod rules_engine;
 pub mod types;
 
 #[instrument(skip_all)]
@@ -105,16 +106,30 @@ where
         frm_data.fraud_check.last_step = FraudCheckLastStep::TransactionOrRecordRefund
     }
 
-    let connector =
-        FraudCheckConnectorData::get_connector_by_name(&frm_data.connector_details.connector_name)?;
-    let router_data_res = router_data
-        .decide_frm_flows(
+    let router_data_res = if frm_data.connector_details.connector_name
+        == api_enums::FrmConnectors::RulesEngine.to_string()
+    {
+        rules_engine::decide_frm_flow(
             state,
-            &connector,
-            payments::CallConnectorAction::Trigger,
-            merchant_account,
+            router_data,
+            payment_data,
+            customer,
+            &merchant_connector_account,
         )
-        .await?;
+        .await?
+    } else {
+        let connector = FraudCheckConnectorData::get_connector_by_name(
+            &frm_data.connector_details.connector_name,
+        )?;
+        router_data
+            .decide_frm_flows(
+                state,
+                &connector,
+                payments::CallConnectorAction::Trigger,
+                merchant_account,
+            )
+            .await?
+    };
 
     Ok(router_data_res)
 }
@@ -0,0 +1,246 @@
+//! In-house fraud rules engine.
+//!
+//! Evaluates the fraud rules program configured in the metadata of a `rules_engine` merchant
+//! connector account over the attributes of the payment and its velocity counts, instead of
+//! calling an external fraud provider. Velocity counts are maintained in Redis sorted sets, with
+//! the time of the attempt as the score, over rolling windows configured in `frm_rules_engine`.
+
+use api_models::frm_rules::{FrmRuleDecision, FrmRuleOutput, FrmRulesEngineMetadata};
+use common_utils::{
+    crypto::{GenerateDigest, Sha256},
+    errors::CustomResult,
+    ext_traits::ValueExt,
+};
+use error_stack::ResultExt;
+use euclid::backend::{self, inputs as dsl_inputs, EuclidBackend};
+use masking::{ExposeInterface, PeekInterface};
+use router_env::logger;
+
+use crate::{
+    core::{
+        blocklist::utils as blocklist_utils,
+        errors::{self, RouterResult},
+        payments::{self, helpers, routing},
+    },
+    types::{
+        self as oss_types, domain, fraud_check as frm_types, storage::enums::FraudCheckStatus,
+        transformers::ForeignFrom, BrowserInformation, ResponseId,
+    },
+    SessionState,
+};
+
+impl ForeignFrom<FrmRuleDecision> for FraudCheckStatus {
+    fn foreign_from(decision: FrmRuleDecision) -> Self {
+        match decision {
+            FrmRuleDecision::Approve => Self::Legit,
+            FrmRuleDecision::Review => Self::ManualReview,
+            FrmRuleDecision::Decline => Self::Fraud,
+        }
+    }
+}
+
+/// Evaluates the fraud rules of the merchant for the payment and populates the response of the
+/// router data with the decision, the same way a fraud provider response would.
+pub async fn decide_frm_flow<F, Req, D>(
+    state: &SessionState,
+    mut router_data: oss_types::RouterData<F, Req, frm_types::FraudCheckResponseData>,
+    payment_data: &payments::PaymentData<D>,
+    customer: &Option<domain::Customer>,
+    merchant_connector_account: &helpers::MerchantConnectorAccountType,
+) -> RouterResult<oss_types::RouterData<F, Req, frm_types::FraudCheckResponseData>>
+where
+    D: Clone,
+{
+    let metadata: FrmRulesEngineMetadata = merchant_connector_account
+        .get_metadata()
+        .ok_or(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "metadata",
+        })
+        .attach_printable("fraud rules are not configured for the rules engine")?
+        .expose()
+        .parse_value("FrmRulesEngineMetadata")
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "metadata",
+        })?;
+
+    let interpreter = backend::VirInterpreterBackend::<FrmRuleOutput>::with_program(metadata.rules)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error initializing the fraud rules interpreter backend")?;
+
+    let velocity = get_velocity_input(state, payment_data, customer).await?;
+    let mut backend_input = routing::make_dsl_input(payment_data)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to construct the input for the fraud rules")?;
+    backend_input.velocity = velocity.clone();
+
+    let output = interpreter
+        .execute(backend_input)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to evaluate the fraud rules")?;
+    logger::debug!(
+        frm_rule = ?output.rule_name,
+        frm_decision = %output.connector_selection.decision,
+        "fraud rules evaluated"
+    );
+
+    router_data.response = Ok(frm_types::FraudCheckResponseData::TransactionResponse {
+        resource_id: ResponseId::NoResponseId,
+        status: FraudCheckStatus::foreign_from(output.connector_selection.decision),
+        connector_metadata: Some(serde_json::json!({ "velocity": velocity })),
+        reason: output.rule_name.map(serde_json::Value::String),
+        score: output.connector_selection.score,
+    });
+
+    Ok(router_data)
+}
+
+/// Records the payment attempt in the velocity windows of its card, customer, IP address and email,
+/// and returns the number of attempts in each window. A velocity count which cannot be computed is
+/// left out of the input, so that rules depending on it do not match.
+async fn get_velocity_input<D: Clone>(
+    state: &SessionState,
+    payment_data: &payments::PaymentData<D>,
+    customer: &Option<domain::Customer>,
+) -> RouterResult<dsl_inputs::VelocityInput> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let windows = &state.conf.frm_rules_engine;
+    let merchant_id = &payment_data.payment_attempt.merchant_id;
+    let attempt_id = &payment_data.payment_attempt.attempt_id;
+    let now = to_score(common_utils::date_time::now_unix_timestamp());
+
+    let card_fingerprint = get_card_fingerprint(state, payment_data).await?;
+    let customer_id = payment_data
+        .payment_intent
+        .customer_id
+        .as_ref()
+        .or(customer.as_ref().map(|customer| &customer.customer_id))
+        .map(|customer_id| customer_id.get_string_repr().to_owned());
+    let ip_address = payment_data
+        .payment_attempt
+        .browser_info
+        .clone()
+        .map(|browser_info| browser_info.parse_value::<BrowserInformation>("BrowserInformation"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "browser_info",
+        })?
+        .and_then(|browser_info| browser_info.ip_address)
+        .map(|ip_address| ip_address.to_string());
+    let email = payment_data
+        .email
+        .as_ref()
+        .map(|email| email.peek().to_lowercase());
+
+    let redis_conn = &*redis_conn;
+    let count_attempts = |kind: &'static str, value: Option<String>, window_in_secs| async move {
+        let key = get_velocity_key(merchant_id, kind, value?.as_str());
+        record_and_count(redis_conn, &key, attempt_id, now, window_in_secs).await
+    };
+    let count_distinct_cards = async {
+        let key = get_velocity_key(merchant_id, "customer_cards", customer_id.as_deref()?);
+        let card_fingerprint = card_fingerprint.as_deref()?;
+        let window_in_secs = windows.customer_distinct_cards_window_in_secs;
+        record_and_count(redis_conn, &key, card_fingerprint, now, window_in_secs).await
+    };
+
+    let (card_count, customer_count, ip_count, email_count, distinct_card_count) = futures::join!(
+        count_attempts(
+            "card",
+            card_fingerprint.clone(),
+            windows.card_attempts_window_in_secs
+        ),
+        count_attempts(
+            "customer",
+            customer_id.clone(),
+            windows.customer_attempts_window_in_secs
+        ),
+        count_attempts(
+            "ip",
+            ip_address.as_deref().and_then(hash_value),
+            windows.ip_attempts_window_in_secs
+        ),
+        count_attempts(
+            "email",
+            email.as_deref().and_then(hash_value),
+            windows.email_attempts_window_in_secs
+        ),
+        count_distinct_cards,
+    );
+
+    Ok(dsl_inputs::VelocityInput {
+        card_attempts: card_count,
+        customer_attempts: customer_count,
+        ip_attempts: ip_count,
+        email_attempts: email_count,
+        customer_distinct_cards: distinct_card_count,
+    })
+}
+
+async fn get_card_fingerprint<D: Clone>(
+    state: &SessionState,
+    payment_data: &payments::PaymentData<D>,
+) -> RouterResult<Option<String>> {
+    match (
+        &payment_data.payment_attempt.fingerprint_id,
+        &payment_data.payment_method_data,
+    ) {
+        (Some(fingerprint_id), _) => Ok(Some(fingerprint_id.clone())),
+        (None, Some(api_models::payments::PaymentMethodData::Card(_))) => {
+            blocklist_utils::generate_payment_fingerprint(
+                state,
+                payment_data.payment_attempt.merchant_id.clone(),
+                payment_data.payment_method_data.clone(),
+            )
+            .await
+        }
+        (None, _) => Ok(None),
+    }
+}
+
+/// Adds the member to the sorted set at the key, drops the members which fell out of the window
+/// and returns the number of members left in the window.
+async fn record_and_count(
+    redis_conn: &redis_interface::RedisConnectionPool,
+    key: &str,
+    member: &str,
+    now: f64,
+    window_in_secs: i64,
+) -> Option<u32> {
+    let result: CustomResult<usize, redis_interface::errors::RedisError> = async {
+        redis_conn.zadd(key, now, member).await?;
+        redis_conn
+            .zremrangebyscore(key, 0.0, now - to_score(window_in_secs))
+            .await?;
+        redis_conn.set_expiry(key, window_in_secs).await?;
+        redis_conn.zcard(key).await
+    }
+    .await;
+
+    result
+        .map_err(|error| logger::error!(?error, "Failed to update the fraud velocity window"))
+        .ok()
+        .and_then(|count| u32::try_from(count).ok())
+}
+
+fn get_velocity_key(merchant_id: &str, kind: &str, value: &str) -> String {
+    format!("frm_velocity_{merchant_id}_{kind}_{value}")
+}
+
+/// Hashes personal data before it is used in a Redis key
+fn hash_value(value: &str) -> Option<String> {
+    Sha256
+        .generate_digest(value.as_bytes())
+        .map(hex::encode)
+        .ok()
+}
+
+// Safety: Unix timestamps and velocity windows are well below 2^53, so they are represented
+// exactly as Redis scores
+#[allow(clippy::as_conversions)]
+fn to_score(seconds: i64) -> f64 {
+    seconds as f64
+}
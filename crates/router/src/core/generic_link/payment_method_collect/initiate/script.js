@@ -56,6 +56,8 @@ function initializeCollectSDK() {
   widgets = hyper.widgets({
     appearance: appearance,
     clientSecret: clientSecret,
+    // @ts-ignore
+    locale: (window.__LINK_LOCALE || {}).locale,
   });
 
   // Create payment method collect widget
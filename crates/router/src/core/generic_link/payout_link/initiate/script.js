@@ -126,6 +126,8 @@ function initializePayoutSDK() {
   widgets = hyper.widgets({
     appearance: appearance,
     clientSecret: clientSecret,
+    // @ts-ignore
+    locale: (window.__LINK_LOCALE || {}).locale,
   });
 
   // Create payment method collect widget
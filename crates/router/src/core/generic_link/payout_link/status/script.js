@@ -1,4 +1,26 @@
 // @ts-check
+
+/**
+ * Trigger - on rendering any user facing text
+ * Uses
+ *  - Look up the localized string for the given key
+ *  - Substitute placeholders of the form {name} using params
+ * @param {string} key
+ * @param {Record<string, string | number>} [params]
+ * @returns {string}
+ */
+function translate(key, params) {
+  // @ts-ignore
+  var linkLocale = window.__LINK_LOCALE || {};
+  var strings = linkLocale.strings || {};
+  var text = typeof strings[key] === "string" ? strings[key] : key;
+  var values = params || {};
+  for (var param in values) {
+    text = text.replace("{" + param + "}", String(values[param]));
+  }
+  return text;
+}
+
 /**
  * Trigger - init
  * Uses
@@ -69,8 +91,8 @@ function renderStatusDetails(payoutDetails) {
   var statusInfo = {
     statusImageSrc:
       "https://live.hyperswitch.io/payment-link-assets/success.png",
-    statusText: "Payout Successful",
-    statusMessage: "Your payout was made to selected payment method.",
+    statusText: translate("payout_successful"),
+    statusMessage: translate("payout_successful_message"),
   };
   switch (status) {
     case "success":
@@ -80,9 +102,8 @@ function renderStatusDetails(payoutDetails) {
     case "pending":
       statusInfo.statusImageSrc =
         "https://live.hyperswitch.io/payment-link-assets/pending.png";
-      statusInfo.statusText = "Payout Processing";
-      statusInfo.statusMessage =
-        "Your payout should be processed within 2-3 business days.";
+      statusInfo.statusText = translate("payout_processing");
+      statusInfo.statusMessage = translate("payout_processing_message");
       break;
     case "failed":
     case "cancelled":
@@ -96,9 +117,8 @@ function renderStatusDetails(payoutDetails) {
     default:
       statusInfo.statusImageSrc =
         "https://live.hyperswitch.io/payment-link-assets/failed.png";
-      statusInfo.statusText = "Payout Failed";
-      statusInfo.statusMessage =
-        "Failed to process your payout. Please check with your provider for more details.";
+      statusInfo.statusText = translate("payout_failed");
+      statusInfo.statusMessage = translate("payout_failed_message");
       break;
   }
 
@@ -119,14 +139,13 @@ function renderStatusDetails(payoutDetails) {
     statusCardNode.appendChild(statusMsgNode);
   }
 
-  var resourceInfo = {
-    "Ref Id": payoutDetails.payout_id,
-  };
+  var resourceInfo = {};
+  resourceInfo[translate("ref_id")] = payoutDetails.payout_id;
   if (typeof payoutDetails.error_code === "string") {
-    resourceInfo["Error Code"] = payoutDetails.error_code;
+    resourceInfo[translate("error_code")] = payoutDetails.error_code;
   }
   if (typeof payoutDetails.error_message === "string") {
-    resourceInfo["Error Message"] = payoutDetails.error_message;
+    resourceInfo[translate("error_message")] = payoutDetails.error_message;
   }
   var resourceNode = document.createElement("div");
   resourceNode.id = "resource-info-container";
@@ -166,8 +185,8 @@ function redirectToEndUrl(returnUrl) {
       var secondsLeft = timeout - j++;
       var innerText =
         secondsLeft === 0
-          ? "Redirecting ..."
-          : "Redirecting in " + secondsLeft + " seconds ...";
+          ? translate("redirecting")
+          : translate("redirecting_in", { seconds: secondsLeft });
       if (statusRedirectTextNode instanceof HTMLDivElement) {
         statusRedirectTextNode.innerText = innerText;
       }
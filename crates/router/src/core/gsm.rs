@@ -1,14 +1,20 @@
-use api_models::gsm as gsm_api_types;
-use diesel_models::gsm as storage;
-use error_stack::ResultExt;
+use std::collections::{HashMap, HashSet};
+
+use api_models::{enums as api_enums, gsm as gsm_api_types};
+use common_utils::{fp_utils::when, types::RetrySchedule};
+use diesel_models::{gsm as storage, unified_translations as translations_storage};
+use error_stack::{report, ResultExt};
 use router_env::{instrument, tracing};
+use storage_impl::redis::cache;
 
 use crate::{
+    consts,
     core::{
         errors,
         errors::{RouterResponse, StorageErrorExt},
+        payments::helpers,
     },
-    db::gsm::GsmInterface,
+    db::{gsm::GsmInterface, unified_translations::UnifiedTranslationsInterface},
     services,
     types::transformers::ForeignInto,
     SessionState,
@@ -20,6 +26,19 @@ pub async fn create_gsm_rule(
     gsm_rule: gsm_api_types::GsmCreateRequest,
 ) -> RouterResponse<gsm_api_types::GsmResponse> {
     let db = state.store.as_ref();
+    when(
+        gsm_rule.decision == gsm_api_types::GsmDecision::RetryLater
+            && gsm_rule.retry_schedule.is_none(),
+        || {
+            Err(report!(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "retry_schedule"
+            }))
+        },
+    )?;
+    if let Some(retry_schedule) = &gsm_rule.retry_schedule {
+        validate_retry_schedule(retry_schedule)?;
+    }
+
     GsmInterface::add_gsm_rule(db, gsm_rule.foreign_into())
         .await
         .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
@@ -67,7 +86,39 @@ pub async fn update_gsm_rule(
         step_up_possible,
         unified_code,
         unified_message,
+        retry_schedule,
     } = gsm_request;
+    if let Some(retry_schedule) = &retry_schedule {
+        validate_retry_schedule(retry_schedule)?;
+    }
+
+    // The updated rule must have a retry schedule when its decision is retry_later, as with the
+    // creation of a rule
+    let existing_rule = GsmInterface::find_gsm_rule(
+        db,
+        connector.to_string(),
+        flow.clone(),
+        sub_flow.clone(),
+        code.clone(),
+        message.clone(),
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+        message: "GSM with given key does not exist in our records".to_string(),
+    })?;
+    let is_retry_later = decision.as_ref().map_or_else(
+        || existing_rule.decision == gsm_api_types::GsmDecision::RetryLater.to_string(),
+        |decision| *decision == gsm_api_types::GsmDecision::RetryLater,
+    );
+    when(
+        is_retry_later && retry_schedule.is_none() && existing_rule.retry_schedule.is_none(),
+        || {
+            Err(report!(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "retry_schedule"
+            }))
+        },
+    )?;
+
     GsmInterface::update_gsm_rule(
         db,
         connector.to_string(),
@@ -82,6 +133,7 @@ pub async fn update_gsm_rule(
             step_up_possible,
             unified_code,
             unified_message,
+            retry_schedule,
         },
     )
     .await
@@ -138,3 +190,758 @@ pub async fn delete_gsm_rule(
         Err(err) => Err(err),
     }
 }
+
+#[instrument(skip_all)]
+pub async fn list_gsm_rules(
+    state: SessionState,
+    request: gsm_api_types::GsmListRequest,
+) -> RouterResponse<Vec<gsm_api_types::GsmResponse>> {
+    let db = state.store.as_ref();
+    let constraints = storage::GatewayStatusMapListConstraints {
+        connector: request.connector.map(|connector| connector.to_string()),
+        flow: request.flow,
+        decision: request.decision.map(|decision| decision.to_string()),
+        limit: request.limit.map(i64::from),
+        offset: request.offset.map(i64::from),
+    };
+
+    GsmInterface::list_gsm_rules(db, constraints)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while listing Gsm rules")
+        .map(|rules| {
+            services::ApplicationResponse::Json(
+                rules.into_iter().map(ForeignInto::foreign_into).collect(),
+            )
+        })
+}
+
+/// A row of a CSV file of GSM rules. The retry schedule is held as JSON, since it cannot be
+/// represented as a single column.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct GsmCsvRecord {
+    connector: String,
+    flow: String,
+    sub_flow: String,
+    code: String,
+    message: String,
+    status: String,
+    router_error: Option<String>,
+    decision: String,
+    step_up_possible: bool,
+    unified_code: Option<String>,
+    unified_message: Option<String>,
+    retry_schedule: Option<String>,
+}
+
+impl TryFrom<GsmCsvRecord> for gsm_api_types::GsmCreateRequest {
+    type Error = String;
+
+    fn try_from(record: GsmCsvRecord) -> Result<Self, Self::Error> {
+        let connector = record
+            .connector
+            .parse::<api_enums::Connector>()
+            .map_err(|_| format!("Invalid connector `{}`", record.connector))?;
+        let decision = record
+            .decision
+            .parse::<gsm_api_types::GsmDecision>()
+            .map_err(|_| format!("Invalid decision `{}`", record.decision))?;
+        let retry_schedule = record
+            .retry_schedule
+            .as_deref()
+            .map(serde_json::from_str::<RetrySchedule>)
+            .transpose()
+            .map_err(|error| format!("Invalid retry_schedule: {error}"))?;
+
+        Ok(Self {
+            connector,
+            flow: record.flow,
+            sub_flow: record.sub_flow,
+            code: record.code,
+            message: record.message,
+            status: record.status,
+            router_error: record.router_error,
+            decision,
+            step_up_possible: record.step_up_possible,
+            unified_code: record.unified_code,
+            unified_message: record.unified_message,
+            retry_schedule,
+        })
+    }
+}
+
+impl TryFrom<storage::GatewayStatusMap> for GsmCsvRecord {
+    type Error = serde_json::Error;
+
+    fn try_from(rule: storage::GatewayStatusMap) -> Result<Self, Self::Error> {
+        Ok(Self {
+            retry_schedule: rule
+                .retry_schedule
+                .as_ref()
+                .map(serde_json::to_string)
+                .transpose()?,
+            connector: rule.connector,
+            flow: rule.flow,
+            sub_flow: rule.sub_flow,
+            code: rule.code,
+            message: rule.message,
+            status: rule.status,
+            router_error: rule.router_error,
+            decision: rule.decision,
+            step_up_possible: rule.step_up_possible,
+            unified_code: rule.unified_code,
+            unified_message: rule.unified_message,
+        })
+    }
+}
+
+/// Parse the rows of an import of GSM rules. Rows are parsed individually, so that the rows which
+/// cannot be parsed are reported along with the rest of the rows.
+fn parse_gsm_import_data(
+    format: gsm_api_types::GsmFileFormat,
+    data: &str,
+) -> errors::RouterResult<Vec<Result<gsm_api_types::GsmCreateRequest, String>>> {
+    match format {
+        gsm_api_types::GsmFileFormat::Csv => Ok(csv::Reader::from_reader(data.as_bytes())
+            .deserialize::<GsmCsvRecord>()
+            .map(|record| {
+                record
+                    .map_err(|error| error.to_string())
+                    .and_then(gsm_api_types::GsmCreateRequest::try_from)
+            })
+            .collect()),
+        gsm_api_types::GsmFileFormat::Json => {
+            let rows = serde_json::from_str::<Vec<serde_json::Value>>(data).map_err(|error| {
+                report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!("Invalid Gsm rules, expected a JSON array: {error}"),
+                })
+            })?;
+            Ok(rows
+                .into_iter()
+                .map(|row| serde_json::from_value(row).map_err(|error| error.to_string()))
+                .collect())
+        }
+    }
+}
+
+/// Get the reason the rule is invalid, if any. The same validations as the creation of a rule are
+/// applied.
+fn get_gsm_rule_error(rule: &gsm_api_types::GsmCreateRequest) -> Option<String> {
+    match &rule.retry_schedule {
+        None if rule.decision == gsm_api_types::GsmDecision::RetryLater => {
+            Some("retry_schedule is required when the decision is retry_later".to_string())
+        }
+        Some(retry_schedule) if !is_valid_retry_schedule(retry_schedule) => {
+            Some(INVALID_RETRY_SCHEDULE_MESSAGE.to_string())
+        }
+        _ => None,
+    }
+}
+
+/// Get the fields of the existing rule which are changed by the imported rule. Optional fields
+/// which are not provided in the imported rule keep their existing values, as with the update of
+/// a rule.
+fn get_changed_fields(
+    existing_rule: &storage::GatewayStatusMap,
+    rule: &gsm_api_types::GsmCreateRequest,
+) -> Vec<String> {
+    let is_provided_and_changed = |new_value: &Option<String>, existing_value: &Option<String>| {
+        new_value.is_some() && new_value != existing_value
+    };
+
+    [
+        ("status", existing_rule.status != rule.status),
+        (
+            "router_error",
+            existing_rule.router_error != rule.router_error,
+        ),
+        (
+            "decision",
+            existing_rule.decision != rule.decision.to_string(),
+        ),
+        (
+            "step_up_possible",
+            existing_rule.step_up_possible != rule.step_up_possible,
+        ),
+        (
+            "unified_code",
+            is_provided_and_changed(&rule.unified_code, &existing_rule.unified_code),
+        ),
+        (
+            "unified_message",
+            is_provided_and_changed(&rule.unified_message, &existing_rule.unified_message),
+        ),
+        (
+            "retry_schedule",
+            rule.retry_schedule.is_some() && rule.retry_schedule != existing_rule.retry_schedule,
+        ),
+    ]
+    .into_iter()
+    .filter(|(_, is_changed)| *is_changed)
+    .map(|(field, _)| field.to_string())
+    .collect()
+}
+
+type GsmRuleKey = (String, String, String, String, String);
+
+fn get_gsm_rule_key(rule: &gsm_api_types::GsmCreateRequest) -> GsmRuleKey {
+    (
+        rule.connector.to_string(),
+        rule.flow.clone(),
+        rule.sub_flow.clone(),
+        rule.code.clone(),
+        rule.message.clone(),
+    )
+}
+
+/// Get the reason the imported rule is invalid, if any. A rule is invalid when it fails the
+/// validations of the creation of a rule, or when an earlier row imports the same rule.
+fn get_import_rule_error(
+    imported_keys: &mut HashSet<GsmRuleKey>,
+    rule: &gsm_api_types::GsmCreateRequest,
+) -> Option<String> {
+    if imported_keys.insert(get_gsm_rule_key(rule)) {
+        get_gsm_rule_error(rule)
+    } else {
+        Some("The rule is imported more than once".to_string())
+    }
+}
+
+fn get_import_row_result(
+    row_number: usize,
+    rule: Option<&gsm_api_types::GsmCreateRequest>,
+    action: gsm_api_types::GsmImportAction,
+    changed_fields: Vec<String>,
+    error: Option<String>,
+) -> gsm_api_types::GsmImportRowResult {
+    gsm_api_types::GsmImportRowResult {
+        row_number,
+        connector: rule.map(|rule| rule.connector.to_string()),
+        flow: rule.map(|rule| rule.flow.clone()),
+        sub_flow: rule.map(|rule| rule.sub_flow.clone()),
+        code: rule.map(|rule| rule.code.clone()),
+        message: rule.map(|rule| rule.message.clone()),
+        action,
+        changed_fields,
+        error,
+    }
+}
+
+/// Import GSM rules in bulk. Every row is validated and compared with the existing rule, and the
+/// changes are only applied when none of the rows are invalid and the import is not a dry run.
+#[instrument(skip_all)]
+pub async fn import_gsm_rules(
+    state: SessionState,
+    request: gsm_api_types::GsmImportRequest,
+) -> RouterResponse<gsm_api_types::GsmImportResponse> {
+    let db = state.store.as_ref();
+    let rows = parse_gsm_import_data(request.format, &request.data)?;
+    when(rows.len() > consts::MAX_GSM_IMPORT_SIZE, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "An import cannot have more than {} Gsm rules",
+                consts::MAX_GSM_IMPORT_SIZE
+            ),
+        }))
+    })?;
+
+    let mut imported_keys = HashSet::new();
+    let validated_rows = rows
+        .into_iter()
+        .map(|row| match row {
+            Ok(rule) => match get_import_rule_error(&mut imported_keys, &rule) {
+                Some(error) => Err((Some(rule), error)),
+                None => Ok(rule),
+            },
+            Err(error) => Err((None, error)),
+        })
+        .collect::<Vec<_>>();
+
+    // The existing rules are fetched once for all the connectors of the import, instead of once
+    // for every row
+    let connectors = validated_rows
+        .iter()
+        .filter_map(|row| row.as_ref().ok())
+        .map(|rule| rule.connector.to_string())
+        .collect::<HashSet<_>>();
+    let existing_rules = db
+        .find_gsm_rules_by_connectors(connectors.into_iter().collect())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while fetching the existing Gsm rules")?
+        .into_iter()
+        .map(|rule| {
+            (
+                (
+                    rule.connector.clone(),
+                    rule.flow.clone(),
+                    rule.sub_flow.clone(),
+                    rule.code.clone(),
+                    rule.message.clone(),
+                ),
+                rule,
+            )
+        })
+        .collect::<HashMap<GsmRuleKey, _>>();
+
+    let mut row_results = Vec::with_capacity(validated_rows.len());
+    let mut rules_to_create = Vec::new();
+    let mut rules_to_update = Vec::new();
+    for (index, row) in validated_rows.into_iter().enumerate() {
+        let row_number = index + 1;
+        let rule = match row {
+            Ok(rule) => rule,
+            Err((rule, error)) => {
+                row_results.push(get_import_row_result(
+                    row_number,
+                    rule.as_ref(),
+                    gsm_api_types::GsmImportAction::Invalid,
+                    Vec::new(),
+                    Some(error),
+                ));
+                continue;
+            }
+        };
+
+        let (action, changed_fields) = match existing_rules.get(&get_gsm_rule_key(&rule)) {
+            Some(existing_rule) => {
+                let changed_fields = get_changed_fields(existing_rule, &rule);
+                let action = if changed_fields.is_empty() {
+                    gsm_api_types::GsmImportAction::Unchanged
+                } else {
+                    gsm_api_types::GsmImportAction::Update
+                };
+                (action, changed_fields)
+            }
+            None => (gsm_api_types::GsmImportAction::Create, Vec::new()),
+        };
+        row_results.push(get_import_row_result(
+            row_number,
+            Some(&rule),
+            action,
+            changed_fields,
+            None,
+        ));
+        match action {
+            gsm_api_types::GsmImportAction::Create => rules_to_create.push(rule.foreign_into()),
+            gsm_api_types::GsmImportAction::Update => rules_to_update.push(rule.foreign_into()),
+            gsm_api_types::GsmImportAction::Unchanged | gsm_api_types::GsmImportAction::Invalid => {
+            }
+        }
+    }
+
+    let count = |action| {
+        row_results
+            .iter()
+            .filter(|row| row.action == action)
+            .count()
+    };
+    let response = gsm_api_types::GsmImportResponse {
+        dry_run: request.dry_run,
+        created_count: count(gsm_api_types::GsmImportAction::Create),
+        updated_count: count(gsm_api_types::GsmImportAction::Update),
+        unchanged_count: count(gsm_api_types::GsmImportAction::Unchanged),
+        invalid_count: count(gsm_api_types::GsmImportAction::Invalid),
+        rows: row_results,
+    };
+    if request.dry_run {
+        return Ok(services::ApplicationResponse::Json(response));
+    }
+
+    when(response.invalid_count > 0, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "{} of the rows are invalid, no Gsm rules were imported. Import the rules with \
+                 dry_run to find the invalid rows",
+                response.invalid_count
+            ),
+        }))
+    })?;
+
+    db.import_gsm_rules(rules_to_create, rules_to_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while importing Gsm rules")?;
+
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+/// Export the GSM rules matching the filters, in a format which can be imported back.
+#[instrument(skip_all)]
+pub async fn export_gsm_rules(
+    state: SessionState,
+    request: gsm_api_types::GsmExportRequest,
+) -> RouterResponse<()> {
+    let db = state.store.as_ref();
+    let constraints = storage::GatewayStatusMapListConstraints {
+        connector: request.connector.map(|connector| connector.to_string()),
+        flow: request.flow,
+        decision: request.decision.map(|decision| decision.to_string()),
+        limit: None,
+        offset: None,
+    };
+    let rules = GsmInterface::list_gsm_rules(db, constraints)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while listing Gsm rules")?;
+
+    match request.format {
+        gsm_api_types::GsmFileFormat::Csv => {
+            let mut writer = csv::Writer::from_writer(Vec::new());
+            for rule in rules {
+                let record = GsmCsvRecord::try_from(rule)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to serialize the retry schedule of a Gsm rule")?;
+                writer
+                    .serialize(record)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to write Gsm rule to the export file")?;
+            }
+            let file_data = writer
+                .into_inner()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to write the Gsm export file")?;
+            Ok(services::ApplicationResponse::FileData((
+                file_data,
+                mime::TEXT_CSV,
+            )))
+        }
+        gsm_api_types::GsmFileFormat::Json => {
+            let rules = rules
+                .into_iter()
+                .map(ForeignInto::foreign_into)
+                .collect::<Vec<gsm_api_types::GsmResponse>>();
+            let file_data = serde_json::to_vec(&rules)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to write the Gsm export file")?;
+            Ok(services::ApplicationResponse::FileData((
+                file_data,
+                mime::APPLICATION_JSON,
+            )))
+        }
+    }
+}
+
+/// Locales are stored in lowercase, since language tags are case insensitive
+fn normalize_locale(locale: &str) -> errors::RouterResult<String> {
+    let is_valid = !locale.is_empty()
+        && locale.len() <= 35
+        && locale.split('-').all(|subtag| {
+            !subtag.is_empty() && subtag.chars().all(|char| char.is_ascii_alphanumeric())
+        });
+    when(!is_valid, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "locale must be a language tag, such as `fr` or `fr-CA`".to_string(),
+        }))
+    })?;
+
+    Ok(locale.to_lowercase())
+}
+
+#[instrument(skip_all)]
+pub async fn create_unified_translation(
+    state: SessionState,
+    request: gsm_api_types::UnifiedTranslationsCreateRequest,
+) -> RouterResponse<gsm_api_types::UnifiedTranslationsResponse> {
+    let db = state.store.as_ref();
+    let now = common_utils::date_time::now();
+    let translation = translations_storage::UnifiedTranslationsNew {
+        locale: normalize_locale(&request.locale)?,
+        unified_code: request.unified_code,
+        unified_message: request.unified_message,
+        translation: request.translation,
+        created_at: now,
+        last_modified_at: now,
+    };
+    // The absence of the translation may be cached
+    let cache_key = helpers::get_unified_translation_cache_key(
+        &translation.unified_code,
+        &translation.unified_message,
+        &translation.locale,
+    );
+
+    cache::publish_and_redact(
+        db.get_cache_store().as_ref(),
+        cache::CacheKind::UnifiedTranslations(cache_key.into()),
+        || UnifiedTranslationsInterface::add_unified_translation(db, translation),
+    )
+    .await
+    .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+        message: "Translation with given key already exists in our records".to_string(),
+    })
+    .map(|translation| services::ApplicationResponse::Json(translation.foreign_into()))
+}
+
+#[instrument(skip_all)]
+pub async fn update_unified_translation(
+    state: SessionState,
+    request: gsm_api_types::UnifiedTranslationsUpdateRequest,
+) -> RouterResponse<gsm_api_types::UnifiedTranslationsResponse> {
+    let db = state.store.as_ref();
+    let locale = normalize_locale(&request.locale)?;
+    let cache_key = helpers::get_unified_translation_cache_key(
+        &request.unified_code,
+        &request.unified_message,
+        &locale,
+    );
+
+    cache::publish_and_redact(
+        db.get_cache_store().as_ref(),
+        cache::CacheKind::UnifiedTranslations(cache_key.into()),
+        || {
+            UnifiedTranslationsInterface::update_translation(
+                db,
+                request.unified_code,
+                request.unified_message,
+                locale,
+                translations_storage::UnifiedTranslationsUpdate {
+                    translation: request.translation,
+                },
+            )
+        },
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+        message: "Translation with given key does not exist in our records".to_string(),
+    })
+    .attach_printable("Failed while updating unified translation")
+    .map(|translation| services::ApplicationResponse::Json(translation.foreign_into()))
+}
+
+#[instrument(skip_all)]
+pub async fn list_unified_translations(
+    state: SessionState,
+    request: gsm_api_types::UnifiedTranslationsListRequest,
+) -> RouterResponse<Vec<gsm_api_types::UnifiedTranslationsResponse>> {
+    let db = state.store.as_ref();
+    let constraints = translations_storage::UnifiedTranslationsListConstraints {
+        unified_code: request.unified_code,
+        locale: request
+            .locale
+            .as_deref()
+            .map(normalize_locale)
+            .transpose()?,
+        limit: request.limit.map(i64::from),
+        offset: request.offset.map(i64::from),
+    };
+
+    UnifiedTranslationsInterface::list_translations(db, constraints)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while listing unified translations")
+        .map(|translations| {
+            services::ApplicationResponse::Json(
+                translations
+                    .into_iter()
+                    .map(ForeignInto::foreign_into)
+                    .collect(),
+            )
+        })
+}
+
+#[instrument(skip_all)]
+pub async fn delete_unified_translation(
+    state: SessionState,
+    request: gsm_api_types::UnifiedTranslationsDeleteRequest,
+) -> RouterResponse<gsm_api_types::UnifiedTranslationsDeleteResponse> {
+    let db = state.store.as_ref();
+    let locale = normalize_locale(&request.locale)?;
+    let cache_key = helpers::get_unified_translation_cache_key(
+        &request.unified_code,
+        &request.unified_message,
+        &locale,
+    );
+    let is_deleted = cache::publish_and_redact(
+        db.get_cache_store().as_ref(),
+        cache::CacheKind::UnifiedTranslations(cache_key.into()),
+        || {
+            UnifiedTranslationsInterface::delete_translation(
+                db,
+                request.unified_code.clone(),
+                request.unified_message.clone(),
+                locale.clone(),
+            )
+        },
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+        message: "Translation with given key does not exist in our records".to_string(),
+    })
+    .attach_printable("Failed while deleting unified translation")?;
+
+    when(!is_deleted, || {
+        Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Failed while deleting unified translation, got response as false")
+    })?;
+
+    Ok(services::ApplicationResponse::Json(
+        gsm_api_types::UnifiedTranslationsDeleteResponse {
+            translation_delete: true,
+            unified_code: request.unified_code,
+            unified_message: request.unified_message,
+            locale,
+        },
+    ))
+}
+
+const INVALID_RETRY_SCHEDULE_MESSAGE: &str = "retry_schedule must contain non-zero intervals, \
+                                              or days of the month between 1 and 31 along with \
+                                              a non-zero max_retries";
+
+fn is_valid_retry_schedule(retry_schedule: &RetrySchedule) -> bool {
+    match retry_schedule {
+        RetrySchedule::Intervals { intervals_in_hours } => {
+            !intervals_in_hours.is_empty() && !intervals_in_hours.contains(&0)
+        }
+        RetrySchedule::DaysOfMonth { days, max_retries } => {
+            !days.is_empty() && days.iter().all(|day| (1..=31).contains(day)) && *max_retries > 0
+        }
+    }
+}
+
+fn validate_retry_schedule(retry_schedule: &RetrySchedule) -> errors::RouterResult<()> {
+    when(!is_valid_retry_schedule(retry_schedule), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: INVALID_RETRY_SCHEDULE_MESSAGE.to_string(),
+        }))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use api_models::{
+        enums::Connector,
+        gsm::{GsmCreateRequest, GsmDecision, GsmFileFormat},
+    };
+    use common_utils::types::RetrySchedule;
+    use diesel_models::gsm::GatewayStatusMap;
+
+    use super::{
+        get_changed_fields, get_import_rule_error, normalize_locale, parse_gsm_import_data,
+    };
+
+    fn get_rule(code: &str) -> GsmCreateRequest {
+        GsmCreateRequest {
+            connector: Connector::Stripe,
+            flow: "Authorize".to_string(),
+            sub_flow: "sub_flow".to_string(),
+            code: code.to_string(),
+            message: "Insufficient funds".to_string(),
+            status: "failure".to_string(),
+            router_error: None,
+            decision: GsmDecision::DoDefault,
+            step_up_possible: false,
+            unified_code: Some("UE_9000".to_string()),
+            unified_message: None,
+            retry_schedule: None,
+        }
+    }
+
+    fn get_existing_rule(rule: &GsmCreateRequest) -> GatewayStatusMap {
+        let now = common_utils::date_time::now();
+        GatewayStatusMap {
+            connector: rule.connector.to_string(),
+            flow: rule.flow.clone(),
+            sub_flow: rule.sub_flow.clone(),
+            code: rule.code.clone(),
+            message: rule.message.clone(),
+            status: rule.status.clone(),
+            router_error: rule.router_error.clone(),
+            decision: rule.decision.to_string(),
+            created_at: now,
+            last_modified: now,
+            step_up_possible: rule.step_up_possible,
+            unified_code: rule.unified_code.clone(),
+            unified_message: Some("Insufficient funds".to_string()),
+            retry_schedule: None,
+        }
+    }
+
+    #[test]
+    fn test_get_changed_fields() {
+        let rule = get_rule("51");
+        let existing_rule = get_existing_rule(&rule);
+        // The unified_message which is not provided keeps its existing value
+        assert!(get_changed_fields(&existing_rule, &rule).is_empty());
+
+        let rule = GsmCreateRequest {
+            decision: GsmDecision::RetryLater,
+            unified_code: Some("UE_9001".to_string()),
+            retry_schedule: Some(RetrySchedule::Intervals {
+                intervals_in_hours: vec![24],
+            }),
+            ..get_rule("51")
+        };
+        assert_eq!(
+            get_changed_fields(&existing_rule, &rule),
+            vec!["decision", "unified_code", "retry_schedule"]
+        );
+    }
+
+    #[test]
+    fn test_get_import_rule_error_for_duplicate_rows() {
+        let mut imported_keys = HashSet::new();
+
+        assert_eq!(
+            get_import_rule_error(&mut imported_keys, &get_rule("51")),
+            None
+        );
+        assert_eq!(
+            get_import_rule_error(&mut imported_keys, &get_rule("54")),
+            None
+        );
+        assert_eq!(
+            get_import_rule_error(&mut imported_keys, &get_rule("51")),
+            Some("The rule is imported more than once".to_string())
+        );
+    }
+
+    #[test]
+    fn test_get_import_rule_error_for_invalid_rule() {
+        let mut imported_keys = HashSet::new();
+        let rule = GsmCreateRequest {
+            decision: GsmDecision::RetryLater,
+            ..get_rule("51")
+        };
+
+        assert!(get_import_rule_error(&mut imported_keys, &rule)
+            .is_some_and(|error| error.contains("retry_schedule is required")));
+    }
+
+    #[test]
+    fn test_normalize_locale() {
+        assert_eq!(normalize_locale("fr-CA").ok(), Some("fr-ca".to_string()));
+        assert_eq!(normalize_locale("EN").ok(), Some("en".to_string()));
+        assert!(normalize_locale("").is_err());
+        assert!(normalize_locale("fr--CA").is_err());
+        assert!(normalize_locale("fr_CA").is_err());
+        assert!(normalize_locale(&"a".repeat(36)).is_err());
+    }
+
+    #[test]
+    fn test_parse_gsm_import_csv() {
+        let data = "connector,flow,sub_flow,code,message,status,router_error,decision,\
+                    step_up_possible,unified_code,unified_message,retry_schedule\n\
+                    stripe,Authorize,sub_flow,51,Insufficient funds,failure,,retry_later,false,\
+                    UE_9000,Insufficient funds,\"{\"\"type\"\":\"\"intervals\"\",\
+                    \"\"intervals_in_hours\"\":[24,72]}\"\n\
+                    stripe,Authorize,sub_flow,54,Expired card,failure,,skip,false,,,\n";
+        let rows = parse_gsm_import_data(GsmFileFormat::Csv, data).unwrap_or_default();
+
+        assert_eq!(rows.len(), 2);
+        assert!(matches!(
+            rows.first(),
+            Some(Ok(rule)) if rule.decision == GsmDecision::RetryLater
+                && rule.router_error.is_none()
+                && rule.retry_schedule == Some(RetrySchedule::Intervals {
+                    intervals_in_hours: vec![24, 72],
+                })
+        ));
+        assert!(matches!(
+            rows.get(1),
+            Some(Err(error)) if error.contains("Invalid decision")
+        ));
+    }
+}
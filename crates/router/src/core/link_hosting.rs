@@ -0,0 +1,481 @@
+//! Hosting of payment links, payment method collect links and payout links on the custom domains
+//! configured in business profiles.
+
+use std::collections::{HashMap, HashSet};
+
+use actix_web::http::header;
+use api_models::{admin, payments::RedirectionResponse};
+use common_utils::link_utils::LinkTemplateConfig;
+use error_stack::ResultExt;
+use once_cell::sync::Lazy;
+use router_env::logger;
+use tera::Tera;
+
+use crate::{
+    configs::settings,
+    consts,
+    core::errors::{self, RouterResult},
+    routes::SessionState,
+};
+
+/// Strings shown on the hosted links, keyed by locale. The strings of every locale are expected
+/// to be the same set as the strings of the `en` locale.
+static LOCALE_STRINGS: Lazy<HashMap<String, HashMap<String, String>>> = Lazy::new(|| {
+    serde_json::from_str(include_str!("link_hosting/locales.json")).unwrap_or_else(|error| {
+        logger::error!(?error, "Failed to parse the locale strings of links");
+        HashMap::new()
+    })
+});
+
+const FALLBACK_LOCALE: &str = "en";
+
+/// Details of the request made for rendering a link
+#[derive(Debug, Clone)]
+pub struct LinkRenderRequestContext {
+    /// Host the link was requested on
+    pub host: String,
+    /// Path of the request, used when redirecting the client to the domain of the link
+    pub path: String,
+    /// Query string of the request, used when redirecting the client to the domain of the link
+    pub query: Option<String>,
+    /// Supported locale which is most preferred by the client
+    pub locale: String,
+}
+
+impl LinkRenderRequestContext {
+    pub fn new(request: &actix_web::HttpRequest, config: &settings::CustomDomain) -> Self {
+        let locale = request
+            .headers()
+            .get(header::ACCEPT_LANGUAGE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(get_locale_from_accept_language)
+            .unwrap_or_else(|| config.default_locale.clone());
+        let query = Some(request.query_string())
+            .filter(|query| !query.is_empty())
+            .map(ToOwned::to_owned);
+
+        Self {
+            host: request.connection_info().host().to_owned(),
+            path: request.path().to_owned(),
+            query,
+            locale,
+        }
+    }
+}
+
+pub fn is_supported_locale(locale: &str) -> bool {
+    LOCALE_STRINGS.contains_key(locale)
+}
+
+/// Returns the language tags in an `Accept-Language` header value in lowercase, in the order of
+/// their preference by the client. Language tags with a quality value of zero are excluded.
+pub fn get_languages_from_accept_language(accept_language: &str) -> Vec<String> {
+    let mut languages = accept_language
+        .split(',')
+        .filter_map(|language| {
+            let mut parts = language.split(';');
+            let tag = parts.next()?.trim().to_lowercase();
+            let quality = parts
+                .find_map(|param| param.trim().strip_prefix("q="))
+                .map_or(Some(1.0), |quality| quality.trim().parse::<f32>().ok())?;
+            (!tag.is_empty() && quality > 0.0).then_some((tag, quality))
+        })
+        .collect::<Vec<_>>();
+
+    // The sort is stable, languages with the same quality value retain the order of the header
+    languages.sort_by(|(_, first), (_, second)| second.total_cmp(first));
+    languages.into_iter().map(|(tag, _)| tag).collect()
+}
+
+/// Returns the supported locale with the highest quality value in an `Accept-Language` header
+/// value. Language tags with a region are matched against their primary language as well.
+pub fn get_locale_from_accept_language(accept_language: &str) -> Option<String> {
+    get_languages_from_accept_language(accept_language)
+        .into_iter()
+        .find_map(|tag| {
+            let primary_language = tag.split('-').next().unwrap_or(&tag).to_owned();
+            [tag, primary_language]
+                .into_iter()
+                .find(|locale| is_supported_locale(locale))
+        })
+}
+
+/// Returns the strings shown on the links in the given locale. Strings missing in the locale are
+/// returned in English.
+pub fn get_locale_strings(locale: &str) -> HashMap<String, String> {
+    let mut strings = LOCALE_STRINGS
+        .get(FALLBACK_LOCALE)
+        .cloned()
+        .unwrap_or_default();
+    if let Some(locale_strings) = LOCALE_STRINGS.get(locale) {
+        strings.extend(locale_strings.clone());
+    }
+    strings
+}
+
+/// Returns the string for the given key in the given locale, or the key itself if the string is
+/// not found.
+pub fn translate(locale: &str, key: &str) -> String {
+    LOCALE_STRINGS
+        .get(locale)
+        .and_then(|strings| strings.get(key))
+        .or_else(|| {
+            LOCALE_STRINGS
+                .get(FALLBACK_LOCALE)
+                .and_then(|strings| strings.get(key))
+        })
+        .cloned()
+        .unwrap_or_else(|| key.to_owned())
+}
+
+/// Script exposing the locale of the link and its strings to the link's scripts through
+/// `window.__LINK_LOCALE`
+pub fn get_locale_js_script(locale: &str) -> RouterResult<String> {
+    let link_locale = serde_json::json!({
+        "locale": locale,
+        "strings": get_locale_strings(locale),
+    });
+    let link_locale = serde_json::to_string(&link_locale)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize locale strings of link")?;
+    Ok(format!("window.__LINK_LOCALE = {link_locale};"))
+}
+
+/// Host a link is served on
+#[derive(Debug)]
+pub enum LinkHost {
+    /// The link is served on the router's host, or links are not restricted to custom domains
+    Default,
+    /// The link is served on the allowed custom domain it was issued for
+    CustomDomain,
+    /// The link was requested on the router's host, the client is redirected to the custom domain
+    /// of the link
+    Redirect(RedirectionResponse),
+}
+
+impl LinkHost {
+    /// Custom templates of a link are rendered only on the custom domain of the link, so that the
+    /// merchant's markup is never served under the router's own domain
+    pub fn is_custom_domain(&self) -> bool {
+        matches!(self, Self::CustomDomain)
+    }
+}
+
+/// Validates the host a link was requested on against the host the link was issued for, and
+/// against the custom domain currently configured for the links of the link's business profile
+/// or merchant account.
+///
+/// The link is looked up by the merchant and link ids in the request path, the host is only used
+/// to verify that the link is served on the domain of its own profile. A link is served as a
+/// custom domain link only if its host is still the custom domain configured for the profile, and
+/// requests made for such links on the router's own host are redirected to the custom domain.
+/// Requests made on any other host are rejected, so that a link is never rendered under a domain
+/// of another merchant.
+pub fn validate_link_host(
+    state: &SessionState,
+    request_context: &LinkRenderRequestContext,
+    link: &str,
+    configured_domain: Option<&str>,
+) -> RouterResult<LinkHost> {
+    let config = &state.conf.custom_domain;
+    if !config.enabled {
+        return Ok(LinkHost::Default);
+    }
+
+    let mut link_url = url::Url::parse(link)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse URL of link")?;
+    let link_host = link_url.host_str().unwrap_or_default().to_lowercase();
+    let router_host = get_host(&state.base_url).unwrap_or_default();
+    let request_host =
+        get_host(&format!("{}://{}", link_url.scheme(), request_context.host)).unwrap_or_default();
+
+    let link_host_kind = get_link_host(&link_host, &router_host, configured_domain, config);
+
+    if request_host == link_host {
+        Ok(link_host_kind)
+    } else if request_host == router_host {
+        // Links whose domain is no longer the custom domain of their profile are served on the
+        // router's host, rather than redirecting the client to a domain the merchant may not own
+        if !link_host_kind.is_custom_domain() {
+            return Ok(LinkHost::Default);
+        }
+        link_url.set_path(&request_context.path);
+        link_url.set_query(request_context.query.as_deref());
+        logger::info!(
+            "redirecting link requested on the router's host to its custom domain {}",
+            link_host
+        );
+
+        Ok(LinkHost::Redirect(RedirectionResponse {
+            return_url: link_url.to_string(),
+            params: vec![],
+            return_url_with_query_params: link_url.to_string(),
+            http_method: "GET".to_string(),
+            headers: vec![],
+        }))
+    } else {
+        Err(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "link not found".to_string(),
+        })
+        .attach_printable_lazy(|| {
+            format!("link issued for host {link_host} was requested on host {request_host}")
+        })
+    }
+}
+
+/// Host of a link requested on the host it was issued for, the host is a custom domain only if it
+/// is the custom domain configured for the link's profile and one of the domains allowed for
+/// hosting links
+fn get_link_host(
+    link_host: &str,
+    router_host: &str,
+    configured_domain: Option<&str>,
+    config: &settings::CustomDomain,
+) -> LinkHost {
+    if link_host != router_host
+        && configured_domain.is_some_and(|domain| domain.eq_ignore_ascii_case(link_host))
+        && is_domain_allowed(link_host, &config.allowed_domains)
+    {
+        LinkHost::CustomDomain
+    } else {
+        LinkHost::Default
+    }
+}
+
+fn get_host(url: &str) -> Option<String> {
+    url::Url::parse(url)
+        .ok()
+        .and_then(|url| url.host_str().map(str::to_lowercase))
+}
+
+/// Validates a domain name, or a domain name prefixed with `*.` matching all of its subdomains
+pub fn is_valid_domain_pattern(pattern: &str) -> bool {
+    let domain = pattern.strip_prefix("*.").unwrap_or(pattern);
+    let labels = domain.split('.').collect::<Vec<_>>();
+
+    labels.len() > 1
+        && domain.len() <= 253
+        && labels.iter().all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label
+                    .chars()
+                    .all(|character| character.is_ascii_alphanumeric() || character == '-')
+        })
+}
+
+/// Checks whether a domain matches any of the domain patterns, entries prefixed with `*.` match
+/// all subdomains of the domain
+pub fn is_domain_allowed(domain: &str, allowed_domains: &HashSet<String>) -> bool {
+    let domain = domain.to_lowercase();
+    allowed_domains.iter().any(|pattern| {
+        let pattern = pattern.to_lowercase();
+        match pattern.strip_prefix("*.") {
+            Some(parent_domain) => domain
+                .strip_suffix(parent_domain)
+                .is_some_and(|subdomain| subdomain.len() > 1 && subdomain.ends_with('.')),
+            None => domain == pattern,
+        }
+    })
+}
+
+/// Validates the link configuration of payment links in a business profile
+pub fn validate_payment_link_config(
+    config: &settings::CustomDomain,
+    link_config: &admin::BusinessPaymentLinkConfig,
+) -> RouterResult<()> {
+    validate_link_config(
+        config,
+        "payment_link_config",
+        link_config.domain_name.as_deref(),
+        &link_config.allowed_domains.clone().unwrap_or_default(),
+        link_config.custom_template.as_ref(),
+    )
+}
+
+/// Validates the link configuration of payout links or payment method collect links
+pub fn validate_generic_link_config(
+    config: &settings::CustomDomain,
+    field_name: &'static str,
+    link_config: &admin::BusinessGenericLinkConfig,
+) -> RouterResult<()> {
+    validate_link_config(
+        config,
+        field_name,
+        link_config.domain_name.as_deref(),
+        &link_config.allowed_domains,
+        link_config.custom_template.as_ref(),
+    )
+}
+
+/// Validates the custom domain, the domains allowed to embed the link and the custom template
+/// configured for the links of a business profile or a merchant account
+fn validate_link_config(
+    config: &settings::CustomDomain,
+    field_name: &'static str,
+    domain_name: Option<&str>,
+    allowed_domains: &HashSet<String>,
+    custom_template: Option<&LinkTemplateConfig>,
+) -> RouterResult<()> {
+    // Custom domains are not restricted unless links are restricted to their custom domains
+    if let Some(domain_name) = domain_name.filter(|_| config.enabled) {
+        if !is_domain_allowed(domain_name, &config.allowed_domains) {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "domain_name `{domain_name}` in {field_name} is not allowed for hosting links"
+                ),
+            })?
+        }
+    }
+
+    if let Some(invalid_domain) = allowed_domains
+        .iter()
+        .find(|domain| !is_valid_domain_pattern(domain))
+    {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "`{invalid_domain}` in allowed_domains of {field_name} is not a valid domain"
+            ),
+        })?
+    }
+
+    if let Some(custom_template) = custom_template {
+        let template_size = custom_template.html.as_ref().map_or(0, String::len)
+            + custom_template.css.as_ref().map_or(0, String::len);
+        if template_size > consts::MAX_LINK_TEMPLATE_SIZE {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "custom_template in {field_name} must not be larger than {} bytes",
+                    consts::MAX_LINK_TEMPLATE_SIZE
+                ),
+            })?
+        }
+
+        if let Some(html) = &custom_template.html {
+            Tera::default()
+                .add_raw_template("custom_template", html)
+                .change_context(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "custom_template.html in {field_name} is not a valid template"
+                    ),
+                })?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Builds the `Content-Security-Policy` header value for a link, restricting the domains which
+/// can embed the link. Links without any allowed domains can be embedded anywhere.
+pub fn get_content_security_policy(allowed_domains: &HashSet<String>) -> Option<String> {
+    (!allowed_domains.is_empty()).then(|| {
+        let mut allowed_domains = allowed_domains.iter().cloned().collect::<Vec<_>>();
+        allowed_domains.sort();
+        format!(
+            "frame-ancestors 'self' {}; base-uri 'self'; object-src 'none'",
+            allowed_domains.join(" ")
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_all_locales_have_the_same_strings() {
+        let fallback_strings = LOCALE_STRINGS.get(FALLBACK_LOCALE).unwrap();
+        for (locale, strings) in LOCALE_STRINGS.iter() {
+            let mut missing_keys = fallback_strings
+                .keys()
+                .filter(|key| !strings.contains_key(*key))
+                .collect::<Vec<_>>();
+            missing_keys.extend(
+                strings
+                    .keys()
+                    .filter(|key| !fallback_strings.contains_key(*key)),
+            );
+            assert!(missing_keys.is_empty(), "{locale}: {missing_keys:?}");
+        }
+    }
+
+    #[test]
+    fn test_get_locale_from_accept_language() {
+        assert_eq!(
+            get_locale_from_accept_language("fr-CH, fr;q=0.9, en;q=0.8, de;q=0.7, *;q=0.5"),
+            Some("fr".to_string())
+        );
+        assert_eq!(
+            get_locale_from_accept_language("xx, en;q=0.4, de;q=0.6"),
+            Some("de".to_string())
+        );
+        assert_eq!(get_locale_from_accept_language("de;q=0, xx"), None);
+        assert_eq!(get_locale_from_accept_language(""), None);
+    }
+
+    #[test]
+    fn test_is_domain_allowed() {
+        let allowed_domains =
+            HashSet::from(["pay.example.com".to_string(), "*.example.org".to_string()]);
+        assert!(is_domain_allowed("pay.example.com", &allowed_domains));
+        assert!(is_domain_allowed("Checkout.Example.org", &allowed_domains));
+        assert!(is_domain_allowed("a.b.example.org", &allowed_domains));
+        assert!(!is_domain_allowed("example.org", &allowed_domains));
+        assert!(!is_domain_allowed("badexample.org", &allowed_domains));
+        assert!(!is_domain_allowed("example.com", &allowed_domains));
+    }
+
+    #[test]
+    fn test_get_link_host() {
+        let config = settings::CustomDomain {
+            enabled: true,
+            allowed_domains: HashSet::from(["*.example.com".to_string()]),
+            ..Default::default()
+        };
+        let configured_domain = Some("Pay.Example.com");
+        let is_custom_domain = |link_host, router_host, configured_domain| {
+            get_link_host(link_host, router_host, configured_domain, &config).is_custom_domain()
+        };
+        assert!(is_custom_domain(
+            "pay.example.com",
+            "api.router.io",
+            configured_domain
+        ));
+        assert!(!is_custom_domain(
+            "api.router.io",
+            "api.router.io",
+            configured_domain
+        ));
+        assert!(!is_custom_domain(
+            "pay.example.org",
+            "api.router.io",
+            Some("pay.example.org")
+        ));
+        assert!(!is_custom_domain(
+            "pay.example.com",
+            "pay.example.com",
+            configured_domain
+        ));
+        assert!(!is_custom_domain(
+            "shop.example.com",
+            "api.router.io",
+            configured_domain
+        ));
+        assert!(!is_custom_domain("pay.example.com", "api.router.io", None));
+    }
+
+    #[test]
+    fn test_is_valid_domain_pattern() {
+        assert!(is_valid_domain_pattern("pay.example.com"));
+        assert!(is_valid_domain_pattern("*.example.com"));
+        assert!(!is_valid_domain_pattern("localhost"));
+        assert!(!is_valid_domain_pattern("https://example.com"));
+        assert!(!is_valid_domain_pattern("example.com/path"));
+        assert!(!is_valid_domain_pattern("-example.com"));
+        assert!(!is_valid_domain_pattern("*.*.example.com"));
+    }
+}
@@ -0,0 +1,198 @@
+//! Log filters applied at runtime.
+//!
+//! A log filter logs the requests of a merchant, business profile, connector or request path at
+//! debug or trace level for a bounded time, without restarting the instances. The active log
+//! filters are kept in a Redis hash, so that the instances started later apply them as well, and
+//! are announced to all the instances on the in-memory cache invalidation channel. Each log filter
+//! is a field of the hash, so that log filters created or deleted concurrently are not lost.
+
+use std::time::{Duration, SystemTime};
+
+use api_models::log_filter as log_filter_api_types;
+use error_stack::{report, ResultExt};
+use redis_interface::{DelReply, RedisConnectionPool};
+use router_env::{instrument, logger, tracing, LogFilterOverride};
+use storage_impl::redis::{
+    cache::{publish_into_redact_channel, CacheKind},
+    kv_store::RedisConnInterface,
+};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult},
+    routes::AppState,
+    services,
+    types::transformers::ForeignInto,
+};
+
+#[instrument(skip_all)]
+pub async fn create_log_filter(
+    app_state: AppState,
+    request: log_filter_api_types::LogFilterCreateRequest,
+) -> RouterResponse<log_filter_api_types::LogFilterResponse> {
+    let duration_in_secs = request
+        .duration_in_secs
+        .unwrap_or(consts::DEFAULT_LOG_FILTER_DURATION_IN_SECS);
+    common_utils::fp_utils::when(
+        duration_in_secs == 0 || duration_in_secs > consts::MAX_LOG_FILTER_DURATION_IN_SECS,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "duration_in_secs must be between 1 and {}",
+                    consts::MAX_LOG_FILTER_DURATION_IN_SECS
+                ),
+            }))
+        },
+    )?;
+    common_utils::fp_utils::when(request.value.trim().is_empty(), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "value must not be empty".to_string(),
+        }))
+    })?;
+
+    let redis_conn = get_redis_conn(&app_state)?;
+    let log_filter = LogFilterOverride {
+        filter_id: common_utils::generate_time_ordered_id("log_filter"),
+        field: request.field.foreign_into(),
+        value: request.value,
+        level: request.level.foreign_into(),
+        expires_at: SystemTime::now() + Duration::from_secs(duration_in_secs),
+    };
+    // The hash is retained for as long as a log filter can be applied, expired log filters are
+    // removed from it when the log filters are fetched
+    redis_conn
+        .serialize_and_set_hash_field_if_not_exist(
+            consts::LOG_FILTERS_REDIS_KEY,
+            &log_filter.filter_id,
+            &log_filter,
+            Some(u32::try_from(consts::MAX_LOG_FILTER_DURATION_IN_SECS).unwrap_or(u32::MAX)),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to store the log filter")?;
+
+    // The count is checked after the log filter is stored, so that concurrent requests cannot
+    // exceed it
+    let log_filters = get_log_filters(&app_state).await?;
+    if log_filters.len() > consts::MAX_LOG_FILTER_COUNT {
+        redis_conn
+            .delete_hash_field(consts::LOG_FILTERS_REDIS_KEY, &log_filter.filter_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to remove the log filter")?;
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "At most {} log filters can be applied at the same time",
+                consts::MAX_LOG_FILTER_COUNT
+            ),
+        }))?
+    }
+    apply_log_filters(&app_state, &log_filters).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        log_filter.foreign_into(),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn list_log_filters(
+    app_state: AppState,
+) -> RouterResponse<Vec<log_filter_api_types::LogFilterResponse>> {
+    let log_filters = get_log_filters(&app_state).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        log_filters
+            .into_iter()
+            .map(ForeignInto::foreign_into)
+            .collect(),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn delete_log_filter(
+    app_state: AppState,
+    filter_id: String,
+) -> RouterResponse<log_filter_api_types::LogFilterDeleteResponse> {
+    let deleted = get_redis_conn(&app_state)?
+        .delete_hash_field(consts::LOG_FILTERS_REDIS_KEY, &filter_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to delete the log filter")?;
+
+    common_utils::fp_utils::when(matches!(deleted, DelReply::KeyNotDeleted), || {
+        Err(report!(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Log filter with the given filter_id does not exist".to_string(),
+        }))
+    })?;
+    let log_filters = get_log_filters(&app_state).await?;
+    apply_log_filters(&app_state, &log_filters).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        log_filter_api_types::LogFilterDeleteResponse {
+            filter_id,
+            deleted: true,
+        },
+    ))
+}
+
+/// Applies the active log filters on an instance which is starting up
+pub async fn load_log_filters(app_state: &AppState) {
+    match get_log_filters(app_state).await {
+        Ok(log_filters) => router_env::reload_log_filter(&log_filters),
+        Err(error) => logger::error!(?error, "Failed to load the log filters"),
+    }
+}
+
+fn get_redis_conn(app_state: &AppState) -> RouterResult<std::sync::Arc<RedisConnectionPool>> {
+    app_state
+        .cache_store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")
+}
+
+/// Fetches the log filters from Redis, the expired ones are left out and removed from Redis
+async fn get_log_filters(app_state: &AppState) -> RouterResult<Vec<LogFilterOverride>> {
+    let redis_conn = get_redis_conn(app_state)?;
+    let log_filters = redis_conn
+        .hscan_and_deserialize::<LogFilterOverride>(consts::LOG_FILTERS_REDIS_KEY, "*", None)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the log filters")?;
+
+    let now = SystemTime::now();
+    let (mut active_log_filters, expired_log_filters): (Vec<_>, Vec<_>) = log_filters
+        .into_iter()
+        .partition(|log_filter| log_filter.is_active(now));
+    for log_filter in expired_log_filters {
+        redis_conn
+            .delete_hash_field(consts::LOG_FILTERS_REDIS_KEY, &log_filter.filter_id)
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to remove an expired log filter"))
+            .ok();
+    }
+    active_log_filters.sort_by(|a, b| a.filter_id.cmp(&b.filter_id));
+
+    Ok(active_log_filters)
+}
+
+/// Applies the log filters on this instance and announces them to the other instances
+async fn apply_log_filters(
+    app_state: &AppState,
+    log_filters: &[LogFilterOverride],
+) -> RouterResult<()> {
+    router_env::reload_log_filter(log_filters);
+
+    let serialized_log_filters = serde_json::to_string(log_filters)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the log filters")?;
+    publish_into_redact_channel(
+        app_state.cache_store.as_ref(),
+        [CacheKind::LogFilter(serialized_log_filters.into())],
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to publish the log filters")?;
+
+    Ok(())
+}
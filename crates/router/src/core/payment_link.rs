@@ -13,7 +13,10 @@ use masking::{PeekInterface, Secret};
 use router_env::logger;
 use time::PrimitiveDateTime;
 
-use super::errors::{self, RouterResult, StorageErrorExt};
+use super::{
+    errors::{self, RouterResult, StorageErrorExt},
+    link_hosting,
+};
 use crate::{
     errors::RouterResponse,
     get_payment_link_config_value, get_payment_link_config_value_based_on_priority,
@@ -55,6 +58,7 @@ pub async fn initiate_payment_link_flow(
     key_store: domain::MerchantKeyStore,
     merchant_id: String,
     payment_id: String,
+    request_context: link_hosting::LinkRenderRequestContext,
 ) -> RouterResponse<services::PaymentLinkFormData> {
     let db = &*state.store;
     let payment_intent = db
@@ -83,6 +87,35 @@ pub async fn initiate_payment_link_flow(
         .await
         .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
 
+    let profile_id = payment_link
+        .profile_id
+        .or(payment_intent.profile_id)
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Profile id missing in payment link and payment intent")?;
+
+    let business_profile = db
+        .find_business_profile_by_profile_id(&profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.to_string(),
+        })?;
+    let business_link_config =
+        parse_business_link_config(business_profile.payment_link_config.clone())?;
+
+    let link_host = link_hosting::validate_link_host(
+        &state,
+        &request_context,
+        &payment_link.link_to_pay,
+        business_link_config
+            .as_ref()
+            .and_then(|config| config.domain_name.as_deref()),
+    )?;
+    if let link_hosting::LinkHost::Redirect(redirection_response) = link_host {
+        return Ok(services::ApplicationResponse::JsonForRedirection(
+            redirection_response,
+        ));
+    }
+
     let payment_link_config = if let Some(pl_config_value) = payment_link.payment_link_config {
         extract_payment_link_config(pl_config_value)?
     } else {
@@ -96,18 +129,8 @@ pub async fn initiate_payment_link_flow(
         }
     };
 
-    let profile_id = payment_link
-        .profile_id
-        .or(payment_intent.profile_id)
-        .ok_or(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Profile id missing in payment link and payment intent")?;
-
-    let business_profile = db
-        .find_business_profile_by_profile_id(&profile_id)
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
-            id: profile_id.to_string(),
-        })?;
+    let render_config =
+        get_link_render_config(business_link_config, request_context.locale, &link_host);
 
     let return_url = if let Some(payment_create_return_url) = payment_intent.return_url.clone() {
         payment_create_return_url
@@ -150,6 +173,7 @@ pub async fn initiate_payment_link_flow(
         &payment_intent.status,
         &[
             storage_enums::IntentStatus::Cancelled,
+            storage_enums::IntentStatus::Expired,
             storage_enums::IntentStatus::Failed,
             storage_enums::IntentStatus::Processing,
             storage_enums::IntentStatus::RequiresCapture,
@@ -215,6 +239,7 @@ pub async fn initiate_payment_link_flow(
         let payment_link_error_data = services::PaymentLinkStatusData {
             js_script,
             css_script,
+            render_config,
         };
         return Ok(services::ApplicationResponse::PaymentLinkForm(Box::new(
             services::api::PaymentLinkAction::PaymentLinkStatus(payment_link_error_data),
@@ -251,6 +276,7 @@ pub async fn initiate_payment_link_flow(
         sdk_url: state.conf.payment_link.sdk_url.clone(),
         css_script,
         html_meta_tags,
+        render_config,
     };
 
     logger::info!(
@@ -407,6 +433,38 @@ pub fn extract_payment_link_config(
         })
 }
 
+fn parse_business_link_config(
+    business_link_config: Option<serde_json::Value>,
+) -> RouterResult<Option<admin_types::BusinessPaymentLinkConfig>> {
+    business_link_config
+        .map(|config| {
+            config
+                .parse_value::<admin_types::BusinessPaymentLinkConfig>("BusinessPaymentLinkConfig")
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "payment_link_config",
+        })
+        .attach_printable("Invalid payment_link_config given in business config")
+}
+
+fn get_link_render_config(
+    business_config: Option<admin_types::BusinessPaymentLinkConfig>,
+    locale: String,
+    link_host: &link_hosting::LinkHost,
+) -> services::LinkRenderConfig {
+    services::LinkRenderConfig {
+        locale,
+        allowed_domains: business_config
+            .as_ref()
+            .and_then(|config| config.allowed_domains.clone())
+            .unwrap_or_default(),
+        custom_template: business_config
+            .and_then(|config| config.custom_template)
+            .filter(|_| link_host.is_custom_domain()),
+    }
+}
+
 pub fn get_payment_link_config_based_on_priority(
     payment_create_link_config: Option<api_models::payments::PaymentCreatePaymentLinkConfig>,
     business_link_config: Option<serde_json::Value>,
@@ -499,6 +557,7 @@ pub async fn get_payment_link_status(
     key_store: domain::MerchantKeyStore,
     merchant_id: String,
     payment_id: String,
+    request_context: link_hosting::LinkRenderRequestContext,
 ) -> RouterResponse<services::PaymentLinkFormData> {
     let db = &*state.store;
     let payment_intent = db
@@ -538,6 +597,35 @@ pub async fn get_payment_link_status(
         .await
         .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
 
+    let profile_id = payment_link
+        .profile_id
+        .or(payment_intent.profile_id)
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Profile id missing in payment link and payment intent")?;
+
+    let business_profile = db
+        .find_business_profile_by_profile_id(&profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.to_string(),
+        })?;
+    let business_link_config =
+        parse_business_link_config(business_profile.payment_link_config.clone())?;
+
+    let link_host = link_hosting::validate_link_host(
+        &state,
+        &request_context,
+        &payment_link.link_to_pay,
+        business_link_config
+            .as_ref()
+            .and_then(|config| config.domain_name.as_deref()),
+    )?;
+    if let link_hosting::LinkHost::Redirect(redirection_response) = link_host {
+        return Ok(services::ApplicationResponse::JsonForRedirection(
+            redirection_response,
+        ));
+    }
+
     let payment_link_config = if let Some(pl_config_value) = payment_link.payment_link_config {
         extract_payment_link_config(pl_config_value)?
     } else {
@@ -570,18 +658,8 @@ pub async fn get_payment_link_status(
     let merchant_name = capitalize_first_char(&payment_link_config.seller_name);
     let css_script = get_color_scheme_css(payment_link_config.clone());
 
-    let profile_id = payment_link
-        .profile_id
-        .or(payment_intent.profile_id)
-        .ok_or(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Profile id missing in payment link and payment intent")?;
-
-    let business_profile = db
-        .find_business_profile_by_profile_id(&profile_id)
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
-            id: profile_id.to_string(),
-        })?;
+    let render_config =
+        get_link_render_config(business_link_config, request_context.locale, &link_host);
 
     let return_url = if let Some(payment_create_return_url) = payment_intent.return_url.clone() {
         payment_create_return_url
@@ -613,6 +691,7 @@ pub async fn get_payment_link_status(
     let payment_link_status_data = services::PaymentLinkStatusData {
         js_script,
         css_script,
+        render_config,
     };
     Ok(services::ApplicationResponse::PaymentLinkForm(Box::new(
         services::api::PaymentLinkAction::PaymentLinkStatus(payment_link_status_data),
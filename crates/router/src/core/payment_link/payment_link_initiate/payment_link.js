@@ -158,6 +158,27 @@ function invert(color, bw) {
   );
 }
 
+/**
+ * Trigger - on rendering any user facing text
+ * Uses
+ *  - Look up the localized string for the given key
+ *  - Substitute placeholders of the form {name} using params
+ * @param {string} key
+ * @param {Record<string, string | number>} [params]
+ * @returns {string}
+ */
+function translate(key, params) {
+  // @ts-ignore
+  var linkLocale = window.__LINK_LOCALE || {};
+  var strings = linkLocale.strings || {};
+  var text = typeof strings[key] === "string" ? strings[key] : key;
+  var values = params || {};
+  for (var param in values) {
+    text = text.replace("{" + param + "}", String(values[param]));
+  }
+  return text;
+}
+
 /**
  * UTIL FUNCTIONS END HERE
  */
@@ -219,7 +240,9 @@ function boot() {
   }
 
   if (paymentDetails.merchant_name) {
-    document.title = "Payment requested by " + paymentDetails.merchant_name;
+    document.title = translate("payment_requested_by", {
+      merchant_name: paymentDetails.merchant_name,
+    });
   }
 
   if (paymentDetails.merchant_logo) {
@@ -410,6 +433,8 @@ function initializeSDK() {
   widgets = hyper.widgets({
     appearance: appearance,
     clientSecret: client_secret,
+    // @ts-ignore
+    locale: (window.__LINK_LOCALE || {}).locale,
   });
   var type =
     paymentDetails.sdk_layout === "spaced_accordion" ||
@@ -494,7 +519,7 @@ function handleSubmit(e) {
         if (error.type === "validation_error") {
           showMessage(error.message);
         } else {
-          showMessage("An unexpected error occurred.");
+          showMessage(translate("unexpected_error"));
         }
       } else {
         redirectToStatus();
@@ -630,12 +655,15 @@ function renderPaymentDetails(paymentDetails) {
   // Create merchant name's node
   var merchantNameNode = document.createElement("div");
   merchantNameNode.className = "hyper-checkout-payment-merchant-name";
-  merchantNameNode.innerText = "Requested by " + paymentDetails.merchant_name;
+  merchantNameNode.innerText = translate("requested_by", {
+    merchant_name: paymentDetails.merchant_name,
+  });
 
   // Create payment ID node
   var paymentIdNode = document.createElement("div");
   paymentIdNode.className = "hyper-checkout-payment-ref";
-  paymentIdNode.innerText = "Ref Id: " + paymentDetails.payment_id;
+  paymentIdNode.innerText =
+    translate("ref_id") + ": " + paymentDetails.payment_id;
 
   // Create merchant logo's node
   var merchantLogoNode = document.createElement("img");
@@ -648,7 +676,9 @@ function renderPaymentDetails(paymentDetails) {
   paymentExpiryNode.className = "hyper-checkout-payment-footer-expiry";
   var expiryDate = new Date(paymentDetails.session_expiry);
   var formattedDate = formatDate(expiryDate);
-  paymentExpiryNode.innerText = "Link expires on: " + formattedDate;
+  paymentExpiryNode.innerText = translate("link_expires_on", {
+    date: formattedDate,
+  });
 
   // Append information to DOM
   var paymentContextNode = document.getElementById(
@@ -723,7 +753,9 @@ function renderCart(paymentDetails) {
       buttonTextNode.id = "hyper-checkout-cart-button-text";
       var hiddenItemsCount =
         orderDetails.length - MAX_ITEMS_VISIBLE_AFTER_COLLAPSE;
-      buttonTextNode.innerText = "Show More (" + hiddenItemsCount + ")";
+      buttonTextNode.innerText = translate("show_more", {
+        count: hiddenItemsCount,
+      });
       expandButtonNode.append(buttonTextNode, buttonImageNode);
       if (cartNode instanceof HTMLDivElement) {
         cartNode.insertBefore(expandButtonNode, cartNode.lastElementChild);
@@ -787,7 +819,9 @@ function renderCartItem(
   if (item.quantity !== null) {
     var quantityNode = document.createElement("div");
     quantityNode.className = "hyper-checkout-card-item-quantity";
-    quantityNode.innerText = "Qty: " + item.quantity;
+    quantityNode.innerText = translate("quantity", {
+      quantity: item.quantity,
+    });
   }  
   // Product price
   var priceNode = document.createElement("div");
@@ -862,7 +896,7 @@ function handleCartView(paymentDetails) {
     }
 
     if (cartButtonTextNode instanceof HTMLSpanElement) {
-      cartButtonTextNode.innerText = "Show Less";
+      cartButtonTextNode.innerText = translate("show_less");
     }
 
     var arrowUpImage = document.getElementById("arrow-up");
@@ -900,7 +934,9 @@ function handleCartView(paymentDetails) {
       var hiddenItemsCount =
         orderDetails.length - MAX_ITEMS_VISIBLE_AFTER_COLLAPSE;
       if (cartButtonTextNode instanceof HTMLSpanElement) {
-        cartButtonTextNode.innerText = "Show More (" + hiddenItemsCount + ")";
+        cartButtonTextNode.innerText = translate("show_more", {
+          count: hiddenItemsCount,
+        });
       }
       var arrowDownImage = document.getElementById("arrow-down");
       if (
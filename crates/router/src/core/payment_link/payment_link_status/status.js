@@ -71,6 +71,27 @@ function invert(color, bw) {
   );
 }
 
+/**
+ * Trigger - on rendering any user facing text
+ * Uses
+ *  - Look up the localized string for the given key
+ *  - Substitute placeholders of the form {name} using params
+ * @param {string} key
+ * @param {Record<string, string | number>} [params]
+ * @returns {string}
+ */
+function translate(key, params) {
+  // @ts-ignore
+  var linkLocale = window.__LINK_LOCALE || {};
+  var strings = linkLocale.strings || {};
+  var text = typeof strings[key] === "string" ? strings[key] : key;
+  var values = params || {};
+  for (var param in values) {
+    text = text.replace("{" + param + "}", String(values[param]));
+  }
+  return text;
+}
+
 /**
  * UTIL FUNCTIONS END HERE
  */
@@ -135,7 +156,7 @@ function renderStatusDetails(paymentDetails) {
   };
 
   // Payment details
-  var paymentId = createItem("Ref Id", paymentDetails.payment_id);
+  var paymentId = createItem(translate("ref_id"), paymentDetails.payment_id);
   // @ts-ignore
   statusDetails.items.push(paymentId);
 
@@ -143,15 +164,15 @@ function renderStatusDetails(paymentDetails) {
   switch (status) {
     case "expired":
       statusDetails.imageSource = "https://live.hyperswitch.io/payment-link-assets/failed.png";
-      statusDetails.status = "Payment Link Expired";
+      statusDetails.status = translate("payment_link_expired");
       statusDetails.message =
-        "Sorry, this payment link has expired. Please use below reference for further investigation.";
+        translate("payment_link_expired_message");
       break;
 
     case "succeeded":
       statusDetails.imageSource = "https://live.hyperswitch.io/payment-link-assets/success.png";
-      statusDetails.message = "We have successfully received your payment";
-      statusDetails.status = "Paid successfully";
+      statusDetails.message = translate("payment_received_message");
+      statusDetails.status = translate("paid_successfully");
       statusDetails.amountText = new Date(
         paymentDetails.created
       ).toTimeString();
@@ -160,16 +181,16 @@ function renderStatusDetails(paymentDetails) {
     case "processing":
       statusDetails.imageSource = "https://live.hyperswitch.io/payment-link-assets/pending.png";
       statusDetails.message =
-        "Sorry! Your payment is taking longer than expected. Please check back again in sometime.";
-      statusDetails.status = "Payment Pending";
+        translate("payment_pending_message");
+      statusDetails.status = translate("payment_pending");
       break;
 
     case "failed":
       statusDetails.imageSource = "https://live.hyperswitch.io/payment-link-assets/failed.png";
-      statusDetails.status = "Payment Failed!";
-      var errorCodeNode = createItem("Error code", paymentDetails.error_code);
+      statusDetails.status = translate("payment_failed");
+      var errorCodeNode = createItem(translate("error_code"), paymentDetails.error_code);
       var errorMessageNode = createItem(
-        "Error message",
+        translate("error_message"),
         paymentDetails.error_message
       );
       // @ts-ignore
@@ -178,34 +199,34 @@ function renderStatusDetails(paymentDetails) {
 
     case "cancelled":
       statusDetails.imageSource = "https://live.hyperswitch.io/payment-link-assets/failed.png";
-      statusDetails.status = "Payment Cancelled";
+      statusDetails.status = translate("payment_cancelled");
       break;
 
     case "requires_merchant_action":
       statusDetails.imageSource = "https://live.hyperswitch.io/payment-link-assets/pending.png";
-      statusDetails.status = "Payment under review";
+      statusDetails.status = translate("payment_under_review");
       break;
 
     case "requires_capture":
       statusDetails.imageSource = "https://live.hyperswitch.io/payment-link-assets/success.png";
-      statusDetails.message = "We have successfully received your payment";
-      statusDetails.status = "Payment Success";
+      statusDetails.message = translate("payment_received_message");
+      statusDetails.status = translate("payment_success");
       break;
 
     case "partially_captured":
       statusDetails.imageSource = "https://live.hyperswitch.io/payment-link-assets/success.png";
-      statusDetails.message = "Partial payment was captured.";
-      statusDetails.status = "Payment Success";
+      statusDetails.message = translate("partial_payment_captured_message");
+      statusDetails.status = translate("payment_success");
       break;
 
     default:
       statusDetails.imageSource = "https://live.hyperswitch.io/payment-link-assets/failed.png";
-      statusDetails.status = "Something went wrong";
+      statusDetails.status = translate("something_went_wrong");
       // Error details
       if (typeof paymentDetails.error === "object") {
-        var errorCodeNode = createItem("Error Code", paymentDetails.error.code);
+        var errorCodeNode = createItem(translate("error_code"), paymentDetails.error.code);
         var errorMessageNode = createItem(
-          "Error Message",
+          translate("error_message"),
           paymentDetails.error.message
         );
         // @ts-ignore
@@ -276,8 +297,8 @@ function renderStatusDetails(paymentDetails) {
           var secondsLeft = timeout - j++;
           var innerText =
             secondsLeft === 0
-              ? "Redirecting ..."
-              : "Redirecting in " + secondsLeft + " seconds ...";
+              ? translate("redirecting")
+              : translate("redirecting_in", { seconds: secondsLeft });
           // @ts-ignore
           statusRedirectTextNode.innerText = innerText;
           if (secondsLeft === 0) {
@@ -1,4 +1,6 @@
+pub mod card_account_updater;
 pub mod cards;
+pub mod network_tokenization;
 pub mod surcharge_decision_configs;
 pub mod transformers;
 pub mod vault;
@@ -6,7 +8,10 @@ pub use api_models::enums::Connector;
 #[cfg(feature = "payouts")]
 pub use api_models::{enums::PayoutConnectors, payouts as payout_types};
 use api_models::{payment_methods, payments::CardToken};
-use common_utils::{ext_traits::Encode, id_type::CustomerId};
+use common_utils::{
+    ext_traits::{Encode, ValueExt},
+    id_type::CustomerId,
+};
 use diesel_models::{
     enums, GenericLinkNew, PaymentMethodCollectLink, PaymentMethodCollectLinkData,
 };
@@ -21,11 +26,12 @@ use crate::{
     consts,
     core::{
         errors::{self, RouterResult},
+        link_hosting,
         payments::helpers,
         pm_auth as core_pm_auth,
     },
     routes::{app::StorageInterface, SessionState},
-    services::{self, GenericLinks},
+    services::{self, GenericLinks, GenericLinksData},
     types::{
         api::{self, payments},
         domain, storage,
@@ -204,6 +210,7 @@ pub async fn render_pm_collect_link(
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
     req: payment_methods::PaymentMethodCollectLinkRenderRequest,
+    request_context: link_hosting::LinkRenderRequestContext,
 ) -> RouterResponse<services::GenericLinkFormData> {
     let db: &dyn StorageInterface = &*state.store;
 
@@ -214,6 +221,51 @@ pub async fn render_pm_collect_link(
         .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
             message: "payment method collect link not found".to_string(),
         })?;
+    if pm_collect_link.merchant_id != req.merchant_id {
+        Err(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "payment method collect link not found".to_string(),
+        })
+        .attach_printable("payment method collect link belongs to a different merchant")?;
+    }
+
+    // Fetch rendering customizations of the merchant
+    let merchant_config = merchant_account
+        .pm_collect_link_config
+        .clone()
+        .map(|config| {
+            config.parse_value::<api_models::admin::BusinessCollectLinkConfig>(
+                "BusinessCollectLinkConfig",
+            )
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "pm_collect_link_config in merchant_account",
+        })?;
+
+    let link_host = link_hosting::validate_link_host(
+        &state,
+        &request_context,
+        pm_collect_link.url.peek(),
+        merchant_config
+            .as_ref()
+            .and_then(|config| config.config.domain_name.as_deref()),
+    )?;
+    if let link_hosting::LinkHost::Redirect(redirection_response) = link_host {
+        return Ok(services::ApplicationResponse::JsonForRedirection(
+            redirection_response,
+        ));
+    }
+
+    let render_config = services::LinkRenderConfig {
+        locale: request_context.locale.clone(),
+        allowed_domains: merchant_config
+            .as_ref()
+            .map(|config| config.config.allowed_domains.clone())
+            .unwrap_or_default(),
+        custom_template: merchant_config
+            .and_then(|config| config.config.custom_template)
+            .filter(|_| link_host.is_custom_domain()),
+    };
 
     // Check status and return form data accordingly
     let has_expired = common_utils::date_time::now() > pm_collect_link.expiry;
@@ -238,12 +290,21 @@ pub async fn render_pm_collect_link(
             // if expired, send back expired status page
             if has_expired {
                 let expired_link_data = services::GenericExpiredLinkData {
-                    title: "Payment collect link has expired".to_string(),
-                    message: "This payment collect link has expired.".to_string(),
+                    title: link_hosting::translate(
+                        &request_context.locale,
+                        "pm_collect_link_expired_title",
+                    ),
+                    message: link_hosting::translate(
+                        &request_context.locale,
+                        "pm_collect_link_expired_message",
+                    ),
                     theme: link_data.ui_config.theme.unwrap_or(default_ui_config.theme),
                 };
                 Ok(services::ApplicationResponse::GenericLinkForm(Box::new(
-                    GenericLinks::ExpiredLink(expired_link_data),
+                    GenericLinks {
+                        render_config,
+                        data: GenericLinksData::ExpiredLink(expired_link_data),
+                    },
                 )))
 
             // else, send back form link
@@ -306,7 +367,10 @@ pub async fn render_pm_collect_link(
                     html_meta_tags: String::new(),
                 };
                 Ok(services::ApplicationResponse::GenericLinkForm(Box::new(
-                    GenericLinks::PaymentMethodCollect(generic_form_data),
+                    GenericLinks {
+                        render_config,
+                        data: GenericLinksData::PaymentMethodCollect(generic_form_data),
+                    },
                 )))
             }
         }
@@ -347,7 +411,10 @@ pub async fn render_pm_collect_link(
                 css_data: serialized_css_content,
             };
             Ok(services::ApplicationResponse::GenericLinkForm(Box::new(
-                GenericLinks::PaymentMethodCollectStatus(generic_status_data),
+                GenericLinks {
+                    render_config,
+                    data: GenericLinksData::PaymentMethodCollectStatus(generic_status_data),
+                },
             )))
         }
     }
@@ -0,0 +1,871 @@
+pub mod updater_service;
+
+use std::collections::HashMap;
+
+use api_models::payment_methods::{Card, CardDetailsPaymentMethod, PaymentMethodsData};
+use common_utils::id_type;
+use error_stack::ResultExt;
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, tracing};
+use scheduler::utils as pt_utils;
+use time::PrimitiveDateTime;
+
+use self::updater_service::{AccountUpdateRequest, AccountUpdateResult, CardAccountUpdaterService};
+use crate::{
+    core::{
+        errors::{self, CustomResult, RouterResult},
+        payment_methods::transformers as payment_methods,
+        webhooks as webhooks_core,
+    },
+    db::StorageInterface,
+    logger,
+    routes::SessionState,
+    services,
+    types::{
+        api, domain,
+        storage::{self, enums, CardAccountUpdaterTrackingData},
+    },
+};
+
+const CARD_ACCOUNT_UPDATER_TASK: &str = "CARD_ACCOUNT_UPDATER";
+const CARD_ACCOUNT_UPDATER_TAG: [&str; 2] = ["PAYMENT_METHOD", "CARD_ACCOUNT_UPDATER"];
+
+/// Number of saved cards read from the database at a time when looking for cards to be updated
+const PAYMENT_METHOD_FETCH_LIMIT: i64 = 500;
+
+/// Schedule the card account updater task for a merchant, unless one is already scheduled.
+///
+/// A single recurring task refreshes all the saved cards of the merchant, the task is resumed if
+/// it was finished earlier.
+pub async fn add_card_account_updater_task(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    schedule_time: PrimitiveDateTime,
+) -> CustomResult<(), errors::StorageError> {
+    let runner = storage::ProcessTrackerRunner::CardAccountUpdaterWorkflow;
+    let task = CARD_ACCOUNT_UPDATER_TASK;
+    let process_tracker_id =
+        pt_utils::get_process_tracker_id(runner, task, merchant_id, merchant_id);
+    let tracking_data = CardAccountUpdaterTrackingData {
+        merchant_id: merchant_id.to_owned(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id.clone(),
+        task,
+        runner,
+        CARD_ACCOUNT_UPDATER_TAG,
+        tracking_data,
+        schedule_time,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    match db.insert_process(process_tracker_entry).await {
+        Ok(_) => Ok(()),
+        Err(error) if error.current_context().is_db_unique_violation() => {
+            match db.find_process_by_id(&process_tracker_id).await? {
+                Some(process) if process.status == enums::ProcessTrackerStatus::Finish => {
+                    db.reset_process(process, schedule_time).await
+                }
+                Some(_) | None => Ok(()),
+            }
+        }
+        Err(error) => Err(error),
+    }
+}
+
+/// Schedule the card account updater for the merchant after a card has been saved.
+///
+/// Failures are only logged, the card is picked up by the next scheduled run in that case.
+#[instrument(skip_all)]
+pub async fn schedule_card_account_updater(state: &SessionState, merchant_id: &str) {
+    let config = &state.conf.card_account_updater;
+    if !config.enabled {
+        return;
+    }
+
+    let schedule_time = common_utils::date_time::now().saturating_add(time::Duration::hours(
+        i64::from(config.schedule_interval_hours),
+    ));
+    if let Err(error) =
+        add_card_account_updater_task(state.store.as_ref(), merchant_id, schedule_time).await
+    {
+        logger::error!(?error, %merchant_id, "Failed to schedule card account updater task");
+    }
+}
+
+/// Flag a saved card to be refreshed by the card account updater, if the payment made with it
+/// was declined due to the card having expired
+#[instrument(skip_all)]
+pub async fn request_account_update_for_declined_card(
+    state: &SessionState,
+    payment_method: &storage::PaymentMethod,
+    gsm: &storage::gsm::GatewayStatusMap,
+    storage_scheme: enums::MerchantStorageScheme,
+) {
+    let config = &state.conf.card_account_updater;
+    let is_expired_card_decline = gsm
+        .unified_code
+        .as_ref()
+        .is_some_and(|unified_code| config.expired_card_unified_codes.contains(unified_code));
+
+    if !config.enabled
+        || !is_expired_card_decline
+        || payment_method.payment_method != Some(enums::PaymentMethod::Card)
+        || payment_method.account_update_requested_at.is_some()
+    {
+        return;
+    }
+
+    let payment_method_update = storage::PaymentMethodUpdate::AccountUpdateRequestedUpdate {
+        account_update_requested_at: common_utils::date_time::now(),
+    };
+    if let Err(error) = state
+        .store
+        .update_payment_method(
+            payment_method.clone(),
+            payment_method_update,
+            storage_scheme,
+        )
+        .await
+    {
+        logger::error!(
+            ?error,
+            payment_method_id = %payment_method.payment_method_id,
+            "Failed to request account update for saved card"
+        );
+        return;
+    }
+
+    // The card is picked up by the next run of the updater if the task is already scheduled
+    if let Err(error) = add_card_account_updater_task(
+        state.store.as_ref(),
+        &payment_method.merchant_id,
+        common_utils::date_time::now(),
+    )
+    .await
+    {
+        logger::error!(
+            ?error,
+            merchant_id = %payment_method.merchant_id,
+            "Failed to schedule card account updater task"
+        );
+    }
+}
+
+struct AccountUpdateCandidate {
+    payment_method: storage::PaymentMethod,
+    card_details: CardDetailsPaymentMethod,
+}
+
+/// Refresh the saved cards of a merchant which are nearing expiry, or have been flagged after a
+/// payment made with them was declined as expired
+#[instrument(skip_all)]
+pub async fn process_card_account_updates(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<()> {
+    let config = &state.conf.card_account_updater;
+    let updater_service = updater_service::get_card_account_updater_service(config)
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Card account updater service is not configured")?;
+
+    let expiry_cutoff = common_utils::date_time::now()
+        .saturating_add(time::Duration::days(i64::from(config.expiry_window_days)));
+    let candidates =
+        get_account_update_candidates(state, merchant_account, key_store, expiry_cutoff).await?;
+
+    logger::info!(
+        merchant_id = %merchant_account.merchant_id,
+        card_count = candidates.len(),
+        "Requesting account updates for saved cards"
+    );
+
+    for batch in candidates.chunks(usize::from(config.batch_size)) {
+        update_saved_cards(
+            state,
+            updater_service.as_ref(),
+            merchant_account,
+            key_store,
+            batch,
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+async fn get_account_update_candidates(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    expiry_cutoff: PrimitiveDateTime,
+) -> RouterResult<Vec<AccountUpdateCandidate>> {
+    let db = state.store.as_ref();
+    let mut candidates = Vec::new();
+    let mut last_id = None;
+
+    loop {
+        // Only the cards which are flagged, expiring or whose expiry is not known are fetched, so
+        // that the other saved cards are not decrypted on every run
+        let payment_methods = db
+            .find_account_update_candidates_by_merchant_id(
+                &merchant_account.merchant_id,
+                expiry_cutoff,
+                last_id,
+                PAYMENT_METHOD_FETCH_LIMIT,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch saved cards of merchant")?;
+        let fetched_count = payment_methods.len();
+        last_id = payment_methods
+            .last()
+            .map(|payment_method| payment_method.id)
+            .or(last_id);
+
+        for payment_method in payment_methods {
+            let card_details = match get_card_details(key_store, &payment_method).await {
+                Ok(Some(card_details)) => card_details,
+                Ok(None) => continue,
+                Err(error) => {
+                    logger::error!(
+                        ?error,
+                        payment_method_id = %payment_method.payment_method_id,
+                        "Failed to decrypt saved card details"
+                    );
+                    continue;
+                }
+            };
+
+            // The expiry is recorded for cards saved before it was stored against the payment
+            // method, and corrected for cards whose details were updated since
+            if let Some(card_expiry_month_start) =
+                get_card_details_expiry_month_start(&card_details).filter(|expiry_month_start| {
+                    payment_method.card_expiry_month_start != Some(*expiry_month_start)
+                })
+            {
+                record_card_expiry(
+                    state,
+                    merchant_account,
+                    &payment_method,
+                    card_expiry_month_start,
+                )
+                .await;
+            }
+
+            if payment_method.account_update_requested_at.is_some()
+                || is_card_expiring_before(&card_details, expiry_cutoff)
+            {
+                candidates.push(AccountUpdateCandidate {
+                    payment_method,
+                    card_details,
+                });
+            }
+        }
+
+        if i64::try_from(fetched_count).unwrap_or(i64::MAX) < PAYMENT_METHOD_FETCH_LIMIT {
+            break;
+        }
+    }
+
+    Ok(candidates)
+}
+
+async fn record_card_expiry(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    payment_method: &storage::PaymentMethod,
+    card_expiry_month_start: PrimitiveDateTime,
+) {
+    if let Err(error) = state
+        .store
+        .update_payment_method(
+            payment_method.clone(),
+            storage::PaymentMethodUpdate::CardExpiryUpdate {
+                card_expiry_month_start,
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+    {
+        logger::error!(
+            ?error,
+            payment_method_id = %payment_method.payment_method_id,
+            "Failed to record expiry of saved card"
+        );
+    }
+}
+
+async fn get_card_details(
+    key_store: &domain::MerchantKeyStore,
+    payment_method: &storage::PaymentMethod,
+) -> RouterResult<Option<CardDetailsPaymentMethod>> {
+    let payment_method_data = super::cards::decrypt_generic_data::<PaymentMethodsData>(
+        payment_method.payment_method_data.clone(),
+        key_store.key.get_inner().peek(),
+    )
+    .await?;
+
+    Ok(match payment_method_data {
+        Some(PaymentMethodsData::Card(card_details)) => Some(card_details),
+        Some(PaymentMethodsData::BankDetails(_)) | None => None,
+    })
+}
+
+/// Cards expire at the end of their expiry month, the start of the expiry month is stored against
+/// the saved card so that the cards nearing expiry can be selected without decrypting every card
+pub fn get_card_expiry_month_start(
+    card_exp_month: &Secret<String>,
+    card_exp_year: &Secret<String>,
+) -> Option<PrimitiveDateTime> {
+    let month = card_exp_month.peek().parse::<u8>().ok()?;
+    let year = card_exp_year.peek().parse::<i32>().ok()?;
+    // Two digit years are stored for some of the saved cards
+    let year = if year < 100 { year + 2000 } else { year };
+
+    time::Date::from_calendar_date(year, time::Month::try_from(month).ok()?, 1)
+        .ok()
+        .map(time::Date::midnight)
+}
+
+fn get_card_details_expiry_month_start(
+    card_details: &CardDetailsPaymentMethod,
+) -> Option<PrimitiveDateTime> {
+    card_details
+        .expiry_month
+        .as_ref()
+        .zip(card_details.expiry_year.as_ref())
+        .and_then(|(month, year)| get_card_expiry_month_start(month, year))
+}
+
+/// The card is considered to be expiring if the expiry month is not later than the month of the
+/// cutoff date
+fn is_card_expiring_before(
+    card_details: &CardDetailsPaymentMethod,
+    expiry_cutoff: PrimitiveDateTime,
+) -> bool {
+    get_card_details_expiry_month_start(card_details)
+        .is_some_and(|expiry_month_start| expiry_month_start <= expiry_cutoff)
+}
+
+async fn update_saved_cards(
+    state: &SessionState,
+    updater_service: &dyn CardAccountUpdaterService,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    candidates: &[AccountUpdateCandidate],
+) -> RouterResult<()> {
+    let mut requests = Vec::with_capacity(candidates.len());
+    let mut cards = HashMap::with_capacity(candidates.len());
+
+    for candidate in candidates {
+        let payment_method = &candidate.payment_method;
+        let card = match super::cards::get_card_from_locker(
+            state,
+            &payment_method.customer_id,
+            &payment_method.merchant_id,
+            payment_method
+                .locker_id
+                .as_ref()
+                .unwrap_or(&payment_method.payment_method_id),
+        )
+        .await
+        {
+            Ok(card) => card,
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    payment_method_id = %payment_method.payment_method_id,
+                    "Failed to fetch saved card from locker"
+                );
+                continue;
+            }
+        };
+
+        requests.push(AccountUpdateRequest {
+            reference_id: payment_method.payment_method_id.clone(),
+            merchant_id: payment_method.merchant_id.clone(),
+            card_number: card.card_number.clone(),
+            card_exp_month: card.card_exp_month.clone(),
+            card_exp_year: card.card_exp_year.clone(),
+            card_network: candidate.card_details.card_network.clone(),
+        });
+        cards.insert(payment_method.payment_method_id.as_str(), (candidate, card));
+    }
+
+    if requests.is_empty() {
+        return Ok(());
+    }
+
+    let responses = updater_service
+        .get_account_updates(state, &requests)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch account updates for saved cards")?;
+
+    for response in responses {
+        let Some((candidate, card)) = cards.remove(response.reference_id.as_str()) else {
+            logger::warn!(
+                reference_id = %response.reference_id,
+                "Received account update for an unknown saved card"
+            );
+            continue;
+        };
+
+        let payment_method_id = candidate.payment_method.payment_method_id.clone();
+        if let Err(error) = apply_account_update(
+            state,
+            merchant_account,
+            key_store,
+            candidate,
+            card,
+            response.result,
+        )
+        .await
+        {
+            logger::error!(?error, %payment_method_id, "Failed to apply account update");
+        }
+    }
+
+    Ok(())
+}
+
+async fn apply_account_update(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    candidate: &AccountUpdateCandidate,
+    card: Card,
+    result: AccountUpdateResult,
+) -> RouterResult<()> {
+    let (payment_method_update, replaced_locker_reference) = match result {
+        AccountUpdateResult::CardUpdated {
+            card_number,
+            card_exp_month,
+            card_exp_year,
+        } => {
+            let updated_card = Card {
+                card_number,
+                card_exp_month,
+                card_exp_year,
+                ..card
+            };
+            save_updated_card(state, merchant_account, key_store, candidate, updated_card).await?
+        }
+        AccountUpdateResult::ExpiryUpdated {
+            card_exp_month,
+            card_exp_year,
+        } => {
+            let updated_card = Card {
+                card_exp_month,
+                card_exp_year,
+                ..card
+            };
+            save_updated_card(state, merchant_account, key_store, candidate, updated_card).await?
+        }
+        AccountUpdateResult::AccountClosed => (
+            storage::PaymentMethodUpdate::AccountUpdaterUpdate {
+                payment_method_data: None,
+                status: Some(enums::PaymentMethodStatus::Inactive),
+                locker_id: None,
+                card_expiry_month_start: None,
+            },
+            None,
+        ),
+        AccountUpdateResult::NoUpdate => {
+            // Clear the flag set after an expired card decline, so that the card is not sent to
+            // the updater service again until it nears expiry
+            if candidate
+                .payment_method
+                .account_update_requested_at
+                .is_some()
+            {
+                state
+                    .store
+                    .update_payment_method(
+                        candidate.payment_method.clone(),
+                        storage::PaymentMethodUpdate::AccountUpdaterUpdate {
+                            payment_method_data: None,
+                            status: None,
+                            locker_id: None,
+                            card_expiry_month_start: None,
+                        },
+                        merchant_account.storage_scheme,
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to update payment method in db")?;
+            }
+            return Ok(());
+        }
+    };
+
+    let payment_method = state
+        .store
+        .update_payment_method(
+            candidate.payment_method.clone(),
+            payment_method_update,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update payment method in db")?;
+
+    // The replaced card is deleted only after the payment method points to the updated card, a
+    // failure leaves the replaced card unused in the locker
+    if let Some(locker_reference) = replaced_locker_reference {
+        if let Err(error) = super::cards::delete_card_from_locker(
+            state,
+            &payment_method.customer_id,
+            &payment_method.merchant_id,
+            &locker_reference,
+        )
+        .await
+        {
+            logger::error!(
+                ?error,
+                payment_method_id = %payment_method.payment_method_id,
+                "Failed to delete replaced card from locker"
+            );
+        }
+    }
+
+    trigger_payment_method_updated_webhook(state, merchant_account, key_store, &payment_method)
+        .await;
+
+    Ok(())
+}
+
+/// Save the updated card to the locker under a new locker reference, returning the update which
+/// points the payment method to the updated card along with the locker reference of the card it
+/// replaces.
+///
+/// The locker holds a single card per card number, if only the expiry of the card has changed the
+/// card is replaced under its existing locker reference instead.
+async fn save_updated_card(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    candidate: &AccountUpdateCandidate,
+    updated_card: Card,
+) -> RouterResult<(storage::PaymentMethodUpdate, Option<String>)> {
+    let payment_method = &candidate.payment_method;
+    let card_details = &candidate.card_details;
+    let locker_reference = payment_method
+        .locker_id
+        .as_ref()
+        .unwrap_or(&payment_method.payment_method_id);
+
+    let card = api::CardDetail {
+        card_number: updated_card.card_number.clone(),
+        card_exp_month: updated_card.card_exp_month.clone(),
+        card_exp_year: updated_card.card_exp_year.clone(),
+        card_holder_name: updated_card.name_on_card.clone(),
+        nick_name: updated_card.nick_name.clone().map(Secret::new),
+        card_issuing_country: card_details.issuer_country.clone(),
+        card_network: card_details.card_network.clone(),
+        card_issuer: card_details.card_issuer.clone(),
+        card_type: card_details.card_type.clone(),
+    };
+    let payment_method_create = api::PaymentMethodCreate {
+        payment_method: payment_method.payment_method,
+        payment_method_type: payment_method.payment_method_type,
+        payment_method_issuer: payment_method.payment_method_issuer.clone(),
+        payment_method_issuer_code: payment_method.payment_method_issuer_code,
+        #[cfg(feature = "payouts")]
+        bank_transfer: None,
+        card: Some(card.clone()),
+        #[cfg(feature = "payouts")]
+        wallet: None,
+        metadata: None,
+        customer_id: Some(payment_method.customer_id.clone()),
+        client_secret: None,
+        payment_method_data: None,
+        card_network: None,
+    };
+
+    let (response, duplication_check) = Box::pin(super::cards::add_card_to_locker(
+        state,
+        payment_method_create.clone(),
+        &card,
+        &payment_method.customer_id,
+        merchant_account,
+        None,
+    ))
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to add updated card to locker")?;
+    let updated_locker_reference = response.payment_method_id;
+
+    if duplication_check == Some(payment_methods::DataDuplicationCheck::MetaDataChanged) {
+        replace_card_in_locker(
+            state,
+            merchant_account,
+            payment_method_create,
+            &card,
+            &payment_method.customer_id,
+            &updated_locker_reference,
+        )
+        .await?;
+    }
+
+    let updated_card_details = CardDetailsPaymentMethod {
+        last4_digits: Some(updated_card.card_number.get_last4()),
+        card_isin: Some(updated_card.card_number.get_card_isin()),
+        expiry_month: Some(updated_card.card_exp_month),
+        expiry_year: Some(updated_card.card_exp_year),
+        ..card_details.clone()
+    };
+    let card_expiry_month_start = get_card_details_expiry_month_start(&updated_card_details);
+    let payment_method_data = super::cards::create_encrypted_data(
+        key_store,
+        Some(PaymentMethodsData::Card(updated_card_details)),
+    )
+    .await
+    .map(|details| details.into())
+    .ok_or(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt updated card details")?;
+
+    let replaced_locker_reference =
+        (updated_locker_reference != *locker_reference).then(|| locker_reference.clone());
+
+    Ok((
+        storage::PaymentMethodUpdate::AccountUpdaterUpdate {
+            payment_method_data: Some(payment_method_data),
+            status: None,
+            locker_id: Some(updated_locker_reference),
+            card_expiry_month_start,
+        },
+        replaced_locker_reference,
+    ))
+}
+
+/// Replace a card stored in the locker with the same card having different details, restoring the
+/// stored card if the updated card could not be saved
+async fn replace_card_in_locker(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    payment_method_create: api::PaymentMethodCreate,
+    card: &api::CardDetail,
+    customer_id: &id_type::CustomerId,
+    locker_reference: &str,
+) -> RouterResult<()> {
+    let merchant_id = merchant_account.merchant_id.as_str();
+    let stored_card =
+        super::cards::get_card_from_locker(state, customer_id, merchant_id, locker_reference)
+            .await?;
+
+    super::cards::delete_card_from_locker(state, customer_id, merchant_id, locker_reference)
+        .await?;
+
+    let result = Box::pin(super::cards::add_card_to_locker(
+        state,
+        payment_method_create.clone(),
+        card,
+        customer_id,
+        merchant_account,
+        Some(locker_reference),
+    ))
+    .await;
+
+    if result.is_err() {
+        let stored_card = api::CardDetail {
+            card_number: stored_card.card_number,
+            card_exp_month: stored_card.card_exp_month,
+            card_exp_year: stored_card.card_exp_year,
+            card_holder_name: stored_card.name_on_card,
+            nick_name: stored_card.nick_name.map(Secret::new),
+            ..card.clone()
+        };
+        Box::pin(super::cards::add_card_to_locker(
+            state,
+            payment_method_create,
+            &stored_card,
+            customer_id,
+            merchant_account,
+            Some(locker_reference),
+        ))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to restore card in locker")?;
+    }
+
+    result
+        .map(|_| ())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to add updated card to locker")
+}
+
+/// The primary object ID of a payment method updated event includes the time of the update, so
+/// that the events raised for subsequent updates of the same card are not treated as duplicates
+fn get_payment_method_updated_event_object_id(payment_method: &storage::PaymentMethod) -> String {
+    format!(
+        "{}_{}",
+        payment_method.payment_method_id,
+        payment_method.last_modified.assume_utc().unix_timestamp()
+    )
+}
+
+/// Extract the payment method ID from the primary object ID of a payment method updated event
+pub fn get_payment_method_id_from_event_object_id(primary_object_id: &str) -> &str {
+    primary_object_id
+        .rsplit_once('_')
+        .map_or(primary_object_id, |(payment_method_id, _)| {
+            payment_method_id
+        })
+}
+
+async fn trigger_payment_method_updated_webhook(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_method: &storage::PaymentMethod,
+) {
+    let Some(profile_id) = merchant_account.default_profile.as_ref() else {
+        logger::warn!(
+            payment_method_id = %payment_method.payment_method_id,
+            "Default business profile not found for merchant, skipping outgoing webhook"
+        );
+        return;
+    };
+
+    let business_profile = match state
+        .store
+        .find_business_profile_by_profile_id(profile_id)
+        .await
+    {
+        Ok(business_profile) => business_profile,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to fetch business profile for payment method"
+            );
+            return;
+        }
+    };
+
+    let payment_method_response = match super::cards::retrieve_payment_method(
+        state.clone(),
+        api::PaymentMethodId {
+            payment_method_id: payment_method.payment_method_id.clone(),
+        },
+        key_store.clone(),
+        merchant_account.clone(),
+    )
+    .await
+    {
+        Ok(services::ApplicationResponse::Json(payment_method_response)) => payment_method_response,
+        Ok(_) => {
+            logger::error!("Unexpected response received when retrieving payment method");
+            return;
+        }
+        Err(error) => {
+            logger::error!(?error, "Failed to retrieve payment method");
+            return;
+        }
+    };
+
+    let result = Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account.clone(),
+        business_profile,
+        key_store,
+        enums::EventType::PaymentMethodUpdated,
+        enums::EventClass::PaymentMethods,
+        get_payment_method_updated_event_object_id(payment_method),
+        enums::EventObjectType::PaymentMethodDetails,
+        api::OutgoingWebhookContent::PaymentMethodDetails(Box::new(payment_method_response)),
+        Some(payment_method.created_at),
+    ))
+    .await;
+
+    if let Err(error) = result {
+        logger::error!(
+            ?error,
+            payment_method_id = %payment_method.payment_method_id,
+            "Failed to trigger outgoing webhook for updated payment method"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn get_card_details(expiry_month: &str, expiry_year: &str) -> CardDetailsPaymentMethod {
+        CardDetailsPaymentMethod {
+            last4_digits: Some("4242".to_string()),
+            issuer_country: None,
+            expiry_month: Some(Secret::new(expiry_month.to_string())),
+            expiry_year: Some(Secret::new(expiry_year.to_string())),
+            nick_name: None,
+            card_holder_name: None,
+            card_isin: None,
+            card_issuer: None,
+            card_network: None,
+            card_type: None,
+            saved_to_locker: true,
+        }
+    }
+
+    #[test]
+    fn test_get_card_expiry_month_start() {
+        assert_eq!(
+            get_card_expiry_month_start(
+                &Secret::new("03".to_string()),
+                &Secret::new("2026".to_string())
+            ),
+            Some(time::macros::datetime!(2026-03-01 00:00))
+        );
+        assert_eq!(
+            get_card_expiry_month_start(
+                &Secret::new("12".to_string()),
+                &Secret::new("26".to_string())
+            ),
+            Some(time::macros::datetime!(2026-12-01 00:00))
+        );
+        assert_eq!(
+            get_card_expiry_month_start(
+                &Secret::new("13".to_string()),
+                &Secret::new("2026".to_string())
+            ),
+            None
+        );
+        assert_eq!(
+            get_card_expiry_month_start(
+                &Secret::new("ab".to_string()),
+                &Secret::new("2026".to_string())
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_is_card_expiring_before() {
+        let expiry_cutoff = time::macros::datetime!(2026-03-15 10:00);
+
+        assert!(is_card_expiring_before(
+            &get_card_details("02", "2026"),
+            expiry_cutoff
+        ));
+        assert!(is_card_expiring_before(
+            &get_card_details("03", "26"),
+            expiry_cutoff
+        ));
+        assert!(!is_card_expiring_before(
+            &get_card_details("04", "2026"),
+            expiry_cutoff
+        ));
+        assert!(!is_card_expiring_before(
+            &get_card_details("01", "2027"),
+            expiry_cutoff
+        ));
+        assert!(!is_card_expiring_before(
+            &get_card_details("", "2026"),
+            expiry_cutoff
+        ));
+    }
+}
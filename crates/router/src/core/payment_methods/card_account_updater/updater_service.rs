@@ -0,0 +1,137 @@
+use std::collections::HashMap;
+
+use api_models::enums as api_enums;
+use cards::CardNumber;
+use common_utils::ext_traits::ByteSliceExt;
+use error_stack::ResultExt;
+use masking::Secret;
+use router_env::{instrument, tracing};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    configs::settings,
+    core::errors::{self, CustomResult},
+    routes::SessionState,
+};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AccountUpdateRequest {
+    /// Identifier used for matching the update to the saved card, the payment method ID
+    pub reference_id: String,
+    pub merchant_id: String,
+    pub card_number: CardNumber,
+    pub card_exp_month: Secret<String>,
+    pub card_exp_year: Secret<String>,
+    pub card_network: Option<api_enums::CardNetwork>,
+}
+
+/// Outcome of an account update inquiry for a single card
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "result", rename_all = "snake_case")]
+pub enum AccountUpdateResult {
+    /// The card has been reissued with a new card number
+    CardUpdated {
+        card_number: CardNumber,
+        card_exp_month: Secret<String>,
+        card_exp_year: Secret<String>,
+    },
+    /// The card has been reissued with the same card number and a new expiry date
+    ExpiryUpdated {
+        card_exp_month: Secret<String>,
+        card_exp_year: Secret<String>,
+    },
+    /// The account has been closed by the cardholder or the issuer
+    AccountClosed,
+    /// No update is available for the card
+    NoUpdate,
+}
+
+#[derive(Debug, Clone)]
+pub struct AccountUpdateResponse {
+    pub reference_id: String,
+    pub result: AccountUpdateResult,
+}
+
+/// Interface to be implemented by card account updater services
+#[async_trait::async_trait]
+pub trait CardAccountUpdaterService: Send + Sync {
+    /// Fetch the latest details of the given cards. Cards without a response are treated as not
+    /// having any update.
+    async fn get_account_updates(
+        &self,
+        state: &SessionState,
+        requests: &[AccountUpdateRequest],
+    ) -> CustomResult<Vec<AccountUpdateResponse>, errors::CardAccountUpdaterError>;
+}
+
+/// Returns the configured card account updater service, if the card account updater is enabled
+pub fn get_card_account_updater_service(
+    config: &settings::CardAccountUpdater,
+) -> Option<Box<dyn CardAccountUpdaterService>> {
+    if !config.enabled {
+        return None;
+    }
+
+    config
+        .updater_service
+        .as_ref()
+        .map(|updater_service| match updater_service {
+            settings::CardAccountUpdaterServiceConfig::File { response_file_path } => {
+                Box::new(FileCardAccountUpdaterService {
+                    response_file_path: response_file_path.clone(),
+                }) as Box<dyn CardAccountUpdaterService>
+            }
+        })
+}
+
+/// Updater service serving account updates from a local JSON file, keyed by card number.
+///
+/// Meant as a stand-in for an actual updater service when testing the updater workflow.
+#[derive(Debug, Clone)]
+pub struct FileCardAccountUpdaterService {
+    response_file_path: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct FileAccountUpdateRecord {
+    card_number: CardNumber,
+    #[serde(flatten)]
+    result: AccountUpdateResult,
+}
+
+#[async_trait::async_trait]
+impl CardAccountUpdaterService for FileCardAccountUpdaterService {
+    #[instrument(skip_all)]
+    async fn get_account_updates(
+        &self,
+        _state: &SessionState,
+        requests: &[AccountUpdateRequest],
+    ) -> CustomResult<Vec<AccountUpdateResponse>, errors::CardAccountUpdaterError> {
+        let contents = std::fs::read(&self.response_file_path)
+            .change_context(errors::CardAccountUpdaterError::ReadAccountUpdatesFailed)
+            .attach_printable_lazy(|| {
+                format!(
+                    "Failed to read account updates from {}",
+                    self.response_file_path
+                )
+            })?;
+
+        let records = contents
+            .parse_struct::<Vec<FileAccountUpdateRecord>>("Vec<FileAccountUpdateRecord>")
+            .change_context(errors::CardAccountUpdaterError::ResponseDeserializationFailed)?
+            .into_iter()
+            .map(|record| (record.card_number.get_card_no(), record.result))
+            .collect::<HashMap<_, _>>();
+
+        Ok(requests
+            .iter()
+            .map(|request| AccountUpdateResponse {
+                reference_id: request.reference_id.clone(),
+                result: records
+                    .get(&request.card_number.get_card_no())
+                    .cloned()
+                    .unwrap_or(AccountUpdateResult::NoUpdate),
+            })
+            .collect())
+    }
+}
@@ -48,7 +48,8 @@ use crate::{
     core::{
         errors::{self, StorageErrorExt},
         payment_methods::{
-            add_payment_method_status_update_task, transformers as payment_methods, vault,
+            add_payment_method_status_update_task, card_account_updater, network_tokenization,
+            transformers as payment_methods, vault,
         },
         payments::{
             helpers,
@@ -141,6 +142,16 @@ pub async fn create_payment_method(
                 last_used_at: current_time,
                 payment_method_billing_address,
                 updated_by: None,
+                network_token_reference_id: None,
+                network_token_status: None,
+                network_token_data: None,
+                account_update_requested_at: None,
+                card_expiry_month_start: req.card.as_ref().and_then(|card| {
+                    card_account_updater::get_card_expiry_month_start(
+                        &card.card_exp_month,
+                        &card.card_exp_year,
+                    )
+                }),
             },
             storage_scheme,
         )
@@ -723,6 +734,7 @@ pub async fn add_payment_method(
                 None
             };
             resp.payment_method_id = generate_id(consts::ID_LENGTH, "pm");
+            let is_card = req.card.is_some();
             let pm = insert_payment_method(
                 db,
                 &resp,
@@ -740,7 +752,12 @@ pub async fn add_payment_method(
             )
             .await?;
 
-            resp.client_secret = pm.client_secret;
+            resp.client_secret.clone_from(&pm.client_secret);
+
+            if is_card {
+                network_tokenization::schedule_network_tokenization(&state, &pm).await;
+                card_account_updater::schedule_card_account_updater(&state, merchant_id).await;
+            }
         }
     }
 
@@ -2295,6 +2312,9 @@ pub async fn list_payment_methods(
     let mut bank_transfer_consolidated_hm =
         HashMap::<api_enums::PaymentMethodType, Vec<String>>::new();
 
+    let mut installments_consolidated_hm =
+        HashMap::<api_enums::PaymentMethodType, Vec<String>>::new();
+
     // All the required fields will be stored here and later filtered out based on business profile config
     let mut required_fields_hm = HashMap::<
         api_enums::PaymentMethod,
@@ -2494,6 +2514,19 @@ pub async fn list_payment_methods(
                 bank_transfer_consolidated_hm.insert(element.payment_method_type, vec![connector]);
             }
         }
+
+        if element.payment_method == api_enums::PaymentMethod::Card
+            && element.installment_payment_enabled
+        {
+            let connector = element.connector.clone();
+            if let Some(vector_of_connectors) =
+                installments_consolidated_hm.get_mut(&element.payment_method_type)
+            {
+                vector_of_connectors.push(connector);
+            } else {
+                installments_consolidated_hm.insert(element.payment_method_type, vec![connector]);
+            }
+        }
     }
 
     let mut payment_method_responses: Vec<ResponsePaymentMethodsEnabled> = vec![];
@@ -2515,6 +2548,11 @@ pub async fn list_payment_methods(
                 bank_names: None,
                 bank_debits: None,
                 bank_transfers: None,
+                installments: installments_consolidated_hm
+                    .get(payment_method_types_hm.0)
+                    .map(|connectors| api_models::payment_methods::InstallmentTypes {
+                        eligible_connectors: connectors.clone(),
+                    }),
                 // Required fields for PayLater payment method
                 required_fields: required_fields_hm
                     .get(key.0)
@@ -2552,6 +2590,11 @@ pub async fn list_payment_methods(
                 bank_names: None,
                 bank_debits: None,
                 bank_transfers: None,
+                installments: installments_consolidated_hm
+                    .get(payment_method_types_hm.0)
+                    .map(|connectors| api_models::payment_methods::InstallmentTypes {
+                        eligible_connectors: connectors.clone(),
+                    }),
                 // Required fields for Card payment method
                 required_fields: required_fields_hm
                     .get(key.0)
@@ -2584,6 +2627,7 @@ pub async fn list_payment_methods(
                 card_networks: None,
                 bank_debits: None,
                 bank_transfers: None,
+                installments: None,
                 // Required fields for BankRedirect payment method
                 required_fields: required_fields_hm
                     .get(&api_enums::PaymentMethod::BankRedirect)
@@ -2617,6 +2661,7 @@ pub async fn list_payment_methods(
                     eligible_connectors: connectors.clone(),
                 }),
                 bank_transfers: None,
+                installments: None,
                 // Required fields for BankDebit payment method
                 required_fields: required_fields_hm
                     .get(&api_enums::PaymentMethod::BankDebit)
@@ -2650,6 +2695,7 @@ pub async fn list_payment_methods(
                 bank_transfers: Some(api_models::payment_methods::BankTransferTypes {
                     eligible_connectors: connectors,
                 }),
+                installments: None,
                 // Required fields for BankTransfer payment method
                 required_fields: required_fields_hm
                     .get(&api_enums::PaymentMethod::BankTransfer)
@@ -4357,6 +4403,8 @@ pub async fn delete_payment_method(
             logger::error!("Error: Deleting Card From Locker!\n{:#?}", response);
             Err(errors::ApiErrorResponse::InternalServerError)?
         }
+
+        network_tokenization::delete_network_token_for_payment_method(&state, &key).await;
     }
 
     db.delete_payment_method_by_merchant_id_payment_method_id(
@@ -0,0 +1,458 @@
+pub mod token_service;
+
+use std::str::FromStr;
+
+use api_models::{
+    enums as api_enums,
+    payment_methods::{Card, PaymentMethodsData},
+};
+use cards::CardNumber;
+use common_utils::{
+    crypto::{self, VerifySignature},
+    ext_traits::ByteSliceExt,
+};
+use error_stack::{report, ResultExt};
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, tracing};
+use scheduler::utils as pt_utils;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use self::token_service::{
+    CryptogramRequest, NetworkTokenEventType, NetworkTokenWebhookEvent, ProvisionTokenRequest,
+    TokenServiceProvider,
+};
+use crate::{
+    core::errors::{self, CustomResult, RouterResponse, StorageErrorExt},
+    db::StorageInterface,
+    headers, logger,
+    routes::SessionState,
+    services,
+    types::{self, domain, storage},
+};
+
+const NETWORK_TOKENIZATION_TASK: &str = "NETWORK_TOKENIZATION";
+const NETWORK_TOKENIZATION_TAG: [&str; 2] = ["PAYMENT_METHOD", "NETWORK_TOKENIZATION"];
+
+/// Network token details stored encrypted against the payment method
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NetworkTokenDetails {
+    pub token_number: CardNumber,
+    pub token_exp_month: Secret<String>,
+    pub token_exp_year: Secret<String>,
+    pub card_network: Option<api_enums::CardNetwork>,
+}
+
+/// Schedule the provisioning of a network token for a saved card.
+pub async fn add_network_tokenization_task(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    payment_method_id: &str,
+    schedule_time: PrimitiveDateTime,
+) -> CustomResult<(), errors::StorageError> {
+    let runner = storage::ProcessTrackerRunner::NetworkTokenizationWorkflow;
+    let task = NETWORK_TOKENIZATION_TASK;
+    let process_tracker_id =
+        pt_utils::get_process_tracker_id(runner, task, payment_method_id, merchant_id);
+    let tracking_data = storage::NetworkTokenizationTrackingData {
+        merchant_id: merchant_id.to_owned(),
+        payment_method_id: payment_method_id.to_owned(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        NETWORK_TOKENIZATION_TAG,
+        tracking_data,
+        schedule_time,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    db.insert_process(process_tracker_entry).await?;
+
+    Ok(())
+}
+
+/// Schedule the provisioning of a network token for a newly saved card, the token is provisioned
+/// by the scheduler so that saving the card does not wait on the token service.
+///
+/// Failures are only logged, payments made with the payment method fall back to the card number.
+#[instrument(skip_all)]
+pub async fn schedule_network_tokenization(
+    state: &SessionState,
+    payment_method: &storage::PaymentMethod,
+) {
+    if !state.conf.network_tokenization.get_inner().enabled {
+        return;
+    }
+
+    if let Err(error) = add_network_tokenization_task(
+        state.store.as_ref(),
+        &payment_method.merchant_id,
+        &payment_method.payment_method_id,
+        common_utils::date_time::now(),
+    )
+    .await
+    {
+        logger::error!(
+            ?error,
+            payment_method_id = %payment_method.payment_method_id,
+            "Failed to schedule network tokenization task"
+        );
+    }
+}
+
+/// Whether a network token has to be provisioned for the payment method
+pub fn is_network_token_pending(payment_method: &storage::PaymentMethod) -> bool {
+    payment_method.network_token_reference_id.is_none()
+        && payment_method.payment_method == Some(api_enums::PaymentMethod::Card)
+}
+
+/// Provision a network token for a saved card, using the card stored in the locker, and store
+/// the token details against the payment method
+#[instrument(skip_all)]
+pub async fn provision_network_token_for_payment_method(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_method: storage::PaymentMethod,
+) -> CustomResult<(), errors::NetworkTokenizationError> {
+    let token_service =
+        token_service::get_token_service_provider(state.conf.network_tokenization.get_inner())
+            .ok_or(errors::NetworkTokenizationError::NotConfigured)?;
+
+    let card = super::cards::get_card_from_locker(
+        state,
+        &payment_method.customer_id,
+        &payment_method.merchant_id,
+        payment_method
+            .locker_id
+            .as_ref()
+            .unwrap_or(&payment_method.payment_method_id),
+    )
+    .await
+    .change_context(errors::NetworkTokenizationError::FetchCardFailed)?;
+
+    // The card network is not stored in the locker, the token service identifies the network
+    // from the card number when it is not known
+    let card_network = super::cards::decrypt_generic_data::<PaymentMethodsData>(
+        payment_method.payment_method_data.clone(),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .ok()
+    .flatten()
+    .and_then(|payment_method_data| match payment_method_data {
+        PaymentMethodsData::Card(card_details) => card_details.card_network,
+        PaymentMethodsData::BankDetails(_) => None,
+    });
+
+    provision_and_store_network_token(
+        state,
+        token_service.as_ref(),
+        merchant_account,
+        key_store,
+        payment_method,
+        &card,
+        card_network,
+    )
+    .await
+}
+
+async fn provision_and_store_network_token(
+    state: &SessionState,
+    token_service: &dyn TokenServiceProvider,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_method: storage::PaymentMethod,
+    card: &Card,
+    card_network: Option<api_enums::CardNetwork>,
+) -> CustomResult<(), errors::NetworkTokenizationError> {
+    let request = ProvisionTokenRequest {
+        card_number: card.card_number.clone(),
+        card_exp_month: card.card_exp_month.clone(),
+        card_exp_year: card.card_exp_year.clone(),
+        card_holder_name: card.name_on_card.clone(),
+        card_network,
+        merchant_id: merchant_account.merchant_id.clone(),
+        payment_method_id: payment_method.payment_method_id.clone(),
+    };
+    let response = token_service.provision_token(state, &request).await?;
+
+    let token_details = NetworkTokenDetails {
+        token_number: response.token_number,
+        token_exp_month: response.token_exp_month,
+        token_exp_year: response.token_exp_year,
+        card_network: response.card_network.or(request.card_network),
+    };
+    let network_token_data = super::cards::create_encrypted_data(key_store, Some(token_details))
+        .await
+        .map(|details| details.into())
+        .ok_or(errors::NetworkTokenizationError::SaveNetworkTokenDataFailed)?;
+
+    let payment_method_update = storage::PaymentMethodUpdate::NetworkTokenDataUpdate {
+        network_token_reference_id: Some(response.token_reference_id),
+        network_token_status: Some(response.status),
+        network_token_data: Some(network_token_data),
+    };
+
+    state
+        .store
+        .update_payment_method(
+            payment_method,
+            payment_method_update,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::NetworkTokenizationError::SaveNetworkTokenDataFailed)?;
+
+    Ok(())
+}
+
+/// Fetch the network token of a saved card along with a fresh cryptogram, when the token can be
+/// used for the payment with the given connector.
+///
+/// Only the Checkout connector reads the network token from the authorize request, other
+/// connectors are sent the card number.
+///
+/// Returns `None` if the card number has to be used instead.
+#[instrument(skip_all)]
+pub async fn get_network_token_data_for_payment(
+    state: &SessionState,
+    connector_name: &str,
+    key_store: &domain::MerchantKeyStore,
+    payment_method: Option<&storage::PaymentMethod>,
+    request: &types::PaymentsAuthorizeData,
+) -> Option<domain::NetworkTokenData> {
+    let config = state.conf.network_tokenization.get_inner();
+    let token_service = token_service::get_token_service_provider(config)?;
+    let connector = api_enums::Connector::from_str(connector_name).ok()?;
+    let payment_method = payment_method?;
+
+    if !config.supported_connectors.contains(&connector)
+        || !matches!(
+            request.payment_method_data,
+            domain::PaymentMethodData::Card(_)
+        )
+        || payment_method.network_token_status != Some(api_enums::NetworkTokenStatus::Active)
+    {
+        return None;
+    }
+
+    let token_reference_id = payment_method.network_token_reference_id.clone()?;
+    let token_details = decrypt_network_token_details(key_store, payment_method).await;
+
+    let network_token_data = match token_details {
+        Ok(token_details) => token_service
+            .get_cryptogram(
+                state,
+                &CryptogramRequest {
+                    token_reference_id,
+                    amount: request.minor_amount,
+                    currency: request.currency,
+                },
+            )
+            .await
+            .map(|cryptogram| domain::NetworkTokenData {
+                token_number: token_details.token_number,
+                token_exp_month: token_details.token_exp_month,
+                token_exp_year: token_details.token_exp_year,
+                token_cryptogram: cryptogram.cryptogram,
+                eci: cryptogram.eci,
+                card_network: token_details.card_network,
+            }),
+        Err(error) => Err(error),
+    };
+
+    network_token_data
+        .map_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to fetch network token, proceeding with the card number"
+            )
+        })
+        .ok()
+}
+
+/// Delete the network token of a payment method which is being deleted
+#[instrument(skip_all)]
+pub async fn delete_network_token_for_payment_method(
+    state: &SessionState,
+    payment_method: &storage::PaymentMethod,
+) {
+    let Some(token_service) =
+        token_service::get_token_service_provider(state.conf.network_tokenization.get_inner())
+    else {
+        return;
+    };
+
+    if payment_method.network_token_status == Some(api_enums::NetworkTokenStatus::Deactivated) {
+        return;
+    }
+
+    if let Some(token_reference_id) = payment_method.network_token_reference_id.as_deref() {
+        if let Err(error) = token_service.delete_token(state, token_reference_id).await {
+            logger::error!(?error, "Failed to delete network token");
+        }
+    }
+}
+
+/// Apply a token lifecycle update sent by the token service provider
+#[instrument(skip_all)]
+pub async fn handle_network_token_webhook(
+    state: SessionState,
+    req: &actix_web::HttpRequest,
+    body: actix_web::web::Bytes,
+) -> RouterResponse<()> {
+    let config = state.conf.network_tokenization.get_inner();
+    if !config.enabled {
+        return Err(report!(errors::ApiErrorResponse::NotSupported {
+            message: "Network tokenization".to_string(),
+        }));
+    }
+
+    let signature = req
+        .headers()
+        .get(headers::X_NETWORK_TOKEN_SIGNATURE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| hex::decode(value).ok())
+        .ok_or(errors::ApiErrorResponse::WebhookAuthenticationFailed)?;
+
+    let is_signature_valid = crypto::HmacSha256
+        .verify_signature(config.webhook_secret.peek().as_bytes(), &signature, &body)
+        .change_context(errors::ApiErrorResponse::WebhookAuthenticationFailed)?;
+    if !is_signature_valid {
+        return Err(report!(
+            errors::ApiErrorResponse::WebhookAuthenticationFailed
+        ));
+    }
+
+    let event: NetworkTokenWebhookEvent = body
+        .parse_struct("NetworkTokenWebhookEvent")
+        .change_context(errors::ApiErrorResponse::WebhookBadRequest)?;
+
+    let db = state.store.as_ref();
+    let payment_method = db
+        .find_payment_method_by_network_token_reference_id(&event.token_reference_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)?;
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            &payment_method.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&payment_method.merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let payment_method_update = match event.event_type {
+        NetworkTokenEventType::TokenUpdated => {
+            let token_details = decrypt_network_token_details(&key_store, &payment_method)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+            let token_details = NetworkTokenDetails {
+                token_number: event.token_number.unwrap_or(token_details.token_number),
+                token_exp_month: event
+                    .token_exp_month
+                    .unwrap_or(token_details.token_exp_month),
+                token_exp_year: event.token_exp_year.unwrap_or(token_details.token_exp_year),
+                card_network: token_details.card_network,
+            };
+            let network_token_data =
+                super::cards::create_encrypted_data(&key_store, Some(token_details))
+                    .await
+                    .map(|details| details.into());
+
+            storage::PaymentMethodUpdate::NetworkTokenDataUpdate {
+                network_token_reference_id: None,
+                network_token_status: Some(api_enums::NetworkTokenStatus::Active),
+                network_token_data,
+            }
+        }
+        NetworkTokenEventType::TokenSuspended => {
+            storage::PaymentMethodUpdate::NetworkTokenStatusUpdate {
+                network_token_status: Some(api_enums::NetworkTokenStatus::Suspended),
+            }
+        }
+        NetworkTokenEventType::TokenResumed => {
+            storage::PaymentMethodUpdate::NetworkTokenStatusUpdate {
+                network_token_status: Some(api_enums::NetworkTokenStatus::Active),
+            }
+        }
+        NetworkTokenEventType::TokenDeleted => {
+            storage::PaymentMethodUpdate::NetworkTokenStatusUpdate {
+                network_token_status: Some(api_enums::NetworkTokenStatus::Deactivated),
+            }
+        }
+    };
+
+    db.update_payment_method(
+        payment_method,
+        payment_method_update,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update network token details of payment method")?;
+
+    Ok(services::ApplicationResponse::StatusOk)
+}
+
+async fn decrypt_network_token_details(
+    key_store: &domain::MerchantKeyStore,
+    payment_method: &storage::PaymentMethod,
+) -> CustomResult<NetworkTokenDetails, errors::NetworkTokenizationError> {
+    super::cards::decrypt_generic_data::<NetworkTokenDetails>(
+        payment_method.network_token_data.clone(),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .change_context(errors::NetworkTokenizationError::FetchNetworkTokenDataFailed)?
+    .ok_or(report!(
+        errors::NetworkTokenizationError::FetchNetworkTokenDataFailed
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::db::MockDb;
+
+    #[tokio::test]
+    async fn test_add_network_tokenization_task() {
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+        let db: &dyn StorageInterface = &mockdb;
+        let schedule_time = time::macros::datetime!(2024-07-10 12:00);
+
+        add_network_tokenization_task(db, "merchant_123", "pm_123", schedule_time)
+            .await
+            .unwrap();
+
+        let process = db
+            .find_process_by_id(&pt_utils::get_process_tracker_id(
+                storage::ProcessTrackerRunner::NetworkTokenizationWorkflow,
+                NETWORK_TOKENIZATION_TASK,
+                "pm_123",
+                "merchant_123",
+            ))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(process.schedule_time, Some(schedule_time));
+        assert_eq!(
+            process.runner,
+            Some(storage::ProcessTrackerRunner::NetworkTokenizationWorkflow.to_string())
+        );
+        let tracking_data: storage::NetworkTokenizationTrackingData =
+            serde_json::from_value(process.tracking_data).unwrap();
+        assert_eq!(tracking_data.merchant_id, "merchant_123");
+        assert_eq!(tracking_data.payment_method_id, "pm_123");
+    }
+}
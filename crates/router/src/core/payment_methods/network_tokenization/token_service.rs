@@ -0,0 +1,204 @@
+use api_models::enums as api_enums;
+use cards::CardNumber;
+use common_utils::{request::RequestContent, types::MinorUnit};
+use error_stack::ResultExt;
+use masking::{Mask, PeekInterface, Secret};
+use router_env::{instrument, tracing};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    configs::settings,
+    core::errors::{self, CustomResult},
+    headers,
+    routes::SessionState,
+    services,
+    utils::ConnectorResponseExt,
+};
+
+const PROVISION_TOKEN_PATH: &str = "/tokens";
+const CRYPTOGRAM_PATH: &str = "/tokens/cryptograms";
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProvisionTokenRequest {
+    pub card_number: CardNumber,
+    pub card_exp_month: Secret<String>,
+    pub card_exp_year: Secret<String>,
+    pub card_holder_name: Option<Secret<String>>,
+    pub card_network: Option<api_enums::CardNetwork>,
+    pub merchant_id: String,
+    pub payment_method_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProvisionTokenResponse {
+    pub token_reference_id: String,
+    pub token_number: CardNumber,
+    pub token_exp_month: Secret<String>,
+    pub token_exp_year: Secret<String>,
+    pub card_network: Option<api_enums::CardNetwork>,
+    pub status: api_enums::NetworkTokenStatus,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CryptogramRequest {
+    pub token_reference_id: String,
+    pub amount: MinorUnit,
+    pub currency: api_enums::Currency,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CryptogramResponse {
+    pub cryptogram: Secret<String>,
+    pub eci: Option<String>,
+}
+
+/// Lifecycle events sent by the token service when the card networks update a token
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NetworkTokenEventType {
+    TokenUpdated,
+    TokenSuspended,
+    TokenResumed,
+    TokenDeleted,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct NetworkTokenWebhookEvent {
+    pub event_type: NetworkTokenEventType,
+    pub token_reference_id: String,
+    pub token_number: Option<CardNumber>,
+    pub token_exp_month: Option<Secret<String>>,
+    pub token_exp_year: Option<Secret<String>>,
+}
+
+/// Interface to be implemented by token service providers (network token requestors)
+#[async_trait::async_trait]
+pub trait TokenServiceProvider: Send + Sync {
+    /// Provision a network token for the given card
+    async fn provision_token(
+        &self,
+        state: &SessionState,
+        request: &ProvisionTokenRequest,
+    ) -> CustomResult<ProvisionTokenResponse, errors::NetworkTokenizationError>;
+
+    /// Generate a single use cryptogram for a previously provisioned token
+    async fn get_cryptogram(
+        &self,
+        state: &SessionState,
+        request: &CryptogramRequest,
+    ) -> CustomResult<CryptogramResponse, errors::NetworkTokenizationError>;
+
+    /// Delete a previously provisioned token
+    async fn delete_token(
+        &self,
+        state: &SessionState,
+        token_reference_id: &str,
+    ) -> CustomResult<(), errors::NetworkTokenizationError>;
+}
+
+/// Returns the configured token service provider, if network tokenization is enabled
+pub fn get_token_service_provider(
+    config: &settings::NetworkTokenization,
+) -> Option<Box<dyn TokenServiceProvider>> {
+    if !config.enabled {
+        return None;
+    }
+
+    config
+        .token_service
+        .as_ref()
+        .map(|token_service| match token_service {
+            settings::TokenServiceProviderConfig::Http { base_url, api_key } => {
+                Box::new(HttpTokenServiceProvider {
+                    base_url: base_url.clone(),
+                    api_key: api_key.clone(),
+                }) as Box<dyn TokenServiceProvider>
+            }
+        })
+}
+
+/// Token service provider reachable over a JSON HTTP API
+#[derive(Debug, Clone)]
+pub struct HttpTokenServiceProvider {
+    base_url: String,
+    api_key: Secret<String>,
+}
+
+impl HttpTokenServiceProvider {
+    fn build_request(
+        &self,
+        method: services::Method,
+        path: &str,
+        body: Option<RequestContent>,
+    ) -> services::Request {
+        let url = format!("{}{}", self.base_url, path);
+        let mut request = services::Request::new(method, &url);
+        request.add_header(headers::CONTENT_TYPE, "application/json".into());
+        request.add_header(
+            headers::AUTHORIZATION,
+            format!("Bearer {}", self.api_key.peek()).into_masked(),
+        );
+        if let Some(body) = body {
+            request.set_body(body);
+        }
+        request
+    }
+}
+
+#[async_trait::async_trait]
+impl TokenServiceProvider for HttpTokenServiceProvider {
+    #[instrument(skip_all)]
+    async fn provision_token(
+        &self,
+        state: &SessionState,
+        request: &ProvisionTokenRequest,
+    ) -> CustomResult<ProvisionTokenResponse, errors::NetworkTokenizationError> {
+        let request = self.build_request(
+            services::Method::Post,
+            PROVISION_TOKEN_PATH,
+            Some(RequestContent::Json(Box::new(request.clone()))),
+        );
+
+        services::call_connector_api(state, request, "provision_network_token")
+            .await
+            .get_response_inner("ProvisionTokenResponse")
+            .change_context(errors::NetworkTokenizationError::ProvisionTokenFailed)
+    }
+
+    #[instrument(skip_all)]
+    async fn get_cryptogram(
+        &self,
+        state: &SessionState,
+        request: &CryptogramRequest,
+    ) -> CustomResult<CryptogramResponse, errors::NetworkTokenizationError> {
+        let request = self.build_request(
+            services::Method::Post,
+            CRYPTOGRAM_PATH,
+            Some(RequestContent::Json(Box::new(request.clone()))),
+        );
+
+        services::call_connector_api(state, request, "get_network_token_cryptogram")
+            .await
+            .get_response_inner("CryptogramResponse")
+            .change_context(errors::NetworkTokenizationError::FetchCryptogramFailed)
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_token(
+        &self,
+        state: &SessionState,
+        token_reference_id: &str,
+    ) -> CustomResult<(), errors::NetworkTokenizationError> {
+        let request = self.build_request(
+            services::Method::Delete,
+            &format!("{PROVISION_TOKEN_PATH}/{token_reference_id}"),
+            None,
+        );
+
+        services::call_connector_api(state, request, "delete_network_token")
+            .await
+            .get_response()
+            .change_context(errors::NetworkTokenizationError::DeleteTokenFailed)
+            .map(|_| ())
+    }
+}
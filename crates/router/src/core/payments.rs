@@ -8,6 +8,7 @@ pub mod operations;
 #[cfg(feature = "retry")]
 pub mod retry;
 pub mod routing;
+pub mod smart_retry;
 pub mod tokenization;
 pub mod transformers;
 pub mod types;
@@ -96,7 +97,7 @@ use crate::{
 };
 
 #[allow(clippy::too_many_arguments, clippy::type_complexity)]
-#[instrument(skip_all, fields(payment_id, merchant_id))]
+#[instrument(skip_all, fields(payment_id, merchant_id, profile_id))]
 pub async fn payments_operation_core<F, Req, Op, FData>(
     state: &SessionState,
     req_state: ReqState,
@@ -160,6 +161,8 @@ where
         )
         .await?;
 
+    tracing::Span::current().record("profile_id", business_profile.profile_id.as_str());
+
     let (operation, customer) = operation
         .to_domain()?
         .get_or_create_customer_details(
@@ -782,7 +785,7 @@ where
             .flat_map(|c| c.foreign_try_into())
             .collect()
     });
-    let (payment_data, _req, customer, connector_http_status_code, external_latency) =
+    let (mut payment_data, _req, customer, connector_http_status_code, external_latency) =
         payments_operation_core::<_, _, _, _>(
             &state,
             req_state,
@@ -797,6 +800,20 @@ where
         )
         .await?;
 
+    // The unified error message is only translated in the response, the payment attempt holds
+    // the message which is unified across the connectors
+    if let (Some(locale), Some(unified_code), Some(unified_message)) = (
+        header_payload.locale.clone(),
+        payment_data.payment_attempt.unified_code.clone(),
+        payment_data.payment_attempt.unified_message.clone(),
+    ) {
+        if let Some(translation) =
+            helpers::get_unified_translation(&state, unified_code, unified_message, locale).await
+        {
+            payment_data.payment_attempt.unified_message = Some(translation);
+        }
+    }
+
     Res::generate_response(
         payment_data,
         customer,
@@ -944,6 +961,7 @@ impl PaymentRedirectFlow for PaymentRedirectCompleteAuthorize {
                     param: req.param.map(Secret::new),
                     json_payload: Some(req.json_payload.unwrap_or(serde_json::json!({})).into()),
                 }),
+                ..Default::default()
             }),
             ..Default::default()
         };
@@ -1035,7 +1053,7 @@ impl PaymentRedirectFlow for PaymentRedirectCompleteAuthorize {
             // If the status is terminal status, then redirect to merchant return url to provide status
             enums::IntentStatus::Succeeded
             | enums::IntentStatus::Failed
-            | enums::IntentStatus::Cancelled | enums::IntentStatus::RequiresCapture| enums::IntentStatus::Processing=> helpers::get_handle_response_url(
+            | enums::IntentStatus::Cancelled | enums::IntentStatus::Expired | enums::IntentStatus::RequiresCapture| enums::IntentStatus::Processing=> helpers::get_handle_response_url(
                 payment_id,
                 &payment_flow_response.business_profile,
                 payments_response,
@@ -1230,6 +1248,7 @@ impl PaymentRedirectFlow for PaymentAuthenticateCompleteAuthorize {
                             req.json_payload.unwrap_or(serde_json::json!({})).into(),
                         ),
                     }),
+                    ..Default::default()
                 }),
                 ..Default::default()
             };
@@ -2502,6 +2521,7 @@ where
     pub currency: storage_enums::Currency,
     pub setup_mandate: Option<MandateData>,
     pub customer_acceptance: Option<CustomerAcceptance>,
+    pub installments: Option<api_models::payments::Installments>,
     pub address: PaymentAddress,
     pub token: Option<String>,
     pub token_data: Option<storage::PaymentTokenData>,
@@ -2988,6 +3008,152 @@ pub async fn reset_process_sync_task(
     Ok(())
 }
 
+const SCHEDULED_CAPTURE_TASK: &str = "SCHEDULED_CAPTURE";
+
+fn get_scheduled_capture_process_tracker_id(payment_attempt: &storage::PaymentAttempt) -> String {
+    pt_utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::ScheduledCaptureWorkflow,
+        SCHEDULED_CAPTURE_TASK,
+        &payment_attempt.attempt_id,
+        &payment_attempt.merchant_id,
+    )
+}
+
+pub async fn add_scheduled_capture_task(
+    db: &dyn StorageInterface,
+    payment_attempt: &storage::PaymentAttempt,
+    schedule_time: time::PrimitiveDateTime,
+) -> CustomResult<(), errors::StorageError> {
+    let tracking_data = storage::payment_attempt::ScheduledCaptureTrackingData {
+        payment_id: payment_attempt.payment_id.clone(),
+        attempt_id: payment_attempt.attempt_id.clone(),
+        merchant_id: payment_attempt.merchant_id.clone(),
+    };
+    let runner = storage::ProcessTrackerRunner::ScheduledCaptureWorkflow;
+    let tag = ["CAPTURE", "PAYMENT"];
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        get_scheduled_capture_process_tracker_id(payment_attempt),
+        SCHEDULED_CAPTURE_TASK,
+        runner,
+        tag,
+        tracking_data,
+        schedule_time,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+/// Whether the payment attempt has been captured, or its capture is being processed by the
+/// connector, following which the scheduled capture of the payment is no longer required.
+pub fn is_scheduled_capture_superseded(attempt_status: storage::enums::AttemptStatus) -> bool {
+    matches!(
+        attempt_status,
+        storage::enums::AttemptStatus::Charged
+            | storage::enums::AttemptStatus::PartialCharged
+            | storage::enums::AttemptStatus::PartialChargedAndChargeable
+            | storage::enums::AttemptStatus::CaptureInitiated
+    )
+}
+
+/// Revokes the scheduled capture task of the payment attempt, if one is yet to be completed.
+pub async fn revoke_scheduled_capture_task(
+    db: &dyn StorageInterface,
+    payment_attempt: &storage::PaymentAttempt,
+) -> CustomResult<(), errors::StorageError> {
+    let process_tracker_id = get_scheduled_capture_process_tracker_id(payment_attempt);
+    let is_task_pending = db
+        .find_process_by_id(&process_tracker_id)
+        .await?
+        .is_some_and(|process| process.status != storage::enums::ProcessTrackerStatus::Finish);
+
+    if is_task_pending {
+        db.as_scheduler()
+            .process_tracker_update_process_status_by_ids(
+                vec![process_tracker_id],
+                storage::ProcessTrackerUpdate::StatusUpdate {
+                    status: storage::enums::ProcessTrackerStatus::Finish,
+                    business_status: Some(String::from(storage::business_status::REVOKED)),
+                },
+            )
+            .await?;
+    }
+
+    Ok(())
+}
+
+const PAYMENT_INTENT_EXPIRY_TASK: &str = "PAYMENT_INTENT_EXPIRY";
+
+/// Adds a task to move the payment intent to `Expired` status once its session expires, if it
+/// has not been confirmed by then.
+pub async fn add_payment_intent_expiry_task(
+    db: &dyn StorageInterface,
+    payment_intent: &storage::PaymentIntent,
+    schedule_time: time::PrimitiveDateTime,
+) -> CustomResult<(), errors::StorageError> {
+    let tracking_data = storage::PaymentIntentExpiryTrackingData {
+        payment_id: payment_intent.payment_id.clone(),
+        merchant_id: payment_intent.merchant_id.clone(),
+    };
+    let runner = storage::ProcessTrackerRunner::PaymentIntentExpiryWorkflow;
+    let task = PAYMENT_INTENT_EXPIRY_TASK;
+    let tag = ["EXPIRY", "PAYMENT"];
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        pt_utils::get_process_tracker_id(
+            runner,
+            task,
+            &payment_intent.payment_id,
+            &payment_intent.merchant_id,
+        ),
+        task,
+        runner,
+        tag,
+        tracking_data,
+        schedule_time,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+const AUTO_VOID_TASK: &str = "AUTO_VOID";
+
+/// Adds a task to void the authorization of the payment attempt, if it has not been captured
+/// or voided by the schedule time.
+pub async fn add_auto_void_task(
+    db: &dyn StorageInterface,
+    payment_attempt: &storage::PaymentAttempt,
+    schedule_time: time::PrimitiveDateTime,
+) -> CustomResult<(), errors::StorageError> {
+    let tracking_data = storage::payment_attempt::AutoVoidTrackingData {
+        payment_id: payment_attempt.payment_id.clone(),
+        attempt_id: payment_attempt.attempt_id.clone(),
+        merchant_id: payment_attempt.merchant_id.clone(),
+    };
+    let runner = storage::ProcessTrackerRunner::AutoVoidWorkflow;
+    let task = AUTO_VOID_TASK;
+    let tag = ["VOID", "PAYMENT"];
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        pt_utils::get_process_tracker_id(
+            runner,
+            task,
+            &payment_attempt.attempt_id,
+            &payment_attempt.merchant_id,
+        ),
+        task,
+        runner,
+        tag,
+        tracking_data,
+        schedule_time,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
 pub fn update_straight_through_routing<F>(
     payment_data: &mut PaymentData<F>,
     request_straight_through: serde_json::Value,
@@ -4266,3 +4432,124 @@ pub async fn payments_manual_update(
     }
     Ok(services::ApplicationResponse::StatusOk)
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::db::MockDb;
+
+    #[test]
+    fn test_is_scheduled_capture_superseded() {
+        for attempt_status in [
+            storage::enums::AttemptStatus::Charged,
+            storage::enums::AttemptStatus::PartialCharged,
+            storage::enums::AttemptStatus::PartialChargedAndChargeable,
+            storage::enums::AttemptStatus::CaptureInitiated,
+        ] {
+            assert!(is_scheduled_capture_superseded(attempt_status));
+        }
+
+        for attempt_status in [
+            storage::enums::AttemptStatus::Authorized,
+            storage::enums::AttemptStatus::CaptureFailed,
+            storage::enums::AttemptStatus::Failure,
+            storage::enums::AttemptStatus::Pending,
+        ] {
+            assert!(!is_scheduled_capture_superseded(attempt_status));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_revoke_scheduled_capture_task() {
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+        let db: &dyn StorageInterface = &mockdb;
+
+        let payment_attempt = db
+            .insert_payment_attempt(
+                storage::PaymentAttemptNew {
+                    payment_id: "pay_123".to_string(),
+                    merchant_id: "merchant_123".to_string(),
+                    attempt_id: "pay_123_1".to_string(),
+                    ..Default::default()
+                },
+                storage::enums::MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+        let process_tracker_id = get_scheduled_capture_process_tracker_id(&payment_attempt);
+
+        // Revoking a payment attempt without a scheduled capture task is a no-op
+        revoke_scheduled_capture_task(db, &payment_attempt)
+            .await
+            .unwrap();
+        assert!(db
+            .find_process_by_id(&process_tracker_id)
+            .await
+            .unwrap()
+            .is_none());
+
+        add_scheduled_capture_task(db, &payment_attempt, common_utils::date_time::now())
+            .await
+            .unwrap();
+        revoke_scheduled_capture_task(db, &payment_attempt)
+            .await
+            .unwrap();
+
+        let process = db
+            .find_process_by_id(&process_tracker_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(process.status, storage::enums::ProcessTrackerStatus::Finish);
+        assert_eq!(process.business_status, storage::business_status::REVOKED);
+    }
+
+    #[tokio::test]
+    async fn test_add_auto_void_task() {
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+        let db: &dyn StorageInterface = &mockdb;
+        let schedule_time = time::macros::datetime!(2024-07-10 12:00);
+
+        let payment_attempt = db
+            .insert_payment_attempt(
+                storage::PaymentAttemptNew {
+                    payment_id: "pay_123".to_string(),
+                    merchant_id: "merchant_123".to_string(),
+                    attempt_id: "pay_123_1".to_string(),
+                    ..Default::default()
+                },
+                storage::enums::MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+        add_auto_void_task(db, &payment_attempt, schedule_time)
+            .await
+            .unwrap();
+
+        let process = db
+            .find_process_by_id(&pt_utils::get_process_tracker_id(
+                storage::ProcessTrackerRunner::AutoVoidWorkflow,
+                AUTO_VOID_TASK,
+                "pay_123_1",
+                "merchant_123",
+            ))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(process.schedule_time, Some(schedule_time));
+        assert_eq!(
+            process.runner,
+            Some(storage::ProcessTrackerRunner::AutoVoidWorkflow.to_string())
+        );
+        let tracking_data: storage::payment_attempt::AutoVoidTrackingData =
+            serde_json::from_value(process.tracking_data).unwrap();
+        assert_eq!(tracking_data.payment_id, "pay_123");
+        assert_eq!(tracking_data.attempt_id, "pay_123_1");
+    }
+}
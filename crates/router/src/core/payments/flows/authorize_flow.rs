@@ -7,6 +7,7 @@ use crate::{
     core::{
         errors::{ConnectorErrorExt, RouterResult},
         mandate,
+        payment_methods::network_tokenization,
         payments::{
             self, access_token, customers, helpers, tokenization, transformers, PaymentData,
         },
@@ -41,7 +42,7 @@ impl
             types::PaymentsResponseData,
         >,
     > {
-        Box::pin(transformers::construct_payment_router_data::<
+        let mut router_data = Box::pin(transformers::construct_payment_router_data::<
             api::Authorize,
             types::PaymentsAuthorizeData,
         >(
@@ -53,7 +54,19 @@ impl
             customer,
             merchant_connector_account,
         ))
-        .await
+        .await?;
+
+        router_data.request.network_token_data =
+            network_tokenization::get_network_token_data_for_payment(
+                state,
+                connector_id,
+                key_store,
+                self.payment_method_info.as_ref(),
+                &router_data.request,
+            )
+            .await;
+
+        Ok(router_data)
     }
 }
 #[async_trait]
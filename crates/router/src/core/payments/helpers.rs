@@ -1,4 +1,4 @@
-use std::{borrow::Cow, str::FromStr};
+use std::{borrow::Cow, collections::HashSet, str::FromStr};
 
 use api_models::{
     mandates::RecurringDetails,
@@ -29,6 +29,7 @@ use openssl::{
     symm::{decrypt_aead, Cipher},
 };
 use router_env::{instrument, logger, metrics::add_attributes, tracing};
+use storage_impl::redis::cache;
 use uuid::Uuid;
 use x509_parser::parse_x509_certificate;
 
@@ -1455,6 +1456,173 @@ fn validate_options_for_inequality<T: PartialEq>(
     )
 }
 
+pub fn validate_installments(
+    request: &api_models::payments::PaymentsRequest,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let Some(installments) = request.installments else {
+        return Ok(());
+    };
+
+    utils::when(
+        !(consts::MIN_INSTALLMENT_COUNT..=consts::MAX_INSTALLMENT_COUNT)
+            .contains(&installments.count),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "installments.count should be between {} and {}",
+                    consts::MIN_INSTALLMENT_COUNT,
+                    consts::MAX_INSTALLMENT_COUNT
+                ),
+            }))
+        },
+    )?;
+
+    // Installments are only offered on card payments
+    utils::when(
+        request
+            .payment_method
+            .is_some_and(|payment_method| payment_method != api_enums::PaymentMethod::Card),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "installments are only supported for card payments".to_string(),
+            }))
+        },
+    )?;
+
+    utils::when(
+        request.setup_future_usage == Some(api_enums::FutureUsage::OffSession)
+            || request.mandate_data.is_some(),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "installments cannot be used while setting up a mandate".to_string(),
+            }))
+        },
+    )
+}
+
+/// Returns the installment plan of the request, falling back to the plan persisted on the attempt
+/// when the payment is confirmed without one
+pub fn get_installments(
+    request_installments: Option<api_models::payments::Installments>,
+    payment_attempt: &PaymentAttempt,
+) -> RouterResult<Option<api_models::payments::Installments>> {
+    match request_installments {
+        Some(installments) => Ok(Some(installments)),
+        None => payment_attempt
+            .installments
+            .clone()
+            .map(|installments| installments.parse_value("Installments"))
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse installments of the payment attempt"),
+    }
+}
+
+/// Installment plans are only passed on to the connectors which support them, so the payment is
+/// rejected instead of being charged in full by any other connector
+pub fn validate_installments_connector(
+    installments: Option<&api_models::payments::Installments>,
+    connector_name: &str,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    if installments.is_none() {
+        return Ok(());
+    }
+
+    let connector = api_enums::Connector::from_str(connector_name).change_context(
+        errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "connector",
+        },
+    )?;
+
+    utils::when(
+        !matches!(
+            connector,
+            api_enums::Connector::Adyen
+                | api_enums::Connector::Dlocal
+                | api_enums::Connector::Stripe
+        ),
+        || {
+            Err(report!(errors::ApiErrorResponse::NotSupported {
+                message: format!("installments are not supported by {connector_name}"),
+            }))
+        },
+    )
+}
+
+pub fn validate_split_payments(
+    request: &api_models::payments::PaymentsRequest,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let Some(split_payments) = request.split_payments.as_ref() else {
+        return Ok(());
+    };
+
+    utils::when(request.charges.is_some(), || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "split_payments cannot be passed along with charges".to_string(),
+        }))
+    })?;
+
+    utils::when(split_payments.0.is_empty(), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "split_payments should contain at least one split".to_string(),
+        }))
+    })?;
+
+    let mut account_ids = HashSet::new();
+    for split in split_payments.0.iter() {
+        utils::when(!account_ids.insert(split.account_id.as_str()), || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "split_payments contains more than one split for the account {}",
+                    split.account_id
+                ),
+            }))
+        })?;
+
+        utils::when(split.amount <= MinorUnit::new(0), || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "split amount for the account {} should be greater than 0",
+                    split.account_id
+                ),
+            }))
+        })?;
+
+        utils::when(
+            split.fees < MinorUnit::new(0) || split.fees > split.amount,
+            || {
+                Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "split fees for the account {} should be between 0 and the split amount",
+                        split.account_id
+                    ),
+                }))
+            },
+        )?;
+    }
+
+    // The amount can be skipped while updating a payment, in which case the splits are validated
+    // against the amount of the payment while fetching the trackers
+    request.amount.map_or(Ok(()), |amount| {
+        validate_split_payments_amount(Some(split_payments), amount.into())
+    })
+}
+
+pub fn validate_split_payments_amount(
+    split_payments: Option<&common_utils::types::SplitPayments>,
+    amount: MinorUnit,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    utils::when(
+        split_payments.is_some_and(|split_payments| split_payments.total_amount() > amount),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "sum of the split amounts should not be more than the payment amount"
+                    .to_string(),
+            }))
+        },
+    )
+}
+
 pub fn validate_max_amount(
     amount: api_models::payments::Amount,
 ) -> CustomResult<(), errors::ApiErrorResponse> {
@@ -3132,6 +3300,7 @@ mod tests {
             charges: None,
             frm_metadata: None,
             customer_details: None,
+            split_payments: None,
         };
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent).is_ok());
@@ -3192,6 +3361,7 @@ mod tests {
             charges: None,
             frm_metadata: None,
             customer_details: None,
+            split_payments: None,
         };
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent,).is_err())
@@ -3251,6 +3421,7 @@ mod tests {
             charges: None,
             frm_metadata: None,
             customer_details: None,
+            split_payments: None,
         };
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent).is_err())
@@ -3608,6 +3779,7 @@ pub fn get_attempt_type(
             }
         }
         enums::IntentStatus::Cancelled
+        | enums::IntentStatus::Expired
         | enums::IntentStatus::RequiresCapture
         | enums::IntentStatus::PartiallyCaptured
         | enums::IntentStatus::PartiallyCapturedAndCapturable
@@ -3722,6 +3894,7 @@ impl AttemptType {
             charge_id: None,
             client_source: old_payment_attempt.client_source,
             client_version: old_payment_attempt.client_version,
+            installments: old_payment_attempt.installments,
         }
     }
 
@@ -3832,6 +4005,7 @@ pub fn is_manual_retry_allowed(
             | storage_enums::AttemptStatus::Failure => Some(true),
         },
         enums::IntentStatus::Cancelled
+        | enums::IntentStatus::Expired
         | enums::IntentStatus::RequiresCapture
         | enums::IntentStatus::PartiallyCaptured
         | enums::IntentStatus::PartiallyCapturedAndCapturable
@@ -3873,6 +4047,30 @@ mod test {
             super::get_payment_id_from_client_secret(client_secret3).unwrap()
         );
     }
+
+    #[test]
+    fn test_validate_auto_void_after_days() {
+        assert!(super::validate_auto_void_after_days(1).is_ok());
+        assert!(super::validate_auto_void_after_days(7).is_ok());
+        assert!(super::validate_auto_void_after_days(30).is_ok());
+        assert!(super::validate_auto_void_after_days(0).is_err());
+        assert!(super::validate_auto_void_after_days(31).is_err());
+    }
+
+    #[test]
+    fn test_validate_installments_connector() {
+        let installments = api_models::payments::Installments {
+            count: 6,
+            plan_type: super::api_enums::InstallmentPlanType::default(),
+            financing_type: super::api_enums::InstallmentFinancingType::default(),
+        };
+
+        for connector in ["adyen", "dlocal", "stripe"] {
+            assert!(super::validate_installments_connector(Some(&installments), connector).is_ok());
+        }
+        assert!(super::validate_installments_connector(Some(&installments), "checkout").is_err());
+        assert!(super::validate_installments_connector(None, "checkout").is_ok());
+    }
 }
 
 #[instrument(skip_all)]
@@ -4621,6 +4819,21 @@ pub fn validate_session_expiry(session_expiry: u32) -> Result<(), errors::ApiErr
     }
 }
 
+// This function validates the number of days after which uncaptured authorizations are voided
+pub fn validate_auto_void_after_days(
+    auto_void_after_days: u16,
+) -> Result<(), errors::ApiErrorResponse> {
+    if !(consts::MIN_AUTO_VOID_AFTER_DAYS..=consts::MAX_AUTO_VOID_AFTER_DAYS)
+        .contains(&auto_void_after_days)
+    {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "auto_void_after_days should be between 1 to 30.".to_string(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
 // This function validates the intent fulfillment time expiry set by the merchant in the request
 pub fn validate_intent_fulfillment_expiry(
     intent_fulfillment_time: u32,
@@ -4968,3 +5181,76 @@ pub async fn config_skip_saving_wallet_at_connector(
         }
     })
 }
+
+/// Get the translation of the unified error message in the given locale. When there is no
+/// translation for a locale with a region, the translation in its primary language is used.
+pub async fn get_unified_translation(
+    state: &SessionState,
+    unified_code: String,
+    unified_message: String,
+    locale: String,
+) -> Option<String> {
+    // Locales are stored in lowercase
+    let locale = locale.to_lowercase();
+    let primary_language = locale
+        .split_once('-')
+        .map(|(primary_language, _)| primary_language.to_owned());
+
+    for locale in std::iter::once(locale).chain(primary_language) {
+        match find_unified_translation(state, &unified_code, &unified_message, &locale).await {
+            Ok(Some(translation)) => return Some(translation),
+            Ok(None) => {}
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    "Failed to fetch the translation of the unified error message"
+                );
+                return None;
+            }
+        }
+    }
+    None
+}
+
+/// The key of the translation of a unified error message in a locale in the unified translations
+/// cache
+pub fn get_unified_translation_cache_key(
+    unified_code: &str,
+    unified_message: &str,
+    locale: &str,
+) -> String {
+    format!("unified_translation_{locale}_{unified_code}_{unified_message}")
+}
+
+/// Find the translation of a unified error message in a locale. The absence of a translation is
+/// cached as well, since most of the unified error messages are not translated in every locale.
+async fn find_unified_translation(
+    state: &SessionState,
+    unified_code: &str,
+    unified_message: &str,
+    locale: &str,
+) -> CustomResult<Option<String>, errors::StorageError> {
+    let db = &*state.store;
+    let find_translation = || async {
+        match db
+            .find_translation(
+                unified_code.to_owned(),
+                unified_message.to_owned(),
+                locale.to_owned(),
+            )
+            .await
+        {
+            Ok(translation) => Ok(Some(translation.translation)),
+            Err(error) if error.current_context().is_db_not_found() => Ok(None),
+            Err(error) => Err(error),
+        }
+    };
+
+    cache::get_or_populate_in_memory(
+        db.get_cache_store().as_ref(),
+        &get_unified_translation_cache_key(unified_code, unified_message, locale),
+        find_translation,
+        &cache::UNIFIED_TRANSLATIONS_CACHE,
+    )
+    .await
+}
@@ -63,7 +63,11 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsCaptureRequest>
 
         helpers::validate_payment_status_against_not_allowed_statuses(
             &payment_intent.status,
-            &[IntentStatus::Failed, IntentStatus::Succeeded],
+            &[
+                IntentStatus::Failed,
+                IntentStatus::Succeeded,
+                IntentStatus::Expired,
+            ],
             "approve",
         )?;
 
@@ -148,6 +152,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsCaptureRequest>
             mandate_connector: None,
             setup_mandate: None,
             customer_acceptance: None,
+            installments: None,
             token: None,
             token_data: None,
             address: PaymentAddress::new(
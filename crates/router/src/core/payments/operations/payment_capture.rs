@@ -208,6 +208,7 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
             mandate_connector: None,
             setup_mandate: None,
             customer_acceptance: None,
+            installments: None,
             token: None,
             token_data: None,
             address: core_types::PaymentAddress::new(
@@ -300,6 +301,7 @@ impl<F: Clone> UpdateTracker<F, payments::PaymentData<F>, api::PaymentsCaptureRe
         } else {
             payment_data.payment_attempt
         };
+
         let capture_amount = payment_data.payment_attempt.amount_to_capture;
         let multiple_capture_count = payment_data.payment_attempt.multiple_capture_count;
         req_state
@@ -71,6 +71,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Co
             &[
                 storage_enums::IntentStatus::Failed,
                 storage_enums::IntentStatus::Succeeded,
+                storage_enums::IntentStatus::Expired,
             ],
             "confirm",
         )?;
@@ -272,6 +273,8 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Co
                 id: profile_id.to_string(),
             })?;
 
+        let installments = helpers::get_installments(request.installments, &payment_attempt)?;
+
         let payment_data = PaymentData {
             flow: PhantomData,
             payment_intent,
@@ -283,6 +286,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Co
             mandate_connector,
             setup_mandate,
             customer_acceptance,
+            installments,
             token,
             token_data,
             address: PaymentAddress::new(
@@ -100,6 +100,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 &payment_intent.status,
                 &[
                     storage_enums::IntentStatus::Cancelled,
+                    storage_enums::IntentStatus::Expired,
                     storage_enums::IntentStatus::Succeeded,
                     storage_enums::IntentStatus::Processing,
                     storage_enums::IntentStatus::RequiresCapture,
@@ -112,6 +113,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 &payment_intent.status,
                 &[
                     storage_enums::IntentStatus::Cancelled,
+                    storage_enums::IntentStatus::Expired,
                     storage_enums::IntentStatus::Succeeded,
                     storage_enums::IntentStatus::Processing,
                     storage_enums::IntentStatus::RequiresCapture,
@@ -601,6 +603,8 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             .as_ref()
             .map(|payment_method_billing| payment_method_billing.address_id.clone());
 
+        let installments = helpers::get_installments(request.installments, &payment_attempt)?;
+
         let payment_data = PaymentData {
             flow: PhantomData,
             payment_intent,
@@ -612,6 +616,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             mandate_connector,
             setup_mandate,
             customer_acceptance,
+            installments,
             token,
             address: PaymentAddress::new(
                 shipping_address.as_ref().map(From::from),
@@ -1128,6 +1133,13 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
             .client_version
             .clone()
             .or(payment_data.payment_attempt.client_version.clone());
+        let installments = payment_data
+            .installments
+            .as_ref()
+            .map(Encode::encode_to_value)
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encode installments")?;
 
         let m_payment_data_payment_attempt = payment_data.payment_attempt.clone();
         let m_payment_method_id =
@@ -1206,6 +1218,7 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
                         payment_method_id: m_payment_method_id,
                         client_source,
                         client_version,
+                        installments,
                     },
                     storage_scheme,
                 )
@@ -1227,6 +1240,7 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
         let m_order_details = order_details.clone();
         let m_metadata = metadata.clone();
         let m_frm_metadata = frm_metadata.clone();
+        let m_split_payments = payment_data.payment_intent.split_payments.clone();
         let m_db = state.clone().store;
         let m_storage_scheme = storage_scheme.to_string();
         let session_expiry = m_payment_data_payment_intent.session_expiry;
@@ -1258,6 +1272,7 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
                         session_expiry,
                         request_external_three_ds_authentication: None,
                         frm_metadata: m_frm_metadata,
+                        split_payments: m_split_payments,
                         customer_details,
                     },
                     &m_key_store,
@@ -1350,6 +1365,7 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest> for PaymentConfir
             })?;
 
         helpers::validate_payment_method_fields_present(request)?;
+        helpers::validate_installments(request)?;
 
         let _mandate_type =
             helpers::validate_mandate(request, payments::is_operation_confirm(self))?;
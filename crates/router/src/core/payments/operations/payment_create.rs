@@ -17,7 +17,7 @@ use hyperswitch_domain_models::{
 };
 use masking::{ExposeInterface, PeekInterface, Secret};
 use router_derive::PaymentOperation;
-use router_env::{instrument, logger, tracing};
+use router_env::{instrument, logger, metrics::add_attributes, tracing};
 use time::PrimitiveDateTime;
 
 use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, ValidateRequest};
@@ -32,7 +32,7 @@ use crate::{
         utils as core_utils,
     },
     db::StorageInterface,
-    routes::{app::ReqState, SessionState},
+    routes::{app::ReqState, metrics, SessionState},
     services,
     types::{
         api::{self, PaymentIdTypeExt},
@@ -304,6 +304,25 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             .to_duplicate_response(errors::ApiErrorResponse::DuplicatePayment {
                 payment_id: payment_id.clone(),
             })?;
+
+        // Payment intents which are not confirmed before their session expires are moved to
+        // `Expired` status by the scheduler
+        if let Some(session_expiry) = payment_intent.session_expiry {
+            match payments::add_payment_intent_expiry_task(
+                &*state.store,
+                &payment_intent,
+                session_expiry,
+            )
+            .await
+            {
+                Ok(()) => metrics::TASKS_ADDED_COUNT.add(
+                    &metrics::CONTEXT,
+                    1,
+                    &add_attributes([("flow", "PaymentIntentExpiry")]),
+                ),
+                Err(error) => logger::error!(?error, "Failed to add payment intent expiry task"),
+            }
+        }
         let mandate_details_present = payment_attempt.mandate_details.is_some();
 
         helpers::validate_mandate_data_and_future_usage(
@@ -422,6 +441,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             mandate_connector,
             setup_mandate,
             customer_acceptance,
+            installments: request.installments,
             token,
             address: PaymentAddress::new(
                 shipping_address.as_ref().map(From::from),
@@ -724,6 +744,8 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest> for PaymentCreate
         )?;
 
         helpers::validate_payment_method_fields_present(request)?;
+        helpers::validate_installments(request)?;
+        helpers::validate_split_payments(request)?;
 
         let mandate_type =
             helpers::validate_mandate(request, payments::is_operation_confirm(self))?;
@@ -871,6 +893,15 @@ impl PaymentCreate {
             .transpose()
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Failed to encode additional pm data")?;
+
+        let installments = request
+            .installments
+            .as_ref()
+            .map(Encode::encode_to_value)
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encode installments")?;
+
         let attempt_id = if core_utils::is_merchant_enabled_for_payment_id_as_connector_request_id(
             &state.conf,
             merchant_id,
@@ -967,6 +998,7 @@ impl PaymentCreate {
                 charge_id: None,
                 client_source: None,
                 client_version: None,
+                installments,
             },
             additional_pm_data,
         ))
@@ -1116,6 +1148,7 @@ impl PaymentCreate {
             charges,
             frm_metadata: request.frm_metadata.clone(),
             customer_details,
+            split_payments: request.split_payments.clone(),
         })
     }
 
@@ -60,6 +60,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, PaymentsCancelRequest> for P
             &payment_intent.status,
             &[
                 enums::IntentStatus::Cancelled,
+                enums::IntentStatus::Expired,
                 enums::IntentStatus::Failed,
                 enums::IntentStatus::Succeeded,
                 enums::IntentStatus::Processing,
@@ -145,6 +146,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, PaymentsCancelRequest> for P
             mandate_connector: None,
             setup_mandate: None,
             customer_acceptance: None,
+            installments: None,
             token: None,
             address: PaymentAddress::new(
                 shipping_address.as_ref().map(From::from),
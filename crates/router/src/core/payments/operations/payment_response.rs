@@ -7,7 +7,7 @@ use error_stack::{report, ResultExt};
 use futures::FutureExt;
 use hyperswitch_domain_models::payments::payment_attempt::PaymentAttempt;
 use router_derive;
-use router_env::{instrument, logger, tracing};
+use router_env::{instrument, logger, metrics::add_attributes, tracing};
 use storage_impl::DataModelExt;
 use tracing_futures::Instrument;
 
@@ -18,11 +18,12 @@ use crate::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
         mandate, payment_methods,
         payments::{
+            self,
             helpers::{
                 self as payments_helpers,
                 update_additional_payment_data_with_connector_response_pm_data,
             },
-            tokenization,
+            smart_retry, tokenization,
             types::MultipleCaptureData,
             PaymentData,
         },
@@ -500,6 +501,21 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsCaptureData>
         ))
         .await?;
 
+        // A successful capture of the payment supersedes its scheduled capture, while a failed
+        // capture leaves the task in place so that the payment is still captured as scheduled.
+        // When the capture is being performed by the scheduled capture task itself, the task is
+        // rescheduled or finished by the workflow based on the outcome of the capture.
+        if payment_data.payment_attempt.capture_on.is_some()
+            && payments::is_scheduled_capture_superseded(payment_data.payment_attempt.status)
+        {
+            if let Err(error) =
+                payments::revoke_scheduled_capture_task(&*db.store, &payment_data.payment_attempt)
+                    .await
+            {
+                logger::error!(?error, "Failed to revoke scheduled capture task");
+            }
+        }
+
         Ok(payment_data)
     }
 }
@@ -528,6 +544,19 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsCancelData> f
         ))
         .await?;
 
+        // The scheduled capture task is only revoked once the payment is voided, so that the
+        // payment is still captured as scheduled when the void fails or is declined
+        if payment_data.payment_attempt.capture_on.is_some()
+            && payment_data.payment_attempt.status == enums::AttemptStatus::Voided
+        {
+            if let Err(error) =
+                payments::revoke_scheduled_capture_task(&*db.store, &payment_data.payment_attempt)
+                    .await
+            {
+                logger::error!(?error, "Failed to revoke scheduled capture task");
+            }
+        }
+
         Ok(payment_data)
     }
 }
@@ -817,6 +846,19 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                     )
                     .await;
 
+                    if let Some((gsm, payment_method)) = option_gsm
+                        .as_ref()
+                        .zip(payment_data.payment_method_info.as_ref())
+                    {
+                        payment_methods::card_account_updater::request_account_update_for_declined_card(
+                            state,
+                            payment_method,
+                            gsm,
+                            storage_scheme,
+                        )
+                        .await;
+                    }
+
                     let status = match err.attempt_status {
                         // Use the status sent by connector in error_response if it's present
                         Some(status) => status,
@@ -1302,6 +1344,51 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
     )?;
 
     payment_data.payment_intent = payment_intent;
+
+    // Manually captured payments which specify `capture_on` are captured by the scheduler once
+    // they have been authorized. The task may already exist if the payment was synced after
+    // authorization, or if the scheduled capture was cancelled.
+    if let Some(capture_on) = payment_data.payment_attempt.capture_on.filter(|_| {
+        payment_data.payment_intent.status == enums::IntentStatus::RequiresCapture
+            && payment_data.payment_attempt.capture_method == Some(enums::CaptureMethod::Manual)
+    }) {
+        let schedule_time = capture_on.max(common_utils::date_time::now());
+        match payments::add_scheduled_capture_task(
+            &*state.store,
+            &payment_data.payment_attempt,
+            schedule_time,
+        )
+        .await
+        {
+            Ok(()) => metrics::TASKS_ADDED_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &add_attributes([("flow", "ScheduledCapture")]),
+            ),
+            Err(error) if error.current_context().is_db_unique_violation() => {}
+            Err(error) => logger::error!(?error, "Failed to add scheduled capture task"),
+        }
+    }
+
+    // Authorizations which are not captured within the number of days configured in the
+    // business profile are voided by the scheduler, before the hold is released by the issuer
+    if payment_data.payment_intent.status == enums::IntentStatus::RequiresCapture {
+        if let Err(error) = add_auto_void_task_if_enabled(state, &payment_data).await {
+            logger::error!(?error, "Failed to add auto void task");
+        }
+    }
+
+    // Off-session payments which are declined with an error mapped to the `retry_later` GSM
+    // decision are retried later by the scheduler
+    if payment_data.payment_attempt.status == enums::AttemptStatus::Failure
+        && payment_data.payment_intent.off_session == Some(true)
+        && state.conf.smart_retry.enabled
+    {
+        if let Err(error) = add_smart_retry_task_if_applicable(state, &payment_data).await {
+            logger::error!(?error, "Failed to add smart retry task");
+        }
+    }
+
     router_data.payment_method_status.and_then(|status| {
         payment_data
             .payment_method_info
@@ -1311,6 +1398,115 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
     Ok(payment_data)
 }
 
+async fn add_auto_void_task_if_enabled<F: Clone>(
+    state: &SessionState,
+    payment_data: &PaymentData<F>,
+) -> CustomResult<(), errors::StorageError> {
+    let Some(profile_id) = payment_data.payment_intent.profile_id.as_ref() else {
+        return Ok(());
+    };
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(profile_id)
+        .await?;
+    let Some(auto_void_after_days) = business_profile.auto_void_after_days else {
+        return Ok(());
+    };
+
+    let schedule_time = payment_data
+        .payment_attempt
+        .created_at
+        .saturating_add(time::Duration::days(i64::from(auto_void_after_days)))
+        .max(common_utils::date_time::now());
+    match payments::add_auto_void_task(&*state.store, &payment_data.payment_attempt, schedule_time)
+        .await
+    {
+        Ok(()) => {
+            metrics::TASKS_ADDED_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &add_attributes([("flow", "AutoVoid")]),
+            );
+            Ok(())
+        }
+        // The task already exists if the payment was synced after authorization
+        Err(error) if error.current_context().is_db_unique_violation() => Ok(()),
+        Err(error) => Err(error),
+    }
+}
+
+async fn add_smart_retry_task_if_applicable<F: Clone>(
+    state: &SessionState,
+    payment_data: &PaymentData<F>,
+) -> CustomResult<(), errors::StorageError> {
+    let payment_attempt = &payment_data.payment_attempt;
+    let feature_metadata = smart_retry::get_feature_metadata(&payment_data.payment_intent)?;
+    if feature_metadata
+        .as_ref()
+        .and_then(|feature_metadata| feature_metadata.smart_retry_enabled)
+        == Some(false)
+    {
+        return Ok(());
+    }
+
+    let Some(connector) = payment_attempt.connector.clone() else {
+        return Ok(());
+    };
+    let Some(retry_schedule) = payments_helpers::get_gsm_record(
+        state,
+        payment_attempt.error_code.clone(),
+        payment_attempt.error_message.clone(),
+        connector,
+        "Authorize".to_string(),
+    )
+    .await
+    .filter(|gsm| gsm.decision == api_models::gsm::GsmDecision::RetryLater.to_string())
+    .and_then(|gsm| gsm.retry_schedule) else {
+        return Ok(());
+    };
+
+    let (original_payment_id, retry_count) =
+        match feature_metadata.and_then(|feature_metadata| feature_metadata.smart_retry_details) {
+            Some(details) => (
+                details.original_payment_id,
+                details.retry_count.saturating_add(1),
+            ),
+            None => (payment_attempt.payment_id.clone(), 1),
+        };
+    let Some(schedule_time) = smart_retry::get_retry_schedule_time(
+        &retry_schedule,
+        retry_count,
+        common_utils::date_time::now(),
+    ) else {
+        logger::info!(
+            payment_id = %payment_attempt.payment_id,
+            %original_payment_id,
+            "Smart retries of the payment have been exhausted"
+        );
+        return Ok(());
+    };
+
+    let tracking_data = storage::payment_attempt::SmartRetryTrackingData {
+        payment_id: payment_attempt.payment_id.clone(),
+        merchant_id: payment_attempt.merchant_id.clone(),
+        original_payment_id,
+        retry_count,
+    };
+    match smart_retry::add_smart_retry_task(&*state.store, tracking_data, schedule_time).await {
+        Ok(()) => {
+            metrics::TASKS_ADDED_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &add_attributes([("flow", "SmartRetry")]),
+            );
+            Ok(())
+        }
+        // The task already exists if the declined payment was synced
+        Err(error) if error.current_context().is_db_unique_violation() => Ok(()),
+        Err(error) => Err(error),
+    }
+}
+
 async fn update_payment_method_status_and_ntid<F: Clone>(
     state: &SessionState,
     payment_data: &mut PaymentData<F>,
@@ -66,6 +66,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsSessionRequest>
             &[
                 storage_enums::IntentStatus::Failed,
                 storage_enums::IntentStatus::Succeeded,
+                storage_enums::IntentStatus::Expired,
             ],
             "create a session token for",
         )?;
@@ -171,6 +172,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsSessionRequest>
             mandate_id: None,
             mandate_connector: None,
             customer_acceptance: None,
+            installments: None,
             token: None,
             token_data: None,
             setup_mandate: None,
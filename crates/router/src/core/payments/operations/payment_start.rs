@@ -64,6 +64,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsStartRequest> f
             &[
                 storage_enums::IntentStatus::Failed,
                 storage_enums::IntentStatus::Succeeded,
+                storage_enums::IntentStatus::Expired,
             ],
             "update",
         )?;
@@ -156,6 +157,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsStartRequest> f
             mandate_connector: None,
             setup_mandate: None,
             customer_acceptance: None,
+            installments: None,
             token: payment_attempt.payment_token.clone(),
             address: PaymentAddress::new(
                 shipping_address.as_ref().map(From::from),
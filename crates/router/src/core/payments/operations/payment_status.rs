@@ -440,6 +440,7 @@ async fn get_tracker_for_sync<
         mandate_connector: None,
         setup_mandate: None,
         customer_acceptance: None,
+        installments: None,
         token: None,
         address: PaymentAddress::new(
             shipping_address.as_ref().map(From::from),
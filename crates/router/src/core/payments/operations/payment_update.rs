@@ -97,6 +97,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 storage_enums::IntentStatus::Succeeded,
                 storage_enums::IntentStatus::PartiallyCaptured,
                 storage_enums::IntentStatus::RequiresCapture,
+                storage_enums::IntentStatus::Expired,
             ],
             "update",
         )?;
@@ -260,6 +261,14 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             .or(payment_intent.feature_metadata);
         payment_intent.metadata = request.metadata.clone().or(payment_intent.metadata);
         payment_intent.frm_metadata = request.frm_metadata.clone().or(payment_intent.frm_metadata);
+        payment_intent.split_payments = request
+            .split_payments
+            .clone()
+            .or(payment_intent.split_payments);
+        helpers::validate_split_payments_amount(
+            payment_intent.split_payments.as_ref(),
+            amount.into(),
+        )?;
         Self::populate_payment_intent_with_request(&mut payment_intent, request);
 
         let token = token.or_else(|| payment_attempt.payment_token.clone());
@@ -417,6 +426,8 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             payments::types::SurchargeDetails::from((&request_surcharge_details, &payment_attempt))
         });
 
+        let installments = helpers::get_installments(request.installments, &payment_attempt)?;
+
         let payment_data = PaymentData {
             flow: PhantomData,
             payment_intent,
@@ -430,6 +441,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             token_data,
             setup_mandate,
             customer_acceptance,
+            installments,
             address: PaymentAddress::new(
                 shipping_address.as_ref().map(From::from),
                 billing_address.as_ref().map(From::from),
@@ -639,6 +651,13 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
             .surcharge_details
             .as_ref()
             .map(|surcharge_details| surcharge_details.tax_on_surcharge_amount);
+        let installments = payment_data
+            .installments
+            .as_ref()
+            .map(Encode::encode_to_value)
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encode installments")?;
         payment_data.payment_attempt = state
             .store
             .update_payment_attempt_with_attempt_id(
@@ -661,6 +680,7 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
                     fingerprint_id: None,
                     payment_method_billing_address_id,
                     updated_by: storage_scheme.to_string(),
+                    installments,
                 },
                 storage_scheme,
             )
@@ -705,6 +725,7 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
         let order_details = payment_data.payment_intent.order_details.clone();
         let metadata = payment_data.payment_intent.metadata.clone();
         let frm_metadata = payment_data.payment_intent.frm_metadata.clone();
+        let split_payments = payment_data.payment_intent.split_payments.clone();
         let session_expiry = payment_data.payment_intent.session_expiry;
         payment_data.payment_intent = state
             .store
@@ -734,6 +755,7 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
                         .payment_intent
                         .request_external_three_ds_authentication,
                     frm_metadata,
+                    split_payments,
                     customer_details,
                 },
                 key_store,
@@ -801,6 +823,8 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest> for PaymentUpdate
         })?;
 
         helpers::validate_payment_method_fields_present(request)?;
+        helpers::validate_split_payments(request)?;
+        helpers::validate_installments(request)?;
 
         let _mandate_type = helpers::validate_mandate(request, false)?;
 
@@ -126,6 +126,7 @@ impl<F: Send + Clone>
             mandate_connector: None,
             setup_mandate: None,
             customer_acceptance: None,
+            installments: None,
             token: None,
             token_data: None,
             address: PaymentAddress::new(None, None, None, None),
@@ -155,7 +155,10 @@ where
                         ),
                     }))?
                 }
-                api_models::gsm::GsmDecision::DoDefault => break,
+                // Payments declined with the `RetryLater` decision are retried later by the
+                // scheduler, once the attempt has been marked as failed
+                api_models::gsm::GsmDecision::RetryLater
+                | api_models::gsm::GsmDecision::DoDefault => break,
             }
             initial_gsm = None;
         }
@@ -524,6 +527,7 @@ pub fn make_new_payment_attempt(
         payment_token: old_payment_attempt.payment_token,
         client_source: old_payment_attempt.client_source,
         client_version: old_payment_attempt.client_version,
+        installments: old_payment_attempt.installments,
         created_at,
         modified_at,
         last_synced,
@@ -136,6 +136,7 @@ pub fn make_dsl_input_for_payouts(
             .map(api_enums::Country::from_alpha2),
         business_label: payout_data.payout_attempt.business_label.clone(),
         setup_future_usage: None,
+        installment_count: None,
     };
     let payment_method = dsl_inputs::PaymentMethodInput {
         payment_method: payout_data
@@ -153,6 +154,7 @@ pub fn make_dsl_input_for_payouts(
         metadata,
         payment,
         payment_method,
+        velocity: dsl_inputs::VelocityInput::default(),
     })
 }
 
@@ -239,6 +241,9 @@ where
             .map(api_enums::Country::from_alpha2),
         business_label: payment_data.payment_intent.business_label.clone(),
         setup_future_usage: payment_data.payment_intent.setup_future_usage,
+        installment_count: payment_data
+            .installments
+            .map(|installments| installments.count),
     };
 
     let metadata = payment_data
@@ -259,6 +264,7 @@ where
         payment: payment_input,
         payment_method: payment_method_input,
         mandate: mandate_data,
+        velocity: dsl_inputs::VelocityInput::default(),
     })
 }
 
@@ -927,6 +933,7 @@ pub async fn perform_session_flow_routing(
             .map(storage_enums::Country::from_alpha2),
         business_label: session_input.payment_intent.business_label.clone(),
         setup_future_usage: session_input.payment_intent.setup_future_usage,
+        installment_count: None,
     };
 
     let metadata = session_input
@@ -951,6 +958,7 @@ pub async fn perform_session_flow_routing(
             mandate_type: None,
             payment_type: None,
         },
+        velocity: dsl_inputs::VelocityInput::default(),
     };
 
     for connector_data in session_input.chosen.iter() {
@@ -1158,6 +1166,7 @@ pub fn make_dsl_input_for_surcharge(
             .map(api_enums::Country::from_alpha2),
         business_label: payment_intent.business_label.clone(),
         setup_future_usage: payment_intent.setup_future_usage,
+        installment_count: None,
     };
     let metadata = payment_intent
         .metadata
@@ -1180,6 +1189,7 @@ pub fn make_dsl_input_for_surcharge(
         payment: payment_input,
         payment_method: payment_method_input,
         mandate: mandate_data,
+        velocity: dsl_inputs::VelocityInput::default(),
     };
     Ok(backend_input)
 }
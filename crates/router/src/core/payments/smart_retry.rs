@@ -0,0 +1,229 @@
+//! Deferred retries of soft declined off-session payments.
+//!
+//! Off-session payments declined with an error which is mapped to the `retry_later` GSM decision
+//! are retried by the scheduler, on the retry schedule configured in the GSM record. Each retry
+//! creates a new payment carrying the details of the retry in its feature metadata, and the
+//! retries made for a card are restricted to the limits imposed by its card network.
+
+use api_models::payments::{AdditionalPaymentData, FeatureMetadata};
+use common_utils::{errors::CustomResult, ext_traits::ValueExt, types::RetrySchedule};
+use error_stack::ResultExt;
+use scheduler::utils as pt_utils;
+
+use crate::{
+    configs::settings::NetworkRetryLimit,
+    core::errors,
+    db::StorageInterface,
+    routes::SessionState,
+    types::storage::{self, payment_attempt::SmartRetryTrackingData},
+};
+
+const SMART_RETRY_TASK: &str = "SMART_RETRY";
+
+pub async fn add_smart_retry_task(
+    db: &dyn StorageInterface,
+    tracking_data: SmartRetryTrackingData,
+    schedule_time: time::PrimitiveDateTime,
+) -> CustomResult<(), errors::StorageError> {
+    let runner = storage::ProcessTrackerRunner::SmartRetryWorkflow;
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        SMART_RETRY_TASK,
+        &tracking_data.payment_id,
+        &tracking_data.merchant_id,
+    );
+    let tag = ["SMART_RETRY", "PAYMENT"];
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        SMART_RETRY_TASK,
+        runner,
+        tag,
+        tracking_data,
+        schedule_time,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+pub fn get_feature_metadata(
+    payment_intent: &storage::PaymentIntent,
+) -> CustomResult<Option<FeatureMetadata>, errors::StorageError> {
+    payment_intent
+        .feature_metadata
+        .clone()
+        .map(|feature_metadata| feature_metadata.parse_value("FeatureMetadata"))
+        .transpose()
+        .change_context(errors::StorageError::DeserializationFailed)
+}
+
+/// Get the time at which the retry with the given number, starting from 1, is to be made as per
+/// the retry schedule. Returns `None` once the retries in the schedule have been exhausted.
+pub fn get_retry_schedule_time(
+    retry_schedule: &RetrySchedule,
+    retry_count: u16,
+    declined_at: time::PrimitiveDateTime,
+) -> Option<time::PrimitiveDateTime> {
+    match retry_schedule {
+        RetrySchedule::Intervals { intervals_in_hours } => {
+            let interval = intervals_in_hours.get(usize::from(retry_count.checked_sub(1)?))?;
+            Some(declined_at.saturating_add(time::Duration::hours(i64::from(*interval))))
+        }
+        RetrySchedule::DaysOfMonth { days, max_retries } => {
+            if retry_count == 0 || retry_count > u16::from(*max_retries) {
+                return None;
+            }
+
+            // Any day of the month occurs within the next two months
+            std::iter::successors(declined_at.date().next_day(), |date| date.next_day())
+                .take(62)
+                .find(|date| {
+                    let last_day = time::util::days_in_year_month(date.year(), date.month());
+                    days.iter().any(|day| (*day).min(last_day) == date.day())
+                })
+                .map(|date| time::PrimitiveDateTime::new(date, declined_at.time()))
+        }
+    }
+}
+
+/// The identifier used for tracking the retries made for the card used in the payment attempt.
+/// The card fingerprint is preferred, since a card may be saved more than once.
+pub fn get_card_identifier(payment_attempt: &storage::PaymentAttempt) -> Option<&str> {
+    payment_attempt
+        .fingerprint_id
+        .as_deref()
+        .or(payment_attempt.payment_method_id.as_deref())
+}
+
+/// The retry limit imposed by the network of the card used in the payment attempt
+pub fn get_network_retry_limit(
+    state: &SessionState,
+    payment_attempt: &storage::PaymentAttempt,
+) -> NetworkRetryLimit {
+    let card_network = payment_attempt
+        .payment_method_data
+        .clone()
+        .and_then(|payment_method_data| {
+            payment_method_data
+                .parse_value::<AdditionalPaymentData>("AdditionalPaymentData")
+                .ok()
+        })
+        .and_then(|payment_method_data| match payment_method_data {
+            AdditionalPaymentData::Card(card_info) => card_info.card_network,
+            _ => None,
+        });
+
+    card_network
+        .and_then(|card_network| {
+            state
+                .conf
+                .smart_retry
+                .network_retry_limits
+                .get(&card_network.to_string().to_lowercase())
+        })
+        .copied()
+        .unwrap_or(state.conf.smart_retry.default_retry_limit)
+}
+
+/// Get the number of retries made for the card within the window of the retry limit
+pub async fn get_card_retry_count(
+    state: &SessionState,
+    merchant_id: &str,
+    card_identifier: &str,
+    retry_limit: NetworkRetryLimit,
+) -> CustomResult<usize, errors::RedisError> {
+    let redis_conn = state.store.get_redis_conn()?;
+    let key = get_card_retries_key(merchant_id, card_identifier);
+    let window_start =
+        common_utils::date_time::now_unix_timestamp() - get_window_in_secs(retry_limit);
+
+    redis_conn
+        .zremrangebyscore(&key, 0.0, to_score(window_start))
+        .await?;
+    redis_conn.zcard(&key).await
+}
+
+/// Record a retry made for the card, identified by the payment created for the retry
+pub async fn record_card_retry(
+    state: &SessionState,
+    merchant_id: &str,
+    card_identifier: &str,
+    payment_id: &str,
+    retry_limit: NetworkRetryLimit,
+) -> CustomResult<(), errors::RedisError> {
+    let redis_conn = state.store.get_redis_conn()?;
+    let key = get_card_retries_key(merchant_id, card_identifier);
+
+    redis_conn
+        .zadd(
+            &key,
+            to_score(common_utils::date_time::now_unix_timestamp()),
+            payment_id,
+        )
+        .await?;
+    redis_conn
+        .set_expiry(&key, get_window_in_secs(retry_limit))
+        .await
+}
+
+fn get_card_retries_key(merchant_id: &str, card_identifier: &str) -> String {
+    format!("smart_retry_{merchant_id}_{card_identifier}")
+}
+
+fn get_window_in_secs(retry_limit: NetworkRetryLimit) -> i64 {
+    i64::from(retry_limit.window_in_days) * 24 * 60 * 60
+}
+
+// Unix timestamps fit well within the range of integers represented exactly by an f64
+#[allow(clippy::as_conversions)]
+fn to_score(unix_timestamp: i64) -> f64 {
+    unix_timestamp as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use common_utils::types::RetrySchedule;
+    use time::macros::datetime;
+
+    use super::get_retry_schedule_time;
+
+    #[test]
+    fn test_retry_schedule_time_for_intervals() {
+        let schedule = RetrySchedule::Intervals {
+            intervals_in_hours: vec![24, 72],
+        };
+        let declined_at = datetime!(2024-07-10 10:30);
+
+        assert_eq!(
+            get_retry_schedule_time(&schedule, 1, declined_at),
+            Some(datetime!(2024-07-11 10:30))
+        );
+        assert_eq!(
+            get_retry_schedule_time(&schedule, 2, declined_at),
+            Some(datetime!(2024-07-13 10:30))
+        );
+        assert_eq!(get_retry_schedule_time(&schedule, 3, declined_at), None);
+    }
+
+    #[test]
+    fn test_retry_schedule_time_for_days_of_month() {
+        let schedule = RetrySchedule::DaysOfMonth {
+            days: vec![1, 31],
+            max_retries: 2,
+        };
+
+        assert_eq!(
+            get_retry_schedule_time(&schedule, 1, datetime!(2024-02-10 08:00)),
+            Some(datetime!(2024-02-29 08:00))
+        );
+        assert_eq!(
+            get_retry_schedule_time(&schedule, 2, datetime!(2024-02-29 08:00)),
+            Some(datetime!(2024-03-01 08:00))
+        );
+        assert_eq!(
+            get_retry_schedule_time(&schedule, 3, datetime!(2024-03-01 08:00)),
+            None
+        );
+    }
+}
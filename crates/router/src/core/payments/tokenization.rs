@@ -586,7 +586,7 @@ where
                             });
 
                             resp.payment_method_id = generate_id(consts::ID_LENGTH, "pm");
-                            payment_methods::cards::create_payment_method(
+                            let payment_method = payment_methods::cards::create_payment_method(
                                 db,
                                 &payment_method_create_request,
                                 &customer_id,
@@ -604,6 +604,19 @@ where
                                 encrypted_payment_method_billing_address,
                             )
                             .await?;
+
+                            if payment_method_create_request.card.is_some() {
+                                payment_methods::network_tokenization::schedule_network_tokenization(
+                                    state,
+                                    &payment_method,
+                                )
+                                .await;
+                                payment_methods::card_account_updater::schedule_card_account_updater(
+                                    state,
+                                    merchant_id,
+                                )
+                                .await;
+                            }
                         };
                     }
                 }
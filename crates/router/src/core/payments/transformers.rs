@@ -726,6 +726,17 @@ where
             }
         };
 
+        let installments = payment_attempt
+            .installments
+            .clone()
+            .map(|installments| installments.parse_value("Installments"))
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable(format!(
+                "Failed to parse Installments for payment_attempt {}",
+                payment_attempt.attempt_id
+            ))?;
+
         services::ApplicationResponse::JsonWithHeaders((
             response
                 .set_net_amount(payment_attempt.net_amount)
@@ -878,6 +889,8 @@ where
                 .set_browser_info(payment_attempt.browser_info)
                 .set_updated(Some(payment_intent.modified_at))
                 .set_charges(charges_response)
+                .set_split_payments(payment_intent.split_payments)
+                .set_installments(installments)
                 .set_frm_metadata(payment_intent.frm_metadata)
                 .to_owned(),
             headers,
@@ -1010,6 +1023,7 @@ impl ForeignFrom<(storage::PaymentIntent, storage::PaymentAttempt)> for api::Pay
             attempt_count: pi.attempt_count,
             profile_id: pi.profile_id,
             merchant_connector_id: pa.merchant_connector_id,
+            split_payments: pi.split_payments,
             payment_method_data: pa.payment_method_data.and_then(|data| {
                 match data.parse_value("PaymentMethodDataResponseWithBilling") {
                     Ok(parsed_data) => Some(parsed_data),
@@ -1212,6 +1226,12 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
         let router_base_url = &additional_data.router_base_url;
         let connector_name = &additional_data.connector_name;
         let attempt = &payment_data.payment_attempt;
+
+        helpers::validate_installments_connector(
+            payment_data.installments.as_ref(),
+            connector_name,
+        )?;
+
         let browser_info: Option<types::BrowserInformation> = attempt
             .browser_info
             .clone()
@@ -1349,6 +1369,9 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
                 .transpose()?,
             customer_acceptance: payment_data.customer_acceptance,
             charges,
+            installments: payment_data.installments,
+            split_payments: payment_data.payment_intent.split_payments,
+            network_token_data: None,
         })
     }
 }
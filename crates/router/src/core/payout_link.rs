@@ -1,20 +1,21 @@
 use std::collections::{HashMap, HashSet};
 
-use api_models::payouts;
+use api_models::{admin, payouts};
 use common_utils::{
-    ext_traits::{Encode, OptionExt},
+    ext_traits::{Encode, OptionExt, ValueExt},
     link_utils,
     types::{AmountConvertor, StringMajorUnitForConnector},
 };
 use diesel_models::PayoutLinkUpdate;
 use error_stack::ResultExt;
+use masking::PeekInterface;
 
 use super::errors::{RouterResponse, StorageErrorExt};
 use crate::{
-    core::payments::helpers,
+    core::{link_hosting, payments::helpers},
     errors,
     routes::{app::StorageInterface, SessionState},
-    services::{self, GenericLinks},
+    services::{self, GenericLinks, GenericLinksData},
     types::domain,
 };
 
@@ -23,6 +24,7 @@ pub async fn initiate_payout_link(
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
     req: payouts::PayoutLinkInitiateRequest,
+    request_context: link_hosting::LinkRenderRequestContext,
 ) -> RouterResponse<services::GenericLinkFormData> {
     let db: &dyn StorageInterface = &*state.store;
     let merchant_id = &merchant_account.merchant_id;
@@ -58,6 +60,48 @@ pub async fn initiate_payout_link(
             message: "payout link not found".to_string(),
         })?;
 
+    // Fetch rendering customizations of the payout's business profile
+    let business_profile = db
+        .find_business_profile_by_profile_id(&payout.profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: payout.profile_id.clone(),
+        })?;
+    let profile_config = business_profile
+        .payout_link_config
+        .map(|config| {
+            config.parse_value::<admin::BusinessPayoutLinkConfig>("BusinessPayoutLinkConfig")
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "payout_link_config in business_profile",
+        })?;
+
+    let link_host = link_hosting::validate_link_host(
+        &state,
+        &request_context,
+        payout_link.url.peek(),
+        profile_config
+            .as_ref()
+            .and_then(|config| config.config.domain_name.as_deref()),
+    )?;
+    if let link_hosting::LinkHost::Redirect(redirection_response) = link_host {
+        return Ok(services::ApplicationResponse::JsonForRedirection(
+            redirection_response,
+        ));
+    }
+
+    let render_config = services::LinkRenderConfig {
+        locale: request_context.locale.clone(),
+        allowed_domains: profile_config
+            .as_ref()
+            .map(|config| config.config.allowed_domains.clone())
+            .unwrap_or_default(),
+        custom_template: profile_config
+            .and_then(|config| config.config.custom_template)
+            .filter(|_| link_host.is_custom_domain()),
+    };
+
     // Check status and return form data accordingly
     let has_expired = common_utils::date_time::now() > payout_link.expiry;
     let status = payout_link.link_status.clone();
@@ -80,8 +124,14 @@ pub async fn initiate_payout_link(
         // Send back generic expired page
         (true, _) | (_, &link_utils::PayoutLinkStatus::Invalidated) => {
             let expired_link_data = services::GenericExpiredLinkData {
-                title: "Payout Expired".to_string(),
-                message: "This payout link has expired.".to_string(),
+                title: link_hosting::translate(
+                    &request_context.locale,
+                    "payout_link_expired_title",
+                ),
+                message: link_hosting::translate(
+                    &request_context.locale,
+                    "payout_link_expired_message",
+                ),
                 theme: link_data.ui_config.theme.unwrap_or(default_ui_config.theme),
             };
 
@@ -96,7 +146,10 @@ pub async fn initiate_payout_link(
             }
 
             Ok(services::ApplicationResponse::GenericLinkForm(Box::new(
-                GenericLinks::ExpiredLink(expired_link_data),
+                GenericLinks {
+                    render_config,
+                    data: GenericLinksData::ExpiredLink(expired_link_data),
+                },
             )))
         }
 
@@ -191,7 +244,10 @@ pub async fn initiate_payout_link(
                 html_meta_tags: String::new(),
             };
             Ok(services::ApplicationResponse::GenericLinkForm(Box::new(
-                GenericLinks::PayoutLink(generic_form_data),
+                GenericLinks {
+                    render_config,
+                    data: GenericLinksData::PayoutLink(generic_form_data),
+                },
             )))
         }
 
@@ -230,7 +286,10 @@ pub async fn initiate_payout_link(
                 css_data: serialized_css_content,
             };
             Ok(services::ApplicationResponse::GenericLinkForm(Box::new(
-                GenericLinks::PayoutLinkStatus(generic_status_data),
+                GenericLinks {
+                    render_config,
+                    data: GenericLinksData::PayoutLinkStatus(generic_status_data),
+                },
             )))
         }
     }
@@ -1,4 +1,5 @@
 pub mod access_token;
+pub mod batch;
 pub mod helpers;
 #[cfg(feature = "payout_retry")]
 pub mod retry;
@@ -0,0 +1,951 @@
+use std::collections::HashSet;
+
+use api_models::{enums as api_enums, payments};
+use common_utils::{fp_utils::when, id_type, types::MinorUnit};
+use diesel_models::enums as storage_enums;
+use error_stack::{report, ResultExt};
+use futures::StreamExt;
+use router_env::{instrument, logger, tracing};
+use scheduler::utils as pt_utils;
+
+use super::helpers;
+use crate::{
+    consts,
+    core::{
+        errors::{self, CustomResult, RouterResponse, RouterResult, StorageErrorExt},
+        files::helpers as file_helpers,
+        utils as core_utils,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::{
+        api::{self, payouts},
+        domain,
+        storage::{self, PayoutBatchTrackingData},
+        transformers::ForeignFrom,
+    },
+};
+
+/// A row of a CSV payout batch file. Only the fields which can be represented as a single column
+/// are supported, payouts which need payout method data to be passed in the request are expected
+/// to reference a saved payout method through `payout_token`.
+#[derive(Debug, serde::Deserialize)]
+struct PayoutBatchCsvRecord {
+    payout_id: Option<String>,
+    amount: i64,
+    currency: api_enums::Currency,
+    customer_id: Option<id_type::CustomerId>,
+    payout_type: Option<api_enums::PayoutType>,
+    payout_token: Option<String>,
+    connector: Option<api_enums::PayoutConnectors>,
+    description: Option<String>,
+    priority: Option<api_enums::PayoutSendPriority>,
+    auto_fulfill: Option<bool>,
+    entity_type: Option<api_enums::PayoutEntityType>,
+    profile_id: Option<String>,
+    business_country: Option<api_enums::CountryAlpha2>,
+    business_label: Option<String>,
+}
+
+impl From<PayoutBatchCsvRecord> for payouts::PayoutCreateRequest {
+    fn from(record: PayoutBatchCsvRecord) -> Self {
+        Self {
+            payout_id: record.payout_id,
+            amount: Some(payments::Amount::from(MinorUnit::new(record.amount))),
+            currency: Some(record.currency),
+            customer_id: record.customer_id,
+            payout_type: record.payout_type,
+            payout_token: record.payout_token,
+            connector: record.connector.map(|connector| vec![connector]),
+            description: record.description,
+            priority: record.priority,
+            auto_fulfill: record.auto_fulfill,
+            entity_type: record.entity_type,
+            profile_id: record.profile_id,
+            business_country: record.business_country,
+            business_label: record.business_label,
+            ..Default::default()
+        }
+    }
+}
+
+/// The outcome of a row of the batch, as written to the result file
+#[derive(Debug, serde::Serialize)]
+struct PayoutBatchResultRecord {
+    row_number: i32,
+    payout_id: String,
+    status: storage_enums::PayoutBatchItemStatus,
+    payout_status: Option<storage_enums::PayoutStatus>,
+    error_code: Option<String>,
+    error_message: Option<String>,
+}
+
+impl From<storage::PayoutBatchItem> for PayoutBatchResultRecord {
+    fn from(item: storage::PayoutBatchItem) -> Self {
+        Self {
+            row_number: item.row_number,
+            payout_id: item.payout_id,
+            status: item.status,
+            payout_status: item.payout_status,
+            error_code: item.error_code,
+            error_message: item.error_message,
+        }
+    }
+}
+
+fn invalid_batch_row(
+    row_number: usize,
+    reason: impl std::fmt::Display,
+) -> errors::ApiErrorResponse {
+    errors::ApiErrorResponse::InvalidRequestData {
+        message: format!("Invalid payout batch row {row_number}: {reason}"),
+    }
+}
+
+/// Parse the payout create requests from a payout batch file, based on the type of the file.
+pub fn parse_payout_batch_file(
+    file_type: &str,
+    file_data: &[u8],
+) -> RouterResult<Vec<payouts::PayoutCreateRequest>> {
+    let file_type = file_type
+        .parse::<mime::Mime>()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the file type of the payout batch file")?;
+
+    if file_type.essence_str() == mime::TEXT_CSV.essence_str() {
+        csv::Reader::from_reader(file_data)
+            .deserialize::<PayoutBatchCsvRecord>()
+            .enumerate()
+            .map(|(index, record)| {
+                record
+                    .map(payouts::PayoutCreateRequest::from)
+                    .map_err(|error| report!(invalid_batch_row(index + 1, error)))
+            })
+            .collect()
+    } else if file_type.essence_str() == mime::APPLICATION_JSON.essence_str() {
+        serde_json::from_slice::<Vec<payouts::PayoutCreateRequest>>(file_data).map_err(|error| {
+            report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("Invalid payout batch file: {error}"),
+            })
+        })
+    } else {
+        Err(report!(errors::ApiErrorResponse::FileValidationFailed {
+            reason: "file_type does not match CSV or JSON format".to_string(),
+        }))
+    }
+}
+
+async fn get_payout_batch_requests(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    file_id: &str,
+) -> RouterResult<Vec<payouts::PayoutCreateRequest>> {
+    let file_metadata = state
+        .store
+        .find_file_metadata_by_merchant_id_file_id(&merchant_account.merchant_id, file_id)
+        .await
+        .change_context(errors::ApiErrorResponse::FileNotFound)?;
+
+    let (file_data, _) = file_helpers::retrieve_file_and_provider_file_id_from_file_id(
+        state,
+        Some(file_id.to_string()),
+        merchant_account,
+        key_store,
+        api::FileDataRequired::Required,
+    )
+    .await?;
+    let file_data = file_data
+        .ok_or(errors::ApiErrorResponse::FileNotAvailable)
+        .attach_printable("Payout batch file data not found")?;
+
+    parse_payout_batch_file(&file_metadata.file_type, &file_data)
+}
+
+/// Validate the rows of a payout batch file, returning the payout ID of every row. The payout IDs
+/// are assigned upfront, so that the rows of the batch can be tracked before their payouts are
+/// created.
+pub fn validate_payout_batch_requests(
+    requests: &[payouts::PayoutCreateRequest],
+) -> RouterResult<Vec<String>> {
+    if requests.is_empty() {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Payout batch file does not contain any payouts".to_string(),
+        })?
+    }
+    if requests.len() > consts::MAX_PAYOUT_BATCH_SIZE {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Payout batch file contains more than {} payouts",
+                consts::MAX_PAYOUT_BATCH_SIZE
+            ),
+        })?
+    }
+
+    let mut payout_ids = HashSet::with_capacity(requests.len());
+    requests
+        .iter()
+        .enumerate()
+        .map(|(index, request)| -> RouterResult<String> {
+            let row_number = index + 1;
+            if request.payout_link.unwrap_or(false) {
+                Err(invalid_batch_row(
+                    row_number,
+                    "payout links cannot be created through a payout batch",
+                ))?
+            }
+            let payout_id =
+                core_utils::get_or_generate_uuid("payout_id", request.payout_id.as_ref())
+                    .map_err(|error| invalid_batch_row(row_number, error.error_message()))?;
+            if !payout_ids.insert(payout_id.clone()) {
+                Err(invalid_batch_row(
+                    row_number,
+                    format!("duplicate payout_id {payout_id}"),
+                ))?
+            }
+            Ok(payout_id)
+        })
+        .collect()
+}
+
+fn get_payout_batch_concurrency(concurrency: Option<u16>) -> RouterResult<u16> {
+    match concurrency.unwrap_or(consts::DEFAULT_PAYOUT_BATCH_CONCURRENCY) {
+        concurrency @ 1..=consts::MAX_PAYOUT_BATCH_CONCURRENCY => Ok(concurrency),
+        _ => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "concurrency must be between 1 and {}",
+                consts::MAX_PAYOUT_BATCH_CONCURRENCY
+            ),
+        })),
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn payout_batch_create_core(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: payouts::PayoutBatchCreateRequest,
+) -> RouterResponse<payouts::PayoutBatchResponse> {
+    let db = &*state.store;
+    let concurrency = get_payout_batch_concurrency(req.concurrency)?;
+    let requests =
+        get_payout_batch_requests(&state, &merchant_account, &key_store, &req.file_id).await?;
+
+    let payout_ids = validate_payout_batch_requests(&requests)?;
+
+    let batch_id = common_utils::generate_id(consts::ID_LENGTH, "pbatch");
+    let now = common_utils::date_time::now();
+    let items = payout_ids
+        .into_iter()
+        .enumerate()
+        .map(
+            |(index, payout_id)| -> RouterResult<storage::PayoutBatchItemNew> {
+                Ok(storage::PayoutBatchItemNew {
+                    batch_id: batch_id.clone(),
+                    row_number: i32::try_from(index + 1)
+                        .change_context(errors::ApiErrorResponse::InternalServerError)?,
+                    merchant_id: merchant_account.merchant_id.clone(),
+                    payout_id,
+                    status: storage_enums::PayoutBatchItemStatus::Pending,
+                    payout_status: None,
+                    error_code: None,
+                    error_message: None,
+                    created_at: now,
+                    modified_at: now,
+                })
+            },
+        )
+        .collect::<RouterResult<Vec<_>>>()?;
+
+    let total_count =
+        i32::try_from(items.len()).change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let payout_batch = db
+        .insert_payout_batch(storage::PayoutBatchNew {
+            batch_id: batch_id.clone(),
+            merchant_id: merchant_account.merchant_id.clone(),
+            file_id: req.file_id,
+            status: storage_enums::PayoutBatchStatus::Pending,
+            concurrency: i32::from(concurrency),
+            total_count,
+            succeeded_count: 0,
+            failed_count: 0,
+            cancelled_count: 0,
+            result_file_id: None,
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert payout batch")?;
+
+    // Rows are inserted in chunks to stay within the bind parameter limit of postgres
+    for chunk in items.chunks(consts::PAYOUT_BATCH_INSERT_CHUNK_SIZE) {
+        db.insert_payout_batch_items(chunk.to_vec())
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert payout batch items")?;
+    }
+
+    add_payout_batch_task(db, &payout_batch, common_utils::date_time::now())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("Failed to add process tracker task for payout batch {batch_id}")
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutBatchResponse::foreign_from(payout_batch),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn payout_batch_retrieve_core(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: payouts::PayoutBatchRetrieveRequest,
+) -> RouterResponse<payouts::PayoutBatchResponse> {
+    let payout_batch = find_payout_batch(&state, &merchant_account, &req.batch_id).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutBatchResponse::foreign_from(payout_batch),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn payout_batch_items_list_core(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: payouts::PayoutBatchItemListRequest,
+) -> RouterResponse<payouts::PayoutBatchItemListResponse> {
+    let payout_batch = find_payout_batch(&state, &merchant_account, &req.batch_id).await?;
+
+    let items = state
+        .store
+        .list_payout_batch_items_by_batch_id_constraints(
+            &payout_batch.batch_id,
+            storage::PayoutBatchItemListConstraints {
+                status: req.constraints.status,
+                limit: Some(i64::from(req.constraints.limit)),
+                offset: req.constraints.offset.map(i64::from),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list payout batch items")?;
+
+    let data = items
+        .into_iter()
+        .map(payouts::PayoutBatchItemResponse::foreign_from)
+        .collect::<Vec<_>>();
+
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutBatchItemListResponse {
+            size: data.len(),
+            data,
+        },
+    ))
+}
+
+/// Cancel a payout batch. Rows which are yet to be processed are cancelled without creating their
+/// payouts, and the payouts already created for the batch are cancelled if they are not in a
+/// terminal state.
+#[instrument(skip_all)]
+pub async fn payout_batch_cancel_core(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: payouts::PayoutBatchRetrieveRequest,
+) -> RouterResponse<payouts::PayoutBatchResponse> {
+    let db = &*state.store;
+    let payout_batch = find_payout_batch(&state, &merchant_account, &req.batch_id).await?;
+
+    if payout_batch.status.is_terminal() {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Payout batch {} cannot be cancelled for status {}",
+                payout_batch.batch_id, payout_batch.status
+            ),
+        })?
+    }
+
+    // Stop the rows which are yet to be processed, before cancelling the payouts which have
+    // already been created
+    let payout_batch = db
+        .update_payout_batch(
+            payout_batch,
+            storage::PayoutBatchUpdate::StatusUpdate {
+                status: storage_enums::PayoutBatchStatus::Cancelled,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update payout batch")?;
+    db.update_payout_batch_items_by_batch_id_status(
+        &payout_batch.batch_id,
+        storage_enums::PayoutBatchItemStatus::Pending,
+        storage::PayoutBatchItemUpdate::Cancelled,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to cancel pending payout batch items")?;
+
+    let mut offset = 0;
+    loop {
+        let items = db
+            .list_payout_batch_items_by_batch_id_constraints(
+                &payout_batch.batch_id,
+                storage::PayoutBatchItemListConstraints {
+                    status: Some(storage_enums::PayoutBatchItemStatus::Succeeded),
+                    limit: Some(consts::PAYOUT_BATCH_CHUNK_SIZE),
+                    offset: Some(offset),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to list payout batch items")?;
+        let fetched_count = i64::try_from(items.len())
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+        for item in items {
+            if item
+                .payout_status
+                .map_or(true, helpers::is_payout_terminal_state)
+            {
+                continue;
+            }
+            let cancel_request = payouts::PayoutActionRequest {
+                payout_id: item.payout_id.clone(),
+            };
+            match super::payouts_cancel_core(
+                state.clone(),
+                merchant_account.clone(),
+                key_store.clone(),
+                cancel_request,
+            )
+            .await
+            {
+                Ok(services::ApplicationResponse::Json(response)) => {
+                    db.update_payout_batch_item(
+                        item,
+                        storage::PayoutBatchItemUpdate::PayoutStatusUpdate {
+                            payout_status: response.status,
+                        },
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to update payout batch item")?;
+                }
+                Ok(_) => {}
+                Err(error) => logger::error!(
+                    ?error,
+                    payout_id = %item.payout_id,
+                    "Failed to cancel payout of payout batch"
+                ),
+            }
+        }
+
+        if fetched_count < consts::PAYOUT_BATCH_CHUNK_SIZE {
+            break;
+        }
+        offset += consts::PAYOUT_BATCH_CHUNK_SIZE;
+    }
+
+    let payout_batch = update_payout_batch_counts(
+        db,
+        payout_batch,
+        storage_enums::PayoutBatchStatus::Cancelled,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutBatchResponse::foreign_from(payout_batch),
+    ))
+}
+
+async fn find_payout_batch(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    batch_id: &str,
+) -> RouterResult<storage::PayoutBatch> {
+    state
+        .store
+        .find_payout_batch_by_merchant_id_batch_id(&merchant_account.merchant_id, batch_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Payout batch {batch_id} not found"),
+        })
+}
+
+async fn update_payout_batch_counts(
+    db: &dyn StorageInterface,
+    payout_batch: storage::PayoutBatch,
+    status: storage_enums::PayoutBatchStatus,
+) -> RouterResult<storage::PayoutBatch> {
+    let status_counts = db
+        .get_payout_batch_item_status_counts(&payout_batch.batch_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to count payout batch items")?;
+    let get_count = |status: storage_enums::PayoutBatchItemStatus| -> RouterResult<i32> {
+        let count = status_counts
+            .iter()
+            .find_map(|(item_status, count)| (*item_status == status).then_some(*count))
+            .unwrap_or_default();
+        i32::try_from(count).change_context(errors::ApiErrorResponse::InternalServerError)
+    };
+
+    db.update_payout_batch(
+        payout_batch,
+        storage::PayoutBatchUpdate::CountsUpdate {
+            status,
+            succeeded_count: get_count(storage_enums::PayoutBatchItemStatus::Succeeded)?,
+            failed_count: get_count(storage_enums::PayoutBatchItemStatus::Failed)?,
+            cancelled_count: get_count(storage_enums::PayoutBatchItemStatus::Cancelled)?,
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update payout batch")
+}
+
+/// Create the payout for a single row of the batch, recording the outcome against the row.
+async fn process_payout_batch_item(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    item: storage::PayoutBatchItem,
+    request: Option<payouts::PayoutCreateRequest>,
+) -> RouterResult<storage::PayoutBatchItem> {
+    let update = match request {
+        Some(request) => {
+            let request = payouts::PayoutCreateRequest {
+                payout_id: Some(item.payout_id.clone()),
+                confirm: Some(request.confirm.unwrap_or(true)),
+                ..request
+            };
+            let result = super::payouts_create_core(
+                state.clone(),
+                merchant_account.clone(),
+                key_store.clone(),
+                request,
+            )
+            .await;
+            match result {
+                Ok(services::ApplicationResponse::Json(response)) => {
+                    storage::PayoutBatchItemUpdate::PayoutCreated {
+                        payout_status: response.status,
+                    }
+                }
+                Ok(_) => Err(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to get the payout create response in json")?,
+                // The payout was created by an earlier run of the task which did not record it
+                Err(error)
+                    if matches!(
+                        error.current_context(),
+                        errors::ApiErrorResponse::DuplicatePayout { .. }
+                    ) =>
+                {
+                    let payout = state
+                        .store
+                        .find_payout_by_merchant_id_payout_id(
+                            &merchant_account.merchant_id,
+                            &item.payout_id,
+                            merchant_account.storage_scheme,
+                        )
+                        .await
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to find payout of payout batch item")?;
+                    storage::PayoutBatchItemUpdate::PayoutCreated {
+                        payout_status: payout.status,
+                    }
+                }
+                Err(error) => {
+                    let error = error.current_context();
+                    storage::PayoutBatchItemUpdate::Failed {
+                        error_code: error.error_code(),
+                        error_message: error.error_message(),
+                    }
+                }
+            }
+        }
+        None => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Payout batch item not found in the payout batch file")?,
+    };
+
+    let is_payout_created = matches!(update, storage::PayoutBatchItemUpdate::PayoutCreated { .. });
+    match state
+        .store
+        .update_payout_batch_item(item.clone(), update)
+        .await
+    {
+        Ok(item) => Ok(item),
+        // The row is only updated while it is pending, so a row cancelled along with its batch
+        // while its payout was being created stays cancelled, and so does its payout
+        Err(error) if error.current_context().is_db_not_found() => {
+            logger::info!(
+                batch_id = %item.batch_id,
+                payout_id = %item.payout_id,
+                "Payout batch item was cancelled while its payout was being created"
+            );
+            if is_payout_created {
+                let cancel_request = payouts::PayoutActionRequest {
+                    payout_id: item.payout_id.clone(),
+                };
+                super::payouts_cancel_core(
+                    state.clone(),
+                    merchant_account.clone(),
+                    key_store.clone(),
+                    cancel_request,
+                )
+                .await
+                .attach_printable("Failed to cancel payout of cancelled payout batch item")?;
+            }
+            Ok(storage::PayoutBatchItem {
+                status: storage_enums::PayoutBatchItemStatus::Cancelled,
+                ..item
+            })
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update payout batch item"),
+    }
+}
+
+/// Mark a row which could not be processed as failed, so that it is not processed again
+async fn fail_payout_batch_item(
+    db: &dyn StorageInterface,
+    item: storage::PayoutBatchItem,
+    error: &errors::ApiErrorResponse,
+) -> RouterResult<storage::PayoutBatchItem> {
+    db.update_payout_batch_item(
+        item,
+        storage::PayoutBatchItemUpdate::Failed {
+            error_code: error.error_code(),
+            error_message: error.error_message(),
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update payout batch item")
+}
+
+/// Process the pending rows of a payout batch chunk by chunk, stopping early if the batch is
+/// cancelled in between. The batch file is parsed once for all the chunks.
+#[instrument(skip_all)]
+pub async fn process_payout_batch(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payout_batch: storage::PayoutBatch,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let requests =
+        get_payout_batch_requests(state, merchant_account, key_store, &payout_batch.file_id)
+            .await?;
+    let concurrency = usize::try_from(payout_batch.concurrency)
+        .change_context(errors::ApiErrorResponse::InternalServerError)?
+        .max(1);
+
+    let mut payout_batch = payout_batch;
+    loop {
+        let items = db
+            .list_payout_batch_items_by_batch_id_constraints(
+                &payout_batch.batch_id,
+                storage::PayoutBatchItemListConstraints {
+                    status: Some(storage_enums::PayoutBatchItemStatus::Pending),
+                    limit: Some(consts::PAYOUT_BATCH_CHUNK_SIZE),
+                    offset: None,
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to list pending payout batch items")?;
+        let is_last_chunk = i64::try_from(items.len())
+            .change_context(errors::ApiErrorResponse::InternalServerError)?
+            < consts::PAYOUT_BATCH_CHUNK_SIZE;
+
+        let items_count = items.len();
+        let requests = &requests;
+        let results = futures::stream::iter(items.into_iter().map(|item| async move {
+            let request = usize::try_from(item.row_number)
+                .ok()
+                .and_then(|row_number| row_number.checked_sub(1))
+                .and_then(|index| requests.get(index))
+                .cloned();
+            match process_payout_batch_item(
+                state,
+                merchant_account,
+                key_store,
+                item.clone(),
+                request,
+            )
+            .await
+            {
+                Ok(item) => Ok(item),
+                Err(error) => {
+                    logger::error!(
+                        ?error,
+                        batch_id = %item.batch_id,
+                        row_number = item.row_number,
+                        "Failed to process payout batch item"
+                    );
+                    fail_payout_batch_item(db, item, error.current_context()).await
+                }
+            }
+        }))
+        .buffer_unordered(concurrency)
+        .collect::<Vec<_>>()
+        .await;
+        let processed_count = results
+            .into_iter()
+            .filter_map(|result| {
+                result
+                    .map_err(|error| {
+                        logger::error!(
+                            ?error,
+                            batch_id = %payout_batch.batch_id,
+                            "Failed to mark payout batch item as failed"
+                        );
+                    })
+                    .ok()
+            })
+            .filter(|item| item.status != storage_enums::PayoutBatchItemStatus::Pending)
+            .count();
+
+        // The batch may have been cancelled while the chunk was being processed
+        payout_batch = find_payout_batch(state, merchant_account, &payout_batch.batch_id).await?;
+        if payout_batch.status == storage_enums::PayoutBatchStatus::Cancelled {
+            update_payout_batch_counts(
+                db,
+                payout_batch,
+                storage_enums::PayoutBatchStatus::Cancelled,
+            )
+            .await?;
+            return Ok(());
+        }
+
+        payout_batch = update_payout_batch_counts(
+            db,
+            payout_batch,
+            storage_enums::PayoutBatchStatus::Processing,
+        )
+        .await?;
+        if is_last_chunk {
+            break;
+        }
+        // The same rows would be listed again if none of the rows of the chunk left the pending
+        // status, so they are left to a retry of the task instead
+        if processed_count == 0 {
+            logger::error!(
+                batch_id = %payout_batch.batch_id,
+                items_count,
+                "None of the payout batch items of the chunk could be processed"
+            );
+            break;
+        }
+    }
+
+    let pending_count = payout_batch
+        .total_count
+        .saturating_sub(payout_batch.succeeded_count)
+        .saturating_sub(payout_batch.failed_count)
+        .saturating_sub(payout_batch.cancelled_count);
+    when(pending_count > 0, || {
+        Err(errors::ApiErrorResponse::InternalServerError).attach_printable(format!(
+            "{pending_count} rows of the payout batch are still pending"
+        ))
+    })?;
+
+    let status = match (payout_batch.succeeded_count, payout_batch.failed_count) {
+        (_, 0) => storage_enums::PayoutBatchStatus::Completed,
+        (0, _) => storage_enums::PayoutBatchStatus::Failed,
+        _ => storage_enums::PayoutBatchStatus::PartiallyCompleted,
+    };
+    let result_file_id = create_payout_batch_result_file(state, &payout_batch).await?;
+    let payout_batch = db
+        .update_payout_batch(
+            payout_batch,
+            storage::PayoutBatchUpdate::ResultFileUpdate { result_file_id },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update payout batch")?;
+    db.update_payout_batch(
+        payout_batch,
+        storage::PayoutBatchUpdate::StatusUpdate { status },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update payout batch")?;
+
+    Ok(())
+}
+
+/// Write the outcome of every row of the batch to a CSV file, which can be downloaded through the
+/// `/files` API.
+async fn create_payout_batch_result_file(
+    state: &SessionState,
+    payout_batch: &storage::PayoutBatch,
+) -> RouterResult<String> {
+    let db = &*state.store;
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    let mut offset = 0;
+    loop {
+        let items = db
+            .list_payout_batch_items_by_batch_id_constraints(
+                &payout_batch.batch_id,
+                storage::PayoutBatchItemListConstraints {
+                    status: None,
+                    limit: Some(consts::PAYOUT_BATCH_CHUNK_SIZE),
+                    offset: Some(offset),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to list payout batch items")?;
+        let fetched_count = i64::try_from(items.len())
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+        for item in items {
+            writer
+                .serialize(PayoutBatchResultRecord::from(item))
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to write payout batch result record")?;
+        }
+
+        if fetched_count < consts::PAYOUT_BATCH_CHUNK_SIZE {
+            break;
+        }
+        offset += consts::PAYOUT_BATCH_CHUNK_SIZE;
+    }
+    let file_data = writer
+        .into_inner()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to write payout batch result file")?;
+
+    let file_id = common_utils::generate_id(consts::ID_LENGTH, "file");
+    let file_key = format!("{}/{}", payout_batch.merchant_id, file_id);
+    let file_size = i32::try_from(file_data.len())
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    state
+        .file_storage_client
+        .upload_file(&file_key, file_data)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to upload payout batch result file")?;
+    db.insert_file_metadata(diesel_models::file::FileMetadataNew {
+        file_id: file_id.clone(),
+        merchant_id: payout_batch.merchant_id.clone(),
+        file_name: Some(format!("{}_result.csv", payout_batch.batch_id)),
+        file_size,
+        file_type: mime::TEXT_CSV.to_string(),
+        provider_file_id: Some(file_key),
+        file_upload_provider: Some(storage_enums::FileUploadProvider::Router),
+        available: true,
+        connector_label: None,
+        profile_id: None,
+        merchant_connector_id: None,
+    })
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to insert payout batch result file metadata")?;
+
+    Ok(file_id)
+}
+
+pub async fn add_payout_batch_task(
+    db: &dyn StorageInterface,
+    payout_batch: &storage::PayoutBatch,
+    schedule_time: time::PrimitiveDateTime,
+) -> CustomResult<(), errors::StorageError> {
+    let runner = storage::ProcessTrackerRunner::PayoutBatchWorkflow;
+    let task = "PAYOUT_BATCH";
+    let tag = ["PAYOUTS", "BATCH"];
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        task,
+        &payout_batch.batch_id,
+        &payout_batch.merchant_id,
+    );
+    let tracking_data = PayoutBatchTrackingData {
+        batch_id: payout_batch.batch_id.clone(),
+        merchant_id: payout_batch.merchant_id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        tag,
+        tracking_data,
+        schedule_time,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_parse_payout_batch_file() {
+        let csv_file = concat!(
+            "payout_id,amount,currency,payout_token\n",
+            "payout_1,1000,USD,token_1\n",
+            ",2000,EUR,token_2\n"
+        );
+        let requests = parse_payout_batch_file("text/csv", csv_file.as_bytes()).unwrap();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(
+            requests.first().unwrap().payout_id.as_deref(),
+            Some("payout_1")
+        );
+        assert_eq!(requests.last().unwrap().payout_id, None);
+        assert_eq!(
+            requests.last().unwrap().amount,
+            Some(payments::Amount::from(MinorUnit::new(2000)))
+        );
+
+        let json_file = r#"[{"amount": 1000, "currency": "USD", "payout_token": "token_1"}]"#;
+        let requests = parse_payout_batch_file("application/json", json_file.as_bytes()).unwrap();
+        assert_eq!(requests.len(), 1);
+
+        let invalid_csv_file = "amount,currency\n1000,USD\nten,USD\n";
+        assert!(parse_payout_batch_file("text/csv", invalid_csv_file.as_bytes()).is_err());
+        assert!(parse_payout_batch_file("application/pdf", json_file.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_validate_payout_batch_requests() {
+        let request = |payout_id: Option<&str>| payouts::PayoutCreateRequest {
+            payout_id: payout_id.map(ToString::to_string),
+            amount: Some(payments::Amount::from(MinorUnit::new(1000))),
+            currency: Some(api_enums::Currency::USD),
+            ..Default::default()
+        };
+
+        let payout_ids =
+            validate_payout_batch_requests(&[request(Some("payout_1")), request(None)]).unwrap();
+        assert_eq!(payout_ids.len(), 2);
+        assert_eq!(payout_ids.first().map(String::as_str), Some("payout_1"));
+
+        assert!(validate_payout_batch_requests(&[]).is_err());
+        assert!(validate_payout_batch_requests(&[
+            request(Some("payout_1")),
+            request(Some("payout_1"))
+        ])
+        .is_err());
+        assert!(
+            validate_payout_batch_requests(&[payouts::PayoutCreateRequest {
+                payout_link: Some(true),
+                ..request(None)
+            }])
+            .is_err()
+        );
+    }
+}
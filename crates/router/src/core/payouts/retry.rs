@@ -86,7 +86,9 @@ pub async fn do_gsm_multiple_connector_actions(
                     ),
                 }))?
             }
-            api_models::gsm::GsmDecision::DoDefault => break,
+            api_models::gsm::GsmDecision::RetryLater | api_models::gsm::GsmDecision::DoDefault => {
+                break
+            }
         }
     }
     Ok(())
@@ -150,7 +152,9 @@ pub async fn do_gsm_single_connector_actions(
                     ),
                 }))?
             }
-            api_models::gsm::GsmDecision::DoDefault => break,
+            api_models::gsm::GsmDecision::RetryLater | api_models::gsm::GsmDecision::DoDefault => {
+                break
+            }
         }
     }
     Ok(())
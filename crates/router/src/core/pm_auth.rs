@@ -469,6 +469,11 @@ async fn store_bank_details_in_payment_methods(
                 client_secret: None,
                 payment_method_billing_address: None,
                 updated_by: None,
+                network_token_reference_id: None,
+                network_token_status: None,
+                network_token_data: None,
+                account_update_requested_at: None,
+                card_expiry_month_start: None,
             };
 
             new_entries.push(pm_new);
@@ -698,6 +698,14 @@ pub async fn validate_and_create_refund(
     )
     .change_context(errors::ApiErrorResponse::MaximumRefundCount)?;
 
+    let split_refunds = validator::get_split_refunds(
+        payment_intent.split_payments.as_ref(),
+        req.split_refunds,
+        payment_intent.amount,
+        refund_amount,
+        &all_refunds,
+    )?;
+
     let connector = payment_attempt
         .connector
         .clone()
@@ -726,6 +734,7 @@ pub async fn validate_and_create_refund(
         .set_profile_id(payment_intent.profile_id.clone())
         .set_merchant_connector_id(payment_attempt.merchant_connector_id.clone())
         .set_charges(req.charges)
+        .set_split_refunds(split_refunds)
         .to_owned();
 
     let refund = match db
@@ -952,6 +961,7 @@ impl ForeignFrom<storage::Refund> for api::RefundResponse {
             connector: refund.connector,
             merchant_connector_id: refund.merchant_connector_id,
             charges: refund.charges,
+            split_refunds: refund.split_refunds,
         }
     }
 }
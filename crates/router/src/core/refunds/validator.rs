@@ -1,3 +1,4 @@
+use common_utils::types::MinorUnit;
 use error_stack::report;
 use router_env::{instrument, tracing};
 use time::PrimitiveDateTime;
@@ -170,3 +171,319 @@ pub fn validate_charge_refund(
         ),
     }
 }
+
+/// Splits the refund across the recipients of a split payment.
+/// If the split is not passed in the request, the refund is reversed from every recipient
+/// in proportion to the amount remaining to be refunded from them.
+pub fn get_split_refunds(
+    split_payments: Option<&common_utils::types::SplitPayments>,
+    split_refunds: Option<common_utils::types::SplitRefunds>,
+    payment_amount: MinorUnit,
+    refund_amount: MinorUnit,
+    all_refunds: &[storage::Refund],
+) -> RouterResult<Option<common_utils::types::SplitRefunds>> {
+    let Some(split_payments) = split_payments else {
+        utils::when(split_refunds.is_some(), || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "split_refunds can only be passed for split payments".to_string(),
+            }))
+        })?;
+        return Ok(None);
+    };
+
+    let Some(split_refunds) = split_refunds else {
+        return Ok(Some(get_proportional_split_refunds(
+            split_payments,
+            payment_amount,
+            refund_amount,
+            all_refunds,
+        )));
+    };
+
+    utils::when(split_refunds.total_amount() > refund_amount, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "sum of the split refund amounts should not be more than the refund amount"
+                .to_string(),
+        }))
+    })?;
+
+    for refund_split in split_refunds.0.iter() {
+        let payment_split = split_payments
+            .find_by_account_id(&refund_split.account_id)
+            .ok_or(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "account {} is not a recipient of the payment",
+                    refund_split.account_id
+                ),
+            })?;
+
+        let already_refunded =
+            get_amount_refunded_from_account(all_refunds, &refund_split.account_id);
+
+        utils::when(
+            refund_split.amount <= MinorUnit::new(0)
+                || refund_split.amount + already_refunded > payment_split.amount,
+            || {
+                Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "split refund amount for the account {} should be greater than 0 and not more than the amount remaining to be refunded from it",
+                        refund_split.account_id
+                    ),
+                }))
+            },
+        )?;
+    }
+
+    Ok(Some(split_refunds))
+}
+
+fn is_refund_not_failed(refund: &storage::Refund) -> bool {
+    refund.refund_status != enums::RefundStatus::Failure
+        && refund.refund_status != enums::RefundStatus::TransactionFailure
+}
+
+fn get_amount_refunded_from_account(
+    all_refunds: &[storage::Refund],
+    account_id: &str,
+) -> MinorUnit {
+    all_refunds
+        .iter()
+        .filter(|refund| is_refund_not_failed(refund))
+        .filter_map(|refund| refund.split_refunds.as_ref())
+        .flat_map(|splits| splits.0.iter())
+        .filter(|split| split.account_id == account_id)
+        .fold(MinorUnit::new(0), |total, split| total + split.amount)
+}
+
+/// Shares the refund between the recipients in proportion to the amount remaining to be refunded
+/// from each of them, so that a recipient who has already been refunded in full is not reversed
+/// from again. The units dropped while flooring the shares go to the recipients with the largest
+/// fractional part, so the recipients together bear their exact share of the refund.
+fn get_proportional_split_refunds(
+    split_payments: &common_utils::types::SplitPayments,
+    payment_amount: MinorUnit,
+    refund_amount: MinorUnit,
+    all_refunds: &[storage::Refund],
+) -> common_utils::types::SplitRefunds {
+    let refund_amount = refund_amount.get_amount_as_i64();
+    let refunded_amount = all_refunds
+        .iter()
+        .filter(|refund| is_refund_not_failed(refund))
+        .fold(MinorUnit::new(0), |total, refund| {
+            total + refund.refund_amount
+        });
+
+    let remaining_amounts: Vec<i64> = split_payments
+        .0
+        .iter()
+        .map(|split| {
+            (split.amount - get_amount_refunded_from_account(all_refunds, &split.account_id))
+                .get_amount_as_i64()
+                .max(0)
+        })
+        .collect();
+    let total_remaining_amount: i64 = remaining_amounts.iter().sum();
+
+    // The recipients can have more left to be refunded than the payment itself when earlier
+    // refunds were not split in proportion, in which case they bear the refund in full
+    let denominator = (payment_amount - refunded_amount)
+        .get_amount_as_i64()
+        .max(total_remaining_amount);
+
+    let mut shares: Vec<(usize, i64, i64)> = remaining_amounts
+        .iter()
+        .enumerate()
+        .map(|(index, remaining_amount)| {
+            let amount = remaining_amount * refund_amount;
+            (
+                index,
+                amount.checked_div(denominator).unwrap_or_default(),
+                amount.checked_rem(denominator).unwrap_or_default(),
+            )
+        })
+        .collect();
+
+    let total_share = (total_remaining_amount * refund_amount)
+        .checked_div(denominator)
+        .unwrap_or_default();
+    let mut undistributed_amount =
+        total_share - shares.iter().map(|(_, share, _)| share).sum::<i64>();
+
+    shares.sort_by(|(_, _, remainder_a), (_, _, remainder_b)| remainder_b.cmp(remainder_a));
+    for (_, share, _) in shares.iter_mut() {
+        if undistributed_amount <= 0 {
+            break;
+        }
+        *share += 1;
+        undistributed_amount -= 1;
+    }
+    shares.sort_by_key(|(index, _, _)| *index);
+
+    common_utils::types::SplitRefunds(
+        split_payments
+            .0
+            .iter()
+            .zip(shares)
+            .filter(|(_, (_, share, _))| *share > 0)
+            .map(|(split, (_, share, _))| common_utils::types::RefundSplit {
+                account_id: split.account_id.clone(),
+                amount: MinorUnit::new(share),
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use common_utils::types::{PaymentSplit, RefundSplit, SplitPayments, SplitRefunds};
+
+    use super::*;
+
+    fn get_split_payments(splits: &[(&str, i64)]) -> SplitPayments {
+        SplitPayments(
+            splits
+                .iter()
+                .map(|(account_id, amount)| PaymentSplit {
+                    account_id: account_id.to_string(),
+                    amount: MinorUnit::new(*amount),
+                    fees: MinorUnit::new(0),
+                    reference: None,
+                })
+                .collect(),
+        )
+    }
+
+    fn get_split_refunds(splits: &[(&str, i64)]) -> SplitRefunds {
+        SplitRefunds(
+            splits
+                .iter()
+                .map(|(account_id, amount)| RefundSplit {
+                    account_id: account_id.to_string(),
+                    amount: MinorUnit::new(*amount),
+                })
+                .collect(),
+        )
+    }
+
+    fn get_refund(refund_amount: i64, status: &str, splits: &[(&str, i64)]) -> storage::Refund {
+        serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "internal_reference_id": "internal_ref_123",
+            "refund_id": "refund_456",
+            "payment_id": "payment_789",
+            "merchant_id": "merchant_123",
+            "connector_transaction_id": "connector_txn_789",
+            "connector": "adyen",
+            "refund_type": "instant_refund",
+            "total_amount": 10000,
+            "currency": "USD",
+            "refund_amount": refund_amount,
+            "refund_status": status,
+            "sent_to_gateway": true,
+            "created_at": "2024-02-26T12:00:00Z",
+            "updated_at": "2024-02-26T12:00:00Z",
+            "attempt_id": "attempt_123",
+            "updated_by": "admin",
+            "split_refunds": get_split_refunds(splits),
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_proportional_split_refunds_distribute_the_remainder() {
+        let split_payments =
+            get_split_payments(&[("acc_a", 3333), ("acc_b", 3333), ("acc_c", 3334)]);
+
+        let split_refunds = super::get_split_refunds(
+            Some(&split_payments),
+            None,
+            MinorUnit::new(10000),
+            MinorUnit::new(100),
+            &[],
+        )
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(split_refunds.total_amount(), MinorUnit::new(100));
+        assert_eq!(
+            split_refunds,
+            get_split_refunds(&[("acc_a", 33), ("acc_b", 33), ("acc_c", 34)])
+        );
+    }
+
+    #[test]
+    fn test_proportional_split_refunds_leave_the_platform_share() {
+        let split_payments = get_split_payments(&[("acc_a", 4500), ("acc_b", 4500)]);
+
+        let split_refunds = super::get_split_refunds(
+            Some(&split_payments),
+            None,
+            MinorUnit::new(10000),
+            MinorUnit::new(1010),
+            &[],
+        )
+        .unwrap()
+        .unwrap();
+
+        // 90% of the refund is borne by the recipients, the rest by the platform
+        assert_eq!(
+            split_refunds,
+            get_split_refunds(&[("acc_a", 455), ("acc_b", 454)])
+        );
+    }
+
+    #[test]
+    fn test_proportional_split_refunds_skip_accounts_refunded_in_full() {
+        let split_payments = get_split_payments(&[("acc_a", 6000), ("acc_b", 4000)]);
+        let all_refunds = [
+            get_refund(6000, "Success", &[("acc_a", 6000)]),
+            get_refund(4000, "Failure", &[("acc_b", 4000)]),
+        ];
+
+        let split_refunds = super::get_split_refunds(
+            Some(&split_payments),
+            None,
+            MinorUnit::new(10000),
+            MinorUnit::new(4000),
+            &all_refunds,
+        )
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(split_refunds, get_split_refunds(&[("acc_b", 4000)]));
+    }
+
+    #[test]
+    fn test_split_refunds_validation() {
+        let split_payments = get_split_payments(&[("acc_a", 6000), ("acc_b", 4000)]);
+        let all_refunds = [get_refund(3000, "Success", &[("acc_b", 3000)])];
+        let get_split_refunds_for = |splits: &[(&str, i64)], refund_amount: i64| {
+            super::get_split_refunds(
+                Some(&split_payments),
+                Some(get_split_refunds(splits)),
+                MinorUnit::new(10000),
+                MinorUnit::new(refund_amount),
+                &all_refunds,
+            )
+        };
+
+        assert!(get_split_refunds_for(&[("acc_a", 2000), ("acc_b", 1000)], 3000).is_ok());
+        // More than the amount remaining to be refunded from the account
+        assert!(get_split_refunds_for(&[("acc_b", 1001)], 2000).is_err());
+        // More than the refund amount
+        assert!(get_split_refunds_for(&[("acc_a", 2000)], 1000).is_err());
+        // Not a recipient of the payment
+        assert!(get_split_refunds_for(&[("acc_c", 1000)], 1000).is_err());
+        assert!(get_split_refunds_for(&[("acc_a", 0)], 1000).is_err());
+        // Split refunds can only be passed for split payments
+        assert!(super::get_split_refunds(
+            None,
+            Some(get_split_refunds(&[("acc_a", 1000)])),
+            MinorUnit::new(10000),
+            MinorUnit::new(1000),
+            &[],
+        )
+        .is_err());
+    }
+}
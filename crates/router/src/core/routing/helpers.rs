@@ -287,6 +287,7 @@ pub async fn update_business_profile_active_algorithm_ref(
         collect_shipping_details_from_wallet_connector: None,
         collect_billing_details_from_wallet_connector: None,
         is_connector_agnostic_mit_enabled: None,
+        auto_void_after_days: None,
     };
 
     db.update_business_profile_by_profile_id(current_business_profile, business_profile_update)
@@ -0,0 +1,1341 @@
+//! Subscription billing.
+//!
+//! Merchants define plans with a recurring amount and billing interval, and subscribe customers to
+//! them with a mandate of the customer. A process tracker task per subscription creates an invoice
+//! at the start of every billing period and charges it off-session with the mandate. Failed
+//! payments are attempted again as per the dunning schedule, after which the subscription is
+//! cancelled. A subscription is locked while it is billed or cancelled, so that it cannot be
+//! cancelled while its invoice is being charged.
+
+use api_models::{payments as payment_types, subscriptions as subscription_types};
+use common_utils::fp_utils::when;
+use diesel_models::process_tracker::business_status;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+use scheduler::utils as pt_utils;
+use time::PrimitiveDateTime;
+
+use crate::{
+    consts,
+    core::{
+        api_locking,
+        errors::{self, CustomResult, RouterResponse, RouterResult, StorageErrorExt},
+        payments, webhooks as webhooks_core,
+    },
+    db::StorageInterface,
+    routes::{lock_utils, SessionState},
+    services,
+    types::{
+        api, domain,
+        storage::{self, enums, SubscriptionTrackingData},
+        transformers::ForeignFrom,
+    },
+};
+
+const SUBSCRIPTION_BILLING_TASK: &str = "SUBSCRIPTION_BILLING";
+const SUBSCRIPTION_BILLING_TAG: [&str; 2] = ["SUBSCRIPTION", "BILLING"];
+
+/// What the subscription billing task is to do after a run
+#[derive(Debug)]
+pub enum SubscriptionBillingOutcome {
+    /// Run the task again for the next billing period
+    Reschedule(PrimitiveDateTime),
+    /// Run the task again for the same invoice, to attempt the payment again or to check its
+    /// status
+    Retry(PrimitiveDateTime),
+    /// The subscription is not billed anymore
+    Finish(&'static str),
+}
+
+/// The status of the payment created for an invoice
+#[derive(Debug, PartialEq)]
+enum InvoicePaymentStatus {
+    Succeeded,
+    Failed {
+        error_code: Option<String>,
+        error_message: Option<String>,
+    },
+    Pending,
+}
+
+impl InvoicePaymentStatus {
+    fn from_intent_status(
+        status: enums::IntentStatus,
+        error_code: Option<String>,
+        error_message: Option<String>,
+    ) -> Self {
+        match status {
+            enums::IntentStatus::Succeeded => Self::Succeeded,
+            // Off-session payments cannot be authenticated by the customer
+            enums::IntentStatus::Failed
+            | enums::IntentStatus::Cancelled
+            | enums::IntentStatus::Expired
+            | enums::IntentStatus::RequiresPaymentMethod
+            | enums::IntentStatus::RequiresCustomerAction => Self::Failed {
+                error_code,
+                error_message,
+            },
+            enums::IntentStatus::Processing
+            | enums::IntentStatus::RequiresMerchantAction
+            | enums::IntentStatus::RequiresConfirmation
+            | enums::IntentStatus::RequiresCapture
+            | enums::IntentStatus::PartiallyCaptured
+            | enums::IntentStatus::PartiallyCapturedAndCapturable => Self::Pending,
+        }
+    }
+}
+
+/// The lock held while a subscription is billed or cancelled
+fn get_subscription_lock_action(subscription_id: &str) -> api_locking::LockAction {
+    api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: subscription_id.to_owned(),
+            api_identifier: lock_utils::ApiIdentifier::Subscriptions,
+            override_lock_retries: None,
+        },
+    }
+}
+
+fn get_list_limit(limit: Option<u32>) -> RouterResult<i64> {
+    let limit = limit.unwrap_or(consts::DEFAULT_SUBSCRIPTION_LIST_LIMIT);
+    when(limit > consts::MAX_SUBSCRIPTION_LIST_LIMIT, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "limit should not be more than {}",
+                consts::MAX_SUBSCRIPTION_LIST_LIMIT
+            ),
+        }))
+    })?;
+    Ok(i64::from(limit))
+}
+
+#[instrument(skip_all)]
+pub async fn create_subscription_plan(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: subscription_types::SubscriptionPlanCreateRequest,
+) -> RouterResponse<subscription_types::SubscriptionPlanResponse> {
+    let interval_count = req.interval_count.unwrap_or(1);
+    when(interval_count == 0, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "interval_count must be greater than 0".to_string(),
+        }))
+    })?;
+    when(req.amount.get_amount_as_i64() <= 0, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "amount must be greater than 0".to_string(),
+        }))
+    })?;
+
+    let now = common_utils::date_time::now();
+    let plan = state
+        .store
+        .insert_subscription_plan(storage::SubscriptionPlanNew {
+            plan_id: common_utils::generate_id(consts::ID_LENGTH, "plan"),
+            merchant_id: merchant_account.merchant_id,
+            name: req.name,
+            description: req.description,
+            amount: req.amount,
+            currency: req.currency,
+            billing_interval: req.interval,
+            billing_interval_count: i32::from(interval_count),
+            trial_period_days: req.trial_period_days.map(i32::from),
+            active: true,
+            metadata: req.metadata,
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert subscription plan")?;
+
+    Ok(services::ApplicationResponse::Json(
+        subscription_types::SubscriptionPlanResponse::foreign_from(plan),
+    ))
+}
+
+async fn find_subscription_plan(
+    state: &SessionState,
+    merchant_id: &str,
+    plan_id: &str,
+) -> RouterResult<storage::SubscriptionPlan> {
+    state
+        .store
+        .find_subscription_plan_by_merchant_id_plan_id(merchant_id, plan_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Subscription plan {plan_id} not found"),
+        })
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_subscription_plan(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    plan_id: String,
+) -> RouterResponse<subscription_types::SubscriptionPlanResponse> {
+    let plan = find_subscription_plan(&state, &merchant_account.merchant_id, &plan_id).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        subscription_types::SubscriptionPlanResponse::foreign_from(plan),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn list_subscription_plans(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    constraints: subscription_types::SubscriptionPlanListConstraints,
+) -> RouterResponse<Vec<subscription_types::SubscriptionPlanResponse>> {
+    let plans = state
+        .store
+        .list_subscription_plans_by_merchant_id(
+            &merchant_account.merchant_id,
+            storage::SubscriptionPlanListConstraints {
+                active: constraints.active,
+                limit: Some(get_list_limit(constraints.limit)?),
+                offset: constraints.offset.map(i64::from),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list subscription plans")?;
+
+    Ok(services::ApplicationResponse::Json(
+        plans
+            .into_iter()
+            .map(subscription_types::SubscriptionPlanResponse::foreign_from)
+            .collect(),
+    ))
+}
+
+/// Update a subscription plan. The amount and billing interval of a plan cannot be changed, as
+/// they apply to the existing subscriptions to the plan.
+#[instrument(skip_all)]
+pub async fn update_subscription_plan(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    plan_id: String,
+    req: subscription_types::SubscriptionPlanUpdateRequest,
+) -> RouterResponse<subscription_types::SubscriptionPlanResponse> {
+    let plan = find_subscription_plan(&state, &merchant_account.merchant_id, &plan_id).await?;
+
+    let plan = state
+        .store
+        .update_subscription_plan(
+            plan,
+            storage::SubscriptionPlanUpdate::Update {
+                name: req.name,
+                description: req.description,
+                active: req.active,
+                metadata: req.metadata,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update subscription plan")?;
+
+    Ok(services::ApplicationResponse::Json(
+        subscription_types::SubscriptionPlanResponse::foreign_from(plan),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn create_subscription(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: subscription_types::SubscriptionCreateRequest,
+) -> RouterResponse<subscription_types::SubscriptionResponse> {
+    let db = &*state.store;
+    let merchant_id = &merchant_account.merchant_id;
+
+    let plan = find_subscription_plan(&state, merchant_id, &req.plan_id).await?;
+    when(!plan.active, || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!("Subscription plan {} is not active", plan.plan_id),
+        }))
+    })?;
+
+    db.find_customer_by_customer_id_merchant_id(
+        &req.customer_id,
+        merchant_id,
+        &key_store,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    let mandate = db
+        .find_mandate_by_merchant_id_mandate_id(
+            merchant_id,
+            &req.mandate_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
+    when(mandate.customer_id != req.customer_id, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "mandate_id does not belong to the customer".to_string(),
+        }))
+    })?;
+    when(
+        mandate.mandate_status != enums::MandateStatus::Active,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!("Mandate {} is not active", mandate.mandate_id),
+            }))
+        },
+    )?;
+
+    let profile_id = req
+        .profile_id
+        .or(merchant_account.default_profile.clone())
+        .ok_or(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "profile_id",
+        })?;
+    let business_profile = db
+        .find_business_profile_by_profile_id(&profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.clone(),
+        })?;
+    when(business_profile.merchant_id != *merchant_id, || {
+        Err(report!(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.clone(),
+        }))
+    })?;
+
+    let now = common_utils::date_time::now();
+    let trial_end = req
+        .trial_period_days
+        .map(i32::from)
+        .or(plan.trial_period_days)
+        .filter(|trial_period_days| *trial_period_days > 0)
+        .map(|trial_period_days| {
+            now.saturating_add(time::Duration::days(i64::from(trial_period_days)))
+        });
+    // The billing periods start at the end of the trial, the first invoice is charged right away
+    // for subscriptions without a trial
+    let billing_cycle_anchor = trial_end.unwrap_or(now);
+
+    let subscription = db
+        .insert_subscription(storage::SubscriptionNew {
+            subscription_id: common_utils::generate_id(consts::ID_LENGTH, "sub"),
+            merchant_id: merchant_id.clone(),
+            customer_id: req.customer_id,
+            plan_id: plan.plan_id,
+            mandate_id: mandate.mandate_id,
+            profile_id,
+            status: if trial_end.is_some() {
+                enums::SubscriptionStatus::Trialing
+            } else {
+                enums::SubscriptionStatus::Incomplete
+            },
+            billing_cycle_anchor,
+            current_period_start: trial_end.map(|_| now),
+            current_period_end: trial_end,
+            trial_end,
+            next_billing_at: Some(billing_cycle_anchor),
+            cancel_at_period_end: false,
+            cancelled_at: None,
+            cancellation_reason: None,
+            metadata: req.metadata,
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert subscription")?;
+
+    add_subscription_billing_task(db, &subscription, billing_cycle_anchor)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed to add process tracker task for subscription {}",
+                subscription.subscription_id
+            )
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        subscription_types::SubscriptionResponse::foreign_from(subscription),
+    ))
+}
+
+async fn find_subscription(
+    state: &SessionState,
+    merchant_id: &str,
+    subscription_id: &str,
+) -> RouterResult<storage::Subscription> {
+    state
+        .store
+        .find_subscription_by_merchant_id_subscription_id(merchant_id, subscription_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Subscription {subscription_id} not found"),
+        })
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_subscription(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: subscription_types::SubscriptionId,
+) -> RouterResponse<subscription_types::SubscriptionResponse> {
+    let subscription =
+        find_subscription(&state, &merchant_account.merchant_id, &req.subscription_id).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        subscription_types::SubscriptionResponse::foreign_from(subscription),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn list_subscriptions(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    constraints: subscription_types::SubscriptionListConstraints,
+) -> RouterResponse<Vec<subscription_types::SubscriptionResponse>> {
+    let subscriptions = state
+        .store
+        .list_subscriptions_by_merchant_id(
+            &merchant_account.merchant_id,
+            storage::SubscriptionListConstraints {
+                customer_id: constraints.customer_id,
+                plan_id: constraints.plan_id,
+                status: constraints.status,
+                limit: Some(get_list_limit(constraints.limit)?),
+                offset: constraints.offset.map(i64::from),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list subscriptions")?;
+
+    Ok(services::ApplicationResponse::Json(
+        subscriptions
+            .into_iter()
+            .map(subscription_types::SubscriptionResponse::foreign_from)
+            .collect(),
+    ))
+}
+
+/// Cancel a subscription, either right away or at the end of its current billing or trial period.
+///
+/// Subscriptions which are not in good standing are always cancelled right away, voiding their
+/// unpaid invoice. A subscription cannot be cancelled while the payment for its invoice is being
+/// processed.
+#[instrument(skip_all)]
+pub async fn cancel_subscription(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: subscription_types::SubscriptionCancelRequestWithId,
+) -> RouterResponse<subscription_types::SubscriptionResponse> {
+    let lock_action = get_subscription_lock_action(&req.subscription_id);
+    lock_action
+        .clone()
+        .perform_locking_action(&state, merchant_account.merchant_id.clone())
+        .await?;
+
+    let result = cancel_locked_subscription(&state, &merchant_account, &key_store, req).await;
+
+    lock_action
+        .free_lock_action(&state, merchant_account.merchant_id.clone())
+        .await?;
+    result
+}
+
+async fn cancel_locked_subscription(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    req: subscription_types::SubscriptionCancelRequestWithId,
+) -> RouterResponse<subscription_types::SubscriptionResponse> {
+    let db = &*state.store;
+    let subscription =
+        find_subscription(state, &merchant_account.merchant_id, &req.subscription_id).await?;
+
+    when(
+        subscription.status == enums::SubscriptionStatus::Cancelled,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "Subscription {} is already cancelled",
+                    subscription.subscription_id
+                ),
+            }))
+        },
+    )?;
+
+    let cancellation_reason = req.request.cancellation_reason;
+    let cancel_at_period_end = req.request.cancel_at_period_end
+        && matches!(
+            subscription.status,
+            enums::SubscriptionStatus::Active | enums::SubscriptionStatus::Trialing
+        );
+
+    let subscription = if cancel_at_period_end {
+        db.update_subscription(
+            subscription,
+            storage::SubscriptionUpdate::CancelAtPeriodEndUpdate {
+                cancellation_reason,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update subscription")?
+    } else {
+        let unpaid_invoice = db
+            .find_unpaid_subscription_invoice_by_subscription_id(&subscription.subscription_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to find unpaid subscription invoice")?;
+        when(
+            unpaid_invoice.as_ref().map(|invoice| invoice.status)
+                == Some(enums::SubscriptionInvoiceStatus::Processing),
+            || {
+                Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                    message: format!(
+                        "The payment for an invoice of subscription {} is being processed",
+                        subscription.subscription_id
+                    ),
+                }))
+            },
+        )?;
+
+        end_subscription(
+            state,
+            merchant_account,
+            key_store,
+            subscription,
+            unpaid_invoice,
+            cancellation_reason,
+        )
+        .await?
+    };
+
+    Ok(services::ApplicationResponse::Json(
+        subscription_types::SubscriptionResponse::foreign_from(subscription),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn list_subscription_invoices(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: subscription_types::SubscriptionInvoiceListRequest,
+) -> RouterResponse<Vec<subscription_types::SubscriptionInvoiceResponse>> {
+    let subscription =
+        find_subscription(&state, &merchant_account.merchant_id, &req.subscription_id).await?;
+
+    let invoices = state
+        .store
+        .list_subscription_invoices_by_subscription_id(
+            &subscription.subscription_id,
+            Some(get_list_limit(req.constraints.limit)?),
+            req.constraints.offset.map(i64::from),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list subscription invoices")?;
+
+    Ok(services::ApplicationResponse::Json(
+        invoices
+            .into_iter()
+            .map(subscription_types::SubscriptionInvoiceResponse::foreign_from)
+            .collect(),
+    ))
+}
+
+/// Void the unpaid invoice of a subscription if any, cancel the subscription and notify the
+/// merchant of the cancellation.
+async fn end_subscription(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    subscription: storage::Subscription,
+    unpaid_invoice: Option<storage::SubscriptionInvoice>,
+    cancellation_reason: Option<String>,
+) -> RouterResult<storage::Subscription> {
+    let db = &*state.store;
+    if let Some(invoice) = unpaid_invoice {
+        db.update_subscription_invoice(invoice, storage::SubscriptionInvoiceUpdate::Voided)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to void subscription invoice")?;
+    }
+
+    let subscription = db
+        .update_subscription(
+            subscription,
+            storage::SubscriptionUpdate::Cancelled {
+                cancellation_reason,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to cancel subscription")?;
+
+    trigger_subscription_webhook(state, merchant_account, key_store, &subscription).await;
+
+    Ok(subscription)
+}
+
+/// Schedule the billing task of a subscription, which first runs at `schedule_time`.
+pub async fn add_subscription_billing_task(
+    db: &dyn StorageInterface,
+    subscription: &storage::Subscription,
+    schedule_time: PrimitiveDateTime,
+) -> CustomResult<(), errors::StorageError> {
+    let runner = storage::ProcessTrackerRunner::SubscriptionBillingWorkflow;
+    let task = SUBSCRIPTION_BILLING_TASK;
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        task,
+        &subscription.subscription_id,
+        &subscription.merchant_id,
+    );
+    let tracking_data = SubscriptionTrackingData {
+        subscription_id: subscription.subscription_id.clone(),
+        merchant_id: subscription.merchant_id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        SUBSCRIPTION_BILLING_TAG,
+        tracking_data,
+        schedule_time,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+/// Compute the end of the billing period starting at `period_start`. The billing periods of a
+/// subscription are aligned on its billing cycle anchor, the day of the month of the anchor is
+/// clamped to the last day of shorter months.
+fn get_billing_period_end(
+    billing_cycle_anchor: PrimitiveDateTime,
+    plan: &storage::SubscriptionPlan,
+    period_start: PrimitiveDateTime,
+) -> Option<PrimitiveDateTime> {
+    (1..)
+        .map(|periods| {
+            let count = plan.billing_interval_count.checked_mul(periods)?;
+            match plan.billing_interval {
+                enums::SubscriptionInterval::Day => {
+                    billing_cycle_anchor.checked_add(time::Duration::days(i64::from(count)))
+                }
+                enums::SubscriptionInterval::Week => {
+                    billing_cycle_anchor.checked_add(time::Duration::weeks(i64::from(count)))
+                }
+                enums::SubscriptionInterval::Month => add_months(billing_cycle_anchor, count),
+                enums::SubscriptionInterval::Year => {
+                    add_months(billing_cycle_anchor, count.checked_mul(12)?)
+                }
+            }
+        })
+        .find(|period_end| period_end.map_or(true, |period_end| period_end > period_start))
+        .flatten()
+}
+
+fn add_months(date_time: PrimitiveDateTime, months: i32) -> Option<PrimitiveDateTime> {
+    let month_index = date_time
+        .year()
+        .checked_mul(12)?
+        .checked_add(i32::from(u8::from(date_time.month())) - 1)?
+        .checked_add(months)?;
+    let year = month_index.div_euclid(12);
+    let month = time::Month::try_from(u8::try_from(month_index.rem_euclid(12) + 1).ok()?).ok()?;
+    let day = date_time
+        .day()
+        .min(time::util::days_in_year_month(year, month));
+    let date = time::Date::from_calendar_date(year, month, day).ok()?;
+
+    Some(PrimitiveDateTime::new(date, date_time.time()))
+}
+
+/// Bill a subscription which is due: create the invoice for the billing period if it does not
+/// exist yet, charge it, and update the subscription based on the outcome of the payment.
+#[instrument(skip_all, fields(subscription_id = %subscription_id))]
+pub async fn process_subscription_billing(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    subscription_id: &str,
+) -> RouterResult<SubscriptionBillingOutcome> {
+    let lock_action = get_subscription_lock_action(subscription_id);
+    match lock_action
+        .clone()
+        .perform_locking_action(state, merchant_account.merchant_id.clone())
+        .await
+    {
+        Ok(()) => (),
+        Err(error)
+            if matches!(
+                error.current_context(),
+                errors::ApiErrorResponse::ResourceBusy
+            ) =>
+        {
+            logger::info!("Subscription is locked, it is being cancelled");
+            return Ok(SubscriptionBillingOutcome::Retry(
+                common_utils::date_time::now().saturating_add(time::Duration::seconds(
+                    consts::SUBSCRIPTION_LOCKED_BILLING_RETRY_INTERVAL_IN_SECS,
+                )),
+            ));
+        }
+        Err(error) => return Err(error),
+    }
+
+    let result = Box::pin(bill_locked_subscription(
+        state,
+        merchant_account,
+        key_store,
+        subscription_id,
+    ))
+    .await;
+
+    lock_action
+        .free_lock_action(state, merchant_account.merchant_id.clone())
+        .await?;
+    result
+}
+
+async fn bill_locked_subscription(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    subscription_id: &str,
+) -> RouterResult<SubscriptionBillingOutcome> {
+    let db = &*state.store;
+    let subscription =
+        find_subscription(state, &merchant_account.merchant_id, subscription_id).await?;
+
+    let Some(next_billing_at) = subscription
+        .next_billing_at
+        .filter(|_| subscription.status != enums::SubscriptionStatus::Cancelled)
+    else {
+        logger::info!("Subscription is cancelled, finishing subscription billing task");
+        return Ok(SubscriptionBillingOutcome::Finish(
+            business_status::RESOURCE_STATUS_MISMATCH,
+        ));
+    };
+    if next_billing_at > common_utils::date_time::now() {
+        return Ok(SubscriptionBillingOutcome::Reschedule(next_billing_at));
+    }
+
+    let unpaid_invoice = db
+        .find_unpaid_subscription_invoice_by_subscription_id(&subscription.subscription_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find unpaid subscription invoice")?;
+
+    if subscription.cancel_at_period_end
+        && unpaid_invoice.as_ref().map(|invoice| invoice.status)
+            != Some(enums::SubscriptionInvoiceStatus::Processing)
+    {
+        let cancellation_reason = subscription.cancellation_reason.clone();
+        end_subscription(
+            state,
+            merchant_account,
+            key_store,
+            subscription,
+            unpaid_invoice,
+            cancellation_reason,
+        )
+        .await?;
+        return Ok(SubscriptionBillingOutcome::Finish(
+            business_status::COMPLETED_BY_PT,
+        ));
+    }
+
+    let invoice = match unpaid_invoice {
+        Some(invoice) => invoice,
+        None => create_subscription_invoice(state, &subscription, next_billing_at).await?,
+    };
+
+    let (invoice, payment_status) = match (invoice.status, invoice.payment_id.clone()) {
+        (enums::SubscriptionInvoiceStatus::Processing, Some(payment_id)) => {
+            let payment_status = get_invoice_payment_status(
+                state,
+                merchant_account,
+                key_store,
+                &subscription,
+                &invoice,
+                &payment_id,
+            )
+            .await?;
+            (invoice, payment_status)
+        }
+        (_, previous_payment_id) => {
+            // The previous payment of the invoice may have succeeded or be processed after it was
+            // found to have failed, it is not attempted again in that case
+            let previous_payment_status = match &previous_payment_id {
+                Some(payment_id) => {
+                    find_invoice_payment_status(state, merchant_account, key_store, payment_id)
+                        .await?
+                }
+                None => None,
+            };
+
+            match (previous_payment_status, previous_payment_id) {
+                (Some(InvoicePaymentStatus::Succeeded), _) => {
+                    (invoice, InvoicePaymentStatus::Succeeded)
+                }
+                (Some(InvoicePaymentStatus::Pending), Some(payment_id)) => {
+                    let attempt_count = invoice.attempt_count;
+                    let invoice = db
+                        .update_subscription_invoice(
+                            invoice,
+                            storage::SubscriptionInvoiceUpdate::PaymentAttempted {
+                                payment_id,
+                                attempt_count,
+                            },
+                        )
+                        .await
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to update subscription invoice")?;
+                    (invoice, InvoicePaymentStatus::Pending)
+                }
+                _ => {
+                    let payment_id = common_utils::generate_id(consts::ID_LENGTH, "pay");
+                    let attempt_count = invoice.attempt_count + 1;
+                    // The payment ID is recorded before the payment is created, so that the
+                    // outcome of the payment can be looked up if the task is interrupted
+                    let invoice = db
+                        .update_subscription_invoice(
+                            invoice,
+                            storage::SubscriptionInvoiceUpdate::PaymentAttempted {
+                                payment_id: payment_id.clone(),
+                                attempt_count,
+                            },
+                        )
+                        .await
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to update subscription invoice")?;
+                    let payment_status = charge_subscription_invoice(
+                        state,
+                        merchant_account,
+                        key_store,
+                        &subscription,
+                        &invoice,
+                        payment_id,
+                    )
+                    .await?;
+                    (invoice, payment_status)
+                }
+            }
+        }
+    };
+
+    match payment_status {
+        InvoicePaymentStatus::Succeeded => {
+            let invoice = db
+                .update_subscription_invoice(invoice, storage::SubscriptionInvoiceUpdate::Paid)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to update subscription invoice")?;
+            let subscription = db
+                .update_subscription(
+                    subscription,
+                    storage::SubscriptionUpdate::BillingPeriodUpdate {
+                        current_period_start: invoice.period_start,
+                        current_period_end: invoice.period_end,
+                    },
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to update subscription")?;
+            trigger_subscription_webhook(state, merchant_account, key_store, &subscription).await;
+
+            Ok(SubscriptionBillingOutcome::Reschedule(invoice.period_end))
+        }
+        InvoicePaymentStatus::Pending => Ok(SubscriptionBillingOutcome::Retry(
+            common_utils::date_time::now().saturating_add(time::Duration::seconds(
+                consts::SUBSCRIPTION_PAYMENT_STATUS_CHECK_INTERVAL_IN_SECS,
+            )),
+        )),
+        InvoicePaymentStatus::Failed {
+            error_code,
+            error_message,
+        } => {
+            let next_attempt_at = usize::try_from(invoice.attempt_count - 1)
+                .ok()
+                .and_then(|index| consts::SUBSCRIPTION_DUNNING_RETRY_INTERVALS_IN_HOURS.get(index))
+                .map(|hours| {
+                    common_utils::date_time::now().saturating_add(time::Duration::hours(*hours))
+                });
+            logger::info!(
+                invoice_id = %invoice.invoice_id,
+                attempt_count = invoice.attempt_count,
+                ?error_code,
+                ?next_attempt_at,
+                "Payment for subscription invoice failed"
+            );
+            let invoice = db
+                .update_subscription_invoice(
+                    invoice,
+                    storage::SubscriptionInvoiceUpdate::PaymentFailed {
+                        error_code,
+                        error_message,
+                        next_attempt_at,
+                    },
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to update subscription invoice")?;
+
+            match next_attempt_at {
+                Some(next_attempt_at) => {
+                    if subscription.status != enums::SubscriptionStatus::PastDue {
+                        let subscription = db
+                            .update_subscription(
+                                subscription,
+                                storage::SubscriptionUpdate::StatusUpdate {
+                                    status: enums::SubscriptionStatus::PastDue,
+                                },
+                            )
+                            .await
+                            .change_context(errors::ApiErrorResponse::InternalServerError)
+                            .attach_printable("Failed to update subscription")?;
+                        trigger_subscription_webhook(
+                            state,
+                            merchant_account,
+                            key_store,
+                            &subscription,
+                        )
+                        .await;
+                    }
+                    Ok(SubscriptionBillingOutcome::Retry(next_attempt_at))
+                }
+                None => {
+                    // The invoice is marked as uncollectible by the failed payment
+                    end_subscription(
+                        state,
+                        merchant_account,
+                        key_store,
+                        subscription,
+                        None,
+                        Some(format!(
+                            "All the payment attempts for invoice {} failed",
+                            invoice.invoice_id
+                        )),
+                    )
+                    .await?;
+                    Ok(SubscriptionBillingOutcome::Finish(business_status::FAILURE))
+                }
+            }
+        }
+    }
+}
+
+async fn create_subscription_invoice(
+    state: &SessionState,
+    subscription: &storage::Subscription,
+    period_start: PrimitiveDateTime,
+) -> RouterResult<storage::SubscriptionInvoice> {
+    let plan =
+        find_subscription_plan(state, &subscription.merchant_id, &subscription.plan_id).await?;
+    let period_end = get_billing_period_end(subscription.billing_cycle_anchor, &plan, period_start)
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to compute the end of the billing period")?;
+
+    let now = common_utils::date_time::now();
+    state
+        .store
+        .insert_subscription_invoice(storage::SubscriptionInvoiceNew {
+            invoice_id: common_utils::generate_id(consts::ID_LENGTH, "inv"),
+            subscription_id: subscription.subscription_id.clone(),
+            merchant_id: subscription.merchant_id.clone(),
+            amount: plan.amount,
+            currency: plan.currency,
+            status: enums::SubscriptionInvoiceStatus::Open,
+            period_start,
+            period_end,
+            payment_id: None,
+            attempt_count: 0,
+            next_attempt_at: None,
+            error_code: None,
+            error_message: None,
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert subscription invoice")
+}
+
+/// Look up the outcome of the payment created for an invoice. The payment is created again with
+/// the same payment ID if the task was interrupted before it could be created.
+async fn get_invoice_payment_status(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    subscription: &storage::Subscription,
+    invoice: &storage::SubscriptionInvoice,
+    payment_id: &str,
+) -> RouterResult<InvoicePaymentStatus> {
+    match find_invoice_payment_status(state, merchant_account, key_store, payment_id).await? {
+        Some(payment_status) => Ok(payment_status),
+        None => {
+            charge_subscription_invoice(
+                state,
+                merchant_account,
+                key_store,
+                subscription,
+                invoice,
+                payment_id.to_owned(),
+            )
+            .await
+        }
+    }
+}
+
+/// Look up the status of a payment created for an invoice, `None` if the payment does not exist.
+async fn find_invoice_payment_status(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_id: &str,
+) -> RouterResult<Option<InvoicePaymentStatus>> {
+    let db = &*state.store;
+    let payment_intent = match db
+        .find_payment_intent_by_payment_id_merchant_id(
+            payment_id,
+            &merchant_account.merchant_id,
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+    {
+        Ok(payment_intent) => payment_intent,
+        Err(error) if error.current_context().is_db_not_found() => return Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to find payment of subscription invoice")?,
+    };
+
+    let payment_attempt = db
+        .find_payment_attempt_by_attempt_id_merchant_id(
+            &payment_intent.active_attempt.get_id(),
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .map_err(|error| logger::warn!(?error, "Failed to find payment attempt"))
+        .ok();
+
+    Ok(Some(InvoicePaymentStatus::from_intent_status(
+        payment_intent.status,
+        payment_attempt
+            .as_ref()
+            .and_then(|attempt| attempt.error_code.clone()),
+        payment_attempt.and_then(|attempt| attempt.error_message),
+    )))
+}
+
+/// Charge an invoice off-session with the mandate of the subscription.
+async fn charge_subscription_invoice(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    subscription: &storage::Subscription,
+    invoice: &storage::SubscriptionInvoice,
+    payment_id: String,
+) -> RouterResult<InvoicePaymentStatus> {
+    let payment_request = payment_types::PaymentsRequest {
+        payment_id: Some(payment_types::PaymentIdType::PaymentIntentId(
+            payment_id.clone(),
+        )),
+        amount: Some(payment_types::Amount::from(invoice.amount)),
+        currency: Some(invoice.currency),
+        customer_id: Some(subscription.customer_id.clone()),
+        profile_id: Some(subscription.profile_id.clone()),
+        confirm: Some(true),
+        off_session: Some(true),
+        capture_method: Some(enums::CaptureMethod::Automatic),
+        recurring_details: Some(api_models::mandates::RecurringDetails::MandateId(
+            subscription.mandate_id.clone(),
+        )),
+        description: Some(format!(
+            "Invoice {} of subscription {}",
+            invoice.invoice_id, subscription.subscription_id
+        )),
+        // Declined invoice payments are retried by the dunning schedule of the subscription
+        feature_metadata: Some(payment_types::FeatureMetadata {
+            smart_retry_enabled: Some(false),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let result = Box::pin(payments::payments_core::<
+        api::Authorize,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+    >(
+        state.clone(),
+        state.get_req_state(),
+        merchant_account.clone(),
+        key_store.clone(),
+        payments::PaymentCreate,
+        payment_request,
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        None,
+        api::HeaderPayload::default(),
+    ))
+    .await;
+
+    match result {
+        Ok(services::ApplicationResponse::Json(payment_response))
+        | Ok(services::ApplicationResponse::JsonWithHeaders((payment_response, _))) => {
+            Ok(InvoicePaymentStatus::from_intent_status(
+                payment_response.status,
+                payment_response.error_code,
+                payment_response.error_message,
+            ))
+        }
+        Ok(_) => Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Unexpected response received when creating invoice payment"),
+        Err(error) => {
+            logger::warn!(?error, "Failed to create payment for subscription invoice");
+            // The payment may have been processed by the connector even though an error was
+            // returned, its outcome is then looked up when the task is run again. It has failed
+            // only if it was not created at all.
+            match find_invoice_payment_status(state, merchant_account, key_store, &payment_id).await
+            {
+                Ok(None) => {
+                    let error = error.current_context();
+                    Ok(InvoicePaymentStatus::Failed {
+                        error_code: Some(error.error_code()),
+                        error_message: Some(error.error_message()),
+                    })
+                }
+                Ok(Some(_)) => Ok(InvoicePaymentStatus::Pending),
+                Err(error) => {
+                    logger::warn!(?error, "Failed to find payment of subscription invoice");
+                    Ok(InvoicePaymentStatus::Pending)
+                }
+            }
+        }
+    }
+}
+
+/// The event raised for the current status of a subscription, if any
+pub fn get_subscription_event_type(status: enums::SubscriptionStatus) -> Option<enums::EventType> {
+    match status {
+        enums::SubscriptionStatus::Active => Some(enums::EventType::SubscriptionRenewed),
+        enums::SubscriptionStatus::PastDue => Some(enums::EventType::SubscriptionPastDue),
+        enums::SubscriptionStatus::Cancelled => Some(enums::EventType::SubscriptionCancelled),
+        enums::SubscriptionStatus::Incomplete | enums::SubscriptionStatus::Trialing => None,
+    }
+}
+
+/// The primary object ID of a subscription event includes the time of the update, so that the
+/// events raised for every renewal of the same subscription are not treated as duplicates
+fn get_subscription_event_object_id(subscription: &storage::Subscription) -> String {
+    format!(
+        "{}_{}",
+        subscription.subscription_id,
+        subscription.modified_at.assume_utc().unix_timestamp()
+    )
+}
+
+/// Extract the subscription ID from the primary object ID of a subscription event
+pub fn get_subscription_id_from_event_object_id(primary_object_id: &str) -> &str {
+    primary_object_id
+        .rsplit_once('_')
+        .map_or(primary_object_id, |(subscription_id, _)| subscription_id)
+}
+
+async fn trigger_subscription_webhook(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    subscription: &storage::Subscription,
+) {
+    let Some(event_type) = get_subscription_event_type(subscription.status) else {
+        return;
+    };
+
+    let business_profile = match state
+        .store
+        .find_business_profile_by_profile_id(&subscription.profile_id)
+        .await
+    {
+        Ok(business_profile) => business_profile,
+        Err(error) => {
+            logger::error!(?error, "Failed to fetch business profile for subscription");
+            return;
+        }
+    };
+
+    let result = Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account.clone(),
+        business_profile,
+        key_store,
+        event_type,
+        enums::EventClass::Subscriptions,
+        get_subscription_event_object_id(subscription),
+        enums::EventObjectType::SubscriptionDetails,
+        api::OutgoingWebhookContent::SubscriptionDetails(Box::new(
+            subscription_types::SubscriptionResponse::foreign_from(subscription.clone()),
+        )),
+        Some(subscription.created_at),
+    ))
+    .await;
+
+    if let Err(error) = result {
+        logger::error!(
+            ?error,
+            subscription_id = %subscription.subscription_id,
+            %event_type,
+            "Failed to trigger outgoing webhook for subscription"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn get_plan(
+        billing_interval: enums::SubscriptionInterval,
+        billing_interval_count: i32,
+    ) -> storage::SubscriptionPlan {
+        storage::SubscriptionPlan {
+            plan_id: "plan_test".to_string(),
+            merchant_id: "merchant_test".to_string(),
+            name: "Test plan".to_string(),
+            description: None,
+            amount: common_utils::types::MinorUnit::new(1000),
+            currency: enums::Currency::USD,
+            billing_interval,
+            billing_interval_count,
+            trial_period_days: None,
+            active: true,
+            metadata: None,
+            created_at: datetime!(2024-01-01 0:00),
+            modified_at: datetime!(2024-01-01 0:00),
+        }
+    }
+
+    #[test]
+    fn test_add_months() {
+        assert_eq!(
+            add_months(datetime!(2024-01-15 10:30), 1).unwrap(),
+            datetime!(2024-02-15 10:30)
+        );
+        // The day of the month is clamped to the last day of shorter months
+        assert_eq!(
+            add_months(datetime!(2024-01-31 0:00), 1).unwrap(),
+            datetime!(2024-02-29 0:00)
+        );
+        assert_eq!(
+            add_months(datetime!(2023-01-31 0:00), 1).unwrap(),
+            datetime!(2023-02-28 0:00)
+        );
+        assert_eq!(
+            add_months(datetime!(2024-01-31 0:00), 3).unwrap(),
+            datetime!(2024-04-30 0:00)
+        );
+        assert_eq!(
+            add_months(datetime!(2024-11-30 0:00), 14).unwrap(),
+            datetime!(2026-01-30 0:00)
+        );
+    }
+
+    #[test]
+    fn test_get_billing_period_end() {
+        let anchor = datetime!(2024-01-31 10:00);
+        let monthly_plan = get_plan(enums::SubscriptionInterval::Month, 1);
+        assert_eq!(
+            get_billing_period_end(anchor, &monthly_plan, anchor),
+            Some(datetime!(2024-02-29 10:00))
+        );
+        // The periods stay aligned on the day of the month of the anchor after shorter months
+        assert_eq!(
+            get_billing_period_end(anchor, &monthly_plan, datetime!(2024-02-29 10:00)),
+            Some(datetime!(2024-03-31 10:00))
+        );
+        assert_eq!(
+            get_billing_period_end(
+                anchor,
+                &get_plan(enums::SubscriptionInterval::Week, 2),
+                datetime!(2024-02-14 10:00)
+            ),
+            Some(datetime!(2024-02-28 10:00))
+        );
+        assert_eq!(
+            get_billing_period_end(
+                anchor,
+                &get_plan(enums::SubscriptionInterval::Year, 1),
+                anchor
+            ),
+            Some(datetime!(2025-01-31 10:00))
+        );
+    }
+
+    #[test]
+    fn test_invoice_payment_status_from_intent_status() {
+        assert_eq!(
+            InvoicePaymentStatus::from_intent_status(enums::IntentStatus::Succeeded, None, None),
+            InvoicePaymentStatus::Succeeded
+        );
+        // Off-session payments requiring the customer are not completed
+        assert_eq!(
+            InvoicePaymentStatus::from_intent_status(
+                enums::IntentStatus::RequiresCustomerAction,
+                Some("authentication_required".to_string()),
+                None
+            ),
+            InvoicePaymentStatus::Failed {
+                error_code: Some("authentication_required".to_string()),
+                error_message: None,
+            }
+        );
+        assert_eq!(
+            InvoicePaymentStatus::from_intent_status(enums::IntentStatus::Processing, None, None),
+            InvoicePaymentStatus::Pending
+        );
+    }
+
+    #[test]
+    fn test_get_list_limit() {
+        assert_eq!(
+            get_list_limit(None).unwrap(),
+            i64::from(consts::DEFAULT_SUBSCRIPTION_LIST_LIMIT)
+        );
+        assert_eq!(get_list_limit(Some(5)).unwrap(), 5);
+        assert!(get_list_limit(Some(consts::MAX_SUBSCRIPTION_LIST_LIMIT + 1)).is_err());
+    }
+
+    #[test]
+    fn test_get_subscription_id_from_event_object_id() {
+        assert_eq!(
+            get_subscription_id_from_event_object_id("sub_abc123_1720000000"),
+            "sub_abc123"
+        );
+    }
+
+    #[test]
+    fn test_get_subscription_lock_action() {
+        assert!(matches!(
+            get_subscription_lock_action("sub_abc123"),
+            api_locking::LockAction::Hold { input }
+                if input.unique_locking_key == "sub_abc123"
+                    && matches!(input.api_identifier, lock_utils::ApiIdentifier::Subscriptions)
+        ));
+    }
+}
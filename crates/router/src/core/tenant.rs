@@ -0,0 +1,466 @@
+//! Provisioning of tenants at runtime.
+//!
+//! Tenants provisioned through the admin API are recorded in the global schema, and served in
+//! addition to the tenants declared in the `multitenancy` configuration. Changes to a tenant are
+//! applied on the instance handling the request, and announced to all the other instances of the
+//! router, scheduler and drainer on the tenant registry channel.
+//!
+//! The schema of a tenant is migrated in the background after the tenant is recorded, the tenant
+//! is served once its schema has been migrated.
+
+use api_models::tenant as tenant_api_types;
+use common_utils::{consts::TENANT_REGISTRY_CHANNEL, DbConnectionParams};
+use diesel::{
+    migration::{Migration, MigrationSource},
+    pg::Pg,
+    Connection, PgConnection, RunQueryDsl,
+};
+use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+use error_stack::{report, ResultExt};
+use redis_interface::PubsubInterface;
+use router_env::{instrument, logger, tracing, tracing::Instrument};
+use storage_impl::redis::kv_store::RedisConnInterface;
+
+use crate::{
+    core::{
+        api_locking,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    },
+    db::{tenant::TenantInterface, StorageImpl},
+    routes::{lock_utils, AppState},
+    services,
+    types::{storage, storage::enums, transformers::ForeignInto},
+    SessionState,
+};
+
+const MIGRATIONS: EmbeddedMigrations = embed_migrations!("../../migrations");
+
+/// Migrations of the tables of the global schema, which are not run on the schemas of tenants
+const GLOBAL_SCHEMA_MIGRATIONS: [&str; 2] = [
+    "2024-07-04-101530_create_tenant_table",
+    "2024-07-12-091244_add_unique_index_on_tenant_redis_key_prefix",
+];
+
+/// Migrations run on the schema of a tenant, all the migrations except the ones of the global
+/// schema
+struct TenantSchemaMigrations;
+
+impl MigrationSource<Pg> for TenantSchemaMigrations {
+    fn migrations(&self) -> diesel::migration::Result<Vec<Box<dyn Migration<Pg>>>> {
+        Ok(MigrationSource::<Pg>::migrations(&MIGRATIONS)?
+            .into_iter()
+            .filter(|migration| !is_global_schema_migration(&migration.name().to_string()))
+            .collect())
+    }
+}
+
+/// Maximum length of a Postgres identifier
+const MAX_IDENTIFIER_LENGTH: usize = 63;
+
+const DUPLICATE_TENANT_MESSAGE: &str =
+    "Tenant with the given tenant_id, schema_name or redis_key_prefix already exists";
+
+#[instrument(skip_all)]
+pub async fn create_tenant(
+    state: SessionState,
+    app_state: AppState,
+    request: tenant_api_types::TenantCreateRequest,
+) -> RouterResponse<tenant_api_types::TenantResponse> {
+    validate_multitenancy_enabled(&state)?;
+
+    let schema_name = request
+        .schema_name
+        .unwrap_or_else(|| request.tenant_id.clone());
+    let redis_key_prefix = request
+        .redis_key_prefix
+        .unwrap_or_else(|| request.tenant_id.clone());
+    validate_identifier("tenant_id", &request.tenant_id)?;
+    validate_identifier("schema_name", &schema_name)?;
+    validate_identifier("redis_key_prefix", &redis_key_prefix)?;
+
+    let multitenancy = &state.conf.multitenancy;
+    if multitenancy.get_tenant(&request.tenant_id).is_some()
+        || multitenancy.global_tenant.schema == schema_name
+        || multitenancy.global_tenant.redis_key_prefix == redis_key_prefix
+        || multitenancy.get_tenants().values().any(|tenant| {
+            tenant.schema == schema_name || tenant.redis_key_prefix == redis_key_prefix
+        })
+    {
+        Err(errors::ApiErrorResponse::GenericDuplicateError {
+            message: DUPLICATE_TENANT_MESSAGE.to_string(),
+        })?
+    }
+
+    let now = common_utils::date_time::now();
+    let tenant = state
+        .global_store
+        .insert_tenant(storage::TenantNew {
+            tenant_id: request.tenant_id,
+            name: request.name,
+            base_url: request.base_url,
+            schema_name,
+            redis_key_prefix,
+            clickhouse_database: request.clickhouse_database,
+            status: enums::TenantStatus::Provisioning,
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: DUPLICATE_TENANT_MESSAGE.to_string(),
+        })?;
+
+    let lock_action = lock_provisioning(&state, &tenant.tenant_id).await?;
+    spawn_provisioning(state, app_state, tenant.tenant_id.clone(), lock_action);
+
+    Ok(services::ApplicationResponse::Json(tenant.foreign_into()))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_tenant(
+    state: SessionState,
+    tenant_id: String,
+) -> RouterResponse<tenant_api_types::TenantResponse> {
+    validate_multitenancy_enabled(&state)?;
+
+    find_tenant(&state, &tenant_id)
+        .await
+        .map(|tenant| services::ApplicationResponse::Json(tenant.foreign_into()))
+}
+
+#[instrument(skip_all)]
+pub async fn list_tenants(
+    state: SessionState,
+) -> RouterResponse<Vec<tenant_api_types::TenantResponse>> {
+    validate_multitenancy_enabled(&state)?;
+
+    let tenants = state
+        .global_store
+        .list_tenants()
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the tenants")?;
+
+    Ok(services::ApplicationResponse::Json(
+        tenants.into_iter().map(ForeignInto::foreign_into).collect(),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn suspend_tenant(
+    state: SessionState,
+    app_state: AppState,
+    tenant_id: String,
+) -> RouterResponse<tenant_api_types::TenantResponse> {
+    validate_multitenancy_enabled(&state)?;
+
+    let tenant = find_tenant(&state, &tenant_id).await?;
+    if matches!(
+        tenant.status,
+        enums::TenantStatus::Provisioning | enums::TenantStatus::ProvisioningFailed
+    ) {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Only tenants which have been provisioned can be suspended".to_string(),
+        })?
+    }
+
+    let tenant = update_tenant_status(&state, &tenant_id, enums::TenantStatus::Suspended).await?;
+
+    app_state.deregister_tenant(&tenant.tenant_id);
+    publish_tenant_change(&app_state, &tenant.tenant_id).await?;
+
+    Ok(services::ApplicationResponse::Json(tenant.foreign_into()))
+}
+
+/// Resumes a suspended tenant. Provisioning is started again for tenants which have not been
+/// provisioned, the migrations already run on the schema of the tenant are not run again.
+#[instrument(skip_all)]
+pub async fn resume_tenant(
+    state: SessionState,
+    app_state: AppState,
+    tenant_id: String,
+) -> RouterResponse<tenant_api_types::TenantResponse> {
+    validate_multitenancy_enabled(&state)?;
+
+    let tenant = find_tenant(&state, &tenant_id).await?;
+    if matches!(
+        tenant.status,
+        enums::TenantStatus::Provisioning | enums::TenantStatus::ProvisioningFailed
+    ) {
+        let lock_action = lock_provisioning(&state, &tenant_id).await?;
+        let tenant =
+            match update_tenant_status(&state, &tenant_id, enums::TenantStatus::Provisioning).await
+            {
+                Ok(tenant) => tenant,
+                Err(error) => {
+                    lock_action
+                        .free_lock_action(&state, tenant_id.clone())
+                        .await?;
+                    return Err(error);
+                }
+            };
+        spawn_provisioning(state, app_state, tenant_id, lock_action);
+        return Ok(services::ApplicationResponse::Json(tenant.foreign_into()));
+    }
+
+    let tenant = update_tenant_status(&state, &tenant_id, enums::TenantStatus::Active).await?;
+
+    app_state
+        .register_tenant(&tenant.tenant_id, tenant.clone().into())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to register the tenant")?;
+    publish_tenant_change(&app_state, &tenant.tenant_id).await?;
+
+    Ok(services::ApplicationResponse::Json(tenant.foreign_into()))
+}
+
+/// Deletes the tenant from the registry. The schema of the tenant and its data are retained.
+#[instrument(skip_all)]
+pub async fn delete_tenant(
+    state: SessionState,
+    app_state: AppState,
+    tenant_id: String,
+) -> RouterResponse<tenant_api_types::TenantDeleteResponse> {
+    validate_multitenancy_enabled(&state)?;
+
+    let deleted = state
+        .global_store
+        .delete_tenant_by_tenant_id(&tenant_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Tenant with the given tenant_id does not exist".to_string(),
+        })?;
+
+    app_state.deregister_tenant(&tenant_id);
+    publish_tenant_change(&app_state, &tenant_id).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        tenant_api_types::TenantDeleteResponse { tenant_id, deleted },
+    ))
+}
+
+async fn find_tenant(state: &SessionState, tenant_id: &str) -> RouterResult<storage::Tenant> {
+    state
+        .global_store
+        .find_tenant_by_tenant_id(tenant_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Tenant with the given tenant_id does not exist".to_string(),
+        })
+}
+
+async fn update_tenant_status(
+    state: &SessionState,
+    tenant_id: &str,
+    status: enums::TenantStatus,
+) -> RouterResult<storage::Tenant> {
+    state
+        .global_store
+        .update_tenant_by_tenant_id(tenant_id, storage::TenantUpdate::StatusUpdate { status })
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Tenant with the given tenant_id does not exist".to_string(),
+        })
+}
+
+fn validate_multitenancy_enabled(state: &SessionState) -> RouterResult<()> {
+    common_utils::fp_utils::when(!state.conf.multitenancy.enabled, || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Multitenancy is not enabled".to_string(),
+        }))
+    })
+}
+
+/// Validates that the value can be used as an unquoted Postgres identifier, since tenant IDs are
+/// used as schema names by default
+fn validate_identifier(field_name: &str, value: &str) -> RouterResult<()> {
+    let is_valid = value.len() <= MAX_IDENTIFIER_LENGTH
+        && value
+            .chars()
+            .next()
+            .is_some_and(|first| first.is_ascii_lowercase() || first == '_')
+        && value
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+
+    common_utils::fp_utils::when(!is_valid, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "{field_name} must start with a lowercase letter or an underscore, contain only \
+                 lowercase letters, digits and underscores, and be at most \
+                 {MAX_IDENTIFIER_LENGTH} characters long"
+            ),
+        }))
+    })
+}
+
+fn get_provisioning_lock_action(tenant_id: &str) -> api_locking::LockAction {
+    api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: format!("provisioning_{tenant_id}"),
+            api_identifier: lock_utils::ApiIdentifier::Tenant,
+            // Provisioning is not waited for, concurrent requests are rejected right away
+            override_lock_retries: Some(1),
+        },
+    }
+}
+
+/// Acquires the lock held while the tenant is being provisioned, so that the schema of a tenant is
+/// never migrated by concurrent requests
+async fn lock_provisioning(
+    state: &SessionState,
+    tenant_id: &str,
+) -> RouterResult<api_locking::LockAction> {
+    let lock_action = get_provisioning_lock_action(tenant_id);
+    lock_action
+        .clone()
+        .perform_locking_action(state, tenant_id.to_owned())
+        .await?;
+    Ok(lock_action)
+}
+
+/// Provisions the tenant in the background, since migrating its schema may take longer than a
+/// request is allowed to take. The provisioning lock is released once done.
+fn spawn_provisioning(
+    state: SessionState,
+    app_state: AppState,
+    tenant_id: String,
+    lock_action: api_locking::LockAction,
+) {
+    let _task_handle = tokio::spawn(
+        async move {
+            match provision_tenant(&state, &app_state, &tenant_id).await {
+                Ok(()) => logger::info!(%tenant_id, "Provisioned tenant"),
+                Err(error) => logger::error!(?error, %tenant_id, "Failed to provision tenant"),
+            }
+            lock_action
+                .free_lock_action(&state, tenant_id)
+                .await
+                .map_err(|error| logger::error!(?error, "Failed to release the provisioning lock"))
+                .ok();
+        }
+        .in_current_span(),
+    );
+}
+
+/// Migrates the schema of the tenant and registers it, the tenant is marked as failed to be
+/// provisioned if its schema could not be migrated
+async fn provision_tenant(
+    state: &SessionState,
+    app_state: &AppState,
+    tenant_id: &str,
+) -> RouterResult<()> {
+    let tenant = find_tenant(state, tenant_id).await?;
+
+    if app_state.storage_impl != StorageImpl::Mock {
+        if let Err(error) = run_schema_migrations(state, &tenant.schema_name).await {
+            update_tenant_status(state, tenant_id, enums::TenantStatus::ProvisioningFailed)
+                .await
+                .map_err(|error| logger::error!(?error, "Failed to update the tenant status"))
+                .ok();
+            return Err(error);
+        }
+    }
+
+    let tenant = update_tenant_status(state, tenant_id, enums::TenantStatus::Active).await?;
+    app_state
+        .register_tenant(&tenant.tenant_id, tenant.clone().into())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to register the tenant")?;
+    publish_tenant_change(app_state, &tenant.tenant_id).await
+}
+
+fn is_global_schema_migration(migration_name: &str) -> bool {
+    GLOBAL_SCHEMA_MIGRATIONS.contains(&migration_name)
+}
+
+/// Creates the schema of the tenant and runs the pending migrations on it
+async fn run_schema_migrations(state: &SessionState, schema_name: &str) -> RouterResult<()> {
+    let database_url =
+        storage_impl::config::Database::from(state.conf.master_database.get_inner().clone())
+            .get_database_url(schema_name);
+    let create_schema_query = format!("CREATE SCHEMA IF NOT EXISTS \"{schema_name}\"");
+
+    tokio::task::spawn_blocking(move || {
+        let mut conn = PgConnection::establish(&database_url)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to connect to the database")?;
+        diesel::sql_query(create_schema_query)
+            .execute(&mut conn)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to create the schema of the tenant")?;
+        conn.run_pending_migrations(TenantSchemaMigrations)
+            .map_err(|error| {
+                report!(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable(format!("Failed to migrate the schema: {error}"))
+            })?;
+        Ok(())
+    })
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to run the schema migrations")?
+}
+
+/// Announces the change to the tenant to the other instances, which reload it from the global
+/// store
+async fn publish_tenant_change(app_state: &AppState, tenant_id: &str) -> RouterResult<()> {
+    let redis_conn = app_state
+        .cache_store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let _receivers: usize = redis_conn
+        .publisher
+        .publish(TENANT_REGISTRY_CHANNEL, tenant_id.to_owned())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to publish the change to the tenant")?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_validate_identifier() {
+        assert!(validate_identifier("tenant_id", "acme").is_ok());
+        assert!(validate_identifier("tenant_id", "_acme_2").is_ok());
+        assert!(validate_identifier("tenant_id", &"a".repeat(MAX_IDENTIFIER_LENGTH)).is_ok());
+
+        assert!(validate_identifier("tenant_id", "").is_err());
+        assert!(validate_identifier("tenant_id", "2acme").is_err());
+        assert!(validate_identifier("tenant_id", "Acme").is_err());
+        assert!(validate_identifier("redis_key_prefix", "acme:").is_err());
+        assert!(validate_identifier("schema_name", "acme\"; DROP SCHEMA public").is_err());
+        assert!(validate_identifier("tenant_id", &"a".repeat(MAX_IDENTIFIER_LENGTH + 1)).is_err());
+    }
+
+    #[test]
+    fn test_tenant_schema_migrations_exclude_global_schema_migrations() {
+        let all_migrations = MigrationSource::<Pg>::migrations(&MIGRATIONS)
+            .unwrap()
+            .iter()
+            .map(|migration| migration.name().to_string())
+            .collect::<Vec<_>>();
+        let tenant_migrations = MigrationSource::<Pg>::migrations(&TenantSchemaMigrations)
+            .unwrap()
+            .iter()
+            .map(|migration| migration.name().to_string())
+            .collect::<Vec<_>>();
+
+        for migration_name in GLOBAL_SCHEMA_MIGRATIONS {
+            assert!(all_migrations.iter().any(|name| name == migration_name));
+            assert!(!tenant_migrations.iter().any(|name| name == migration_name));
+        }
+        assert_eq!(
+            tenant_migrations.len() + GLOBAL_SCHEMA_MIGRATIONS.len(),
+            all_migrations.len()
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use api_models::{
     payments::RedirectionResponse,
@@ -9,7 +9,9 @@ use diesel_models::user_role::UserRoleUpdate;
 use diesel_models::{
     enums::{TotpStatus, UserStatus},
     user as storage_user,
-    user_authentication_method::{UserAuthenticationMethodNew, UserAuthenticationMethodUpdate},
+    user_authentication_method::{
+        UserAuthenticationMethod, UserAuthenticationMethodNew, UserAuthenticationMethodUpdate,
+    },
     user_role::UserRoleNew,
 };
 use error_stack::{report, ResultExt};
@@ -28,7 +30,9 @@ use crate::services::email::types as email_types;
 use crate::{
     consts,
     routes::{app::ReqState, SessionState},
-    services::{authentication as auth, authorization::roles, openidconnect, ApplicationResponse},
+    services::{
+        authentication as auth, authorization::roles, openidconnect, saml, ApplicationResponse,
+    },
     types::{domain, transformers::ForeignInto},
     utils::{self, user::two_factor_auth as tfa_utils},
 };
@@ -2036,6 +2040,10 @@ pub async fn create_user_authentication_method(
     .change_context(UserErrors::InternalServerError)
     .attach_printable("Failed to decode DEK")?;
 
+    if let user_api::AuthConfig::Saml { private_config, .. } = &req.auth_method {
+        validate_saml_config(&state, &req.owner_id, req.owner_type, private_config).await?;
+    }
+
     let (private_config, public_config) = utils::user::construct_public_and_private_db_configs(
         &req.auth_method,
         &user_auth_encryption_key,
@@ -2071,6 +2079,19 @@ pub async fn create_user_authentication_method(
                 let req_auth_name = public_config.name;
                 db_auth_name.is_some_and(|name| name == req_auth_name)
             }
+            user_api::AuthConfig::Saml { public_config, .. } => {
+                let db_auth_name = db_auth_method
+                    .public_config
+                    .map(|config| {
+                        utils::user::parse_value::<user_api::SamlPublicConfig>(
+                            config,
+                            "SamlPublicConfig",
+                        )
+                    })
+                    .transpose()?
+                    .map(|config| config.name);
+                db_auth_name.is_some_and(|name| name == public_config.name)
+            }
             user_api::AuthConfig::Password | user_api::AuthConfig::MagicLink => true,
         };
         if is_type_same && is_extra_identifier_same {
@@ -2115,6 +2136,21 @@ pub async fn update_user_authentication_method(
     .change_context(UserErrors::InternalServerError)
     .attach_printable("Failed to decode DEK")?;
 
+    if let user_api::AuthConfig::Saml { private_config, .. } = &req.auth_method {
+        let user_authentication_method = state
+            .store
+            .get_user_authentication_method_by_id(&req.id)
+            .await
+            .to_not_found_response(UserErrors::InvalidUserAuthMethodOperation)?;
+        validate_saml_config(
+            &state,
+            &user_authentication_method.owner_id,
+            user_authentication_method.owner_type,
+            private_config,
+        )
+        .await?;
+    }
+
     let (private_config, public_config) = utils::user::construct_public_and_private_db_configs(
         &req.auth_method,
         &user_auth_encryption_key,
@@ -2191,6 +2227,10 @@ pub async fn get_sso_auth_url(
         .await
         .to_not_found_response(UserErrors::InvalidUserAuthMethodOperation)?;
 
+    if user_authentication_method.auth_type == common_enums::UserAuthType::Saml {
+        return get_saml_auth_url(&state, user_authentication_method).await;
+    }
+
     let open_id_private_config =
         utils::user::decrypt_oidc_private_config(&state, user_authentication_method.private_config)
             .await?;
@@ -2229,6 +2269,339 @@ pub async fn get_sso_auth_url(
     })
 }
 
+async fn get_saml_auth_url(
+    state: &SessionState,
+    user_authentication_method: UserAuthenticationMethod,
+) -> UserResponse<()> {
+    let saml_private_config =
+        utils::user::decrypt_saml_private_config(state, user_authentication_method.private_config)
+            .await?;
+    let idp_metadata = saml::parse_idp_metadata(&saml_private_config.idp_metadata)?;
+
+    // The request ID is sent as the relay state, and is used as the SSO state while signing in
+    let request_id = Secret::new(common_utils::generate_id(consts::ID_LENGTH, "saml"));
+    utils::user::set_sso_id_in_redis(
+        state,
+        request_id.clone(),
+        user_authentication_method.id.clone(),
+    )
+    .await?;
+
+    saml::get_authorization_url(
+        &idp_metadata,
+        &utils::user::get_saml_sp_entity_id(state, &user_authentication_method.id),
+        &utils::user::get_saml_acs_url(state, &user_authentication_method.id),
+        &request_id,
+    )
+    .map(|url| {
+        ApplicationResponse::JsonForRedirection(RedirectionResponse {
+            headers: Vec::with_capacity(0),
+            return_url: String::new(),
+            http_method: String::new(),
+            params: Vec::with_capacity(0),
+            return_url_with_query_params: url.to_string(),
+        })
+    })
+}
+
+pub async fn get_saml_sp_metadata(state: SessionState, auth_method_id: String) -> UserResponse<()> {
+    let user_authentication_method = state
+        .store
+        .get_user_authentication_method_by_id(&auth_method_id)
+        .await
+        .to_not_found_response(UserErrors::InvalidUserAuthMethodOperation)?;
+
+    if user_authentication_method.auth_type != common_enums::UserAuthType::Saml {
+        return Err(report!(UserErrors::InvalidUserAuthMethodOperation))
+            .attach_printable("Auth method is not of type SAML");
+    }
+
+    let sp_metadata = saml::generate_sp_metadata(
+        &utils::user::get_saml_sp_entity_id(&state, &auth_method_id),
+        &utils::user::get_saml_acs_url(&state, &auth_method_id),
+    );
+
+    Ok(ApplicationResponse::FileData((
+        sp_metadata.into_bytes(),
+        mime::TEXT_XML,
+    )))
+}
+
+/// Assertion consumer service, the SAML response posted by the IdP is validated and the user is
+/// provisioned if required. The browser is then redirected to the dashboard with a single use code,
+/// which is exchanged for a token through the SSO sign in.
+pub async fn saml_acs(
+    state: SessionState,
+    auth_method_id: String,
+    request: user_api::SamlAcsRequest,
+) -> UserResponse<()> {
+    // Only SP initiated sign in is supported, as the relay state is needed to correlate the
+    // response with the request
+    let relay_state = request
+        .relay_state
+        .ok_or(UserErrors::SSOFailed)
+        .attach_printable("RelayState not found in SAML response")?;
+
+    let authentication_method_id =
+        utils::user::get_sso_id_from_redis(&state, relay_state.clone()).await?;
+    if authentication_method_id != auth_method_id {
+        return Err(report!(UserErrors::SSOFailed))
+            .attach_printable("RelayState does not belong to the auth method");
+    }
+
+    let user_authentication_method = state
+        .store
+        .get_user_authentication_method_by_id(&auth_method_id)
+        .await
+        .to_not_found_response(UserErrors::InvalidUserAuthMethodOperation)?;
+
+    let saml_private_config = utils::user::decrypt_saml_private_config(
+        &state,
+        user_authentication_method.private_config.clone(),
+    )
+    .await?;
+    let idp_metadata = saml::parse_idp_metadata(&saml_private_config.idp_metadata)?;
+
+    let assertion = saml::validate_saml_response(
+        request.saml_response,
+        &idp_metadata,
+        &utils::user::get_saml_sp_entity_id(&state, &auth_method_id),
+        &utils::user::get_saml_acs_url(&state, &auth_method_id),
+        relay_state.peek(),
+    )?;
+    utils::user::set_saml_assertion_id_in_redis(&state, &assertion.id).await?;
+
+    let email = saml_private_config
+        .email_attribute
+        .as_deref()
+        .and_then(|attribute| assertion.get_first_attribute_value(attribute))
+        .map(|email| email.to_string())
+        .unwrap_or_else(|| assertion.name_id.clone().expose());
+    let email = domain::UserEmail::new(Secret::new(email))?;
+
+    match state
+        .global_store
+        .find_user_by_email(&email.clone().into_inner())
+        .await
+    {
+        Ok(_) => (),
+        Err(error) if error.current_context().is_db_not_found() => {
+            provision_saml_user(
+                &state,
+                &user_authentication_method,
+                &saml_private_config,
+                &assertion,
+                email.clone(),
+            )
+            .await?
+        }
+        Err(error) => return Err(error.change_context(UserErrors::InternalServerError)),
+    };
+
+    let code = Secret::new(nanoid::nanoid!());
+    utils::user::set_saml_code_in_redis(
+        &state,
+        code.clone(),
+        email,
+        &auth_method_id,
+        relay_state.clone(),
+    )
+    .await?;
+
+    let mut redirect_url = url::Url::parse(&utils::user::get_saml_sso_redirect_url(&state))
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Invalid SAML redirect url")?;
+    redirect_url
+        .query_pairs_mut()
+        .append_pair("state", relay_state.peek())
+        .append_pair("code", code.peek());
+
+    Ok(ApplicationResponse::JsonForRedirection(
+        RedirectionResponse {
+            headers: Vec::with_capacity(0),
+            return_url: String::new(),
+            http_method: String::new(),
+            params: Vec::with_capacity(0),
+            return_url_with_query_params: redirect_url.to_string(),
+        },
+    ))
+}
+
+/// Just-in-time provisioning of the user signing in through SAML, roles are assigned based on the
+/// role mappings of the auth method. Roles of existing users are not modified.
+async fn provision_saml_user(
+    state: &SessionState,
+    user_authentication_method: &UserAuthenticationMethod,
+    saml_private_config: &user_api::SamlPrivateConfig,
+    assertion: &saml::SamlAssertion,
+    email: domain::UserEmail,
+) -> UserResult<()> {
+    if !user_authentication_method.allow_signup {
+        return Err(report!(UserErrors::UserNotFound))
+            .attach_printable("Signup is not allowed for the auth method");
+    }
+
+    if user_authentication_method.owner_type != common_enums::Owner::Organization {
+        return Err(report!(UserErrors::InvalidUserAuthMethodOperation))
+            .attach_printable("Users can be provisioned only for auth methods owned by an org");
+    }
+    let org_id = user_authentication_method.owner_id.clone();
+
+    let role_assignments = get_saml_role_assignments(saml_private_config, assertion);
+    let (primary_role_assignment, other_role_assignments) = role_assignments
+        .split_first()
+        .ok_or(UserErrors::SSOFailed)
+        .attach_printable("No role mapping found for the user")?;
+
+    for role_assignment in &role_assignments {
+        validate_saml_role_assignment(state, role_assignment, &org_id).await?;
+    }
+
+    let name = saml_private_config
+        .name_attribute
+        .as_deref()
+        .and_then(|attribute| assertion.get_first_attribute_value(attribute))
+        .map(|name| Secret::new(name.to_string()));
+
+    let new_user =
+        domain::NewUser::try_from((email, name, primary_role_assignment.clone(), org_id.clone()))?;
+    let user_from_db = new_user
+        .insert_user_in_db(state.global_store.as_ref())
+        .await?;
+    new_user
+        .insert_user_role_in_db(
+            state.clone(),
+            primary_role_assignment.role_id.clone(),
+            UserStatus::Active,
+        )
+        .await?;
+
+    let now = common_utils::date_time::now();
+    for role_assignment in other_role_assignments {
+        state
+            .store
+            .insert_user_role(UserRoleNew {
+                user_id: user_from_db.get_user_id().to_owned(),
+                merchant_id: role_assignment.merchant_id.clone(),
+                role_id: role_assignment.role_id.clone(),
+                org_id: org_id.clone(),
+                status: UserStatus::Active,
+                created_by: user_from_db.get_user_id().to_owned(),
+                last_modified_by: user_from_db.get_user_id().to_owned(),
+                created_at: now,
+                last_modified: now,
+            })
+            .await
+            .change_context(UserErrors::InternalServerError)?;
+    }
+
+    Ok(())
+}
+
+/// A user can have only one role in a merchant, so the first mapping matching the role attribute
+/// is used for each merchant. The default role is used if none of the mappings match.
+fn get_saml_role_assignments(
+    saml_private_config: &user_api::SamlPrivateConfig,
+    assertion: &saml::SamlAssertion,
+) -> Vec<user_api::SamlRoleAssignment> {
+    let attribute_values = saml_private_config
+        .role_attribute
+        .as_deref()
+        .map(|attribute| assertion.get_attribute_values(attribute))
+        .unwrap_or_default();
+
+    let mut role_assignments: Vec<user_api::SamlRoleAssignment> = Vec::new();
+    for role_mapping in saml_private_config
+        .role_mappings
+        .iter()
+        .filter(|role_mapping| attribute_values.contains(&role_mapping.attribute_value))
+    {
+        if !role_assignments
+            .iter()
+            .any(|assignment| assignment.merchant_id == role_mapping.assignment.merchant_id)
+        {
+            role_assignments.push(role_mapping.assignment.clone());
+        }
+    }
+
+    if role_assignments.is_empty() {
+        role_assignments.extend(saml_private_config.default_role.clone());
+    }
+
+    role_assignments
+}
+
+async fn validate_saml_config(
+    state: &SessionState,
+    owner_id: &str,
+    owner_type: common_enums::Owner,
+    saml_private_config: &user_api::SamlPrivateConfig,
+) -> UserResult<()> {
+    saml::parse_idp_metadata(&saml_private_config.idp_metadata)?;
+
+    let role_assignments = saml_private_config
+        .role_mappings
+        .iter()
+        .map(|role_mapping| &role_mapping.assignment)
+        .chain(saml_private_config.default_role.as_ref())
+        .collect::<Vec<_>>();
+
+    if role_assignments.is_empty() {
+        return Ok(());
+    }
+
+    // Provisioned users are added to the merchants of the organization owning the auth method
+    if owner_type != common_enums::Owner::Organization {
+        return Err(report!(UserErrors::InvalidUserAuthMethodOperation))
+            .attach_printable("Role mappings are supported only for auth methods owned by an org");
+    }
+
+    let merchant_ids = state
+        .store
+        .list_merchant_accounts_by_organization_id(owner_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?
+        .into_iter()
+        .map(|merchant_account| merchant_account.merchant_id)
+        .collect::<HashSet<_>>();
+
+    for role_assignment in role_assignments {
+        if !merchant_ids.contains(&role_assignment.merchant_id) {
+            return Err(report!(UserErrors::MerchantIdNotFound)).attach_printable(format!(
+                "merchant_id = {} does not belong to the org",
+                role_assignment.merchant_id
+            ));
+        }
+        validate_saml_role_assignment(state, role_assignment, owner_id).await?;
+    }
+
+    Ok(())
+}
+
+async fn validate_saml_role_assignment(
+    state: &SessionState,
+    role_assignment: &user_api::SamlRoleAssignment,
+    org_id: &str,
+) -> UserResult<()> {
+    let role_info = roles::RoleInfo::from_role_id(
+        state,
+        &role_assignment.role_id,
+        &role_assignment.merchant_id,
+        org_id,
+    )
+    .await
+    .to_not_found_response(UserErrors::InvalidRoleId)?;
+
+    if !role_info.is_invitable() {
+        return Err(report!(UserErrors::InvalidRoleId)).attach_printable(format!(
+            "role_id = {} cannot be assigned through SAML",
+            role_assignment.role_id
+        ));
+    }
+
+    Ok(())
+}
+
 pub async fn sso_sign(
     state: SessionState,
     request: user_api::SsoSignInRequest,
@@ -2243,31 +2616,44 @@ pub async fn sso_sign(
         .await
         .change_context(UserErrors::InternalServerError)?;
 
-    let open_id_private_config =
-        utils::user::decrypt_oidc_private_config(&state, user_authentication_method.private_config)
-            .await?;
+    let email = if user_authentication_method.auth_type == common_enums::UserAuthType::Saml {
+        // The assertion has already been validated on the assertion consumer service
+        utils::user::get_email_from_saml_code(
+            &state,
+            request.code,
+            &authentication_method_id,
+            &request.state,
+        )
+        .await?
+    } else {
+        let open_id_private_config = utils::user::decrypt_oidc_private_config(
+            &state,
+            user_authentication_method.private_config,
+        )
+        .await?;
 
-    let open_id_public_config = serde_json::from_value::<user_api::OpenIdConnectPublicConfig>(
-        user_authentication_method
-            .public_config
-            .ok_or(UserErrors::InternalServerError)
-            .attach_printable("Public config not present")?,
-    )
-    .change_context(UserErrors::InternalServerError)
-    .attach_printable("Unable to parse OpenIdConnectPublicConfig")?;
+        let open_id_public_config = serde_json::from_value::<user_api::OpenIdConnectPublicConfig>(
+            user_authentication_method
+                .public_config
+                .ok_or(UserErrors::InternalServerError)
+                .attach_printable("Public config not present")?,
+        )
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Unable to parse OpenIdConnectPublicConfig")?;
 
-    let redirect_url =
-        utils::user::get_oidc_sso_redirect_url(&state, &open_id_public_config.name.to_string());
-    let email = openidconnect::get_user_email_from_oidc_provider(
-        &state,
-        redirect_url,
-        request.state,
-        open_id_private_config.base_url.into(),
-        open_id_private_config.client_id,
-        request.code,
-        open_id_private_config.client_secret,
-    )
-    .await?;
+        let redirect_url =
+            utils::user::get_oidc_sso_redirect_url(&state, &open_id_public_config.name.to_string());
+        openidconnect::get_user_email_from_oidc_provider(
+            &state,
+            redirect_url,
+            request.state,
+            open_id_private_config.base_url.into(),
+            open_id_private_config.client_id,
+            request.code,
+            open_id_private_config.client_secret,
+        )
+        .await?
+    };
 
     // TODO: Use config to handle not found error
     let user_from_db = state
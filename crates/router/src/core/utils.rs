@@ -339,6 +339,7 @@ pub async fn construct_refund_router_data<'a, F>(
             connector_refund_id: refund.connector_refund_id.clone(),
             browser_info,
             charges,
+            split_refunds: refund.split_refunds.clone(),
         },
 
         response: Ok(types::RefundsResponseData {
@@ -1,7 +1,9 @@
 mod incoming;
 mod outgoing;
+pub mod signing_secrets;
 pub mod types;
 pub mod utils;
+pub mod webhook_endpoints;
 #[cfg(feature = "olap")]
 pub mod webhook_events;
 
@@ -9,7 +11,7 @@ pub(crate) use self::{
     incoming::incoming_webhooks_wrapper,
     outgoing::{
         create_event_and_trigger_outgoing_webhook, get_outgoing_webhook_request,
-        trigger_webhook_and_raise_event,
+        get_webhook_signing_keys, trigger_webhook_and_raise_event,
     },
 };
 
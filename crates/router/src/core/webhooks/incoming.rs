@@ -16,7 +16,7 @@ use super::{types, utils, MERCHANT_ID};
 use crate::{
     consts,
     core::{
-        api_locking,
+        api_locking, disputes,
         errors::{self, ConnectorErrorExt, CustomResult, RouterResponse, StorageErrorExt},
         metrics, payments, refunds, utils as core_utils,
     },
@@ -1378,6 +1378,18 @@ async fn disputes_incoming_webhook_flow(
             connector.id(),
         )
         .await?;
+        if let Err(error) = disputes::add_dispute_deadline_reminder_task(
+            db,
+            &dispute_object,
+            &state.conf.dispute_deadline_reminder.reminder_hours,
+        )
+        .await
+        {
+            logger::error!(
+                ?error,
+                "Failed to add dispute deadline reminder task to process tracker"
+            );
+        }
         let disputes_response = Box::new(dispute_object.clone().foreign_into());
         let event_type: enums::EventType = dispute_object.dispute_status.foreign_into();
 
@@ -2,10 +2,11 @@ use api_models::{
     webhook_events::{OutgoingWebhookRequestContent, OutgoingWebhookResponseContent},
     webhooks,
 };
+use base64::Engine;
 use common_utils::{ext_traits::Encode, request::RequestContent};
 use diesel_models::process_tracker::business_status;
 use error_stack::{report, ResultExt};
-use masking::{ExposeInterface, Mask, PeekInterface, Secret};
+use masking::{ExposeInterface, Mask, Maskable, PeekInterface, Secret};
 use router_env::{
     instrument,
     metrics::add_attributes,
@@ -16,6 +17,7 @@ use super::{types, utils, MERCHANT_ID};
 #[cfg(feature = "stripe")]
 use crate::compatibility::stripe::webhooks as stripe_webhooks;
 use crate::{
+    consts,
     core::{
         errors::{self, CustomResult},
         metrics,
@@ -24,7 +26,7 @@ use crate::{
     events::outgoing_webhook_logs::{
         OutgoingWebhookEvent, OutgoingWebhookEventContent, OutgoingWebhookEventMetric,
     },
-    logger,
+    headers, logger,
     routes::{app::SessionStateInfo, SessionState},
     services,
     types::{
@@ -53,25 +55,111 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
     content: api::OutgoingWebhookContent,
     primary_object_created_at: Option<time::PrimitiveDateTime>,
 ) -> CustomResult<(), errors::ApiErrorResponse> {
-    let delivery_attempt = enums::WebhookDeliveryAttempt::InitialAttempt;
-    let idempotent_event_id =
-        utils::get_idempotent_event_id(&primary_object_id, event_type, delivery_attempt);
-    let webhook_url_result = get_webhook_url_from_business_profile(&business_profile);
-
-    if !state.conf.webhooks.outgoing_enabled
-        || webhook_url_result.is_err()
-        || webhook_url_result.as_ref().is_ok_and(String::is_empty)
-    {
+    if !state.conf.webhooks.outgoing_enabled {
         logger::debug!(
             business_profile_id=%business_profile.profile_id,
-            %idempotent_event_id,
-            "Outgoing webhooks are disabled in application configuration, or merchant webhook URL \
-             could not be obtained; skipping outgoing webhooks for event"
+            "Outgoing webhooks are disabled in application configuration; skipping outgoing \
+             webhooks for event"
         );
         return Ok(());
     }
 
+    let subscribed_webhook_endpoints = state
+        .store
+        .list_webhook_endpoints_by_profile_id(&business_profile.profile_id, merchant_key_store)
+        .await
+        .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+        .attach_printable("Failed to list webhook endpoints")?
+        .into_iter()
+        .filter(|webhook_endpoint| webhook_endpoint.is_subscribed_to(event_type));
+
+    // The webhook URL configured in the business profile receives all events, in addition to the
+    // webhook endpoints subscribed to the event type
+    let is_business_profile_webhook_url_configured =
+        get_webhook_url_from_business_profile(&business_profile)
+            .is_ok_and(|webhook_url| !webhook_url.is_empty());
+    let webhook_destinations = is_business_profile_webhook_url_configured
+        .then_some(None)
+        .into_iter()
+        .chain(subscribed_webhook_endpoints.map(Some))
+        .collect::<Vec<_>>();
+
+    if webhook_destinations.is_empty() {
+        logger::debug!(
+            business_profile_id=%business_profile.profile_id,
+            ?event_type,
+            "Merchant webhook URL could not be obtained and no webhook endpoints are subscribed \
+             to the event type; skipping outgoing webhooks for event"
+        );
+        return Ok(());
+    }
+
+    let mut result = Ok(());
+    for webhook_endpoint in webhook_destinations {
+        let webhook_endpoint_id = webhook_endpoint
+            .as_ref()
+            .map(|webhook_endpoint| webhook_endpoint.endpoint_id.clone());
+
+        // Delivery to each destination is independent of the others, so that a failure for one
+        // destination does not prevent webhooks from being delivered to the remaining ones
+        if let Err(error) = Box::pin(create_event_and_trigger_outgoing_webhook_for_destination(
+            state.clone(),
+            &merchant_account,
+            business_profile.clone(),
+            merchant_key_store,
+            event_type,
+            event_class,
+            primary_object_id.clone(),
+            primary_object_type,
+            content.clone(),
+            primary_object_created_at,
+            webhook_endpoint,
+        ))
+        .await
+        {
+            logger::error!(
+                ?error,
+                ?webhook_endpoint_id,
+                "Failed to trigger outgoing webhook"
+            );
+            result = Err(error);
+        }
+    }
+
+    result
+}
+
+/// Creates an event for delivering the webhook to a single destination, which is either the
+/// webhook URL configured in the business profile (when `webhook_endpoint` is `None`), or one of
+/// the webhook endpoints configured for the business profile.
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+async fn create_event_and_trigger_outgoing_webhook_for_destination(
+    state: SessionState,
+    merchant_account: &domain::MerchantAccount,
+    business_profile: diesel_models::business_profile::BusinessProfile,
+    merchant_key_store: &domain::MerchantKeyStore,
+    event_type: enums::EventType,
+    event_class: enums::EventClass,
+    primary_object_id: String,
+    primary_object_type: enums::EventObjectType,
+    content: api::OutgoingWebhookContent,
+    primary_object_created_at: Option<time::PrimitiveDateTime>,
+    webhook_endpoint: Option<domain::WebhookEndpoint>,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let delivery_attempt = enums::WebhookDeliveryAttempt::InitialAttempt;
+    let webhook_endpoint_id = webhook_endpoint
+        .as_ref()
+        .map(|webhook_endpoint| webhook_endpoint.endpoint_id.clone());
+    let idempotent_event_id = utils::get_idempotent_event_id(
+        &primary_object_id,
+        event_type,
+        delivery_attempt,
+        webhook_endpoint_id.as_deref(),
+    );
+
     let event_id = utils::generate_event_id();
+
     let merchant_id = business_profile.merchant_id.clone();
     let now = common_utils::date_time::now();
 
@@ -83,13 +171,18 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         timestamp: now,
     };
 
-    let request_content = get_outgoing_webhook_request(
-        &merchant_account,
-        outgoing_webhook,
-        business_profile.payment_response_hash_key.as_deref(),
-    )
-    .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
-    .attach_printable("Failed to construct outgoing webhook request content")?;
+    let signing_keys = match webhook_endpoint {
+        Some(webhook_endpoint) => webhook_endpoint.get_signing_keys(now),
+        None => get_webhook_signing_keys(&state, &business_profile, merchant_key_store)
+            .await
+            .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+            .attach_printable("Failed to fetch webhook signing keys")?,
+    };
+
+    let request_content =
+        get_outgoing_webhook_request(merchant_account, outgoing_webhook, &signing_keys)
+            .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+            .attach_printable("Failed to construct outgoing webhook request content")?;
 
     let event_metadata = storage::EventMetadata::foreign_from((&content, &primary_object_id));
 
@@ -122,6 +215,7 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         response: None,
         delivery_attempt: Some(delivery_attempt),
         metadata: Some(event_metadata),
+        webhook_endpoint_id,
     };
 
     let event_insert_result = state
@@ -225,17 +319,27 @@ async fn trigger_webhook_to_merchant(
     delivery_attempt: enums::WebhookDeliveryAttempt,
     process_tracker: Option<storage::ProcessTracker>,
 ) -> CustomResult<(), errors::WebhooksFlowError> {
-    let webhook_url = match (
-        get_webhook_url_from_business_profile(&business_profile),
-        process_tracker.clone(),
-    ) {
-        (Ok(webhook_url), _) => Ok(webhook_url),
+    // The request is signed when it is sent, so that retried deliveries carry a fresh timestamp
+    // and are signed using the keys active at the time
+    let destination = get_webhook_destination(
+        &state,
+        &business_profile,
+        merchant_key_store,
+        event.webhook_endpoint_id.as_deref(),
+    )
+    .await
+    .and_then(|destination| {
+        resign_outgoing_webhook_request(request_content, &destination.signing_keys)
+            .map(|request_content| (destination, request_content))
+    });
+    let (destination, request_content) = match (destination, process_tracker.clone()) {
+        (Ok(destination), _) => Ok(destination),
         (Err(error), Some(process_tracker)) => {
             if !error
                 .current_context()
                 .is_webhook_delivery_retryable_error()
             {
-                logger::debug!("Failed to obtain merchant webhook URL, aborting retries");
+                logger::debug!("Failed to prepare webhook for delivery, aborting retries");
                 state
                     .store
                     .as_scheduler()
@@ -256,10 +360,11 @@ async fn trigger_webhook_to_merchant(
         .headers
         .into_iter()
         .map(|(name, value)| (name, value.into_masked()))
+        .chain(destination.headers)
         .collect();
     let request = services::RequestBuilder::new()
         .method(services::Method::Post)
-        .url(&webhook_url)
+        .url(&destination.url)
         .attach_default_headers()
         .headers(headers)
         .set_body(RequestContent::RawBytes(
@@ -546,15 +651,143 @@ fn get_webhook_url_from_business_profile(
         .map(ExposeInterface::expose)
 }
 
+struct WebhookDestination {
+    url: String,
+    headers: Vec<(String, Maskable<String>)>,
+    signing_keys: Vec<Secret<String>>,
+}
+
+/// Obtains the URL to which the webhook is to be delivered, along with any additional headers
+/// required by the destination and the keys for signing the webhook. Events without a webhook
+/// endpoint are delivered to the webhook URL configured in the business profile.
+async fn get_webhook_destination(
+    state: &SessionState,
+    business_profile: &diesel_models::business_profile::BusinessProfile,
+    merchant_key_store: &domain::MerchantKeyStore,
+    webhook_endpoint_id: Option<&str>,
+) -> CustomResult<WebhookDestination, errors::WebhooksFlowError> {
+    let Some(webhook_endpoint_id) = webhook_endpoint_id else {
+        let url = get_webhook_url_from_business_profile(business_profile)?;
+        let signing_keys =
+            get_webhook_signing_keys(state, business_profile, merchant_key_store).await?;
+        return Ok(WebhookDestination {
+            url,
+            headers: Vec::new(),
+            signing_keys,
+        });
+    };
+
+    let webhook_endpoint = state
+        .store
+        .find_webhook_endpoint_by_profile_id_endpoint_id(
+            &business_profile.profile_id,
+            webhook_endpoint_id,
+            merchant_key_store,
+        )
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(errors::WebhooksFlowError::WebhookEndpointUnavailable)
+            } else {
+                error.change_context(errors::WebhooksFlowError::WebhookEndpointFetchFailed)
+            }
+        })?;
+
+    if !webhook_endpoint.is_enabled {
+        return Err(report!(
+            errors::WebhooksFlowError::WebhookEndpointUnavailable
+        ))
+        .attach_printable("Webhook endpoint has been disabled");
+    }
+
+    let headers = get_webhook_endpoint_auth_headers(&webhook_endpoint)?;
+
+    Ok(WebhookDestination {
+        url: webhook_endpoint.url,
+        headers,
+        signing_keys: webhook_endpoint.get_signing_keys(common_utils::date_time::now()),
+    })
+}
+
+fn get_webhook_endpoint_auth_headers(
+    webhook_endpoint: &domain::WebhookEndpoint,
+) -> CustomResult<Vec<(String, Maskable<String>)>, errors::WebhooksFlowError> {
+    let Some(auth) = webhook_endpoint.auth.as_ref() else {
+        return Ok(Vec::new());
+    };
+
+    let auth: api_models::webhook_endpoints::WebhookEndpointAuth = auth
+        .get_inner()
+        .peek()
+        .clone()
+        .parse_value("WebhookEndpointAuth")
+        .change_context(errors::WebhooksFlowError::WebhookEndpointUnavailable)
+        .attach_printable("Failed to parse webhook endpoint authentication")?;
+
+    let header = match auth {
+        api_models::webhook_endpoints::WebhookEndpointAuth::Basic { username, password } => {
+            let credentials =
+                consts::BASE64_ENGINE.encode(format!("{}:{}", username.peek(), password.peek()));
+            (
+                headers::AUTHORIZATION.to_string(),
+                format!("Basic {credentials}").into_masked(),
+            )
+        }
+        api_models::webhook_endpoints::WebhookEndpointAuth::Bearer { token } => (
+            headers::AUTHORIZATION.to_string(),
+            format!("Bearer {}", token.peek()).into_masked(),
+        ),
+        api_models::webhook_endpoints::WebhookEndpointAuth::CustomHeader { name, value } => {
+            (name, value.expose().into_masked())
+        }
+    };
+
+    Ok(vec![header])
+}
+
+/// Obtains the keys to be used for signing outgoing webhooks for the business profile, ordered
+/// from the oldest to the newest key. Profiles which have never rotated their signing secret
+/// continue to use the `payment_response_hash_key`.
+pub(crate) async fn get_webhook_signing_keys(
+    state: &SessionState,
+    business_profile: &diesel_models::business_profile::BusinessProfile,
+    merchant_key_store: &domain::MerchantKeyStore,
+) -> CustomResult<Vec<Secret<String>>, errors::WebhooksFlowError> {
+    let signing_secrets = state
+        .store
+        .list_webhook_signing_secrets_by_profile_id(
+            &business_profile.profile_id,
+            merchant_key_store,
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::WebhookSigningSecretsFetchFailed)?;
+
+    if signing_secrets.is_empty() {
+        return Ok(business_profile
+            .payment_response_hash_key
+            .clone()
+            .map(Secret::new)
+            .into_iter()
+            .collect());
+    }
+
+    let now = common_utils::date_time::now();
+    Ok(signing_secrets
+        .into_iter()
+        .filter(|signing_secret| signing_secret.is_active(now))
+        .map(|signing_secret| signing_secret.secret.into_inner())
+        .collect())
+}
+
 pub(crate) fn get_outgoing_webhook_request(
     merchant_account: &domain::MerchantAccount,
     outgoing_webhook: api::OutgoingWebhook,
-    payment_response_hash_key: Option<&str>,
+    signing_keys: &[Secret<String>],
 ) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
     #[inline]
     fn get_outgoing_webhook_request_inner<WebhookType: types::OutgoingWebhookType>(
         outgoing_webhook: api::OutgoingWebhook,
-        payment_response_hash_key: Option<&str>,
+        signing_keys: &[Secret<String>],
     ) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
         let mut headers = vec![(
             reqwest::header::CONTENT_TYPE.to_string(),
@@ -564,12 +797,19 @@ pub(crate) fn get_outgoing_webhook_request(
         let transformed_outgoing_webhook = WebhookType::from(outgoing_webhook);
 
         let outgoing_webhooks_signature = transformed_outgoing_webhook
-            .get_outgoing_webhooks_signature(payment_response_hash_key)?;
+            .get_outgoing_webhooks_signature(
+                signing_keys,
+                common_utils::date_time::now_unix_timestamp(),
+            )?;
 
         if let Some(signature) = outgoing_webhooks_signature.signature {
             WebhookType::add_webhook_header(&mut headers, signature)
         }
 
+        if let Some(timestamped_signature) = outgoing_webhooks_signature.timestamped_signature {
+            WebhookType::add_timestamped_webhook_header(&mut headers, timestamped_signature)
+        }
+
         Ok(OutgoingWebhookRequestContent {
             body: outgoing_webhooks_signature.payload,
             headers: headers
@@ -583,16 +823,95 @@ pub(crate) fn get_outgoing_webhook_request(
         #[cfg(feature = "stripe")]
         Some(api_models::enums::Connector::Stripe) => get_outgoing_webhook_request_inner::<
             stripe_webhooks::StripeOutgoingWebhook,
-        >(
-            outgoing_webhook, payment_response_hash_key
-        ),
+        >(outgoing_webhook, signing_keys),
         _ => get_outgoing_webhook_request_inner::<webhooks::OutgoingWebhook>(
             outgoing_webhook,
-            payment_response_hash_key,
+            signing_keys,
         ),
     }
 }
 
+/// Replaces the signatures of a previously constructed webhook request with signatures computed
+/// using the given signing keys at the current time.
+pub(crate) fn resign_outgoing_webhook_request(
+    request_content: OutgoingWebhookRequestContent,
+    signing_keys: &[Secret<String>],
+) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
+    resign_outgoing_webhook_request_at(
+        request_content,
+        signing_keys,
+        common_utils::date_time::now_unix_timestamp(),
+    )
+}
+
+fn resign_outgoing_webhook_request_at(
+    request_content: OutgoingWebhookRequestContent,
+    signing_keys: &[Secret<String>],
+    timestamp: i64,
+) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
+    #[inline]
+    fn get_signature_headers<WebhookType: types::OutgoingWebhookType>(
+        payload: &str,
+        signing_keys: &[Secret<String>],
+        timestamp: i64,
+    ) -> CustomResult<Vec<(String, Maskable<String>)>, errors::WebhooksFlowError> {
+        let signatures = WebhookType::get_payload_signatures(payload, signing_keys, timestamp)?;
+
+        let mut headers = Vec::new();
+        if let Some(signature) = signatures.signature {
+            WebhookType::add_webhook_header(&mut headers, signature)
+        }
+        if let Some(timestamped_signature) = signatures.timestamped_signature {
+            WebhookType::add_timestamped_webhook_header(&mut headers, timestamped_signature)
+        }
+        Ok(headers)
+    }
+
+    let signature_header_names = [
+        headers::X_WEBHOOK_SIGNATURE,
+        headers::X_WEBHOOK_SIGNATURE_TIMESTAMPED,
+        headers::STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE,
+    ];
+    let is_signature_header = |name: &str| {
+        signature_header_names
+            .iter()
+            .any(|header_name| name.eq_ignore_ascii_case(header_name))
+    };
+
+    // Signing keys are mandatory for Stripe compatible webhooks, so requests in the Stripe
+    // compatible format always carry its signature header
+    let is_stripe_compatible = request_content
+        .headers
+        .iter()
+        .any(|(name, _)| name.eq_ignore_ascii_case(headers::STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE));
+    let payload = request_content.body.peek();
+    let signature_headers = match is_stripe_compatible {
+        #[cfg(feature = "stripe")]
+        true => get_signature_headers::<stripe_webhooks::StripeOutgoingWebhook>(
+            payload,
+            signing_keys,
+            timestamp,
+        ),
+        _ => get_signature_headers::<webhooks::OutgoingWebhook>(payload, signing_keys, timestamp),
+    }?;
+
+    let headers = request_content
+        .headers
+        .into_iter()
+        .filter(|(name, _)| !is_signature_header(name))
+        .chain(
+            signature_headers
+                .into_iter()
+                .map(|(name, value)| (name, Secret::new(value.into_inner()))),
+        )
+        .collect();
+
+    Ok(OutgoingWebhookRequestContent {
+        body: request_content.body,
+        headers,
+    })
+}
+
 #[derive(Debug)]
 enum ScheduleWebhookRetry {
     WithProcessTracker(storage::ProcessTracker),
@@ -845,6 +1164,16 @@ impl ForeignFrom<(&api::OutgoingWebhookContent, &str)> for storage::EventMetadat
             webhooks::OutgoingWebhookContent::PayoutDetails(payout_response) => Self::Payout {
                 payout_id: payout_response.payout_id.clone(),
             },
+            webhooks::OutgoingWebhookContent::PaymentMethodDetails(payment_method_response) => {
+                Self::PaymentMethod {
+                    payment_method_id: payment_method_response.payment_method_id.clone(),
+                }
+            }
+            webhooks::OutgoingWebhookContent::SubscriptionDetails(subscription_response) => {
+                Self::Subscription {
+                    subscription_id: subscription_response.subscription_id.clone(),
+                }
+            }
         }
     }
 }
@@ -889,5 +1218,150 @@ fn get_outgoing_webhook_event_content_from_event_metadata(
             mandate_id,
             content: serde_json::Value::Null,
         },
+        diesel_models::EventMetadata::PaymentMethod { payment_method_id } => {
+            OutgoingWebhookEventContent::PaymentMethod {
+                payment_method_id,
+                content: serde_json::Value::Null,
+            }
+        }
+        diesel_models::EventMetadata::Subscription { subscription_id } => {
+            OutgoingWebhookEventContent::Subscription {
+                subscription_id,
+                content: serde_json::Value::Null,
+            }
+        }
     })
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use common_utils::crypto::SignMessage;
+
+    use super::*;
+
+    const PAYLOAD: &str = r#"{"event_id":"evt_123"}"#;
+
+    fn get_header<'a>(request_content: &'a OutgoingWebhookRequestContent, name: &str) -> &'a str {
+        request_content
+            .headers
+            .iter()
+            .find(|(header_name, _)| header_name == name)
+            .map(|(_, value)| value.peek().as_str())
+            .unwrap()
+    }
+
+    fn hmac_hex<Algorithm: SignMessage>(algorithm: &Algorithm, key: &str, message: &str) -> String {
+        hex::encode(
+            algorithm
+                .sign_message(key.as_bytes(), message.as_bytes())
+                .unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_resign_outgoing_webhook_request() {
+        let request_content = OutgoingWebhookRequestContent {
+            body: Secret::new(PAYLOAD.to_string()),
+            headers: vec![
+                (
+                    "content-type".to_string(),
+                    Secret::new("application/json".to_string()),
+                ),
+                (
+                    headers::X_WEBHOOK_SIGNATURE.to_string(),
+                    Secret::new("stale".to_string()),
+                ),
+                (
+                    headers::X_WEBHOOK_SIGNATURE_TIMESTAMPED.to_string(),
+                    Secret::new("t=1600000000,v1=stale".to_string()),
+                ),
+            ],
+        };
+
+        let signing_keys = [Secret::new("current_secret".to_string())];
+        let request_content =
+            resign_outgoing_webhook_request_at(request_content, &signing_keys, 1_700_000_000)
+                .unwrap();
+
+        assert_eq!(request_content.body.peek(), PAYLOAD);
+        assert_eq!(request_content.headers.len(), 3);
+        assert_eq!(
+            get_header(&request_content, "content-type"),
+            "application/json"
+        );
+        assert_eq!(
+            get_header(&request_content, headers::X_WEBHOOK_SIGNATURE),
+            hmac_hex(&common_utils::crypto::HmacSha512, "current_secret", PAYLOAD)
+        );
+        assert_eq!(
+            get_header(&request_content, headers::X_WEBHOOK_SIGNATURE_TIMESTAMPED),
+            format!(
+                "t=1700000000,v1={}",
+                hmac_hex(
+                    &common_utils::crypto::HmacSha512,
+                    "current_secret",
+                    &format!("1700000000.{PAYLOAD}")
+                )
+            )
+        );
+    }
+
+    #[cfg(feature = "stripe")]
+    #[test]
+    fn test_resign_stripe_compatible_outgoing_webhook_request() {
+        let request_content = OutgoingWebhookRequestContent {
+            body: Secret::new(PAYLOAD.to_string()),
+            headers: vec![(
+                headers::STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE.to_string(),
+                Secret::new("t=1600000000,v1=stale".to_string()),
+            )],
+        };
+
+        let signing_keys = [
+            Secret::new("previous_secret".to_string()),
+            Secret::new("current_secret".to_string()),
+        ];
+        let request_content =
+            resign_outgoing_webhook_request_at(request_content, &signing_keys, 1_700_000_000)
+                .unwrap();
+
+        let signed_payload = format!("1700000000.{PAYLOAD}");
+        assert_eq!(request_content.headers.len(), 1);
+        assert_eq!(
+            get_header(
+                &request_content,
+                headers::STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE
+            ),
+            format!(
+                "t=1700000000,v1={},v1={}",
+                hmac_hex(
+                    &common_utils::crypto::HmacSha256,
+                    "previous_secret",
+                    &signed_payload
+                ),
+                hmac_hex(
+                    &common_utils::crypto::HmacSha256,
+                    "current_secret",
+                    &signed_payload
+                )
+            )
+        );
+    }
+
+    #[test]
+    fn test_resign_outgoing_webhook_request_without_keys() {
+        let request_content = OutgoingWebhookRequestContent {
+            body: Secret::new(PAYLOAD.to_string()),
+            headers: vec![(
+                headers::X_WEBHOOK_SIGNATURE.to_string(),
+                Secret::new("stale".to_string()),
+            )],
+        };
+
+        let request_content =
+            resign_outgoing_webhook_request_at(request_content, &[], 1_700_000_000).unwrap();
+
+        assert!(request_content.headers.is_empty());
+    }
+}
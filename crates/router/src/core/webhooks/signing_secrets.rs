@@ -0,0 +1,289 @@
+use api_models::admin as admin_types;
+use common_utils::{crypto::generate_cryptographically_secure_random_string, date_time};
+use error_stack::{report, ResultExt};
+use masking::{PeekInterface, Secret, StrongSecret};
+use router_env::{instrument, tracing};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{
+        domain::{self, types as domain_types},
+        storage,
+    },
+    utils,
+};
+
+const WEBHOOK_SIGNING_SECRET_ID_PREFIX: &str = "whsec";
+const WEBHOOK_SIGNING_SECRET_LENGTH: usize = 64;
+const DEFAULT_SIGNING_SECRET_OVERLAP_SECS: u32 = 60 * 60 * 24; // 1 day
+const MAX_SIGNING_SECRET_OVERLAP_SECS: u32 = 60 * 60 * 24 * 7; // 7 days
+
+/// Validates the requested overlap window during which the replaced signing secrets continue to
+/// be used for signing webhooks.
+pub(super) fn get_signing_secret_overlap(
+    overlap_seconds: Option<u32>,
+) -> RouterResult<time::Duration> {
+    let overlap_seconds = overlap_seconds.unwrap_or(DEFAULT_SIGNING_SECRET_OVERLAP_SECS);
+    if overlap_seconds > MAX_SIGNING_SECRET_OVERLAP_SECS {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "`overlap_seconds` must not be greater than {MAX_SIGNING_SECRET_OVERLAP_SECS}"
+            ),
+        }));
+    }
+
+    Ok(time::Duration::seconds(i64::from(overlap_seconds)))
+}
+
+#[instrument(skip(state))]
+pub async fn rotate_webhook_signing_secret(
+    state: SessionState,
+    merchant_id: &str,
+    profile_id: &str,
+    request: admin_types::WebhookSigningSecretRotateRequest,
+) -> RouterResponse<admin_types::WebhookSigningSecretRotateResponse> {
+    let overlap = get_signing_secret_overlap(request.overlap_seconds)?;
+
+    let (business_profile, key_store) =
+        get_business_profile_and_key_store(&state, merchant_id, profile_id).await?;
+    let store = state.store.as_ref();
+    let now = date_time::now();
+
+    let mut signing_secrets = store
+        .list_webhook_signing_secrets_by_profile_id(profile_id, &key_store)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list webhook signing secrets")?;
+
+    // Profiles which have never rotated their signing secret sign webhooks using the
+    // `payment_response_hash_key`, which is imported as a signing secret so that it remains
+    // usable during the overlap window.
+    if signing_secrets.is_empty() {
+        if let Some(payment_response_hash_key) = business_profile.payment_response_hash_key {
+            let legacy_signing_secret = new_webhook_signing_secret(
+                &key_store,
+                profile_id,
+                Secret::new(payment_response_hash_key),
+                business_profile.created_at,
+            )
+            .await?;
+            signing_secrets.push(
+                store
+                    .insert_webhook_signing_secret(legacy_signing_secret, &key_store)
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to import payment response hash key")?,
+            );
+        }
+    }
+
+    let expires_at = now.saturating_add(overlap);
+    let mut previous_secrets_expire_at = None;
+    for signing_secret in signing_secrets
+        .into_iter()
+        .filter(|signing_secret| signing_secret.is_active(now))
+    {
+        if let Some(current_expiry) = signing_secret
+            .expires_at
+            .filter(|current_expiry| *current_expiry <= expires_at)
+        {
+            previous_secrets_expire_at = previous_secrets_expire_at.max(Some(current_expiry));
+            continue;
+        }
+
+        previous_secrets_expire_at = Some(expires_at);
+
+        store
+            .update_webhook_signing_secret_by_profile_id_secret_id(
+                profile_id,
+                &signing_secret.secret_id,
+                storage::WebhookSigningSecretUpdate::ExpiryUpdate { expires_at },
+                &key_store,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update webhook signing secret expiry")?;
+    }
+
+    let plaintext_secret =
+        generate_cryptographically_secure_random_string(WEBHOOK_SIGNING_SECRET_LENGTH);
+    let signing_secret = new_webhook_signing_secret(
+        &key_store,
+        profile_id,
+        Secret::new(plaintext_secret.clone()),
+        now,
+    )
+    .await?;
+    let signing_secret = store
+        .insert_webhook_signing_secret(signing_secret, &key_store)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert webhook signing secret")?;
+
+    Ok(ApplicationResponse::Json(
+        admin_types::WebhookSigningSecretRotateResponse {
+            profile_id: signing_secret.profile_id,
+            secret_id: signing_secret.secret_id,
+            secret: StrongSecret::new(plaintext_secret),
+            created_at: signing_secret.created_at,
+            previous_secrets_expire_at,
+        },
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn list_webhook_signing_secrets(
+    state: SessionState,
+    merchant_id: &str,
+    profile_id: &str,
+) -> RouterResponse<Vec<admin_types::WebhookSigningSecretResponse>> {
+    let (_, key_store) =
+        get_business_profile_and_key_store(&state, merchant_id, profile_id).await?;
+    let now = date_time::now();
+
+    let signing_secrets = state
+        .store
+        .list_webhook_signing_secrets_by_profile_id(profile_id, &key_store)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list webhook signing secrets")?;
+
+    Ok(ApplicationResponse::Json(
+        signing_secrets
+            .into_iter()
+            .map(|signing_secret| get_webhook_signing_secret_response(signing_secret, now))
+            .collect(),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn revoke_webhook_signing_secret(
+    state: SessionState,
+    merchant_id: &str,
+    profile_id: &str,
+    secret_id: &str,
+) -> RouterResponse<admin_types::WebhookSigningSecretResponse> {
+    let (_, key_store) =
+        get_business_profile_and_key_store(&state, merchant_id, profile_id).await?;
+    let store = state.store.as_ref();
+    let now = date_time::now();
+
+    let signing_secrets = store
+        .list_webhook_signing_secrets_by_profile_id(profile_id, &key_store)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list webhook signing secrets")?;
+
+    let signing_secret = signing_secrets
+        .iter()
+        .find(|signing_secret| signing_secret.secret_id == secret_id)
+        .cloned()
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Webhook signing secret `{secret_id}` does not exist"),
+        })?;
+
+    if signing_secret.revoked_at.is_some() {
+        return Ok(ApplicationResponse::Json(
+            get_webhook_signing_secret_response(signing_secret, now),
+        ));
+    }
+
+    let has_other_active_secrets = signing_secrets.iter().any(|other_secret| {
+        other_secret.secret_id != signing_secret.secret_id && other_secret.is_active(now)
+    });
+    if signing_secret.is_active(now) && !has_other_active_secrets {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Cannot revoke the only active webhook signing secret, rotate the secret \
+                      before revoking it"
+                .to_string(),
+        }));
+    }
+
+    let signing_secret = store
+        .update_webhook_signing_secret_by_profile_id_secret_id(
+            profile_id,
+            secret_id,
+            storage::WebhookSigningSecretUpdate::Revoke { revoked_at: now },
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Webhook signing secret `{secret_id}` does not exist"),
+        })?;
+
+    Ok(ApplicationResponse::Json(
+        get_webhook_signing_secret_response(signing_secret, now),
+    ))
+}
+
+pub(super) async fn get_business_profile_and_key_store(
+    state: &SessionState,
+    merchant_id: &str,
+    profile_id: &str,
+) -> RouterResult<(storage::BusinessProfile, domain::MerchantKeyStore)> {
+    let store = state.store.as_ref();
+    let business_profile = store
+        .find_business_profile_by_profile_id(profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.to_owned(),
+        })?;
+
+    if business_profile.merchant_id != merchant_id {
+        return Err(report!(errors::ApiErrorResponse::AccessForbidden {
+            resource: profile_id.to_owned(),
+        }));
+    }
+
+    let key_store = store
+        .get_merchant_key_store_by_merchant_id(merchant_id, &store.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    Ok((business_profile, key_store))
+}
+
+async fn new_webhook_signing_secret(
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &str,
+    secret: Secret<String>,
+    created_at: time::PrimitiveDateTime,
+) -> RouterResult<domain::WebhookSigningSecret> {
+    Ok(domain::WebhookSigningSecret {
+        secret_id: utils::generate_id(consts::ID_LENGTH, WEBHOOK_SIGNING_SECRET_ID_PREFIX),
+        merchant_id: key_store.merchant_id.clone(),
+        profile_id: profile_id.to_owned(),
+        secret: domain_types::encrypt(secret, key_store.key.get_inner().peek())
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encrypt webhook signing secret")?,
+        created_at,
+        expires_at: None,
+        revoked_at: None,
+    })
+}
+
+fn get_webhook_signing_secret_response(
+    signing_secret: domain::WebhookSigningSecret,
+    now: time::PrimitiveDateTime,
+) -> admin_types::WebhookSigningSecretResponse {
+    let status = if signing_secret.revoked_at.is_some() {
+        admin_types::WebhookSigningSecretStatus::Revoked
+    } else if signing_secret.is_active(now) {
+        admin_types::WebhookSigningSecretStatus::Active
+    } else {
+        admin_types::WebhookSigningSecretStatus::Expired
+    };
+
+    admin_types::WebhookSigningSecretResponse {
+        profile_id: signing_secret.profile_id,
+        secret_id: signing_secret.secret_id,
+        status,
+        created_at: signing_secret.created_at,
+        expires_at: signing_secret.expires_at,
+        revoked_at: signing_secret.revoked_at,
+    }
+}
@@ -1,7 +1,7 @@
 use api_models::webhooks;
 use common_utils::{crypto::SignMessage, ext_traits::Encode};
 use error_stack::ResultExt;
-use masking::Secret;
+use masking::{PeekInterface, Secret};
 use serde::Serialize;
 
 use crate::{core::errors, headers, services::request::Maskable, types::storage::enums};
@@ -9,35 +9,103 @@ use crate::{core::errors, headers, services::request::Maskable, types::storage::
 pub struct OutgoingWebhookPayloadWithSignature {
     pub payload: Secret<String>,
     pub signature: Option<String>,
+    pub timestamped_signature: Option<String>,
+}
+
+pub struct OutgoingWebhookSignatures {
+    pub signature: Option<String>,
+    pub timestamped_signature: Option<String>,
 }
 
 pub trait OutgoingWebhookType:
     Serialize + From<webhooks::OutgoingWebhook> + Sync + Send + std::fmt::Debug + 'static
 {
-    fn get_outgoing_webhooks_signature(
-        &self,
-        payment_response_hash_key: Option<impl AsRef<[u8]>>,
-    ) -> errors::CustomResult<OutgoingWebhookPayloadWithSignature, errors::WebhooksFlowError>;
+    /// Signs the serialized webhook payload with each of the active signing keys, ordered from
+    /// the oldest to the newest key. The timestamp is the time at which the webhook is sent.
+    fn get_payload_signatures(
+        payload: &str,
+        signing_keys: &[Secret<String>],
+        timestamp: i64,
+    ) -> errors::CustomResult<OutgoingWebhookSignatures, errors::WebhooksFlowError>;
 
-    fn add_webhook_header(header: &mut Vec<(String, Maskable<String>)>, signature: String);
-}
-
-impl OutgoingWebhookType for webhooks::OutgoingWebhook {
     fn get_outgoing_webhooks_signature(
         &self,
-        payment_response_hash_key: Option<impl AsRef<[u8]>>,
+        signing_keys: &[Secret<String>],
+        timestamp: i64,
     ) -> errors::CustomResult<OutgoingWebhookPayloadWithSignature, errors::WebhooksFlowError> {
         let webhook_signature_payload = self
             .encode_to_string_of_json()
             .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
             .attach_printable("failed encoding outgoing webhook payload")?;
 
-        let signature = payment_response_hash_key
+        let OutgoingWebhookSignatures {
+            signature,
+            timestamped_signature,
+        } = Self::get_payload_signatures(&webhook_signature_payload, signing_keys, timestamp)?;
+
+        Ok(OutgoingWebhookPayloadWithSignature {
+            payload: webhook_signature_payload.into(),
+            signature,
+            timestamped_signature,
+        })
+    }
+
+    fn add_webhook_header(header: &mut Vec<(String, Maskable<String>)>, signature: String);
+
+    fn add_timestamped_webhook_header(
+        header: &mut Vec<(String, Maskable<String>)>,
+        timestamped_signature: String,
+    ) {
+        header.push((
+            headers::X_WEBHOOK_SIGNATURE_TIMESTAMPED.to_string(),
+            timestamped_signature.into(),
+        ))
+    }
+}
+
+/// Builds a signature of the form `t=<timestamp>,v1=<signature>[,v1=<signature>...]`, with one
+/// `v1` entry per signing key. The timestamp is part of the signed message, which allows
+/// receivers to reject replayed requests.
+pub fn get_timestamped_signature<Algorithm: SignMessage>(
+    algorithm: &Algorithm,
+    signing_keys: &[Secret<String>],
+    timestamp: i64,
+    payload: &str,
+) -> errors::CustomResult<Option<String>, errors::WebhooksFlowError> {
+    if signing_keys.is_empty() {
+        return Ok(None);
+    }
+
+    let signed_payload = format!("{timestamp}.{payload}");
+    let signatures = signing_keys
+        .iter()
+        .map(|key| {
+            algorithm
+                .sign_message(key.peek().as_bytes(), signed_payload.as_bytes())
+                .map(|signature| format!("v1={}", hex::encode(signature)))
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+        .attach_printable("Failed to sign the message")?;
+
+    Ok(Some(format!("t={timestamp},{}", signatures.join(","))))
+}
+
+impl OutgoingWebhookType for webhooks::OutgoingWebhook {
+    fn get_payload_signatures(
+        payload: &str,
+        signing_keys: &[Secret<String>],
+        timestamp: i64,
+    ) -> errors::CustomResult<OutgoingWebhookSignatures, errors::WebhooksFlowError> {
+        // The legacy signature header carries a single signature, which is computed using the
+        // oldest active key so that receivers can switch over once the older key expires.
+        let signature = signing_keys
+            .first()
             .map(|key| {
                 common_utils::crypto::HmacSha512::sign_message(
                     &common_utils::crypto::HmacSha512,
-                    key.as_ref(),
-                    webhook_signature_payload.as_bytes(),
+                    key.peek().as_bytes(),
+                    payload.as_bytes(),
                 )
             })
             .transpose()
@@ -45,9 +113,16 @@ impl OutgoingWebhookType for webhooks::OutgoingWebhook {
             .attach_printable("Failed to sign the message")?
             .map(hex::encode);
 
-        Ok(OutgoingWebhookPayloadWithSignature {
-            payload: webhook_signature_payload.into(),
+        let timestamped_signature = get_timestamped_signature(
+            &common_utils::crypto::HmacSha512,
+            signing_keys,
+            timestamp,
+            payload,
+        )?;
+
+        Ok(OutgoingWebhookSignatures {
             signature,
+            timestamped_signature,
         })
     }
 
@@ -66,3 +141,45 @@ pub(crate) struct OutgoingWebhookTrackingData {
     pub(crate) primary_object_type: enums::EventObjectType,
     pub(crate) initial_attempt_id: Option<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_timestamped_signature_contains_one_signature_per_key() {
+        let signing_keys = vec![
+            Secret::new("old_secret".to_string()),
+            Secret::new("new_secret".to_string()),
+        ];
+
+        let signature = get_timestamped_signature(
+            &common_utils::crypto::HmacSha512,
+            &signing_keys,
+            1_700_000_000,
+            r#"{"event_id":"evt_123"}"#,
+        )
+        .unwrap()
+        .unwrap();
+
+        let parts = signature.split(',').collect::<Vec<_>>();
+        assert_eq!(parts.len(), 3);
+        assert_eq!(parts.first(), Some(&"t=1700000000"));
+        assert!(parts.iter().skip(1).all(|part| part.starts_with("v1=")));
+        assert_ne!(parts.get(1), parts.get(2));
+    }
+
+    #[test]
+    fn test_timestamped_signature_without_keys() {
+        let signature = get_timestamped_signature(
+            &common_utils::crypto::HmacSha512,
+            &[],
+            1_700_000_000,
+            r#"{"event_id":"evt_123"}"#,
+        )
+        .unwrap();
+
+        assert!(signature.is_none());
+    }
+}
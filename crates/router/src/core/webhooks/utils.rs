@@ -128,12 +128,19 @@ pub(crate) fn get_idempotent_event_id(
     primary_object_id: &str,
     event_type: types::storage::enums::EventType,
     delivery_attempt: types::storage::enums::WebhookDeliveryAttempt,
+    webhook_endpoint_id: Option<&str>,
 ) -> String {
     use crate::types::storage::enums::WebhookDeliveryAttempt;
 
     const EVENT_ID_SUFFIX_LENGTH: usize = 8;
 
-    let common_prefix = format!("{primary_object_id}_{event_type}");
+    // Events delivered to webhook endpoints are deduplicated separately for each endpoint
+    let common_prefix = match webhook_endpoint_id {
+        Some(webhook_endpoint_id) => {
+            format!("{primary_object_id}_{event_type}_{webhook_endpoint_id}")
+        }
+        None => format!("{primary_object_id}_{event_type}"),
+    };
     match delivery_attempt {
         WebhookDeliveryAttempt::InitialAttempt => common_prefix,
         WebhookDeliveryAttempt::AutomaticRetry | WebhookDeliveryAttempt::ManualRetry => {
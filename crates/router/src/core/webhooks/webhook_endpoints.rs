@@ -0,0 +1,332 @@
+use api_models::webhook_endpoints as webhook_endpoint_types;
+use common_utils::{
+    crypto::generate_cryptographically_secure_random_string, date_time, ext_traits::Encode,
+};
+use error_stack::{report, ResultExt};
+use masking::{PeekInterface, Secret, StrongSecret};
+use router_env::{instrument, tracing};
+
+use super::signing_secrets::{get_business_profile_and_key_store, get_signing_secret_overlap};
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{
+        domain::{self, types as domain_types},
+        storage::{self, enums},
+    },
+    utils,
+};
+
+const WEBHOOK_ENDPOINT_ID_PREFIX: &str = "whep";
+const WEBHOOK_ENDPOINT_SIGNING_SECRET_LENGTH: usize = 64;
+const WEBHOOK_ENDPOINT_DESCRIPTION_MAX_LENGTH: usize = 255;
+const MAX_WEBHOOK_ENDPOINTS_PER_PROFILE: usize = 16;
+
+#[instrument(skip(state))]
+pub async fn create_webhook_endpoint(
+    state: SessionState,
+    merchant_id: &str,
+    profile_id: &str,
+    request: webhook_endpoint_types::WebhookEndpointCreateRequest,
+) -> RouterResponse<webhook_endpoint_types::WebhookEndpointResponse> {
+    let enabled_events = validate_enabled_events(request.enabled_events)?;
+    validate_description(request.description.as_deref())?;
+
+    let (_, key_store) =
+        get_business_profile_and_key_store(&state, merchant_id, profile_id).await?;
+    let store = state.store.as_ref();
+
+    let existing_endpoints = store
+        .list_webhook_endpoints_by_profile_id(profile_id, &key_store)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list webhook endpoints")?;
+    if existing_endpoints.len() >= MAX_WEBHOOK_ENDPOINTS_PER_PROFILE {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "A business profile can have at most {MAX_WEBHOOK_ENDPOINTS_PER_PROFILE} webhook \
+                 endpoints"
+            ),
+        }));
+    }
+
+    let signing_secret =
+        generate_cryptographically_secure_random_string(WEBHOOK_ENDPOINT_SIGNING_SECRET_LENGTH);
+    let now = date_time::now();
+    let webhook_endpoint = domain::WebhookEndpoint {
+        endpoint_id: utils::generate_id(consts::ID_LENGTH, WEBHOOK_ENDPOINT_ID_PREFIX),
+        merchant_id: merchant_id.to_owned(),
+        profile_id: profile_id.to_owned(),
+        url: request.url.to_string(),
+        description: request.description,
+        auth: encrypt_webhook_endpoint_auth(request.auth, &key_store).await?,
+        signing_secret: domain_types::encrypt(
+            Secret::new(signing_secret.clone()),
+            key_store.key.get_inner().peek(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encrypt webhook endpoint signing secret")?,
+        enabled_events,
+        is_enabled: request.is_enabled.unwrap_or(true),
+        created_at: now,
+        modified_at: now,
+        previous_signing_secret: None,
+        previous_signing_secret_expires_at: None,
+    };
+
+    let webhook_endpoint = store
+        .insert_webhook_endpoint(webhook_endpoint, &key_store)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert webhook endpoint")?;
+
+    Ok(ApplicationResponse::Json(get_webhook_endpoint_response(
+        webhook_endpoint,
+        Some(StrongSecret::new(signing_secret)),
+    )))
+}
+
+#[instrument(skip(state))]
+pub async fn list_webhook_endpoints(
+    state: SessionState,
+    merchant_id: &str,
+    profile_id: &str,
+) -> RouterResponse<Vec<webhook_endpoint_types::WebhookEndpointResponse>> {
+    let (_, key_store) =
+        get_business_profile_and_key_store(&state, merchant_id, profile_id).await?;
+
+    let webhook_endpoints = state
+        .store
+        .list_webhook_endpoints_by_profile_id(profile_id, &key_store)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list webhook endpoints")?;
+
+    Ok(ApplicationResponse::Json(
+        webhook_endpoints
+            .into_iter()
+            .map(|webhook_endpoint| get_webhook_endpoint_response(webhook_endpoint, None))
+            .collect(),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_webhook_endpoint(
+    state: SessionState,
+    merchant_id: &str,
+    profile_id: &str,
+    endpoint_id: &str,
+) -> RouterResponse<webhook_endpoint_types::WebhookEndpointResponse> {
+    let (_, key_store) =
+        get_business_profile_and_key_store(&state, merchant_id, profile_id).await?;
+
+    let webhook_endpoint = state
+        .store
+        .find_webhook_endpoint_by_profile_id_endpoint_id(profile_id, endpoint_id, &key_store)
+        .await
+        .to_not_found_response(webhook_endpoint_not_found(endpoint_id))?;
+
+    Ok(ApplicationResponse::Json(get_webhook_endpoint_response(
+        webhook_endpoint,
+        None,
+    )))
+}
+
+#[instrument(skip(state))]
+pub async fn update_webhook_endpoint(
+    state: SessionState,
+    merchant_id: &str,
+    profile_id: &str,
+    endpoint_id: &str,
+    request: webhook_endpoint_types::WebhookEndpointUpdateRequest,
+) -> RouterResponse<webhook_endpoint_types::WebhookEndpointResponse> {
+    let enabled_events = request
+        .enabled_events
+        .map(validate_enabled_events)
+        .transpose()?;
+    validate_description(request.description.as_deref())?;
+
+    let (_, key_store) =
+        get_business_profile_and_key_store(&state, merchant_id, profile_id).await?;
+
+    let webhook_endpoint_update = storage::WebhookEndpointUpdate::Update {
+        url: request.url.map(|url| url.to_string()),
+        description: request.description,
+        auth: encrypt_webhook_endpoint_auth(request.auth, &key_store)
+            .await?
+            .map(Into::into),
+        enabled_events,
+        is_enabled: request.is_enabled,
+    };
+
+    let webhook_endpoint = state
+        .store
+        .update_webhook_endpoint_by_profile_id_endpoint_id(
+            profile_id,
+            endpoint_id,
+            webhook_endpoint_update,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(webhook_endpoint_not_found(endpoint_id))?;
+
+    Ok(ApplicationResponse::Json(get_webhook_endpoint_response(
+        webhook_endpoint,
+        None,
+    )))
+}
+
+#[instrument(skip(state))]
+pub async fn delete_webhook_endpoint(
+    state: SessionState,
+    merchant_id: &str,
+    profile_id: &str,
+    endpoint_id: &str,
+) -> RouterResponse<webhook_endpoint_types::WebhookEndpointDeleteResponse> {
+    // Ensures that the business profile belongs to the merchant
+    let _ = get_business_profile_and_key_store(&state, merchant_id, profile_id).await?;
+
+    let deleted = state
+        .store
+        .delete_webhook_endpoint_by_profile_id_endpoint_id(profile_id, endpoint_id)
+        .await
+        .to_not_found_response(webhook_endpoint_not_found(endpoint_id))?;
+
+    Ok(ApplicationResponse::Json(
+        webhook_endpoint_types::WebhookEndpointDeleteResponse {
+            endpoint_id: endpoint_id.to_owned(),
+            profile_id: profile_id.to_owned(),
+            deleted,
+        },
+    ))
+}
+
+/// Replaces the signing secret of the webhook endpoint with a newly generated one. Webhooks are
+/// signed using both the secrets until the overlap window elapses, so that merchants can switch
+/// to the new secret without rejecting webhooks in the meantime.
+#[instrument(skip(state))]
+pub async fn rotate_webhook_endpoint_signing_secret(
+    state: SessionState,
+    merchant_id: &str,
+    profile_id: &str,
+    endpoint_id: &str,
+    request: webhook_endpoint_types::WebhookEndpointSecretRotateRequest,
+) -> RouterResponse<webhook_endpoint_types::WebhookEndpointResponse> {
+    let overlap = get_signing_secret_overlap(request.overlap_seconds)?;
+
+    let (_, key_store) =
+        get_business_profile_and_key_store(&state, merchant_id, profile_id).await?;
+    let store = state.store.as_ref();
+
+    let webhook_endpoint = store
+        .find_webhook_endpoint_by_profile_id_endpoint_id(profile_id, endpoint_id, &key_store)
+        .await
+        .to_not_found_response(webhook_endpoint_not_found(endpoint_id))?;
+
+    let signing_secret =
+        generate_cryptographically_secure_random_string(WEBHOOK_ENDPOINT_SIGNING_SECRET_LENGTH);
+    let webhook_endpoint_update = storage::WebhookEndpointUpdate::SigningSecretUpdate {
+        signing_secret: domain_types::encrypt(
+            Secret::new(signing_secret.clone()),
+            key_store.key.get_inner().peek(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encrypt webhook endpoint signing secret")?
+        .into(),
+        previous_signing_secret: webhook_endpoint.signing_secret.into(),
+        previous_signing_secret_expires_at: date_time::now().saturating_add(overlap),
+    };
+
+    let webhook_endpoint = store
+        .update_webhook_endpoint_by_profile_id_endpoint_id(
+            profile_id,
+            endpoint_id,
+            webhook_endpoint_update,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(webhook_endpoint_not_found(endpoint_id))?;
+
+    Ok(ApplicationResponse::Json(get_webhook_endpoint_response(
+        webhook_endpoint,
+        Some(StrongSecret::new(signing_secret)),
+    )))
+}
+
+fn validate_enabled_events(
+    mut enabled_events: Vec<enums::EventType>,
+) -> RouterResult<Vec<enums::EventType>> {
+    enabled_events.sort_by_key(ToString::to_string);
+    enabled_events.dedup();
+
+    if enabled_events.is_empty() {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`enabled_events` must contain at least one event type".to_string(),
+        }));
+    }
+
+    Ok(enabled_events)
+}
+
+fn validate_description(description: Option<&str>) -> RouterResult<()> {
+    if description.is_some_and(|description| {
+        description.chars().count() > WEBHOOK_ENDPOINT_DESCRIPTION_MAX_LENGTH
+    }) {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "`description` must not be longer than {WEBHOOK_ENDPOINT_DESCRIPTION_MAX_LENGTH} \
+                 characters"
+            ),
+        }));
+    }
+
+    Ok(())
+}
+
+async fn encrypt_webhook_endpoint_auth(
+    auth: Option<webhook_endpoint_types::WebhookEndpointAuth>,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<common_utils::crypto::OptionalEncryptableValue> {
+    let auth = auth
+        .map(|auth| auth.encode_to_value())
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize webhook endpoint authentication")?;
+
+    domain_types::encrypt_optional(auth.map(Secret::new), key_store.key.get_inner().peek())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encrypt webhook endpoint authentication")
+}
+
+fn webhook_endpoint_not_found(endpoint_id: &str) -> errors::ApiErrorResponse {
+    errors::ApiErrorResponse::GenericNotFoundError {
+        message: format!("Webhook endpoint `{endpoint_id}` does not exist"),
+    }
+}
+
+fn get_webhook_endpoint_response(
+    webhook_endpoint: domain::WebhookEndpoint,
+    signing_secret: Option<StrongSecret<String>>,
+) -> webhook_endpoint_types::WebhookEndpointResponse {
+    webhook_endpoint_types::WebhookEndpointResponse {
+        endpoint_id: webhook_endpoint.endpoint_id,
+        merchant_id: webhook_endpoint.merchant_id,
+        profile_id: webhook_endpoint.profile_id,
+        url: webhook_endpoint.url,
+        description: webhook_endpoint.description,
+        enabled_events: webhook_endpoint.enabled_events,
+        is_enabled: webhook_endpoint.is_enabled,
+        has_auth: webhook_endpoint.auth.is_some(),
+        signing_secret,
+        previous_signing_secret_expires_at: webhook_endpoint
+            .previous_signing_secret_expires_at
+            .filter(|expires_at| *expires_at > date_time::now()),
+        created_at: webhook_endpoint.created_at,
+        modified_at: webhook_endpoint.modified_at,
+    }
+}
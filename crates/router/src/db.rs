@@ -1,5 +1,6 @@
 pub mod address;
 pub mod api_keys;
+pub mod audit_log;
 pub mod authentication;
 pub mod authorization;
 pub mod blocklist;
@@ -28,14 +29,20 @@ pub mod merchant_key_store;
 pub mod organization;
 pub mod payment_link;
 pub mod payment_method;
+pub mod payout_batch;
 pub mod refund;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod subscription;
+pub mod tenant;
+pub mod unified_translations;
 pub mod user;
 pub mod user_authentication_method;
 pub mod user_key_store;
 pub mod user_role;
+pub mod webhook_endpoint;
+pub mod webhook_signing_secret;
 
 use diesel_models::{
     fraud_check::{FraudCheck, FraudCheckNew, FraudCheckUpdate},
@@ -65,7 +72,7 @@ pub use crate::{
     },
 };
 
-#[derive(PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub enum StorageImpl {
     Postgresql,
     PostgresqlTest,
@@ -79,6 +86,7 @@ pub trait StorageInterface:
     + dyn_clone::DynClone
     + address::AddressInterface
     + api_keys::ApiKeyInterface
+    + audit_log::AuditLogInterface
     + blocklist_lookup::BlocklistLookupInterface
     + configs::ConfigInterface
     + capture::CaptureInterface
@@ -102,6 +110,8 @@ pub trait StorageInterface:
     + scheduler::SchedulerInterface
     + PayoutAttemptInterface
     + PayoutsInterface
+    + payout_batch::PayoutBatchInterface
+    + subscription::SubscriptionInterface
     + refund::RefundInterface
     + reverse_lookup::ReverseLookupInterface
     + cards_info::CardsInfoInterface
@@ -114,6 +124,7 @@ pub trait StorageInterface:
     + OrganizationInterface
     + routing_algorithm::RoutingAlgorithmInterface
     + gsm::GsmInterface
+    + unified_translations::UnifiedTranslationsInterface
     + user_role::UserRoleInterface
     + authorization::AuthorizationInterface
     + user::sample_data::BatchSampleDataInterface
@@ -122,6 +133,8 @@ pub trait StorageInterface:
     + user_authentication_method::UserAuthenticationMethodInterface
     + authentication::AuthenticationInterface
     + generic_link::GenericLinkInterface
+    + webhook_signing_secret::WebhookSigningSecretInterface
+    + webhook_endpoint::WebhookEndpointInterface
     + 'static
 {
     fn get_scheduler_db(&self) -> Box<dyn scheduler::SchedulerInterface>;
@@ -136,6 +149,7 @@ pub trait GlobalStorageInterface:
     + dyn_clone::DynClone
     + user::UserInterface
     + user_key_store::UserKeyStoreInterface
+    + tenant::TenantInterface
     + 'static
 {
 }
@@ -0,0 +1,130 @@
+use diesel_models::audit_log as storage;
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::MockDb;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    services::Store,
+};
+
+#[async_trait::async_trait]
+pub trait AuditLogInterface {
+    async fn insert_audit_log(
+        &self,
+        audit_log: storage::AuditLogNew,
+    ) -> CustomResult<storage::AuditLog, errors::StorageError>;
+
+    async fn list_audit_logs_by_merchant_id_constraints(
+        &self,
+        merchant_id: &str,
+        constraints: storage::AuditLogListConstraints,
+    ) -> CustomResult<Vec<storage::AuditLog>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl AuditLogInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_audit_log(
+        &self,
+        audit_log: storage::AuditLogNew,
+    ) -> CustomResult<storage::AuditLog, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        audit_log
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_audit_logs_by_merchant_id_constraints(
+        &self,
+        merchant_id: &str,
+        constraints: storage::AuditLogListConstraints,
+    ) -> CustomResult<Vec<storage::AuditLog>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AuditLog::list_by_merchant_id_constraints(&conn, merchant_id, constraints)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl AuditLogInterface for MockDb {
+    async fn insert_audit_log(
+        &self,
+        audit_log: storage::AuditLogNew,
+    ) -> CustomResult<storage::AuditLog, errors::StorageError> {
+        let mut audit_logs = self.audit_logs.lock().await;
+        let audit_log = storage::AuditLog {
+            id: audit_log.id,
+            tenant_id: audit_log.tenant_id,
+            org_id: audit_log.org_id,
+            merchant_id: audit_log.merchant_id,
+            actor_type: audit_log.actor_type,
+            actor_id: audit_log.actor_id,
+            action: audit_log.action,
+            entity_type: audit_log.entity_type,
+            entity_id: audit_log.entity_id,
+            previous_state: audit_log.previous_state,
+            after_state: audit_log.after_state,
+            ip_address: audit_log.ip_address,
+            request_id: audit_log.request_id,
+            created_at: audit_log.created_at,
+        };
+        audit_logs.push(audit_log.clone());
+        Ok(audit_log)
+    }
+
+    async fn list_audit_logs_by_merchant_id_constraints(
+        &self,
+        merchant_id: &str,
+        constraints: storage::AuditLogListConstraints,
+    ) -> CustomResult<Vec<storage::AuditLog>, errors::StorageError> {
+        let audit_logs = self.audit_logs.lock().await;
+        let mut filtered_audit_logs = audit_logs
+            .iter()
+            .filter(|audit_log| {
+                audit_log.merchant_id.as_deref() == Some(merchant_id)
+                    && constraints
+                        .entity_type
+                        .map_or(true, |entity_type| audit_log.entity_type == entity_type)
+                    && constraints.entity_id.as_ref().map_or(true, |entity_id| {
+                        audit_log.entity_id.as_ref() == Some(entity_id)
+                    })
+                    && constraints.actor_id.as_ref().map_or(true, |actor_id| {
+                        audit_log.actor_id.as_ref() == Some(actor_id)
+                    })
+                    && constraints
+                        .action
+                        .as_ref()
+                        .map_or(true, |action| &audit_log.action == action)
+                    && constraints
+                        .created_after
+                        .map_or(true, |created_after| audit_log.created_at >= created_after)
+                    && constraints.created_before.map_or(true, |created_before| {
+                        audit_log.created_at <= created_before
+                    })
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        filtered_audit_logs.sort_by(|left, right| right.created_at.cmp(&left.created_at));
+
+        let offset = constraints
+            .offset
+            .and_then(|offset| usize::try_from(offset).ok())
+            .unwrap_or(0);
+        let limit = constraints
+            .limit
+            .and_then(|limit| usize::try_from(limit).ok())
+            .unwrap_or(usize::MAX);
+
+        Ok(filtered_audit_logs
+            .into_iter()
+            .skip(offset)
+            .take(limit)
+            .collect())
+    }
+}
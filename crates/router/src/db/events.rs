@@ -732,6 +732,7 @@ mod tests {
                     metadata: Some(EventMetadata::Payment {
                         payment_id: payment_id.into(),
                     }),
+                    webhook_endpoint_id: None,
                 },
                 &merchant_key_store,
             )
@@ -1,3 +1,4 @@
+use async_bb8_diesel::AsyncConnection;
 use diesel_models::gsm as storage;
 use error_stack::report;
 use router_env::{instrument, tracing};
@@ -41,6 +42,11 @@ pub trait GsmInterface {
         data: storage::GatewayStatusMappingUpdate,
     ) -> CustomResult<storage::GatewayStatusMap, errors::StorageError>;
 
+    async fn list_gsm_rules(
+        &self,
+        constraints: storage::GatewayStatusMapListConstraints,
+    ) -> CustomResult<Vec<storage::GatewayStatusMap>, errors::StorageError>;
+
     async fn delete_gsm_rule(
         &self,
         connector: String,
@@ -49,6 +55,19 @@ pub trait GsmInterface {
         code: String,
         message: String,
     ) -> CustomResult<bool, errors::StorageError>;
+
+    async fn find_gsm_rules_by_connectors(
+        &self,
+        connectors: Vec<String>,
+    ) -> CustomResult<Vec<storage::GatewayStatusMap>, errors::StorageError>;
+
+    /// Create and update the imported rules in a single transaction, so that either all or none
+    /// of the rules are imported
+    async fn import_gsm_rules(
+        &self,
+        rules_to_create: Vec<storage::GatewayStatusMappingNew>,
+        rules_to_update: Vec<storage::GatewayStatusMappingNew>,
+    ) -> CustomResult<(), errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -112,6 +131,17 @@ impl GsmInterface for Store {
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
 
+    #[instrument(skip_all)]
+    async fn list_gsm_rules(
+        &self,
+        constraints: storage::GatewayStatusMapListConstraints,
+    ) -> CustomResult<Vec<storage::GatewayStatusMap>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::GatewayStatusMap::list_by_constraints(&conn, constraints)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
     #[instrument(skip_all)]
     async fn delete_gsm_rule(
         &self,
@@ -126,6 +156,59 @@ impl GsmInterface for Store {
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
+
+    #[instrument(skip_all)]
+    async fn find_gsm_rules_by_connectors(
+        &self,
+        connectors: Vec<String>,
+    ) -> CustomResult<Vec<storage::GatewayStatusMap>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::GatewayStatusMap::find_by_connectors(&conn, connectors)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn import_gsm_rules(
+        &self,
+        rules_to_create: Vec<storage::GatewayStatusMappingNew>,
+        rules_to_update: Vec<storage::GatewayStatusMappingNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        conn.transaction_async(|conn| async move {
+            for rule in rules_to_create {
+                rule.insert(&conn)
+                    .await
+                    .map_err(|error| *error.current_context())?;
+            }
+
+            for rule in rules_to_update {
+                storage::GatewayStatusMap::update(
+                    &conn,
+                    rule.connector,
+                    rule.flow,
+                    rule.sub_flow,
+                    rule.code,
+                    rule.message,
+                    storage::GatewayStatusMappingUpdate {
+                        decision: Some(rule.decision),
+                        status: Some(rule.status),
+                        router_error: Some(rule.router_error),
+                        step_up_possible: Some(rule.step_up_possible),
+                        unified_code: rule.unified_code,
+                        unified_message: rule.unified_message,
+                        retry_schedule: rule.retry_schedule,
+                    },
+                )
+                .await
+                .map_err(|error| *error.current_context())?;
+            }
+
+            Ok::<_, errors::DatabaseError>(())
+        })
+        .await
+        .map_err(|error| report!(errors::StorageError::from(report!(error))))
+    }
 }
 
 #[async_trait::async_trait]
@@ -171,6 +254,13 @@ impl GsmInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
+    async fn list_gsm_rules(
+        &self,
+        _constraints: storage::GatewayStatusMapListConstraints,
+    ) -> CustomResult<Vec<storage::GatewayStatusMap>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
     async fn delete_gsm_rule(
         &self,
         _connector: String,
@@ -181,4 +271,19 @@ impl GsmInterface for MockDb {
     ) -> CustomResult<bool, errors::StorageError> {
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn find_gsm_rules_by_connectors(
+        &self,
+        _connectors: Vec<String>,
+    ) -> CustomResult<Vec<storage::GatewayStatusMap>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn import_gsm_rules(
+        &self,
+        _rules_to_create: Vec<storage::GatewayStatusMappingNew>,
+        _rules_to_update: Vec<storage::GatewayStatusMappingNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
 }
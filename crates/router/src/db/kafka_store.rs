@@ -44,6 +44,7 @@ use crate::{
     db::{
         address::AddressInterface,
         api_keys::ApiKeyInterface,
+        audit_log::AuditLogInterface,
         authentication::AuthenticationInterface,
         authorization::AuthorizationInterface,
         business_profile::BusinessProfileInterface,
@@ -65,9 +66,15 @@ use crate::{
         merchant_key_store::MerchantKeyStoreInterface,
         payment_link::PaymentLinkInterface,
         payment_method::PaymentMethodInterface,
+        payout_batch::PayoutBatchInterface,
         refund::RefundInterface,
         reverse_lookup::ReverseLookupInterface,
         routing_algorithm::RoutingAlgorithmInterface,
+        subscription::SubscriptionInterface,
+        tenant::TenantInterface,
+        unified_translations::UnifiedTranslationsInterface,
+        webhook_endpoint::WebhookEndpointInterface,
+        webhook_signing_secret::WebhookSigningSecretInterface,
         CommonStorageInterface, GlobalStorageInterface, MasterKeyInterface, StorageInterface,
     },
     services::{authentication, kafka::KafkaProducer, Store},
@@ -247,6 +254,26 @@ impl ApiKeyInterface for KafkaStore {
     }
 }
 
+#[async_trait::async_trait]
+impl AuditLogInterface for KafkaStore {
+    async fn insert_audit_log(
+        &self,
+        audit_log: storage::AuditLogNew,
+    ) -> CustomResult<storage::AuditLog, errors::StorageError> {
+        self.diesel_store.insert_audit_log(audit_log).await
+    }
+
+    async fn list_audit_logs_by_merchant_id_constraints(
+        &self,
+        merchant_id: &str,
+        constraints: storage::AuditLogListConstraints,
+    ) -> CustomResult<Vec<storage::AuditLog>, errors::StorageError> {
+        self.diesel_store
+            .list_audit_logs_by_merchant_id_constraints(merchant_id, constraints)
+            .await
+    }
+}
+
 #[async_trait::async_trait]
 impl CardsInfoInterface for KafkaStore {
     async fn get_card_info(
@@ -1536,6 +1563,32 @@ impl PaymentMethodInterface for KafkaStore {
             .await
     }
 
+    async fn find_account_update_candidates_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        expiry_cutoff: PrimitiveDateTime,
+        after_id: Option<i32>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
+        self.diesel_store
+            .find_account_update_candidates_by_merchant_id(
+                merchant_id,
+                expiry_cutoff,
+                after_id,
+                limit,
+            )
+            .await
+    }
+
+    async fn find_payment_method_by_network_token_reference_id(
+        &self,
+        network_token_reference_id: &str,
+    ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
+        self.diesel_store
+            .find_payment_method_by_network_token_reference_id(network_token_reference_id)
+            .await
+    }
+
     async fn insert_payment_method(
         &self,
         m: storage::PaymentMethodNew,
@@ -1777,6 +1830,207 @@ impl PayoutsInterface for KafkaStore {
     }
 }
 
+#[async_trait::async_trait]
+impl PayoutBatchInterface for KafkaStore {
+    async fn insert_payout_batch(
+        &self,
+        payout_batch: storage::PayoutBatchNew,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        self.diesel_store.insert_payout_batch(payout_batch).await
+    }
+
+    async fn find_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &str,
+        batch_id: &str,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        self.diesel_store
+            .find_payout_batch_by_merchant_id_batch_id(merchant_id, batch_id)
+            .await
+    }
+
+    async fn update_payout_batch(
+        &self,
+        this: storage::PayoutBatch,
+        payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        self.diesel_store
+            .update_payout_batch(this, payout_batch_update)
+            .await
+    }
+
+    async fn insert_payout_batch_items(
+        &self,
+        payout_batch_items: Vec<storage::PayoutBatchItemNew>,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        self.diesel_store
+            .insert_payout_batch_items(payout_batch_items)
+            .await
+    }
+
+    async fn list_payout_batch_items_by_batch_id_constraints(
+        &self,
+        batch_id: &str,
+        constraints: storage::PayoutBatchItemListConstraints,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        self.diesel_store
+            .list_payout_batch_items_by_batch_id_constraints(batch_id, constraints)
+            .await
+    }
+
+    async fn get_payout_batch_item_status_counts(
+        &self,
+        batch_id: &str,
+    ) -> CustomResult<Vec<(enums::PayoutBatchItemStatus, i64)>, errors::StorageError> {
+        self.diesel_store
+            .get_payout_batch_item_status_counts(batch_id)
+            .await
+    }
+
+    async fn update_payout_batch_item(
+        &self,
+        this: storage::PayoutBatchItem,
+        payout_batch_item_update: storage::PayoutBatchItemUpdate,
+    ) -> CustomResult<storage::PayoutBatchItem, errors::StorageError> {
+        self.diesel_store
+            .update_payout_batch_item(this, payout_batch_item_update)
+            .await
+    }
+
+    async fn update_payout_batch_items_by_batch_id_status(
+        &self,
+        batch_id: &str,
+        status: enums::PayoutBatchItemStatus,
+        payout_batch_item_update: storage::PayoutBatchItemUpdate,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        self.diesel_store
+            .update_payout_batch_items_by_batch_id_status(
+                batch_id,
+                status,
+                payout_batch_item_update,
+            )
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl SubscriptionInterface for KafkaStore {
+    async fn insert_subscription_plan(
+        &self,
+        subscription_plan: storage::SubscriptionPlanNew,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError> {
+        self.diesel_store
+            .insert_subscription_plan(subscription_plan)
+            .await
+    }
+
+    async fn find_subscription_plan_by_merchant_id_plan_id(
+        &self,
+        merchant_id: &str,
+        plan_id: &str,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError> {
+        self.diesel_store
+            .find_subscription_plan_by_merchant_id_plan_id(merchant_id, plan_id)
+            .await
+    }
+
+    async fn list_subscription_plans_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        constraints: storage::SubscriptionPlanListConstraints,
+    ) -> CustomResult<Vec<storage::SubscriptionPlan>, errors::StorageError> {
+        self.diesel_store
+            .list_subscription_plans_by_merchant_id(merchant_id, constraints)
+            .await
+    }
+
+    async fn update_subscription_plan(
+        &self,
+        this: storage::SubscriptionPlan,
+        subscription_plan_update: storage::SubscriptionPlanUpdate,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError> {
+        self.diesel_store
+            .update_subscription_plan(this, subscription_plan_update)
+            .await
+    }
+
+    async fn insert_subscription(
+        &self,
+        subscription: storage::SubscriptionNew,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        self.diesel_store.insert_subscription(subscription).await
+    }
+
+    async fn find_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &str,
+        subscription_id: &str,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        self.diesel_store
+            .find_subscription_by_merchant_id_subscription_id(merchant_id, subscription_id)
+            .await
+    }
+
+    async fn list_subscriptions_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        constraints: storage::SubscriptionListConstraints,
+    ) -> CustomResult<Vec<storage::Subscription>, errors::StorageError> {
+        self.diesel_store
+            .list_subscriptions_by_merchant_id(merchant_id, constraints)
+            .await
+    }
+
+    async fn update_subscription(
+        &self,
+        this: storage::Subscription,
+        subscription_update: storage::SubscriptionUpdate,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        self.diesel_store
+            .update_subscription(this, subscription_update)
+            .await
+    }
+
+    async fn insert_subscription_invoice(
+        &self,
+        subscription_invoice: storage::SubscriptionInvoiceNew,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError> {
+        self.diesel_store
+            .insert_subscription_invoice(subscription_invoice)
+            .await
+    }
+
+    async fn list_subscription_invoices_by_subscription_id(
+        &self,
+        subscription_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::SubscriptionInvoice>, errors::StorageError> {
+        self.diesel_store
+            .list_subscription_invoices_by_subscription_id(subscription_id, limit, offset)
+            .await
+    }
+
+    async fn find_unpaid_subscription_invoice_by_subscription_id(
+        &self,
+        subscription_id: &str,
+    ) -> CustomResult<Option<storage::SubscriptionInvoice>, errors::StorageError> {
+        self.diesel_store
+            .find_unpaid_subscription_invoice_by_subscription_id(subscription_id)
+            .await
+    }
+
+    async fn update_subscription_invoice(
+        &self,
+        this: storage::SubscriptionInvoice,
+        subscription_invoice_update: storage::SubscriptionInvoiceUpdate,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError> {
+        self.diesel_store
+            .update_subscription_invoice(this, subscription_invoice_update)
+            .await
+    }
+}
+
 #[async_trait::async_trait]
 impl ProcessTrackerInterface for KafkaStore {
     async fn reinitialize_limbo_processes(
@@ -2311,6 +2565,13 @@ impl GsmInterface for KafkaStore {
             .await
     }
 
+    async fn list_gsm_rules(
+        &self,
+        constraints: storage::GatewayStatusMapListConstraints,
+    ) -> CustomResult<Vec<storage::GatewayStatusMap>, errors::StorageError> {
+        self.diesel_store.list_gsm_rules(constraints).await
+    }
+
     async fn delete_gsm_rule(
         &self,
         connector: String,
@@ -2323,6 +2584,76 @@ impl GsmInterface for KafkaStore {
             .delete_gsm_rule(connector, flow, sub_flow, code, message)
             .await
     }
+
+    async fn find_gsm_rules_by_connectors(
+        &self,
+        connectors: Vec<String>,
+    ) -> CustomResult<Vec<storage::GatewayStatusMap>, errors::StorageError> {
+        self.diesel_store
+            .find_gsm_rules_by_connectors(connectors)
+            .await
+    }
+
+    async fn import_gsm_rules(
+        &self,
+        rules_to_create: Vec<storage::GatewayStatusMappingNew>,
+        rules_to_update: Vec<storage::GatewayStatusMappingNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        self.diesel_store
+            .import_gsm_rules(rules_to_create, rules_to_update)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl UnifiedTranslationsInterface for KafkaStore {
+    async fn add_unified_translation(
+        &self,
+        translation: storage::UnifiedTranslationsNew,
+    ) -> CustomResult<storage::UnifiedTranslations, errors::StorageError> {
+        self.diesel_store.add_unified_translation(translation).await
+    }
+
+    async fn find_translation(
+        &self,
+        unified_code: String,
+        unified_message: String,
+        locale: String,
+    ) -> CustomResult<storage::UnifiedTranslations, errors::StorageError> {
+        self.diesel_store
+            .find_translation(unified_code, unified_message, locale)
+            .await
+    }
+
+    async fn update_translation(
+        &self,
+        unified_code: String,
+        unified_message: String,
+        locale: String,
+        data: storage::UnifiedTranslationsUpdate,
+    ) -> CustomResult<storage::UnifiedTranslations, errors::StorageError> {
+        self.diesel_store
+            .update_translation(unified_code, unified_message, locale, data)
+            .await
+    }
+
+    async fn list_translations(
+        &self,
+        constraints: storage::UnifiedTranslationsListConstraints,
+    ) -> CustomResult<Vec<storage::UnifiedTranslations>, errors::StorageError> {
+        self.diesel_store.list_translations(constraints).await
+    }
+
+    async fn delete_translation(
+        &self,
+        unified_code: String,
+        unified_message: String,
+        locale: String,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_translation(unified_code, unified_message, locale)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -2960,6 +3291,46 @@ impl UserKeyStoreInterface for KafkaStore {
     }
 }
 
+#[async_trait::async_trait]
+impl TenantInterface for KafkaStore {
+    async fn insert_tenant(
+        &self,
+        tenant: storage::TenantNew,
+    ) -> CustomResult<storage::Tenant, errors::StorageError> {
+        self.diesel_store.insert_tenant(tenant).await
+    }
+
+    async fn find_tenant_by_tenant_id(
+        &self,
+        tenant_id: &str,
+    ) -> CustomResult<storage::Tenant, errors::StorageError> {
+        self.diesel_store.find_tenant_by_tenant_id(tenant_id).await
+    }
+
+    async fn list_tenants(&self) -> CustomResult<Vec<storage::Tenant>, errors::StorageError> {
+        self.diesel_store.list_tenants().await
+    }
+
+    async fn update_tenant_by_tenant_id(
+        &self,
+        tenant_id: &str,
+        tenant_update: storage::TenantUpdate,
+    ) -> CustomResult<storage::Tenant, errors::StorageError> {
+        self.diesel_store
+            .update_tenant_by_tenant_id(tenant_id, tenant_update)
+            .await
+    }
+
+    async fn delete_tenant_by_tenant_id(
+        &self,
+        tenant_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_tenant_by_tenant_id(tenant_id)
+            .await
+    }
+}
+
 #[async_trait::async_trait]
 impl UserAuthenticationMethodInterface for KafkaStore {
     async fn insert_user_authentication_method(
@@ -3008,3 +3379,108 @@ impl UserAuthenticationMethodInterface for KafkaStore {
             .await
     }
 }
+
+#[async_trait::async_trait]
+impl WebhookSigningSecretInterface for KafkaStore {
+    async fn insert_webhook_signing_secret(
+        &self,
+        webhook_signing_secret: domain::WebhookSigningSecret,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookSigningSecret, errors::StorageError> {
+        self.diesel_store
+            .insert_webhook_signing_secret(webhook_signing_secret, merchant_key_store)
+            .await
+    }
+
+    async fn list_webhook_signing_secrets_by_profile_id(
+        &self,
+        profile_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::WebhookSigningSecret>, errors::StorageError> {
+        self.diesel_store
+            .list_webhook_signing_secrets_by_profile_id(profile_id, merchant_key_store)
+            .await
+    }
+
+    async fn update_webhook_signing_secret_by_profile_id_secret_id(
+        &self,
+        profile_id: &str,
+        secret_id: &str,
+        webhook_signing_secret_update: storage::WebhookSigningSecretUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookSigningSecret, errors::StorageError> {
+        self.diesel_store
+            .update_webhook_signing_secret_by_profile_id_secret_id(
+                profile_id,
+                secret_id,
+                webhook_signing_secret_update,
+                merchant_key_store,
+            )
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookEndpointInterface for KafkaStore {
+    async fn insert_webhook_endpoint(
+        &self,
+        webhook_endpoint: domain::WebhookEndpoint,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookEndpoint, errors::StorageError> {
+        self.diesel_store
+            .insert_webhook_endpoint(webhook_endpoint, merchant_key_store)
+            .await
+    }
+
+    async fn find_webhook_endpoint_by_profile_id_endpoint_id(
+        &self,
+        profile_id: &str,
+        endpoint_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookEndpoint, errors::StorageError> {
+        self.diesel_store
+            .find_webhook_endpoint_by_profile_id_endpoint_id(
+                profile_id,
+                endpoint_id,
+                merchant_key_store,
+            )
+            .await
+    }
+
+    async fn list_webhook_endpoints_by_profile_id(
+        &self,
+        profile_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::WebhookEndpoint>, errors::StorageError> {
+        self.diesel_store
+            .list_webhook_endpoints_by_profile_id(profile_id, merchant_key_store)
+            .await
+    }
+
+    async fn update_webhook_endpoint_by_profile_id_endpoint_id(
+        &self,
+        profile_id: &str,
+        endpoint_id: &str,
+        webhook_endpoint_update: storage::WebhookEndpointUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookEndpoint, errors::StorageError> {
+        self.diesel_store
+            .update_webhook_endpoint_by_profile_id_endpoint_id(
+                profile_id,
+                endpoint_id,
+                webhook_endpoint_update,
+                merchant_key_store,
+            )
+            .await
+    }
+
+    async fn delete_webhook_endpoint_by_profile_id_endpoint_id(
+        &self,
+        profile_id: &str,
+        endpoint_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_webhook_endpoint_by_profile_id_endpoint_id(profile_id, endpoint_id)
+            .await
+    }
+}
@@ -1,6 +1,7 @@
 use common_utils::id_type;
 use diesel_models::payment_method::PaymentMethodUpdateInternal;
 use error_stack::ResultExt;
+use time::PrimitiveDateTime;
 
 use super::MockDb;
 use crate::{
@@ -22,6 +23,11 @@ pub trait PaymentMethodInterface {
         storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<storage_types::PaymentMethod, errors::StorageError>;
 
+    async fn find_payment_method_by_network_token_reference_id(
+        &self,
+        network_token_reference_id: &str,
+    ) -> CustomResult<storage_types::PaymentMethod, errors::StorageError>;
+
     async fn find_payment_method_by_customer_id_merchant_id_list(
         &self,
         customer_id: &id_type::CustomerId,
@@ -29,6 +35,14 @@ pub trait PaymentMethodInterface {
         limit: Option<i64>,
     ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError>;
 
+    async fn find_account_update_candidates_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        expiry_cutoff: PrimitiveDateTime,
+        after_id: Option<i32>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError>;
+
     async fn find_payment_method_by_customer_id_merchant_id_status(
         &self,
         customer_id: &id_type::CustomerId,
@@ -75,6 +89,7 @@ mod storage {
     use storage_impl::redis::kv_store::{
         decide_storage_scheme, kv_wrapper, KvOperation, Op, PartitionKey,
     };
+    use time::PrimitiveDateTime;
 
     use super::PaymentMethodInterface;
     use crate::{
@@ -185,6 +200,42 @@ mod storage {
                 }
             }
         }
+        // not supported in kv
+        #[instrument(skip_all)]
+        async fn find_account_update_candidates_by_merchant_id(
+            &self,
+            merchant_id: &str,
+            expiry_cutoff: PrimitiveDateTime,
+            after_id: Option<i32>,
+            limit: i64,
+        ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::PaymentMethod::find_account_update_candidates_by_merchant_id(
+                &conn,
+                merchant_id,
+                expiry_cutoff,
+                after_id,
+                limit,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        // not supported in kv
+        #[instrument(skip_all)]
+        async fn find_payment_method_by_network_token_reference_id(
+            &self,
+            network_token_reference_id: &str,
+        ) -> CustomResult<storage_types::PaymentMethod, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::PaymentMethod::find_by_network_token_reference_id(
+                &conn,
+                network_token_reference_id,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
         // not supported in kv
         #[instrument(skip_all)]
         async fn get_payment_method_count_by_customer_id_merchant_id_status(
@@ -460,6 +511,7 @@ mod storage {
     use common_utils::id_type;
     use error_stack::report;
     use router_env::{instrument, tracing};
+    use time::PrimitiveDateTime;
 
     use super::PaymentMethodInterface;
     use crate::{
@@ -495,6 +547,40 @@ mod storage {
                 .map_err(|error| report!(errors::StorageError::from(error)))
         }
 
+        #[instrument(skip_all)]
+        async fn find_account_update_candidates_by_merchant_id(
+            &self,
+            merchant_id: &str,
+            expiry_cutoff: PrimitiveDateTime,
+            after_id: Option<i32>,
+            limit: i64,
+        ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::PaymentMethod::find_account_update_candidates_by_merchant_id(
+                &conn,
+                merchant_id,
+                expiry_cutoff,
+                after_id,
+                limit,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[instrument(skip_all)]
+        async fn find_payment_method_by_network_token_reference_id(
+            &self,
+            network_token_reference_id: &str,
+        ) -> CustomResult<storage_types::PaymentMethod, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::PaymentMethod::find_by_network_token_reference_id(
+                &conn,
+                network_token_reference_id,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
         #[instrument(skip_all)]
         async fn get_payment_method_count_by_customer_id_merchant_id_status(
             &self,
@@ -638,6 +724,54 @@ impl PaymentMethodInterface for MockDb {
         }
     }
 
+    async fn find_account_update_candidates_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        expiry_cutoff: PrimitiveDateTime,
+        after_id: Option<i32>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError> {
+        let payment_methods = self.payment_methods.lock().await;
+
+        Ok(payment_methods
+            .iter()
+            .filter(|pm| {
+                pm.merchant_id == merchant_id
+                    && pm.payment_method == Some(common_enums::PaymentMethod::Card)
+                    && pm.status == common_enums::PaymentMethodStatus::Active
+                    && pm.locker_id.is_some()
+                    && after_id.map_or(true, |after_id| pm.id > after_id)
+                    && (pm.account_update_requested_at.is_some()
+                        || pm
+                            .card_expiry_month_start
+                            .map_or(true, |expiry_month_start| {
+                                expiry_month_start <= expiry_cutoff
+                            }))
+            })
+            .take(usize::try_from(limit).unwrap_or(usize::MAX))
+            .cloned()
+            .collect())
+    }
+
+    async fn find_payment_method_by_network_token_reference_id(
+        &self,
+        network_token_reference_id: &str,
+    ) -> CustomResult<storage_types::PaymentMethod, errors::StorageError> {
+        let payment_methods = self.payment_methods.lock().await;
+        let payment_method = payment_methods
+            .iter()
+            .find(|pm| pm.network_token_reference_id.as_deref() == Some(network_token_reference_id))
+            .cloned();
+
+        match payment_method {
+            Some(pm) => Ok(pm),
+            None => Err(errors::StorageError::ValueNotFound(
+                "cannot find payment method".to_string(),
+            )
+            .into()),
+        }
+    }
+
     async fn get_payment_method_count_by_customer_id_merchant_id_status(
         &self,
         customer_id: &id_type::CustomerId,
@@ -696,6 +830,11 @@ impl PaymentMethodInterface for MockDb {
             network_transaction_id: payment_method_new.network_transaction_id,
             updated_by: payment_method_new.updated_by,
             payment_method_billing_address: payment_method_new.payment_method_billing_address,
+            network_token_reference_id: payment_method_new.network_token_reference_id,
+            network_token_status: payment_method_new.network_token_status,
+            network_token_data: payment_method_new.network_token_data,
+            account_update_requested_at: payment_method_new.account_update_requested_at,
+            card_expiry_month_start: payment_method_new.card_expiry_month_start,
         };
         payment_methods.push(payment_method.clone());
         Ok(payment_method)
@@ -802,3 +941,133 @@ impl PaymentMethodInterface for MockDb {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use common_utils::id_type;
+    use time::{macros::datetime, PrimitiveDateTime};
+
+    use crate::{
+        db::{payment_method::PaymentMethodInterface, MockDb},
+        types::storage::{self as storage_types, enums},
+    };
+
+    fn get_payment_method_new(
+        payment_method_id: &str,
+        locker_id: Option<&str>,
+        account_update_requested_at: Option<PrimitiveDateTime>,
+        card_expiry_month_start: Option<PrimitiveDateTime>,
+    ) -> storage_types::PaymentMethodNew {
+        let current_time = datetime!(2026-01-01 00:00);
+
+        storage_types::PaymentMethodNew {
+            customer_id: id_type::CustomerId::from("cus_123".into()).unwrap(),
+            merchant_id: "merchant_123".to_string(),
+            payment_method_id: payment_method_id.to_string(),
+            payment_method: Some(enums::PaymentMethod::Card),
+            payment_method_type: None,
+            payment_method_issuer: None,
+            payment_method_issuer_code: None,
+            accepted_currency: None,
+            scheme: None,
+            token: None,
+            cardholder_name: None,
+            issuer_name: None,
+            issuer_country: None,
+            payer_country: None,
+            is_stored: None,
+            swift_code: None,
+            direct_debit_token: None,
+            created_at: current_time,
+            last_modified: current_time,
+            metadata: None,
+            payment_method_data: None,
+            locker_id: locker_id.map(str::to_string),
+            last_used_at: current_time,
+            connector_mandate_details: None,
+            customer_acceptance: None,
+            status: enums::PaymentMethodStatus::Active,
+            network_transaction_id: None,
+            client_secret: None,
+            payment_method_billing_address: None,
+            updated_by: None,
+            network_token_reference_id: None,
+            network_token_status: None,
+            network_token_data: None,
+            account_update_requested_at,
+            card_expiry_month_start,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mockdb_find_account_update_candidates() {
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+        let expiry_cutoff = datetime!(2026-03-15 00:00);
+
+        for payment_method_new in [
+            get_payment_method_new(
+                "pm_expiring",
+                Some("card_1"),
+                None,
+                Some(datetime!(2026-03-01 00:00)),
+            ),
+            get_payment_method_new(
+                "pm_flagged",
+                Some("card_2"),
+                Some(datetime!(2026-01-10 00:00)),
+                Some(datetime!(2028-01-01 00:00)),
+            ),
+            get_payment_method_new("pm_unknown_expiry", Some("card_3"), None, None),
+            get_payment_method_new(
+                "pm_not_expiring",
+                Some("card_4"),
+                None,
+                Some(datetime!(2026-04-01 00:00)),
+            ),
+            get_payment_method_new(
+                "pm_not_in_locker",
+                None,
+                None,
+                Some(datetime!(2026-01-01 00:00)),
+            ),
+        ] {
+            mockdb
+                .insert_payment_method(
+                    payment_method_new,
+                    enums::MerchantStorageScheme::PostgresOnly,
+                )
+                .await
+                .unwrap();
+        }
+
+        let candidates = mockdb
+            .find_account_update_candidates_by_merchant_id("merchant_123", expiry_cutoff, None, 10)
+            .await
+            .unwrap();
+        let candidate_ids = candidates
+            .iter()
+            .map(|payment_method| payment_method.payment_method_id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            candidate_ids,
+            ["pm_expiring", "pm_flagged", "pm_unknown_expiry"]
+        );
+
+        // The candidates are fetched in pages after the last fetched payment method
+        let next_page = mockdb
+            .find_account_update_candidates_by_merchant_id(
+                "merchant_123",
+                expiry_cutoff,
+                candidates.first().map(|payment_method| payment_method.id),
+                1,
+            )
+            .await
+            .unwrap();
+        assert_eq!(next_page.len(), 1);
+        assert_eq!(next_page.first().unwrap().payment_method_id, "pm_flagged");
+    }
+}
@@ -0,0 +1,424 @@
+use diesel_models::{enums, payout_batch as storage};
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::MockDb;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    services::Store,
+};
+
+#[async_trait::async_trait]
+pub trait PayoutBatchInterface {
+    async fn insert_payout_batch(
+        &self,
+        payout_batch: storage::PayoutBatchNew,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError>;
+
+    async fn find_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &str,
+        batch_id: &str,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError>;
+
+    async fn update_payout_batch(
+        &self,
+        this: storage::PayoutBatch,
+        payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError>;
+
+    async fn insert_payout_batch_items(
+        &self,
+        payout_batch_items: Vec<storage::PayoutBatchItemNew>,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError>;
+
+    async fn list_payout_batch_items_by_batch_id_constraints(
+        &self,
+        batch_id: &str,
+        constraints: storage::PayoutBatchItemListConstraints,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError>;
+
+    async fn get_payout_batch_item_status_counts(
+        &self,
+        batch_id: &str,
+    ) -> CustomResult<Vec<(enums::PayoutBatchItemStatus, i64)>, errors::StorageError>;
+
+    async fn update_payout_batch_item(
+        &self,
+        this: storage::PayoutBatchItem,
+        payout_batch_item_update: storage::PayoutBatchItemUpdate,
+    ) -> CustomResult<storage::PayoutBatchItem, errors::StorageError>;
+
+    async fn update_payout_batch_items_by_batch_id_status(
+        &self,
+        batch_id: &str,
+        status: enums::PayoutBatchItemStatus,
+        payout_batch_item_update: storage::PayoutBatchItemUpdate,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PayoutBatchInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_payout_batch(
+        &self,
+        payout_batch: storage::PayoutBatchNew,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        payout_batch
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &str,
+        batch_id: &str,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PayoutBatch::find_by_merchant_id_batch_id(&conn, merchant_id, batch_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payout_batch(
+        &self,
+        this: storage::PayoutBatch,
+        payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, payout_batch_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_payout_batch_items(
+        &self,
+        payout_batch_items: Vec<storage::PayoutBatchItemNew>,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PayoutBatchItem::insert_batch(&conn, payout_batch_items)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_payout_batch_items_by_batch_id_constraints(
+        &self,
+        batch_id: &str,
+        constraints: storage::PayoutBatchItemListConstraints,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PayoutBatchItem::list_by_batch_id_constraints(&conn, batch_id, constraints)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn get_payout_batch_item_status_counts(
+        &self,
+        batch_id: &str,
+    ) -> CustomResult<Vec<(enums::PayoutBatchItemStatus, i64)>, errors::StorageError> {
+        // Read from the master database, since the counts are used right after updating the items
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PayoutBatchItem::get_status_counts_by_batch_id(&conn, batch_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payout_batch_item(
+        &self,
+        this: storage::PayoutBatchItem,
+        payout_batch_item_update: storage::PayoutBatchItemUpdate,
+    ) -> CustomResult<storage::PayoutBatchItem, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, payout_batch_item_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payout_batch_items_by_batch_id_status(
+        &self,
+        batch_id: &str,
+        status: enums::PayoutBatchItemStatus,
+        payout_batch_item_update: storage::PayoutBatchItemUpdate,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PayoutBatchItem::update_by_batch_id_status(
+            &conn,
+            batch_id,
+            status,
+            payout_batch_item_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl PayoutBatchInterface for MockDb {
+    async fn insert_payout_batch(
+        &self,
+        payout_batch: storage::PayoutBatchNew,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let mut payout_batches = self.payout_batches.lock().await;
+        if payout_batches
+            .iter()
+            .any(|batch| batch.batch_id == payout_batch.batch_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "batch_id",
+                key: Some(payout_batch.batch_id.clone()),
+            })?
+        }
+        let payout_batch = storage::PayoutBatch {
+            batch_id: payout_batch.batch_id,
+            merchant_id: payout_batch.merchant_id,
+            file_id: payout_batch.file_id,
+            status: payout_batch.status,
+            concurrency: payout_batch.concurrency,
+            total_count: payout_batch.total_count,
+            succeeded_count: payout_batch.succeeded_count,
+            failed_count: payout_batch.failed_count,
+            cancelled_count: payout_batch.cancelled_count,
+            result_file_id: payout_batch.result_file_id,
+            created_at: payout_batch.created_at,
+            modified_at: payout_batch.modified_at,
+        };
+        payout_batches.push(payout_batch.clone());
+        Ok(payout_batch)
+    }
+
+    async fn find_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &str,
+        batch_id: &str,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        self.payout_batches
+            .lock()
+            .await
+            .iter()
+            .find(|batch| batch.merchant_id == merchant_id && batch.batch_id == batch_id)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No payout batch available for merchant_id = {merchant_id} and batch_id = {batch_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn update_payout_batch(
+        &self,
+        this: storage::PayoutBatch,
+        payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let mut payout_batches = self.payout_batches.lock().await;
+        let payout_batch = payout_batches
+            .iter_mut()
+            .find(|batch| batch.batch_id == this.batch_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No payout batch available for batch_id = {}",
+                this.batch_id
+            )))?;
+        *payout_batch =
+            storage::PayoutBatchUpdateInternal::from(payout_batch_update).apply_changeset(this);
+        Ok(payout_batch.clone())
+    }
+
+    async fn insert_payout_batch_items(
+        &self,
+        payout_batch_items: Vec<storage::PayoutBatchItemNew>,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        let mut stored_items = self.payout_batch_items.lock().await;
+        let payout_batch_items = payout_batch_items
+            .into_iter()
+            .map(|item| storage::PayoutBatchItem {
+                batch_id: item.batch_id,
+                row_number: item.row_number,
+                merchant_id: item.merchant_id,
+                payout_id: item.payout_id,
+                status: item.status,
+                payout_status: item.payout_status,
+                error_code: item.error_code,
+                error_message: item.error_message,
+                created_at: item.created_at,
+                modified_at: item.modified_at,
+            })
+            .collect::<Vec<_>>();
+        stored_items.extend(payout_batch_items.iter().cloned());
+        Ok(payout_batch_items)
+    }
+
+    async fn list_payout_batch_items_by_batch_id_constraints(
+        &self,
+        batch_id: &str,
+        constraints: storage::PayoutBatchItemListConstraints,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        let stored_items = self.payout_batch_items.lock().await;
+        let mut payout_batch_items = stored_items
+            .iter()
+            .filter(|item| {
+                item.batch_id == batch_id
+                    && constraints
+                        .status
+                        .map_or(true, |status| item.status == status)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        payout_batch_items.sort_by_key(|item| item.row_number);
+
+        let offset = constraints
+            .offset
+            .and_then(|offset| usize::try_from(offset).ok())
+            .unwrap_or(0);
+        let limit = constraints
+            .limit
+            .and_then(|limit| usize::try_from(limit).ok())
+            .unwrap_or(usize::MAX);
+
+        Ok(payout_batch_items
+            .into_iter()
+            .skip(offset)
+            .take(limit)
+            .collect())
+    }
+
+    async fn get_payout_batch_item_status_counts(
+        &self,
+        batch_id: &str,
+    ) -> CustomResult<Vec<(enums::PayoutBatchItemStatus, i64)>, errors::StorageError> {
+        let stored_items = self.payout_batch_items.lock().await;
+        let mut status_counts: Vec<(enums::PayoutBatchItemStatus, i64)> = Vec::new();
+        for item in stored_items.iter().filter(|item| item.batch_id == batch_id) {
+            match status_counts
+                .iter_mut()
+                .find(|(status, _)| *status == item.status)
+            {
+                Some((_, count)) => *count += 1,
+                None => status_counts.push((item.status, 1)),
+            }
+        }
+        Ok(status_counts)
+    }
+
+    async fn update_payout_batch_item(
+        &self,
+        this: storage::PayoutBatchItem,
+        payout_batch_item_update: storage::PayoutBatchItemUpdate,
+    ) -> CustomResult<storage::PayoutBatchItem, errors::StorageError> {
+        let mut stored_items = self.payout_batch_items.lock().await;
+        let payout_batch_item = stored_items
+            .iter_mut()
+            .find(|item| {
+                item.batch_id == this.batch_id
+                    && item.row_number == this.row_number
+                    && item.status == this.status
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No payout batch item available for batch_id = {}, row_number = {} and status = {}",
+                this.batch_id, this.row_number, this.status
+            )))?;
+        *payout_batch_item = storage::PayoutBatchItemUpdateInternal::from(payout_batch_item_update)
+            .apply_changeset(this);
+        Ok(payout_batch_item.clone())
+    }
+
+    async fn update_payout_batch_items_by_batch_id_status(
+        &self,
+        batch_id: &str,
+        status: enums::PayoutBatchItemStatus,
+        payout_batch_item_update: storage::PayoutBatchItemUpdate,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        let mut stored_items = self.payout_batch_items.lock().await;
+        let payout_batch_item_update =
+            storage::PayoutBatchItemUpdateInternal::from(payout_batch_item_update);
+        let mut updated_items = Vec::new();
+        for item in stored_items
+            .iter_mut()
+            .filter(|item| item.batch_id == batch_id && item.status == status)
+        {
+            *item = payout_batch_item_update
+                .clone()
+                .apply_changeset(item.clone());
+            updated_items.push(item.clone());
+        }
+        Ok(updated_items)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use diesel_models::{enums, payout_batch as storage};
+
+    use crate::db::{payout_batch::PayoutBatchInterface, MockDb};
+
+    #[allow(clippy::unwrap_used)]
+    #[tokio::test]
+    async fn test_mockdb_update_payout_batch_item_of_cancelled_batch() {
+        #[allow(clippy::expect_used)]
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+        let now = common_utils::date_time::now();
+        let item = mockdb
+            .insert_payout_batch_items(vec![storage::PayoutBatchItemNew {
+                batch_id: "pbatch_1".to_string(),
+                row_number: 1,
+                merchant_id: "merchant_1".to_string(),
+                payout_id: "payout_1".to_string(),
+                status: enums::PayoutBatchItemStatus::Pending,
+                payout_status: None,
+                error_code: None,
+                error_message: None,
+                created_at: now,
+                modified_at: now,
+            }])
+            .await
+            .unwrap()
+            .pop()
+            .unwrap();
+
+        mockdb
+            .update_payout_batch_items_by_batch_id_status(
+                "pbatch_1",
+                enums::PayoutBatchItemStatus::Pending,
+                storage::PayoutBatchItemUpdate::Cancelled,
+            )
+            .await
+            .unwrap();
+
+        // The row read before it was cancelled must not overwrite the cancellation
+        let error = mockdb
+            .update_payout_batch_item(
+                item,
+                storage::PayoutBatchItemUpdate::PayoutCreated {
+                    payout_status: enums::PayoutStatus::Success,
+                },
+            )
+            .await
+            .unwrap_err();
+        assert!(error.current_context().is_db_not_found());
+
+        let items = mockdb
+            .list_payout_batch_items_by_batch_id_constraints(
+                "pbatch_1",
+                storage::PayoutBatchItemListConstraints::default(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(items.len(), 1);
+        assert!(items.iter().all(
+            |item| item.status == enums::PayoutBatchItemStatus::Cancelled
+                && item.payout_status.is_none()
+        ));
+    }
+}
@@ -397,6 +397,7 @@ mod storage {
                         updated_by: new.updated_by.clone(),
                         merchant_connector_id: new.merchant_connector_id.clone(),
                         charges: new.charges.clone(),
+                        split_refunds: new.split_refunds.clone(),
                     };
 
                     let field = format!(
@@ -852,6 +853,7 @@ impl RefundInterface for MockDb {
             updated_by: new.updated_by,
             merchant_connector_id: new.merchant_connector_id,
             charges: new.charges,
+            split_refunds: new.split_refunds,
         };
         refunds.push(refund.clone());
         Ok(refund)
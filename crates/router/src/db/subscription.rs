@@ -0,0 +1,555 @@
+use diesel_models::{enums, subscription as storage};
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::MockDb;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    services::Store,
+};
+
+#[async_trait::async_trait]
+pub trait SubscriptionInterface {
+    async fn insert_subscription_plan(
+        &self,
+        subscription_plan: storage::SubscriptionPlanNew,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError>;
+
+    async fn find_subscription_plan_by_merchant_id_plan_id(
+        &self,
+        merchant_id: &str,
+        plan_id: &str,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError>;
+
+    async fn list_subscription_plans_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        constraints: storage::SubscriptionPlanListConstraints,
+    ) -> CustomResult<Vec<storage::SubscriptionPlan>, errors::StorageError>;
+
+    async fn update_subscription_plan(
+        &self,
+        this: storage::SubscriptionPlan,
+        subscription_plan_update: storage::SubscriptionPlanUpdate,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError>;
+
+    async fn insert_subscription(
+        &self,
+        subscription: storage::SubscriptionNew,
+    ) -> CustomResult<storage::Subscription, errors::StorageError>;
+
+    async fn find_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &str,
+        subscription_id: &str,
+    ) -> CustomResult<storage::Subscription, errors::StorageError>;
+
+    async fn list_subscriptions_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        constraints: storage::SubscriptionListConstraints,
+    ) -> CustomResult<Vec<storage::Subscription>, errors::StorageError>;
+
+    async fn update_subscription(
+        &self,
+        this: storage::Subscription,
+        subscription_update: storage::SubscriptionUpdate,
+    ) -> CustomResult<storage::Subscription, errors::StorageError>;
+
+    async fn insert_subscription_invoice(
+        &self,
+        subscription_invoice: storage::SubscriptionInvoiceNew,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError>;
+
+    async fn list_subscription_invoices_by_subscription_id(
+        &self,
+        subscription_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::SubscriptionInvoice>, errors::StorageError>;
+
+    async fn find_unpaid_subscription_invoice_by_subscription_id(
+        &self,
+        subscription_id: &str,
+    ) -> CustomResult<Option<storage::SubscriptionInvoice>, errors::StorageError>;
+
+    async fn update_subscription_invoice(
+        &self,
+        this: storage::SubscriptionInvoice,
+        subscription_invoice_update: storage::SubscriptionInvoiceUpdate,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl SubscriptionInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_subscription_plan(
+        &self,
+        subscription_plan: storage::SubscriptionPlanNew,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        subscription_plan
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_subscription_plan_by_merchant_id_plan_id(
+        &self,
+        merchant_id: &str,
+        plan_id: &str,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SubscriptionPlan::find_by_merchant_id_plan_id(&conn, merchant_id, plan_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_subscription_plans_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        constraints: storage::SubscriptionPlanListConstraints,
+    ) -> CustomResult<Vec<storage::SubscriptionPlan>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SubscriptionPlan::list_by_merchant_id_constraints(&conn, merchant_id, constraints)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_subscription_plan(
+        &self,
+        this: storage::SubscriptionPlan,
+        subscription_plan_update: storage::SubscriptionPlanUpdate,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, subscription_plan_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_subscription(
+        &self,
+        subscription: storage::SubscriptionNew,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        subscription
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &str,
+        subscription_id: &str,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Subscription::find_by_merchant_id_subscription_id(
+            &conn,
+            merchant_id,
+            subscription_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_subscriptions_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        constraints: storage::SubscriptionListConstraints,
+    ) -> CustomResult<Vec<storage::Subscription>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Subscription::list_by_merchant_id_constraints(&conn, merchant_id, constraints)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_subscription(
+        &self,
+        this: storage::Subscription,
+        subscription_update: storage::SubscriptionUpdate,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, subscription_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_subscription_invoice(
+        &self,
+        subscription_invoice: storage::SubscriptionInvoiceNew,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        subscription_invoice
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_subscription_invoices_by_subscription_id(
+        &self,
+        subscription_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::SubscriptionInvoice>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SubscriptionInvoice::list_by_subscription_id(&conn, subscription_id, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_unpaid_subscription_invoice_by_subscription_id(
+        &self,
+        subscription_id: &str,
+    ) -> CustomResult<Option<storage::SubscriptionInvoice>, errors::StorageError> {
+        // Read from the master database, since the invoice is charged based on its status
+        let conn = connection::pg_connection_write(self).await?;
+        storage::SubscriptionInvoice::find_unpaid_by_subscription_id(&conn, subscription_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_subscription_invoice(
+        &self,
+        this: storage::SubscriptionInvoice,
+        subscription_invoice_update: storage::SubscriptionInvoiceUpdate,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, subscription_invoice_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl SubscriptionInterface for MockDb {
+    async fn insert_subscription_plan(
+        &self,
+        subscription_plan: storage::SubscriptionPlanNew,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError> {
+        let mut subscription_plans = self.subscription_plans.lock().await;
+        if subscription_plans
+            .iter()
+            .any(|plan| plan.plan_id == subscription_plan.plan_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "plan_id",
+                key: Some(subscription_plan.plan_id.clone()),
+            })?
+        }
+        let subscription_plan = storage::SubscriptionPlan {
+            plan_id: subscription_plan.plan_id,
+            merchant_id: subscription_plan.merchant_id,
+            name: subscription_plan.name,
+            description: subscription_plan.description,
+            amount: subscription_plan.amount,
+            currency: subscription_plan.currency,
+            billing_interval: subscription_plan.billing_interval,
+            billing_interval_count: subscription_plan.billing_interval_count,
+            trial_period_days: subscription_plan.trial_period_days,
+            active: subscription_plan.active,
+            metadata: subscription_plan.metadata,
+            created_at: subscription_plan.created_at,
+            modified_at: subscription_plan.modified_at,
+        };
+        subscription_plans.push(subscription_plan.clone());
+        Ok(subscription_plan)
+    }
+
+    async fn find_subscription_plan_by_merchant_id_plan_id(
+        &self,
+        merchant_id: &str,
+        plan_id: &str,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError> {
+        self.subscription_plans
+            .lock()
+            .await
+            .iter()
+            .find(|plan| plan.merchant_id == merchant_id && plan.plan_id == plan_id)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No subscription plan available for merchant_id = {merchant_id} and plan_id = {plan_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_subscription_plans_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        constraints: storage::SubscriptionPlanListConstraints,
+    ) -> CustomResult<Vec<storage::SubscriptionPlan>, errors::StorageError> {
+        let subscription_plans = self.subscription_plans.lock().await;
+        let mut subscription_plans = subscription_plans
+            .iter()
+            .filter(|plan| {
+                plan.merchant_id == merchant_id
+                    && constraints
+                        .active
+                        .map_or(true, |active| plan.active == active)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        subscription_plans.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+        Ok(subscription_plans
+            .into_iter()
+            .skip(get_offset(constraints.offset))
+            .take(get_limit(constraints.limit))
+            .collect())
+    }
+
+    async fn update_subscription_plan(
+        &self,
+        this: storage::SubscriptionPlan,
+        subscription_plan_update: storage::SubscriptionPlanUpdate,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError> {
+        let mut subscription_plans = self.subscription_plans.lock().await;
+        let subscription_plan = subscription_plans
+            .iter_mut()
+            .find(|plan| plan.plan_id == this.plan_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No subscription plan available for plan_id = {}",
+                this.plan_id
+            )))?;
+        *subscription_plan =
+            storage::SubscriptionPlanUpdateInternal::from(subscription_plan_update)
+                .apply_changeset(this);
+        Ok(subscription_plan.clone())
+    }
+
+    async fn insert_subscription(
+        &self,
+        subscription: storage::SubscriptionNew,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        let mut subscriptions = self.subscriptions.lock().await;
+        if subscriptions
+            .iter()
+            .any(|stored| stored.subscription_id == subscription.subscription_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "subscription_id",
+                key: Some(subscription.subscription_id.clone()),
+            })?
+        }
+        let subscription = storage::Subscription {
+            subscription_id: subscription.subscription_id,
+            merchant_id: subscription.merchant_id,
+            customer_id: subscription.customer_id,
+            plan_id: subscription.plan_id,
+            mandate_id: subscription.mandate_id,
+            profile_id: subscription.profile_id,
+            status: subscription.status,
+            billing_cycle_anchor: subscription.billing_cycle_anchor,
+            current_period_start: subscription.current_period_start,
+            current_period_end: subscription.current_period_end,
+            trial_end: subscription.trial_end,
+            next_billing_at: subscription.next_billing_at,
+            cancel_at_period_end: subscription.cancel_at_period_end,
+            cancelled_at: subscription.cancelled_at,
+            cancellation_reason: subscription.cancellation_reason,
+            metadata: subscription.metadata,
+            created_at: subscription.created_at,
+            modified_at: subscription.modified_at,
+        };
+        subscriptions.push(subscription.clone());
+        Ok(subscription)
+    }
+
+    async fn find_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &str,
+        subscription_id: &str,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        self.subscriptions
+            .lock()
+            .await
+            .iter()
+            .find(|subscription| {
+                subscription.merchant_id == merchant_id
+                    && subscription.subscription_id == subscription_id
+            })
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No subscription available for merchant_id = {merchant_id} and subscription_id = {subscription_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_subscriptions_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        constraints: storage::SubscriptionListConstraints,
+    ) -> CustomResult<Vec<storage::Subscription>, errors::StorageError> {
+        let subscriptions = self.subscriptions.lock().await;
+        let mut subscriptions = subscriptions
+            .iter()
+            .filter(|subscription| {
+                subscription.merchant_id == merchant_id
+                    && constraints
+                        .customer_id
+                        .as_ref()
+                        .map_or(true, |customer_id| subscription.customer_id == *customer_id)
+                    && constraints
+                        .plan_id
+                        .as_ref()
+                        .map_or(true, |plan_id| subscription.plan_id == *plan_id)
+                    && constraints
+                        .status
+                        .map_or(true, |status| subscription.status == status)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        subscriptions.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+        Ok(subscriptions
+            .into_iter()
+            .skip(get_offset(constraints.offset))
+            .take(get_limit(constraints.limit))
+            .collect())
+    }
+
+    async fn update_subscription(
+        &self,
+        this: storage::Subscription,
+        subscription_update: storage::SubscriptionUpdate,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        let mut subscriptions = self.subscriptions.lock().await;
+        let subscription = subscriptions
+            .iter_mut()
+            .find(|subscription| subscription.subscription_id == this.subscription_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No subscription available for subscription_id = {}",
+                this.subscription_id
+            )))?;
+        *subscription =
+            storage::SubscriptionUpdateInternal::from(subscription_update).apply_changeset(this);
+        Ok(subscription.clone())
+    }
+
+    async fn insert_subscription_invoice(
+        &self,
+        subscription_invoice: storage::SubscriptionInvoiceNew,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError> {
+        let mut subscription_invoices = self.subscription_invoices.lock().await;
+        if subscription_invoices
+            .iter()
+            .any(|invoice| invoice.invoice_id == subscription_invoice.invoice_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "invoice_id",
+                key: Some(subscription_invoice.invoice_id.clone()),
+            })?
+        }
+        let subscription_invoice = storage::SubscriptionInvoice {
+            invoice_id: subscription_invoice.invoice_id,
+            subscription_id: subscription_invoice.subscription_id,
+            merchant_id: subscription_invoice.merchant_id,
+            amount: subscription_invoice.amount,
+            currency: subscription_invoice.currency,
+            status: subscription_invoice.status,
+            period_start: subscription_invoice.period_start,
+            period_end: subscription_invoice.period_end,
+            payment_id: subscription_invoice.payment_id,
+            attempt_count: subscription_invoice.attempt_count,
+            next_attempt_at: subscription_invoice.next_attempt_at,
+            error_code: subscription_invoice.error_code,
+            error_message: subscription_invoice.error_message,
+            created_at: subscription_invoice.created_at,
+            modified_at: subscription_invoice.modified_at,
+        };
+        subscription_invoices.push(subscription_invoice.clone());
+        Ok(subscription_invoice)
+    }
+
+    async fn list_subscription_invoices_by_subscription_id(
+        &self,
+        subscription_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::SubscriptionInvoice>, errors::StorageError> {
+        let subscription_invoices = self.subscription_invoices.lock().await;
+        let mut subscription_invoices = subscription_invoices
+            .iter()
+            .filter(|invoice| invoice.subscription_id == subscription_id)
+            .cloned()
+            .collect::<Vec<_>>();
+
+        subscription_invoices.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+        Ok(subscription_invoices
+            .into_iter()
+            .skip(get_offset(offset))
+            .take(get_limit(limit))
+            .collect())
+    }
+
+    async fn find_unpaid_subscription_invoice_by_subscription_id(
+        &self,
+        subscription_id: &str,
+    ) -> CustomResult<Option<storage::SubscriptionInvoice>, errors::StorageError> {
+        Ok(self
+            .subscription_invoices
+            .lock()
+            .await
+            .iter()
+            .filter(|invoice| {
+                invoice.subscription_id == subscription_id
+                    && matches!(
+                        invoice.status,
+                        enums::SubscriptionInvoiceStatus::Open
+                            | enums::SubscriptionInvoiceStatus::Processing
+                    )
+            })
+            .max_by_key(|invoice| invoice.created_at)
+            .cloned())
+    }
+
+    async fn update_subscription_invoice(
+        &self,
+        this: storage::SubscriptionInvoice,
+        subscription_invoice_update: storage::SubscriptionInvoiceUpdate,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError> {
+        let mut subscription_invoices = self.subscription_invoices.lock().await;
+        let subscription_invoice = subscription_invoices
+            .iter_mut()
+            .find(|invoice| invoice.invoice_id == this.invoice_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No subscription invoice available for invoice_id = {}",
+                this.invoice_id
+            )))?;
+        *subscription_invoice =
+            storage::SubscriptionInvoiceUpdateInternal::from(subscription_invoice_update)
+                .apply_changeset(this);
+        Ok(subscription_invoice.clone())
+    }
+}
+
+fn get_offset(offset: Option<i64>) -> usize {
+    offset
+        .and_then(|offset| usize::try_from(offset).ok())
+        .unwrap_or(0)
+}
+
+fn get_limit(limit: Option<i64>) -> usize {
+    limit
+        .and_then(|limit| usize::try_from(limit).ok())
+        .unwrap_or(usize::MAX)
+}
@@ -0,0 +1,183 @@
+use common_utils::errors::CustomResult;
+use diesel_models::tenant as storage;
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use crate::{connection, core::errors, services::Store};
+
+#[async_trait::async_trait]
+pub trait TenantInterface {
+    async fn insert_tenant(
+        &self,
+        tenant: storage::TenantNew,
+    ) -> CustomResult<storage::Tenant, errors::StorageError>;
+
+    async fn find_tenant_by_tenant_id(
+        &self,
+        tenant_id: &str,
+    ) -> CustomResult<storage::Tenant, errors::StorageError>;
+
+    async fn list_tenants(&self) -> CustomResult<Vec<storage::Tenant>, errors::StorageError>;
+
+    async fn update_tenant_by_tenant_id(
+        &self,
+        tenant_id: &str,
+        tenant_update: storage::TenantUpdate,
+    ) -> CustomResult<storage::Tenant, errors::StorageError>;
+
+    async fn delete_tenant_by_tenant_id(
+        &self,
+        tenant_id: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl TenantInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_tenant(
+        &self,
+        tenant: storage::TenantNew,
+    ) -> CustomResult<storage::Tenant, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        tenant
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_tenant_by_tenant_id(
+        &self,
+        tenant_id: &str,
+    ) -> CustomResult<storage::Tenant, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Tenant::find_by_tenant_id(&conn, tenant_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_tenants(&self) -> CustomResult<Vec<storage::Tenant>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Tenant::list(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_tenant_by_tenant_id(
+        &self,
+        tenant_id: &str,
+        tenant_update: storage::TenantUpdate,
+    ) -> CustomResult<storage::Tenant, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::Tenant::update_by_tenant_id(&conn, tenant_id, tenant_update.into())
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_tenant_by_tenant_id(
+        &self,
+        tenant_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::Tenant::delete_by_tenant_id(&conn, tenant_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl TenantInterface for super::MockDb {
+    async fn insert_tenant(
+        &self,
+        tenant: storage::TenantNew,
+    ) -> CustomResult<storage::Tenant, errors::StorageError> {
+        let mut tenants = self.tenants.lock().await;
+
+        if tenants.iter().any(|existing_tenant| {
+            existing_tenant.tenant_id == tenant.tenant_id
+                || existing_tenant.schema_name == tenant.schema_name
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "tenant",
+                key: Some(tenant.tenant_id.clone()),
+            })?
+        }
+        let tenant = storage::Tenant {
+            tenant_id: tenant.tenant_id,
+            name: tenant.name,
+            base_url: tenant.base_url,
+            schema_name: tenant.schema_name,
+            redis_key_prefix: tenant.redis_key_prefix,
+            clickhouse_database: tenant.clickhouse_database,
+            status: tenant.status,
+            created_at: tenant.created_at,
+            modified_at: tenant.modified_at,
+        };
+        tenants.push(tenant.clone());
+        Ok(tenant)
+    }
+
+    async fn find_tenant_by_tenant_id(
+        &self,
+        tenant_id: &str,
+    ) -> CustomResult<storage::Tenant, errors::StorageError> {
+        let tenants = self.tenants.lock().await;
+
+        tenants
+            .iter()
+            .find(|tenant| tenant.tenant_id == tenant_id)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No tenant available for tenant_id = {tenant_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_tenants(&self) -> CustomResult<Vec<storage::Tenant>, errors::StorageError> {
+        Ok(self.tenants.lock().await.clone())
+    }
+
+    async fn update_tenant_by_tenant_id(
+        &self,
+        tenant_id: &str,
+        tenant_update: storage::TenantUpdate,
+    ) -> CustomResult<storage::Tenant, errors::StorageError> {
+        let mut tenants = self.tenants.lock().await;
+
+        tenants
+            .iter_mut()
+            .find(|tenant| tenant.tenant_id == tenant_id)
+            .map(|tenant| {
+                *tenant = storage::TenantUpdateInternal::from(tenant_update)
+                    .apply_changeset(tenant.clone());
+                tenant.clone()
+            })
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No tenant available for tenant_id = {tenant_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn delete_tenant_by_tenant_id(
+        &self,
+        tenant_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut tenants = self.tenants.lock().await;
+
+        let position = tenants
+            .iter()
+            .position(|tenant| tenant.tenant_id == tenant_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No tenant available for tenant_id = {tenant_id}"
+            )))?;
+        tenants.remove(position);
+        Ok(true)
+    }
+}
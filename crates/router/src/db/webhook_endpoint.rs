@@ -0,0 +1,277 @@
+use common_utils::ext_traits::AsyncExt;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::{
+        domain::{
+            self,
+            behaviour::{Conversion, ReverseConversion},
+        },
+        storage,
+    },
+};
+
+#[async_trait::async_trait]
+pub trait WebhookEndpointInterface
+where
+    domain::WebhookEndpoint:
+        Conversion<DstType = storage::WebhookEndpoint, NewDstType = storage::WebhookEndpointNew>,
+{
+    async fn insert_webhook_endpoint(
+        &self,
+        webhook_endpoint: domain::WebhookEndpoint,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookEndpoint, errors::StorageError>;
+
+    async fn find_webhook_endpoint_by_profile_id_endpoint_id(
+        &self,
+        profile_id: &str,
+        endpoint_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookEndpoint, errors::StorageError>;
+
+    async fn list_webhook_endpoints_by_profile_id(
+        &self,
+        profile_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::WebhookEndpoint>, errors::StorageError>;
+
+    async fn update_webhook_endpoint_by_profile_id_endpoint_id(
+        &self,
+        profile_id: &str,
+        endpoint_id: &str,
+        webhook_endpoint_update: storage::WebhookEndpointUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookEndpoint, errors::StorageError>;
+
+    async fn delete_webhook_endpoint_by_profile_id_endpoint_id(
+        &self,
+        profile_id: &str,
+        endpoint_id: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl WebhookEndpointInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_webhook_endpoint(
+        &self,
+        webhook_endpoint: domain::WebhookEndpoint,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookEndpoint, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        webhook_endpoint
+            .construct_new()
+            .await
+            .change_context(errors::StorageError::EncryptionError)?
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))?
+            .convert(merchant_key_store.key.get_inner())
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
+    #[instrument(skip_all)]
+    async fn find_webhook_endpoint_by_profile_id_endpoint_id(
+        &self,
+        profile_id: &str,
+        endpoint_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookEndpoint, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::WebhookEndpoint::find_by_profile_id_endpoint_id(&conn, profile_id, endpoint_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))?
+            .convert(merchant_key_store.key.get_inner())
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
+    #[instrument(skip_all)]
+    async fn list_webhook_endpoints_by_profile_id(
+        &self,
+        profile_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::WebhookEndpoint>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::WebhookEndpoint::list_by_profile_id(&conn, profile_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+            .async_and_then(|webhook_endpoints| async {
+                let mut domain_webhook_endpoints = Vec::with_capacity(webhook_endpoints.len());
+                for webhook_endpoint in webhook_endpoints.into_iter() {
+                    domain_webhook_endpoints.push(
+                        webhook_endpoint
+                            .convert(merchant_key_store.key.get_inner())
+                            .await
+                            .change_context(errors::StorageError::DecryptionError)?,
+                    );
+                }
+                Ok(domain_webhook_endpoints)
+            })
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_webhook_endpoint_by_profile_id_endpoint_id(
+        &self,
+        profile_id: &str,
+        endpoint_id: &str,
+        webhook_endpoint_update: storage::WebhookEndpointUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookEndpoint, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::WebhookEndpoint::update_by_profile_id_endpoint_id(
+            &conn,
+            profile_id,
+            endpoint_id,
+            webhook_endpoint_update.into(),
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))?
+        .convert(merchant_key_store.key.get_inner())
+        .await
+        .change_context(errors::StorageError::DecryptionError)
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_webhook_endpoint_by_profile_id_endpoint_id(
+        &self,
+        profile_id: &str,
+        endpoint_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::WebhookEndpoint::delete_by_profile_id_endpoint_id(&conn, profile_id, endpoint_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookEndpointInterface for MockDb {
+    async fn insert_webhook_endpoint(
+        &self,
+        webhook_endpoint: domain::WebhookEndpoint,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookEndpoint, errors::StorageError> {
+        let mut locked_webhook_endpoints = self.webhook_endpoints.lock().await;
+
+        let stored_webhook_endpoint = Conversion::convert(webhook_endpoint)
+            .await
+            .change_context(errors::StorageError::EncryptionError)?;
+
+        locked_webhook_endpoints.push(stored_webhook_endpoint.clone());
+
+        stored_webhook_endpoint
+            .convert(merchant_key_store.key.get_inner())
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
+    async fn find_webhook_endpoint_by_profile_id_endpoint_id(
+        &self,
+        profile_id: &str,
+        endpoint_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookEndpoint, errors::StorageError> {
+        let locked_webhook_endpoints = self.webhook_endpoints.lock().await;
+        locked_webhook_endpoints
+            .iter()
+            .find(|webhook_endpoint| {
+                webhook_endpoint.profile_id == profile_id
+                    && webhook_endpoint.endpoint_id == endpoint_id
+            })
+            .cloned()
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No webhook endpoint available for profile_id = {profile_id} and \
+                 endpoint_id = {endpoint_id}"
+            )))?
+            .convert(merchant_key_store.key.get_inner())
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
+    async fn list_webhook_endpoints_by_profile_id(
+        &self,
+        profile_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::WebhookEndpoint>, errors::StorageError> {
+        let locked_webhook_endpoints = self.webhook_endpoints.lock().await;
+        let mut webhook_endpoints = locked_webhook_endpoints
+            .iter()
+            .filter(|webhook_endpoint| webhook_endpoint.profile_id == profile_id)
+            .cloned()
+            .collect::<Vec<_>>();
+        webhook_endpoints.sort_by_key(|webhook_endpoint| webhook_endpoint.created_at);
+
+        let mut domain_webhook_endpoints = Vec::with_capacity(webhook_endpoints.len());
+        for webhook_endpoint in webhook_endpoints {
+            domain_webhook_endpoints.push(
+                webhook_endpoint
+                    .convert(merchant_key_store.key.get_inner())
+                    .await
+                    .change_context(errors::StorageError::DecryptionError)?,
+            );
+        }
+
+        Ok(domain_webhook_endpoints)
+    }
+
+    async fn update_webhook_endpoint_by_profile_id_endpoint_id(
+        &self,
+        profile_id: &str,
+        endpoint_id: &str,
+        webhook_endpoint_update: storage::WebhookEndpointUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookEndpoint, errors::StorageError> {
+        let mut locked_webhook_endpoints = self.webhook_endpoints.lock().await;
+        let webhook_endpoint_to_update = locked_webhook_endpoints
+            .iter_mut()
+            .find(|webhook_endpoint| {
+                webhook_endpoint.profile_id == profile_id
+                    && webhook_endpoint.endpoint_id == endpoint_id
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No webhook endpoint available for profile_id = {profile_id} and \
+                 endpoint_id = {endpoint_id}"
+            )))?;
+
+        *webhook_endpoint_to_update =
+            storage::WebhookEndpointUpdateInternal::from(webhook_endpoint_update)
+                .apply_changeset(webhook_endpoint_to_update.clone());
+
+        webhook_endpoint_to_update
+            .clone()
+            .convert(merchant_key_store.key.get_inner())
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
+    async fn delete_webhook_endpoint_by_profile_id_endpoint_id(
+        &self,
+        profile_id: &str,
+        endpoint_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut locked_webhook_endpoints = self.webhook_endpoints.lock().await;
+        let initial_length = locked_webhook_endpoints.len();
+        locked_webhook_endpoints.retain(|webhook_endpoint| {
+            !(webhook_endpoint.profile_id == profile_id
+                && webhook_endpoint.endpoint_id == endpoint_id)
+        });
+
+        if locked_webhook_endpoints.len() == initial_length {
+            return Err(errors::StorageError::ValueNotFound(format!(
+                "No webhook endpoint available for profile_id = {profile_id} and \
+                 endpoint_id = {endpoint_id}"
+            ))
+            .into());
+        }
+
+        Ok(true)
+    }
+}
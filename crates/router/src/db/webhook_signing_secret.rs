@@ -0,0 +1,194 @@
+use common_utils::ext_traits::AsyncExt;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::{
+        domain::{
+            self,
+            behaviour::{Conversion, ReverseConversion},
+        },
+        storage,
+    },
+};
+
+#[async_trait::async_trait]
+pub trait WebhookSigningSecretInterface
+where
+    domain::WebhookSigningSecret: Conversion<
+        DstType = storage::WebhookSigningSecret,
+        NewDstType = storage::WebhookSigningSecretNew,
+    >,
+{
+    async fn insert_webhook_signing_secret(
+        &self,
+        webhook_signing_secret: domain::WebhookSigningSecret,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookSigningSecret, errors::StorageError>;
+
+    async fn list_webhook_signing_secrets_by_profile_id(
+        &self,
+        profile_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::WebhookSigningSecret>, errors::StorageError>;
+
+    async fn update_webhook_signing_secret_by_profile_id_secret_id(
+        &self,
+        profile_id: &str,
+        secret_id: &str,
+        webhook_signing_secret_update: storage::WebhookSigningSecretUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookSigningSecret, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl WebhookSigningSecretInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_webhook_signing_secret(
+        &self,
+        webhook_signing_secret: domain::WebhookSigningSecret,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookSigningSecret, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        webhook_signing_secret
+            .construct_new()
+            .await
+            .change_context(errors::StorageError::EncryptionError)?
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))?
+            .convert(merchant_key_store.key.get_inner())
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
+    #[instrument(skip_all)]
+    async fn list_webhook_signing_secrets_by_profile_id(
+        &self,
+        profile_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::WebhookSigningSecret>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::WebhookSigningSecret::list_by_profile_id(&conn, profile_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+            .async_and_then(|webhook_signing_secrets| async {
+                let mut domain_webhook_signing_secrets =
+                    Vec::with_capacity(webhook_signing_secrets.len());
+                for webhook_signing_secret in webhook_signing_secrets.into_iter() {
+                    domain_webhook_signing_secrets.push(
+                        webhook_signing_secret
+                            .convert(merchant_key_store.key.get_inner())
+                            .await
+                            .change_context(errors::StorageError::DecryptionError)?,
+                    );
+                }
+                Ok(domain_webhook_signing_secrets)
+            })
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_webhook_signing_secret_by_profile_id_secret_id(
+        &self,
+        profile_id: &str,
+        secret_id: &str,
+        webhook_signing_secret_update: storage::WebhookSigningSecretUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookSigningSecret, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::WebhookSigningSecret::update_by_profile_id_secret_id(
+            &conn,
+            profile_id,
+            secret_id,
+            webhook_signing_secret_update.into(),
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))?
+        .convert(merchant_key_store.key.get_inner())
+        .await
+        .change_context(errors::StorageError::DecryptionError)
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookSigningSecretInterface for MockDb {
+    async fn insert_webhook_signing_secret(
+        &self,
+        webhook_signing_secret: domain::WebhookSigningSecret,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookSigningSecret, errors::StorageError> {
+        let mut locked_webhook_signing_secrets = self.webhook_signing_secrets.lock().await;
+
+        let stored_webhook_signing_secret = Conversion::convert(webhook_signing_secret)
+            .await
+            .change_context(errors::StorageError::EncryptionError)?;
+
+        locked_webhook_signing_secrets.push(stored_webhook_signing_secret.clone());
+
+        stored_webhook_signing_secret
+            .convert(merchant_key_store.key.get_inner())
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
+    async fn list_webhook_signing_secrets_by_profile_id(
+        &self,
+        profile_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::WebhookSigningSecret>, errors::StorageError> {
+        let locked_webhook_signing_secrets = self.webhook_signing_secrets.lock().await;
+        let mut webhook_signing_secrets = locked_webhook_signing_secrets
+            .iter()
+            .filter(|webhook_signing_secret| webhook_signing_secret.profile_id == profile_id)
+            .cloned()
+            .collect::<Vec<_>>();
+        webhook_signing_secrets
+            .sort_by_key(|webhook_signing_secret| webhook_signing_secret.created_at);
+
+        let mut domain_webhook_signing_secrets = Vec::with_capacity(webhook_signing_secrets.len());
+        for webhook_signing_secret in webhook_signing_secrets {
+            domain_webhook_signing_secrets.push(
+                webhook_signing_secret
+                    .convert(merchant_key_store.key.get_inner())
+                    .await
+                    .change_context(errors::StorageError::DecryptionError)?,
+            );
+        }
+
+        Ok(domain_webhook_signing_secrets)
+    }
+
+    async fn update_webhook_signing_secret_by_profile_id_secret_id(
+        &self,
+        profile_id: &str,
+        secret_id: &str,
+        webhook_signing_secret_update: storage::WebhookSigningSecretUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookSigningSecret, errors::StorageError> {
+        let mut locked_webhook_signing_secrets = self.webhook_signing_secrets.lock().await;
+        let webhook_signing_secret_to_update = locked_webhook_signing_secrets
+            .iter_mut()
+            .find(|webhook_signing_secret| {
+                webhook_signing_secret.profile_id == profile_id
+                    && webhook_signing_secret.secret_id == secret_id
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No webhook signing secret available for profile_id = {profile_id} and \
+                 secret_id = {secret_id}"
+            )))?;
+
+        *webhook_signing_secret_to_update =
+            storage::WebhookSigningSecretUpdateInternal::from(webhook_signing_secret_update)
+                .apply_changeset(webhook_signing_secret_to_update.clone());
+
+        webhook_signing_secret_to_update
+            .clone()
+            .convert(merchant_key_store.key.get_inner())
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+}
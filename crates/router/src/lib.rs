@@ -47,6 +47,7 @@ static ALLOC: mimalloc::MiMalloc = mimalloc::MiMalloc;
 /// Header Constants
 pub mod headers {
     pub const ACCEPT: &str = "Accept";
+    pub const ACCEPT_LANGUAGE: &str = "Accept-Language";
     pub const KEY: &str = "key";
     pub const API_KEY: &str = "API-KEY";
     pub const APIKEY: &str = "apikey";
@@ -70,6 +71,7 @@ pub mod headers {
     pub const X_ACCEPT_VERSION: &str = "X-Accept-Version";
     pub const X_DATE: &str = "X-Date";
     pub const X_WEBHOOK_SIGNATURE: &str = "X-Webhook-Signature-512";
+    pub const X_WEBHOOK_SIGNATURE_TIMESTAMPED: &str = "X-Webhook-Signature-Timestamped";
     pub const X_REQUEST_ID: &str = "X-Request-Id";
     pub const STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE: &str = "Stripe-Signature";
     pub const STRIPE_COMPATIBLE_CONNECT_ACCOUNT: &str = "Stripe-Account";
@@ -79,6 +81,7 @@ pub mod headers {
     pub const CONTENT_LENGTH: &str = "Content-Length";
     pub const BROWSER_NAME: &str = "x-browser-name";
     pub const X_CLIENT_PLATFORM: &str = "x-client-platform";
+    pub const X_NETWORK_TOKEN_SIGNATURE: &str = "X-Network-Token-Signature";
 }
 
 pub mod pii {
@@ -134,6 +137,7 @@ pub fn mk_app(
             .service(routes::Webhooks::server(state.clone()))
             .service(routes::PaymentMethods::server(state.clone()))
             .service(routes::Poll::server(state.clone()))
+            .service(routes::Subscriptions::server(state.clone()))
     }
 
     #[cfg(feature = "olap")]
@@ -152,7 +156,9 @@ pub fn mk_app(
             .service(routes::User::server(state.clone()))
             .service(routes::ConnectorOnboarding::server(state.clone()))
             .service(routes::Verify::server(state.clone()))
-            .service(routes::WebhookEvents::server(state.clone()));
+            .service(routes::WebhookEvents::server(state.clone()))
+            .service(routes::AuditLog::server(state.clone()))
+            .service(routes::Tenants::server(state.clone()));
     }
 
     #[cfg(feature = "payouts")]
@@ -174,6 +180,7 @@ pub fn mk_app(
 
     server_app = server_app.service(routes::Cards::server(state.clone()));
     server_app = server_app.service(routes::Cache::server(state.clone()));
+    server_app = server_app.service(routes::LogFilters::server(state.clone()));
     server_app = server_app.service(routes::Health::server(state));
 
     server_app
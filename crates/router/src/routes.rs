@@ -3,6 +3,8 @@ pub mod api_keys;
 pub mod app;
 pub mod apple_pay_certificates_migration;
 #[cfg(feature = "olap")]
+pub mod audit_log;
+#[cfg(feature = "olap")]
 pub mod blocklist;
 pub mod cache;
 pub mod cards_info;
@@ -23,6 +25,7 @@ pub mod gsm;
 pub mod health;
 pub mod lock_utils;
 pub mod locker_migration;
+pub mod log_filter;
 pub mod mandates;
 pub mod metrics;
 pub mod payment_link;
@@ -39,6 +42,10 @@ pub mod recon;
 pub mod refunds;
 #[cfg(feature = "olap")]
 pub mod routing;
+#[cfg(feature = "oltp")]
+pub mod subscriptions;
+#[cfg(feature = "olap")]
+pub mod tenant;
 #[cfg(feature = "olap")]
 pub mod user;
 #[cfg(feature = "olap")]
@@ -59,12 +66,12 @@ pub use self::app::Forex;
 pub use self::app::Recon;
 pub use self::app::{
     ApiKeys, AppState, ApplePayCertificatesMigration, BusinessProfile, Cache, Cards, Configs,
-    ConnectorOnboarding, Customers, Disputes, EphemeralKey, Files, Gsm, Health, Mandates,
-    MerchantAccount, MerchantConnectorAccount, PaymentLink, PaymentMethods, Payments, Poll,
-    Refunds, SessionState, User, Webhooks,
+    ConnectorOnboarding, Customers, Disputes, EphemeralKey, Files, Gsm, Health, LogFilters,
+    Mandates, MerchantAccount, MerchantConnectorAccount, PaymentLink, PaymentMethods, Payments,
+    Poll, Refunds, SessionState, Subscriptions, User, Webhooks,
 };
 #[cfg(feature = "olap")]
-pub use self::app::{Blocklist, Routing, Verify, WebhookEvents};
+pub use self::app::{AuditLog, Blocklist, Routing, Tenants, Verify, WebhookEvents};
 #[cfg(feature = "payouts")]
 pub use self::app::{PayoutLink, Payouts};
 #[cfg(feature = "stripe")]
@@ -1,20 +1,33 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::HashMap,
+    sync::{Arc, PoisonError, RwLock},
+};
 
 use actix_web::{web, Scope};
 #[cfg(all(feature = "business_profile_routing", feature = "olap"))]
 use api_models::routing::RoutingRetrieveQuery;
 #[cfg(feature = "olap")]
 use common_enums::TransactionType;
+use error_stack::ResultExt;
 #[cfg(feature = "email")]
 use external_services::email::{ses::AwsSes, EmailService};
 use external_services::file_storage::FileStorageInterface;
+use hyperswitch_domain_models::errors::StorageResult;
 use hyperswitch_interfaces::{
     encryption_interface::EncryptionManagementInterface,
-    secrets_interface::secret_state::{RawSecret, SecuredSecret},
+    secrets_interface::{
+        secret_state::{RawSecret, SecuredSecret},
+        SecretManagementInterface,
+    },
 };
-use router_env::tracing_actix_web::RequestId;
+use redis_interface::{errors::RedisError, PubsubInterface};
+use router_env::{logger, tracing::Instrument, tracing_actix_web::RequestId};
 use scheduler::SchedulerInterface;
-use storage_impl::{config::TenantConfig, redis::RedisStore, MockDb};
+use storage_impl::{
+    config::TenantConfig,
+    redis::{kv_store::RedisConnInterface, RedisStore},
+    MockDb,
+};
 use tokio::sync::oneshot;
 
 use self::settings::Tenant;
@@ -29,13 +42,15 @@ use super::payouts::*;
 #[cfg(feature = "olap")]
 use super::routing as cloud_routing;
 #[cfg(feature = "olap")]
+use super::tenant as tenant_routes;
+#[cfg(feature = "olap")]
 use super::verification::{apple_pay_merchant_registration, retrieve_apple_pay_verified_domains};
 #[cfg(feature = "olap")]
 use super::{
-    admin::*, api_keys::*, apple_pay_certificates_migration, connector_onboarding::*, disputes::*,
-    files::*, gsm::*, payment_link::*, user::*, user_role::*, webhook_events::*,
+    admin::*, api_keys::*, apple_pay_certificates_migration, audit_log::*, connector_onboarding::*,
+    disputes::*, files::*, gsm::*, payment_link::*, user::*, user_role::*, webhook_events::*,
 };
-use super::{cache::*, health::*};
+use super::{cache::*, health::*, log_filter as log_filter_routes};
 #[cfg(any(feature = "olap", feature = "oltp"))]
 use super::{configs::*, customers::*, mandates::*, payments::*, refunds::*};
 #[cfg(any(feature = "olap", feature = "oltp"))]
@@ -43,7 +58,7 @@ use super::{currency, payment_methods::*};
 #[cfg(feature = "oltp")]
 use super::{ephemeral_key::*, webhooks::*};
 #[cfg(feature = "oltp")]
-use super::{pm_auth, poll::retrieve_poll_status};
+use super::{pm_auth, poll::retrieve_poll_status, subscriptions as subscription_routes};
 #[cfg(feature = "olap")]
 pub use crate::analytics::opensearch::OpenSearchClient;
 #[cfg(feature = "olap")]
@@ -60,11 +75,14 @@ pub use crate::{
     db::{CommonStorageInterface, GlobalStorageInterface, StorageImpl, StorageInterface},
     events::EventsHandler,
     routes::cards_info::card_iin_info,
-    services::{get_cache_store, get_store},
+    services::{get_cache_store, get_store, DatabaseCredentials},
 };
 use crate::{
     configs::{secrets_transformers, Settings},
-    db::kafka_store::{KafkaStore, TenantID},
+    db::{
+        kafka_store::{KafkaStore, TenantID},
+        tenant::TenantInterface,
+    },
 };
 
 #[derive(Clone)]
@@ -131,27 +149,40 @@ impl SessionStateInfo for SessionState {
         self.request_id.replace(request_id);
     }
 }
+/// Store and analytics pool serving the requests of a tenant
+#[derive(Clone)]
+pub struct TenantState {
+    pub tenant: Tenant,
+    pub store: Box<dyn StorageInterface>,
+    #[cfg(feature = "olap")]
+    pub pool: AnalyticsProvider,
+}
+
 #[derive(Clone)]
 pub struct AppState {
     pub flow_name: String,
     pub global_store: Box<dyn GlobalStorageInterface>,
-    pub stores: HashMap<String, Box<dyn StorageInterface>>,
+    /// Tenants declared in the configuration and tenants provisioned at runtime, by tenant ID.
+    /// Shared by all the clones of the application state, so that tenants can be registered and
+    /// deregistered without restarting the server.
+    pub tenants: Arc<RwLock<HashMap<String, TenantState>>>,
+    pub storage_impl: StorageImpl,
+    pub cache_store: Arc<RedisStore>,
     pub conf: Arc<settings::Settings<RawSecret>>,
     pub event_handler: EventsHandler,
     #[cfg(feature = "email")]
     pub email_client: Arc<dyn EmailService>,
     pub api_client: Box<dyn crate::services::ApiClient>,
     #[cfg(feature = "olap")]
-    pub pools: HashMap<String, AnalyticsProvider>,
-    #[cfg(feature = "olap")]
     pub opensearch_client: Arc<OpenSearchClient>,
     pub request_id: Option<RequestId>,
     pub file_storage_client: Arc<dyn FileStorageInterface>,
     pub encryption_client: Arc<dyn EncryptionManagementInterface>,
+    pub database_credentials: DatabaseCredentials,
 }
 impl scheduler::SchedulerAppState for AppState {
     fn get_tenants(&self) -> Vec<String> {
-        self.conf.multitenancy.get_tenant_names()
+        self.get_tenant_names()
     }
 }
 pub trait AppStateInfo {
@@ -218,11 +249,14 @@ impl AppState {
         api_client: Box<dyn crate::services::ApiClient>,
     ) -> Self {
         #[allow(clippy::expect_used)]
-        let secret_management_client = conf
+        let secret_management_client: Arc<dyn SecretManagementInterface> = conf
             .secrets_management
             .get_secret_management_client()
             .await
-            .expect("Failed to create secret management client");
+            .expect("Failed to create secret management client")
+            .into();
+
+        let database_credentials = DatabaseCredentials::new(&conf, &secret_management_client);
 
         let conf = Box::pin(secrets_transformers::fetch_raw_secrets(
             conf,
@@ -255,49 +289,33 @@ impl AppState {
                     .expect("Failed to create opensearch client"),
             );
 
-            #[cfg(feature = "olap")]
-            let mut pools: HashMap<String, AnalyticsProvider> = HashMap::new();
-            let mut stores = HashMap::new();
             #[allow(clippy::expect_used)]
             let cache_store = get_cache_store(&conf.clone(), shut_down_signal, testable)
                 .await
                 .expect("Failed to create store");
+            #[allow(clippy::expect_used)]
             let global_store: Box<dyn GlobalStorageInterface> = Self::get_store_interface(
                 &storage_impl,
                 &event_handler,
                 &conf,
                 &conf.multitenancy.global_tenant,
                 Arc::clone(&cache_store),
-                testable,
+                &database_credentials,
             )
             .await
+            .expect("Failed to create store")
             .get_global_storage_interface();
-            for (tenant_name, tenant) in conf.clone().multitenancy.get_tenants() {
-                let store: Box<dyn StorageInterface> = Self::get_store_interface(
-                    &storage_impl,
-                    &event_handler,
-                    &conf,
-                    tenant,
-                    Arc::clone(&cache_store),
-                    testable,
-                )
-                .await
-                .get_storage_interface();
-                stores.insert(tenant_name.clone(), store);
-                #[cfg(feature = "olap")]
-                let pool = AnalyticsProvider::from_conf(conf.analytics.get_inner(), tenant).await;
-                #[cfg(feature = "olap")]
-                pools.insert(tenant_name.clone(), pool);
-            }
 
             #[cfg(feature = "email")]
             let email_client = Arc::new(create_email_client(&conf).await);
 
             let file_storage_client = conf.file_storage.get_file_storage_client().await;
 
-            Self {
+            let state = Self {
                 flow_name: String::from("default"),
-                stores,
+                tenants: Arc::new(RwLock::new(HashMap::new())),
+                storage_impl,
+                cache_store,
                 global_store,
                 conf: Arc::new(conf),
                 #[cfg(feature = "email")]
@@ -305,52 +323,229 @@ impl AppState {
                 api_client,
                 event_handler,
                 #[cfg(feature = "olap")]
-                pools,
-                #[cfg(feature = "olap")]
                 opensearch_client,
                 request_id: None,
                 file_storage_client,
                 encryption_client,
+                database_credentials,
+            };
+
+            for (tenant_id, tenant) in state.conf.multitenancy.get_tenants() {
+                #[allow(clippy::expect_used)]
+                state
+                    .register_tenant(tenant_id, tenant.clone())
+                    .await
+                    .expect("Failed to create store");
+            }
+
+            if state.conf.multitenancy.enabled {
+                #[allow(clippy::expect_used)]
+                let provisioned_tenants = state
+                    .global_store
+                    .list_tenants()
+                    .await
+                    .expect("Failed to list the provisioned tenants");
+                for tenant in provisioned_tenants {
+                    state.reload_tenant(tenant).await;
+                }
+
+                #[allow(clippy::expect_used)]
+                state
+                    .spawn_tenant_registry_listener()
+                    .await
+                    .expect("Failed to subscribe to the tenant registry channel");
             }
+
+            crate::core::log_filter::load_log_filters(&state).await;
+
+            state
         })
         .await
     }
 
+    /// Creates the store and analytics pool of the tenant and starts serving its requests. A
+    /// tenant which is already registered with the same configuration is left untouched.
+    pub async fn register_tenant(&self, tenant_id: &str, tenant: Tenant) -> StorageResult<()> {
+        if self
+            .get_tenant_state(tenant_id)
+            .is_some_and(|tenant_state| tenant_state.tenant == tenant)
+        {
+            return Ok(());
+        }
+
+        let store = Self::get_store_interface(
+            &self.storage_impl,
+            &self.event_handler,
+            &self.conf,
+            &tenant,
+            Arc::clone(&self.cache_store),
+            &self.database_credentials,
+        )
+        .await?
+        .get_storage_interface();
+        #[cfg(feature = "olap")]
+        let pool = AnalyticsProvider::from_conf(self.conf.analytics.get_inner(), &tenant).await;
+
+        self.tenants
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(
+                tenant_id.to_owned(),
+                TenantState {
+                    tenant,
+                    store,
+                    #[cfg(feature = "olap")]
+                    pool,
+                },
+            );
+        logger::info!(%tenant_id, "Registered tenant");
+        Ok(())
+    }
+
+    /// Stops serving the requests of the tenant
+    pub fn deregister_tenant(&self, tenant_id: &str) {
+        if self
+            .tenants
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(tenant_id)
+            .is_some()
+        {
+            logger::info!(%tenant_id, "Deregistered tenant");
+        }
+    }
+
+    /// Registers a tenant provisioned at runtime if it is active, and deregisters it otherwise.
+    /// Tenants declared in the configuration cannot be managed at runtime and are left untouched.
+    pub async fn reload_tenant(&self, tenant: diesel_models::tenant::Tenant) {
+        if self
+            .conf
+            .multitenancy
+            .get_tenant(&tenant.tenant_id)
+            .is_some()
+        {
+            return;
+        }
+
+        let tenant_id = tenant.tenant_id.clone();
+        match tenant.status {
+            common_enums::TenantStatus::Active => {
+                if let Err(error) = self.register_tenant(&tenant_id, tenant.into()).await {
+                    logger::error!(?error, %tenant_id, "Failed to register tenant");
+                }
+            }
+            common_enums::TenantStatus::Suspended
+            | common_enums::TenantStatus::Provisioning
+            | common_enums::TenantStatus::ProvisioningFailed => self.deregister_tenant(&tenant_id),
+        }
+    }
+
+    pub fn get_tenant_names(&self) -> Vec<String> {
+        self.tenants
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .keys()
+            .cloned()
+            .collect()
+    }
+
+    pub fn get_tenant_state(&self, tenant_id: &str) -> Option<TenantState> {
+        self.tenants
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(tenant_id)
+            .cloned()
+    }
+
+    /// Listens on the tenant registry channel for the tenants provisioned, suspended or deleted
+    /// through any of the instances, and reloads them from the global store
+    async fn spawn_tenant_registry_listener(&self) -> error_stack::Result<(), RedisError> {
+        let redis_conn = self.cache_store.get_redis_conn()?;
+        redis_conn.subscriber.manage_subscriptions();
+        redis_conn
+            .subscriber
+            .subscribe(common_utils::consts::TENANT_REGISTRY_CHANNEL)
+            .await
+            .change_context(RedisError::SubscribeError)?;
+
+        let mut rx = redis_conn.subscriber.on_message();
+        let state = self.clone();
+        let _task_handle = tokio::spawn(
+            async move {
+                while let Ok(message) = rx.recv().await {
+                    let channel_name = message.channel.to_string();
+                    if channel_name != common_utils::consts::TENANT_REGISTRY_CHANNEL {
+                        continue;
+                    }
+                    let Some(tenant_id) = message.value.as_string() else {
+                        logger::error!(
+                            "Received an invalid message on the tenant registry channel"
+                        );
+                        continue;
+                    };
+
+                    match state
+                        .global_store
+                        .find_tenant_by_tenant_id(&tenant_id)
+                        .await
+                    {
+                        Ok(tenant) => state.reload_tenant(tenant).await,
+                        Err(error) if error.current_context().is_db_not_found() => {
+                            if state.conf.multitenancy.get_tenant(&tenant_id).is_none() {
+                                state.deregister_tenant(&tenant_id);
+                            }
+                        }
+                        Err(error) => {
+                            logger::error!(?error, %tenant_id, "Failed to reload tenant");
+                        }
+                    }
+                }
+            }
+            .in_current_span(),
+        );
+
+        Ok(())
+    }
+
     async fn get_store_interface(
         storage_impl: &StorageImpl,
         event_handler: &EventsHandler,
         conf: &Settings,
         tenant: &dyn TenantConfig,
         cache_store: Arc<RedisStore>,
-        testable: bool,
-    ) -> Box<dyn CommonStorageInterface> {
-        match storage_impl {
+        database_credentials: &DatabaseCredentials,
+    ) -> StorageResult<Box<dyn CommonStorageInterface>> {
+        let testable = *storage_impl == StorageImpl::PostgresqlTest;
+        Ok(match storage_impl {
             StorageImpl::Postgresql | StorageImpl::PostgresqlTest => match event_handler {
                 EventsHandler::Kafka(kafka_client) => Box::new(
                     KafkaStore::new(
-                        #[allow(clippy::expect_used)]
-                        get_store(&conf.clone(), tenant, Arc::clone(&cache_store), testable)
-                            .await
-                            .expect("Failed to create store"),
+                        get_store(
+                            &conf.clone(),
+                            tenant,
+                            Arc::clone(&cache_store),
+                            database_credentials,
+                            testable,
+                        )
+                        .await?,
                         kafka_client.clone(),
                         TenantID(tenant.get_schema().to_string()),
                     )
                     .await,
                 ),
                 EventsHandler::Logs(_) => Box::new(
-                    #[allow(clippy::expect_used)]
-                    get_store(conf, tenant, Arc::clone(&cache_store), testable)
-                        .await
-                        .expect("Failed to create store"),
+                    get_store(
+                        conf,
+                        tenant,
+                        Arc::clone(&cache_store),
+                        database_credentials,
+                        testable,
+                    )
+                    .await?,
                 ),
             },
-            #[allow(clippy::expect_used)]
-            StorageImpl::Mock => Box::new(
-                MockDb::new(&conf.redis)
-                    .await
-                    .expect("Failed to create mock store"),
-            ),
-        }
+            StorageImpl::Mock => Box::new(MockDb::new(conf.redis.get_inner()).await?),
+        })
     }
 
     pub async fn new(
@@ -376,19 +571,19 @@ impl AppState {
     where
         F: FnOnce() -> E + Copy,
     {
-        let tenant_conf = self.conf.multitenancy.get_tenant(tenant).ok_or_else(err)?;
+        let tenant_state = self.get_tenant_state(tenant).ok_or_else(err)?;
         Ok(SessionState {
-            store: self.stores.get(tenant).ok_or_else(err)?.clone(),
+            store: tenant_state.store,
             global_store: self.global_store.clone(),
             conf: Arc::clone(&self.conf),
             api_client: self.api_client.clone(),
             event_handler: self.event_handler.clone(),
             #[cfg(feature = "olap")]
-            pool: self.pools.get(tenant).ok_or_else(err)?.clone(),
+            pool: tenant_state.pool,
             file_storage_client: self.file_storage_client.clone(),
             request_id: self.request_id,
-            base_url: tenant_conf.base_url.clone(),
-            tenant: tenant_conf.clone(),
+            base_url: tenant_state.tenant.base_url.clone(),
+            tenant: tenant_state.tenant,
             #[cfg(feature = "email")]
             email_client: Arc::clone(&self.email_client),
             #[cfg(feature = "olap")]
@@ -426,6 +621,13 @@ impl DummyConnector {
                 web::resource("/payments/{payment_id}")
                     .route(web::get().to(dummy_connector_payment_data)),
             )
+            .service(
+                web::resource("/{payment_id}/capture")
+                    .route(web::post().to(dummy_connector_capture)),
+            )
+            .service(
+                web::resource("/{payment_id}/void").route(web::post().to(dummy_connector_void)),
+            )
             .service(
                 web::resource("/{payment_id}/refund").route(web::post().to(dummy_connector_refund)),
             )
@@ -898,6 +1100,18 @@ impl Payouts {
                 );
         }
         route = route
+            .service(web::resource("/batches").route(web::post().to(payout_batch_create)))
+            .service(
+                web::resource("/batches/{batch_id}").route(web::get().to(payout_batch_retrieve)),
+            )
+            .service(
+                web::resource("/batches/{batch_id}/items")
+                    .route(web::get().to(payout_batch_items_list)),
+            )
+            .service(
+                web::resource("/batches/{batch_id}/cancel")
+                    .route(web::post().to(payout_batch_cancel)),
+            )
             .service(
                 web::resource("/{payout_id}")
                     .route(web::get().to(payouts_retrieve))
@@ -938,6 +1152,10 @@ impl PaymentMethods {
                     web::resource("/collect/{merchant_id}/{collect_id}")
                         .route(web::get().to(render_pm_collect_link)),
                 )
+                .service(
+                    web::resource("/network_tokens/webhooks")
+                        .route(web::post().to(network_token_webhook_api)),
+                )
                 .service(
                     web::resource("/{payment_method_id}")
                         .route(web::get().to(payment_method_retrieve_api))
@@ -1172,6 +1390,43 @@ impl Poll {
     }
 }
 
+pub struct Subscriptions;
+
+#[cfg(feature = "oltp")]
+impl Subscriptions {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/subscriptions")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("")
+                    .route(web::post().to(subscription_routes::create_subscription))
+                    .route(web::get().to(subscription_routes::list_subscriptions)),
+            )
+            .service(
+                web::resource("/plans")
+                    .route(web::post().to(subscription_routes::create_subscription_plan))
+                    .route(web::get().to(subscription_routes::list_subscription_plans)),
+            )
+            .service(
+                web::resource("/plans/{plan_id}")
+                    .route(web::get().to(subscription_routes::retrieve_subscription_plan))
+                    .route(web::post().to(subscription_routes::update_subscription_plan)),
+            )
+            .service(
+                web::resource("/{subscription_id}")
+                    .route(web::get().to(subscription_routes::retrieve_subscription)),
+            )
+            .service(
+                web::resource("/{subscription_id}/cancel")
+                    .route(web::post().to(subscription_routes::cancel_subscription)),
+            )
+            .service(
+                web::resource("/{subscription_id}/invoices")
+                    .route(web::get().to(subscription_routes::list_subscription_invoices)),
+            )
+    }
+}
+
 pub struct ApiKeys;
 
 #[cfg(feature = "olap")]
@@ -1249,6 +1504,24 @@ impl Cache {
     }
 }
 
+pub struct LogFilters;
+
+impl LogFilters {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/log_filters")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("")
+                    .route(web::post().to(log_filter_routes::create_log_filter))
+                    .route(web::get().to(log_filter_routes::list_log_filters)),
+            )
+            .service(
+                web::resource("/{filter_id}")
+                    .route(web::delete().to(log_filter_routes::delete_log_filter)),
+            )
+    }
+}
+
 pub struct PaymentLink;
 #[cfg(feature = "olap")]
 impl PaymentLink {
@@ -1311,6 +1584,38 @@ impl BusinessProfile {
                     .service(
                         web::resource("/toggle_connector_agnostic_mit")
                             .route(web::post().to(toggle_connector_agnostic_mit)),
+                    )
+                    .service(
+                        web::scope("/webhook_secrets")
+                            .service(
+                                web::resource("").route(web::get().to(webhook_signing_secret_list)),
+                            )
+                            .service(
+                                web::resource("/rotate")
+                                    .route(web::post().to(webhook_signing_secret_rotate)),
+                            )
+                            .service(
+                                web::resource("/{secret_id}/revoke")
+                                    .route(web::post().to(webhook_signing_secret_revoke)),
+                            ),
+                    )
+                    .service(
+                        web::scope("/webhook_endpoints")
+                            .service(
+                                web::resource("")
+                                    .route(web::post().to(webhook_endpoint_create))
+                                    .route(web::get().to(webhook_endpoint_list)),
+                            )
+                            .service(
+                                web::resource("/{endpoint_id}")
+                                    .route(web::get().to(webhook_endpoint_retrieve))
+                                    .route(web::post().to(webhook_endpoint_update))
+                                    .route(web::delete().to(webhook_endpoint_delete)),
+                            )
+                            .service(
+                                web::resource("/{endpoint_id}/rotate_secret")
+                                    .route(web::post().to(webhook_endpoint_secret_rotate)),
+                            ),
                     ),
             )
     }
@@ -1327,6 +1632,22 @@ impl Gsm {
             .service(web::resource("/get").route(web::post().to(get_gsm_rule)))
             .service(web::resource("/update").route(web::post().to(update_gsm_rule)))
             .service(web::resource("/delete").route(web::post().to(delete_gsm_rule)))
+            .service(web::resource("/list").route(web::post().to(list_gsm_rules)))
+            .service(web::resource("/import").route(web::post().to(import_gsm_rules)))
+            .service(web::resource("/export").route(web::post().to(export_gsm_rules)))
+            .service(
+                web::scope("/translations")
+                    .service(web::resource("").route(web::post().to(create_unified_translation)))
+                    .service(
+                        web::resource("/update").route(web::post().to(update_unified_translation)),
+                    )
+                    .service(
+                        web::resource("/delete").route(web::post().to(delete_unified_translation)),
+                    )
+                    .service(
+                        web::resource("/list").route(web::post().to(list_unified_translations)),
+                    ),
+            )
     }
 }
 
@@ -1429,6 +1750,13 @@ impl User {
                 .service(web::resource("/select").route(web::post().to(terminate_auth_select))),
         );
 
+        // Service provider endpoints for signin/signup with sso using saml
+        route = route.service(
+            web::scope("/saml/{id}")
+                .service(web::resource("/metadata").route(web::get().to(get_saml_sp_metadata)))
+                .service(web::resource("/acs").route(web::post().to(saml_acs))),
+        );
+
         #[cfg(feature = "email")]
         {
             route = route
@@ -1544,3 +1872,44 @@ impl WebhookEvents {
             )
     }
 }
+
+#[cfg(feature = "olap")]
+pub struct AuditLog;
+
+#[cfg(feature = "olap")]
+impl AuditLog {
+    pub fn server(config: AppState) -> Scope {
+        web::scope("/audit_log/{merchant_id}")
+            .app_data(web::Data::new(config))
+            .service(web::resource("").route(web::get().to(list_audit_logs)))
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct Tenants;
+
+#[cfg(feature = "olap")]
+impl Tenants {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/tenants")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("")
+                    .route(web::post().to(tenant_routes::create_tenant))
+                    .route(web::get().to(tenant_routes::list_tenants)),
+            )
+            .service(
+                web::resource("/{tenant_id}")
+                    .route(web::get().to(tenant_routes::retrieve_tenant))
+                    .route(web::delete().to(tenant_routes::delete_tenant)),
+            )
+            .service(
+                web::resource("/{tenant_id}/suspend")
+                    .route(web::post().to(tenant_routes::suspend_tenant)),
+            )
+            .service(
+                web::resource("/{tenant_id}/resume")
+                    .route(web::post().to(tenant_routes::resume_tenant)),
+            )
+    }
+}
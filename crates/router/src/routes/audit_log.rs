@@ -0,0 +1,50 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::audit_log::{AuditLogListConstraints, AuditLogListRequestInternal};
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    core::{api_locking, audit_log},
+    routes::AppState,
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::AuditLogList))]
+pub async fn list_audit_logs(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query: web::Query<AuditLogListConstraints>,
+) -> impl Responder {
+    let flow = Flow::AuditLogList;
+    let merchant_id = path.into_inner();
+    let constraints = query.into_inner();
+
+    let request_internal = AuditLogListRequestInternal {
+        merchant_id: merchant_id.clone(),
+        constraints,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            audit_log::list_audit_logs(
+                state,
+                request_internal.merchant_id,
+                request_internal.constraints,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::AuditLogRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -1,9 +1,11 @@
 use actix_web::web;
+use masking::Secret;
 use router_env::{instrument, tracing};
 
 use super::app;
 use crate::{
     core::api_locking,
+    headers,
     services::{api, authentication as auth},
 };
 
@@ -63,7 +65,12 @@ pub async fn dummy_connector_payment(
     req: actix_web::HttpRequest,
     json_payload: web::Json<types::DummyConnectorPaymentRequest>,
 ) -> impl actix_web::Responder {
-    let payload = json_payload.into_inner();
+    let mut payload = json_payload.into_inner();
+    payload.webhook_secret = req
+        .headers()
+        .get(headers::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| Secret::new(value.to_string()));
     let flow = types::Flow::DummyPaymentCreate;
     api::server_wrap(
         flow,
@@ -96,6 +103,47 @@ pub async fn dummy_connector_payment_data(
     )
     .await
 }
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyPaymentCapture))]
+pub async fn dummy_connector_capture(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<types::DummyConnectorCaptureRequest>,
+    path: web::Path<String>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyPaymentCapture;
+    let mut payload = json_payload.into_inner();
+    payload.payment_id = Some(path.to_string());
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _: (), req, _| core::payment_capture(state, req),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyPaymentVoid))]
+pub async fn dummy_connector_void(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyPaymentVoid;
+    let payment_id = path.into_inner();
+    let payload = types::DummyConnectorVoidRequest { payment_id };
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _: (), req, _| core::payment_void(state, req),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
 #[instrument(skip_all, fields(flow = ?types::Flow::DummyRefundCreate))]
 pub async fn dummy_connector_refund(
     state: web::Data<app::AppState>,
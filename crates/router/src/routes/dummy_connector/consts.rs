@@ -1,4 +1,8 @@
 pub const PAYMENT_ID_PREFIX: &str = "dummy_pay";
 pub const ATTEMPT_ID_PREFIX: &str = "dummy_attempt";
 pub const REFUND_ID_PREFIX: &str = "dummy_ref";
+pub const DISPUTE_ID_PREFIX: &str = "dummy_dp";
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "X-Dummy-Connector-Signature";
+/// Number of days within which evidence has to be submitted for simulated disputes
+pub const DISPUTE_EVIDENCE_DUE_DAYS: i64 = 7;
 pub const THREE_DS_CSS: &str = include_str!("threeds_page.css");
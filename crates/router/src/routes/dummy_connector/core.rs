@@ -1,6 +1,7 @@
 use app::SessionState;
 use common_utils::generate_id_with_default_len;
-use error_stack::ResultExt;
+use error_stack::{report, ResultExt};
+use router_env::{logger, tracing::Instrument};
 
 use super::{errors, types, utils};
 use crate::{
@@ -20,8 +21,23 @@ pub async fn payment(
     .await;
 
     let payment_attempt: types::DummyConnectorPaymentAttempt = req.into();
-    let payment_data =
-        types::DummyConnectorPaymentData::process_payment_attempt(&state, payment_attempt)?;
+    let scenario = payment_attempt.get_scenario()?;
+    match scenario {
+        Some(types::DummyConnectorScenario::ServerError) => {
+            return Err(report!(errors::DummyConnectorErrors::InternalServerError)
+                .attach_printable("Simulating connector server error"));
+        }
+        Some(types::DummyConnectorScenario::Timeout) => {
+            utils::slow_response_sleep(&state.conf.dummy_connector).await
+        }
+        _ => {}
+    }
+
+    let payment_data = types::DummyConnectorPaymentData::process_payment_attempt(
+        &state,
+        payment_attempt,
+        scenario,
+    );
 
     utils::store_data_in_redis(
         &state,
@@ -37,9 +53,157 @@ pub async fn payment(
         state.conf.dummy_connector.payment_ttl,
     )
     .await?;
+
+    if let Some(scenario) = scenario.filter(|scenario| scenario.has_async_outcome()) {
+        let payment_id = payment_data.payment_id.clone();
+        let state = state.clone();
+        tokio::spawn(
+            async move {
+                if let Err(error) = process_async_outcome(&state, payment_id, scenario).await {
+                    logger::error!(?error, "Failed to process dummy connector async outcome");
+                }
+            }
+            .in_current_span(),
+        );
+    }
+
     Ok(api::ApplicationResponse::Json(payment_data.into()))
 }
 
+/// Resolve the outcome of a payment whose scenario completes asynchronously, after the
+/// configured webhook delay, and notify it through a webhook
+async fn process_async_outcome(
+    state: &SessionState,
+    payment_id: String,
+    scenario: types::DummyConnectorScenario,
+) -> types::DummyConnectorResult<()> {
+    tokio::time::sleep(tokio::time::Duration::from_millis(
+        state.conf.dummy_connector.webhook_delay,
+    ))
+    .await;
+
+    let payment_data = utils::get_payment_data_from_payment_id(state, payment_id).await?;
+
+    let webhook = match scenario {
+        types::DummyConnectorScenario::Dispute => {
+            let dispute = types::DummyConnectorDispute {
+                id: generate_id_with_default_len(consts::DISPUTE_ID_PREFIX),
+                payment_id: payment_data.payment_id.clone(),
+                status: types::DummyConnectorDisputeStatus::Opened,
+                amount: payment_data.amount_captured,
+                currency: payment_data.currency,
+                reason: "fraudulent".to_string(),
+                created: common_utils::date_time::now(),
+                evidence_due_by: common_utils::date_time::now()
+                    .saturating_add(time::Duration::days(consts::DISPUTE_EVIDENCE_DUE_DAYS)),
+            };
+            utils::store_data_in_redis(
+                state,
+                dispute.id.clone(),
+                dispute.clone(),
+                state.conf.dummy_connector.payment_ttl,
+            )
+            .await?;
+            types::DummyConnectorWebhook {
+                event_type: types::DummyConnectorWebhookEventType::DisputeOpened,
+                data: types::DummyConnectorWebhookData::Dispute(dispute),
+            }
+        }
+        types::DummyConnectorScenario::PendingThenSucceed
+        | types::DummyConnectorScenario::PendingThenFail => {
+            let updated_payment_data = match scenario.get_decline_details() {
+                Some(error) => payment_data.clone().fail(Some(error)),
+                None => payment_data.clone().authorize(),
+            };
+            utils::store_data_in_redis(
+                state,
+                updated_payment_data.payment_id.clone(),
+                updated_payment_data.clone(),
+                state.conf.dummy_connector.payment_ttl,
+            )
+            .await?;
+            let event_type = match updated_payment_data.status {
+                types::DummyConnectorStatus::RequiresCapture => {
+                    types::DummyConnectorWebhookEventType::PaymentAuthorized
+                }
+                types::DummyConnectorStatus::Failed => {
+                    types::DummyConnectorWebhookEventType::PaymentFailed
+                }
+                _ => types::DummyConnectorWebhookEventType::PaymentSucceeded,
+            };
+            types::DummyConnectorWebhook {
+                event_type,
+                data: types::DummyConnectorWebhookData::Payment(updated_payment_data.into()),
+            }
+        }
+        _ => return Ok(()),
+    };
+
+    utils::send_webhook(state, &payment_data, webhook).await
+}
+
+pub async fn payment_capture(
+    state: SessionState,
+    req: types::DummyConnectorCaptureRequest,
+) -> types::DummyConnectorResponse<types::DummyConnectorPaymentResponse> {
+    utils::tokio_mock_sleep(
+        state.conf.dummy_connector.payment_duration,
+        state.conf.dummy_connector.payment_tolerance,
+    )
+    .await;
+
+    let payment_id = req
+        .payment_id
+        .get_required_value("payment_id")
+        .change_context(errors::DummyConnectorErrors::MissingRequiredField {
+            field_name: "payment_id",
+        })?;
+
+    let payment_data = utils::get_payment_data_from_payment_id(&state, payment_id.clone()).await?;
+    payment_data.is_eligible_for_capture(req.amount)?;
+
+    let updated_payment_data = payment_data.capture(req.amount);
+    utils::store_data_in_redis(
+        &state,
+        payment_id,
+        updated_payment_data.clone(),
+        state.conf.dummy_connector.payment_ttl,
+    )
+    .await?;
+    Ok(api::ApplicationResponse::Json(updated_payment_data.into()))
+}
+
+pub async fn payment_void(
+    state: SessionState,
+    req: types::DummyConnectorVoidRequest,
+) -> types::DummyConnectorResponse<types::DummyConnectorPaymentResponse> {
+    utils::tokio_mock_sleep(
+        state.conf.dummy_connector.payment_duration,
+        state.conf.dummy_connector.payment_tolerance,
+    )
+    .await;
+
+    let payment_data =
+        utils::get_payment_data_from_payment_id(&state, req.payment_id.clone()).await?;
+    if payment_data.status != types::DummyConnectorStatus::RequiresCapture {
+        return Err(report!(errors::DummyConnectorErrors::PaymentNotAuthorized)
+            .attach_printable("Payment is not authorized to process the void"));
+    }
+
+    let updated_payment_data = types::DummyConnectorPaymentData {
+        status: types::DummyConnectorStatus::Cancelled,
+        ..payment_data
+    };
+    utils::store_data_in_redis(
+        &state,
+        req.payment_id,
+        updated_payment_data.clone(),
+        state.conf.dummy_connector.payment_ttl,
+    )
+    .await?;
+    Ok(api::ApplicationResponse::Json(updated_payment_data.into()))
+}
+
 pub async fn payment_data(
     state: SessionState,
     req: types::DummyConnectorPaymentRetrieveRequest,
@@ -94,12 +258,6 @@ pub async fn payment_complete(
 
     let payment_data = utils::get_payment_data_by_attempt_id(&state, req.attempt_id.clone()).await;
 
-    let payment_status = if req.confirm {
-        types::DummyConnectorStatus::Succeeded
-    } else {
-        types::DummyConnectorStatus::Failed
-    };
-
     let redis_conn = state
         .store
         .get_redis_conn()
@@ -109,10 +267,10 @@ pub async fn payment_complete(
     let _ = redis_conn.delete_key(req.attempt_id.as_str()).await;
 
     if let Ok(payment_data) = payment_data {
-        let updated_payment_data = types::DummyConnectorPaymentData {
-            status: payment_status,
-            next_action: None,
-            ..payment_data
+        let updated_payment_data = if req.confirm {
+            payment_data.authorize()
+        } else {
+            payment_data.fail(None)
         };
         utils::store_data_in_redis(
             &state,
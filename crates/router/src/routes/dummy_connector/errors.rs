@@ -35,8 +35,14 @@ pub enum DummyConnectorErrors {
     #[error(error_type = ErrorType::ServerNotAvailable, code = "DC_07", message = "Error occurred while storing the payment")]
     PaymentStoringError,
 
-    #[error(error_type = ErrorType::InvalidRequestError, code = "DC_08", message = "Payment declined: {message}")]
-    PaymentDeclined { message: &'static str },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "DC_09", message = "Payment is not authorized")]
+    PaymentNotAuthorized,
+
+    #[error(error_type = ErrorType::InvalidRequestError, code = "DC_10", message = "The capture amount exceeds the amount authorized")]
+    CaptureAmountExceedsPaymentAmount,
+
+    #[error(error_type = ErrorType::InvalidRequestError, code = "DC_11", message = "Scenario not supported: {scenario}")]
+    ScenarioNotSupported { scenario: String },
 }
 
 impl core::fmt::Display for DummyConnectorErrors {
@@ -80,8 +86,14 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
             Self::PaymentStoringError => {
                 AER::InternalServerError(ApiError::new("DC", 7, self.error_message(), None))
             }
-            Self::PaymentDeclined { message: _ } => {
-                AER::BadRequest(ApiError::new("DC", 8, self.error_message(), None))
+            Self::PaymentNotAuthorized => {
+                AER::BadRequest(ApiError::new("DC", 9, self.error_message(), None))
+            }
+            Self::CaptureAmountExceedsPaymentAmount => {
+                AER::BadRequest(ApiError::new("DC", 10, self.error_message(), None))
+            }
+            Self::ScenarioNotSupported { scenario: _ } => {
+                AER::BadRequest(ApiError::new("DC", 11, self.error_message(), None))
             }
         }
     }
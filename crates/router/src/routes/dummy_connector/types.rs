@@ -1,4 +1,4 @@
-use api_models::enums::Currency;
+use api_models::enums::{CaptureMethod, Currency};
 use common_utils::{errors::CustomResult, generate_id_with_default_len};
 use error_stack::report;
 use masking::Secret;
@@ -16,6 +16,8 @@ pub enum Flow {
     DummyPaymentRetrieve,
     DummyPaymentAuthorize,
     DummyPaymentComplete,
+    DummyPaymentCapture,
+    DummyPaymentVoid,
     DummyRefundCreate,
     DummyRefundRetrieve,
 }
@@ -64,6 +66,84 @@ pub enum DummyConnectorStatus {
     #[default]
     Processing,
     Failed,
+    Pending,
+    #[serde(rename = "requires_capture")]
+    RequiresCapture,
+    #[serde(rename = "partially_captured")]
+    PartiallyCaptured,
+    Cancelled,
+}
+
+/// The outcome simulated by the dummy connector for a payment. Scenarios are selected by magic
+/// card numbers, or by the `dummy_connector_scenario` key in the payment metadata.
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, strum::Display, strum::EnumString,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DummyConnectorScenario {
+    /// The payment is authorized, and captured as well if the capture method is automatic
+    Success,
+    /// The customer is redirected to a 3DS page to authenticate the payment
+    ThreeDs,
+    CardDeclined,
+    InsufficientFunds,
+    LostCard,
+    StolenCard,
+    ExpiredCard,
+    IncorrectCvc,
+    DoNotHonor,
+    /// The payment stays pending, and succeeds through a webhook after `webhook_delay`
+    PendingThenSucceed,
+    /// The payment stays pending, and fails through a webhook after `webhook_delay`
+    PendingThenFail,
+    /// The payment succeeds, and a dispute is opened through a webhook after `webhook_delay`
+    Dispute,
+    /// The connector responds with an internal server error
+    ServerError,
+    /// The connector responds successfully after `slow_response_duration`
+    Timeout,
+}
+
+impl DummyConnectorScenario {
+    /// The error code and message of the scenarios in which the payment is declined. The codes are
+    /// stable, so that they can be mapped through the gateway status map.
+    pub fn get_decline_details(self) -> Option<DummyConnectorErrorDetails> {
+        let (code, message) = match self {
+            Self::CardDeclined => ("card_declined", "Card declined"),
+            Self::InsufficientFunds => ("insufficient_funds", "Insufficient funds"),
+            Self::LostCard => ("lost_card", "Lost card"),
+            Self::StolenCard => ("stolen_card", "Stolen card"),
+            Self::ExpiredCard => ("expired_card", "Expired card"),
+            Self::IncorrectCvc => ("incorrect_cvc", "Incorrect CVC"),
+            Self::DoNotHonor => ("do_not_honor", "Do not honor"),
+            Self::PendingThenFail => ("processing_error", "Payment could not be processed"),
+            Self::Success
+            | Self::ThreeDs
+            | Self::PendingThenSucceed
+            | Self::Dispute
+            | Self::ServerError
+            | Self::Timeout => return None,
+        };
+        Some(DummyConnectorErrorDetails {
+            code: code.to_string(),
+            message: message.to_string(),
+        })
+    }
+
+    /// Whether the outcome of the scenario is notified through a webhook
+    pub fn has_async_outcome(self) -> bool {
+        matches!(
+            self,
+            Self::PendingThenSucceed | Self::PendingThenFail | Self::Dispute
+        )
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorErrorDetails {
+    pub code: String,
+    pub message: String,
 }
 
 #[derive(Clone, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
@@ -94,19 +174,30 @@ impl DummyConnectorPaymentAttempt {
         status: DummyConnectorStatus,
         next_action: Option<DummyConnectorNextAction>,
         return_url: Option<String>,
+        error: Option<DummyConnectorErrorDetails>,
     ) -> DummyConnectorPaymentData {
+        let amount_captured = if status == DummyConnectorStatus::Succeeded {
+            self.payment_request.amount
+        } else {
+            0
+        };
         DummyConnectorPaymentData {
             attempt_id: self.attempt_id,
             payment_id: self.payment_id,
             status,
             amount: self.payment_request.amount,
-            eligible_amount: self.payment_request.amount,
+            amount_captured,
+            eligible_amount: amount_captured,
             connector: self.payment_request.connector,
             created: self.timestamp,
             currency: self.payment_request.currency,
             payment_method_type: self.payment_request.payment_method_data.into(),
             next_action,
             return_url,
+            capture_method: self.payment_request.capture_method,
+            error,
+            webhook_url: self.payment_request.webhook_url,
+            webhook_secret: self.payment_request.webhook_secret,
         }
     }
 }
@@ -118,6 +209,14 @@ pub struct DummyConnectorPaymentRequest {
     pub payment_method_data: DummyConnectorPaymentMethodData,
     pub return_url: Option<String>,
     pub connector: DummyConnectors,
+    pub capture_method: Option<CaptureMethod>,
+    /// The url to which the webhooks of the payment are sent
+    pub webhook_url: Option<String>,
+    /// Overrides the scenario selected by the card number
+    pub scenario: Option<String>,
+    /// The secret with which webhooks are signed, which is the API key used for the payment
+    #[serde(skip)]
+    pub webhook_secret: Option<Secret<String>>,
 }
 
 pub trait GetPaymentMethodDetails {
@@ -181,11 +280,6 @@ pub struct DummyConnectorCard {
     pub cvc: Secret<String>,
 }
 
-pub enum DummyConnectorCardFlow {
-    NoThreeDS(DummyConnectorStatus, Option<DummyConnectorErrors>),
-    ThreeDS(DummyConnectorStatus, Option<DummyConnectorErrors>),
-}
-
 #[derive(Clone, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
 pub enum DummyConnectorWallet {
     GooglePay,
@@ -251,6 +345,8 @@ pub struct DummyConnectorPaymentData {
     pub payment_id: String,
     pub status: DummyConnectorStatus,
     pub amount: i64,
+    #[serde(default)]
+    pub amount_captured: i64,
     pub eligible_amount: i64,
     pub currency: Currency,
     #[serde(with = "common_utils::custom_serde::iso8601")]
@@ -259,6 +355,10 @@ pub struct DummyConnectorPaymentData {
     pub connector: DummyConnectors,
     pub next_action: Option<DummyConnectorNextAction>,
     pub return_url: Option<String>,
+    pub capture_method: Option<CaptureMethod>,
+    pub error: Option<DummyConnectorErrorDetails>,
+    pub webhook_url: Option<String>,
+    pub webhook_secret: Option<Secret<String>>,
 }
 
 impl DummyConnectorPaymentData {
@@ -269,12 +369,70 @@ impl DummyConnectorPaymentData {
                     .attach_printable("Eligible amount is lesser than refund amount"),
             );
         }
-        if self.status != DummyConnectorStatus::Succeeded {
+        if !matches!(
+            self.status,
+            DummyConnectorStatus::Succeeded | DummyConnectorStatus::PartiallyCaptured
+        ) {
             return Err(report!(DummyConnectorErrors::PaymentNotSuccessful)
                 .attach_printable("Payment is not successful to process the refund"));
         }
         Ok(())
     }
+
+    pub fn is_eligible_for_capture(&self, amount_to_capture: i64) -> DummyConnectorResult<()> {
+        if self.status != DummyConnectorStatus::RequiresCapture {
+            return Err(report!(DummyConnectorErrors::PaymentNotAuthorized)
+                .attach_printable("Payment is not authorized to process the capture"));
+        }
+        if self.amount < amount_to_capture {
+            return Err(
+                report!(DummyConnectorErrors::CaptureAmountExceedsPaymentAmount)
+                    .attach_printable("Payment amount is lesser than capture amount"),
+            );
+        }
+        Ok(())
+    }
+
+    /// Authorizes the payment, capturing it as well unless the capture method is manual
+    pub fn authorize(self) -> Self {
+        match self.capture_method {
+            Some(CaptureMethod::Manual) => Self {
+                status: DummyConnectorStatus::RequiresCapture,
+                next_action: None,
+                ..self
+            },
+            _ => Self {
+                status: DummyConnectorStatus::Succeeded,
+                amount_captured: self.amount,
+                eligible_amount: self.amount,
+                next_action: None,
+                ..self
+            },
+        }
+    }
+
+    pub fn fail(self, error: Option<DummyConnectorErrorDetails>) -> Self {
+        Self {
+            status: DummyConnectorStatus::Failed,
+            next_action: None,
+            error,
+            ..self
+        }
+    }
+
+    pub fn capture(self, amount_to_capture: i64) -> Self {
+        let status = if amount_to_capture < self.amount {
+            DummyConnectorStatus::PartiallyCaptured
+        } else {
+            DummyConnectorStatus::Succeeded
+        };
+        Self {
+            status,
+            amount_captured: amount_to_capture,
+            eligible_amount: amount_to_capture,
+            ..self
+        }
+    }
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
@@ -293,6 +451,8 @@ pub struct DummyConnectorPaymentResponse {
     pub created: PrimitiveDateTime,
     pub payment_method_type: DummyConnectorPaymentMethodType,
     pub next_action: Option<DummyConnectorNextAction>,
+    pub amount_captured: i64,
+    pub error: Option<DummyConnectorErrorDetails>,
 }
 
 impl From<DummyConnectorPaymentData> for DummyConnectorPaymentResponse {
@@ -305,6 +465,8 @@ impl From<DummyConnectorPaymentData> for DummyConnectorPaymentResponse {
             created: value.created,
             payment_method_type: value.payment_method_type,
             next_action: value.next_action,
+            amount_captured: value.amount_captured,
+            error: value.error,
         }
     }
 }
@@ -330,6 +492,17 @@ pub struct DummyConnectorPaymentCompleteBody {
     pub confirm: bool,
 }
 
+#[derive(Default, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
+pub struct DummyConnectorCaptureRequest {
+    pub amount: i64,
+    pub payment_id: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorVoidRequest {
+    pub payment_id: String,
+}
+
 #[derive(Default, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
 pub struct DummyConnectorRefundRequest {
     pub amount: i64,
@@ -372,6 +545,50 @@ pub struct DummyConnectorRefundRetrieveRequest {
     pub refund_id: String,
 }
 
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, strum::Display, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DummyConnectorDisputeStatus {
+    Opened,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, Eq, PartialEq)]
+pub struct DummyConnectorDispute {
+    pub id: String,
+    pub payment_id: String,
+    pub status: DummyConnectorDisputeStatus,
+    pub amount: i64,
+    pub currency: Currency,
+    pub reason: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub evidence_due_by: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Serialize, strum::Display, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DummyConnectorWebhookEventType {
+    PaymentSucceeded,
+    PaymentAuthorized,
+    PaymentFailed,
+    DisputeOpened,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+#[serde(tag = "object", rename_all = "snake_case")]
+pub enum DummyConnectorWebhookData {
+    Payment(DummyConnectorPaymentResponse),
+    Dispute(DummyConnectorDispute),
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct DummyConnectorWebhook {
+    pub event_type: DummyConnectorWebhookEventType,
+    pub data: DummyConnectorWebhookData,
+}
+
 pub type DummyConnectorResponse<T> =
     CustomResult<services::ApplicationResponse<T>, DummyConnectorErrors>;
 
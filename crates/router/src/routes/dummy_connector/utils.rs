@@ -1,17 +1,22 @@
-use std::fmt::Debug;
+use std::{fmt::Debug, str::FromStr};
 
-use common_utils::ext_traits::AsyncExt;
+use common_utils::{
+    crypto::{self, SignMessage},
+    ext_traits::AsyncExt,
+    request::RequestContent,
+};
 use error_stack::{report, ResultExt};
 use masking::PeekInterface;
 use maud::html;
 use rand::{distributions::Uniform, prelude::Distribution};
+use router_env::logger;
 use tokio::time as tokio;
 
 use super::{
     consts, errors,
     types::{self, GetPaymentMethodDetails},
 };
-use crate::{configs::settings, routes::SessionState};
+use crate::{configs::settings, headers, routes::SessionState, services};
 
 pub async fn tokio_mock_sleep(delay: u64, tolerance: u64) {
     let mut rng = rand::thread_rng();
@@ -205,131 +210,55 @@ pub fn get_expired_page(dummy_connector_conf: &settings::DummyConnector) -> Stri
     .into_string()
 }
 
-pub trait ProcessPaymentAttempt {
-    fn build_payment_data_from_payment_attempt(
-        self,
-        payment_attempt: types::DummyConnectorPaymentAttempt,
-        redirect_url: String,
-    ) -> types::DummyConnectorResult<types::DummyConnectorPaymentData>;
-}
-
-impl ProcessPaymentAttempt for types::DummyConnectorCard {
-    fn build_payment_data_from_payment_attempt(
-        self,
-        payment_attempt: types::DummyConnectorPaymentAttempt,
-        redirect_url: String,
-    ) -> types::DummyConnectorResult<types::DummyConnectorPaymentData> {
-        match self.get_flow_from_card_number()? {
-            types::DummyConnectorCardFlow::NoThreeDS(status, error) => {
-                if let Some(error) = error {
-                    Err(error)?;
-                }
-                Ok(payment_attempt.build_payment_data(status, None, None))
-            }
-            types::DummyConnectorCardFlow::ThreeDS(_, _) => {
-                Ok(payment_attempt.clone().build_payment_data(
-                    types::DummyConnectorStatus::Processing,
-                    Some(types::DummyConnectorNextAction::RedirectToUrl(redirect_url)),
-                    payment_attempt.payment_request.return_url,
-                ))
-            }
-        }
-    }
-}
-
 impl types::DummyConnectorCard {
-    pub fn get_flow_from_card_number(
-        self,
-    ) -> types::DummyConnectorResult<types::DummyConnectorCardFlow> {
+    pub fn get_scenario_from_card_number(
+        &self,
+    ) -> types::DummyConnectorResult<types::DummyConnectorScenario> {
         let card_number = self.number.peek();
         match card_number.as_str() {
             "4111111111111111" | "4242424242424242" | "5555555555554444" | "38000000000006"
-            | "378282246310005" | "6011111111111117" => {
-                Ok(types::DummyConnectorCardFlow::NoThreeDS(
-                    types::DummyConnectorStatus::Succeeded,
-                    None,
-                ))
-            }
+            | "378282246310005" | "6011111111111117" => Ok(types::DummyConnectorScenario::Success),
             "5105105105105100" | "4000000000000002" => {
-                Ok(types::DummyConnectorCardFlow::NoThreeDS(
-                    types::DummyConnectorStatus::Failed,
-                    Some(errors::DummyConnectorErrors::PaymentDeclined {
-                        message: "Card declined",
-                    }),
-                ))
+                Ok(types::DummyConnectorScenario::CardDeclined)
             }
-            "4000000000009995" => Ok(types::DummyConnectorCardFlow::NoThreeDS(
-                types::DummyConnectorStatus::Failed,
-                Some(errors::DummyConnectorErrors::PaymentDeclined {
-                    message: "Insufficient funds",
-                }),
-            )),
-            "4000000000009987" => Ok(types::DummyConnectorCardFlow::NoThreeDS(
-                types::DummyConnectorStatus::Failed,
-                Some(errors::DummyConnectorErrors::PaymentDeclined {
-                    message: "Lost card",
-                }),
-            )),
-            "4000000000009979" => Ok(types::DummyConnectorCardFlow::NoThreeDS(
-                types::DummyConnectorStatus::Failed,
-                Some(errors::DummyConnectorErrors::PaymentDeclined {
-                    message: "Stolen card",
-                }),
-            )),
-            "4000003800000446" => Ok(types::DummyConnectorCardFlow::ThreeDS(
-                types::DummyConnectorStatus::Succeeded,
-                None,
-            )),
+            "4000000000009995" => Ok(types::DummyConnectorScenario::InsufficientFunds),
+            "4000000000009987" => Ok(types::DummyConnectorScenario::LostCard),
+            "4000000000009979" => Ok(types::DummyConnectorScenario::StolenCard),
+            "4000000000000069" => Ok(types::DummyConnectorScenario::ExpiredCard),
+            "4000000000000127" => Ok(types::DummyConnectorScenario::IncorrectCvc),
+            "4000000000000036" => Ok(types::DummyConnectorScenario::DoNotHonor),
+            "4000003800000446" => Ok(types::DummyConnectorScenario::ThreeDs),
+            "4000000000000077" => Ok(types::DummyConnectorScenario::PendingThenSucceed),
+            "4000000000000085" => Ok(types::DummyConnectorScenario::PendingThenFail),
+            "4000000000000259" => Ok(types::DummyConnectorScenario::Dispute),
+            "4000000000000119" => Ok(types::DummyConnectorScenario::ServerError),
+            "4000000000000101" => Ok(types::DummyConnectorScenario::Timeout),
             _ => Err(report!(errors::DummyConnectorErrors::CardNotSupported)
                 .attach_printable("The card is not supported")),
         }
     }
 }
 
-impl ProcessPaymentAttempt for types::DummyConnectorWallet {
-    fn build_payment_data_from_payment_attempt(
-        self,
-        payment_attempt: types::DummyConnectorPaymentAttempt,
-        redirect_url: String,
-    ) -> types::DummyConnectorResult<types::DummyConnectorPaymentData> {
-        Ok(payment_attempt.clone().build_payment_data(
-            types::DummyConnectorStatus::Processing,
-            Some(types::DummyConnectorNextAction::RedirectToUrl(redirect_url)),
-            payment_attempt.payment_request.return_url,
-        ))
-    }
-}
-
-impl ProcessPaymentAttempt for types::DummyConnectorPayLater {
-    fn build_payment_data_from_payment_attempt(
-        self,
-        payment_attempt: types::DummyConnectorPaymentAttempt,
-        redirect_url: String,
-    ) -> types::DummyConnectorResult<types::DummyConnectorPaymentData> {
-        Ok(payment_attempt.clone().build_payment_data(
-            types::DummyConnectorStatus::Processing,
-            Some(types::DummyConnectorNextAction::RedirectToUrl(redirect_url)),
-            payment_attempt.payment_request.return_url,
-        ))
-    }
-}
-
-impl ProcessPaymentAttempt for types::DummyConnectorPaymentMethodData {
-    fn build_payment_data_from_payment_attempt(
-        self,
-        payment_attempt: types::DummyConnectorPaymentAttempt,
-        redirect_url: String,
-    ) -> types::DummyConnectorResult<types::DummyConnectorPaymentData> {
-        match self {
-            Self::Card(card) => {
-                card.build_payment_data_from_payment_attempt(payment_attempt, redirect_url)
-            }
-            Self::Wallet(wallet) => {
-                wallet.build_payment_data_from_payment_attempt(payment_attempt, redirect_url)
-            }
-            Self::PayLater(pay_later) => {
-                pay_later.build_payment_data_from_payment_attempt(payment_attempt, redirect_url)
+impl types::DummyConnectorPaymentAttempt {
+    /// Get the scenario to be simulated for the payment. The scenario passed in the request takes
+    /// precedence over the one selected by the card number. Wallet and pay later payments are
+    /// redirected if no scenario is passed in the request.
+    pub fn get_scenario(
+        &self,
+    ) -> types::DummyConnectorResult<Option<types::DummyConnectorScenario>> {
+        if let Some(scenario) = &self.payment_request.scenario {
+            return types::DummyConnectorScenario::from_str(scenario)
+                .map(Some)
+                .change_context(errors::DummyConnectorErrors::ScenarioNotSupported {
+                    scenario: scenario.to_owned(),
+                });
+        }
+        match &self.payment_request.payment_method_data {
+            types::DummyConnectorPaymentMethodData::Card(card) => {
+                card.get_scenario_from_card_number().map(Some)
             }
+            types::DummyConnectorPaymentMethodData::Wallet(_)
+            | types::DummyConnectorPaymentMethodData::PayLater(_) => Ok(None),
         }
     }
 }
@@ -338,15 +267,101 @@ impl types::DummyConnectorPaymentData {
     pub fn process_payment_attempt(
         state: &SessionState,
         payment_attempt: types::DummyConnectorPaymentAttempt,
-    ) -> types::DummyConnectorResult<Self> {
+        scenario: Option<types::DummyConnectorScenario>,
+    ) -> Self {
         let redirect_url = format!(
             "{}/dummy-connector/authorize/{}",
             state.base_url, payment_attempt.attempt_id
         );
-        payment_attempt
-            .clone()
-            .payment_request
-            .payment_method_data
-            .build_payment_data_from_payment_attempt(payment_attempt, redirect_url)
+        match scenario {
+            None | Some(types::DummyConnectorScenario::ThreeDs) => {
+                let return_url = payment_attempt.payment_request.return_url.clone();
+                payment_attempt.build_payment_data(
+                    types::DummyConnectorStatus::Processing,
+                    Some(types::DummyConnectorNextAction::RedirectToUrl(redirect_url)),
+                    return_url,
+                    None,
+                )
+            }
+            Some(
+                types::DummyConnectorScenario::PendingThenSucceed
+                | types::DummyConnectorScenario::PendingThenFail,
+            ) => payment_attempt.build_payment_data(
+                types::DummyConnectorStatus::Pending,
+                None,
+                None,
+                None,
+            ),
+            Some(scenario) => match scenario.get_decline_details() {
+                Some(error) => payment_attempt.build_payment_data(
+                    types::DummyConnectorStatus::Failed,
+                    None,
+                    None,
+                    Some(error),
+                ),
+                None => payment_attempt
+                    .build_payment_data(types::DummyConnectorStatus::Processing, None, None, None)
+                    .authorize(),
+            },
+        }
+    }
+}
+
+/// Simulate a connector which responds after the router has timed out
+pub async fn slow_response_sleep(dummy_connector_conf: &settings::DummyConnector) {
+    tokio::sleep(tokio::Duration::from_millis(
+        dummy_connector_conf.slow_response_duration,
+    ))
+    .await
+}
+
+/// Send a webhook to the url passed in the payment request, signed with the API key used for the
+/// payment. The signature is the hex encoded HMAC-SHA256 of the request body.
+pub async fn send_webhook(
+    state: &SessionState,
+    payment_data: &types::DummyConnectorPaymentData,
+    webhook: types::DummyConnectorWebhook,
+) -> types::DummyConnectorResult<()> {
+    let webhook_url = payment_data.webhook_url.as_ref().ok_or(
+        errors::DummyConnectorErrors::MissingRequiredField {
+            field_name: "webhook_url",
+        },
+    )?;
+    let webhook_secret = payment_data.webhook_secret.as_ref().ok_or(
+        errors::DummyConnectorErrors::MissingRequiredField {
+            field_name: "authorization",
+        },
+    )?;
+
+    let body = serde_json::to_vec(&webhook)
+        .change_context(errors::DummyConnectorErrors::InternalServerError)
+        .attach_printable("Failed to serialize webhook")?;
+    let signature = crypto::HmacSha256
+        .sign_message(webhook_secret.peek().as_bytes(), &body)
+        .change_context(errors::DummyConnectorErrors::InternalServerError)
+        .attach_printable("Failed to sign webhook")?;
+
+    let request = services::RequestBuilder::new()
+        .method(services::Method::Post)
+        .url(webhook_url)
+        .attach_default_headers()
+        .header(headers::CONTENT_TYPE, "application/json")
+        .header(consts::WEBHOOK_SIGNATURE_HEADER, &hex::encode(signature))
+        .set_body(RequestContent::RawBytes(body))
+        .build();
+
+    let response = services::api::send_request(state, request, None)
+        .await
+        .change_context(errors::DummyConnectorErrors::InternalServerError)
+        .attach_printable("Failed to send webhook")?;
+    if !response.status().is_success() {
+        return Err(report!(errors::DummyConnectorErrors::InternalServerError)
+            .attach_printable(format!("Webhook failed with status {}", response.status())));
     }
+    logger::info!(
+        event_type = %webhook.event_type,
+        payment_id = %payment_data.payment_id,
+        "Dummy connector webhook sent"
+    );
+    Ok(())
 }
@@ -64,13 +64,17 @@ pub async fn get_create_file_request(
         Err(errors::ApiErrorResponse::MissingFile)
             .attach_printable("Missing / Invalid file in the request")?
     }
-    // Get file mime type using 'infer'
-    let kind = infer::get(&file).ok_or(errors::ApiErrorResponse::MissingFileContentType)?;
-    let file_type = kind
-        .mime_type()
-        .parse::<mime::Mime>()
-        .change_context(errors::ApiErrorResponse::MissingFileContentType)
-        .attach_printable("File content type error")?;
+    // Get file mime type using 'infer', falling back to the file extension for text files,
+    // which cannot be identified by their content
+    let file_type = match infer::get(&file) {
+        Some(kind) => kind
+            .mime_type()
+            .parse::<mime::Mime>()
+            .change_context(errors::ApiErrorResponse::MissingFileContentType)
+            .attach_printable("File content type error")?,
+        None => get_text_file_type(file_name.as_deref())
+            .ok_or(errors::ApiErrorResponse::MissingFileContentType)?,
+    };
     Ok(CreateFileRequest {
         file,
         file_name,
@@ -80,3 +84,12 @@ pub async fn get_create_file_request(
         dispute_id,
     })
 }
+
+fn get_text_file_type(file_name: Option<&str>) -> Option<mime::Mime> {
+    let extension = file_name?.rsplit_once('.')?.1.to_lowercase();
+    match extension.as_str() {
+        "csv" => Some(mime::TEXT_CSV),
+        "json" => Some(mime::APPLICATION_JSON),
+        _ => None,
+    }
+}
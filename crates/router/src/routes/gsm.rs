@@ -159,3 +159,270 @@ pub async fn delete_gsm_rule(
     ))
     .await
 }
+
+/// Gsm - List
+///
+/// To list the Gsm Rules, filtered by connector, flow and decision
+#[utoipa::path(
+    post,
+    path = "/gsm/list",
+    request_body(
+        content = GsmListRequest,
+    ),
+    responses(
+        (status = 200, description = "Gsm rules listed", body = Vec<GsmResponse>),
+        (status = 400, description = "Invalid filters")
+    ),
+    tag = "Gsm",
+    operation_id = "List Gsm Rules",
+    security(("admin_api_key" = [])),
+)]
+#[instrument(skip_all, fields(flow = ?Flow::GsmRuleList))]
+pub async fn list_gsm_rules(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<gsm_api_types::GsmListRequest>,
+) -> impl Responder {
+    let payload = json_payload.into_inner();
+
+    let flow = Flow::GsmRuleList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| gsm::list_gsm_rules(state, payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Gsm - Import
+///
+/// To import Gsm Rules in bulk from a CSV or JSON file. With `dry_run`, the rules are only
+/// validated and the changes they would make are returned.
+#[utoipa::path(
+    post,
+    path = "/gsm/import",
+    request_body(
+        content = GsmImportRequest,
+    ),
+    responses(
+        (status = 200, description = "Gsm rules imported", body = GsmImportResponse),
+        (status = 400, description = "Invalid Gsm rules")
+    ),
+    tag = "Gsm",
+    operation_id = "Import Gsm Rules",
+    security(("admin_api_key" = [])),
+)]
+#[instrument(skip_all, fields(flow = ?Flow::GsmRuleImport))]
+pub async fn import_gsm_rules(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<gsm_api_types::GsmImportRequest>,
+) -> impl Responder {
+    let payload = json_payload.into_inner();
+
+    let flow = Flow::GsmRuleImport;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| gsm::import_gsm_rules(state, payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Gsm - Export
+///
+/// To export the Gsm Rules, filtered by connector, flow and decision, as a CSV or JSON file
+#[utoipa::path(
+    post,
+    path = "/gsm/export",
+    request_body(
+        content = GsmExportRequest,
+    ),
+    responses(
+        (status = 200, description = "Gsm rules exported", content_type = "text/csv"),
+        (status = 400, description = "Invalid filters")
+    ),
+    tag = "Gsm",
+    operation_id = "Export Gsm Rules",
+    security(("admin_api_key" = [])),
+)]
+#[instrument(skip_all, fields(flow = ?Flow::GsmRuleExport))]
+pub async fn export_gsm_rules(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<gsm_api_types::GsmExportRequest>,
+) -> impl Responder {
+    let payload = json_payload.into_inner();
+
+    let flow = Flow::GsmRuleExport;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| gsm::export_gsm_rules(state, payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Unified Translation - Create
+///
+/// To create a translation of a unified error message
+#[utoipa::path(
+    post,
+    path = "/gsm/translations",
+    request_body(
+        content = UnifiedTranslationsCreateRequest,
+    ),
+    responses(
+        (status = 200, description = "Translation created", body = UnifiedTranslationsResponse),
+        (status = 400, description = "Missing Mandatory fields")
+    ),
+    tag = "Gsm",
+    operation_id = "Create Unified Translation",
+    security(("admin_api_key" = [])),
+)]
+#[instrument(skip_all, fields(flow = ?Flow::UnifiedTranslationCreate))]
+pub async fn create_unified_translation(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<gsm_api_types::UnifiedTranslationsCreateRequest>,
+) -> impl Responder {
+    let payload = json_payload.into_inner();
+
+    let flow = Flow::UnifiedTranslationCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| gsm::create_unified_translation(state, payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Unified Translation - Update
+///
+/// To update a translation of a unified error message
+#[utoipa::path(
+    post,
+    path = "/gsm/translations/update",
+    request_body(
+        content = UnifiedTranslationsUpdateRequest,
+    ),
+    responses(
+        (status = 200, description = "Translation updated", body = UnifiedTranslationsResponse),
+        (status = 400, description = "Missing Mandatory fields")
+    ),
+    tag = "Gsm",
+    operation_id = "Update Unified Translation",
+    security(("admin_api_key" = [])),
+)]
+#[instrument(skip_all, fields(flow = ?Flow::UnifiedTranslationUpdate))]
+pub async fn update_unified_translation(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<gsm_api_types::UnifiedTranslationsUpdateRequest>,
+) -> impl Responder {
+    let payload = json_payload.into_inner();
+
+    let flow = Flow::UnifiedTranslationUpdate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| gsm::update_unified_translation(state, payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Unified Translation - Delete
+///
+/// To delete a translation of a unified error message
+#[utoipa::path(
+    post,
+    path = "/gsm/translations/delete",
+    request_body(
+        content = UnifiedTranslationsDeleteRequest,
+    ),
+    responses(
+        (status = 200, description = "Translation deleted", body = UnifiedTranslationsDeleteResponse),
+        (status = 400, description = "Missing Mandatory fields")
+    ),
+    tag = "Gsm",
+    operation_id = "Delete Unified Translation",
+    security(("admin_api_key" = [])),
+)]
+#[instrument(skip_all, fields(flow = ?Flow::UnifiedTranslationDelete))]
+pub async fn delete_unified_translation(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<gsm_api_types::UnifiedTranslationsDeleteRequest>,
+) -> impl Responder {
+    let payload = json_payload.into_inner();
+
+    let flow = Flow::UnifiedTranslationDelete;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| gsm::delete_unified_translation(state, payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Unified Translation - List
+///
+/// To list the translations of the unified error messages, filtered by unified code and locale
+#[utoipa::path(
+    post,
+    path = "/gsm/translations/list",
+    request_body(
+        content = UnifiedTranslationsListRequest,
+    ),
+    responses(
+        (status = 200, description = "Translations listed", body = Vec<UnifiedTranslationsResponse>),
+        (status = 400, description = "Invalid filters")
+    ),
+    tag = "Gsm",
+    operation_id = "List Unified Translations",
+    security(("admin_api_key" = [])),
+)]
+#[instrument(skip_all, fields(flow = ?Flow::UnifiedTranslationList))]
+pub async fn list_unified_translations(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<gsm_api_types::UnifiedTranslationsListRequest>,
+) -> impl Responder {
+    let payload = json_payload.into_inner();
+
+    let flow = Flow::UnifiedTranslationList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| gsm::list_unified_translations(state, payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
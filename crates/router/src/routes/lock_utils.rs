@@ -36,6 +36,10 @@ pub enum ApiIdentifier {
     Recon,
     Poll,
     ApplePayCertificatesMigration,
+    AuditLog,
+    Tenant,
+    LogFilter,
+    Subscriptions,
 }
 
 impl From<Flow> for ApiIdentifier {
@@ -102,7 +106,8 @@ impl From<Flow> for ApiIdentifier {
             | Flow::ValidatePaymentMethod
             | Flow::ListCountriesCurrencies
             | Flow::DefaultPaymentMethodsSet
-            | Flow::PaymentMethodSave => Self::PaymentMethods,
+            | Flow::PaymentMethodSave
+            | Flow::NetworkTokenWebhookReceive => Self::PaymentMethods,
 
             Flow::PmAuthLinkTokenCreate | Flow::PmAuthExchangeToken => Self::PaymentMethodAuth,
 
@@ -135,6 +140,10 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PayoutsList
             | Flow::PayoutsFilter
             | Flow::PayoutsAccounts
+            | Flow::PayoutBatchCreate
+            | Flow::PayoutBatchRetrieve
+            | Flow::PayoutBatchItemsList
+            | Flow::PayoutBatchCancel
             | Flow::PayoutsConfirm
             | Flow::PayoutLinkInitiate => Self::Payouts,
 
@@ -177,7 +186,16 @@ impl From<Flow> for ApiIdentifier {
             | Flow::BusinessProfileDelete
             | Flow::BusinessProfileList
             | Flow::ToggleExtendedCardInfo
-            | Flow::ToggleConnectorAgnosticMit => Self::Business,
+            | Flow::ToggleConnectorAgnosticMit
+            | Flow::WebhookSigningSecretRotate
+            | Flow::WebhookSigningSecretList
+            | Flow::WebhookSigningSecretRevoke
+            | Flow::WebhookEndpointCreate
+            | Flow::WebhookEndpointList
+            | Flow::WebhookEndpointRetrieve
+            | Flow::WebhookEndpointUpdate
+            | Flow::WebhookEndpointDelete
+            | Flow::WebhookEndpointSecretRotate => Self::Business,
 
             Flow::PaymentLinkRetrieve
             | Flow::PaymentLinkInitiate
@@ -190,7 +208,14 @@ impl From<Flow> for ApiIdentifier {
             Flow::GsmRuleCreate
             | Flow::GsmRuleRetrieve
             | Flow::GsmRuleUpdate
-            | Flow::GsmRuleDelete => Self::Gsm,
+            | Flow::GsmRuleDelete
+            | Flow::GsmRuleList
+            | Flow::GsmRuleImport
+            | Flow::GsmRuleExport
+            | Flow::UnifiedTranslationCreate
+            | Flow::UnifiedTranslationUpdate
+            | Flow::UnifiedTranslationDelete
+            | Flow::UnifiedTranslationList => Self::Gsm,
 
             Flow::ApplePayCertificatesMigration => Self::ApplePayCertificatesMigration,
 
@@ -234,6 +259,8 @@ impl From<Flow> for ApiIdentifier {
             | Flow::ListUserAuthenticationMethods
             | Flow::GetSsoAuthUrl
             | Flow::SignInWithSso
+            | Flow::GetSamlSpMetadata
+            | Flow::SamlAcs
             | Flow::AuthSelect => Self::User,
 
             Flow::ListRoles
@@ -259,6 +286,27 @@ impl From<Flow> for ApiIdentifier {
             | Flow::ReconVerifyToken => Self::Recon,
 
             Flow::RetrievePollStatus => Self::Poll,
+
+            Flow::AuditLogList => Self::AuditLog,
+
+            Flow::TenantCreate
+            | Flow::TenantRetrieve
+            | Flow::TenantList
+            | Flow::TenantSuspend
+            | Flow::TenantResume
+            | Flow::TenantDelete => Self::Tenant,
+
+            Flow::LogFilterCreate | Flow::LogFilterList | Flow::LogFilterDelete => Self::LogFilter,
+
+            Flow::SubscriptionPlanCreate
+            | Flow::SubscriptionPlanRetrieve
+            | Flow::SubscriptionPlanList
+            | Flow::SubscriptionPlanUpdate
+            | Flow::SubscriptionCreate
+            | Flow::SubscriptionRetrieve
+            | Flow::SubscriptionList
+            | Flow::SubscriptionCancel
+            | Flow::SubscriptionInvoiceList => Self::Subscriptions,
         }
     }
 }
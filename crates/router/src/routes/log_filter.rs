@@ -0,0 +1,75 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::log_filter as log_filter_api_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, log_filter},
+    services::{api, authentication as auth},
+};
+
+/// Log Filter - Create
+///
+/// Log the requests of a merchant, business profile, connector or request path at debug or trace
+/// level on all the instances, for a bounded time
+#[instrument(skip_all, fields(flow = ?Flow::LogFilterCreate))]
+pub async fn create_log_filter(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<log_filter_api_types::LogFilterCreateRequest>,
+) -> impl Responder {
+    let flow = Flow::LogFilterCreate;
+    let app_state = state.get_ref().clone();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |_, _, payload, _| log_filter::create_log_filter(app_state.clone(), payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Log Filter - List
+///
+/// List the log filters which have not expired
+#[instrument(skip_all, fields(flow = ?Flow::LogFilterList))]
+pub async fn list_log_filters(state: web::Data<AppState>, req: HttpRequest) -> impl Responder {
+    let flow = Flow::LogFilterList;
+    let app_state = state.get_ref().clone();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |_, _, _, _| log_filter::list_log_filters(app_state.clone()),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Log Filter - Delete
+///
+/// Stop applying a log filter before it expires
+#[instrument(skip_all, fields(flow = ?Flow::LogFilterDelete))]
+pub async fn delete_log_filter(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::LogFilterDelete;
+    let app_state = state.get_ref().clone();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |_, _, filter_id, _| log_filter::delete_log_filter(app_state.clone(), filter_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -54,6 +54,9 @@ counter_metric!(API_KEY_REVOKED, GLOBAL_METER);
 
 counter_metric!(MCA_CREATE, GLOBAL_METER);
 
+// Payments blocked by the blocklist, counted once per payment by the kind of the first blocked data
+counter_metric!(BLOCKED_PAYMENT_COUNT, GLOBAL_METER);
+
 // Flow Specific Metrics
 
 histogram_metric!(CONNECTOR_REQUEST_TIME, GLOBAL_METER);
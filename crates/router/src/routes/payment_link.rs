@@ -2,7 +2,7 @@ use actix_web::{web, Responder};
 use router_env::{instrument, tracing, Flow};
 
 use crate::{
-    core::{api_locking, payment_link::*},
+    core::{api_locking, link_hosting, payment_link::*},
     services::{api, authentication as auth},
     AppState,
 };
@@ -62,6 +62,8 @@ pub async fn initiate_payment_link(
         payment_id,
         merchant_id: merchant_id.clone(),
     };
+    let request_context =
+        link_hosting::LinkRenderRequestContext::new(&req, &state.conf.custom_domain);
     Box::pin(api::server_wrap(
         flow,
         state,
@@ -74,6 +76,7 @@ pub async fn initiate_payment_link(
                 auth.key_store,
                 payload.merchant_id.clone(),
                 payload.payment_id.clone(),
+                request_context.clone(),
             )
         },
         &crate::services::authentication::MerchantIdAuth(merchant_id),
@@ -136,6 +139,8 @@ pub async fn payment_link_status(
         payment_id,
         merchant_id: merchant_id.clone(),
     };
+    let request_context =
+        link_hosting::LinkRenderRequestContext::new(&req, &state.conf.custom_domain);
     Box::pin(api::server_wrap(
         flow,
         state,
@@ -148,6 +153,7 @@ pub async fn payment_link_status(
                 auth.key_store,
                 payload.merchant_id.clone(),
                 payload.payment_id.clone(),
+                request_context.clone(),
             )
         },
         &crate::services::authentication::MerchantIdAuth(merchant_id),
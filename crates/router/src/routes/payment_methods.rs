@@ -7,8 +7,8 @@ use router_env::{instrument, logger, tracing, Flow};
 use super::app::{AppState, SessionState};
 use crate::{
     core::{
-        api_locking, errors,
-        payment_methods::{self as payment_methods_routes, cards},
+        api_locking, errors, link_hosting,
+        payment_methods::{self as payment_methods_routes, cards, network_tokenization},
     },
     services::{api, authentication as auth, authorization::permissions::Permission},
     types::{
@@ -265,6 +265,8 @@ pub async fn render_pm_collect_link(
         merchant_id: merchant_id.clone(),
         pm_collect_link_id,
     };
+    let request_context =
+        link_hosting::LinkRenderRequestContext::new(&req, &state.conf.custom_domain);
     Box::pin(api::server_wrap(
         flow,
         state,
@@ -276,6 +278,7 @@ pub async fn render_pm_collect_link(
                 auth.merchant_account,
                 auth.key_store,
                 req,
+                request_context.clone(),
             )
         },
         &auth::MerchantIdAuth(merchant_id),
@@ -284,6 +287,29 @@ pub async fn render_pm_collect_link(
     .await
 }
 
+/// Receive token lifecycle updates from the network token service provider
+#[instrument(skip_all, fields(flow = ?Flow::NetworkTokenWebhookReceive))]
+pub async fn network_token_webhook_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    body: web::Bytes,
+) -> HttpResponse {
+    let flow = Flow::NetworkTokenWebhookReceive;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, _: (), _, _| {
+            network_tokenization::handle_network_token_webhook(state, &req, body.clone())
+        },
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsRetrieve))]
 pub async fn payment_method_retrieve_api(
     state: web::Data<AppState>,
@@ -117,6 +117,14 @@ pub async fn payments_create(
             .unwrap_or_default(),
     );
 
+    let header_payload = match HeaderPayload::foreign_try_from(req.headers()) {
+        Ok(headers) => headers,
+        Err(err) => {
+            logger::error!(?err, "Failed to get headers in payments_create");
+            HeaderPayload::default()
+        }
+    };
+
     let locking_action = payload.get_locking_input(flow.clone());
 
     Box::pin(api::server_wrap(
@@ -131,7 +139,7 @@ pub async fn payments_create(
                 req_state,
                 auth.merchant_account,
                 auth.key_store,
-                HeaderPayload::default(),
+                header_payload.clone(),
                 req,
                 api::AuthFlow::Merchant,
             )
@@ -263,6 +271,14 @@ pub async fn payments_retrieve(
             Err(err) => return api::log_and_return_error_response(report!(err)),
         };
 
+    let header_payload = match HeaderPayload::foreign_try_from(req.headers()) {
+        Ok(headers) => headers,
+        Err(err) => {
+            logger::error!(?err, "Failed to get headers in payments_retrieve");
+            HeaderPayload::default()
+        }
+    };
+
     let locking_action = payload.get_locking_input(flow.clone());
 
     Box::pin(api::server_wrap(
@@ -281,7 +297,7 @@ pub async fn payments_retrieve(
                 auth_flow,
                 payments::CallConnectorAction::Trigger,
                 None,
-                HeaderPayload::default(),
+                header_payload.clone(),
             )
         },
         auth::auth_type(
@@ -30,6 +30,7 @@ pub fn populate_ip_into_browser_info(
             accept_header: None,
             user_agent: None,
             ip_address: None,
+            device_fingerprint: None,
         });
 
     let ip_address = req
@@ -7,7 +7,7 @@ use router_env::Flow;
 
 #[cfg(feature = "payouts")]
 use crate::{
-    core::{api_locking, payout_link::*},
+    core::{api_locking, link_hosting, payout_link::*},
     services::{api, authentication as auth},
     AppState,
 };
@@ -23,13 +23,21 @@ pub async fn render_payout_link(
         merchant_id: merchant_id.clone(),
         payout_id,
     };
+    let request_context =
+        link_hosting::LinkRenderRequestContext::new(&req, &state.conf.custom_domain);
     Box::pin(api::server_wrap(
         flow,
         state,
         &req,
         payload.clone(),
         |state, auth, req, _| {
-            initiate_payout_link(state, auth.merchant_account, auth.key_store, req)
+            initiate_payout_link(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+                request_context.clone(),
+            )
         },
         &auth::MerchantIdAuth(merchant_id),
         api_locking::LockAction::NotApplicable,
@@ -363,6 +363,171 @@ pub async fn payouts_list_available_filters(
     .await
 }
 
+/// Payouts - Create batch
+#[utoipa::path(
+    post,
+    path = "/payouts/batches",
+    request_body=PayoutBatchCreateRequest,
+    responses(
+        (status = 200, description = "Payout batch created", body = PayoutBatchResponse),
+        (status = 400, description = "Invalid payout batch file")
+    ),
+    tag = "Payouts",
+    operation_id = "Create a Payout Batch",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutBatchCreate))]
+pub async fn payout_batch_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payout_types::PayoutBatchCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::PayoutBatchCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| {
+            batch::payout_batch_create_core(state, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payouts - Retrieve batch
+#[utoipa::path(
+    get,
+    path = "/payouts/batches/{batch_id}",
+    params(
+        ("batch_id" = String, Path, description = "The identifier for the payout batch")
+    ),
+    responses(
+        (status = 200, description = "Payout batch retrieved", body = PayoutBatchResponse),
+        (status = 404, description = "Payout batch does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Retrieve a Payout Batch",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutBatchRetrieve))]
+pub async fn payout_batch_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PayoutBatchRetrieve;
+    let payload = payout_types::PayoutBatchRetrieveRequest {
+        batch_id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| batch::payout_batch_retrieve_core(state, auth.merchant_account, req),
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PayoutRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payouts - List batch items
+#[utoipa::path(
+    get,
+    path = "/payouts/batches/{batch_id}/items",
+    params(
+        ("batch_id" = String, Path, description = "The identifier for the payout batch"),
+        ("status" = Option<PayoutBatchItemStatus>, Query, description = "Only list the rows with this status"),
+        ("limit" = Option<u32>, Query, description = "The maximum number of rows to return"),
+        ("offset" = Option<u32>, Query, description = "The number of rows to skip")
+    ),
+    responses(
+        (status = 200, description = "Payout batch items listed", body = PayoutBatchItemListResponse),
+        (status = 404, description = "Payout batch does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "List Payout Batch Items",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutBatchItemsList))]
+pub async fn payout_batch_items_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query_params: web::Query<payout_types::PayoutBatchItemListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::PayoutBatchItemsList;
+    let payload = payout_types::PayoutBatchItemListRequest {
+        batch_id: path.into_inner(),
+        constraints: query_params.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            batch::payout_batch_items_list_core(state, auth.merchant_account, req)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PayoutRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payouts - Cancel batch
+#[utoipa::path(
+    post,
+    path = "/payouts/batches/{batch_id}/cancel",
+    params(
+        ("batch_id" = String, Path, description = "The identifier for the payout batch")
+    ),
+    responses(
+        (status = 200, description = "Payout batch cancelled", body = PayoutBatchResponse),
+        (status = 400, description = "Payout batch cannot be cancelled")
+    ),
+    tag = "Payouts",
+    operation_id = "Cancel a Payout Batch",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutBatchCancel))]
+pub async fn payout_batch_cancel(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PayoutBatchCancel;
+    let payload = payout_types::PayoutBatchRetrieveRequest {
+        batch_id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            batch::payout_batch_cancel_core(state, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsAccounts))]
 // #[get("/accounts")]
 pub async fn payouts_accounts() -> impl Responder {
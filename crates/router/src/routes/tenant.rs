@@ -0,0 +1,143 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::tenant as tenant_api_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, tenant},
+    services::{api, authentication as auth},
+};
+
+/// Tenant - Create
+///
+/// Provision a tenant at runtime. The Postgres schema of the tenant is created and migrated in the
+/// background, and the tenant is served by all the instances without a restart once it is active.
+#[instrument(skip_all, fields(flow = ?Flow::TenantCreate))]
+pub async fn create_tenant(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<tenant_api_types::TenantCreateRequest>,
+) -> impl Responder {
+    let flow = Flow::TenantCreate;
+    let app_state = state.get_ref().clone();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, payload, _| tenant::create_tenant(state, app_state.clone(), payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Tenant - Retrieve
+///
+/// Retrieve a tenant provisioned at runtime
+#[instrument(skip_all, fields(flow = ?Flow::TenantRetrieve))]
+pub async fn retrieve_tenant(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::TenantRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, _, tenant_id, _| tenant::retrieve_tenant(state, tenant_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Tenant - List
+///
+/// List the tenants provisioned at runtime
+#[instrument(skip_all, fields(flow = ?Flow::TenantList))]
+pub async fn list_tenants(state: web::Data<AppState>, req: HttpRequest) -> impl Responder {
+    let flow = Flow::TenantList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, _, _, _| tenant::list_tenants(state),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Tenant - Suspend
+///
+/// Suspend a tenant provisioned at runtime. Requests for the tenant are rejected by all the
+/// instances until it is resumed.
+#[instrument(skip_all, fields(flow = ?Flow::TenantSuspend))]
+pub async fn suspend_tenant(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::TenantSuspend;
+    let app_state = state.get_ref().clone();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, _, tenant_id, _| tenant::suspend_tenant(state, app_state.clone(), tenant_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Tenant - Resume
+///
+/// Resume a suspended tenant, or retry provisioning a tenant which failed to be provisioned
+#[instrument(skip_all, fields(flow = ?Flow::TenantResume))]
+pub async fn resume_tenant(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::TenantResume;
+    let app_state = state.get_ref().clone();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, _, tenant_id, _| tenant::resume_tenant(state, app_state.clone(), tenant_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Tenant - Delete
+///
+/// Delete a tenant provisioned at runtime. The schema of the tenant and its data are retained.
+#[instrument(skip_all, fields(flow = ?Flow::TenantDelete))]
+pub async fn delete_tenant(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::TenantDelete;
+    let app_state = state.get_ref().clone();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, _, tenant_id, _| tenant::delete_tenant(state, app_state.clone(), tenant_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -795,6 +795,46 @@ pub async fn get_sso_auth_url(
     .await
 }
 
+pub async fn get_saml_sp_metadata(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::GetSamlSpMetadata;
+    let auth_method_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        auth_method_id,
+        |state, _: (), auth_method_id, _| user_core::get_saml_sp_metadata(state, auth_method_id),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn saml_acs(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    form_payload: web::Form<user_api::SamlAcsRequest>,
+) -> HttpResponse {
+    let flow = Flow::SamlAcs;
+    let auth_method_id = path.into_inner();
+    let payload = form_payload.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        payload,
+        |state, _: (), payload, _| user_core::saml_acs(state, auth_method_id.clone(), payload),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 pub async fn sso_sign(
     state: web::Data<AppState>,
     req: HttpRequest,
@@ -16,6 +16,8 @@ pub mod recon;
 
 #[cfg(feature = "olap")]
 pub mod openidconnect;
+#[cfg(feature = "olap")]
+pub mod saml;
 
 use std::sync::Arc;
 
@@ -24,14 +26,24 @@ use hyperswitch_domain_models::errors::StorageResult;
 pub use hyperswitch_interfaces::connector_integration_v2::{
     BoxedConnectorIntegrationV2, ConnectorIntegrationAnyV2, ConnectorIntegrationV2,
 };
+use hyperswitch_interfaces::secrets_interface::{
+    database_credentials::SecretManagedCredentials, secret_state::SecuredSecret,
+    SecretManagementInterface,
+};
 use masking::{ExposeInterface, StrongSecret};
 #[cfg(feature = "kv_store")]
 use storage_impl::KVRouterStore;
-use storage_impl::{config::TenantConfig, redis::RedisStore, RouterStore};
+use storage_impl::{
+    config::TenantConfig, database::credentials::CredentialsProvider, redis::RedisStore,
+    RouterStore,
+};
 use tokio::sync::oneshot;
 
 pub use self::{api::*, encryption::*};
-use crate::{configs::Settings, core::errors};
+use crate::{
+    configs::{settings, Settings},
+    core::errors,
+};
 
 #[cfg(not(feature = "olap"))]
 pub type StoreType = storage_impl::database::store::Store;
@@ -51,12 +63,19 @@ pub async fn get_store(
     config: &Settings,
     tenant: &dyn TenantConfig,
     cache_store: Arc<RedisStore>,
+    database_credentials: &DatabaseCredentials,
     test_transaction: bool,
 ) -> StorageResult<Store> {
-    let master_config = config.master_database.clone().into_inner();
+    let master_config = storage_impl::config::Database {
+        credentials_provider: database_credentials.master.clone(),
+        ..config.master_database.clone().into_inner().into()
+    };
 
     #[cfg(feature = "olap")]
-    let replica_config = config.replica_database.clone().into_inner();
+    let replica_config = storage_impl::config::Database {
+        credentials_provider: database_credentials.replica.clone(),
+        ..config.replica_database.clone().into_inner().into()
+    };
 
     #[allow(clippy::expect_used)]
     let master_enc_key = hex::decode(config.secrets.get_inner().master_enc_key.clone().expose())
@@ -64,14 +83,13 @@ pub async fn get_store(
         .expect("Failed to decode master key from hex");
 
     #[cfg(not(feature = "olap"))]
-    let conf = master_config.into();
+    let conf = master_config;
     #[cfg(feature = "olap")]
     // this would get abstracted, for all cases
-    #[allow(clippy::useless_conversion)]
-    let conf = (master_config.into(), replica_config.into());
+    let conf = (master_config, replica_config);
 
     let store: RouterStore<StoreType> = if test_transaction {
-        RouterStore::test_store(conf, tenant, &config.redis, master_enc_key).await?
+        RouterStore::test_store(conf, tenant, config.redis.get_inner(), master_enc_key).await?
     } else {
         RouterStore::from_config(
             conf,
@@ -95,13 +113,49 @@ pub async fn get_store(
     Ok(store)
 }
 
+/// Providers of the credentials of the master and replica database users, present for the
+/// databases whose credentials are refreshed periodically
+#[derive(Clone, Debug, Default)]
+pub struct DatabaseCredentials {
+    pub master: Option<Arc<dyn CredentialsProvider>>,
+    #[cfg(feature = "olap")]
+    pub replica: Option<Arc<dyn CredentialsProvider>>,
+}
+
+impl DatabaseCredentials {
+    pub fn new(
+        config: &settings::Settings<SecuredSecret>,
+        secret_management_client: &Arc<dyn SecretManagementInterface>,
+    ) -> Self {
+        Self {
+            master: SecretManagedCredentials::for_database(
+                &config.master_database.get_inner().password,
+                config
+                    .master_database
+                    .get_inner()
+                    .credentials_refresh_interval,
+                secret_management_client,
+            ),
+            #[cfg(feature = "olap")]
+            replica: SecretManagedCredentials::for_database(
+                &config.replica_database.get_inner().password,
+                config
+                    .replica_database
+                    .get_inner()
+                    .credentials_refresh_interval,
+                secret_management_client,
+            ),
+        }
+    }
+}
+
 #[allow(clippy::expect_used)]
 pub async fn get_cache_store(
     config: &Settings,
     shut_down_signal: oneshot::Sender<()>,
     _test_transaction: bool,
 ) -> StorageResult<Arc<RedisStore>> {
-    RouterStore::<StoreType>::cache_store(&config.redis, shut_down_signal).await
+    RouterStore::<StoreType>::cache_store(config.redis.get_inner(), shut_down_signal).await
 }
 
 #[inline]
@@ -17,11 +17,16 @@ use actix_web::{
     ResponseError,
 };
 use api_models::enums::{CaptureMethod, PaymentMethodType};
-pub use client::{proxy_bypass_urls, ApiClient, MockApiClient, ProxyClient};
+pub use client::{
+    proxy_bypass_urls,
+    record_replay::{RecordReplayApiClient, RecordReplayMode},
+    ApiClient, MockApiClient, ProxyClient,
+};
 pub use common_utils::request::{ContentType, Method, Request, RequestBuilder};
 use common_utils::{
     consts::{DEFAULT_TENANT, TENANT_HEADER, X_HS_LATENCY},
     errors::{ErrorSwitch, ReportSwitchExt},
+    link_utils,
     request::RequestContent,
 };
 use error_stack::{report, Report, ResultExt};
@@ -46,13 +51,15 @@ use super::{
     authentication::AuthenticateAndFetch,
     connector_integration_interface::BoxedConnectorIntegrationInterface,
 };
+#[cfg(feature = "olap")]
+use crate::core::audit_log;
 use crate::{
     configs::Settings,
     consts,
     core::{
         api_locking,
         errors::{self, CustomResult},
-        payments,
+        link_hosting, payments,
     },
     events::{
         api_logs::{ApiEvent, ApiEventMetric, ApiEventsType},
@@ -727,7 +734,13 @@ pub enum ApplicationResponse<R> {
 }
 
 #[derive(Debug, Eq, PartialEq)]
-pub enum GenericLinks {
+pub struct GenericLinks {
+    pub render_config: LinkRenderConfig,
+    pub data: GenericLinksData,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum GenericLinksData {
     ExpiredLink(GenericExpiredLinkData),
     PaymentMethodCollect(GenericLinkFormData),
     PayoutLink(GenericLinkFormData),
@@ -735,22 +748,61 @@ pub enum GenericLinks {
     PaymentMethodCollectStatus(GenericLinkStatusData),
 }
 
-impl Display for Box<GenericLinks> {
+impl Display for GenericLinksData {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
             "{}",
-            match **self {
-                GenericLinks::ExpiredLink(_) => "ExpiredLink",
-                GenericLinks::PaymentMethodCollect(_) => "PaymentMethodCollect",
-                GenericLinks::PayoutLink(_) => "PayoutLink",
-                GenericLinks::PayoutLinkStatus(_) => "PayoutLinkStatus",
-                GenericLinks::PaymentMethodCollectStatus(_) => "PaymentMethodCollectStatus",
+            match *self {
+                Self::ExpiredLink(_) => "ExpiredLink",
+                Self::PaymentMethodCollect(_) => "PaymentMethodCollect",
+                Self::PayoutLink(_) => "PayoutLink",
+                Self::PayoutLinkStatus(_) => "PayoutLinkStatus",
+                Self::PaymentMethodCollectStatus(_) => "PaymentMethodCollectStatus",
             }
         )
     }
 }
 
+/// Customizations applied when rendering a link, resolved from the configuration of the link's
+/// business profile and the request made for the link
+#[derive(Debug, Default, Eq, PartialEq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct LinkRenderConfig {
+    /// Locale the link is rendered in
+    pub locale: String,
+    /// Domains allowed to embed the link in an iframe
+    pub allowed_domains: HashSet<String>,
+    /// Custom HTML and CSS of the link
+    pub custom_template: Option<link_utils::LinkTemplateConfig>,
+}
+
+impl LinkRenderConfig {
+    /// Response headers to be sent along with the rendered link
+    pub fn get_response_headers(&self) -> Option<HashSet<(&'static str, String)>> {
+        link_hosting::get_content_security_policy(&self.allowed_domains)
+            .map(|csp| HashSet::from([("content-security-policy", csp)]))
+    }
+
+    /// Custom CSS appended to the default styles of the link.
+    ///
+    /// The CSS is embedded in a `<style>` element, `<` is escaped so that the CSS cannot close
+    /// the element and inject markup into the link.
+    pub fn get_custom_css(&self) -> String {
+        self.custom_template
+            .as_ref()
+            .and_then(|template| template.css.as_deref())
+            .map(|css| css.replace('<', "\\3c "))
+            .unwrap_or_default()
+    }
+
+    /// Custom HTML template of the link's form, if configured
+    pub fn get_custom_html(&self) -> Option<&str> {
+        self.custom_template
+            .as_ref()
+            .and_then(|template| template.html.as_deref())
+    }
+}
+
 #[derive(Debug, Eq, PartialEq, Clone, serde::Serialize, serde::Deserialize)]
 pub struct GenericLinkFormData {
     pub js_data: String,
@@ -784,12 +836,14 @@ pub struct PaymentLinkFormData {
     pub css_script: String,
     pub sdk_url: String,
     pub html_meta_tags: String,
+    pub render_config: LinkRenderConfig,
 }
 
 #[derive(Debug, Eq, PartialEq, Clone, serde::Serialize, serde::Deserialize)]
 pub struct PaymentLinkStatusData {
     pub js_script: String,
     pub css_script: String,
+    pub render_config: LinkRenderConfig,
 }
 
 #[derive(Debug, Eq, PartialEq)]
@@ -863,12 +917,7 @@ where
         .change_context(errors::ApiErrorResponse::InternalServerError.switch())?;
 
     let mut event_type = payload.get_api_event_type();
-    let tenants: HashSet<_> = state
-        .conf
-        .multitenancy
-        .get_tenant_names()
-        .into_iter()
-        .collect();
+    let tenants: HashSet<_> = state.get_tenant_names().into_iter().collect();
     let tenant_id = if !state.conf.multitenancy.enabled {
         DEFAULT_TENANT.to_string()
     } else {
@@ -919,6 +968,17 @@ where
 
     tracing::Span::current().record("merchant_id", &merchant_id);
 
+    // The state of the audited resource is loaded before it is modified by the request
+    #[cfg(feature = "olap")]
+    let audit_previous_state = audit_log::get_previous_state(
+        &session_state,
+        flow,
+        &auth_type,
+        request,
+        &serialized_request,
+    )
+    .await;
+
     let output = {
         lock_action
             .clone()
@@ -979,6 +1039,20 @@ where
         }
     };
 
+    #[cfg(feature = "olap")]
+    if output.is_ok() {
+        audit_log::record_audit_log(
+            &session_state,
+            flow,
+            &auth_type,
+            request,
+            &serialized_request,
+            serialized_response.as_ref(),
+            audit_previous_state,
+        )
+        .await;
+    }
+
     let api_event = ApiEvent::new(
         Some(merchant_id.clone()),
         flow,
@@ -1118,9 +1192,10 @@ where
         }
 
         Ok(ApplicationResponse::GenericLinkForm(boxed_generic_link_data)) => {
-            let link_type = (boxed_generic_link_data).to_string();
+            let link_type = boxed_generic_link_data.data.to_string();
+            let headers = boxed_generic_link_data.render_config.get_response_headers();
             match build_generic_link_html(*boxed_generic_link_data) {
-                Ok(rendered_html) => http_response_html_data(rendered_html),
+                Ok(rendered_html) => http_response_html_data(rendered_html, headers),
                 Err(_) => {
                     http_response_err(format!("Error while rendering {} HTML page", link_type))
                 }
@@ -1130,8 +1205,9 @@ where
         Ok(ApplicationResponse::PaymentLinkForm(boxed_payment_link_data)) => {
             match *boxed_payment_link_data {
                 PaymentLinkAction::PaymentLinkFormData(payment_link_data) => {
+                    let headers = payment_link_data.render_config.get_response_headers();
                     match build_payment_link_html(payment_link_data) {
-                        Ok(rendered_html) => http_response_html_data(rendered_html),
+                        Ok(rendered_html) => http_response_html_data(rendered_html, headers),
                         Err(_) => http_response_err(
                             r#"{
                                 "error": {
@@ -1142,8 +1218,9 @@ where
                     }
                 }
                 PaymentLinkAction::PaymentLinkStatus(payment_link_data) => {
+                    let headers = payment_link_data.render_config.get_response_headers();
                     match get_payment_link_status(payment_link_data) {
-                        Ok(rendered_html) => http_response_html_data(rendered_html),
+                        Ok(rendered_html) => http_response_html_data(rendered_html, headers),
                         Err(_) => http_response_err(
                             r#"{
                                 "error": {
@@ -1300,8 +1377,17 @@ pub fn http_response_file_data<T: body::MessageBody + 'static>(
     HttpResponse::Ok().content_type(content_type).body(res)
 }
 
-pub fn http_response_html_data<T: body::MessageBody + 'static>(res: T) -> HttpResponse {
-    HttpResponse::Ok().content_type(mime::TEXT_HTML).body(res)
+pub fn http_response_html_data<T: body::MessageBody + 'static>(
+    res: T,
+    optional_headers: Option<HashSet<(&'static str, String)>>,
+) -> HttpResponse {
+    let mut response_builder = HttpResponse::Ok();
+    if let Some(headers) = optional_headers {
+        for (key, value) in headers {
+            response_builder.append_header((key, value));
+        }
+    }
+    response_builder.content_type(mime::TEXT_HTML).body(res)
 }
 
 pub fn http_response_ok() -> HttpResponse {
@@ -1913,7 +1999,11 @@ pub fn build_payment_link_html(
     context.insert("css_color_scheme", &payment_link_data.css_script);
 
     let rendered_css = match tera.render("payment_link_css", &context) {
-        Ok(rendered_css) => rendered_css,
+        Ok(rendered_css) => format!(
+            "{}\n{}",
+            rendered_css,
+            payment_link_data.render_config.get_custom_css()
+        ),
         Err(tera_error) => {
             crate::logger::warn!("{tera_error}");
             Err(errors::ApiErrorResponse::InternalServerError)?
@@ -1926,7 +2016,15 @@ pub fn build_payment_link_html(
 
     let _ = tera.add_raw_template("payment_link_js", &js_template);
 
-    context.insert("payment_details_js_script", &payment_link_data.js_script);
+    let locale = &payment_link_data.render_config.locale;
+    context.insert(
+        "payment_details_js_script",
+        &format!(
+            "{}\n{}",
+            link_hosting::get_locale_js_script(locale)?,
+            payment_link_data.js_script
+        ),
+    );
 
     let rendered_js = match tera.render("payment_link_js", &context) {
         Ok(rendered_js) => rendered_js,
@@ -1941,11 +2039,18 @@ pub fn build_payment_link_html(
         include_str!("redirection/assets/redirect_error_logs_push.js").to_string();
 
     // Modify Html template with rendered js and rendered css files
-    let html_template =
-        include_str!("../core/payment_link/payment_link_initiate/payment_link.html").to_string();
+    let html_template = payment_link_data
+        .render_config
+        .get_custom_html()
+        .unwrap_or(include_str!(
+            "../core/payment_link/payment_link_initiate/payment_link.html"
+        ))
+        .to_string();
 
     let _ = tera.add_raw_template("payment_link", &html_template);
 
+    context.insert("locale", locale);
+    context.insert("strings", &link_hosting::get_locale_strings(locale));
     context.insert("rendered_meta_tag_html", &payment_link_data.html_meta_tags);
 
     context.insert(
@@ -2001,7 +2106,11 @@ pub fn get_payment_link_status(
     context.insert("css_color_scheme", &payment_link_data.css_script);
 
     let rendered_css = match tera.render("payment_link_css", &context) {
-        Ok(rendered_css) => rendered_css,
+        Ok(rendered_css) => format!(
+            "{}\n{}",
+            rendered_css,
+            payment_link_data.render_config.get_custom_css()
+        ),
         Err(tera_error) => {
             crate::logger::warn!("{tera_error}");
             Err(errors::ApiErrorResponse::InternalServerError)?
@@ -2016,7 +2125,15 @@ pub fn get_payment_link_status(
     let js_template =
         include_str!("../core/payment_link/payment_link_status/status.js").to_string();
     let _ = tera.add_raw_template("payment_link_js", &js_template);
-    context.insert("payment_details_js_script", &payment_link_data.js_script);
+    let locale = &payment_link_data.render_config.locale;
+    context.insert(
+        "payment_details_js_script",
+        &format!(
+            "{}\n{}",
+            link_hosting::get_locale_js_script(locale)?,
+            payment_link_data.js_script
+        ),
+    );
 
     let rendered_js = match tera.render("payment_link_js", &context) {
         Ok(rendered_js) => rendered_js,
@@ -2031,6 +2148,8 @@ pub fn get_payment_link_status(
         include_str!("../core/payment_link/payment_link_status/status.html").to_string();
     let _ = tera.add_raw_template("payment_link_status", &html_template);
 
+    context.insert("locale", locale);
+    context.insert("strings", &link_hosting::get_locale_strings(locale));
     context.insert("rendered_css", &rendered_css);
 
     context.insert("rendered_js", &rendered_js);
@@ -2047,8 +2166,35 @@ pub fn get_payment_link_status(
 
 #[cfg(test)]
 mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
     #[test]
     fn test_mime_essence() {
         assert_eq!(mime::APPLICATION_JSON.essence_str(), "application/json");
     }
+
+    #[test]
+    fn test_custom_css_cannot_close_style_element() {
+        let render_config = LinkRenderConfig {
+            custom_template: Some(link_utils::LinkTemplateConfig {
+                html: None,
+                css: Some("body { color: red; }</style><script>alert(1)</script>".to_string()),
+            }),
+            ..Default::default()
+        };
+        let custom_css = render_config.get_custom_css();
+        assert!(!custom_css.contains('<'));
+        assert!(custom_css.contains("\\3c /style>"));
+
+        let payment_link_html = build_payment_link_html(PaymentLinkFormData {
+            js_script: String::new(),
+            css_script: String::new(),
+            sdk_url: "https://sdk.example.com/HyperLoader.js".to_string(),
+            html_meta_tags: String::new(),
+            render_config,
+        })
+        .unwrap();
+        assert!(!payment_link_html.contains("<script>alert(1)</script>"));
+    }
 }
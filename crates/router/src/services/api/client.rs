@@ -1,3 +1,5 @@
+pub mod record_replay;
+
 use std::time::Duration;
 
 use error_stack::ResultExt;
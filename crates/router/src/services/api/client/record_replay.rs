@@ -0,0 +1,449 @@
+//! Record and replay of outgoing connector requests.
+//!
+//! In record mode, requests are sent to the connector and every request/response pair is saved
+//! to a cassette file, with the secrets in the request headers and body masked. In replay mode,
+//! the responses are served from the cassette in the order they were recorded, without any network
+//! access, and any request that does not match the next recorded interaction fails.
+
+use std::{
+    collections::BTreeMap,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+use common_utils::request::{Method, Request, RequestContent};
+use error_stack::{report, ResultExt};
+use http::Method as HttpMethod;
+use router_env::{logger, tracing_actix_web::RequestId};
+use serde::{Deserialize, Serialize};
+
+use super::{ApiClient, RequestBuilder};
+use crate::{
+    core::errors::{ApiClientError, CustomResult},
+    routes::SessionState,
+};
+
+/// Response fields whose values are masked before the response is saved to the cassette
+const SENSITIVE_RESPONSE_FIELDS: [&str; 6] = [
+    "access_token",
+    "refresh_token",
+    "client_secret",
+    "api_key",
+    "password",
+    "secret",
+];
+
+/// Response headers which are not saved to the cassette
+const SENSITIVE_RESPONSE_HEADERS: [&str; 2] = ["set-cookie", "authorization"];
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, strum::Display, strum::EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum RecordReplayMode {
+    /// Send requests to the connector and save the interactions to the cassette
+    Record,
+    /// Serve the responses saved in the cassette, without sending any request
+    Replay,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Cassette {
+    pub interactions: Vec<Interaction>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Interaction {
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    pub method: Method,
+    pub url: String,
+    pub headers: BTreeMap<String, String>,
+    pub body: Option<serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    pub status_code: u16,
+    pub headers: BTreeMap<String, String>,
+    pub body: String,
+}
+
+#[derive(Debug, Default)]
+struct CassetteState {
+    cassette: Cassette,
+    /// Index of the next interaction to be served in replay mode
+    position: usize,
+}
+
+/// Api client which records the requests sent to connectors to a cassette file, or replays the
+/// responses from a previously recorded cassette file
+#[derive(Clone)]
+pub struct RecordReplayApiClient {
+    mode: RecordReplayMode,
+    cassette_path: PathBuf,
+    state: Arc<Mutex<CassetteState>>,
+    request_id: Option<String>,
+}
+
+impl RecordReplayApiClient {
+    /// Create a client for the cassette at `cassette_path`. The cassette is loaded from the file
+    /// in replay mode, while recording always starts with an empty cassette.
+    pub fn new(
+        mode: RecordReplayMode,
+        cassette_path: PathBuf,
+    ) -> CustomResult<Self, ApiClientError> {
+        let cassette = match mode {
+            RecordReplayMode::Record => Cassette::default(),
+            RecordReplayMode::Replay => {
+                let contents = std::fs::read_to_string(&cassette_path)
+                    .change_context(ApiClientError::CassetteOperationFailed)
+                    .attach_printable_lazy(|| {
+                        format!("Failed to read cassette at {}", cassette_path.display())
+                    })?;
+                serde_json::from_str(&contents)
+                    .change_context(ApiClientError::CassetteOperationFailed)
+                    .attach_printable_lazy(|| {
+                        format!("Failed to parse cassette at {}", cassette_path.display())
+                    })?
+            }
+        };
+
+        Ok(Self {
+            mode,
+            cassette_path,
+            state: Arc::new(Mutex::new(CassetteState {
+                cassette,
+                position: 0,
+            })),
+            request_id: None,
+        })
+    }
+
+    pub fn get_mode(&self) -> RecordReplayMode {
+        self.mode
+    }
+
+    /// Number of recorded interactions which have not been served yet in replay mode
+    pub fn get_pending_interactions_count(&self) -> CustomResult<usize, ApiClientError> {
+        let state = self.lock_state()?;
+        Ok(state
+            .cassette
+            .interactions
+            .len()
+            .saturating_sub(state.position))
+    }
+
+    fn lock_state(&self) -> CustomResult<std::sync::MutexGuard<'_, CassetteState>, ApiClientError> {
+        self.state
+            .lock()
+            .map_err(|_| report!(ApiClientError::UnexpectedState))
+            .attach_printable("Cassette state lock is poisoned")
+    }
+
+    async fn record(
+        &self,
+        state: &SessionState,
+        request: Request,
+        option_timeout_secs: Option<u64>,
+    ) -> CustomResult<reqwest::Response, ApiClientError> {
+        let recorded_request = sanitize_request(&request);
+        let response = crate::services::send_request(state, request, option_timeout_secs).await?;
+
+        let status_code = response.status().as_u16();
+        let headers = response
+            .headers()
+            .iter()
+            .filter(|(name, _)| !SENSITIVE_RESPONSE_HEADERS.contains(&name.as_str()))
+            .filter_map(|(name, value)| {
+                value
+                    .to_str()
+                    .ok()
+                    .map(|value| (name.to_string(), value.to_string()))
+            })
+            .collect::<BTreeMap<_, _>>();
+        let body = response
+            .bytes()
+            .await
+            .change_context(ApiClientError::ResponseDecodingFailed)?;
+
+        let response = RecordedResponse {
+            status_code,
+            headers,
+            body: String::from_utf8_lossy(&body).into_owned(),
+        };
+
+        {
+            let mut cassette_state = self.lock_state()?;
+            cassette_state.cassette.interactions.push(Interaction {
+                request: recorded_request,
+                response: RecordedResponse {
+                    body: sanitize_response_body(&response.body),
+                    ..response.clone()
+                },
+            });
+            self.save_cassette(&cassette_state.cassette)?;
+        }
+
+        // The connector receives the unmasked response, since later requests of the test may
+        // need the credentials returned by the connector (access tokens, for instance)
+        build_response(&response)
+    }
+
+    fn replay(&self, request: &Request) -> CustomResult<reqwest::Response, ApiClientError> {
+        let actual_request = sanitize_request(request);
+        let mut cassette_state = self.lock_state()?;
+        let position = cassette_state.position;
+
+        let interaction = cassette_state
+            .cassette
+            .interactions
+            .get(position)
+            .cloned()
+            .ok_or(ApiClientError::UnexpectedRequest)
+            .attach_printable_lazy(|| {
+                format!(
+                    "Unexpected request {} {}, all {} recorded interactions have been replayed",
+                    actual_request.method, actual_request.url, position
+                )
+            })?;
+
+        if !interaction.request.matches(&actual_request) {
+            logger::error!(
+                expected = ?interaction.request,
+                actual = ?actual_request,
+                "Request does not match the recorded interaction"
+            );
+            return Err(report!(ApiClientError::UnexpectedRequest)).attach_printable(format!(
+                "Expected {} {} as interaction {position}, found {} {}",
+                interaction.request.method,
+                interaction.request.url,
+                actual_request.method,
+                actual_request.url
+            ));
+        }
+
+        cassette_state.position = position.saturating_add(1);
+        build_response(&interaction.response)
+    }
+
+    fn save_cassette(&self, cassette: &Cassette) -> CustomResult<(), ApiClientError> {
+        if let Some(parent) = self.cassette_path.parent() {
+            std::fs::create_dir_all(parent)
+                .change_context(ApiClientError::CassetteOperationFailed)
+                .attach_printable("Failed to create cassette directory")?;
+        }
+        let contents = serde_json::to_string_pretty(cassette)
+            .change_context(ApiClientError::CassetteOperationFailed)
+            .attach_printable("Failed to serialize cassette")?;
+        std::fs::write(&self.cassette_path, contents)
+            .change_context(ApiClientError::CassetteOperationFailed)
+            .attach_printable_lazy(|| {
+                format!(
+                    "Failed to write cassette at {}",
+                    self.cassette_path.display()
+                )
+            })
+    }
+}
+
+impl RecordedRequest {
+    /// The method and URL must be identical, while only the structure of the body is compared,
+    /// since request bodies contain identifiers generated afresh for every test run
+    fn matches(&self, other: &Self) -> bool {
+        self.method == other.method
+            && self.url == other.url
+            && match (&self.body, &other.body) {
+                (Some(recorded), Some(actual)) => has_same_shape(recorded, actual),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+}
+
+fn has_same_shape(recorded: &serde_json::Value, actual: &serde_json::Value) -> bool {
+    use serde_json::Value;
+
+    match (recorded, actual) {
+        (Value::Object(recorded), Value::Object(actual)) => {
+            recorded.len() == actual.len()
+                && recorded.iter().all(|(key, recorded_value)| {
+                    actual
+                        .get(key)
+                        .is_some_and(|actual_value| has_same_shape(recorded_value, actual_value))
+                })
+        }
+        (Value::Array(recorded), Value::Array(actual)) => {
+            recorded.len() == actual.len()
+                && recorded
+                    .iter()
+                    .zip(actual.iter())
+                    .all(|(recorded_value, actual_value)| {
+                        has_same_shape(recorded_value, actual_value)
+                    })
+        }
+        (recorded, actual) => std::mem::discriminant(recorded) == std::mem::discriminant(actual),
+    }
+}
+
+fn mask_value(value: String) -> String {
+    format!("{:?}", masking::Secret::<String>::new(value))
+}
+
+fn sanitize_request(request: &Request) -> RecordedRequest {
+    let headers = request
+        .headers
+        .iter()
+        .map(|(name, value)| {
+            let value = if value.is_masked() {
+                mask_value(value.clone().into_inner())
+            } else {
+                value.clone().into_inner()
+            };
+            (name.to_lowercase(), value)
+        })
+        .collect();
+
+    let body = request.body.as_ref().map(|body| match body {
+        RequestContent::Json(payload)
+        | RequestContent::FormUrlEncoded(payload)
+        | RequestContent::Xml(payload) => payload
+            .masked_serialize()
+            .unwrap_or_else(|error| serde_json::Value::String(error.to_string())),
+        RequestContent::FormData(_) => serde_json::Value::String("<multipart form>".to_string()),
+        RequestContent::RawBytes(bytes) => {
+            serde_json::Value::String(format!("<{} raw bytes>", bytes.len()))
+        }
+    });
+
+    RecordedRequest {
+        method: request.method,
+        url: request.url.clone(),
+        headers,
+        body,
+    }
+}
+
+fn sanitize_response_body(body: &str) -> String {
+    fn mask_sensitive_fields(value: &mut serde_json::Value) {
+        match value {
+            serde_json::Value::Object(map) => map.iter_mut().for_each(|(key, value)| {
+                if SENSITIVE_RESPONSE_FIELDS.contains(&key.to_lowercase().as_str()) {
+                    if let serde_json::Value::String(secret) = value {
+                        *secret = mask_value(std::mem::take(secret));
+                    }
+                } else {
+                    mask_sensitive_fields(value);
+                }
+            }),
+            serde_json::Value::Array(values) => values.iter_mut().for_each(mask_sensitive_fields),
+            _ => {}
+        }
+    }
+
+    // Bodies which are not JSON (XML, HTML) are saved as received
+    serde_json::from_str::<serde_json::Value>(body)
+        .map(|mut value| {
+            mask_sensitive_fields(&mut value);
+            value.to_string()
+        })
+        .unwrap_or_else(|_| body.to_string())
+}
+
+fn build_response(
+    recorded_response: &RecordedResponse,
+) -> CustomResult<reqwest::Response, ApiClientError> {
+    let response = recorded_response.headers.iter().fold(
+        http::Response::builder().status(recorded_response.status_code),
+        |builder, (name, value)| builder.header(name, value),
+    );
+    response
+        .body(recorded_response.body.clone())
+        .map(reqwest::Response::from)
+        .change_context(ApiClientError::UnexpectedState)
+        .attach_printable("Failed to construct response from the recorded interaction")
+}
+
+#[async_trait::async_trait]
+impl ApiClient for RecordReplayApiClient {
+    fn request(
+        &self,
+        _method: HttpMethod,
+        _url: String,
+    ) -> CustomResult<Box<dyn RequestBuilder>, ApiClientError> {
+        Err(report!(ApiClientError::UnexpectedState))
+            .attach_printable("Request builders are not supported while recording or replaying")
+    }
+
+    fn request_with_certificate(
+        &self,
+        _method: HttpMethod,
+        _url: String,
+        _certificate: Option<masking::Secret<String>>,
+        _certificate_key: Option<masking::Secret<String>>,
+    ) -> CustomResult<Box<dyn RequestBuilder>, ApiClientError> {
+        Err(report!(ApiClientError::UnexpectedState))
+            .attach_printable("Request builders are not supported while recording or replaying")
+    }
+
+    async fn send_request(
+        &self,
+        state: &SessionState,
+        request: Request,
+        option_timeout_secs: Option<u64>,
+        _forward_to_kafka: bool,
+    ) -> CustomResult<reqwest::Response, ApiClientError> {
+        match self.mode {
+            RecordReplayMode::Record => self.record(state, request, option_timeout_secs).await,
+            RecordReplayMode::Replay => self.replay(&request),
+        }
+    }
+
+    fn add_request_id(&mut self, request_id: RequestId) {
+        self.request_id
+            .replace(request_id.as_hyphenated().to_string());
+    }
+
+    fn get_request_id(&self) -> Option<String> {
+        self.request_id.clone()
+    }
+
+    fn add_merchant_id(&mut self, _merchant_id: Option<String>) {}
+
+    fn add_flow_name(&mut self, _flow_name: String) {}
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_body_shape_ignores_values() {
+        let recorded = serde_json::json!({"amount": 100, "reference": "a", "card": {"cvc": "*"}});
+        let actual = serde_json::json!({"amount": 250, "reference": "b", "card": {"cvc": "**"}});
+        assert!(has_same_shape(&recorded, &actual));
+    }
+
+    #[test]
+    fn test_body_shape_detects_changed_fields() {
+        let recorded = serde_json::json!({"amount": 100, "reference": "a"});
+        let missing_field = serde_json::json!({"amount": 100});
+        let changed_type = serde_json::json!({"amount": "100", "reference": "a"});
+        assert!(!has_same_shape(&recorded, &missing_field));
+        assert!(!has_same_shape(&recorded, &changed_type));
+    }
+
+    #[test]
+    fn test_response_body_secrets_are_masked() {
+        let body = r#"{"access_token":"tok_123","expires_in":3600,"data":[{"secret":"s"}]}"#;
+        let sanitized: serde_json::Value =
+            serde_json::from_str(&sanitize_response_body(body)).unwrap();
+        assert_ne!(sanitized["access_token"], "tok_123");
+        assert_ne!(sanitized["data"][0]["secret"], "s");
+        assert_eq!(sanitized["expires_in"], 3600);
+    }
+}
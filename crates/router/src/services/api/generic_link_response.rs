@@ -2,34 +2,61 @@ use common_utils::errors::CustomResult;
 use error_stack::ResultExt;
 use tera::{Context, Tera};
 
-use super::{GenericExpiredLinkData, GenericLinkFormData, GenericLinkStatusData, GenericLinks};
-use crate::core::errors;
+use super::{
+    GenericExpiredLinkData, GenericLinkFormData, GenericLinkStatusData, GenericLinks,
+    GenericLinksData, LinkRenderConfig,
+};
+use crate::core::{errors, link_hosting};
 
 pub fn build_generic_link_html(
     boxed_generic_link_data: GenericLinks,
 ) -> CustomResult<String, errors::ApiErrorResponse> {
-    match boxed_generic_link_data {
-        GenericLinks::ExpiredLink(link_data) => build_generic_expired_link_html(&link_data),
+    let render_config = &boxed_generic_link_data.render_config;
+    match boxed_generic_link_data.data {
+        GenericLinksData::ExpiredLink(link_data) => {
+            build_generic_expired_link_html(&link_data, render_config)
+        }
 
-        GenericLinks::PaymentMethodCollect(pm_collect_data) => {
-            build_pm_collect_link_html(&pm_collect_data)
+        GenericLinksData::PaymentMethodCollect(pm_collect_data) => {
+            build_pm_collect_link_html(&pm_collect_data, render_config)
+        }
+        GenericLinksData::PaymentMethodCollectStatus(pm_collect_data) => {
+            build_pm_collect_link_status_html(&pm_collect_data, render_config)
         }
-        GenericLinks::PaymentMethodCollectStatus(pm_collect_data) => {
-            build_pm_collect_link_status_html(&pm_collect_data)
+        GenericLinksData::PayoutLink(payout_link_data) => {
+            build_payout_link_html(&payout_link_data, render_config)
         }
-        GenericLinks::PayoutLink(payout_link_data) => build_payout_link_html(&payout_link_data),
 
-        GenericLinks::PayoutLinkStatus(pm_collect_data) => {
-            build_payout_link_status_html(&pm_collect_data)
+        GenericLinksData::PayoutLinkStatus(pm_collect_data) => {
+            build_payout_link_status_html(&pm_collect_data, render_config)
         }
     }
 }
 
+/// Inserts the locale of the link and its strings in the context of the link's templates
+fn insert_locale_in_context(
+    context: &mut Context,
+    render_config: &LinkRenderConfig,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    context.insert("locale", &render_config.locale);
+    context.insert(
+        "strings",
+        &link_hosting::get_locale_strings(&render_config.locale),
+    );
+    context.insert(
+        "locale_js_script",
+        &link_hosting::get_locale_js_script(&render_config.locale)?,
+    );
+    Ok(())
+}
+
 pub fn build_generic_expired_link_html(
     link_data: &GenericExpiredLinkData,
+    render_config: &LinkRenderConfig,
 ) -> CustomResult<String, errors::ApiErrorResponse> {
     let mut tera = Tera::default();
     let mut context = Context::new();
+    insert_locale_in_context(&mut context, render_config)?;
 
     // Build HTML
     let html_template = include_str!("../../core/generic_link/expired_link/index.html").to_string();
@@ -45,9 +72,11 @@ pub fn build_generic_expired_link_html(
 
 pub fn build_payout_link_html(
     link_data: &GenericLinkFormData,
+    render_config: &LinkRenderConfig,
 ) -> CustomResult<String, errors::ApiErrorResponse> {
     let mut tera = Tera::default();
     let mut context = Context::new();
+    insert_locale_in_context(&mut context, render_config)?;
 
     // Insert dynamic context in CSS
     let css_dynamic_context = "{{ color_scheme }}";
@@ -59,12 +88,12 @@ pub fn build_payout_link_html(
 
     let css_style_tag = tera
         .render("payout_link_styles", &context)
-        .map(|css| format!("<style>{}</style>", css))
+        .map(|css| format!("<style>{}\n{}</style>", css, render_config.get_custom_css()))
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to render payout link's CSS template")?;
 
     // Insert dynamic context in JS
-    let js_dynamic_context = "{{ payout_link_context }}";
+    let js_dynamic_context = "{{ locale_js_script }}\n{{ payout_link_context }}";
     let js_template =
         include_str!("../../core/generic_link/payout_link/initiate/script.js").to_string();
     let final_js = format!("{}\n{}", js_dynamic_context, js_template);
@@ -78,8 +107,12 @@ pub fn build_payout_link_html(
         .attach_printable("Failed to render payout link's JS template")?;
 
     // Build HTML
-    let html_template =
-        include_str!("../../core/generic_link/payout_link/initiate/index.html").to_string();
+    let html_template = render_config
+        .get_custom_html()
+        .unwrap_or(include_str!(
+            "../../core/generic_link/payout_link/initiate/index.html"
+        ))
+        .to_string();
     let _ = tera.add_raw_template("payout_link", &html_template);
     context.insert("css_style_tag", &css_style_tag);
     context.insert("js_script_tag", &js_script_tag);
@@ -98,9 +131,11 @@ pub fn build_payout_link_html(
 
 pub fn build_pm_collect_link_html(
     link_data: &GenericLinkFormData,
+    render_config: &LinkRenderConfig,
 ) -> CustomResult<String, errors::ApiErrorResponse> {
     let mut tera = Tera::default();
     let mut context = Context::new();
+    insert_locale_in_context(&mut context, render_config)?;
 
     // Insert dynamic context in CSS
     let css_dynamic_context = "{{ color_scheme }}";
@@ -113,12 +148,12 @@ pub fn build_pm_collect_link_html(
 
     let css_style_tag = tera
         .render("pm_collect_link_styles", &context)
-        .map(|css| format!("<style>{}</style>", css))
+        .map(|css| format!("<style>{}\n{}</style>", css, render_config.get_custom_css()))
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to render payment method collect link's CSS template")?;
 
     // Insert dynamic context in JS
-    let js_dynamic_context = "{{ collect_link_context }}";
+    let js_dynamic_context = "{{ locale_js_script }}\n{{ collect_link_context }}";
     let js_template =
         include_str!("../../core/generic_link/payment_method_collect/initiate/script.js")
             .to_string();
@@ -133,9 +168,12 @@ pub fn build_pm_collect_link_html(
         .attach_printable("Failed to render payment method collect link's JS template")?;
 
     // Build HTML
-    let html_template =
-        include_str!("../../core/generic_link/payment_method_collect/initiate/index.html")
-            .to_string();
+    let html_template = render_config
+        .get_custom_html()
+        .unwrap_or(include_str!(
+            "../../core/generic_link/payment_method_collect/initiate/index.html"
+        ))
+        .to_string();
     let _ = tera.add_raw_template("payment_method_collect_link", &html_template);
     context.insert("css_style_tag", &css_style_tag);
     context.insert("js_script_tag", &js_script_tag);
@@ -154,9 +192,11 @@ pub fn build_pm_collect_link_html(
 
 pub fn build_payout_link_status_html(
     link_data: &GenericLinkStatusData,
+    render_config: &LinkRenderConfig,
 ) -> CustomResult<String, errors::ApiErrorResponse> {
     let mut tera = Tera::default();
     let mut context = Context::new();
+    insert_locale_in_context(&mut context, render_config)?;
 
     // Insert dynamic context in CSS
     let css_dynamic_context = "{{ color_scheme }}";
@@ -168,12 +208,12 @@ pub fn build_payout_link_status_html(
 
     let css_style_tag = tera
         .render("payout_link_status_styles", &context)
-        .map(|css| format!("<style>{}</style>", css))
+        .map(|css| format!("<style>{}\n{}</style>", css, render_config.get_custom_css()))
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to render payout link status CSS template")?;
 
     // Insert dynamic context in JS
-    let js_dynamic_context = "{{ collect_link_status_context }}";
+    let js_dynamic_context = "{{ locale_js_script }}\n{{ collect_link_status_context }}";
     let js_template =
         include_str!("../../core/generic_link/payout_link/status/script.js").to_string();
     let final_js = format!("{}\n{}", js_dynamic_context, js_template);
@@ -200,9 +240,11 @@ pub fn build_payout_link_status_html(
 
 pub fn build_pm_collect_link_status_html(
     link_data: &GenericLinkStatusData,
+    render_config: &LinkRenderConfig,
 ) -> CustomResult<String, errors::ApiErrorResponse> {
     let mut tera = Tera::default();
     let mut context = Context::new();
+    insert_locale_in_context(&mut context, render_config)?;
 
     // Insert dynamic context in CSS
     let css_dynamic_context = "{{ color_scheme }}";
@@ -215,12 +257,12 @@ pub fn build_pm_collect_link_status_html(
 
     let css_style_tag = tera
         .render("pm_collect_link_status_styles", &context)
-        .map(|css| format!("<style>{}</style>", css))
+        .map(|css| format!("<style>{}\n{}</style>", css, render_config.get_custom_css()))
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to render payment method collect link status CSS template")?;
 
     // Insert dynamic context in JS
-    let js_dynamic_context = "{{ collect_link_status_context }}";
+    let js_dynamic_context = "{{ locale_js_script }}\n{{ collect_link_status_context }}";
     let js_template =
         include_str!("../../core/generic_link/payment_method_collect/status/script.js").to_string();
     let final_js = format!("{}\n{}", js_dynamic_context, js_template);
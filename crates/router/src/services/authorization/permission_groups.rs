@@ -16,6 +16,7 @@ pub fn get_permissions_vec(permission_group: &PermissionGroup) -> &[Permission]
         PermissionGroup::MerchantDetailsView => &MERCHANT_DETAILS_VIEW,
         PermissionGroup::MerchantDetailsManage => &MERCHANT_DETAILS_MANAGE,
         PermissionGroup::OrganizationManage => &ORGANIZATION_MANAGE,
+        PermissionGroup::AuditLogView => &AUDIT_LOG_VIEW,
     }
 }
 
@@ -88,3 +89,6 @@ pub static ORGANIZATION_MANAGE: [Permission; 2] = [
     Permission::MerchantAccountCreate,
     Permission::MerchantAccountRead,
 ];
+
+pub static AUDIT_LOG_VIEW: [Permission; 2] =
+    [Permission::AuditLogRead, Permission::MerchantAccountRead];
@@ -0,0 +1,1103 @@
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    io::Write,
+};
+
+use base64::Engine;
+use error_stack::{report, ResultExt};
+use masking::{ExposeInterface, PeekInterface, Secret};
+use openssl::{hash::MessageDigest, sign::Verifier, x509::X509};
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+use crate::{
+    consts,
+    core::errors::{UserErrors, UserResult},
+};
+
+const SAML_PROTOCOL_NS: &str = "urn:oasis:names:tc:SAML:2.0:protocol";
+const SAML_ASSERTION_NS: &str = "urn:oasis:names:tc:SAML:2.0:assertion";
+const SAML_METADATA_NS: &str = "urn:oasis:names:tc:SAML:2.0:metadata";
+const XML_DSIG_NS: &str = "http://www.w3.org/2000/09/xmldsig#";
+const XML_NS: &str = "http://www.w3.org/XML/1998/namespace";
+
+const HTTP_REDIRECT_BINDING: &str = "urn:oasis:names:tc:SAML:2.0:bindings:HTTP-Redirect";
+const HTTP_POST_BINDING: &str = "urn:oasis:names:tc:SAML:2.0:bindings:HTTP-POST";
+const EMAIL_NAME_ID_FORMAT: &str = "urn:oasis:names:tc:SAML:1.1:nameid-format:emailAddress";
+const STATUS_SUCCESS: &str = "urn:oasis:names:tc:SAML:2.0:status:Success";
+const BEARER_CONFIRMATION_METHOD: &str = "urn:oasis:names:tc:SAML:2.0:cm:bearer";
+
+const EXCLUSIVE_C14N_ALGORITHM: &str = "http://www.w3.org/2001/10/xml-exc-c14n#";
+const ENVELOPED_SIGNATURE_TRANSFORM: &str = "http://www.w3.org/2000/09/xmldsig#enveloped-signature";
+
+/// Allowed difference between the clocks of the identity provider and the application, used while
+/// validating the time bound conditions of an assertion
+const ALLOWED_CLOCK_SKEW: time::Duration = time::Duration::minutes(3);
+
+#[derive(Debug, Clone)]
+pub struct IdpMetadata {
+    pub entity_id: String,
+    pub sso_url: String,
+    certificates: Vec<Vec<u8>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SamlAssertion {
+    pub id: String,
+    pub name_id: Secret<String>,
+    pub attributes: HashMap<String, Vec<String>>,
+}
+
+impl SamlAssertion {
+    pub fn get_attribute_values(&self, name: &str) -> &[String] {
+        self.attributes
+            .get(name)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    pub fn get_first_attribute_value(&self, name: &str) -> Option<&str> {
+        self.get_attribute_values(name).first().map(String::as_str)
+    }
+}
+
+/// Parses the metadata of the identity provider, only the HTTP-Redirect binding is supported for
+/// sending authentication requests.
+pub fn parse_idp_metadata(metadata: &Secret<String>) -> UserResult<IdpMetadata> {
+    let document = roxmltree::Document::parse(metadata.peek())
+        .change_context(UserErrors::AuthConfigParsingError)
+        .attach_printable("Failed to parse IdP metadata")?;
+
+    let entity_descriptor = document
+        .root_element()
+        .descendants()
+        .find(|node| node.has_tag_name((SAML_METADATA_NS, "EntityDescriptor")))
+        .ok_or(UserErrors::AuthConfigParsingError)
+        .attach_printable("EntityDescriptor not found in IdP metadata")?;
+
+    let entity_id = entity_descriptor
+        .attribute("entityID")
+        .ok_or(UserErrors::AuthConfigParsingError)
+        .attach_printable("entityID not found in IdP metadata")?
+        .to_string();
+
+    let idp_descriptor = find_child(entity_descriptor, SAML_METADATA_NS, "IDPSSODescriptor")
+        .ok_or(UserErrors::AuthConfigParsingError)
+        .attach_printable("IDPSSODescriptor not found in IdP metadata")?;
+
+    let sso_url = idp_descriptor
+        .children()
+        .filter(|node| node.has_tag_name((SAML_METADATA_NS, "SingleSignOnService")))
+        .find(|node| node.attribute("Binding") == Some(HTTP_REDIRECT_BINDING))
+        .and_then(|node| node.attribute("Location"))
+        .ok_or(UserErrors::AuthConfigParsingError)
+        .attach_printable("SingleSignOnService with HTTP-Redirect binding not found")?
+        .to_string();
+
+    let certificates = idp_descriptor
+        .children()
+        .filter(|node| node.has_tag_name((SAML_METADATA_NS, "KeyDescriptor")))
+        // Key descriptors without `use` are applicable for both signing and encryption
+        .filter(|node| {
+            node.attribute("use")
+                .map_or(true, |key_use| key_use == "signing")
+        })
+        .flat_map(|node| node.descendants())
+        .filter(|node| node.has_tag_name((XML_DSIG_NS, "X509Certificate")))
+        .map(|node| decode_base64(node.text().unwrap_or_default()))
+        .collect::<UserResult<Vec<_>>>()
+        .change_context(UserErrors::AuthConfigParsingError)
+        .attach_printable("Failed to decode IdP signing certificate")?;
+
+    for certificate in &certificates {
+        X509::from_der(certificate)
+            .change_context(UserErrors::AuthConfigParsingError)
+            .attach_printable("Invalid IdP signing certificate")?;
+    }
+
+    if certificates.is_empty() {
+        return Err(report!(UserErrors::AuthConfigParsingError))
+            .attach_printable("Signing certificate not found in IdP metadata");
+    }
+
+    Ok(IdpMetadata {
+        entity_id,
+        sso_url,
+        certificates,
+    })
+}
+
+pub fn generate_sp_metadata(entity_id: &str, acs_url: &str) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?><md:EntityDescriptor xmlns:md="{SAML_METADATA_NS}" entityID="{}"><md:SPSSODescriptor AuthnRequestsSigned="false" WantAssertionsSigned="true" protocolSupportEnumeration="{SAML_PROTOCOL_NS}"><md:NameIDFormat>{EMAIL_NAME_ID_FORMAT}</md:NameIDFormat><md:AssertionConsumerService Binding="{HTTP_POST_BINDING}" Location="{}" index="0" isDefault="true"/></md:SPSSODescriptor></md:EntityDescriptor>"#,
+        quick_xml::escape::escape(entity_id),
+        quick_xml::escape::escape(acs_url),
+    )
+}
+
+/// Constructs the URL of the identity provider with an `AuthnRequest` encoded as per the
+/// HTTP-Redirect binding. The request ID is also used as the relay state, so that the response
+/// can be correlated with the request.
+pub fn get_authorization_url(
+    idp_metadata: &IdpMetadata,
+    sp_entity_id: &str,
+    acs_url: &str,
+    request_id: &Secret<String>,
+) -> UserResult<url::Url> {
+    let issue_instant = OffsetDateTime::now_utc()
+        .format(&Rfc3339)
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to format SAML issue instant")?;
+
+    let authn_request = format!(
+        r#"<samlp:AuthnRequest xmlns:samlp="{SAML_PROTOCOL_NS}" xmlns:saml="{SAML_ASSERTION_NS}" ID="{}" Version="2.0" IssueInstant="{issue_instant}" Destination="{}" AssertionConsumerServiceURL="{}" ProtocolBinding="{HTTP_POST_BINDING}"><saml:Issuer>{}</saml:Issuer><samlp:NameIDPolicy Format="{EMAIL_NAME_ID_FORMAT}" AllowCreate="true"/></samlp:AuthnRequest>"#,
+        quick_xml::escape::escape(request_id.peek()),
+        quick_xml::escape::escape(&idp_metadata.sso_url),
+        quick_xml::escape::escape(acs_url),
+        quick_xml::escape::escape(sp_entity_id),
+    );
+
+    let mut encoder =
+        flate2::write::DeflateEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder
+        .write_all(authn_request.as_bytes())
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to deflate SAML request")?;
+    let deflated_request = encoder
+        .finish()
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to deflate SAML request")?;
+
+    let mut url = url::Url::parse(&idp_metadata.sso_url)
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Invalid IdP SSO URL")?;
+    url.query_pairs_mut()
+        .append_pair(
+            "SAMLRequest",
+            &consts::BASE64_ENGINE.encode(deflated_request),
+        )
+        .append_pair("RelayState", request_id.peek());
+
+    Ok(url)
+}
+
+/// Validates the SAML response received on the assertion consumer service and returns the
+/// assertion in it.
+///
+/// Either the response or the assertion has to be signed by one of the certificates present in
+/// the IdP metadata. To guard against signature wrapping attacks, only the assertion which is a
+/// direct child of the response is considered, the signature has to reference the element it is
+/// enveloped in, and responses containing duplicate IDs are rejected.
+pub fn validate_saml_response(
+    saml_response: Secret<String>,
+    idp_metadata: &IdpMetadata,
+    sp_entity_id: &str,
+    acs_url: &str,
+    request_id: &str,
+) -> UserResult<SamlAssertion> {
+    let decoded_response = decode_base64(&saml_response.expose())?;
+    let response_xml = String::from_utf8(decoded_response)
+        .change_context(UserErrors::SSOFailed)
+        .attach_printable("SAML response is not valid UTF-8")?;
+
+    // DTDs are not allowed by default, which prevents entity expansion attacks
+    let document = roxmltree::Document::parse(&response_xml)
+        .change_context(UserErrors::SSOFailed)
+        .attach_printable("Failed to parse SAML response")?;
+
+    let response = document.root_element();
+    if !response.has_tag_name((SAML_PROTOCOL_NS, "Response")) {
+        return Err(report!(UserErrors::SSOFailed))
+            .attach_printable("Root element of SAML response is not Response");
+    }
+
+    validate_unique_ids(&document)?;
+
+    let status_code = find_child(response, SAML_PROTOCOL_NS, "Status")
+        .and_then(|status| find_child(status, SAML_PROTOCOL_NS, "StatusCode"))
+        .and_then(|status_code| status_code.attribute("Value"));
+    if status_code != Some(STATUS_SUCCESS) {
+        return Err(report!(UserErrors::SSOFailed)).attach_printable(format!(
+            "SAML response status is not success: {status_code:?}"
+        ));
+    }
+
+    if response.attribute("InResponseTo") != Some(request_id) {
+        return Err(report!(UserErrors::SSOFailed))
+            .attach_printable("InResponseTo of SAML response does not match the request");
+    }
+
+    if response
+        .attribute("Destination")
+        .is_some_and(|destination| destination != acs_url)
+    {
+        return Err(report!(UserErrors::SSOFailed))
+            .attach_printable("Destination of SAML response does not match the ACS URL");
+    }
+
+    let mut assertions = response
+        .children()
+        .filter(|node| node.has_tag_name((SAML_ASSERTION_NS, "Assertion")));
+    let assertion = assertions
+        .next()
+        .ok_or(UserErrors::SSOFailed)
+        .attach_printable("Assertion not found in SAML response")?;
+    if assertions.next().is_some() {
+        return Err(report!(UserErrors::SSOFailed))
+            .attach_printable("Multiple assertions found in SAML response");
+    }
+
+    let is_response_signed = verify_enveloped_signature(&document, response, idp_metadata)?;
+    let is_assertion_signed = verify_enveloped_signature(&document, assertion, idp_metadata)?;
+    if !is_response_signed && !is_assertion_signed {
+        return Err(report!(UserErrors::SSOFailed))
+            .attach_printable("Neither the SAML response nor the assertion is signed");
+    }
+
+    validate_assertion(assertion, idp_metadata, sp_entity_id, acs_url, request_id)
+}
+
+fn validate_assertion(
+    assertion: roxmltree::Node<'_, '_>,
+    idp_metadata: &IdpMetadata,
+    sp_entity_id: &str,
+    acs_url: &str,
+    request_id: &str,
+) -> UserResult<SamlAssertion> {
+    let now = OffsetDateTime::now_utc();
+
+    let issuer = find_child(assertion, SAML_ASSERTION_NS, "Issuer").and_then(|node| node.text());
+    if issuer.map(str::trim) != Some(idp_metadata.entity_id.as_str()) {
+        return Err(report!(UserErrors::SSOFailed))
+            .attach_printable("Issuer of SAML assertion does not match the IdP entity ID");
+    }
+
+    let conditions = find_child(assertion, SAML_ASSERTION_NS, "Conditions")
+        .ok_or(UserErrors::SSOFailed)
+        .attach_printable("Conditions not found in SAML assertion")?;
+    validate_time_bounds(
+        now,
+        conditions.attribute("NotBefore"),
+        conditions.attribute("NotOnOrAfter"),
+    )?;
+
+    let is_audience_valid = conditions
+        .children()
+        .filter(|node| node.has_tag_name((SAML_ASSERTION_NS, "AudienceRestriction")))
+        .flat_map(|node| node.children())
+        .filter(|node| node.has_tag_name((SAML_ASSERTION_NS, "Audience")))
+        .any(|node| node.text().map(str::trim) == Some(sp_entity_id));
+    if !is_audience_valid {
+        return Err(report!(UserErrors::SSOFailed))
+            .attach_printable("Audience of SAML assertion does not match the SP entity ID");
+    }
+
+    let subject = find_child(assertion, SAML_ASSERTION_NS, "Subject")
+        .ok_or(UserErrors::SSOFailed)
+        .attach_printable("Subject not found in SAML assertion")?;
+
+    let subject_confirmation_data = subject
+        .children()
+        .filter(|node| node.has_tag_name((SAML_ASSERTION_NS, "SubjectConfirmation")))
+        .filter(|node| node.attribute("Method") == Some(BEARER_CONFIRMATION_METHOD))
+        .find_map(|node| find_child(node, SAML_ASSERTION_NS, "SubjectConfirmationData"))
+        .ok_or(UserErrors::SSOFailed)
+        .attach_printable("Bearer SubjectConfirmationData not found in SAML assertion")?;
+
+    if subject_confirmation_data.attribute("Recipient") != Some(acs_url) {
+        return Err(report!(UserErrors::SSOFailed))
+            .attach_printable("Recipient of SAML assertion does not match the ACS URL");
+    }
+    if subject_confirmation_data.attribute("InResponseTo") != Some(request_id) {
+        return Err(report!(UserErrors::SSOFailed))
+            .attach_printable("InResponseTo of SAML assertion does not match the request");
+    }
+    let not_on_or_after = subject_confirmation_data
+        .attribute("NotOnOrAfter")
+        .ok_or(UserErrors::SSOFailed)
+        .attach_printable("NotOnOrAfter not found in SubjectConfirmationData")?;
+    validate_time_bounds(now, None, Some(not_on_or_after))?;
+
+    let name_id = find_child(subject, SAML_ASSERTION_NS, "NameID")
+        .and_then(|node| node.text())
+        .map(|name_id| Secret::new(name_id.trim().to_string()))
+        .ok_or(UserErrors::SSOFailed)
+        .attach_printable("NameID not found in SAML assertion")?;
+
+    let mut attributes: HashMap<String, Vec<String>> = HashMap::new();
+    for attribute in assertion
+        .children()
+        .filter(|node| node.has_tag_name((SAML_ASSERTION_NS, "AttributeStatement")))
+        .flat_map(|node| node.children())
+        .filter(|node| node.has_tag_name((SAML_ASSERTION_NS, "Attribute")))
+    {
+        let Some(name) = attribute.attribute("Name") else {
+            continue;
+        };
+        attributes.entry(name.to_string()).or_default().extend(
+            attribute
+                .children()
+                .filter(|node| node.has_tag_name((SAML_ASSERTION_NS, "AttributeValue")))
+                .filter_map(|node| node.text())
+                .map(|value| value.trim().to_string()),
+        );
+    }
+
+    let id = assertion
+        .attribute("ID")
+        .ok_or(UserErrors::SSOFailed)
+        .attach_printable("ID not found in SAML assertion")?
+        .to_string();
+
+    Ok(SamlAssertion {
+        id,
+        name_id,
+        attributes,
+    })
+}
+
+fn validate_time_bounds(
+    now: OffsetDateTime,
+    not_before: Option<&str>,
+    not_on_or_after: Option<&str>,
+) -> UserResult<()> {
+    if let Some(not_before) = not_before.map(parse_date_time).transpose()? {
+        if now + ALLOWED_CLOCK_SKEW < not_before {
+            return Err(report!(UserErrors::SSOFailed))
+                .attach_printable("SAML assertion is not yet valid");
+        }
+    }
+
+    if let Some(not_on_or_after) = not_on_or_after.map(parse_date_time).transpose()? {
+        if now - ALLOWED_CLOCK_SKEW >= not_on_or_after {
+            return Err(report!(UserErrors::SSOFailed)).attach_printable("SAML assertion expired");
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_date_time(date_time: &str) -> UserResult<OffsetDateTime> {
+    OffsetDateTime::parse(date_time, &Rfc3339)
+        .change_context(UserErrors::SSOFailed)
+        .attach_printable("Failed to parse date time in SAML assertion")
+}
+
+fn validate_unique_ids(document: &roxmltree::Document<'_>) -> UserResult<()> {
+    let mut ids = HashSet::new();
+    for id in document
+        .descendants()
+        .filter(|node| node.is_element())
+        .filter_map(|node| node.attribute("ID"))
+    {
+        if !ids.insert(id) {
+            return Err(report!(UserErrors::SSOFailed))
+                .attach_printable("Duplicate IDs found in SAML response");
+        }
+    }
+    Ok(())
+}
+
+/// Verifies the signature which is a direct child of the given element, returns `false` if the
+/// element is not signed.
+fn verify_enveloped_signature(
+    document: &roxmltree::Document<'_>,
+    element: roxmltree::Node<'_, '_>,
+    idp_metadata: &IdpMetadata,
+) -> UserResult<bool> {
+    let Some(signature) = find_child(element, XML_DSIG_NS, "Signature") else {
+        return Ok(false);
+    };
+
+    let element_id = element
+        .attribute("ID")
+        .ok_or(UserErrors::SSOFailed)
+        .attach_printable("Signed element does not have an ID")?;
+
+    let signed_info = find_child(signature, XML_DSIG_NS, "SignedInfo")
+        .ok_or(UserErrors::SSOFailed)
+        .attach_printable("SignedInfo not found in signature")?;
+
+    let canonicalization_method = find_child(signed_info, XML_DSIG_NS, "CanonicalizationMethod")
+        .ok_or(UserErrors::SSOFailed)
+        .attach_printable("CanonicalizationMethod not found in signature")?;
+    if canonicalization_method.attribute("Algorithm") != Some(EXCLUSIVE_C14N_ALGORITHM) {
+        return Err(report!(UserErrors::SSOFailed))
+            .attach_printable("Unsupported canonicalization method in signature");
+    }
+
+    let signature_digest = find_child(signed_info, XML_DSIG_NS, "SignatureMethod")
+        .and_then(|node| node.attribute("Algorithm"))
+        .and_then(|algorithm| match algorithm {
+            "http://www.w3.org/2001/04/xmldsig-more#rsa-sha256" => Some(MessageDigest::sha256()),
+            "http://www.w3.org/2001/04/xmldsig-more#rsa-sha512" => Some(MessageDigest::sha512()),
+            "http://www.w3.org/2000/09/xmldsig#rsa-sha1" => Some(MessageDigest::sha1()),
+            _ => None,
+        })
+        .ok_or(UserErrors::SSOFailed)
+        .attach_printable("Unsupported signature method in signature")?;
+
+    // Exactly one reference, to the element enveloping the signature, is expected
+    let mut references = signed_info
+        .children()
+        .filter(|node| node.has_tag_name((XML_DSIG_NS, "Reference")));
+    let reference = references
+        .next()
+        .ok_or(UserErrors::SSOFailed)
+        .attach_printable("Reference not found in signature")?;
+    if references.next().is_some()
+        || reference.attribute("URI") != Some(format!("#{element_id}").as_str())
+    {
+        return Err(report!(UserErrors::SSOFailed))
+            .attach_printable("Signature does not reference the signed element");
+    }
+
+    let transforms = find_child(reference, XML_DSIG_NS, "Transforms")
+        .map(|node| {
+            node.children()
+                .filter(|node| node.has_tag_name((XML_DSIG_NS, "Transform")))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    let mut inclusive_prefixes = Vec::new();
+    for transform in transforms {
+        match transform.attribute("Algorithm") {
+            Some(ENVELOPED_SIGNATURE_TRANSFORM) => {}
+            Some(EXCLUSIVE_C14N_ALGORITHM) => {
+                inclusive_prefixes.extend(get_inclusive_prefixes(transform));
+            }
+            _ => {
+                return Err(report!(UserErrors::SSOFailed))
+                    .attach_printable("Unsupported transform in signature");
+            }
+        }
+    }
+
+    let reference_digest = find_child(reference, XML_DSIG_NS, "DigestMethod")
+        .and_then(|node| node.attribute("Algorithm"))
+        .and_then(|algorithm| match algorithm {
+            "http://www.w3.org/2001/04/xmlenc#sha256" => Some(MessageDigest::sha256()),
+            "http://www.w3.org/2001/04/xmlenc#sha512" => Some(MessageDigest::sha512()),
+            "http://www.w3.org/2000/09/xmldsig#sha1" => Some(MessageDigest::sha1()),
+            _ => None,
+        })
+        .ok_or(UserErrors::SSOFailed)
+        .attach_printable("Unsupported digest method in signature")?;
+
+    let expected_digest = find_child(reference, XML_DSIG_NS, "DigestValue")
+        .and_then(|node| node.text())
+        .map(decode_base64)
+        .transpose()?
+        .ok_or(UserErrors::SSOFailed)
+        .attach_printable("DigestValue not found in signature")?;
+
+    let canonical_element = canonicalize(document, element, &inclusive_prefixes, true)?;
+    let digest = openssl::hash::hash(reference_digest, canonical_element.as_bytes())
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to compute digest of signed element")?;
+    if digest.len() != expected_digest.len() || !openssl::memcmp::eq(&digest, &expected_digest) {
+        return Err(report!(UserErrors::SSOFailed))
+            .attach_printable("Digest of signed element does not match");
+    }
+
+    let signature_value = find_child(signature, XML_DSIG_NS, "SignatureValue")
+        .and_then(|node| node.text())
+        .map(decode_base64)
+        .transpose()?
+        .ok_or(UserErrors::SSOFailed)
+        .attach_printable("SignatureValue not found in signature")?;
+
+    let canonical_signed_info = canonicalize(
+        document,
+        signed_info,
+        &get_inclusive_prefixes(canonicalization_method),
+        false,
+    )?;
+
+    for certificate in &idp_metadata.certificates {
+        let public_key = X509::from_der(certificate)
+            .and_then(|certificate| certificate.public_key())
+            .change_context(UserErrors::InternalServerError)
+            .attach_printable("Failed to get public key from IdP certificate")?;
+
+        let mut verifier = Verifier::new(signature_digest, &public_key)
+            .change_context(UserErrors::InternalServerError)
+            .attach_printable("Failed to initialize signature verifier")?;
+        verifier
+            .update(canonical_signed_info.as_bytes())
+            .change_context(UserErrors::InternalServerError)
+            .attach_printable("Failed to update signature verifier")?;
+        let is_valid = verifier.verify(&signature_value).unwrap_or(false);
+
+        if is_valid {
+            return Ok(true);
+        }
+    }
+
+    Err(report!(UserErrors::SSOFailed))
+        .attach_printable("Signature is not valid for any of the IdP certificates")
+}
+
+fn get_inclusive_prefixes(node: roxmltree::Node<'_, '_>) -> Vec<String> {
+    find_child(node, EXCLUSIVE_C14N_ALGORITHM, "InclusiveNamespaces")
+        .and_then(|node| node.attribute("PrefixList"))
+        .map(|prefix_list| {
+            prefix_list
+                .split_whitespace()
+                .map(|prefix| match prefix {
+                    "#default" => String::new(),
+                    prefix => prefix.to_string(),
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn find_child<'a, 'input>(
+    node: roxmltree::Node<'a, 'input>,
+    namespace: &str,
+    name: &str,
+) -> Option<roxmltree::Node<'a, 'input>> {
+    node.children()
+        .find(|child| child.has_tag_name((namespace, name)))
+}
+
+fn decode_base64(data: &str) -> UserResult<Vec<u8>> {
+    let data = data
+        .chars()
+        .filter(|character| !character.is_ascii_whitespace())
+        .collect::<String>();
+    consts::BASE64_ENGINE
+        .decode(data)
+        .change_context(UserErrors::SSOFailed)
+        .attach_printable("Failed to decode base64 data")
+}
+
+/// Serializes the element as per [Exclusive XML Canonicalization](https://www.w3.org/TR/xml-exc-c14n/)
+/// without comments. The source of the element is re-read, since the prefixes used in the
+/// document are not retained by `roxmltree`.
+fn canonicalize(
+    document: &roxmltree::Document<'_>,
+    element: roxmltree::Node<'_, '_>,
+    inclusive_prefixes: &[String],
+    exclude_enveloped_signature: bool,
+) -> UserResult<String> {
+    let source = document
+        .input_text()
+        .get(element.range())
+        .ok_or(UserErrors::InternalServerError)
+        .attach_printable("Failed to get source of the element")?;
+
+    // Namespaces declared on the ancestors of the element are in scope for the element
+    let declared_namespaces = element
+        .parent_element()
+        .map(|parent| {
+            parent
+                .namespaces()
+                .map(|namespace| {
+                    (
+                        namespace.name().unwrap_or_default().to_string(),
+                        namespace.uri().to_string(),
+                    )
+                })
+                .collect::<HashMap<_, _>>()
+        })
+        .unwrap_or_default();
+
+    canonicalize_source(
+        source,
+        declared_namespaces,
+        inclusive_prefixes,
+        exclude_enveloped_signature,
+    )
+}
+
+struct NamespaceScope {
+    qualified_name: String,
+    declared: HashMap<String, String>,
+    rendered: HashMap<String, String>,
+}
+
+fn canonicalize_source(
+    source: &str,
+    declared_namespaces: HashMap<String, String>,
+    inclusive_prefixes: &[String],
+    exclude_enveloped_signature: bool,
+) -> UserResult<String> {
+    use quick_xml::events::Event;
+
+    let mut reader = quick_xml::Reader::from_str(source);
+    reader.expand_empty_elements(true);
+
+    let mut output = String::with_capacity(source.len());
+    let mut scopes: Vec<NamespaceScope> = Vec::new();
+    // Depth of the enveloped signature being skipped, relative to the signature element
+    let mut skipped_depth: Option<usize> = None;
+
+    loop {
+        let event = reader
+            .read_event()
+            .change_context(UserErrors::SSOFailed)
+            .attach_printable("Failed to read element for canonicalization")?;
+
+        if let Some(depth) = skipped_depth {
+            skipped_depth = match event {
+                Event::Start(_) => Some(depth + 1),
+                Event::End(_) => depth.checked_sub(1),
+                Event::Eof => {
+                    return Err(report!(UserErrors::SSOFailed))
+                        .attach_printable("Unexpected end of element");
+                }
+                _ => Some(depth),
+            };
+            continue;
+        }
+
+        match event {
+            Event::Start(start) => {
+                let qualified_name = std::str::from_utf8(start.name().as_ref())
+                    .change_context(UserErrors::SSOFailed)?
+                    .to_string();
+
+                let mut declared = scopes
+                    .last()
+                    .map(|scope| scope.declared.clone())
+                    .unwrap_or_else(|| declared_namespaces.clone());
+                let mut attributes = Vec::new();
+                for attribute in start.attributes() {
+                    let attribute = attribute
+                        .change_context(UserErrors::SSOFailed)
+                        .attach_printable("Failed to read attribute for canonicalization")?;
+                    let name = std::str::from_utf8(attribute.key.as_ref())
+                        .change_context(UserErrors::SSOFailed)?
+                        .to_string();
+                    let value = normalize_attribute_value(&attribute.value)?;
+
+                    if name == "xmlns" {
+                        declared.insert(String::new(), value);
+                    } else if let Some(prefix) = name.strip_prefix("xmlns:") {
+                        declared.insert(prefix.to_string(), value);
+                    } else {
+                        attributes.push((name, value));
+                    }
+                }
+
+                if exclude_enveloped_signature
+                    && scopes.len() == 1
+                    && local_name(&qualified_name) == "Signature"
+                    && declared.get(prefix(&qualified_name)).map(String::as_str)
+                        == Some(XML_DSIG_NS)
+                {
+                    skipped_depth = Some(0);
+                    continue;
+                }
+
+                let rendered_by_ancestors = scopes
+                    .last()
+                    .map(|scope| scope.rendered.clone())
+                    .unwrap_or_default();
+
+                // Namespaces are visibly utilized by the element and its attributes, unqualified
+                // attributes are not in the default namespace
+                let mut utilized_prefixes = vec![prefix(&qualified_name).to_string()];
+                utilized_prefixes.extend(
+                    attributes
+                        .iter()
+                        .map(|(name, _)| prefix(name))
+                        .filter(|prefix| !prefix.is_empty())
+                        .map(ToString::to_string),
+                );
+                utilized_prefixes.extend(
+                    inclusive_prefixes
+                        .iter()
+                        .filter(|prefix| declared.contains_key(prefix.as_str()))
+                        .cloned(),
+                );
+                utilized_prefixes.sort();
+                utilized_prefixes.dedup();
+
+                let mut rendered = rendered_by_ancestors.clone();
+                let mut namespace_declarations = Vec::new();
+                for utilized_prefix in utilized_prefixes {
+                    if utilized_prefix == "xml" {
+                        continue;
+                    }
+                    let uri = match declared.get(&utilized_prefix) {
+                        Some(uri) => uri.clone(),
+                        None if utilized_prefix.is_empty() => String::new(),
+                        None => {
+                            return Err(report!(UserErrors::SSOFailed))
+                                .attach_printable("Unbound namespace prefix in element");
+                        }
+                    };
+                    let rendered_uri = rendered_by_ancestors
+                        .get(&utilized_prefix)
+                        .map(String::as_str)
+                        .unwrap_or_default();
+                    if rendered_uri != uri
+                        || (!utilized_prefix.is_empty()
+                            && !rendered_by_ancestors.contains_key(&utilized_prefix))
+                    {
+                        namespace_declarations.push((utilized_prefix.clone(), uri.clone()));
+                        rendered.insert(utilized_prefix, uri);
+                    }
+                }
+
+                let mut attributes = attributes
+                    .into_iter()
+                    .map(|(name, value)| {
+                        let namespace = match prefix(&name) {
+                            "" => String::new(),
+                            "xml" => XML_NS.to_string(),
+                            attribute_prefix => declared
+                                .get(attribute_prefix)
+                                .cloned()
+                                .ok_or(UserErrors::SSOFailed)
+                                .attach_printable("Unbound namespace prefix in attribute")?,
+                        };
+                        Ok((namespace, local_name(&name).to_string(), name, value))
+                    })
+                    .collect::<UserResult<Vec<_>>>()?;
+                attributes
+                    .sort_by(|first, second| (&first.0, &first.1).cmp(&(&second.0, &second.1)));
+
+                output.push('<');
+                output.push_str(&qualified_name);
+                for (namespace_prefix, uri) in namespace_declarations {
+                    if namespace_prefix.is_empty() {
+                        output.push_str(" xmlns=\"");
+                    } else {
+                        output.push_str(" xmlns:");
+                        output.push_str(&namespace_prefix);
+                        output.push_str("=\"");
+                    }
+                    output.push_str(&escape_attribute_value(&uri));
+                    output.push('"');
+                }
+                for (_, _, name, value) in attributes {
+                    output.push(' ');
+                    output.push_str(&name);
+                    output.push_str("=\"");
+                    output.push_str(&escape_attribute_value(&value));
+                    output.push('"');
+                }
+                output.push('>');
+
+                scopes.push(NamespaceScope {
+                    qualified_name,
+                    declared,
+                    rendered,
+                });
+            }
+            Event::End(_) => {
+                let scope = scopes
+                    .pop()
+                    .ok_or(UserErrors::SSOFailed)
+                    .attach_printable("Unexpected end tag")?;
+                output.push_str("</");
+                output.push_str(&scope.qualified_name);
+                output.push('>');
+
+                if scopes.is_empty() {
+                    break;
+                }
+            }
+            Event::Text(text) => {
+                let text = std::str::from_utf8(&text).change_context(UserErrors::SSOFailed)?;
+                let text = normalize_line_endings(text);
+                let text = quick_xml::escape::unescape(&text)
+                    .change_context(UserErrors::SSOFailed)
+                    .attach_printable("Failed to unescape text")?;
+                output.push_str(&escape_text(&text));
+            }
+            Event::CData(data) => {
+                let data = std::str::from_utf8(&data).change_context(UserErrors::SSOFailed)?;
+                output.push_str(&escape_text(&normalize_line_endings(data)));
+            }
+            Event::PI(instruction) => {
+                let instruction =
+                    std::str::from_utf8(&instruction).change_context(UserErrors::SSOFailed)?;
+                output.push_str("<?");
+                output.push_str(&normalize_line_endings(instruction));
+                output.push_str("?>");
+            }
+            Event::Comment(_) | Event::Decl(_) | Event::DocType(_) => {}
+            Event::Empty(_) | Event::Eof => {
+                return Err(report!(UserErrors::SSOFailed))
+                    .attach_printable("Unexpected end of element");
+            }
+        }
+    }
+
+    Ok(output)
+}
+
+fn prefix(qualified_name: &str) -> &str {
+    qualified_name
+        .split_once(':')
+        .map(|(prefix, _)| prefix)
+        .unwrap_or_default()
+}
+
+fn local_name(qualified_name: &str) -> &str {
+    qualified_name
+        .split_once(':')
+        .map_or(qualified_name, |(_, local_name)| local_name)
+}
+
+fn normalize_line_endings(text: &str) -> Cow<'_, str> {
+    if text.contains('\r') {
+        Cow::Owned(text.replace("\r\n", "\n").replace('\r', "\n"))
+    } else {
+        Cow::Borrowed(text)
+    }
+}
+
+/// Whitespace characters in attribute values are normalized to spaces by XML parsers, character
+/// references are resolved after the normalization
+fn normalize_attribute_value(value: &[u8]) -> UserResult<String> {
+    let value = std::str::from_utf8(value)
+        .change_context(UserErrors::SSOFailed)?
+        .replace("\r\n", " ")
+        .replace(['\r', '\n', '\t'], " ");
+    quick_xml::escape::unescape(&value)
+        .map(Cow::into_owned)
+        .change_context(UserErrors::SSOFailed)
+        .attach_printable("Failed to unescape attribute value")
+}
+
+fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for character in text.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '\r' => escaped.push_str("&#xD;"),
+            character => escaped.push(character),
+        }
+    }
+    escaped
+}
+
+fn escape_attribute_value(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for character in value.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\t' => escaped.push_str("&#x9;"),
+            '\n' => escaped.push_str("&#xA;"),
+            '\r' => escaped.push_str("&#xD;"),
+            character => escaped.push(character),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn canonicalize_element(xml: &str, name: (&str, &str), exclude_signature: bool) -> String {
+        let document = roxmltree::Document::parse(xml).unwrap();
+        let element = document
+            .descendants()
+            .find(|node| node.has_tag_name(name))
+            .unwrap();
+        canonicalize(&document, element, &[], exclude_signature).unwrap()
+    }
+
+    #[test]
+    fn test_canonicalization_renders_only_utilized_namespaces() {
+        let xml = r#"<samlp:Response xmlns:samlp="urn:oasis:names:tc:SAML:2.0:protocol" xmlns:saml="urn:oasis:names:tc:SAML:2.0:assertion" xmlns:xs="http://www.w3.org/2001/XMLSchema"><saml:Assertion Version="2.0" ID="_1"><saml:Issuer>idp</saml:Issuer><saml:Subject/></saml:Assertion></samlp:Response>"#;
+
+        assert_eq!(
+            canonicalize_element(xml, (SAML_ASSERTION_NS, "Assertion"), false),
+            r#"<saml:Assertion xmlns:saml="urn:oasis:names:tc:SAML:2.0:assertion" ID="_1" Version="2.0"><saml:Issuer>idp</saml:Issuer><saml:Subject></saml:Subject></saml:Assertion>"#
+        );
+    }
+
+    #[test]
+    fn test_canonicalization_removes_enveloped_signature() {
+        let xml = r#"<a:Root xmlns:a="urn:a" ID="_1"><ds:Signature xmlns:ds="http://www.w3.org/2000/09/xmldsig#"><ds:SignedInfo/></ds:Signature><a:Child attr="1 &amp; 2">x &gt; y</a:Child></a:Root>"#;
+
+        assert_eq!(
+            canonicalize_element(xml, ("urn:a", "Root"), true),
+            r#"<a:Root xmlns:a="urn:a" ID="_1"><a:Child attr="1 &amp; 2">x &gt; y</a:Child></a:Root>"#
+        );
+    }
+
+    const SP_ENTITY_ID: &str = "https://app.example.com/saml/metadata";
+    const ACS_URL: &str = "https://app.example.com/user/sso/acs";
+    const REQUEST_ID: &str = "_request_9d8e7f6a";
+
+    // Responses signed by an independent XML-DSig implementation, with the certificate of the
+    // signing key in the IdP metadata
+    const IDP_METADATA: &str = include_str!("saml/idp_metadata.xml");
+    const SIGNED_ASSERTION_RESPONSE: &str = include_str!("saml/signed_assertion_response.xml");
+    const SIGNED_RESPONSE: &str = include_str!("saml/signed_response.xml");
+
+    fn validate_response(response: &str) -> UserResult<SamlAssertion> {
+        let idp_metadata = parse_idp_metadata(&Secret::new(IDP_METADATA.to_string()))?;
+        let saml_response = Secret::new(consts::BASE64_ENGINE.encode(response));
+        validate_saml_response(
+            saml_response,
+            &idp_metadata,
+            SP_ENTITY_ID,
+            ACS_URL,
+            REQUEST_ID,
+        )
+    }
+
+    fn get_signed_assertion(response: &str) -> &str {
+        let start = response.find("<saml:Assertion ").unwrap();
+        let end = response.find("</saml:Assertion>").unwrap() + "</saml:Assertion>".len();
+        &response[start..end]
+    }
+
+    fn remove_signature(xml: &str) -> String {
+        let start = xml.find("<ds:Signature ").unwrap();
+        let end = xml.find("</ds:Signature>").unwrap() + "</ds:Signature>".len();
+        format!("{}{}", &xml[..start], &xml[end..])
+    }
+
+    #[test]
+    fn test_validate_idp_signed_assertion() {
+        let assertion = validate_response(SIGNED_ASSERTION_RESPONSE).unwrap();
+
+        assert_eq!(assertion.id, "_assertion_5a4b3c2d");
+        assert_eq!(assertion.name_id.peek(), "jane.doe@example.com");
+        assert_eq!(
+            assertion.get_first_attribute_value("email"),
+            Some("jane.doe@example.com")
+        );
+        assert_eq!(
+            assertion.get_attribute_values("groups"),
+            ["admins", "developers & operators"]
+        );
+    }
+
+    #[test]
+    fn test_validate_idp_signed_response() {
+        let assertion = validate_response(SIGNED_RESPONSE).unwrap();
+
+        assert_eq!(assertion.name_id.peek(), "jane.doe@example.com");
+    }
+
+    #[test]
+    fn test_reject_modified_assertion() {
+        for response in [SIGNED_ASSERTION_RESPONSE, SIGNED_RESPONSE] {
+            let modified = response.replace(
+                ">jane.doe@example.com</saml:NameID>",
+                ">attacker@example.com</saml:NameID>",
+            );
+            assert_ne!(modified, response);
+            assert!(validate_response(&modified).is_err());
+
+            let modified = response.replace(">admins<", ">owners<");
+            assert_ne!(modified, response);
+            assert!(validate_response(&modified).is_err());
+        }
+    }
+
+    #[test]
+    fn test_reject_tampered_signature() {
+        let start = SIGNED_ASSERTION_RESPONSE
+            .find("<ds:SignatureValue>")
+            .unwrap()
+            + "<ds:SignatureValue>".len();
+        let mut tampered = SIGNED_ASSERTION_RESPONSE.to_string();
+        let replacement = if &tampered[start..=start] == "A" {
+            "B"
+        } else {
+            "A"
+        };
+        tampered.replace_range(start..=start, replacement);
+
+        assert!(validate_response(&tampered).is_err());
+    }
+
+    #[test]
+    fn test_reject_unsigned_assertion() {
+        let unsigned = remove_signature(SIGNED_ASSERTION_RESPONSE);
+
+        assert!(validate_response(&unsigned).is_err());
+    }
+
+    #[test]
+    fn test_reject_wrapped_assertion() {
+        let signed_assertion = get_signed_assertion(SIGNED_ASSERTION_RESPONSE);
+        let forged_assertion = remove_signature(signed_assertion)
+            .replace("_assertion_5a4b3c2d", "_assertion_forged")
+            .replace("jane.doe@example.com", "attacker@example.com");
+
+        // The signed assertion is moved out of the way, and a forged assertion takes its place
+        let wrapped = SIGNED_ASSERTION_RESPONSE.replace(
+            signed_assertion,
+            &format!("<samlp:Extensions>{signed_assertion}</samlp:Extensions>{forged_assertion}"),
+        );
+        assert!(validate_response(&wrapped).is_err());
+
+        // The forged assertion carries the signature of the original assertion, which still
+        // references the original assertion
+        let forged_with_signature = signed_assertion
+            .replace(r#"ID="_assertion_5a4b3c2d""#, r#"ID="_assertion_forged""#)
+            .replace("jane.doe@example.com", "attacker@example.com");
+        let wrapped = SIGNED_ASSERTION_RESPONSE.replace(
+            signed_assertion,
+            &format!(
+                "<samlp:Extensions>{signed_assertion}</samlp:Extensions>{forged_with_signature}"
+            ),
+        );
+        assert!(validate_response(&wrapped).is_err());
+
+        // The forged assertion reuses the ID of the signed assertion
+        let forged_assertion = remove_signature(signed_assertion)
+            .replace("jane.doe@example.com", "attacker@example.com");
+        let wrapped = SIGNED_ASSERTION_RESPONSE.replace(
+            signed_assertion,
+            &format!("<samlp:Extensions>{signed_assertion}</samlp:Extensions>{forged_assertion}"),
+        );
+        assert!(validate_response(&wrapped).is_err());
+
+        // A second assertion is placed next to the signed one
+        let wrapped = SIGNED_ASSERTION_RESPONSE.replace(
+            signed_assertion,
+            &format!(
+                "{signed_assertion}{}",
+                remove_signature(signed_assertion)
+                    .replace("_assertion_5a4b3c2d", "_assertion_forged")
+            ),
+        );
+        assert!(validate_response(&wrapped).is_err());
+    }
+
+    #[test]
+    fn test_reject_assertion_for_other_request() {
+        let idp_metadata = parse_idp_metadata(&Secret::new(IDP_METADATA.to_string())).unwrap();
+        let saml_response = Secret::new(consts::BASE64_ENGINE.encode(SIGNED_ASSERTION_RESPONSE));
+
+        assert!(validate_saml_response(
+            saml_response,
+            &idp_metadata,
+            SP_ENTITY_ID,
+            ACS_URL,
+            "_request_other",
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_parse_idp_metadata_without_certificate() {
+        let metadata = Secret::new(format!(
+            r#"<md:EntityDescriptor xmlns:md="{SAML_METADATA_NS}" entityID="https://idp.example.com"><md:IDPSSODescriptor protocolSupportEnumeration="{SAML_PROTOCOL_NS}"><md:SingleSignOnService Binding="{HTTP_POST_BINDING}" Location="https://idp.example.com/post"/><md:SingleSignOnService Binding="{HTTP_REDIRECT_BINDING}" Location="https://idp.example.com/redirect"/></md:IDPSSODescriptor></md:EntityDescriptor>"#
+        ));
+
+        // Metadata without a signing certificate is rejected
+        assert!(parse_idp_metadata(&metadata).is_err());
+    }
+}
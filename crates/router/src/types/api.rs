@@ -492,6 +492,7 @@ impl ConnectorData {
                 enums::Connector::Signifyd
                 | enums::Connector::Plaid
                 | enums::Connector::Riskified
+                | enums::Connector::RulesEngine
                 | enums::Connector::Gpayments
                 | enums::Connector::Threedsecureio => {
                     Err(report!(errors::ConnectorError::InvalidConnectorName)
@@ -101,6 +101,12 @@ impl ForeignTryFrom<storage::business_profile::BusinessProfile> for BusinessProf
             collect_billing_details_from_wallet_connector: item
                 .collect_billing_details_from_wallet_connector,
             is_connector_agnostic_mit_enabled: item.is_connector_agnostic_mit_enabled,
+            auto_void_after_days: item
+                .auto_void_after_days
+                .map(u16::try_from)
+                .transpose()
+                .change_context(errors::ParsingError::UnknownError)
+                .attach_printable("Invalid value for `auto_void_after_days`")?,
         })
     }
 }
@@ -218,6 +224,7 @@ impl ForeignTryFrom<(domain::MerchantAccount, BusinessProfileCreate)>
             collect_billing_details_from_wallet_connector: request
                 .collect_billing_details_from_wallet_connector
                 .or(Some(false)),
+            auto_void_after_days: request.auto_void_after_days.map(i32::from),
         })
     }
 }
@@ -67,6 +67,7 @@ pub struct CreateFileRequest {
 #[strum(serialize_all = "snake_case")]
 pub enum FilePurpose {
     DisputeEvidence,
+    PayoutBatch,
 }
 
 pub trait UploadFile:
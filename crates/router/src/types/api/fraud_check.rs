@@ -82,6 +82,10 @@ impl FraudCheckConnectorData {
             enums::FrmConnectors::Riskified => {
                 Ok(ConnectorEnum::Old(Box::new(&connector::Riskified)))
             }
+            enums::FrmConnectors::RulesEngine => Err(errors::ApiErrorResponse::NotSupported {
+                message: "Connector calls for the in-house rules engine".to_string(),
+            }
+            .into()),
         }
     }
 }
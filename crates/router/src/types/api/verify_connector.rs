@@ -55,6 +55,9 @@ impl VerifyConnectorData {
             authentication_data: None,
             customer_acceptance: None,
             charges: None,
+            installments: None,
+            split_payments: None,
+            network_token_data: None,
         }
     }
 
@@ -15,6 +15,8 @@ pub mod types;
 #[cfg(feature = "olap")]
 pub mod user;
 pub mod user_key_store;
+mod webhook_endpoint;
+mod webhook_signing_secret;
 
 pub use address::*;
 pub use customer::*;
@@ -26,3 +28,5 @@ pub use payments::*;
 #[cfg(feature = "olap")]
 pub use user::*;
 pub use user_key_store::*;
+pub use webhook_endpoint::*;
+pub use webhook_signing_secret::*;
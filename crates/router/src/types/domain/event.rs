@@ -29,6 +29,7 @@ pub struct Event {
     pub response: OptionalEncryptableSecretString,
     pub delivery_attempt: Option<WebhookDeliveryAttempt>,
     pub metadata: Option<EventMetadata>,
+    pub webhook_endpoint_id: Option<String>,
 }
 
 #[derive(Debug)]
@@ -76,6 +77,7 @@ impl super::behaviour::Conversion for Event {
             response: self.response.map(Into::into),
             delivery_attempt: self.delivery_attempt,
             metadata: self.metadata,
+            webhook_endpoint_id: self.webhook_endpoint_id,
         })
     }
 
@@ -110,6 +112,7 @@ impl super::behaviour::Conversion for Event {
                     .await?,
                 delivery_attempt: item.delivery_attempt,
                 metadata: item.metadata,
+                webhook_endpoint_id: item.webhook_endpoint_id,
             })
         }
         .await
@@ -136,6 +139,7 @@ impl super::behaviour::Conversion for Event {
             response: self.response.map(Into::into),
             delivery_attempt: self.delivery_attempt,
             metadata: self.metadata,
+            webhook_endpoint_id: self.webhook_endpoint_id,
         })
     }
 }
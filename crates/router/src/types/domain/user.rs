@@ -291,6 +291,24 @@ impl From<(user_api::CreateInternalUserRequest, String)> for NewUserOrganization
     }
 }
 
+/// Email and name of a user provisioned on signing in through SAML, along with the role
+/// assignment of the user and the organization owning the auth method
+type SamlProvisionedUser = (
+    UserEmail,
+    Option<Secret<String>>,
+    user_api::SamlRoleAssignment,
+    String,
+);
+
+impl From<SamlProvisionedUser> for NewUserOrganization {
+    fn from((_email, _name, _role_assignment, org_id): SamlProvisionedUser) -> Self {
+        Self(diesel_org::OrganizationNew {
+            org_id,
+            org_name: None,
+        })
+    }
+}
+
 impl From<UserMerchantCreateRequestWithToken> for NewUserOrganization {
     fn from(value: UserMerchantCreateRequestWithToken) -> Self {
         Self(diesel_org::OrganizationNew {
@@ -473,6 +491,21 @@ impl TryFrom<(user_api::CreateInternalUserRequest, String)> for NewUserMerchant
     }
 }
 
+impl TryFrom<SamlProvisionedUser> for NewUserMerchant {
+    type Error = error_stack::Report<UserErrors>;
+
+    fn try_from(value: SamlProvisionedUser) -> UserResult<Self> {
+        let merchant_id = MerchantId::new(value.2.merchant_id.clone())?;
+        let new_organization = NewUserOrganization::from(value);
+
+        Ok(Self {
+            company_name: None,
+            merchant_id,
+            new_organization,
+        })
+    }
+}
+
 impl TryFrom<InviteeUserRequestWithInvitedUserToken> for NewUserMerchant {
     type Error = error_stack::Report<UserErrors>;
     fn try_from(value: InviteeUserRequestWithInvitedUserToken) -> UserResult<Self> {
@@ -765,6 +798,29 @@ impl TryFrom<InviteeUserRequestWithInvitedUserToken> for NewUser {
     }
 }
 
+impl TryFrom<SamlProvisionedUser> for NewUser {
+    type Error = error_stack::Report<UserErrors>;
+
+    fn try_from(value: SamlProvisionedUser) -> UserResult<Self> {
+        let user_id = uuid::Uuid::new_v4().to_string();
+        let email = value.0.clone();
+        // Fallback to the name derived from email, if the IdP does not send a valid name
+        let name = match value.1.clone().map(UserName::new).transpose() {
+            Ok(Some(name)) => name,
+            Ok(None) | Err(_) => UserName::try_from(email.clone().into_inner())?,
+        };
+        let new_merchant = NewUserMerchant::try_from(value)?;
+
+        Ok(Self {
+            user_id,
+            name,
+            email,
+            password: None,
+            new_merchant,
+        })
+    }
+}
+
 #[derive(Clone)]
 pub struct UserFromStorage(pub storage_user::User);
 
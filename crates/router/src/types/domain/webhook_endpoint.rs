@@ -0,0 +1,199 @@
+use common_utils::crypto::{
+    Encryptable, GcmAes256, OptionalEncryptableSecretString, OptionalEncryptableValue,
+};
+use diesel_models::enums::EventType;
+use error_stack::ResultExt;
+use masking::{PeekInterface, Secret};
+
+use crate::{
+    errors::{CustomResult, ValidationError},
+    types::domain::types::{self, AsyncLift, TypeEncryption},
+};
+
+#[derive(Clone, Debug)]
+pub struct WebhookEndpoint {
+    pub endpoint_id: String,
+    pub merchant_id: String,
+    pub profile_id: String,
+    pub url: String,
+    pub description: Option<String>,
+    pub auth: OptionalEncryptableValue,
+    pub signing_secret: Encryptable<Secret<String>>,
+    pub enabled_events: Vec<EventType>,
+    pub is_enabled: bool,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+    /// The signing secret replaced by the last rotation, which continues to be used for signing
+    /// webhooks until it expires
+    pub previous_signing_secret: OptionalEncryptableSecretString,
+    pub previous_signing_secret_expires_at: Option<time::PrimitiveDateTime>,
+}
+
+impl WebhookEndpoint {
+    /// Whether the endpoint should receive webhooks for the specified event type.
+    pub fn is_subscribed_to(&self, event_type: EventType) -> bool {
+        self.is_enabled && self.enabled_events.contains(&event_type)
+    }
+
+    /// The keys for signing webhooks sent to the endpoint at the specified time, ordered from the
+    /// oldest to the newest key.
+    pub fn get_signing_keys(&self, now: time::PrimitiveDateTime) -> Vec<Secret<String>> {
+        let previous_signing_secret = self
+            .previous_signing_secret
+            .as_ref()
+            .filter(|_| {
+                self.previous_signing_secret_expires_at
+                    .is_some_and(|expires_at| expires_at > now)
+            })
+            .map(|previous_signing_secret| previous_signing_secret.get_inner().clone());
+
+        previous_signing_secret
+            .into_iter()
+            .chain(std::iter::once(self.signing_secret.get_inner().clone()))
+            .collect()
+    }
+}
+
+#[async_trait::async_trait]
+impl super::behaviour::Conversion for WebhookEndpoint {
+    type DstType = diesel_models::webhook_endpoint::WebhookEndpoint;
+    type NewDstType = diesel_models::webhook_endpoint::WebhookEndpointNew;
+
+    async fn convert(self) -> CustomResult<Self::DstType, ValidationError> {
+        Ok(diesel_models::webhook_endpoint::WebhookEndpoint {
+            endpoint_id: self.endpoint_id,
+            merchant_id: self.merchant_id,
+            profile_id: self.profile_id,
+            url: self.url,
+            description: self.description,
+            auth: self.auth.map(Into::into),
+            signing_secret: self.signing_secret.into(),
+            enabled_events: self.enabled_events,
+            is_enabled: self.is_enabled,
+            created_at: self.created_at,
+            modified_at: self.modified_at,
+            previous_signing_secret: self.previous_signing_secret.map(Into::into),
+            previous_signing_secret_expires_at: self.previous_signing_secret_expires_at,
+        })
+    }
+
+    async fn convert_back(
+        item: Self::DstType,
+        key: &Secret<Vec<u8>>,
+    ) -> CustomResult<Self, ValidationError>
+    where
+        Self: Sized,
+    {
+        async {
+            Ok::<Self, error_stack::Report<common_utils::errors::CryptoError>>(Self {
+                endpoint_id: item.endpoint_id,
+                merchant_id: item.merchant_id,
+                profile_id: item.profile_id,
+                url: item.url,
+                description: item.description,
+                auth: item
+                    .auth
+                    .async_lift(|inner| types::decrypt(inner, key.peek()))
+                    .await?,
+                signing_secret: Encryptable::decrypt(item.signing_secret, key.peek(), GcmAes256)
+                    .await?,
+                enabled_events: item.enabled_events,
+                is_enabled: item.is_enabled,
+                created_at: item.created_at,
+                modified_at: item.modified_at,
+                previous_signing_secret: item
+                    .previous_signing_secret
+                    .async_lift(|inner| types::decrypt(inner, key.peek()))
+                    .await?,
+                previous_signing_secret_expires_at: item.previous_signing_secret_expires_at,
+            })
+        }
+        .await
+        .change_context(ValidationError::InvalidValue {
+            message: "Failed while decrypting webhook endpoint data".to_string(),
+        })
+    }
+
+    async fn construct_new(self) -> CustomResult<Self::NewDstType, ValidationError> {
+        Ok(diesel_models::webhook_endpoint::WebhookEndpointNew {
+            endpoint_id: self.endpoint_id,
+            merchant_id: self.merchant_id,
+            profile_id: self.profile_id,
+            url: self.url,
+            description: self.description,
+            auth: self.auth.map(Into::into),
+            signing_secret: self.signing_secret.into(),
+            enabled_events: self.enabled_events,
+            is_enabled: self.is_enabled,
+            created_at: self.created_at,
+            modified_at: self.modified_at,
+            previous_signing_secret: self.previous_signing_secret.map(Into::into),
+            previous_signing_secret_expires_at: self.previous_signing_secret_expires_at,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use masking::ExposeInterface;
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn encryptable(secret: &str) -> Encryptable<Secret<String>> {
+        Encryptable::new(
+            Secret::new(secret.to_string()),
+            Secret::new(secret.as_bytes().to_vec()),
+        )
+    }
+
+    fn webhook_endpoint(
+        previous_signing_secret_expires_at: Option<time::PrimitiveDateTime>,
+    ) -> WebhookEndpoint {
+        WebhookEndpoint {
+            endpoint_id: "whep_123".to_string(),
+            merchant_id: "merchant_123".to_string(),
+            profile_id: "pro_123".to_string(),
+            url: "https://www.example.com/webhooks".to_string(),
+            description: None,
+            auth: None,
+            signing_secret: encryptable("current_secret"),
+            enabled_events: vec![EventType::PaymentSucceeded],
+            is_enabled: true,
+            created_at: datetime!(2024-07-01 00:00),
+            modified_at: datetime!(2024-07-01 00:00),
+            previous_signing_secret: previous_signing_secret_expires_at
+                .map(|_| encryptable("previous_secret")),
+            previous_signing_secret_expires_at,
+        }
+    }
+
+    fn exposed_signing_keys(
+        webhook_endpoint: &WebhookEndpoint,
+        now: time::PrimitiveDateTime,
+    ) -> Vec<String> {
+        webhook_endpoint
+            .get_signing_keys(now)
+            .into_iter()
+            .map(ExposeInterface::expose)
+            .collect()
+    }
+
+    #[test]
+    fn test_get_signing_keys() {
+        let now = datetime!(2024-07-10 12:00);
+
+        assert_eq!(
+            exposed_signing_keys(&webhook_endpoint(None), now),
+            vec!["current_secret"]
+        );
+        assert_eq!(
+            exposed_signing_keys(&webhook_endpoint(Some(datetime!(2024-07-11 12:00))), now),
+            vec!["previous_secret", "current_secret"]
+        );
+        assert_eq!(
+            exposed_signing_keys(&webhook_endpoint(Some(datetime!(2024-07-10 12:00))), now),
+            vec!["current_secret"]
+        );
+    }
+}
@@ -0,0 +1,82 @@
+use common_utils::crypto::{Encryptable, GcmAes256};
+use error_stack::ResultExt;
+use masking::{PeekInterface, Secret};
+
+use crate::{
+    errors::{CustomResult, ValidationError},
+    types::domain::types::TypeEncryption,
+};
+
+#[derive(Clone, Debug)]
+pub struct WebhookSigningSecret {
+    pub secret_id: String,
+    pub merchant_id: String,
+    pub profile_id: String,
+    pub secret: Encryptable<Secret<String>>,
+    pub created_at: time::PrimitiveDateTime,
+    pub expires_at: Option<time::PrimitiveDateTime>,
+    pub revoked_at: Option<time::PrimitiveDateTime>,
+}
+
+impl WebhookSigningSecret {
+    /// A secret is used for signing outgoing webhooks until it is revoked or it expires.
+    pub fn is_active(&self, now: time::PrimitiveDateTime) -> bool {
+        self.revoked_at.is_none() && self.expires_at.map_or(true, |expires_at| expires_at > now)
+    }
+}
+
+#[async_trait::async_trait]
+impl super::behaviour::Conversion for WebhookSigningSecret {
+    type DstType = diesel_models::webhook_signing_secret::WebhookSigningSecret;
+    type NewDstType = diesel_models::webhook_signing_secret::WebhookSigningSecretNew;
+
+    async fn convert(self) -> CustomResult<Self::DstType, ValidationError> {
+        Ok(
+            diesel_models::webhook_signing_secret::WebhookSigningSecret {
+                secret_id: self.secret_id,
+                merchant_id: self.merchant_id,
+                profile_id: self.profile_id,
+                secret: self.secret.into(),
+                created_at: self.created_at,
+                expires_at: self.expires_at,
+                revoked_at: self.revoked_at,
+            },
+        )
+    }
+
+    async fn convert_back(
+        item: Self::DstType,
+        key: &Secret<Vec<u8>>,
+    ) -> CustomResult<Self, ValidationError>
+    where
+        Self: Sized,
+    {
+        Ok(Self {
+            secret_id: item.secret_id,
+            merchant_id: item.merchant_id,
+            profile_id: item.profile_id,
+            secret: Encryptable::decrypt(item.secret, key.peek(), GcmAes256)
+                .await
+                .change_context(ValidationError::InvalidValue {
+                    message: "Failed while decrypting webhook signing secret".to_string(),
+                })?,
+            created_at: item.created_at,
+            expires_at: item.expires_at,
+            revoked_at: item.revoked_at,
+        })
+    }
+
+    async fn construct_new(self) -> CustomResult<Self::NewDstType, ValidationError> {
+        Ok(
+            diesel_models::webhook_signing_secret::WebhookSigningSecretNew {
+                secret_id: self.secret_id,
+                merchant_id: self.merchant_id,
+                profile_id: self.profile_id,
+                secret: self.secret.into(),
+                created_at: self.created_at,
+                expires_at: self.expires_at,
+                revoked_at: self.revoked_at,
+            },
+        )
+    }
+}
@@ -1,4 +1,4 @@
 pub use diesel_models::gsm::{
-    GatewayStatusMap, GatewayStatusMapperUpdateInternal, GatewayStatusMappingNew,
-    GatewayStatusMappingUpdate,
+    GatewayStatusMap, GatewayStatusMapListConstraints, GatewayStatusMapperUpdateInternal,
+    GatewayStatusMappingNew, GatewayStatusMappingUpdate,
 };
@@ -1,4 +1,7 @@
 use common_utils::types::MinorUnit;
+pub use diesel_models::payment_attempt::{
+    AutoVoidTrackingData, ScheduledCaptureTrackingData, SmartRetryTrackingData,
+};
 use diesel_models::{capture::CaptureNew, enums};
 use error_stack::ResultExt;
 pub use hyperswitch_domain_models::payments::payment_attempt::{
@@ -125,9 +128,9 @@ mod tests {
         };
 
         let store = state
-            .stores
-            .get(state.conf.multitenancy.get_tenant_names().first().unwrap())
-            .unwrap();
+            .get_tenant_state(state.get_tenant_names().first().unwrap())
+            .unwrap()
+            .store;
         let response = store
             .insert_payment_attempt(payment_attempt, enums::MerchantStorageScheme::PostgresOnly)
             .await
@@ -169,9 +172,9 @@ mod tests {
             ..PaymentAttemptNew::default()
         };
         let store = state
-            .stores
-            .get(state.conf.multitenancy.get_tenant_names().first().unwrap())
-            .unwrap();
+            .get_tenant_state(state.get_tenant_names().first().unwrap())
+            .unwrap()
+            .store;
         store
             .insert_payment_attempt(payment_attempt, enums::MerchantStorageScheme::PostgresOnly)
             .await
@@ -224,9 +227,9 @@ mod tests {
             ..PaymentAttemptNew::default()
         };
         let store = state
-            .stores
-            .get(state.conf.multitenancy.get_tenant_names().first().unwrap())
-            .unwrap();
+            .get_tenant_state(state.get_tenant_names().first().unwrap())
+            .unwrap()
+            .store;
         store
             .insert_payment_attempt(payment_attempt, enums::MerchantStorageScheme::PostgresOnly)
             .await
@@ -6,8 +6,8 @@ use std::{
 use api_models::payment_methods;
 use diesel_models::enums;
 pub use diesel_models::payment_method::{
-    PaymentMethod, PaymentMethodNew, PaymentMethodUpdate, PaymentMethodUpdateInternal,
-    TokenizeCoreWorkflow,
+    CardAccountUpdaterTrackingData, NetworkTokenizationTrackingData, PaymentMethod,
+    PaymentMethodNew, PaymentMethodUpdate, PaymentMethodUpdateInternal, TokenizeCoreWorkflow,
 };
 
 use crate::types::api::{self, payments};
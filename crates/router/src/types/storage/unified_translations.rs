@@ -0,0 +1 @@
+pub use diesel_models::unified_translations::*;
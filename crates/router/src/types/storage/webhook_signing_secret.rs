@@ -0,0 +1 @@
+pub use diesel_models::webhook_signing_secret::*;
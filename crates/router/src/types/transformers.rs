@@ -19,9 +19,9 @@ use masking::{ExposeInterface, PeekInterface};
 
 use super::domain;
 use crate::{
-    core::errors,
+    core::{errors, link_hosting},
     headers::{
-        BROWSER_NAME, X_CLIENT_PLATFORM, X_CLIENT_SOURCE, X_CLIENT_VERSION,
+        ACCEPT_LANGUAGE, BROWSER_NAME, X_CLIENT_PLATFORM, X_CLIENT_SOURCE, X_CLIENT_VERSION,
         X_PAYMENT_CONFIRM_SOURCE,
     },
     services::authentication::get_header_value_by_key,
@@ -312,6 +312,11 @@ impl ForeignTryFrom<api_enums::Connector> for common_enums::RoutableConnectors {
                     message: "threedsecureio is not a routable connector".to_string(),
                 })?
             }
+            api_enums::Connector::RulesEngine => {
+                Err(common_utils::errors::ValidationError::InvalidValue {
+                    message: "rules_engine is not a routable connector".to_string(),
+                })?
+            }
         })
     }
 }
@@ -408,6 +413,7 @@ impl ForeignFrom<api_enums::IntentStatus> for Option<storage_enums::EventType> {
                 Some(storage_enums::EventType::ActionRequired)
             }
             api_enums::IntentStatus::Cancelled => Some(storage_enums::EventType::PaymentCancelled),
+            api_enums::IntentStatus::Expired => Some(storage_enums::EventType::PaymentExpired),
             api_enums::IntentStatus::PartiallyCaptured
             | api_enums::IntentStatus::PartiallyCapturedAndCapturable => {
                 Some(storage_enums::EventType::PaymentCaptured)
@@ -1073,6 +1079,116 @@ impl ForeignFrom<api_models::enums::PayoutType> for api_enums::PaymentMethod {
     }
 }
 
+#[cfg(feature = "payouts")]
+impl ForeignFrom<storage::PayoutBatch> for api_models::payouts::PayoutBatchResponse {
+    fn foreign_from(payout_batch: storage::PayoutBatch) -> Self {
+        // The counts are never negative, as they are computed from the rows of the batch
+        let to_count = |count: i32| u32::try_from(count).unwrap_or_default();
+        let pending_count = payout_batch.total_count
+            - payout_batch.succeeded_count
+            - payout_batch.failed_count
+            - payout_batch.cancelled_count;
+        Self {
+            batch_id: payout_batch.batch_id,
+            merchant_id: payout_batch.merchant_id,
+            file_id: payout_batch.file_id,
+            status: payout_batch.status,
+            concurrency: u16::try_from(payout_batch.concurrency).unwrap_or_default(),
+            total_count: to_count(payout_batch.total_count),
+            succeeded_count: to_count(payout_batch.succeeded_count),
+            failed_count: to_count(payout_batch.failed_count),
+            cancelled_count: to_count(payout_batch.cancelled_count),
+            pending_count: to_count(pending_count),
+            result_file_id: payout_batch.result_file_id,
+            created_at: payout_batch.created_at,
+            modified_at: payout_batch.modified_at,
+        }
+    }
+}
+
+#[cfg(feature = "payouts")]
+impl ForeignFrom<storage::PayoutBatchItem> for api_models::payouts::PayoutBatchItemResponse {
+    fn foreign_from(item: storage::PayoutBatchItem) -> Self {
+        Self {
+            row_number: u32::try_from(item.row_number).unwrap_or_default(),
+            payout_id: item.payout_id,
+            status: item.status,
+            payout_status: item.payout_status,
+            error_code: item.error_code,
+            error_message: item.error_message,
+        }
+    }
+}
+
+impl ForeignFrom<storage::SubscriptionPlan>
+    for api_models::subscriptions::SubscriptionPlanResponse
+{
+    fn foreign_from(plan: storage::SubscriptionPlan) -> Self {
+        Self {
+            plan_id: plan.plan_id,
+            merchant_id: plan.merchant_id,
+            name: plan.name,
+            description: plan.description,
+            amount: plan.amount,
+            currency: plan.currency,
+            interval: plan.billing_interval,
+            interval_count: u16::try_from(plan.billing_interval_count).unwrap_or_default(),
+            trial_period_days: plan
+                .trial_period_days
+                .and_then(|trial_period_days| u16::try_from(trial_period_days).ok()),
+            active: plan.active,
+            metadata: plan.metadata,
+            created_at: plan.created_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::Subscription> for api_models::subscriptions::SubscriptionResponse {
+    fn foreign_from(subscription: storage::Subscription) -> Self {
+        Self {
+            subscription_id: subscription.subscription_id,
+            merchant_id: subscription.merchant_id,
+            customer_id: subscription.customer_id,
+            plan_id: subscription.plan_id,
+            mandate_id: subscription.mandate_id,
+            profile_id: subscription.profile_id,
+            status: subscription.status,
+            current_period_start: subscription.current_period_start,
+            current_period_end: subscription.current_period_end,
+            trial_end: subscription.trial_end,
+            next_billing_at: subscription.next_billing_at,
+            cancel_at_period_end: subscription.cancel_at_period_end,
+            cancelled_at: subscription.cancelled_at,
+            cancellation_reason: subscription.cancellation_reason,
+            metadata: subscription.metadata,
+            created_at: subscription.created_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::SubscriptionInvoice>
+    for api_models::subscriptions::SubscriptionInvoiceResponse
+{
+    fn foreign_from(invoice: storage::SubscriptionInvoice) -> Self {
+        Self {
+            invoice_id: invoice.invoice_id,
+            subscription_id: invoice.subscription_id,
+            amount: invoice.amount,
+            currency: invoice.currency,
+            status: invoice.status,
+            period_start: invoice.period_start,
+            period_end: invoice.period_end,
+            payment_id: invoice.payment_id,
+            // The attempt count is never negative, as it is only ever incremented
+            attempt_count: u32::try_from(invoice.attempt_count).unwrap_or_default(),
+            next_attempt_at: invoice.next_attempt_at,
+            error_code: invoice.error_code,
+            error_message: invoice.error_message,
+            created_at: invoice.created_at,
+        }
+    }
+}
+
 impl ForeignTryFrom<&HeaderMap> for payments::HeaderPayload {
     type Error = error_stack::Report<errors::ApiErrorResponse>;
     fn foreign_try_from(headers: &HeaderMap) -> Result<Self, Self::Error> {
@@ -1130,6 +1246,13 @@ impl ForeignTryFrom<&HeaderMap> for payments::HeaderPayload {
                     .unwrap_or(api_enums::ClientPlatform::Unknown)
             });
 
+        let locale =
+            get_header_value_by_key(ACCEPT_LANGUAGE.into(), headers)?.and_then(|accept_language| {
+                link_hosting::get_languages_from_accept_language(accept_language)
+                    .into_iter()
+                    .find(|language| language != "*")
+            });
+
         Ok(Self {
             payment_confirm_source,
             client_source,
@@ -1137,6 +1260,7 @@ impl ForeignTryFrom<&HeaderMap> for payments::HeaderPayload {
             x_hs_latency: Some(x_hs_latency),
             browser_name,
             x_client_platform,
+            locale,
         })
     }
 }
@@ -1270,6 +1394,7 @@ impl ForeignFrom<gsm_api_types::GsmCreateRequest> for storage::GatewayStatusMapp
             step_up_possible: value.step_up_possible,
             unified_code: value.unified_code,
             unified_message: value.unified_message,
+            retry_schedule: value.retry_schedule,
         }
     }
 }
@@ -1288,6 +1413,89 @@ impl ForeignFrom<storage::GatewayStatusMap> for gsm_api_types::GsmResponse {
             step_up_possible: value.step_up_possible,
             unified_code: value.unified_code,
             unified_message: value.unified_message,
+            retry_schedule: value.retry_schedule,
+        }
+    }
+}
+
+impl ForeignFrom<storage::UnifiedTranslations> for gsm_api_types::UnifiedTranslationsResponse {
+    fn foreign_from(value: storage::UnifiedTranslations) -> Self {
+        Self {
+            unified_code: value.unified_code,
+            unified_message: value.unified_message,
+            locale: value.locale,
+            translation: value.translation,
+            created_at: value.created_at,
+            last_modified_at: value.last_modified_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::Tenant> for api_models::tenant::TenantResponse {
+    fn foreign_from(tenant: storage::Tenant) -> Self {
+        Self {
+            tenant_id: tenant.tenant_id,
+            name: tenant.name,
+            base_url: tenant.base_url,
+            schema_name: tenant.schema_name,
+            redis_key_prefix: tenant.redis_key_prefix,
+            clickhouse_database: tenant.clickhouse_database,
+            status: tenant.status,
+            created_at: tenant.created_at,
+            modified_at: tenant.modified_at,
+        }
+    }
+}
+
+impl ForeignFrom<api_models::log_filter::LogFilterField> for router_env::LogFilterField {
+    fn foreign_from(field: api_models::log_filter::LogFilterField) -> Self {
+        match field {
+            api_models::log_filter::LogFilterField::MerchantId => Self::MerchantId,
+            api_models::log_filter::LogFilterField::ProfileId => Self::ProfileId,
+            api_models::log_filter::LogFilterField::Connector => Self::Connector,
+            api_models::log_filter::LogFilterField::RequestPath => Self::RequestPath,
+        }
+    }
+}
+
+impl ForeignFrom<router_env::LogFilterField> for api_models::log_filter::LogFilterField {
+    fn foreign_from(field: router_env::LogFilterField) -> Self {
+        match field {
+            router_env::LogFilterField::MerchantId => Self::MerchantId,
+            router_env::LogFilterField::ProfileId => Self::ProfileId,
+            router_env::LogFilterField::Connector => Self::Connector,
+            router_env::LogFilterField::RequestPath => Self::RequestPath,
+        }
+    }
+}
+
+impl ForeignFrom<api_models::log_filter::LogFilterLevel> for router_env::LogFilterLevel {
+    fn foreign_from(level: api_models::log_filter::LogFilterLevel) -> Self {
+        match level {
+            api_models::log_filter::LogFilterLevel::Debug => Self::Debug,
+            api_models::log_filter::LogFilterLevel::Trace => Self::Trace,
+        }
+    }
+}
+
+impl ForeignFrom<router_env::LogFilterLevel> for api_models::log_filter::LogFilterLevel {
+    fn foreign_from(level: router_env::LogFilterLevel) -> Self {
+        match level {
+            router_env::LogFilterLevel::Debug => Self::Debug,
+            router_env::LogFilterLevel::Trace => Self::Trace,
+        }
+    }
+}
+
+impl ForeignFrom<router_env::LogFilterOverride> for api_models::log_filter::LogFilterResponse {
+    fn foreign_from(log_filter: router_env::LogFilterOverride) -> Self {
+        let expires_at = time::OffsetDateTime::from(log_filter.expires_at);
+        Self {
+            filter_id: log_filter.filter_id,
+            field: log_filter.field.foreign_into(),
+            value: log_filter.value,
+            level: log_filter.level.foreign_into(),
+            expires_at: time::PrimitiveDateTime::new(expires_at.date(), expires_at.time()),
         }
     }
 }
@@ -1378,6 +1586,7 @@ impl TryFrom<domain::Event> for api_models::webhook_events::EventListItemRespons
             is_delivery_successful: item.is_webhook_notified,
             initial_attempt_id,
             created: item.created_at,
+            webhook_endpoint_id: item.webhook_endpoint_id,
         })
     }
 }
@@ -937,6 +937,7 @@ where
             | enums::IntentStatus::Failed
             | enums::IntentStatus::PartiallyCaptured
             | enums::IntentStatus::RequiresMerchantAction
+            | enums::IntentStatus::Expired
     ) {
         let payments_response = crate::core::payments::transformers::payments_to_payments_response(
             payment_data,
@@ -2,14 +2,17 @@ use std::{collections::HashMap, sync::Arc};
 
 use api_models::user as user_api;
 use common_enums::UserAuthType;
-use common_utils::errors::CustomResult;
+use common_utils::{errors::CustomResult, pii};
 use diesel_models::{encryption::Encryption, enums::UserStatus, user_role::UserRole};
-use error_stack::ResultExt;
-use masking::{ExposeInterface, Secret};
-use redis_interface::RedisConnectionPool;
+use error_stack::{report, ResultExt};
+use masking::{ExposeInterface, PeekInterface, Secret};
+use redis_interface::{DelReply, RedisConnectionPool, SetnxReply};
 
 use crate::{
-    consts::user::{REDIS_SSO_PREFIX, REDIS_SSO_TTL},
+    consts::user::{
+        REDIS_SAML_ASSERTION_PREFIX, REDIS_SAML_ASSERTION_TTL, REDIS_SAML_CODE_PREFIX,
+        REDIS_SSO_PREFIX, REDIS_SSO_TTL,
+    },
     core::errors::{StorageError, UserErrors, UserResult},
     routes::SessionState,
     services::{
@@ -211,6 +214,7 @@ impl ForeignFrom<&user_api::AuthConfig> for UserAuthType {
     fn foreign_from(from: &user_api::AuthConfig) -> Self {
         match *from {
             user_api::AuthConfig::OpenIdConnect { .. } => Self::OpenIdConnect,
+            user_api::AuthConfig::Saml { .. } => Self::Saml,
             user_api::AuthConfig::Password => Self::Password,
             user_api::AuthConfig::MagicLink => Self::MagicLink,
         }
@@ -226,31 +230,59 @@ pub async fn construct_public_and_private_db_configs(
             private_config,
             public_config,
         } => {
-            let private_config_value = serde_json::to_value(private_config.clone())
-                .change_context(UserErrors::InternalServerError)
-                .attach_printable("Failed to convert auth config to json")?;
-
-            let encrypted_config = domain::types::encrypt::<serde_json::Value, masking::WithType>(
-                private_config_value.into(),
+            encrypt_private_and_serialize_public_config(
+                private_config,
+                public_config,
+                encryption_key,
+            )
+            .await
+        }
+        user_api::AuthConfig::Saml {
+            private_config,
+            public_config,
+        } => {
+            encrypt_private_and_serialize_public_config(
+                private_config,
+                public_config,
                 encryption_key,
             )
             .await
-            .change_context(UserErrors::InternalServerError)
-            .attach_printable("Failed to encrypt auth config")?;
-
-            Ok((
-                Some(encrypted_config.into()),
-                Some(
-                    serde_json::to_value(public_config.clone())
-                        .change_context(UserErrors::InternalServerError)
-                        .attach_printable("Failed to convert auth config to json")?,
-                ),
-            ))
         }
         user_api::AuthConfig::Password | user_api::AuthConfig::MagicLink => Ok((None, None)),
     }
 }
 
+async fn encrypt_private_and_serialize_public_config<P, Q>(
+    private_config: &P,
+    public_config: &Q,
+    encryption_key: &[u8],
+) -> UserResult<(Option<Encryption>, Option<serde_json::Value>)>
+where
+    P: serde::Serialize,
+    Q: serde::Serialize,
+{
+    let private_config_value = serde_json::to_value(private_config)
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to convert auth config to json")?;
+
+    let encrypted_config = domain::types::encrypt::<serde_json::Value, masking::WithType>(
+        private_config_value.into(),
+        encryption_key,
+    )
+    .await
+    .change_context(UserErrors::InternalServerError)
+    .attach_printable("Failed to encrypt auth config")?;
+
+    Ok((
+        Some(encrypted_config.into()),
+        Some(
+            serde_json::to_value(public_config)
+                .change_context(UserErrors::InternalServerError)
+                .attach_printable("Failed to convert auth config to json")?,
+        ),
+    ))
+}
+
 pub fn parse_value<T>(value: serde_json::Value, type_name: &str) -> UserResult<T>
 where
     T: serde::de::DeserializeOwned,
@@ -264,6 +296,24 @@ pub async fn decrypt_oidc_private_config(
     state: &SessionState,
     encrypted_config: Option<Encryption>,
 ) -> UserResult<user_api::OpenIdConnectPrivateConfig> {
+    decrypt_private_config(state, encrypted_config, "OpenIdConnectPrivateConfig").await
+}
+
+pub async fn decrypt_saml_private_config(
+    state: &SessionState,
+    encrypted_config: Option<Encryption>,
+) -> UserResult<user_api::SamlPrivateConfig> {
+    decrypt_private_config(state, encrypted_config, "SamlPrivateConfig").await
+}
+
+async fn decrypt_private_config<T>(
+    state: &SessionState,
+    encrypted_config: Option<Encryption>,
+    type_name: &str,
+) -> UserResult<T>
+where
+    T: serde::de::DeserializeOwned,
+{
     let user_auth_key = hex::decode(
         state
             .conf
@@ -288,9 +338,9 @@ pub async fn decrypt_oidc_private_config(
     .into_inner()
     .expose();
 
-    serde_json::from_value::<user_api::OpenIdConnectPrivateConfig>(private_config)
+    serde_json::from_value::<T>(private_config)
         .change_context(UserErrors::InternalServerError)
-        .attach_printable("unable to parse OpenIdConnectPrivateConfig")
+        .attach_printable(format!("unable to parse {type_name}"))
 }
 
 pub async fn set_sso_id_in_redis(
@@ -330,9 +380,120 @@ pub fn get_oidc_sso_redirect_url(state: &SessionState, provider: &str) -> String
     format!("{}/redirect/oidc/{}", state.conf.user.base_url, provider)
 }
 
+pub fn get_saml_sso_redirect_url(state: &SessionState) -> String {
+    format!("{}/redirect/saml", state.conf.user.base_url)
+}
+
+pub fn get_saml_sp_entity_id(state: &SessionState, auth_method_id: &str) -> String {
+    format!("{}/user/saml/{auth_method_id}/metadata", state.base_url)
+}
+
+pub fn get_saml_acs_url(state: &SessionState, auth_method_id: &str) -> String {
+    format!("{}/user/saml/{auth_method_id}/acs", state.base_url)
+}
+
+/// Marks the assertion as consumed, so that the same SAML response cannot be replayed
+pub async fn set_saml_assertion_id_in_redis(
+    state: &SessionState,
+    assertion_id: &str,
+) -> UserResult<()> {
+    let connection = get_redis_connection(state)?;
+    let key = format!("{}{assertion_id}", REDIS_SAML_ASSERTION_PREFIX);
+    let reply = connection
+        .set_key_if_not_exists_with_expiry(&key, true, Some(REDIS_SAML_ASSERTION_TTL))
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to set saml assertion id in redis")?;
+
+    match reply {
+        SetnxReply::KeySet => Ok(()),
+        SetnxReply::KeyNotSet => {
+            Err(UserErrors::SSOFailed).attach_printable("SAML assertion has already been consumed")
+        }
+    }
+}
+
+/// Details stored against a SAML code, the code can only be redeemed through the same auth method
+/// and state that the SAML response was received for
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct SamlCodeData {
+    email: Secret<String, pii::EmailStrategy>,
+    authentication_method_id: String,
+    state: Secret<String>,
+}
+
+pub async fn set_saml_code_in_redis(
+    state: &SessionState,
+    code: Secret<String>,
+    email: domain::UserEmail,
+    authentication_method_id: &str,
+    sso_state: Secret<String>,
+) -> UserResult<()> {
+    let connection = get_redis_connection(state)?;
+    let key = get_saml_code_key(&code.expose());
+    let code_data = SamlCodeData {
+        email: email.get_secret(),
+        authentication_method_id: authentication_method_id.to_string(),
+        state: sso_state,
+    };
+    connection
+        .serialize_and_set_key_with_expiry(&key, code_data, REDIS_SSO_TTL)
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to set saml code in redis")
+}
+
+/// Codes are single use, the code is only redeemed by the request which deletes it from redis
+pub async fn get_email_from_saml_code(
+    state: &SessionState,
+    code: Secret<String>,
+    authentication_method_id: &str,
+    sso_state: &Secret<String>,
+) -> UserResult<domain::UserEmail> {
+    let connection = get_redis_connection(state)?;
+    let key = get_saml_code_key(&code.expose());
+    let code_data = connection
+        .get_and_deserialize_key::<SamlCodeData>(&key, "SamlCodeData")
+        .await
+        .map_err(|error| match error.current_context() {
+            redis_interface::errors::RedisError::NotFound => error
+                .change_context(UserErrors::SSOFailed)
+                .attach_printable("Cannot find saml code in redis. Code invalid or expired"),
+            _ => error
+                .change_context(UserErrors::InternalServerError)
+                .attach_printable("Failed to get saml code from redis"),
+        })?;
+
+    match connection
+        .delete_key(&key)
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to delete saml code from redis")?
+    {
+        DelReply::KeyDeleted => (),
+        DelReply::KeyNotDeleted => {
+            return Err(report!(UserErrors::SSOFailed))
+                .attach_printable("SAML code has already been redeemed");
+        }
+    }
+
+    if code_data.authentication_method_id != authentication_method_id
+        || code_data.state.peek() != sso_state.peek()
+    {
+        return Err(report!(UserErrors::SSOFailed))
+            .attach_printable("SAML code does not belong to the auth method or state");
+    }
+
+    domain::UserEmail::new(code_data.email)
+}
+
+fn get_saml_code_key(code: &str) -> String {
+    format!("{}{code}", REDIS_SAML_CODE_PREFIX)
+}
+
 pub fn is_sso_auth_type(auth_type: &UserAuthType) -> bool {
     match auth_type {
-        UserAuthType::OpenIdConnect => true,
+        UserAuthType::OpenIdConnect | UserAuthType::Saml => true,
         UserAuthType::Password | UserAuthType::MagicLink => false,
     }
 }
@@ -47,6 +47,7 @@ impl From<Permission> for user_role_api::Permission {
             Permission::WebhookEventWrite => Self::WebhookEventWrite,
             Permission::PayoutRead => Self::PayoutRead,
             Permission::PayoutWrite => Self::PayoutWrite,
+            Permission::AuditLogRead => Self::AuditLogRead,
         }
     }
 }
@@ -2,8 +2,18 @@
 pub mod api_key_expiry;
 #[cfg(feature = "payouts")]
 pub mod attach_payout_account_workflow;
+pub mod auto_void;
+pub mod card_account_updater;
+pub mod dispute_deadline_reminder;
+pub mod network_tokenization;
 pub mod outgoing_webhook_retry;
+pub mod payment_intent_expiry;
 pub mod payment_method_status_update;
 pub mod payment_sync;
+#[cfg(feature = "payouts")]
+pub mod payout_batch;
 pub mod refund_router;
+pub mod scheduled_capture;
+pub mod smart_retry;
+pub mod subscription_billing;
 pub mod tokenized_data;
@@ -0,0 +1,130 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+use crate::{
+    core::payments::{self as payment_flows, operations},
+    db::StorageInterface,
+    errors,
+    routes::SessionState,
+    services,
+    types::{
+        api,
+        storage::{self, enums, payment_attempt::AutoVoidTrackingData},
+    },
+};
+
+const AUTO_VOID_CANCELLATION_REASON: &str = "auto_void";
+
+pub struct AutoVoidWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for AutoVoidWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: AutoVoidTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("AutoVoidTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let payment_attempt = db
+            .find_payment_attempt_by_attempt_id_merchant_id(
+                &tracking_data.attempt_id,
+                &tracking_data.merchant_id,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+
+        // The payment has either been captured, voided or has failed since the task was created
+        if payment_attempt.status != enums::AttemptStatus::Authorized {
+            logger::info!(
+                payment_id = %payment_attempt.payment_id,
+                attempt_status = %payment_attempt.status,
+                "Payment is no longer voidable, finishing auto void task"
+            );
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    business_status::RESOURCE_STATUS_MISMATCH,
+                )
+                .await
+                .map_err(Into::into);
+        }
+
+        let cancel_request = api::PaymentsCancelRequest {
+            payment_id: tracking_data.payment_id.clone(),
+            cancellation_reason: Some(AUTO_VOID_CANCELLATION_REASON.to_string()),
+            merchant_connector_details: None,
+        };
+
+        // The authorization is voided through the same operation as voids performed through the
+        // API, so that the payment is updated identically
+        let void_result = Box::pin(
+            payment_flows::payments_operation_core::<api::Void, _, _, _>(
+                state,
+                state.get_req_state(),
+                merchant_account,
+                key_store,
+                operations::PaymentCancel,
+                cancel_request,
+                payment_flows::CallConnectorAction::Trigger,
+                services::AuthFlow::Merchant,
+                None,
+                api::HeaderPayload::default(),
+            ),
+        )
+        .await;
+
+        let business_status = match void_result {
+            Ok((payment_data, _, _, _, _))
+                if payment_data.payment_attempt.status != enums::AttemptStatus::VoidFailed =>
+            {
+                business_status::COMPLETED_BY_PT
+            }
+            Ok(_) => business_status::FAILURE,
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    payment_id = %tracking_data.payment_id,
+                    "Failed to void authorization"
+                );
+                business_status::FAILURE
+            }
+        };
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,89 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use error_stack::ResultExt;
+use router_env::logger;
+use scheduler::{consumer::workflows::ProcessTrackerWorkflow, errors};
+
+use crate::{
+    core::payment_methods::card_account_updater,
+    errors as core_errors,
+    routes::SessionState,
+    types::storage::{self, CardAccountUpdaterTrackingData},
+};
+
+pub struct CardAccountUpdaterWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for CardAccountUpdaterWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: CardAccountUpdaterTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("CardAccountUpdaterTrackingData")?;
+
+        // The task is resumed when a card is saved after the updater has been enabled again
+        if !state.conf.card_account_updater.enabled {
+            logger::info!(
+                merchant_id = %tracking_data.merchant_id,
+                "Card account updater is disabled, finishing card account updater task"
+            );
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await
+                .map_err(Into::into);
+        }
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        card_account_updater::process_card_account_updates(state, &merchant_account, &key_store)
+            .await?;
+
+        db.as_scheduler()
+            .reset_process(process, get_next_schedule_time(state))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> core_errors::CustomResult<(), errors::ProcessTrackerError> {
+        logger::error!(
+            pt.id = %process.id,
+            ?error,
+            "Failed to execute card account updater workflow, rescheduling task"
+        );
+
+        // The task is recurring, a failed run must not prevent the later runs
+        state
+            .store
+            .as_scheduler()
+            .reset_process(process, get_next_schedule_time(state))
+            .await
+            .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)
+    }
+}
+
+fn get_next_schedule_time(state: &SessionState) -> time::PrimitiveDateTime {
+    common_utils::date_time::now().saturating_add(time::Duration::hours(i64::from(
+        state.conf.card_account_updater.schedule_interval_hours,
+    )))
+}
@@ -0,0 +1,404 @@
+use std::collections::HashMap;
+
+use common_utils::ext_traits::{StringExt, ValueExt};
+use diesel_models::{
+    enums as storage_enums, process_tracker::business_status, DisputeDeadlineReminderTrackingData,
+};
+use error_stack::ResultExt;
+use router_env::{logger, metrics::add_attributes};
+use scheduler::{consumer, workflows::ProcessTrackerWorkflow};
+
+use crate::{
+    core::{disputes, webhooks as webhooks_core},
+    db::StorageInterface,
+    errors,
+    routes::{metrics, SessionState},
+    services::ApplicationResponse,
+    types::{
+        api::{self, disputes::DisputeId},
+        domain, storage,
+        transformers::ForeignFrom,
+    },
+};
+#[cfg(feature = "email")]
+use crate::{
+    services::email::types::DisputeDeadlineReminder,
+    types::{api::MerchantDetails, domain::UserEmail},
+};
+
+pub struct DisputeDeadlineReminderWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for DisputeDeadlineReminderWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: DisputeDeadlineReminderTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("DisputeDeadlineReminderTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let dispute = db
+            .find_dispute_by_merchant_id_dispute_id(
+                &tracking_data.merchant_id,
+                &tracking_data.dispute_id,
+            )
+            .await?;
+
+        // The dispute has either been challenged, accepted or closed since the task was created,
+        // no further reminders are required
+        let challenge_required_by = match dispute.challenge_required_by {
+            Some(challenge_required_by)
+                if dispute.dispute_stage == storage_enums::DisputeStage::Dispute
+                    && dispute.dispute_status == storage_enums::DisputeStatus::DisputeOpened =>
+            {
+                challenge_required_by
+            }
+            _ => {
+                logger::info!(
+                    dispute_id = %dispute.dispute_id,
+                    dispute_stage = %dispute.dispute_stage,
+                    dispute_status = %dispute.dispute_status,
+                    "Dispute is no longer open, finishing dispute deadline reminder task"
+                );
+                return db
+                    .as_scheduler()
+                    .finish_process_with_business_status(
+                        process,
+                        business_status::RESOURCE_STATUS_MISMATCH,
+                    )
+                    .await
+                    .map_err(Into::into);
+            }
+        };
+
+        if should_auto_accept_dispute(db, &dispute).await {
+            match Box::pin(disputes::accept_dispute(
+                state.clone(),
+                merchant_account.clone(),
+                key_store.clone(),
+                DisputeId {
+                    dispute_id: dispute.dispute_id.clone(),
+                },
+            ))
+            .await
+            {
+                Ok(ApplicationResponse::Json(dispute_response)) => {
+                    let event_type =
+                        storage_enums::EventType::foreign_from(dispute_response.dispute_status);
+                    trigger_dispute_webhook(
+                        state,
+                        merchant_account,
+                        &key_store,
+                        &dispute,
+                        dispute.dispute_id.clone(),
+                        event_type,
+                        dispute_response,
+                    )
+                    .await;
+
+                    return db
+                        .as_scheduler()
+                        .finish_process_with_business_status(
+                            process,
+                            business_status::COMPLETED_BY_PT,
+                        )
+                        .await
+                        .map_err(Into::into);
+                }
+                Ok(_) => {
+                    logger::error!(
+                        dispute_id = %dispute.dispute_id,
+                        "Unexpected response received when auto-accepting dispute"
+                    );
+                }
+                // The merchant still has to act on the dispute, so fall back to sending the reminder
+                Err(error) => {
+                    logger::error!(
+                        ?error,
+                        dispute_id = %dispute.dispute_id,
+                        "Failed to auto-accept dispute, sending reminder instead"
+                    );
+                }
+            }
+        }
+
+        let retry_count = process.retry_count;
+        let reminder_hours = *tracking_data
+            .reminder_hours
+            .get(
+                usize::try_from(retry_count)
+                    .map_err(|_| errors::ProcessTrackerError::TypeConversionError)?,
+            )
+            .ok_or(errors::ProcessTrackerError::EApiErrorResponse)?;
+
+        let dispute_response = api_models::disputes::DisputeResponse::foreign_from(dispute.clone());
+        trigger_dispute_webhook(
+            state,
+            merchant_account.clone(),
+            &key_store,
+            &dispute,
+            // Each reminder is a distinct event, the idempotent event ID is derived from this
+            format!("{}_{reminder_hours}h", dispute.dispute_id),
+            storage_enums::EventType::DisputeEvidenceDue,
+            dispute_response,
+        )
+        .await;
+
+        #[cfg(feature = "email")]
+        send_dispute_deadline_reminder_email(
+            state,
+            &merchant_account,
+            &dispute,
+            challenge_required_by,
+            reminder_hours,
+        )
+        .await;
+
+        // If all the reminders have been sent, then retry_count would be equal to length of the
+        // reminder_hours vector
+        match tracking_data.reminder_hours.get(
+            usize::try_from(retry_count + 1)
+                .map_err(|_| errors::ProcessTrackerError::TypeConversionError)?,
+        ) {
+            None => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await?
+            }
+            Some(next_reminder_hours) => {
+                // The deadline may have been extended by the connector, so the schedule time is
+                // calculated from the current deadline on the dispute
+                let updated_process_tracker_data = storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: Some(retry_count + 1),
+                    schedule_time: Some(disputes::get_dispute_deadline_reminder_time(
+                        challenge_required_by,
+                        *next_reminder_hours,
+                    )),
+                    tracking_data: None,
+                    business_status: None,
+                    status: Some(storage_enums::ProcessTrackerStatus::New),
+                    updated_at: Some(common_utils::date_time::now()),
+                };
+                db.process_tracker_update_process_status_by_ids(
+                    vec![process.id.clone()],
+                    updated_process_tracker_data,
+                )
+                .await?;
+                // Remaining tasks are re-scheduled, so will be resetting the added count
+                metrics::TASKS_RESET_COUNT.add(
+                    &metrics::CONTEXT,
+                    1,
+                    &add_attributes([("flow", "DisputeDeadlineReminder")]),
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+/// Check whether the dispute falls under the auto-accept threshold configured by the merchant.
+///
+/// The threshold can be configured in configs with this key:
+/// `dispute_auto_accept_threshold_{merchant_id}`.
+///
+/// ```json
+/// {
+///   "USD": 2500,
+///   "EUR": 2000
+/// }
+/// ```
+///
+/// This configuration value represents the maximum dispute amount (in minor units) per currency
+/// for which disputes are accepted automatically instead of sending reminders. Disputes in
+/// currencies not present in the configuration are never auto-accepted.
+async fn should_auto_accept_dispute(db: &dyn StorageInterface, dispute: &storage::Dispute) -> bool {
+    let key = format!("dispute_auto_accept_threshold_{}", dispute.merchant_id);
+
+    let thresholds: errors::CustomResult<HashMap<String, i64>, errors::StorageError> = db
+        .find_config_by_key(&key)
+        .await
+        .map(|value| value.config)
+        .and_then(|config| {
+            config
+                .parse_struct("DisputeAutoAcceptThreshold")
+                .change_context(errors::StorageError::DeserializationFailed)
+        });
+
+    match thresholds {
+        Ok(thresholds) => {
+            is_within_auto_accept_threshold(&thresholds, &dispute.currency, dispute.dispute_amount)
+        }
+        Err(error) => {
+            if error.current_context().is_db_not_found() {
+                logger::debug!("Dispute auto-accept config `{key}` not found, ignoring");
+            } else {
+                logger::error!(?error, "Failed to read dispute auto-accept config `{key}`");
+            }
+            false
+        }
+    }
+}
+
+fn is_within_auto_accept_threshold(
+    thresholds: &HashMap<String, i64>,
+    currency: &str,
+    dispute_amount: i64,
+) -> bool {
+    thresholds
+        .get(currency)
+        .is_some_and(|threshold| dispute_amount <= *threshold)
+}
+
+async fn trigger_dispute_webhook(
+    state: &SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    dispute: &storage::Dispute,
+    primary_object_id: String,
+    event_type: storage_enums::EventType,
+    dispute_response: api_models::disputes::DisputeResponse,
+) {
+    let Some(profile_id) = dispute.profile_id.as_ref() else {
+        logger::warn!(
+            dispute_id = %dispute.dispute_id,
+            "Business profile not found for dispute, skipping outgoing webhook"
+        );
+        return;
+    };
+
+    let business_profile = match state
+        .store
+        .find_business_profile_by_profile_id(profile_id)
+        .await
+    {
+        Ok(business_profile) => business_profile,
+        Err(error) => {
+            logger::error!(?error, "Failed to fetch business profile for dispute");
+            return;
+        }
+    };
+
+    let result = Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account,
+        business_profile,
+        key_store,
+        event_type,
+        storage_enums::EventClass::Disputes,
+        primary_object_id,
+        storage_enums::EventObjectType::DisputeDetails,
+        api::OutgoingWebhookContent::DisputeDetails(Box::new(dispute_response)),
+        Some(dispute.created_at),
+    ))
+    .await;
+
+    if let Err(error) = result {
+        logger::error!(
+            ?error,
+            dispute_id = %dispute.dispute_id,
+            "Failed to trigger outgoing webhook for dispute deadline reminder"
+        );
+    }
+}
+
+#[cfg(feature = "email")]
+async fn send_dispute_deadline_reminder_email(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    dispute: &storage::Dispute,
+    challenge_required_by: time::PrimitiveDateTime,
+    hours_remaining: u16,
+) {
+    let primary_email = merchant_account
+        .merchant_details
+        .clone()
+        .map(|merchant_details| merchant_details.parse_value::<MerchantDetails>("MerchantDetails"))
+        .transpose()
+        .map(|merchant_details| merchant_details.and_then(|details| details.primary_email));
+
+    let recipient_email = match primary_email {
+        Ok(Some(email)) => match UserEmail::from_pii_email(email) {
+            Ok(recipient_email) => recipient_email,
+            Err(error) => {
+                logger::error!(?error, "Failed to convert merchant's primary email");
+                return;
+            }
+        },
+        Ok(None) => {
+            logger::info!(
+                merchant_id = %merchant_account.merchant_id,
+                "Merchant primary email not found, skipping dispute deadline reminder email"
+            );
+            return;
+        }
+        Err(error) => {
+            logger::error!(?error, "Failed to parse merchant details");
+            return;
+        }
+    };
+
+    let email_contents = DisputeDeadlineReminder {
+        recipient_email,
+        subject: "Dispute Evidence Due",
+        dispute_id: dispute.dispute_id.clone(),
+        payment_id: dispute.payment_id.clone(),
+        amount: dispute.amount.clone(),
+        currency: dispute.currency.clone(),
+        challenge_required_by: challenge_required_by.to_string(),
+        hours_remaining,
+    };
+
+    if let Err(error) = state
+        .email_client
+        .clone()
+        .compose_and_send_email(
+            Box::new(email_contents),
+            state.conf.proxy.https_url.as_ref(),
+        )
+        .await
+    {
+        logger::error!(?error, "Failed to send dispute deadline reminder email");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_auto_accept_threshold() {
+        let thresholds = HashMap::from([("USD".to_string(), 2500), ("EUR".to_string(), 2000)]);
+
+        assert!(is_within_auto_accept_threshold(&thresholds, "USD", 2500));
+        assert!(is_within_auto_accept_threshold(&thresholds, "EUR", 100));
+        assert!(!is_within_auto_accept_threshold(&thresholds, "USD", 2501));
+        assert!(!is_within_auto_accept_threshold(&thresholds, "GBP", 1));
+        assert!(!is_within_auto_accept_threshold(&HashMap::new(), "USD", 0));
+    }
+}
@@ -0,0 +1,129 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use router_env::logger;
+use scheduler::{
+    consumer::{types::process_data, workflows::ProcessTrackerWorkflow},
+    errors, utils as pt_utils,
+};
+
+use crate::{
+    core::payment_methods::network_tokenization,
+    errors as core_errors,
+    routes::SessionState,
+    types::storage::{self, NetworkTokenizationTrackingData},
+};
+
+pub struct NetworkTokenizationWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for NetworkTokenizationWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: NetworkTokenizationTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("NetworkTokenizationTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let payment_method = match db
+            .find_payment_method(
+                &tracking_data.payment_method_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+        {
+            Ok(payment_method) => payment_method,
+            // The payment method was deleted before the token could be provisioned
+            Err(error) if error.current_context().is_db_not_found() => {
+                return db
+                    .as_scheduler()
+                    .finish_process_with_business_status(process, "PAYMENT_METHOD_NOT_FOUND")
+                    .await
+                    .map_err(Into::into);
+            }
+            Err(error) => return Err(error.into()),
+        };
+
+        if !network_tokenization::is_network_token_pending(&payment_method) {
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(process, "PROCESS_ALREADY_COMPLETED")
+                .await
+                .map_err(Into::into);
+        }
+
+        let result = network_tokenization::provision_network_token_for_payment_method(
+            state,
+            &merchant_account,
+            &key_store,
+            payment_method,
+        )
+        .await;
+
+        match result {
+            Ok(()) => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await?
+            }
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    payment_method_id = %tracking_data.payment_method_id,
+                    "Failed to provision network token"
+                );
+
+                let mapping = process_data::PaymentMethodsPTMapping::default();
+                let time_delta = if process.retry_count == 0 {
+                    Some(mapping.default_mapping.start_after)
+                } else {
+                    pt_utils::get_delay(
+                        process.retry_count + 1,
+                        &mapping.default_mapping.frequencies,
+                    )
+                };
+
+                // Payments made with the payment method fall back to the card number once the
+                // retries are exhausted
+                match pt_utils::get_time_from_delta(time_delta) {
+                    Some(schedule_time) => {
+                        db.as_scheduler()
+                            .retry_process(process, schedule_time)
+                            .await?
+                    }
+                    None => {
+                        db.as_scheduler()
+                            .finish_process_with_business_status(process, "RETRIES_EXCEEDED")
+                            .await?
+                    }
+                }
+            }
+        };
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> core_errors::CustomResult<(), errors::ProcessTrackerError> {
+        logger::error!(pt.id = %process.id, ?error, "Failed to execute network tokenization workflow");
+        Ok(())
+    }
+}
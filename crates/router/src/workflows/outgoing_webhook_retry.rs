@@ -54,11 +54,6 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
             .await?;
 
         let event_id = webhooks_core::utils::generate_event_id();
-        let idempotent_event_id = webhooks_core::utils::get_idempotent_event_id(
-            &tracking_data.primary_object_id,
-            tracking_data.event_type,
-            delivery_attempt,
-        );
 
         let initial_event = match &tracking_data.initial_attempt_id {
             Some(initial_attempt_id) => {
@@ -85,6 +80,13 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
             }
         };
 
+        let idempotent_event_id = webhooks_core::utils::get_idempotent_event_id(
+            &tracking_data.primary_object_id,
+            tracking_data.event_type,
+            delivery_attempt,
+            initial_event.webhook_endpoint_id.as_deref(),
+        );
+
         let now = common_utils::date_time::now();
         let new_event = domain::Event {
             event_id,
@@ -103,6 +105,7 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
             response: None,
             delivery_attempt: Some(delivery_attempt),
             metadata: initial_event.metadata,
+            webhook_endpoint_id: initial_event.webhook_endpoint_id,
         };
 
         let event = db
@@ -161,10 +164,21 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
                             timestamp: event.created_at,
                         };
 
+                        let signing_keys = webhooks_core::get_webhook_signing_keys(
+                            state,
+                            &business_profile,
+                            &key_store,
+                        )
+                        .await
+                        .map_err(|error| {
+                            logger::error!(?error, "Failed to fetch webhook signing keys");
+                            errors::ProcessTrackerError::EApiErrorResponse
+                        })?;
+
                         let request_content = webhooks_core::get_outgoing_webhook_request(
                             &merchant_account,
                             outgoing_webhook,
-                            business_profile.payment_response_hash_key.as_deref(),
+                            &signing_keys,
                         )
                         .map_err(|error| {
                             logger::error!(
@@ -335,12 +349,20 @@ async fn get_outgoing_webhook_content_and_event_type(
         core::{
             disputes::retrieve_dispute,
             mandate::get_mandate,
+            payment_methods::{
+                card_account_updater::get_payment_method_id_from_event_object_id,
+                cards::retrieve_payment_method,
+            },
             payments::{payments_core, CallConnectorAction, PaymentStatus},
             refunds::refund_retrieve_core,
+            subscriptions::{
+                get_subscription_event_type, get_subscription_id_from_event_object_id,
+                retrieve_subscription,
+            },
         },
         services::{ApplicationResponse, AuthFlow},
         types::{
-            api::{DisputeId, PSync},
+            api::{DisputeId, PSync, PaymentMethodId},
             transformers::ForeignFrom,
         },
     };
@@ -511,5 +533,72 @@ async fn get_outgoing_webhook_content_and_event_type(
                 event_type,
             ))
         }
+
+        diesel_models::enums::EventClass::PaymentMethods => {
+            let payment_method_id =
+                get_payment_method_id_from_event_object_id(&tracking_data.primary_object_id)
+                    .to_owned();
+            let request = PaymentMethodId { payment_method_id };
+
+            let payment_method_response =
+                match retrieve_payment_method(state, request, key_store, merchant_account).await? {
+                    ApplicationResponse::Json(payment_method_response)
+                    | ApplicationResponse::JsonWithHeaders((payment_method_response, _)) => {
+                        Ok(payment_method_response)
+                    }
+                    ApplicationResponse::StatusOk
+                    | ApplicationResponse::TextPlain(_)
+                    | ApplicationResponse::JsonForRedirection(_)
+                    | ApplicationResponse::Form(_)
+                    | ApplicationResponse::GenericLinkForm(_)
+                    | ApplicationResponse::PaymentLinkForm(_)
+                    | ApplicationResponse::FileData(_) => {
+                        Err(errors::ProcessTrackerError::ResourceFetchingFailed {
+                            resource_name: tracking_data.primary_object_id.clone(),
+                        })
+                    }
+                }
+                .map(Box::new)?;
+
+            // Payment method webhooks are only raised by the card account updater
+            Ok((
+                OutgoingWebhookContent::PaymentMethodDetails(payment_method_response),
+                Some(EventType::PaymentMethodUpdated),
+            ))
+        }
+
+        diesel_models::enums::EventClass::Subscriptions => {
+            let subscription_id =
+                get_subscription_id_from_event_object_id(&tracking_data.primary_object_id)
+                    .to_owned();
+            let request = api_models::subscriptions::SubscriptionId { subscription_id };
+
+            let subscription_response =
+                match retrieve_subscription(state, merchant_account, request).await? {
+                    ApplicationResponse::Json(subscription_response)
+                    | ApplicationResponse::JsonWithHeaders((subscription_response, _)) => {
+                        Ok(subscription_response)
+                    }
+                    ApplicationResponse::StatusOk
+                    | ApplicationResponse::TextPlain(_)
+                    | ApplicationResponse::JsonForRedirection(_)
+                    | ApplicationResponse::Form(_)
+                    | ApplicationResponse::GenericLinkForm(_)
+                    | ApplicationResponse::PaymentLinkForm(_)
+                    | ApplicationResponse::FileData(_) => {
+                        Err(errors::ProcessTrackerError::ResourceFetchingFailed {
+                            resource_name: tracking_data.primary_object_id.clone(),
+                        })
+                    }
+                }
+                .map(Box::new)?;
+            let event_type = get_subscription_event_type(subscription_response.status);
+            logger::debug!(current_resource_status=%subscription_response.status);
+
+            Ok((
+                OutgoingWebhookContent::SubscriptionDetails(subscription_response),
+                event_type,
+            ))
+        }
     }
 }
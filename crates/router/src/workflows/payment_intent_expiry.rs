@@ -0,0 +1,141 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+use crate::{
+    core::payments::{self as payment_flows, operations},
+    db::StorageInterface,
+    errors,
+    routes::SessionState,
+    services,
+    types::{
+        api,
+        storage::{self, enums, PaymentIntentExpiryTrackingData},
+    },
+};
+
+pub struct PaymentIntentExpiryWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for PaymentIntentExpiryWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: PaymentIntentExpiryTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PaymentIntentExpiryTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                &tracking_data.payment_id,
+                &tracking_data.merchant_id,
+                &key_store,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+
+        // The payment has been confirmed or cancelled since the task was created
+        if !matches!(
+            payment_intent.status,
+            enums::IntentStatus::RequiresPaymentMethod | enums::IntentStatus::RequiresConfirmation
+        ) {
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    business_status::RESOURCE_STATUS_MISMATCH,
+                )
+                .await
+                .map_err(Into::into);
+        }
+
+        // The session may have been extended since the task was created
+        if let Some(session_expiry) = payment_intent
+            .session_expiry
+            .filter(|session_expiry| *session_expiry > common_utils::date_time::now())
+        {
+            return db
+                .as_scheduler()
+                .retry_process(process, session_expiry)
+                .await
+                .map_err(Into::into);
+        }
+
+        db.update_payment_intent(
+            payment_intent,
+            storage::PaymentIntentUpdate::PGStatusUpdate {
+                status: enums::IntentStatus::Expired,
+                incremental_authorization_allowed: None,
+                updated_by: merchant_account.storage_scheme.to_string(),
+            },
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await?;
+
+        // Retrieving the payment triggers the `PaymentExpired` outgoing webhook. The connector is
+        // not called, since the payment was never confirmed.
+        let retrieve_request = api::PaymentsRetrieveRequest {
+            resource_id: api::PaymentIdType::PaymentIntentId(tracking_data.payment_id.clone()),
+            merchant_id: Some(tracking_data.merchant_id.clone()),
+            force_sync: false,
+            ..Default::default()
+        };
+        if let Err(error) = Box::pin(
+            payment_flows::payments_operation_core::<api::PSync, _, _, _>(
+                state,
+                state.get_req_state(),
+                merchant_account,
+                key_store,
+                operations::PaymentStatus,
+                retrieve_request,
+                payment_flows::CallConnectorAction::Avoid,
+                services::AuthFlow::Merchant,
+                None,
+                api::HeaderPayload::default(),
+            ),
+        )
+        .await
+        {
+            logger::error!(
+                ?error,
+                payment_id = %tracking_data.payment_id,
+                "Failed to trigger webhook for expired payment"
+            );
+        }
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
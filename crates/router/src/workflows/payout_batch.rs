@@ -0,0 +1,97 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors,
+};
+
+use crate::{
+    core::payouts::batch,
+    errors as core_errors,
+    routes::SessionState,
+    types::storage::{self, enums, PayoutBatchTrackingData},
+};
+
+pub struct PayoutBatchWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for PayoutBatchWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: PayoutBatchTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PayoutBatchTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let payout_batch = db
+            .find_payout_batch_by_merchant_id_batch_id(
+                &tracking_data.merchant_id,
+                &tracking_data.batch_id,
+            )
+            .await?;
+
+        // The batch has been cancelled, or processed by an earlier run of the task
+        if payout_batch.status.is_terminal() {
+            logger::info!(
+                batch_id = %payout_batch.batch_id,
+                status = %payout_batch.status,
+                "Payout batch is no longer pending, finishing payout batch task"
+            );
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    business_status::RESOURCE_STATUS_MISMATCH,
+                )
+                .await
+                .map_err(Into::into);
+        }
+
+        let payout_batch = if payout_batch.status == enums::PayoutBatchStatus::Pending {
+            db.update_payout_batch(
+                payout_batch,
+                storage::PayoutBatchUpdate::StatusUpdate {
+                    status: enums::PayoutBatchStatus::Processing,
+                },
+            )
+            .await?
+        } else {
+            payout_batch
+        };
+
+        // Rows are only processed while they are pending, so a run of the task which is retried
+        // after failing midway resumes from the rows which are yet to be processed
+        batch::process_payout_batch(state, &merchant_account, &key_store, payout_batch).await?;
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> core_errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
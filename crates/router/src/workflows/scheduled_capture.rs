@@ -0,0 +1,263 @@
+use std::str::FromStr;
+
+use common_utils::ext_traits::{StringExt, ValueExt};
+use diesel_models::process_tracker::business_status;
+use error_stack::ResultExt;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, types::process_data, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors, utils as scheduler_utils,
+};
+
+use crate::{
+    core::payments::{self as payment_flows, helpers, operations},
+    db::StorageInterface,
+    errors,
+    routes::SessionState,
+    services,
+    types::{
+        api,
+        storage::{self, enums, payment_attempt::ScheduledCaptureTrackingData},
+    },
+};
+
+pub struct ScheduledCaptureWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for ScheduledCaptureWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: ScheduledCaptureTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("ScheduledCaptureTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let payment_attempt = db
+            .find_payment_attempt_by_attempt_id_merchant_id(
+                &tracking_data.attempt_id,
+                &tracking_data.merchant_id,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+
+        // The payment has either been captured, voided or has failed since the task was created
+        if payment_attempt.status != enums::AttemptStatus::Authorized {
+            logger::info!(
+                payment_id = %payment_attempt.payment_id,
+                attempt_status = %payment_attempt.status,
+                "Payment is no longer capturable, finishing scheduled capture task"
+            );
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    business_status::RESOURCE_STATUS_MISMATCH,
+                )
+                .await
+                .map_err(Into::into);
+        }
+
+        let connector = payment_attempt
+            .connector
+            .clone()
+            .ok_or(sch_errors::ProcessTrackerError::MissingRequiredField)?;
+
+        let capture_request = api::PaymentsCaptureRequest {
+            payment_id: tracking_data.payment_id.clone(),
+            merchant_id: Some(tracking_data.merchant_id.clone()),
+            ..Default::default()
+        };
+
+        // The `PaymentCaptured` outgoing webhook is triggered by the payments core, as with
+        // captures performed through the API
+        let capture_result = Box::pin(payment_flows::payments_operation_core::<
+            api::Capture,
+            _,
+            _,
+            _,
+        >(
+            state,
+            state.get_req_state(),
+            merchant_account.clone(),
+            key_store,
+            operations::PaymentCapture,
+            capture_request,
+            payment_flows::CallConnectorAction::Trigger,
+            services::AuthFlow::Merchant,
+            None,
+            api::HeaderPayload::default(),
+        ))
+        .await;
+
+        let payment_attempt = match capture_result {
+            Ok((payment_data, _, _, _, _)) => payment_data.payment_attempt,
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    payment_id = %tracking_data.payment_id,
+                    "Failed to perform scheduled capture"
+                );
+                retry_capture_task(db, &connector, &tracking_data.merchant_id, process).await?;
+                return Ok(());
+            }
+        };
+
+        match payment_attempt.status {
+            // The capture failed, but the payment may still be captured later on
+            enums::AttemptStatus::Authorized => {
+                if should_retry_capture(state, &connector, &payment_attempt).await {
+                    retry_capture_task(db, &connector, &tracking_data.merchant_id, process).await?;
+                } else {
+                    logger::info!(
+                        payment_id = %payment_attempt.payment_id,
+                        error_code = ?payment_attempt.error_code,
+                        "Scheduled capture is not to be retried as per GSM decision"
+                    );
+                    db.as_scheduler()
+                        .finish_process_with_business_status(process, business_status::FAILURE)
+                        .await?;
+                }
+            }
+            enums::AttemptStatus::Failure | enums::AttemptStatus::CaptureFailed => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::FAILURE)
+                    .await?
+            }
+            // The capture has either succeeded or is being processed by the connector, the
+            // outcome would be obtained through payment sync or incoming webhooks
+            _ => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await?
+            }
+        };
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+/// Decide whether a failed capture has to be retried, based on the GSM rule configured for the
+/// connector error. Captures are retried if no GSM rule exists for the error, since the payment
+/// is still capturable.
+async fn should_retry_capture(
+    state: &SessionState,
+    connector: &str,
+    payment_attempt: &storage::PaymentAttempt,
+) -> bool {
+    let Some(gsm) = helpers::get_gsm_record(
+        state,
+        payment_attempt.error_code.clone(),
+        payment_attempt.error_message.clone(),
+        connector.to_string(),
+        "Capture".to_string(),
+    )
+    .await
+    else {
+        return true;
+    };
+
+    match api_models::gsm::GsmDecision::from_str(gsm.decision.as_str()) {
+        Ok(
+            api_models::gsm::GsmDecision::Retry
+            | api_models::gsm::GsmDecision::Requeue
+            | api_models::gsm::GsmDecision::RetryLater,
+        ) => true,
+        Ok(api_models::gsm::GsmDecision::DoDefault) => false,
+        Err(error) => {
+            logger::warn!(
+                ?error,
+                "Failed to parse GSM decision, retrying scheduled capture"
+            );
+            true
+        }
+    }
+}
+
+/// Get the schedule time for retrying a failed capture
+///
+/// The schedule time can be configured in configs by this key `pt_mapping_capture_{connector}`,
+/// and uses the same format as the payment sync mapping:
+/// ```json
+/// {
+///     "default_mapping": {
+///         "start_after": 60,
+///         "frequency": [300, 3600],
+///         "count": [3, 5]
+///     },
+///     "max_retries_count": 8
+/// }
+/// ```
+async fn get_capture_retry_schedule_time(
+    db: &dyn StorageInterface,
+    connector: &str,
+    merchant_id: &str,
+    retry_count: i32,
+) -> Option<time::PrimitiveDateTime> {
+    let mapping: errors::CustomResult<process_data::ConnectorPTMapping, errors::StorageError> = db
+        .find_config_by_key(&format!("pt_mapping_capture_{connector}"))
+        .await
+        .map(|value| value.config)
+        .and_then(|config| {
+            config
+                .parse_struct("ConnectorPTMapping")
+                .change_context(errors::StorageError::DeserializationFailed)
+        });
+    let mapping = mapping.unwrap_or_else(|error| {
+        logger::info!(
+            ?error,
+            "Capture retry mapping not found, using default mapping"
+        );
+        process_data::ConnectorPTMapping::default()
+    });
+    let time_delta = scheduler_utils::get_schedule_time(mapping, merchant_id, retry_count);
+
+    scheduler_utils::get_time_from_delta(time_delta)
+}
+
+/// Schedule the task for retry, finishing the task if the retries have been exhausted
+async fn retry_capture_task(
+    db: &dyn StorageInterface,
+    connector: &str,
+    merchant_id: &str,
+    process: storage::ProcessTracker,
+) -> Result<(), sch_errors::ProcessTrackerError> {
+    match get_capture_retry_schedule_time(db, connector, merchant_id, process.retry_count + 1).await
+    {
+        Some(schedule_time) => {
+            db.as_scheduler()
+                .retry_process(process, schedule_time)
+                .await?
+        }
+        None => {
+            db.as_scheduler()
+                .finish_process_with_business_status(process, business_status::RETRIES_EXCEEDED)
+                .await?
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,213 @@
+use api_models::{
+    mandates::RecurringDetails,
+    payments::{FeatureMetadata, SmartRetryDetails},
+};
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+use crate::{
+    consts,
+    core::payments::{self as payment_flows, smart_retry},
+    db::StorageInterface,
+    errors,
+    routes::SessionState,
+    services,
+    types::{
+        api,
+        storage::{self, enums, payment_attempt::SmartRetryTrackingData},
+    },
+};
+
+pub struct SmartRetryWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for SmartRetryWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: SmartRetryTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("SmartRetryTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                &tracking_data.payment_id,
+                &tracking_data.merchant_id,
+                &key_store,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+
+        // The status of the declined payment has changed since the task was created
+        if payment_intent.status != enums::IntentStatus::Failed {
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    business_status::RESOURCE_STATUS_MISMATCH,
+                )
+                .await
+                .map_err(Into::into);
+        }
+
+        let payment_attempt = db
+            .find_payment_attempt_by_attempt_id_merchant_id(
+                &payment_intent.active_attempt.get_id(),
+                &tracking_data.merchant_id,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+
+        let recurring_details = match (
+            payment_attempt.mandate_id.clone(),
+            payment_attempt.payment_method_id.clone(),
+        ) {
+            (Some(mandate_id), _) => RecurringDetails::MandateId(mandate_id),
+            (None, Some(payment_method_id)) => RecurringDetails::PaymentMethodId(payment_method_id),
+            (None, None) => {
+                logger::info!(
+                    payment_id = %tracking_data.payment_id,
+                    "Declined payment has neither a mandate nor a saved payment method to retry with"
+                );
+                return db
+                    .as_scheduler()
+                    .finish_process_with_business_status(process, business_status::FAILURE)
+                    .await
+                    .map_err(Into::into);
+            }
+        };
+
+        let retry_limit = smart_retry::get_network_retry_limit(state, &payment_attempt);
+        let card_identifier = smart_retry::get_card_identifier(&payment_attempt);
+        if let Some(card_identifier) = card_identifier {
+            let card_retry_count = smart_retry::get_card_retry_count(
+                state,
+                &tracking_data.merchant_id,
+                card_identifier,
+                retry_limit,
+            )
+            .await?;
+
+            if card_retry_count >= usize::from(retry_limit.max_retries) {
+                logger::info!(
+                    payment_id = %tracking_data.payment_id,
+                    card_retry_count,
+                    "Retry limit of the card network has been reached, not retrying the payment"
+                );
+                return db
+                    .as_scheduler()
+                    .finish_process_with_business_status(process, business_status::RETRIES_EXCEEDED)
+                    .await
+                    .map_err(Into::into);
+            }
+        }
+
+        let payment_id = common_utils::generate_id(consts::ID_LENGTH, "pay");
+
+        // The retry is recorded before the payment is made, so that the retry limit is respected
+        // even if the outcome of the payment is not known
+        if let Some(card_identifier) = card_identifier {
+            smart_retry::record_card_retry(
+                state,
+                &tracking_data.merchant_id,
+                card_identifier,
+                &payment_id,
+                retry_limit,
+            )
+            .await?;
+        }
+
+        let payment_request = api::PaymentsRequest {
+            payment_id: Some(api::PaymentIdType::PaymentIntentId(payment_id)),
+            amount: Some(api::Amount::from(payment_intent.amount)),
+            currency: payment_intent.currency,
+            customer_id: payment_intent.customer_id.clone(),
+            profile_id: payment_intent.profile_id.clone(),
+            confirm: Some(true),
+            off_session: Some(true),
+            capture_method: payment_attempt.capture_method,
+            recurring_details: Some(recurring_details),
+            description: payment_intent.description.clone(),
+            metadata: payment_intent.metadata.clone(),
+            statement_descriptor_name: payment_intent.statement_descriptor_name.clone(),
+            statement_descriptor_suffix: payment_intent.statement_descriptor_suffix.clone(),
+            feature_metadata: Some(FeatureMetadata {
+                smart_retry_details: Some(SmartRetryDetails {
+                    original_payment_id: tracking_data.original_payment_id.clone(),
+                    retried_payment_id: tracking_data.payment_id.clone(),
+                    retry_count: tracking_data.retry_count,
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        // The outcome of the retry is notified through the outgoing webhooks of the new payment.
+        // If the retry is declined as well, the next retry is scheduled as per its GSM decision.
+        let result = Box::pin(payment_flows::payments_core::<
+            api::Authorize,
+            api::PaymentsResponse,
+            _,
+            _,
+            _,
+        >(
+            state.clone(),
+            state.get_req_state(),
+            merchant_account,
+            key_store,
+            payment_flows::PaymentCreate,
+            payment_request,
+            services::AuthFlow::Merchant,
+            payment_flows::CallConnectorAction::Trigger,
+            None,
+            api::HeaderPayload::default(),
+        ))
+        .await;
+
+        let status = match result {
+            Ok(_) => business_status::COMPLETED_BY_PT,
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    payment_id = %tracking_data.payment_id,
+                    "Failed to create payment for retrying the declined payment"
+                );
+                business_status::FAILURE
+            }
+        };
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, status)
+            .await
+            .map_err(Into::into)
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,79 @@
+use common_utils::ext_traits::ValueExt;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors,
+};
+
+use crate::{
+    core::subscriptions::{self, SubscriptionBillingOutcome},
+    errors as core_errors,
+    routes::SessionState,
+    types::storage::{self, SubscriptionTrackingData},
+};
+
+pub struct SubscriptionBillingWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for SubscriptionBillingWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: SubscriptionTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("SubscriptionTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let outcome = subscriptions::process_subscription_billing(
+            state,
+            &merchant_account,
+            &key_store,
+            &tracking_data.subscription_id,
+        )
+        .await?;
+
+        // A single task bills the subscription for its whole lifetime, it is reset for every new
+        // billing period and retried for the payment attempts of the same invoice
+        match outcome {
+            SubscriptionBillingOutcome::Reschedule(schedule_time) => {
+                db.as_scheduler()
+                    .reset_process(process, schedule_time)
+                    .await?
+            }
+            SubscriptionBillingOutcome::Retry(schedule_time) => {
+                db.as_scheduler()
+                    .retry_process(process, schedule_time)
+                    .await?
+            }
+            SubscriptionBillingOutcome::Finish(status) => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, status)
+                    .await?
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> core_errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -199,7 +199,7 @@ async fn payments_create_success() {
         conf,
         StorageImpl::PostgresqlTest,
         tx,
-        Box::new(services::MockApiClient),
+        utils::get_api_client(),
     ))
     .await;
     let state = Arc::new(app_state)
@@ -246,7 +246,7 @@ async fn payments_create_failure() {
             conf,
             StorageImpl::PostgresqlTest,
             tx,
-            Box::new(services::MockApiClient),
+            utils::get_api_client(),
         ))
         .await;
         let state = Arc::new(app_state)
@@ -309,7 +309,7 @@ async fn refund_for_successful_payments() {
         conf,
         StorageImpl::PostgresqlTest,
         tx,
-        Box::new(services::MockApiClient),
+        utils::get_api_client(),
     ))
     .await;
     let state = Arc::new(app_state)
@@ -379,7 +379,7 @@ async fn refunds_create_failure() {
         conf,
         StorageImpl::PostgresqlTest,
         tx,
-        Box::new(services::MockApiClient),
+        utils::get_api_client(),
     ))
     .await;
     let state = Arc::new(app_state)
@@ -442,4 +442,44 @@ async fn should_fail_for_refund_amount_higher_than_payment_amount() {
 
 // Connector dependent test cases goes here
 
+// Creates a payment with a card that is declined for insufficient funds.
+#[actix_web::test]
+async fn should_fail_payment_for_insufficient_funds_card() {
+    let response = CONNECTOR
+        .make_payment(
+            Some(types::PaymentsAuthorizeData {
+                payment_method_data: domain::PaymentMethodData::Card(domain::Card {
+                    card_number: CardNumber::from_str("4000000000009995").unwrap(),
+                    ..utils::CCardType::default().0
+                }),
+                ..utils::PaymentAuthorizeType::default().0
+            }),
+            get_default_payment_info(),
+        )
+        .await
+        .unwrap();
+    let error = response.response.unwrap_err();
+    assert_eq!(error.code, "insufficient_funds".to_string());
+    assert_eq!(error.message, "Insufficient funds".to_string());
+}
+
+// Creates a payment with a card whose outcome is notified through a webhook.
+#[actix_web::test]
+async fn should_leave_payment_pending_for_async_card() {
+    let response = CONNECTOR
+        .make_payment(
+            Some(types::PaymentsAuthorizeData {
+                payment_method_data: domain::PaymentMethodData::Card(domain::Card {
+                    card_number: CardNumber::from_str("4000000000000077").unwrap(),
+                    ..utils::CCardType::default().0
+                }),
+                ..utils::PaymentAuthorizeType::default().0
+            }),
+            get_default_payment_info(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Pending);
+}
+
 // [#478]: add unit tests for non 3DS, wallets & webhooks in connector tests
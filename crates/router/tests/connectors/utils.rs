@@ -1,4 +1,7 @@
-use std::{fmt::Debug, marker::PhantomData, str::FromStr, sync::Arc, time::Duration};
+use std::{
+    cell::RefCell, fmt::Debug, marker::PhantomData, path::PathBuf, str::FromStr, sync::Arc,
+    time::Duration,
+};
 
 use async_trait::async_trait;
 use common_utils::pii::Email;
@@ -43,6 +46,74 @@ pub trait Connector {
     }
 }
 
+/// Environment variable selecting whether the requests sent to connectors are recorded to
+/// cassettes (`record`) or served from previously recorded cassettes (`replay`)
+const CONNECTOR_TEST_MODE_ENV: &str = "CONNECTOR_TEST_MODE";
+
+/// Directory containing the cassettes, relative to the crate root
+const CASSETTES_DIRECTORY: &str = "tests/connectors/cassettes";
+
+/// The record/replay client of a test, along with the name of the test
+struct TestCassette {
+    test_name: String,
+    client: services::RecordReplayApiClient,
+}
+
+impl Drop for TestCassette {
+    /// Every recorded interaction must have been replayed by the end of the test, otherwise the
+    /// test no longer sends some of the requests it was recorded with. The cassette is dropped
+    /// when the test thread exits, after the test has reported its result, hence a failure here
+    /// aborts the test run instead of failing the test alone.
+    fn drop(&mut self) {
+        if self.client.get_mode() != services::RecordReplayMode::Replay {
+            return;
+        }
+        let pending_interactions_count = self
+            .client
+            .get_pending_interactions_count()
+            .expect("Failed to get the pending interactions of the cassette");
+        assert_eq!(
+            pending_interactions_count, 0,
+            "{} recorded interactions were not replayed by {}",
+            pending_interactions_count, self.test_name
+        );
+    }
+}
+
+thread_local! {
+    /// The cassette of the test running on this thread. A test sends several requests through
+    /// different app states, which must all share the same cassette.
+    static TEST_CASSETTE: RefCell<Option<TestCassette>> = const { RefCell::new(None) };
+}
+
+pub fn get_api_client() -> Box<dyn services::ApiClient> {
+    let Ok(mode) = std::env::var(CONNECTOR_TEST_MODE_ENV) else {
+        return Box::new(services::MockApiClient);
+    };
+    let mode = services::RecordReplayMode::from_str(&mode).expect("Invalid connector test mode");
+
+    TEST_CASSETTE.with(|test_cassette| {
+        let mut test_cassette = test_cassette.borrow_mut();
+        let test_cassette = test_cassette.get_or_insert_with(|| {
+            // Tests run on threads named after the test, such as
+            // `adyen::should_only_authorize_payment`, so the cassette of each test is stored at
+            // `<connector>/<test>.json`
+            let test_name = std::thread::current()
+                .name()
+                .expect("Test thread is not named after the test")
+                .to_string();
+            let cassette_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+                .join(CASSETTES_DIRECTORY)
+                .join(format!("{}.json", test_name.replace("::", "/")));
+            let client = services::RecordReplayApiClient::new(mode, cassette_path)
+                .expect("Failed to load cassette");
+            TestCassette { test_name, client }
+        });
+        let client: Box<dyn services::ApiClient> = Box::new(test_cassette.client.clone());
+        client
+    })
+}
+
 pub fn construct_connector_data_old(
     connector: types::api::BoxedConnector,
     connector_name: types::Connector,
@@ -405,6 +476,7 @@ pub trait ConnectorActions: Connector {
                 connector_refund_id: Some(refund_id),
                 browser_info: None,
                 charges: None,
+                split_refunds: None,
             }),
             payment_info,
         );
@@ -584,7 +656,7 @@ pub trait ConnectorActions: Connector {
             Settings::new().unwrap(),
             StorageImpl::PostgresqlTest,
             tx,
-            Box::new(services::MockApiClient),
+            get_api_client(),
         ))
         .await;
         let state = Arc::new(app_state)
@@ -624,7 +696,7 @@ pub trait ConnectorActions: Connector {
             Settings::new().unwrap(),
             StorageImpl::PostgresqlTest,
             tx,
-            Box::new(services::MockApiClient),
+            get_api_client(),
         ))
         .await;
         let state = Arc::new(app_state)
@@ -665,7 +737,7 @@ pub trait ConnectorActions: Connector {
             Settings::new().unwrap(),
             StorageImpl::PostgresqlTest,
             tx,
-            Box::new(services::MockApiClient),
+            get_api_client(),
         ))
         .await;
         let state = Arc::new(app_state)
@@ -705,7 +777,7 @@ pub trait ConnectorActions: Connector {
             Settings::new().unwrap(),
             StorageImpl::PostgresqlTest,
             tx,
-            Box::new(services::MockApiClient),
+            get_api_client(),
         ))
         .await;
         let state = Arc::new(app_state)
@@ -796,7 +868,7 @@ pub trait ConnectorActions: Connector {
             Settings::new().unwrap(),
             StorageImpl::PostgresqlTest,
             tx,
-            Box::new(services::MockApiClient),
+            get_api_client(),
         ))
         .await;
         let state = Arc::new(app_state)
@@ -833,7 +905,7 @@ async fn call_connector<
         conf,
         StorageImpl::PostgresqlTest,
         tx,
-        Box::new(services::MockApiClient),
+        get_api_client(),
     ))
     .await;
     let state = Arc::new(app_state)
@@ -935,6 +1007,9 @@ impl Default for PaymentAuthorizeType {
             authentication_data: None,
             customer_acceptance: None,
             charges: None,
+            installments: None,
+            split_payments: None,
+            network_token_data: None,
         };
         Self(data)
     }
@@ -975,6 +1050,7 @@ impl Default for BrowserInfoType {
             java_enabled: Some(true),
             java_script_enabled: Some(true),
             ip_address: Some("127.0.0.1".parse().unwrap()),
+            device_fingerprint: None,
         };
         Self(data)
     }
@@ -1015,6 +1091,7 @@ impl Default for PaymentRefundType {
             connector_refund_id: None,
             browser_info: None,
             charges: None,
+            split_refunds: None,
         };
         Self(data)
     }
@@ -105,6 +105,26 @@ pub struct LogTelemetry {
     pub route_to_trace: Option<Vec<String>>,
     /// Interval for collecting the metrics (such as gauge) in background thread
     pub bg_metrics_collection_interval_in_secs: Option<u16>,
+    /// Prometheus pull exporter.
+    pub prometheus: LogPrometheus,
+}
+
+/// Prometheus pull exporter, serving the metrics on `/metrics` for Prometheus to scrape.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct LogPrometheus {
+    /// Whether the metrics are served for Prometheus. The metrics are still pushed to the
+    /// OpenTelemetry collector if `metrics_enabled` is set.
+    pub enabled: bool,
+    /// Host the metrics are served on.
+    pub host: String,
+    /// Port the metrics are served on.
+    pub port: u16,
+    /// Labels whose number of distinct values is limited, per metric.
+    pub limited_labels: Vec<String>,
+    /// Max number of distinct values of each limited label per metric. The measurements with
+    /// further values are recorded into a single series, with the label set to `other`.
+    pub max_label_values: usize,
 }
 
 /// Telemetry / tracing.
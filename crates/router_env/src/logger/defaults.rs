@@ -20,3 +20,20 @@ impl Default for super::config::LogConsole {
         }
     }
 }
+
+impl Default for super::config::LogPrometheus {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            host: "0.0.0.0".into(),
+            port: 9464,
+            limited_labels: vec![
+                "tenant".into(),
+                "merchant_id".into(),
+                "merchant".into(),
+                "profile_id".into(),
+            ],
+            max_label_values: 100,
+        }
+    }
+}
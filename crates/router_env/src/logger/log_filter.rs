@@ -0,0 +1,209 @@
+//!
+//! Log filter overrides applied at runtime, which log the requests of a merchant, business profile,
+//! connector or request path at a more verbose level for a bounded time.
+//!
+
+use std::{
+    sync::{PoisonError, RwLock},
+    time::SystemTime,
+};
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use tracing::{level_filters::LevelFilter, subscriber::Interest, Metadata, Subscriber};
+use tracing_subscriber::{
+    layer::{Context, Filter},
+    registry::LookupSpan,
+    reload,
+};
+
+use super::storage::Storage;
+
+const STORAGE_TARGET: &str = "router_env::logger::storage";
+
+/// Span field which the value of a log filter override is matched against.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LogFilterField {
+    /// Merchant handling the request.
+    MerchantId,
+    /// Business profile of the payment.
+    ProfileId,
+    /// Connector called for the request.
+    Connector,
+    /// Path of the request, matched as a prefix.
+    RequestPath,
+}
+
+impl LogFilterField {
+    /// Name of the span field holding the value.
+    pub fn span_field_name(self) -> &'static str {
+        match self {
+            Self::MerchantId => "merchant_id",
+            Self::ProfileId => "profile_id",
+            Self::Connector => "connector_name",
+            Self::RequestPath => "request_url_path",
+        }
+    }
+}
+
+/// Level at which the matching spans and events are logged.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LogFilterLevel {
+    /// Log the debug spans and events.
+    Debug,
+    /// Log the trace spans and events.
+    Trace,
+}
+
+impl From<LogFilterLevel> for LevelFilter {
+    fn from(level: LogFilterLevel) -> Self {
+        match level {
+            LogFilterLevel::Debug => Self::DEBUG,
+            LogFilterLevel::Trace => Self::TRACE,
+        }
+    }
+}
+
+/// Logs the spans and events within the spans whose field matches the value, at the level of the
+/// override, until the override expires.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LogFilterOverride {
+    /// Identifier of the override.
+    pub filter_id: String,
+    /// Span field matched.
+    pub field: LogFilterField,
+    /// Value the span field is matched against.
+    pub value: String,
+    /// Level at which the matching spans and events are logged.
+    pub level: LogFilterLevel,
+    /// Time after which the override is no longer applied.
+    pub expires_at: SystemTime,
+}
+
+impl LogFilterOverride {
+    /// Whether the override is applied at the given time.
+    pub fn is_active(&self, now: SystemTime) -> bool {
+        now < self.expires_at
+    }
+
+    fn matches(&self, storage: &Storage<'_>) -> bool {
+        storage
+            .values
+            .get(self.field.span_field_name())
+            .and_then(serde_json::Value::as_str)
+            // Values recorded with their `Debug` representation are quoted
+            .map(|value| value.trim_matches('"'))
+            .is_some_and(|value| match self.field {
+                LogFilterField::RequestPath => value.starts_with(&self.value),
+                LogFilterField::MerchantId
+                | LogFilterField::ProfileId
+                | LogFilterField::Connector => value == self.value,
+            })
+    }
+}
+
+/// Filter enabling the spans and events matched by the active log filter overrides. It is combined
+/// with the filtering directive of each logging layer, and reloaded with [`reload_log_filter`].
+#[derive(Clone, Debug, Default)]
+pub struct LogFilter {
+    overrides: Vec<LogFilterOverride>,
+}
+
+impl LogFilter {
+    fn active_overrides(&self) -> impl Iterator<Item = &LogFilterOverride> {
+        let now = SystemTime::now();
+        self.overrides
+            .iter()
+            .filter(move |log_override| log_override.is_active(now))
+    }
+
+    fn max_level(&self) -> LevelFilter {
+        self.active_overrides()
+            .map(|log_override| LevelFilter::from(log_override.level))
+            .max()
+            .unwrap_or(LevelFilter::OFF)
+    }
+}
+
+impl<S> Filter<S> for LogFilter
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn enabled(&self, metadata: &Metadata<'_>, cx: &Context<'_, S>) -> bool {
+        // Events logged by the storage layer are emitted while it holds the span extensions
+        if metadata.target() == STORAGE_TARGET {
+            return false;
+        }
+
+        let overrides: Vec<_> = self
+            .active_overrides()
+            .filter(|log_override| LevelFilter::from(log_override.level) >= *metadata.level())
+            .collect();
+        if overrides.is_empty() {
+            return false;
+        }
+
+        cx.lookup_current().is_some_and(|current_span| {
+            current_span.scope().any(|span| {
+                span.extensions()
+                    .get::<Storage<'_>>()
+                    .is_some_and(|storage| {
+                        overrides
+                            .iter()
+                            .any(|log_override| log_override.matches(storage))
+                    })
+            })
+        })
+    }
+
+    fn callsite_enabled(&self, metadata: &'static Metadata<'static>) -> Interest {
+        if self.max_level() >= *metadata.level() {
+            Interest::sometimes()
+        } else {
+            Interest::never()
+        }
+    }
+
+    fn max_level_hint(&self) -> Option<LevelFilter> {
+        Some(self.max_level())
+    }
+}
+
+type ReloadLogFilter =
+    Box<dyn Fn(&[LogFilterOverride]) -> Result<(), reload::Error> + Send + Sync + 'static>;
+
+/// Reload handles of the log filters of all the logging layers.
+static RELOAD_HANDLES: Lazy<RwLock<Vec<ReloadLogFilter>>> = Lazy::new(RwLock::default);
+
+/// Creates a log filter without overrides, which is reloaded by [`reload_log_filter`].
+pub fn reloadable_log_filter<S>() -> reload::Layer<LogFilter, S>
+where
+    S: Subscriber + for<'a> LookupSpan<'a> + 'static,
+{
+    let (log_filter, handle) = reload::Layer::new(LogFilter::default());
+    RELOAD_HANDLES
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .push(Box::new(move |overrides| {
+            handle.modify(|log_filter| log_filter.overrides = overrides.to_vec())
+        }));
+    log_filter
+}
+
+/// Replaces the log filter overrides applied by all the logging layers. The handles of the log
+/// filters of subscribers which have been dropped are removed.
+pub fn reload_log_filter(overrides: &[LogFilterOverride]) {
+    RELOAD_HANDLES
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .retain(|reload| match reload(overrides) {
+            Ok(()) => true,
+            Err(error) if error.is_dropped() => false,
+            Err(error) => {
+                tracing::error!(?error, "Failed to reload the log filter");
+                true
+            }
+        });
+}
@@ -16,8 +16,13 @@ pub use types::{Category, Flow, Level, Tag};
 mod setup;
 pub use setup::{setup, TelemetryGuard};
 
+pub mod prometheus_exporter;
+
 pub mod formatter;
 pub use formatter::FormattingLayer;
 
 pub mod storage;
 pub use storage::{Storage, StorageSubscription};
+
+pub mod log_filter;
+pub use log_filter::{reload_log_filter, LogFilterField, LogFilterLevel, LogFilterOverride};
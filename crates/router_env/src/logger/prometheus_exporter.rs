@@ -0,0 +1,516 @@
+//!
+//! Prometheus pull exporter, serving the metrics recorded through the global meter provider on
+//! `/metrics`, with the same metric names and attributes as the metrics pushed over OTLP.
+//!
+
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use once_cell::sync::OnceCell;
+use opentelemetry::{
+    metrics::{
+        self, AsyncCounter, AsyncGauge, AsyncUpDownCounter, Counter, Histogram, InstrumentProvider,
+        Meter, MeterProvider, ObservableCounter, ObservableGauge, ObservableUpDownCounter,
+        SyncCounter, SyncHistogram, SyncUpDownCounter, Unit, UpDownCounter,
+    },
+    sdk::metrics::controllers::BasicController,
+    Context, InstrumentationLibrary, KeyValue,
+};
+use opentelemetry_prometheus::PrometheusExporter;
+use prometheus::{Encoder, TextEncoder};
+
+use crate::config;
+
+/// Value of a limited label in the series aggregating the values beyond the max number of values
+const OVERFLOW_LABEL_VALUE: &str = "other";
+
+static PROMETHEUS_EXPORTER: OnceCell<PrometheusExporter> = OnceCell::new();
+
+/// Values of the limited labels which are recorded as is, by metric and label name
+type AdmittedLabelValues = HashMap<(String, String), HashSet<String>>;
+
+/// Registers the Prometheus exporter, collecting the metrics from the controller on every scrape.
+pub(super) fn init_exporter(controller: BasicController) -> Result<(), metrics::MetricsError> {
+    let exporter = opentelemetry_prometheus::exporter(controller).try_init()?;
+    PROMETHEUS_EXPORTER.get_or_init(|| exporter);
+    Ok(())
+}
+
+/// Encodes the metrics in the Prometheus text format. Returns `None` if the Prometheus exporter is
+/// not set up.
+pub fn gather_metrics() -> Option<Result<String, prometheus::Error>> {
+    PROMETHEUS_EXPORTER.get().map(encode_metrics)
+}
+
+fn encode_metrics(exporter: &PrometheusExporter) -> Result<String, prometheus::Error> {
+    let mut buffer = Vec::new();
+    TextEncoder::new()
+        .encode(&exporter.registry().gather(), &mut buffer)
+        .and_then(|()| {
+            String::from_utf8(buffer).map_err(|error| prometheus::Error::Msg(error.to_string()))
+        })
+}
+
+/// Meter provider limiting the number of distinct values of the limited labels when the metrics
+/// are recorded, so that the controller never holds more series than the limits allow.
+///
+/// When the metrics are also pushed over OTLP, every measurement is recorded to the OTLP
+/// controller as well, with the labels as is.
+#[derive(Debug)]
+pub(super) struct LimitedMeterProvider {
+    controller: BasicController,
+    otlp_controller: Option<BasicController>,
+    limits: Arc<LabelLimits>,
+}
+
+impl LimitedMeterProvider {
+    pub(super) fn new(
+        controller: BasicController,
+        otlp_controller: Option<BasicController>,
+        config: &config::LogPrometheus,
+    ) -> Self {
+        Self {
+            controller,
+            otlp_controller,
+            limits: Arc::new(LabelLimits::new(config)),
+        }
+    }
+}
+
+impl MeterProvider for LimitedMeterProvider {
+    fn versioned_meter(
+        &self,
+        name: &'static str,
+        version: Option<&'static str>,
+        schema_url: Option<&'static str>,
+    ) -> Meter {
+        Meter::new(
+            InstrumentationLibrary::new(name, version, schema_url),
+            Arc::new(LimitedInstrumentProvider {
+                meter: self.controller.versioned_meter(name, version, schema_url),
+                otlp_meter: self
+                    .otlp_controller
+                    .as_ref()
+                    .map(|controller| controller.versioned_meter(name, version, schema_url)),
+                limits: Arc::clone(&self.limits),
+            }),
+        )
+    }
+}
+
+#[derive(Debug)]
+struct LabelLimits {
+    limited_labels: HashSet<String>,
+    max_label_values: usize,
+    /// Values are never evicted, so that the series recorded for a value do not change over time.
+    admitted_label_values: Mutex<AdmittedLabelValues>,
+}
+
+impl LabelLimits {
+    fn new(config: &config::LogPrometheus) -> Self {
+        Self {
+            limited_labels: config.limited_labels.iter().cloned().collect(),
+            max_label_values: config.max_label_values,
+            admitted_label_values: Mutex::default(),
+        }
+    }
+
+    /// Replaces the values of the limited labels beyond their max number of values by `other`, so
+    /// that the measurements of these values are aggregated into a single series.
+    fn limit_cardinality<'a>(
+        &self,
+        metric_name: &str,
+        attributes: &'a [KeyValue],
+    ) -> Cow<'a, [KeyValue]> {
+        if !attributes
+            .iter()
+            .any(|attribute| self.limited_labels.contains(attribute.key.as_str()))
+        {
+            return Cow::Borrowed(attributes);
+        }
+
+        let mut admitted_label_values = self
+            .admitted_label_values
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        Cow::Owned(
+            attributes
+                .iter()
+                .map(|attribute| {
+                    if !self.limited_labels.contains(attribute.key.as_str())
+                        || self.admit_label_value(
+                            &mut admitted_label_values,
+                            metric_name,
+                            attribute.key.as_str(),
+                            &attribute.value.as_str(),
+                        )
+                    {
+                        attribute.clone()
+                    } else {
+                        KeyValue::new(attribute.key.clone(), OVERFLOW_LABEL_VALUE)
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    fn admit_label_value(
+        &self,
+        admitted_label_values: &mut AdmittedLabelValues,
+        metric_name: &str,
+        label_name: &str,
+        label_value: &str,
+    ) -> bool {
+        let label_values = admitted_label_values
+            .entry((metric_name.to_owned(), label_name.to_owned()))
+            .or_default();
+
+        if label_values.contains(label_value) {
+            true
+        } else if label_values.len() < self.max_label_values {
+            label_values.insert(label_value.to_owned());
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Creates the instruments of the controller's meter, wrapped to limit the label values they
+/// record, along with the instruments of the OTLP controller's meter if any.
+struct LimitedInstrumentProvider {
+    meter: Meter,
+    otlp_meter: Option<Meter>,
+    limits: Arc<LabelLimits>,
+}
+
+/// Instrument of the controller's meter, recording the measurements with the limited labels, and
+/// of the OTLP controller's meter, recording the measurements with the labels as is.
+struct LimitedInstrument<I> {
+    metric_name: String,
+    limits: Arc<LabelLimits>,
+    instrument: I,
+    otlp_instrument: Option<I>,
+}
+
+impl<I> LimitedInstrument<I> {
+    fn limit_cardinality<'a>(&self, attributes: &'a [KeyValue]) -> Cow<'a, [KeyValue]> {
+        self.limits.limit_cardinality(&self.metric_name, attributes)
+    }
+}
+
+macro_rules! limited_instrument {
+    ($method:ident, $instrument:ident<$value:ty>) => {
+        fn $method(
+            &self,
+            name: String,
+            description: Option<String>,
+            unit: Option<Unit>,
+        ) -> metrics::Result<$instrument<$value>> {
+            let build_instrument = |meter: &Meter| {
+                let mut builder = meter.$method(name.clone());
+                if let Some(description) = description.clone() {
+                    builder = builder.with_description(description);
+                }
+                if let Some(unit) = unit.clone() {
+                    builder = builder.with_unit(unit);
+                }
+                builder.try_init()
+            };
+
+            Ok($instrument::new(Arc::new(LimitedInstrument {
+                instrument: build_instrument(&self.meter)?,
+                otlp_instrument: self.otlp_meter.as_ref().map(build_instrument).transpose()?,
+                metric_name: name,
+                limits: Arc::clone(&self.limits),
+            })))
+        }
+    };
+}
+
+impl InstrumentProvider for LimitedInstrumentProvider {
+    limited_instrument!(u64_counter, Counter<u64>);
+    limited_instrument!(f64_counter, Counter<f64>);
+    limited_instrument!(u64_observable_counter, ObservableCounter<u64>);
+    limited_instrument!(f64_observable_counter, ObservableCounter<f64>);
+    limited_instrument!(i64_up_down_counter, UpDownCounter<i64>);
+    limited_instrument!(f64_up_down_counter, UpDownCounter<f64>);
+    limited_instrument!(i64_observable_up_down_counter, ObservableUpDownCounter<i64>);
+    limited_instrument!(f64_observable_up_down_counter, ObservableUpDownCounter<f64>);
+    limited_instrument!(u64_observable_gauge, ObservableGauge<u64>);
+    limited_instrument!(i64_observable_gauge, ObservableGauge<i64>);
+    limited_instrument!(f64_observable_gauge, ObservableGauge<f64>);
+    limited_instrument!(f64_histogram, Histogram<f64>);
+    limited_instrument!(u64_histogram, Histogram<u64>);
+    limited_instrument!(i64_histogram, Histogram<i64>);
+
+    fn register_callback(
+        &self,
+        callback: Box<dyn Fn(&Context) + Send + Sync>,
+    ) -> metrics::Result<()> {
+        let Some(otlp_meter) = &self.otlp_meter else {
+            return self.meter.register_callback(callback);
+        };
+
+        // The callback is run on the collection of either controller
+        let callback: Arc<dyn Fn(&Context) + Send + Sync> = Arc::from(callback);
+        let otlp_callback = Arc::clone(&callback);
+        otlp_meter.register_callback(move |cx| otlp_callback(cx))?;
+        self.meter.register_callback(move |cx| callback(cx))
+    }
+}
+
+impl<T: Copy> SyncCounter<T> for LimitedInstrument<Counter<T>> {
+    fn add(&self, cx: &Context, value: T, attributes: &[KeyValue]) {
+        if let Some(otlp_instrument) = &self.otlp_instrument {
+            otlp_instrument.add(cx, value, attributes);
+        }
+        self.instrument
+            .add(cx, value, &self.limit_cardinality(attributes))
+    }
+}
+
+impl<T: Copy> AsyncCounter<T> for LimitedInstrument<ObservableCounter<T>> {
+    fn observe(&self, cx: &Context, value: T, attributes: &[KeyValue]) {
+        if let Some(otlp_instrument) = &self.otlp_instrument {
+            otlp_instrument.observe(cx, value, attributes);
+        }
+        self.instrument
+            .observe(cx, value, &self.limit_cardinality(attributes))
+    }
+}
+
+impl<T: Copy> SyncUpDownCounter<T> for LimitedInstrument<UpDownCounter<T>> {
+    fn add(&self, cx: &Context, value: T, attributes: &[KeyValue]) {
+        if let Some(otlp_instrument) = &self.otlp_instrument {
+            otlp_instrument.add(cx, value, attributes);
+        }
+        self.instrument
+            .add(cx, value, &self.limit_cardinality(attributes))
+    }
+}
+
+impl<T: Copy> AsyncUpDownCounter<T> for LimitedInstrument<ObservableUpDownCounter<T>> {
+    fn observe(&self, cx: &Context, value: T, attributes: &[KeyValue]) {
+        if let Some(otlp_instrument) = &self.otlp_instrument {
+            otlp_instrument.observe(cx, value, attributes);
+        }
+        self.instrument
+            .observe(cx, value, &self.limit_cardinality(attributes))
+    }
+}
+
+impl<T: Copy> AsyncGauge<T> for LimitedInstrument<ObservableGauge<T>> {
+    fn observe(&self, cx: &Context, value: T, attributes: &[KeyValue]) {
+        if let Some(otlp_instrument) = &self.otlp_instrument {
+            otlp_instrument.observe(cx, value, attributes);
+        }
+        self.instrument
+            .observe(cx, value, &self.limit_cardinality(attributes))
+    }
+}
+
+impl<T: Copy> SyncHistogram<T> for LimitedInstrument<Histogram<T>> {
+    fn record(&self, cx: &Context, value: T, attributes: &[KeyValue]) {
+        if let Some(otlp_instrument) = &self.otlp_instrument {
+            otlp_instrument.record(cx, value, attributes);
+        }
+        self.instrument
+            .record(cx, value, &self.limit_cardinality(attributes))
+    }
+}
+
+/// Serves the metrics on `/metrics`, on the host and port of the Prometheus exporter.
+#[cfg(feature = "actix_web")]
+pub(super) fn start_metrics_server(config: &config::LogPrometheus) -> std::io::Result<()> {
+    use actix_web::{web, App, HttpResponse, HttpServer};
+
+    async fn metrics() -> HttpResponse {
+        match gather_metrics() {
+            Some(Ok(metrics)) => HttpResponse::Ok()
+                .content_type(prometheus::TEXT_FORMAT)
+                .body(metrics),
+            Some(Err(error)) => {
+                tracing::error!(?error, "Failed to encode the metrics");
+                HttpResponse::InternalServerError().finish()
+            }
+            None => HttpResponse::NotFound().finish(),
+        }
+    }
+
+    let server = HttpServer::new(|| App::new().route("/metrics", web::get().to(metrics)))
+        .bind((config.host.as_str(), config.port))?
+        .workers(1)
+        .run();
+
+    let _task_handle = tokio::spawn(async move {
+        if let Err(error) = server.await {
+            tracing::error!(?error, "The Prometheus metrics server stopped");
+        }
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use opentelemetry::sdk::{
+        export::metrics::aggregation::cumulative_temporality_selector,
+        metrics::{controllers, processors, selectors::simple},
+    };
+
+    use super::*;
+
+    fn get_config(max_label_values: usize) -> config::LogPrometheus {
+        config::LogPrometheus {
+            limited_labels: vec!["merchant_id".to_owned()],
+            max_label_values,
+            ..Default::default()
+        }
+    }
+
+    fn get_attributes(merchant_id: &'static str) -> [KeyValue; 2] {
+        [
+            KeyValue::new("merchant_id", merchant_id),
+            KeyValue::new("flow", "authorize"),
+        ]
+    }
+
+    #[test]
+    fn test_limit_cardinality() {
+        let limits = LabelLimits::new(&get_config(2));
+
+        for merchant_id in ["merchant_1", "merchant_2", "merchant_1"] {
+            assert_eq!(
+                limits
+                    .limit_cardinality("payment_count", &get_attributes(merchant_id))
+                    .to_vec(),
+                get_attributes(merchant_id)
+            );
+        }
+        // The values beyond the max number of values are replaced, other labels are untouched
+        assert_eq!(
+            limits
+                .limit_cardinality("payment_count", &get_attributes("merchant_3"))
+                .to_vec(),
+            get_attributes(OVERFLOW_LABEL_VALUE)
+        );
+        // The values are limited per metric
+        assert_eq!(
+            limits
+                .limit_cardinality("refund_count", &get_attributes("merchant_3"))
+                .to_vec(),
+            get_attributes("merchant_3")
+        );
+
+        let attributes = [KeyValue::new("flow", "authorize")];
+        assert!(matches!(
+            limits.limit_cardinality("payment_count", &attributes),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn test_measurements_beyond_the_limit_are_aggregated() {
+        let controller = controllers::basic(processors::factory(
+            simple::histogram([1.0, 10.0]),
+            cumulative_temporality_selector(),
+        ))
+        .build();
+        let exporter = opentelemetry_prometheus::exporter(controller.clone())
+            .try_init()
+            .unwrap();
+        let meter = LimitedMeterProvider::new(controller, None, &get_config(1)).meter("test");
+        let counter = meter.u64_counter("limited_counter").init();
+        let histogram = meter.f64_histogram("limited_histogram").init();
+
+        let cx = Context::current();
+        for (merchant_id, value) in [("merchant_1", 1), ("merchant_2", 2), ("merchant_3", 4)] {
+            counter.add(&cx, value, &get_attributes(merchant_id));
+            histogram.record(&cx, 5.0, &get_attributes(merchant_id));
+        }
+        let metrics = encode_metrics(&exporter).unwrap();
+
+        let series = |metric_name: &str, merchant_id: &str| {
+            metrics
+                .lines()
+                .filter(|line| {
+                    line.starts_with(metric_name)
+                        && line.contains(&format!("merchant_id=\"{merchant_id}\""))
+                })
+                .map(str::to_owned)
+                .collect::<Vec<_>>()
+        };
+        let value = |line: &String| line.rsplit(' ').next().unwrap().to_owned();
+
+        assert_eq!(
+            series("limited_counter", "merchant_1")
+                .iter()
+                .map(value)
+                .collect::<Vec<_>>(),
+            ["1"]
+        );
+        assert_eq!(
+            series("limited_counter", "other")
+                .iter()
+                .map(value)
+                .collect::<Vec<_>>(),
+            ["6"]
+        );
+        assert!(series("limited_counter", "merchant_3").is_empty());
+
+        let histogram_series = series("limited_histogram", "other");
+        assert!(histogram_series
+            .iter()
+            .any(|line| line.starts_with("limited_histogram_count") && value(line) == "2"));
+        assert!(histogram_series
+            .iter()
+            .any(|line| line.starts_with("limited_histogram_sum") && value(line) == "10"));
+        assert!(histogram_series.iter().any(|line| {
+            line.starts_with("limited_histogram_bucket")
+                && line.contains("le=\"10\"")
+                && value(line) == "2"
+        }));
+        assert!(series("limited_histogram", "merchant_2").is_empty());
+    }
+
+    #[test]
+    fn test_measurements_are_recorded_to_the_otlp_controller_as_is() {
+        let get_controller = || {
+            controllers::basic(processors::factory(
+                simple::histogram([1.0, 10.0]),
+                cumulative_temporality_selector(),
+            ))
+            .build()
+        };
+        let (controller, otlp_controller) = (get_controller(), get_controller());
+        let exporter = opentelemetry_prometheus::exporter(controller.clone())
+            .try_init()
+            .unwrap();
+        // The OTLP controller is exported through Prometheus as well to read its series
+        let otlp_exporter = opentelemetry_prometheus::exporter(otlp_controller.clone())
+            .try_init()
+            .unwrap();
+        let meter = LimitedMeterProvider::new(controller, Some(otlp_controller), &get_config(1))
+            .meter("test");
+        let counter = meter.u64_counter("fanned_out_counter").init();
+
+        let cx = Context::current();
+        for merchant_id in ["merchant_1", "merchant_2"] {
+            counter.add(&cx, 1, &get_attributes(merchant_id));
+        }
+
+        let metrics = encode_metrics(&exporter).unwrap();
+        assert!(metrics.contains("merchant_id=\"merchant_1\""));
+        assert!(!metrics.contains("merchant_id=\"merchant_2\""));
+        let otlp_metrics = encode_metrics(&otlp_exporter).unwrap();
+        assert!(otlp_metrics.contains("merchant_id=\"merchant_1\""));
+        assert!(otlp_metrics.contains("merchant_id=\"merchant_2\""));
+        assert!(!otlp_metrics.contains(&format!("merchant_id=\"{OVERFLOW_LABEL_VALUE}\"")));
+    }
+}
@@ -6,7 +6,11 @@ use opentelemetry::{
     global, runtime,
     sdk::{
         export::metrics::aggregation::cumulative_temporality_selector,
-        metrics::{controllers::BasicController, selectors::simple},
+        metrics::{
+            controllers::{self, BasicController},
+            processors,
+            selectors::simple,
+        },
         propagation::TraceContextPropagator,
         trace,
         trace::BatchConfig,
@@ -18,9 +22,12 @@ use opentelemetry::{
 use opentelemetry_otlp::{TonicExporterBuilder, WithExportConfig};
 use serde_json::ser::{CompactFormatter, PrettyFormatter};
 use tracing_appender::non_blocking::WorkerGuard;
-use tracing_subscriber::{fmt, prelude::*, util::SubscriberInitExt, EnvFilter, Layer};
+use tracing_subscriber::{
+    filter::FilterExt, fmt, prelude::*, util::SubscriberInitExt, EnvFilter, Layer,
+};
 
-use crate::{config, FormattingLayer, StorageSubscription};
+use super::prometheus_exporter;
+use crate::{config, log_filter::reloadable_log_filter, FormattingLayer, StorageSubscription};
 
 /// Contains guards necessary for logging and metrics collection.
 #[derive(Debug)]
@@ -45,7 +52,9 @@ pub fn setup(
     } else {
         None
     };
-    let _metrics_controller = if config.telemetry.metrics_enabled {
+    let _metrics_controller = if config.telemetry.prometheus.enabled {
+        setup_prometheus_pipeline(&config.telemetry)
+    } else if config.telemetry.metrics_enabled {
         setup_metrics_pipeline(&config.telemetry)
     } else {
         None
@@ -72,7 +81,7 @@ pub fn setup(
 
         Some(
             FormattingLayer::new(service_name, file_writer, CompactFormatter)
-                .with_filter(file_filter),
+                .with_filter(file_filter.or(reloadable_log_filter())),
         )
     } else {
         None
@@ -95,6 +104,8 @@ pub fn setup(
             &crates_to_filter,
         );
         println!("Using console logging filter: {console_filter}");
+        // Log filter overrides applied at runtime are combined with the filtering directive
+        let console_filter = console_filter.or(reloadable_log_filter());
 
         match config.console.log_format {
             config::LogFormat::Default => {
@@ -281,21 +292,31 @@ fn setup_tracing_pipeline(
     }
 }
 
-fn setup_metrics_pipeline(config: &config::LogTelemetry) -> Option<BasicController> {
-    let histogram_buckets = {
-        let mut init = 0.01;
-        let mut buckets: [f64; 15] = [0.0; 15];
+fn get_histogram_buckets() -> [f64; 15] {
+    let mut init = 0.01;
+    let mut buckets: [f64; 15] = [0.0; 15];
 
-        for bucket in &mut buckets {
-            init *= 2.0;
-            *bucket = init;
-        }
-        buckets
-    };
+    for bucket in &mut buckets {
+        init *= 2.0;
+        *bucket = init;
+    }
+    buckets
+}
 
+fn get_pod_resource() -> Resource {
+    Resource::new(vec![KeyValue::new(
+        "pod",
+        std::env::var("POD_NAME").map_or(
+            "hyperswitch-server-default".into(),
+            Into::<opentelemetry::Value>::into,
+        ),
+    )])
+}
+
+fn setup_metrics_pipeline(config: &config::LogTelemetry) -> Option<BasicController> {
     let metrics_controller_result = opentelemetry_otlp::new_pipeline()
         .metrics(
-            simple::histogram(histogram_buckets),
+            simple::histogram(get_histogram_buckets()),
             cumulative_temporality_selector(),
             // This would have to be updated if a different web framework is used
             runtime::TokioCurrentThread,
@@ -303,13 +324,7 @@ fn setup_metrics_pipeline(config: &config::LogTelemetry) -> Option<BasicControll
         .with_exporter(get_opentelemetry_exporter(config))
         .with_period(Duration::from_secs(3))
         .with_timeout(Duration::from_secs(10))
-        .with_resource(Resource::new(vec![KeyValue::new(
-            "pod",
-            std::env::var("POD_NAME").map_or(
-                "hyperswitch-server-default".into(),
-                Into::<opentelemetry::Value>::into,
-            ),
-        )]))
+        .with_resource(get_pod_resource())
         .build();
 
     if config.ignore_errors {
@@ -323,6 +338,49 @@ fn setup_metrics_pipeline(config: &config::LogTelemetry) -> Option<BasicControll
     }
 }
 
+/// Sets up a metrics controller which is collected on every scrape of the Prometheus exporter. If
+/// `metrics_enabled` is set, the metrics are pushed to the OpenTelemetry collector as well, by
+/// recording every measurement to the controllers of both pipelines.
+fn setup_prometheus_pipeline(config: &config::LogTelemetry) -> Option<BasicController> {
+    let otlp_metrics_controller = if config.metrics_enabled {
+        setup_metrics_pipeline(config)
+    } else {
+        None
+    };
+
+    let metrics_controller = controllers::basic(processors::factory(
+        simple::histogram(get_histogram_buckets()),
+        cumulative_temporality_selector(),
+    ))
+    .with_resource(get_pod_resource())
+    .build();
+
+    let prometheus_result = prometheus_exporter::init_exporter(metrics_controller.clone())
+        .map_err(|error| format!("Failed to setup the Prometheus exporter: {error:?}"));
+    #[cfg(feature = "actix_web")]
+    let prometheus_result = prometheus_result.and_then(|()| {
+        prometheus_exporter::start_metrics_server(&config.prometheus)
+            .map_err(|error| format!("Failed to start the Prometheus metrics server: {error:?}"))
+    });
+
+    if config.ignore_errors {
+        prometheus_result
+            .map_err(|error| eprintln!("{error}"))
+            .ok()?;
+    } else {
+        // Safety: This is conditional, there is an option to avoid this behavior at runtime.
+        #[allow(clippy::expect_used)]
+        prometheus_result.expect("Failed to setup the Prometheus pipeline");
+    }
+
+    global::set_meter_provider(prometheus_exporter::LimitedMeterProvider::new(
+        metrics_controller.clone(),
+        otlp_metrics_controller,
+        &config.prometheus,
+    ));
+    Some(metrics_controller)
+}
+
 fn get_envfilter(
     filtering_directive: Option<&String>,
     default_log_level: config::Level,
@@ -115,6 +115,8 @@ pub enum Flow {
     PaymentMethodsList,
     /// Payment method save flow
     PaymentMethodSave,
+    /// Network token lifecycle webhook flow
+    NetworkTokenWebhookReceive,
     /// Customer payment methods list flow.
     CustomerPaymentMethodsList,
     /// List Customers for a merchant
@@ -182,6 +184,14 @@ pub enum Flow {
     PayoutsFilter,
     /// Payouts accounts flow.
     PayoutsAccounts,
+    /// Payout batch create flow.
+    PayoutBatchCreate,
+    /// Payout batch retrieve flow.
+    PayoutBatchRetrieve,
+    /// Payout batch items list flow.
+    PayoutBatchItemsList,
+    /// Payout batch cancel flow.
+    PayoutBatchCancel,
     /// Payout link initiate flow
     PayoutLinkInitiate,
     /// Payments Redirect flow
@@ -292,6 +302,24 @@ pub enum Flow {
     BusinessProfileRetrieve,
     /// Delete a business profile
     BusinessProfileDelete,
+    /// Rotate the webhook signing secret of a business profile
+    WebhookSigningSecretRotate,
+    /// List the webhook signing secrets of a business profile
+    WebhookSigningSecretList,
+    /// Revoke a webhook signing secret of a business profile
+    WebhookSigningSecretRevoke,
+    /// Create a webhook endpoint for a business profile
+    WebhookEndpointCreate,
+    /// List the webhook endpoints of a business profile
+    WebhookEndpointList,
+    /// Retrieve a webhook endpoint of a business profile
+    WebhookEndpointRetrieve,
+    /// Update a webhook endpoint of a business profile
+    WebhookEndpointUpdate,
+    /// Delete a webhook endpoint of a business profile
+    WebhookEndpointDelete,
+    /// Rotate the signing secret of a webhook endpoint
+    WebhookEndpointSecretRotate,
     /// List all the business profiles for a merchant
     BusinessProfileList,
     /// Different verification flows
@@ -308,6 +336,20 @@ pub enum Flow {
     ApplePayCertificatesMigration,
     /// Gsm Rule Delete flow
     GsmRuleDelete,
+    /// Gsm Rule List flow
+    GsmRuleList,
+    /// Gsm Rule Import flow
+    GsmRuleImport,
+    /// Gsm Rule Export flow
+    GsmRuleExport,
+    /// Unified Translation Create flow
+    UnifiedTranslationCreate,
+    /// Unified Translation Update flow
+    UnifiedTranslationUpdate,
+    /// Unified Translation Delete flow
+    UnifiedTranslationDelete,
+    /// Unified Translation List flow
+    UnifiedTranslationList,
     /// User Sign Up
     UserSignUp,
     /// User Sign Up
@@ -436,6 +478,10 @@ pub enum Flow {
     GetSsoAuthUrl,
     /// Signin with SSO
     SignInWithSso,
+    /// Get SAML service provider metadata
+    GetSamlSpMetadata,
+    /// SAML assertion consumer service
+    SamlAcs,
     /// Auth Select
     AuthSelect,
     /// List initial webhook delivery attempts
@@ -456,6 +502,44 @@ pub enum Flow {
     RefundsManualUpdate,
     /// Manually update the payment details like status, error code, error message etc.
     PaymentsManualUpdate,
+    /// List the audit log entries of a merchant
+    AuditLogList,
+    /// Provision a tenant at runtime
+    TenantCreate,
+    /// Retrieve a tenant provisioned at runtime
+    TenantRetrieve,
+    /// List the tenants provisioned at runtime
+    TenantList,
+    /// Suspend a tenant provisioned at runtime
+    TenantSuspend,
+    /// Resume a suspended tenant
+    TenantResume,
+    /// Delete a tenant provisioned at runtime
+    TenantDelete,
+    /// Log the matching requests at a more verbose level for a bounded time
+    LogFilterCreate,
+    /// List the active log filters
+    LogFilterList,
+    /// Delete a log filter
+    LogFilterDelete,
+    /// Create a subscription plan
+    SubscriptionPlanCreate,
+    /// Retrieve a subscription plan
+    SubscriptionPlanRetrieve,
+    /// List the subscription plans of a merchant
+    SubscriptionPlanList,
+    /// Update a subscription plan
+    SubscriptionPlanUpdate,
+    /// Subscribe a customer to a plan
+    SubscriptionCreate,
+    /// Retrieve a subscription
+    SubscriptionRetrieve,
+    /// List the subscriptions of a merchant
+    SubscriptionList,
+    /// Cancel a subscription
+    SubscriptionCancel,
+    /// List the invoices of a subscription
+    SubscriptionInvoiceList,
 }
 
 ///
@@ -25,3 +25,75 @@ async fn basic() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn log_filter_override() {
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        time::{Duration, SystemTime},
+    };
+
+    use router_env::tracing::{self, level_filters::LevelFilter, Instrument};
+    use tracing_subscriber::{filter::FilterExt, layer::SubscriberExt, Layer};
+
+    /// Counts the events it is enabled for
+    struct EventCounter(Arc<AtomicUsize>);
+
+    impl<S: tracing::Subscriber> Layer<S> for EventCounter {
+        fn on_event(
+            &self,
+            _event: &tracing::Event<'_>,
+            _ctx: tracing_subscriber::layer::Context<'_, S>,
+        ) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    logger();
+
+    let log_filter = router_env::LogFilterOverride {
+        filter_id: "log_filter_test".to_string(),
+        field: router_env::LogFilterField::MerchantId,
+        value: "merchant_test".to_string(),
+        level: router_env::LogFilterLevel::Trace,
+        expires_at: SystemTime::now() + Duration::from_secs(60),
+    };
+
+    let event_count = Arc::new(AtomicUsize::new(0));
+    let subscriber = tracing_subscriber::registry()
+        .with(router_env::StorageSubscription)
+        .with(
+            EventCounter(event_count.clone())
+                .with_filter(LevelFilter::INFO.or(router_env::log_filter::reloadable_log_filter())),
+        );
+    let trace_events_logged = |merchant_id: &str| {
+        let count_before = event_count.load(Ordering::SeqCst);
+        tracing::info_span!("request", merchant_id).in_scope(|| tracing::trace!("trace event"));
+        event_count.load(Ordering::SeqCst) - count_before
+    };
+
+    tracing::subscriber::with_default(subscriber, || {
+        assert_eq!(trace_events_logged("merchant_test"), 0);
+
+        router_env::reload_log_filter(std::slice::from_ref(&log_filter));
+        assert_eq!(trace_events_logged("merchant_test"), 1);
+        assert_eq!(trace_events_logged("merchant_other"), 0);
+
+        router_env::reload_log_filter(&[]);
+        assert_eq!(trace_events_logged("merchant_test"), 0);
+    });
+
+    router_env::reload_log_filter(&[log_filter]);
+
+    fn_with_colon(13)
+        .instrument(router_env::tracing::info_span!(
+            "request",
+            merchant_id = "merchant_test"
+        ))
+        .await;
+
+    router_env::reload_log_filter(&[]);
+}
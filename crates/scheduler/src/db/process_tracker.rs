@@ -292,10 +292,20 @@ impl ProcessTrackerInterface for MockDb {
 
     async fn process_tracker_update_process_status_by_ids(
         &self,
-        _task_ids: Vec<String>,
-        _task_update: storage::ProcessTrackerUpdate,
+        task_ids: Vec<String>,
+        task_update: storage::ProcessTrackerUpdate,
     ) -> CustomResult<usize, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut processes = self.processes.lock().await;
+        let mut updated_count = 0;
+        for process in processes
+            .iter_mut()
+            .filter(|process| task_ids.contains(&process.id))
+        {
+            *process = storage::ProcessTrackerUpdateInternal::from(task_update.clone())
+                .apply_changeset(process.clone());
+            updated_count += 1;
+        }
+
+        Ok(updated_count)
     }
 }
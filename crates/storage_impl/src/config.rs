@@ -1,6 +1,10 @@
-use common_utils::DbConnectionParams;
+use std::sync::Arc;
+
+use common_utils::{DbConnectionParams, PgSslMode};
 use masking::Secret;
 
+use crate::database::credentials::CredentialsProvider;
+
 #[derive(Debug, Clone, serde::Deserialize)]
 pub struct Database {
     pub username: String,
@@ -13,6 +17,12 @@ pub struct Database {
     pub queue_strategy: QueueStrategy,
     pub min_idle: Option<u32>,
     pub max_lifetime: Option<u64>,
+    pub sslmode: Option<PgSslMode>,
+    pub sslrootcert: Option<String>,
+    /// Interval in seconds at which the password is re-fetched from the credentials provider
+    pub credentials_refresh_interval: Option<u64>,
+    #[serde(skip)]
+    pub credentials_provider: Option<Arc<dyn CredentialsProvider>>,
 }
 
 impl DbConnectionParams for Database {
@@ -31,6 +41,12 @@ impl DbConnectionParams for Database {
     fn get_dbname(&self) -> &str {
         &self.dbname
     }
+    fn get_sslmode(&self) -> Option<PgSslMode> {
+        self.sslmode
+    }
+    fn get_sslrootcert(&self) -> Option<&str> {
+        self.sslrootcert.as_deref()
+    }
 }
 
 pub trait TenantConfig: Send + Sync {
@@ -72,6 +88,10 @@ impl Default for Database {
             queue_strategy: QueueStrategy::default(),
             min_idle: None,
             max_lifetime: None,
+            sslmode: None,
+            sslrootcert: None,
+            credentials_refresh_interval: None,
+            credentials_provider: None,
         }
     }
 }
@@ -3,7 +3,9 @@ use common_utils::errors;
 use diesel::PgConnection;
 use error_stack::ResultExt;
 
-pub type PgPool = bb8::Pool<async_bb8_diesel::ConnectionManager<PgConnection>>;
+use crate::database::credentials::PgConnectionManager;
+
+pub type PgPool = bb8::Pool<PgConnectionManager>;
 
 pub type PgPooledConn = async_bb8_diesel::Connection<PgConnection>;
 
@@ -22,10 +24,7 @@ pub async fn redis_connection(
 
 pub async fn pg_connection_read<T: crate::DatabaseStore>(
     store: &T,
-) -> errors::CustomResult<
-    PooledConnection<'_, async_bb8_diesel::ConnectionManager<PgConnection>>,
-    crate::errors::StorageError,
-> {
+) -> errors::CustomResult<PooledConnection<'_, PgConnectionManager>, crate::errors::StorageError> {
     // If only OLAP is enabled get replica pool.
     #[cfg(all(feature = "olap", not(feature = "oltp")))]
     let pool = store.get_replica_pool();
@@ -48,10 +47,7 @@ pub async fn pg_connection_read<T: crate::DatabaseStore>(
 
 pub async fn pg_connection_write<T: crate::DatabaseStore>(
     store: &T,
-) -> errors::CustomResult<
-    PooledConnection<'_, async_bb8_diesel::ConnectionManager<PgConnection>>,
-    crate::errors::StorageError,
-> {
+) -> errors::CustomResult<PooledConnection<'_, PgConnectionManager>, crate::errors::StorageError> {
     // Since all writes should happen to master DB only choose master DB.
     let pool = store.get_master_pool();
 
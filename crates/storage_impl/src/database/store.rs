@@ -5,9 +5,10 @@ use diesel::PgConnection;
 use error_stack::ResultExt;
 use hyperswitch_domain_models::errors::{StorageError, StorageResult};
 
+use super::credentials::PgConnectionManager;
 use crate::config::{Database, TenantConfig};
 
-pub type PgPool = bb8::Pool<async_bb8_diesel::ConnectionManager<PgConnection>>;
+pub type PgPool = bb8::Pool<PgConnectionManager>;
 pub type PgPooledConn = async_bb8_diesel::Connection<PgConnection>;
 
 #[async_trait::async_trait]
@@ -96,8 +97,12 @@ pub async fn diesel_make_pg_pool(
     schema: &str,
     test_transaction: bool,
 ) -> StorageResult<PgPool> {
+    let credentials_refresh = database
+        .credentials_provider
+        .clone()
+        .zip(database.credentials_refresh_interval);
     let database_url = database.get_database_url(schema);
-    let manager = async_bb8_diesel::ConnectionManager::<PgConnection>::new(database_url);
+    let manager = PgConnectionManager::new(database_url, credentials_refresh.is_some());
     let mut pool = bb8::Pool::builder()
         .max_size(database.pool_size)
         .min_idle(database.min_idle)
@@ -109,10 +114,22 @@ pub async fn diesel_make_pg_pool(
         pool = pool.connection_customizer(Box::new(TestTransaction));
     }
 
-    pool.build(manager)
+    let pool = pool
+        .build(manager.clone())
         .await
         .change_context(StorageError::InitializationError)
-        .attach_printable("Failed to create PostgreSQL connection pool")
+        .attach_printable("Failed to create PostgreSQL connection pool")?;
+
+    if let Some((provider, refresh_interval)) = credentials_refresh {
+        manager.spawn_credentials_refresh(
+            database.clone(),
+            schema.to_owned(),
+            provider,
+            std::time::Duration::from_secs(refresh_interval),
+        );
+    }
+
+    Ok(pool)
 }
 
 #[derive(Debug)]
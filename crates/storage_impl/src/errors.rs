@@ -252,6 +252,11 @@ pub enum ApiClientError {
     GatewayTimeoutReceived,
     #[error("Server responded with unexpected response")]
     UnexpectedServerResponse,
+
+    #[error("Request does not match any recorded interaction")]
+    UnexpectedRequest,
+    #[error("Failed to read or write the recorded interactions")]
+    CassetteOperationFailed,
 }
 
 impl ApiClientError {
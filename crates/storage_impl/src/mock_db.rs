@@ -60,6 +60,16 @@ pub struct MockDb {
     pub user_key_store: Arc<Mutex<Vec<store::user_key_store::UserKeyStore>>>,
     pub user_authentication_methods:
         Arc<Mutex<Vec<store::user_authentication_method::UserAuthenticationMethod>>>,
+    pub audit_logs: Arc<Mutex<Vec<store::audit_log::AuditLog>>>,
+    pub payout_batches: Arc<Mutex<Vec<store::payout_batch::PayoutBatch>>>,
+    pub payout_batch_items: Arc<Mutex<Vec<store::payout_batch::PayoutBatchItem>>>,
+    pub subscription_plans: Arc<Mutex<Vec<store::subscription::SubscriptionPlan>>>,
+    pub subscriptions: Arc<Mutex<Vec<store::subscription::Subscription>>>,
+    pub subscription_invoices: Arc<Mutex<Vec<store::subscription::SubscriptionInvoice>>>,
+    pub webhook_signing_secrets:
+        Arc<Mutex<Vec<store::webhook_signing_secret::WebhookSigningSecret>>>,
+    pub webhook_endpoints: Arc<Mutex<Vec<store::webhook_endpoint::WebhookEndpoint>>>,
+    pub tenants: Arc<Mutex<Vec<store::tenant::Tenant>>>,
 }
 
 impl MockDb {
@@ -105,6 +115,15 @@ impl MockDb {
             roles: Default::default(),
             user_key_store: Default::default(),
             user_authentication_methods: Default::default(),
+            audit_logs: Default::default(),
+            payout_batches: Default::default(),
+            payout_batch_items: Default::default(),
+            subscription_plans: Default::default(),
+            subscriptions: Default::default(),
+            subscription_invoices: Default::default(),
+            webhook_signing_secrets: Default::default(),
+            webhook_endpoints: Default::default(),
+            tenants: Default::default(),
         })
     }
 }
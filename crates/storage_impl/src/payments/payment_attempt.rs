@@ -418,6 +418,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                     charge_id: payment_attempt.charge_id.clone(),
                     client_source: payment_attempt.client_source.clone(),
                     client_version: payment_attempt.client_version.clone(),
+                    installments: payment_attempt.installments.clone(),
                 };
 
                 let field = format!("pa_{}", created_attempt.attempt_id);
@@ -1202,6 +1203,7 @@ impl DataModelExt for PaymentAttempt {
             charge_id: self.charge_id,
             client_source: self.client_source,
             client_version: self.client_version,
+            installments: self.installments,
         }
     }
 
@@ -1268,6 +1270,7 @@ impl DataModelExt for PaymentAttempt {
             charge_id: storage_model.charge_id,
             client_source: storage_model.client_source,
             client_version: storage_model.client_version,
+            installments: storage_model.installments,
         }
     }
 }
@@ -1339,6 +1342,7 @@ impl DataModelExt for PaymentAttemptNew {
             charge_id: self.charge_id,
             client_source: self.client_source,
             client_version: self.client_version,
+            installments: self.installments,
         }
     }
 
@@ -1404,6 +1408,7 @@ impl DataModelExt for PaymentAttemptNew {
             charge_id: storage_model.charge_id,
             client_source: storage_model.client_source,
             client_version: storage_model.client_version,
+            installments: storage_model.installments,
         }
     }
 }
@@ -1431,6 +1436,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 fingerprint_id,
                 payment_method_billing_address_id,
                 updated_by,
+                installments,
             } => DieselPaymentAttemptUpdate::Update {
                 amount: amount.get_amount_as_i64(),
                 currency,
@@ -1451,6 +1457,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 fingerprint_id,
                 payment_method_billing_address_id,
                 updated_by,
+                installments,
             },
             Self::UpdateTrackers {
                 payment_token,
@@ -1528,6 +1535,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 payment_method_billing_address_id,
                 client_source,
                 client_version,
+                installments,
             } => DieselPaymentAttemptUpdate::ConfirmUpdate {
                 amount: amount.get_amount_as_i64(),
                 currency,
@@ -1560,6 +1568,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 payment_method_billing_address_id,
                 client_source,
                 client_version,
+                installments,
             },
             Self::VoidUpdate {
                 status,
@@ -1788,6 +1797,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 fingerprint_id,
                 updated_by,
                 payment_method_billing_address_id,
+                installments,
             } => Self::Update {
                 amount: MinorUnit::new(amount),
                 currency,
@@ -1806,6 +1816,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 fingerprint_id,
                 payment_method_billing_address_id,
                 updated_by,
+                installments,
             },
             DieselPaymentAttemptUpdate::UpdateTrackers {
                 payment_token,
@@ -1863,6 +1874,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 payment_method_billing_address_id,
                 client_source,
                 client_version,
+                installments,
             } => Self::ConfirmUpdate {
                 amount: MinorUnit::new(amount),
                 currency,
@@ -1893,6 +1905,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 payment_method_billing_address_id,
                 client_source,
                 client_version,
+                installments,
             },
             DieselPaymentAttemptUpdate::VoidUpdate {
                 status,
@@ -41,9 +41,15 @@ const SURCHARGE_CACHE_PREFIX: &str = "surcharge";
 /// Prefix for cgraph cache key
 const CGRAPH_CACHE_PREFIX: &str = "cgraph";
 
+/// Prefix for unified translations cache key
+const UNIFIED_TRANSLATIONS_CACHE_PREFIX: &str = "unified_translations";
+
 /// Prefix for all kinds of cache key
 const ALL_CACHE_PREFIX: &str = "all_cache_kind";
 
+/// Prefix for log filter messages, which carry the active log filters instead of a cache key
+const LOG_FILTER_PREFIX: &str = "log_filter";
+
 /// Time to live 30 mins
 const CACHE_TTL: u64 = 30 * 60;
 
@@ -83,6 +89,17 @@ pub static SURCHARGE_CACHE: Lazy<Cache> =
 pub static CGRAPH_CACHE: Lazy<Cache> =
     Lazy::new(|| Cache::new("CGRAPH_CACHE", CACHE_TTL, CACHE_TTI, Some(MAX_CAPACITY)));
 
+/// Unified Translations Cache, holding the translation of a unified error message for a locale
+/// if there is one
+pub static UNIFIED_TRANSLATIONS_CACHE: Lazy<Cache> = Lazy::new(|| {
+    Cache::new(
+        "UNIFIED_TRANSLATIONS_CACHE",
+        CACHE_TTL,
+        CACHE_TTI,
+        Some(MAX_CAPACITY),
+    )
+});
+
 /// Trait which defines the behaviour of types that's gonna be stored in Cache
 pub trait Cacheable: Any + Send + Sync + DynClone {
     fn as_any(&self) -> &dyn Any;
@@ -95,7 +112,10 @@ pub enum CacheKind<'a> {
     DecisionManager(Cow<'a, str>),
     Surcharge(Cow<'a, str>),
     CGraph(Cow<'a, str>),
+    UnifiedTranslations(Cow<'a, str>),
     All(Cow<'a, str>),
+    /// Active log filters, serialized as JSON, to be applied by all the instances
+    LogFilter(Cow<'a, str>),
 }
 
 impl<'a> From<CacheKind<'a>> for RedisValue {
@@ -107,7 +127,9 @@ impl<'a> From<CacheKind<'a>> for RedisValue {
             CacheKind::DecisionManager(s) => format!("{DECISION_MANAGER_CACHE_PREFIX},{s}"),
             CacheKind::Surcharge(s) => format!("{SURCHARGE_CACHE_PREFIX},{s}"),
             CacheKind::CGraph(s) => format!("{CGRAPH_CACHE_PREFIX},{s}"),
+            CacheKind::UnifiedTranslations(s) => format!("{UNIFIED_TRANSLATIONS_CACHE_PREFIX},{s}"),
             CacheKind::All(s) => format!("{ALL_CACHE_PREFIX},{s}"),
+            CacheKind::LogFilter(s) => format!("{LOG_FILTER_PREFIX},{s}"),
         };
         Self::from_string(value)
     }
@@ -130,7 +152,11 @@ impl<'a> TryFrom<RedisValue> for CacheKind<'a> {
             }
             SURCHARGE_CACHE_PREFIX => Ok(Self::Surcharge(Cow::Owned(split.1.to_string()))),
             CGRAPH_CACHE_PREFIX => Ok(Self::CGraph(Cow::Owned(split.1.to_string()))),
+            UNIFIED_TRANSLATIONS_CACHE_PREFIX => {
+                Ok(Self::UnifiedTranslations(Cow::Owned(split.1.to_string())))
+            }
             ALL_CACHE_PREFIX => Ok(Self::All(Cow::Owned(split.1.to_string()))),
+            LOG_FILTER_PREFIX => Ok(Self::LogFilter(Cow::Owned(split.1.to_string()))),
             _ => Err(validation_err.into()),
         }
     }
@@ -6,7 +6,7 @@ use router_env::{logger, tracing::Instrument};
 
 use crate::redis::cache::{
     CacheKey, CacheKind, ACCOUNTS_CACHE, CGRAPH_CACHE, CONFIG_CACHE, DECISION_MANAGER_CACHE,
-    ROUTING_CACHE, SURCHARGE_CACHE,
+    ROUTING_CACHE, SURCHARGE_CACHE, UNIFIED_TRANSLATIONS_CACHE,
 };
 
 #[async_trait::async_trait]
@@ -147,6 +147,15 @@ impl PubSubInterface for std::sync::Arc<redis_interface::RedisConnectionPool> {
                                 .await;
                             key
                         }
+                        CacheKind::UnifiedTranslations(key) => {
+                            UNIFIED_TRANSLATIONS_CACHE
+                                .remove(CacheKey {
+                                    key: key.to_string(),
+                                    prefix: self.key_prefix.clone(),
+                                })
+                                .await;
+                            key
+                        }
                         CacheKind::All(key) => {
                             CONFIG_CACHE
                                 .remove(CacheKey {
@@ -184,9 +193,27 @@ impl PubSubInterface for std::sync::Arc<redis_interface::RedisConnectionPool> {
                                     prefix: self.key_prefix.clone(),
                                 })
                                 .await;
+                            UNIFIED_TRANSLATIONS_CACHE
+                                .remove(CacheKey {
+                                    key: key.to_string(),
+                                    prefix: self.key_prefix.clone(),
+                                })
+                                .await;
 
                             key
                         }
+                        CacheKind::LogFilter(log_filters) => {
+                            match serde_json::from_str::<Vec<router_env::LogFilterOverride>>(
+                                &log_filters,
+                            ) {
+                                Ok(log_filters) => router_env::reload_log_filter(&log_filters),
+                                Err(error) => {
+                                    logger::error!(?error, "Failed to parse the log filters")
+                                }
+                            }
+                            // Log filters are not cached, there is no key to invalidate
+                            continue;
+                        }
                     };
 
                     self.delete_key(key.as_ref())
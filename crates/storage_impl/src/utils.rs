@@ -1,16 +1,14 @@
 use bb8::PooledConnection;
-use diesel::PgConnection;
 use error_stack::ResultExt;
 use hyperswitch_domain_models::errors::StorageError;
 
-use crate::{errors::RedisErrorExt, metrics, DatabaseStore};
+use crate::{
+    database::credentials::PgConnectionManager, errors::RedisErrorExt, metrics, DatabaseStore,
+};
 
 pub async fn pg_connection_read<T: DatabaseStore>(
     store: &T,
-) -> error_stack::Result<
-    PooledConnection<'_, async_bb8_diesel::ConnectionManager<PgConnection>>,
-    StorageError,
-> {
+) -> error_stack::Result<PooledConnection<'_, PgConnectionManager>, StorageError> {
     // If only OLAP is enabled get replica pool.
     #[cfg(all(feature = "olap", not(feature = "oltp")))]
     let pool = store.get_replica_pool();
@@ -33,10 +31,7 @@ pub async fn pg_connection_read<T: DatabaseStore>(
 
 pub async fn pg_connection_write<T: DatabaseStore>(
     store: &T,
-) -> error_stack::Result<
-    PooledConnection<'_, async_bb8_diesel::ConnectionManager<PgConnection>>,
-    StorageError,
-> {
+) -> error_stack::Result<PooledConnection<'_, PgConnectionManager>, StorageError> {
     // Since all writes should happen to master DB only choose master DB.
     let pool = store.get_master_pool();
 
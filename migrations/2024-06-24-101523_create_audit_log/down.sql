@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS audit_log_merchant_id_created_at_index;
+DROP INDEX IF EXISTS audit_log_org_id_created_at_index;
+DROP INDEX IF EXISTS audit_log_entity_created_at_index;
+DROP TABLE IF EXISTS audit_log;
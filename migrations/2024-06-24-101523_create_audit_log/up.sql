@@ -0,0 +1,21 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS audit_log (
+    id VARCHAR(64) PRIMARY KEY,
+    tenant_id VARCHAR(64) NOT NULL,
+    org_id VARCHAR(64),
+    merchant_id VARCHAR(64),
+    actor_type VARCHAR(64) NOT NULL,
+    actor_id VARCHAR(64),
+    action VARCHAR(64) NOT NULL,
+    entity_type VARCHAR(64) NOT NULL,
+    entity_id VARCHAR(255),
+    previous_state JSONB,
+    after_state JSONB,
+    ip_address VARCHAR(64),
+    request_id VARCHAR(64),
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS audit_log_merchant_id_created_at_index ON audit_log (merchant_id, created_at);
+CREATE INDEX IF NOT EXISTS audit_log_org_id_created_at_index ON audit_log (org_id, created_at);
+CREATE INDEX IF NOT EXISTS audit_log_entity_created_at_index ON audit_log (entity_type, entity_id, created_at);
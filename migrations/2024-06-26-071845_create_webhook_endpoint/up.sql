@@ -0,0 +1,23 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS webhook_endpoint (
+    endpoint_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    url TEXT NOT NULL,
+    description VARCHAR(255),
+    auth BYTEA,
+    signing_secret BYTEA NOT NULL,
+    enabled_events "EventType"[] NOT NULL,
+    is_enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS webhook_endpoint_profile_id_index ON webhook_endpoint (profile_id);
+
+ALTER TABLE events
+ADD COLUMN IF NOT EXISTS webhook_endpoint_id VARCHAR(64) DEFAULT NULL;
+
+-- Idempotent event IDs of events delivered to webhook endpoints include the endpoint ID
+ALTER TABLE events
+ALTER COLUMN idempotent_event_id TYPE VARCHAR(255);
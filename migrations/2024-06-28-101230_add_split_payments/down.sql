@@ -0,0 +1,3 @@
+ALTER TABLE payment_intent DROP COLUMN IF EXISTS split_payments;
+
+ALTER TABLE refund DROP COLUMN IF EXISTS split_refunds;
@@ -0,0 +1,3 @@
+ALTER TABLE payment_intent ADD COLUMN IF NOT EXISTS split_payments JSONB;
+
+ALTER TABLE refund ADD COLUMN IF NOT EXISTS split_refunds JSONB;
@@ -0,0 +1,33 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS payout_batch (
+    batch_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    file_id VARCHAR(64) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    concurrency INTEGER NOT NULL,
+    total_count INTEGER NOT NULL,
+    succeeded_count INTEGER NOT NULL DEFAULT 0,
+    failed_count INTEGER NOT NULL DEFAULT 0,
+    cancelled_count INTEGER NOT NULL DEFAULT 0,
+    result_file_id VARCHAR(64),
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS payout_batch_merchant_id_created_at_index ON payout_batch (merchant_id, created_at);
+
+CREATE TABLE IF NOT EXISTS payout_batch_item (
+    batch_id VARCHAR(64) NOT NULL,
+    row_number INTEGER NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    payout_id VARCHAR(64) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    payout_status "PayoutStatus",
+    error_code VARCHAR(64),
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (batch_id, row_number)
+);
+
+CREATE INDEX IF NOT EXISTS payout_batch_item_batch_id_status_index ON payout_batch_item (batch_id, status);
@@ -0,0 +1,7 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS payment_methods_network_token_reference_id_index;
+
+ALTER TABLE payment_methods
+DROP COLUMN IF EXISTS network_token_reference_id,
+DROP COLUMN IF EXISTS network_token_status,
+DROP COLUMN IF EXISTS network_token_data;
@@ -0,0 +1,7 @@
+-- Your SQL goes here
+ALTER TABLE payment_methods
+ADD COLUMN IF NOT EXISTS network_token_reference_id VARCHAR(128) DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS network_token_status VARCHAR(32) DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS network_token_data BYTEA DEFAULT NULL;
+
+CREATE UNIQUE INDEX IF NOT EXISTS payment_methods_network_token_reference_id_index ON payment_methods (network_token_reference_id);
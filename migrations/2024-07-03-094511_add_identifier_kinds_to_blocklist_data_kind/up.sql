@@ -0,0 +1,8 @@
+-- Your SQL goes here
+ALTER TYPE "BlocklistDataKind" ADD VALUE IF NOT EXISTS 'email';
+ALTER TYPE "BlocklistDataKind" ADD VALUE IF NOT EXISTS 'email_domain';
+ALTER TYPE "BlocklistDataKind" ADD VALUE IF NOT EXISTS 'ip_address';
+ALTER TYPE "BlocklistDataKind" ADD VALUE IF NOT EXISTS 'ip_range';
+ALTER TYPE "BlocklistDataKind" ADD VALUE IF NOT EXISTS 'phone_number';
+ALTER TYPE "BlocklistDataKind" ADD VALUE IF NOT EXISTS 'customer_id';
+ALTER TYPE "BlocklistDataKind" ADD VALUE IF NOT EXISTS 'device_fingerprint';
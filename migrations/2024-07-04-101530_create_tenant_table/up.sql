@@ -0,0 +1,14 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS tenant (
+    tenant_id VARCHAR(64) PRIMARY KEY,
+    name VARCHAR(255) NOT NULL,
+    base_url VARCHAR(255) NOT NULL,
+    schema_name VARCHAR(63) NOT NULL,
+    redis_key_prefix VARCHAR(64) NOT NULL,
+    clickhouse_database VARCHAR(64) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS tenant_schema_name_index ON tenant (schema_name);
@@ -0,0 +1,73 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS subscription_plan (
+    plan_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    description TEXT,
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    billing_interval VARCHAR(32) NOT NULL,
+    billing_interval_count INTEGER NOT NULL,
+    trial_period_days INTEGER,
+    active BOOLEAN NOT NULL DEFAULT TRUE,
+    metadata JSONB,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS subscription_plan_merchant_id_created_at_index ON subscription_plan (merchant_id, created_at);
+
+CREATE TABLE IF NOT EXISTS subscription (
+    subscription_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    plan_id VARCHAR(64) NOT NULL,
+    mandate_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    billing_cycle_anchor TIMESTAMP NOT NULL,
+    current_period_start TIMESTAMP,
+    current_period_end TIMESTAMP,
+    trial_end TIMESTAMP,
+    next_billing_at TIMESTAMP,
+    cancel_at_period_end BOOLEAN NOT NULL DEFAULT FALSE,
+    cancelled_at TIMESTAMP,
+    cancellation_reason VARCHAR(255),
+    metadata JSONB,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS subscription_merchant_id_customer_id_index ON subscription (merchant_id, customer_id);
+
+CREATE INDEX IF NOT EXISTS subscription_merchant_id_created_at_index ON subscription (merchant_id, created_at);
+
+CREATE TABLE IF NOT EXISTS subscription_invoice (
+    invoice_id VARCHAR(64) PRIMARY KEY,
+    subscription_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    period_start TIMESTAMP NOT NULL,
+    period_end TIMESTAMP NOT NULL,
+    payment_id VARCHAR(64),
+    attempt_count INTEGER NOT NULL DEFAULT 0,
+    next_attempt_at TIMESTAMP,
+    error_code VARCHAR(255),
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS subscription_invoice_subscription_id_created_at_index ON subscription_invoice (subscription_id, created_at);
+
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'subscriptions';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'subscription_details';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'subscription_renewed';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'subscription_past_due';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'subscription_cancelled';
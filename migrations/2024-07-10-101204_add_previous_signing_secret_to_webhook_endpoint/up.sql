@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE webhook_endpoint
+ADD COLUMN IF NOT EXISTS previous_signing_secret BYTEA,
+ADD COLUMN IF NOT EXISTS previous_signing_secret_expires_at TIMESTAMP;
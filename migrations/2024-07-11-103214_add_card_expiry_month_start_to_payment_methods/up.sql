@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE payment_methods
+ADD COLUMN IF NOT EXISTS card_expiry_month_start TIMESTAMP DEFAULT NULL;
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+CREATE UNIQUE INDEX IF NOT EXISTS tenant_redis_key_prefix_index ON tenant (redis_key_prefix);